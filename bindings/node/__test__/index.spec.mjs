@@ -6,11 +6,15 @@ import {
   CreditCard,
   CompanyId,
   Swift,
+  Iban,
+  VatId,
   generateIban,
   validateIban,
   formatIban,
   ibanCountries,
+  describe as describeScheme,
 } from '../index.js';
+import { stream } from '../stream.js';
 
 describe('IBAN', () => {
   it('should generate a valid German IBAN', () => {
@@ -46,6 +50,12 @@ describe('BankAccount', () => {
     assert.ok(result.formatted);
   });
 
+  it('should use parent country rules for territories', () => {
+    const result = BankAccount.generate('PR');
+    assert.strictEqual(result.countryCode, 'PR');
+    assert.strictEqual(result.effectiveCountryCode, 'US');
+  });
+
   it('should validate a generated account', () => {
     const result = BankAccount.generate('US');
     assert.ok(BankAccount.validate('US', result.raw));
@@ -84,6 +94,11 @@ describe('PersonalId', () => {
     assert.ok(result.dob);
   });
 
+  it('should generate for a region', () => {
+    const code = PersonalId.generate('MX', undefined, undefined, 'MX-JAL');
+    assert.strictEqual(PersonalId.parse('MX', code).region, 'JAL');
+  });
+
   it('should list countries', () => {
     const countries = PersonalId.listCountries();
     assert.ok(countries.length > 40);
@@ -91,6 +106,13 @@ describe('PersonalId', () => {
     assert.ok(codes.includes('US'));
     assert.ok(codes.includes('EE'));
   });
+
+  it('should list countries in a locale', () => {
+    const fi = PersonalId.listCountries('latin').find((c) => c.code === 'FI');
+    assert.strictEqual(fi.countryName, 'Suomi');
+    assert.strictEqual(fi.idName, 'Henkilotunnus');
+    assert.throws(() => PersonalId.listCountries('klingon'));
+  });
 });
 
 describe('CreditCard', () => {
@@ -165,3 +187,76 @@ describe('Swift', () => {
     assert.ok(Swift.validate(result.code));
   });
 });
+
+describe('describe', () => {
+  it('should describe a personal ID scheme', () => {
+    const info = describeScheme('id', 'EE');
+    assert.strictEqual(info.minLength, 11);
+    assert.strictEqual(info.charset, 'digits');
+    assert.ok(info.hasChecksum);
+    assert.ok(info.encodesDob);
+    assert.ok(new RegExp(info.regex).test(info.example));
+  });
+
+  it('should require a country for personal IDs', () => {
+    assert.throws(() => describeScheme('id'));
+  });
+});
+
+describe('seeding and bulk generation', () => {
+  it('should repeat results for the same seed', () => {
+    assert.strictEqual(generateIban('DE', 42), generateIban('DE', 42));
+    assert.deepStrictEqual(CreditCard.generate('visa', 7), CreditCard.generate('visa', 7));
+  });
+
+  it('should generate many typed results', () => {
+    const ids = PersonalId.generateMany(5, { country: 'EE', gender: 'female', seed: 1 });
+    assert.strictEqual(ids.length, 5);
+    for (const id of ids) {
+      assert.ok(id.valid);
+      assert.strictEqual(id.gender, 'female');
+    }
+    assert.deepStrictEqual(ids, PersonalId.generateMany(5, { country: 'EE', gender: 'female', seed: 1 }));
+  });
+
+  it('should generate many on the thread pool', async () => {
+    const cards = await CreditCard.generateManyAsync(100, { brand: 'amex', seed: 3 });
+    assert.deepStrictEqual(cards, CreditCard.generateMany(100, { brand: 'amex', seed: 3 }));
+    assert.ok(cards.every((c) => c.brand === 'AMEX' && c.valid));
+  });
+
+  it('should reject unsupported countries asynchronously', async () => {
+    await assert.rejects(BankAccount.generateManyAsync(1, { country: 'XX' }));
+  });
+
+  it('should stream rows in batches', async () => {
+    const rows = [];
+    for await (const row of stream('vat', 25, { country: 'DE', seed: 9 }, { batchSize: 10 })) {
+      rows.push(row);
+    }
+    assert.deepStrictEqual(rows, VatId.generateMany(25, { country: 'DE', seed: 9 }));
+  });
+
+  it('should require a country to stream personal IDs', () => {
+    assert.throws(() => stream('id', 1));
+  });
+});
+
+describe('Iban', () => {
+  it('should generate and parse an IBAN', () => {
+    const result = Iban.generate('FR', 5);
+    assert.strictEqual(result.country, 'FR');
+    assert.ok(result.valid);
+    assert.deepStrictEqual(Iban.parse(result.formatted), result);
+    assert.strictEqual(Iban.format(result.iban), result.formatted);
+  });
+
+  it('should reject invalid IBANs when parsing', () => {
+    assert.ok(!Iban.validate('DE00000000000000000000'));
+    assert.throws(() => Iban.parse('DE00000000000000000000'));
+  });
+
+  it('should list the same countries as ibanCountries', () => {
+    assert.deepStrictEqual(Iban.listCountries(), ibanCountries());
+  });
+});
@@ -1,12 +1,43 @@
+use idsmith::kind::IdKind;
 use napi::bindgen_prelude::*;
 use napi_derive::napi;
-use rand::thread_rng;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+fn parse_locale(locale: Option<&str>) -> Result<Option<idsmith::locale::Locale>> {
+    locale
+        .map(|l| {
+            l.parse()
+                .map_err(|e: String| Error::new(Status::InvalidArg, e))
+        })
+        .transpose()
+}
+
+/// A generator seeded with `seed` for reproducible output, otherwise from
+/// the OS.
+fn seeded_rng(seed: Option<i64>) -> StdRng {
+    match seed {
+        Some(seed) => StdRng::seed_from_u64(seed as u64),
+        None => StdRng::from_entropy(),
+    }
+}
+
+/// Call `generate` `count` times with one generator.
+fn generate_many<R>(
+    count: u32,
+    seed: Option<i64>,
+    mut generate: impl FnMut(&mut StdRng) -> Result<R>,
+) -> Result<Vec<R>> {
+    let mut rng = seeded_rng(seed);
+    (0..count).map(|_| generate(&mut rng)).collect()
+}
 
 // ── Result types ──
 
 #[napi(object)]
 pub struct AccountResult {
     pub country_code: String,
+    pub effective_country_code: String,
     pub country_name: String,
     pub format_name: String,
     pub bank_code: Option<String>,
@@ -23,6 +54,7 @@ impl From<idsmith::bank_account::AccountResult> for AccountResult {
     fn from(r: idsmith::bank_account::AccountResult) -> Self {
         Self {
             country_code: r.country_code,
+            effective_country_code: r.effective_country_code,
             country_name: r.country_name,
             format_name: r.format_name,
             bank_code: r.bank_code,
@@ -31,7 +63,7 @@ impl From<idsmith::bank_account::AccountResult> for AccountResult {
             check_digits: r.check_digits,
             formatted: r.formatted,
             raw: r.raw,
-            iban: r.iban,
+            iban: r.iban.map(String::from),
             valid: r.valid,
         }
     }
@@ -40,9 +72,11 @@ impl From<idsmith::bank_account::AccountResult> for AccountResult {
 #[napi(object)]
 pub struct IdResult {
     pub country_code: String,
+    pub effective_country_code: String,
     pub code: String,
     pub gender: Option<String>,
     pub dob: Option<String>,
+    pub region: Option<String>,
     pub valid: bool,
 }
 
@@ -50,9 +84,11 @@ impl From<idsmith::personal_id::IdResult> for IdResult {
     fn from(r: idsmith::personal_id::IdResult) -> Self {
         Self {
             country_code: r.country_code,
+            effective_country_code: r.effective_country_code,
             code: r.code,
             gender: r.gender,
             dob: r.dob,
+            region: r.region,
             valid: r.valid,
         }
     }
@@ -72,7 +108,7 @@ impl From<idsmith::credit_card::CardResult> for CardResult {
     fn from(r: idsmith::credit_card::CardResult) -> Self {
         Self {
             brand: r.brand,
-            number: r.number,
+            number: r.number.into(),
             formatted: r.formatted,
             cvv: r.cvv,
             expiry: r.expiry,
@@ -84,6 +120,7 @@ impl From<idsmith::credit_card::CardResult> for CardResult {
 #[napi(object)]
 pub struct CompanyResult {
     pub country_code: String,
+    pub effective_country_code: String,
     pub country_name: String,
     pub name: String,
     pub code: String,
@@ -94,6 +131,7 @@ impl From<idsmith::company_id::CompanyResult> for CompanyResult {
     fn from(r: idsmith::company_id::CompanyResult) -> Self {
         Self {
             country_code: r.country_code,
+            effective_country_code: r.effective_country_code,
             country_name: r.country_name,
             name: r.name,
             code: r.code,
@@ -115,7 +153,7 @@ pub struct SwiftResult {
 impl From<idsmith::swift::SwiftResult> for SwiftResult {
     fn from(r: idsmith::swift::SwiftResult) -> Self {
         Self {
-            code: r.code,
+            code: r.code.into(),
             bank: r.bank,
             country: r.country,
             location: r.location,
@@ -142,6 +180,36 @@ pub struct CountryInfo {
 
 // ── BankAccount ──
 
+#[napi(object)]
+pub struct BankAccountOptions {
+    pub country: String,
+    pub bank_code: Option<String>,
+    /// Fail for territories without their own format instead of using the
+    /// parent country's.
+    pub strict: Option<bool>,
+    pub seed: Option<i64>,
+}
+
+fn bank_accounts(
+    options: BankAccountOptions,
+) -> impl FnMut(&mut StdRng) -> Result<AccountResult> + Send + 'static {
+    let opts = idsmith::bank_account::GenOptions {
+        bank_code: options.bank_code,
+        strict: options.strict.unwrap_or(false),
+    };
+    move |rng| {
+        idsmith::bank_accounts()
+            .generate(&options.country, &opts, rng)
+            .map(AccountResult::from)
+            .ok_or_else(|| {
+                Error::new(
+                    Status::InvalidArg,
+                    format!("Unsupported country: {}", options.country),
+                )
+            })
+    }
+}
+
 #[napi]
 pub struct BankAccount;
 
@@ -153,18 +221,33 @@ impl BankAccount {
     }
 
     #[napi]
-    pub fn generate(country: String, bank_code: Option<String>) -> Result<AccountResult> {
-        let mut rng = thread_rng();
-        let opts = idsmith::bank_account::GenOptions { bank_code };
-        idsmith::bank_accounts()
-            .generate(&country, &opts, &mut rng)
-            .map(AccountResult::from)
-            .ok_or_else(|| {
-                Error::new(
-                    Status::InvalidArg,
-                    format!("Unsupported country: {}", country),
-                )
-            })
+    pub fn generate(
+        country: String,
+        bank_code: Option<String>,
+        seed: Option<i64>,
+    ) -> Result<AccountResult> {
+        let options = BankAccountOptions {
+            country,
+            bank_code,
+            strict: None,
+            seed,
+        };
+        bank_accounts(options)(&mut seeded_rng(seed))
+    }
+
+    #[napi]
+    pub fn generate_many(count: u32, options: BankAccountOptions) -> Result<Vec<AccountResult>> {
+        generate_many(count, options.seed, bank_accounts(options))
+    }
+
+    #[napi(ts_return_type = "Promise<Array<AccountResult>>")]
+    pub fn generate_many_async(
+        count: u32,
+        options: BankAccountOptions,
+        signal: Option<AbortSignal>,
+    ) -> AsyncTask<Many> {
+        let seed = options.seed;
+        Many::task(count, seed, bank_accounts(options), Row::Account, signal)
     }
 
     #[napi]
@@ -192,9 +275,13 @@ impl BankAccount {
     }
 
     #[napi]
-    pub fn list_countries() -> Vec<BankCountryInfo> {
-        idsmith::bank_accounts()
-            .list_countries()
+    pub fn list_countries(locale: Option<String>) -> Result<Vec<BankCountryInfo>> {
+        let registry = idsmith::bank_accounts();
+        let countries = match parse_locale(locale.as_deref())? {
+            Some(locale) => registry.list_countries_in(locale),
+            None => registry.list_countries(),
+        };
+        Ok(countries
             .iter()
             .map(|(code, name, format, has_iban)| BankCountryInfo {
                 code: code.to_string(),
@@ -202,7 +289,7 @@ impl BankAccount {
                 format: format.to_string(),
                 has_iban: *has_iban,
             })
-            .collect()
+            .collect())
     }
 
     #[napi]
@@ -213,6 +300,46 @@ impl BankAccount {
 
 // ── PersonalId ──
 
+#[napi(object)]
+pub struct PersonalIdOptions {
+    pub country: String,
+    /// `male` or `female`; random if unset.
+    pub gender: Option<String>,
+    pub year: Option<u32>,
+    /// ISO 3166-2 subdivision for IDs that encode one (CN, MX).
+    pub region: Option<String>,
+    /// Fail for territories without their own format instead of using the
+    /// parent country's.
+    pub strict: Option<bool>,
+    pub seed: Option<i64>,
+}
+
+/// Generated IDs are parsed, so that results carry the encoded birth date,
+/// gender and region.
+fn personal_ids(
+    options: PersonalIdOptions,
+) -> impl FnMut(&mut StdRng) -> Result<IdResult> + Send + 'static {
+    let opts = idsmith::personal_id::GenOptions {
+        gender: idsmith::personal_id::date::Gender::from_str_opt(options.gender.as_deref()),
+        year: options.year.map(|y| y as u16),
+        region: options.region,
+        strict: options.strict.unwrap_or(false),
+    };
+    move |rng| {
+        let registry = idsmith::personal_ids();
+        registry
+            .generate(&options.country, &opts, rng)
+            .and_then(|code| registry.parse(&options.country, &code))
+            .map(IdResult::from)
+            .ok_or_else(|| {
+                Error::new(
+                    Status::InvalidArg,
+                    format!("Unsupported country or region: {}", options.country),
+                )
+            })
+    }
+}
+
 #[napi]
 pub struct PersonalId;
 
@@ -224,21 +351,37 @@ impl PersonalId {
     }
 
     #[napi]
-    pub fn generate(country: String, gender: Option<String>, year: Option<u32>) -> Result<String> {
-        let mut rng = thread_rng();
-        let g = idsmith::personal_id::date::Gender::from_str_opt(gender.as_deref());
-        let opts = idsmith::personal_id::GenOptions {
-            gender: g,
-            year: year.map(|y| y as u16),
+    pub fn generate(
+        country: String,
+        gender: Option<String>,
+        year: Option<u32>,
+        region: Option<String>,
+        seed: Option<i64>,
+    ) -> Result<String> {
+        let options = PersonalIdOptions {
+            country,
+            gender,
+            year,
+            region,
+            strict: None,
+            seed,
         };
-        idsmith::personal_ids()
-            .generate(&country, &opts, &mut rng)
-            .ok_or_else(|| {
-                Error::new(
-                    Status::InvalidArg,
-                    format!("Unsupported country: {}", country),
-                )
-            })
+        personal_ids(options)(&mut seeded_rng(seed)).map(|r| r.code)
+    }
+
+    #[napi]
+    pub fn generate_many(count: u32, options: PersonalIdOptions) -> Result<Vec<IdResult>> {
+        generate_many(count, options.seed, personal_ids(options))
+    }
+
+    #[napi(ts_return_type = "Promise<Array<IdResult>>")]
+    pub fn generate_many_async(
+        count: u32,
+        options: PersonalIdOptions,
+        signal: Option<AbortSignal>,
+    ) -> AsyncTask<Many> {
+        let seed = options.seed;
+        Many::task(count, seed, personal_ids(options), Row::Id, signal)
     }
 
     #[napi]
@@ -267,16 +410,20 @@ impl PersonalId {
     }
 
     #[napi]
-    pub fn list_countries() -> Vec<CountryInfo> {
-        idsmith::personal_ids()
-            .list_countries()
+    pub fn list_countries(locale: Option<String>) -> Result<Vec<CountryInfo>> {
+        let registry = idsmith::personal_ids();
+        let countries = match parse_locale(locale.as_deref())? {
+            Some(locale) => registry.list_countries_in(locale),
+            None => registry.list_countries(),
+        };
+        Ok(countries
             .iter()
             .map(|(code, country_name, id_name)| CountryInfo {
                 code: code.to_string(),
                 country_name: country_name.to_string(),
                 id_name: id_name.to_string(),
             })
-            .collect()
+            .collect())
     }
 
     #[napi]
@@ -287,6 +434,30 @@ impl PersonalId {
 
 // ── CreditCard ──
 
+#[napi(object)]
+#[derive(Default)]
+pub struct CreditCardOptions {
+    /// `visa`, `mastercard`, `amex`, `discover`, `jcb` or `diners`; random
+    /// if unset.
+    pub brand: Option<String>,
+    pub seed: Option<i64>,
+}
+
+fn credit_cards(
+    options: CreditCardOptions,
+) -> impl FnMut(&mut StdRng) -> Result<CardResult> + Send + 'static {
+    let opts = idsmith::credit_card::GenOptions {
+        brand: options.brand,
+        current_year: None,
+    };
+    move |rng| {
+        idsmith::credit_cards()
+            .generate(&opts, rng)
+            .map(CardResult::from)
+            .ok_or_else(|| Error::new(Status::GenericFailure, "Failed to generate credit card"))
+    }
+}
+
 #[napi]
 pub struct CreditCard;
 
@@ -298,13 +469,28 @@ impl CreditCard {
     }
 
     #[napi]
-    pub fn generate(brand: Option<String>) -> Result<CardResult> {
-        let mut rng = thread_rng();
-        let opts = idsmith::credit_card::GenOptions { brand, current_year: None };
-        idsmith::credit_cards()
-            .generate(&opts, &mut rng)
-            .map(CardResult::from)
-            .ok_or_else(|| Error::new(Status::GenericFailure, "Failed to generate credit card"))
+    pub fn generate(brand: Option<String>, seed: Option<i64>) -> Result<CardResult> {
+        credit_cards(CreditCardOptions { brand, seed })(&mut seeded_rng(seed))
+    }
+
+    #[napi]
+    pub fn generate_many(
+        count: u32,
+        options: Option<CreditCardOptions>,
+    ) -> Result<Vec<CardResult>> {
+        let options = options.unwrap_or_default();
+        generate_many(count, options.seed, credit_cards(options))
+    }
+
+    #[napi(ts_return_type = "Promise<Array<CardResult>>")]
+    pub fn generate_many_async(
+        count: u32,
+        options: Option<CreditCardOptions>,
+        signal: Option<AbortSignal>,
+    ) -> AsyncTask<Many> {
+        let options = options.unwrap_or_default();
+        let seed = options.seed;
+        Many::task(count, seed, credit_cards(options), Row::Card, signal)
     }
 
     #[napi]
@@ -329,6 +515,32 @@ impl CreditCard {
 
 // ── CompanyId ──
 
+#[napi(object)]
+#[derive(Default)]
+pub struct CompanyIdOptions {
+    /// Random if unset.
+    pub country: Option<String>,
+    /// Fail for territories without their own format instead of using the
+    /// parent country's.
+    pub strict: Option<bool>,
+    pub seed: Option<i64>,
+}
+
+fn company_ids(
+    options: CompanyIdOptions,
+) -> impl FnMut(&mut StdRng) -> Result<CompanyResult> + Send + 'static {
+    let opts = idsmith::company_id::GenOptions {
+        country: options.country,
+        strict: options.strict.unwrap_or(false),
+    };
+    move |rng| {
+        idsmith::company_ids()
+            .generate(&opts, rng)
+            .map(CompanyResult::from)
+            .ok_or_else(|| Error::new(Status::GenericFailure, "Failed to generate company ID"))
+    }
+}
+
 #[napi]
 pub struct CompanyId;
 
@@ -340,13 +552,33 @@ impl CompanyId {
     }
 
     #[napi]
-    pub fn generate(country: Option<String>) -> Result<CompanyResult> {
-        let mut rng = thread_rng();
-        let opts = idsmith::company_id::GenOptions { country };
-        idsmith::company_ids()
-            .generate(&opts, &mut rng)
-            .map(CompanyResult::from)
-            .ok_or_else(|| Error::new(Status::GenericFailure, "Failed to generate company ID"))
+    pub fn generate(country: Option<String>, seed: Option<i64>) -> Result<CompanyResult> {
+        let options = CompanyIdOptions {
+            country,
+            strict: None,
+            seed,
+        };
+        company_ids(options)(&mut seeded_rng(seed))
+    }
+
+    #[napi]
+    pub fn generate_many(
+        count: u32,
+        options: Option<CompanyIdOptions>,
+    ) -> Result<Vec<CompanyResult>> {
+        let options = options.unwrap_or_default();
+        generate_many(count, options.seed, company_ids(options))
+    }
+
+    #[napi(ts_return_type = "Promise<Array<CompanyResult>>")]
+    pub fn generate_many_async(
+        count: u32,
+        options: Option<CompanyIdOptions>,
+        signal: Option<AbortSignal>,
+    ) -> AsyncTask<Many> {
+        let options = options.unwrap_or_default();
+        let seed = options.seed;
+        Many::task(count, seed, company_ids(options), Row::Company, signal)
     }
 
     #[napi]
@@ -355,21 +587,42 @@ impl CompanyId {
     }
 
     #[napi]
-    pub fn list_countries() -> Vec<CountryInfo> {
-        idsmith::company_ids()
-            .list_countries()
+    pub fn list_countries(locale: Option<String>) -> Result<Vec<CountryInfo>> {
+        let registry = idsmith::company_ids();
+        let countries = match parse_locale(locale.as_deref())? {
+            Some(locale) => registry.list_countries_in(locale),
+            None => registry.list_countries(),
+        };
+        Ok(countries
             .iter()
             .map(|(code, country_name, id_name)| CountryInfo {
                 code: code.to_string(),
                 country_name: country_name.to_string(),
                 id_name: id_name.to_string(),
             })
-            .collect()
+            .collect())
     }
 }
 
 // ── Swift ──
 
+#[napi(object)]
+#[derive(Default)]
+pub struct SwiftOptions {
+    /// Random if unset.
+    pub country: Option<String>,
+    pub seed: Option<i64>,
+}
+
+fn swift_codes(
+    options: SwiftOptions,
+) -> impl FnMut(&mut StdRng) -> Result<SwiftResult> + Send + 'static {
+    let opts = idsmith::swift::GenOptions {
+        country: options.country,
+    };
+    move |rng| Ok(idsmith::swift_codes().generate(&opts, rng).into())
+}
+
 #[napi]
 pub struct Swift;
 
@@ -381,10 +634,25 @@ impl Swift {
     }
 
     #[napi]
-    pub fn generate(country: Option<String>) -> SwiftResult {
-        let mut rng = thread_rng();
-        let opts = idsmith::swift::GenOptions { country };
-        idsmith::swift_codes().generate(&opts, &mut rng).into()
+    pub fn generate(country: Option<String>, seed: Option<i64>) -> Result<SwiftResult> {
+        swift_codes(SwiftOptions { country, seed })(&mut seeded_rng(seed))
+    }
+
+    #[napi]
+    pub fn generate_many(count: u32, options: Option<SwiftOptions>) -> Result<Vec<SwiftResult>> {
+        let options = options.unwrap_or_default();
+        generate_many(count, options.seed, swift_codes(options))
+    }
+
+    #[napi(ts_return_type = "Promise<Array<SwiftResult>>")]
+    pub fn generate_many_async(
+        count: u32,
+        options: Option<SwiftOptions>,
+        signal: Option<AbortSignal>,
+    ) -> AsyncTask<Many> {
+        let options = options.unwrap_or_default();
+        let seed = options.seed;
+        Many::task(count, seed, swift_codes(options), Row::Swift, signal)
     }
 
     #[napi]
@@ -398,6 +666,7 @@ impl Swift {
 #[napi(object)]
 pub struct DriverLicenseResult {
     pub country_code: String,
+    pub effective_country_code: String,
     pub country_name: String,
     pub name: String,
     pub code: String,
@@ -409,6 +678,7 @@ impl From<idsmith::driver_license::DriverLicenseResult> for DriverLicenseResult
     fn from(r: idsmith::driver_license::DriverLicenseResult) -> Self {
         Self {
             country_code: r.country_code,
+            effective_country_code: r.effective_country_code,
             country_name: r.country_name,
             name: r.name,
             code: r.code,
@@ -418,6 +688,41 @@ impl From<idsmith::driver_license::DriverLicenseResult> for DriverLicenseResult
     }
 }
 
+#[napi(object)]
+#[derive(Default)]
+pub struct DriverLicenseOptions {
+    /// Random if unset.
+    pub country: Option<String>,
+    /// ISO 3166-2 subdivision for countries that issue licences per state,
+    /// as `US-CA`, `CA` or `California`.
+    pub state: Option<String>,
+    /// Fail for territories without their own format instead of using the
+    /// parent country's.
+    pub strict: Option<bool>,
+    pub seed: Option<i64>,
+}
+
+fn driver_licenses(
+    options: DriverLicenseOptions,
+) -> impl FnMut(&mut StdRng) -> Result<DriverLicenseResult> + Send + 'static {
+    let opts = idsmith::driver_license::GenOptions {
+        country: options.country,
+        state: options.state,
+        strict: options.strict.unwrap_or(false),
+    };
+    move |rng| {
+        idsmith::driver_licenses()
+            .generate(&opts, rng)
+            .map(DriverLicenseResult::from)
+            .ok_or_else(|| {
+                Error::new(
+                    Status::GenericFailure,
+                    "Failed to generate driver's license",
+                )
+            })
+    }
+}
+
 #[napi]
 pub struct DriverLicense;
 
@@ -429,18 +734,44 @@ impl DriverLicense {
     }
 
     #[napi]
-    pub fn generate(country: Option<String>, state: Option<String>) -> Result<DriverLicenseResult> {
-        let mut rng = thread_rng();
-        let opts = idsmith::driver_license::GenOptions { country, state };
-        idsmith::driver_licenses()
-            .generate(&opts, &mut rng)
-            .map(DriverLicenseResult::from)
-            .ok_or_else(|| {
-                Error::new(
-                    Status::GenericFailure,
-                    "Failed to generate driver's license",
-                )
-            })
+    pub fn generate(
+        country: Option<String>,
+        state: Option<String>,
+        seed: Option<i64>,
+    ) -> Result<DriverLicenseResult> {
+        let options = DriverLicenseOptions {
+            country,
+            state,
+            strict: None,
+            seed,
+        };
+        driver_licenses(options)(&mut seeded_rng(seed))
+    }
+
+    #[napi]
+    pub fn generate_many(
+        count: u32,
+        options: Option<DriverLicenseOptions>,
+    ) -> Result<Vec<DriverLicenseResult>> {
+        let options = options.unwrap_or_default();
+        generate_many(count, options.seed, driver_licenses(options))
+    }
+
+    #[napi(ts_return_type = "Promise<Array<DriverLicenseResult>>")]
+    pub fn generate_many_async(
+        count: u32,
+        options: Option<DriverLicenseOptions>,
+        signal: Option<AbortSignal>,
+    ) -> AsyncTask<Many> {
+        let options = options.unwrap_or_default();
+        let seed = options.seed;
+        Many::task(
+            count,
+            seed,
+            driver_licenses(options),
+            Row::DriverLicense,
+            signal,
+        )
     }
 
     #[napi]
@@ -449,16 +780,20 @@ impl DriverLicense {
     }
 
     #[napi]
-    pub fn list_countries() -> Vec<CountryInfo> {
-        idsmith::driver_licenses()
-            .list_countries()
+    pub fn list_countries(locale: Option<String>) -> Result<Vec<CountryInfo>> {
+        let registry = idsmith::driver_licenses();
+        let countries = match parse_locale(locale.as_deref())? {
+            Some(locale) => registry.list_countries_in(locale),
+            None => registry.list_countries(),
+        };
+        Ok(countries
             .iter()
             .map(|(code, country_name, id_name)| CountryInfo {
                 code: code.to_string(),
                 country_name: country_name.to_string(),
                 id_name: id_name.to_string(),
             })
-            .collect()
+            .collect())
     }
 }
 
@@ -467,6 +802,7 @@ impl DriverLicense {
 #[napi(object)]
 pub struct TaxIdResult {
     pub country_code: String,
+    pub effective_country_code: String,
     pub country_name: String,
     pub name: String,
     pub code: String,
@@ -478,6 +814,7 @@ impl From<idsmith::tax_id::TaxIdResult> for TaxIdResult {
     fn from(r: idsmith::tax_id::TaxIdResult) -> Self {
         Self {
             country_code: r.country_code,
+            effective_country_code: r.effective_country_code,
             country_name: r.country_name,
             name: r.name,
             code: r.code,
@@ -487,6 +824,35 @@ impl From<idsmith::tax_id::TaxIdResult> for TaxIdResult {
     }
 }
 
+#[napi(object)]
+#[derive(Default)]
+pub struct TaxIdOptions {
+    /// Random if unset.
+    pub country: Option<String>,
+    /// PAN holder type for IN, e.g. `P` (person) or `C` (company).
+    pub holder_type: Option<String>,
+    /// Fail for territories without their own format instead of using the
+    /// parent country's.
+    pub strict: Option<bool>,
+    pub seed: Option<i64>,
+}
+
+fn tax_ids(
+    options: TaxIdOptions,
+) -> impl FnMut(&mut StdRng) -> Result<TaxIdResult> + Send + 'static {
+    let opts = idsmith::tax_id::GenOptions {
+        country: options.country,
+        holder_type: options.holder_type,
+        strict: options.strict.unwrap_or(false),
+    };
+    move |rng| {
+        idsmith::tax_ids()
+            .generate(&opts, rng)
+            .map(TaxIdResult::from)
+            .ok_or_else(|| Error::new(Status::GenericFailure, "Failed to generate tax ID"))
+    }
+}
+
 #[napi]
 pub struct TaxId;
 
@@ -498,16 +864,35 @@ impl TaxId {
     }
 
     #[napi]
-    pub fn generate(country: Option<String>, holder_type: Option<String>) -> Result<TaxIdResult> {
-        let mut rng = thread_rng();
-        let opts = idsmith::tax_id::GenOptions {
+    pub fn generate(
+        country: Option<String>,
+        holder_type: Option<String>,
+        seed: Option<i64>,
+    ) -> Result<TaxIdResult> {
+        let options = TaxIdOptions {
             country,
             holder_type,
+            strict: None,
+            seed,
         };
-        idsmith::tax_ids()
-            .generate(&opts, &mut rng)
-            .map(TaxIdResult::from)
-            .ok_or_else(|| Error::new(Status::GenericFailure, "Failed to generate tax ID"))
+        tax_ids(options)(&mut seeded_rng(seed))
+    }
+
+    #[napi]
+    pub fn generate_many(count: u32, options: Option<TaxIdOptions>) -> Result<Vec<TaxIdResult>> {
+        let options = options.unwrap_or_default();
+        generate_many(count, options.seed, tax_ids(options))
+    }
+
+    #[napi(ts_return_type = "Promise<Array<TaxIdResult>>")]
+    pub fn generate_many_async(
+        count: u32,
+        options: Option<TaxIdOptions>,
+        signal: Option<AbortSignal>,
+    ) -> AsyncTask<Many> {
+        let options = options.unwrap_or_default();
+        let seed = options.seed;
+        Many::task(count, seed, tax_ids(options), Row::TaxId, signal)
     }
 
     #[napi]
@@ -516,16 +901,20 @@ impl TaxId {
     }
 
     #[napi]
-    pub fn list_countries() -> Vec<CountryInfo> {
-        idsmith::tax_ids()
-            .list_countries()
+    pub fn list_countries(locale: Option<String>) -> Result<Vec<CountryInfo>> {
+        let registry = idsmith::tax_ids();
+        let countries = match parse_locale(locale.as_deref())? {
+            Some(locale) => registry.list_countries_in(locale),
+            None => registry.list_countries(),
+        };
+        Ok(countries
             .iter()
             .map(|(code, country_name, id_name)| CountryInfo {
                 code: code.to_string(),
                 country_name: country_name.to_string(),
                 id_name: id_name.to_string(),
             })
-            .collect()
+            .collect())
     }
 }
 
@@ -534,6 +923,7 @@ impl TaxId {
 #[napi(object)]
 pub struct PassportResult {
     pub country_code: String,
+    pub effective_country_code: String,
     pub country_name: String,
     pub name: String,
     pub code: String,
@@ -544,6 +934,7 @@ impl From<idsmith::passport::PassportResult> for PassportResult {
     fn from(r: idsmith::passport::PassportResult) -> Self {
         Self {
             country_code: r.country_code,
+            effective_country_code: r.effective_country_code,
             country_name: r.country_name,
             name: r.name,
             code: r.code,
@@ -552,6 +943,32 @@ impl From<idsmith::passport::PassportResult> for PassportResult {
     }
 }
 
+#[napi(object)]
+#[derive(Default)]
+pub struct PassportOptions {
+    /// Random if unset.
+    pub country: Option<String>,
+    /// Fail for territories without their own format instead of using the
+    /// parent country's.
+    pub strict: Option<bool>,
+    pub seed: Option<i64>,
+}
+
+fn passports(
+    options: PassportOptions,
+) -> impl FnMut(&mut StdRng) -> Result<PassportResult> + Send + 'static {
+    let opts = idsmith::passport::GenOptions {
+        country: options.country,
+        strict: options.strict.unwrap_or(false),
+    };
+    move |rng| {
+        idsmith::passports()
+            .generate(&opts, rng)
+            .map(PassportResult::from)
+            .ok_or_else(|| Error::new(Status::GenericFailure, "Failed to generate passport"))
+    }
+}
+
 #[napi]
 pub struct Passport;
 
@@ -563,13 +980,33 @@ impl Passport {
     }
 
     #[napi]
-    pub fn generate(country: Option<String>) -> Result<PassportResult> {
-        let mut rng = thread_rng();
-        let opts = idsmith::passport::GenOptions { country };
-        idsmith::passports()
-            .generate(&opts, &mut rng)
-            .map(PassportResult::from)
-            .ok_or_else(|| Error::new(Status::GenericFailure, "Failed to generate passport"))
+    pub fn generate(country: Option<String>, seed: Option<i64>) -> Result<PassportResult> {
+        let options = PassportOptions {
+            country,
+            strict: None,
+            seed,
+        };
+        passports(options)(&mut seeded_rng(seed))
+    }
+
+    #[napi]
+    pub fn generate_many(
+        count: u32,
+        options: Option<PassportOptions>,
+    ) -> Result<Vec<PassportResult>> {
+        let options = options.unwrap_or_default();
+        generate_many(count, options.seed, passports(options))
+    }
+
+    #[napi(ts_return_type = "Promise<Array<PassportResult>>")]
+    pub fn generate_many_async(
+        count: u32,
+        options: Option<PassportOptions>,
+        signal: Option<AbortSignal>,
+    ) -> AsyncTask<Many> {
+        let options = options.unwrap_or_default();
+        let seed = options.seed;
+        Many::task(count, seed, passports(options), Row::Passport, signal)
     }
 
     #[napi]
@@ -578,16 +1015,20 @@ impl Passport {
     }
 
     #[napi]
-    pub fn list_countries() -> Vec<CountryInfo> {
-        idsmith::passports()
-            .list_countries()
+    pub fn list_countries(locale: Option<String>) -> Result<Vec<CountryInfo>> {
+        let registry = idsmith::passports();
+        let countries = match parse_locale(locale.as_deref())? {
+            Some(locale) => registry.list_countries_in(locale),
+            None => registry.list_countries(),
+        };
+        Ok(countries
             .iter()
             .map(|(code, country_name, id_name)| CountryInfo {
                 code: code.to_string(),
                 country_name: country_name.to_string(),
                 id_name: id_name.to_string(),
             })
-            .collect()
+            .collect())
     }
 }
 
@@ -604,7 +1045,7 @@ pub struct LeiResult {
 impl From<idsmith::lei::LeiResult> for LeiResult {
     fn from(r: idsmith::lei::LeiResult) -> Self {
         Self {
-            code: r.code,
+            code: r.code.into(),
             lou: r.lou,
             country_code: r.country_code,
             valid: r.valid,
@@ -612,6 +1053,21 @@ impl From<idsmith::lei::LeiResult> for LeiResult {
     }
 }
 
+#[napi(object)]
+#[derive(Default)]
+pub struct LeiOptions {
+    /// Random if unset.
+    pub country: Option<String>,
+    pub seed: Option<i64>,
+}
+
+fn lei_codes(options: LeiOptions) -> impl FnMut(&mut StdRng) -> Result<LeiResult> + Send + 'static {
+    let opts = idsmith::lei::GenOptions {
+        country: options.country,
+    };
+    move |rng| Ok(idsmith::lei_codes().generate(&opts, rng).into())
+}
+
 #[napi]
 pub struct LegalEntityId;
 
@@ -623,10 +1079,25 @@ impl LegalEntityId {
     }
 
     #[napi]
-    pub fn generate(country: Option<String>) -> LeiResult {
-        let mut rng = thread_rng();
-        let opts = idsmith::lei::GenOptions { country };
-        idsmith::lei_codes().generate(&opts, &mut rng).into()
+    pub fn generate(country: Option<String>, seed: Option<i64>) -> Result<LeiResult> {
+        lei_codes(LeiOptions { country, seed })(&mut seeded_rng(seed))
+    }
+
+    #[napi]
+    pub fn generate_many(count: u32, options: Option<LeiOptions>) -> Result<Vec<LeiResult>> {
+        let options = options.unwrap_or_default();
+        generate_many(count, options.seed, lei_codes(options))
+    }
+
+    #[napi(ts_return_type = "Promise<Array<LeiResult>>")]
+    pub fn generate_many_async(
+        count: u32,
+        options: Option<LeiOptions>,
+        signal: Option<AbortSignal>,
+    ) -> AsyncTask<Many> {
+        let options = options.unwrap_or_default();
+        let seed = options.seed;
+        Many::task(count, seed, lei_codes(options), Row::Lei, signal)
     }
 
     #[napi]
@@ -641,6 +1112,7 @@ impl LegalEntityId {
 pub struct VatResult {
     pub code: String,
     pub country_code: String,
+    pub effective_country_code: String,
     pub country_name: String,
     pub valid: bool,
 }
@@ -648,14 +1120,40 @@ pub struct VatResult {
 impl From<idsmith::vat::VatResult> for VatResult {
     fn from(r: idsmith::vat::VatResult) -> Self {
         Self {
-            code: r.code,
+            code: r.code.into(),
             country_code: r.country_code,
+            effective_country_code: r.effective_country_code,
             country_name: r.country_name,
             valid: r.valid,
         }
     }
 }
 
+#[napi(object)]
+#[derive(Default)]
+pub struct VatOptions {
+    /// A country, or a group (`eu`, `eurozone`, ...) to pick one from.
+    /// Random if unset.
+    pub country: Option<String>,
+    /// Fail for territories without their own format instead of using the
+    /// parent country's.
+    pub strict: Option<bool>,
+    pub seed: Option<i64>,
+}
+
+fn vat_ids(options: VatOptions) -> impl FnMut(&mut StdRng) -> Result<VatResult> + Send + 'static {
+    let opts = idsmith::vat::GenOptions {
+        country: options.country,
+        strict: options.strict.unwrap_or(false),
+    };
+    move |rng| {
+        idsmith::vat_ids()
+            .generate(&opts, rng)
+            .map(VatResult::from)
+            .ok_or_else(|| Error::new(Status::GenericFailure, "Failed to generate VAT number"))
+    }
+}
+
 #[napi]
 pub struct VatId;
 
@@ -667,13 +1165,30 @@ impl VatId {
     }
 
     #[napi]
-    pub fn generate(country: Option<String>) -> Result<VatResult> {
-        let mut rng = thread_rng();
-        let opts = idsmith::vat::GenOptions { country };
-        idsmith::vat_ids()
-            .generate(&opts, &mut rng)
-            .map(VatResult::from)
-            .ok_or_else(|| Error::new(Status::GenericFailure, "Failed to generate VAT number"))
+    pub fn generate(country: Option<String>, seed: Option<i64>) -> Result<VatResult> {
+        let options = VatOptions {
+            country,
+            strict: None,
+            seed,
+        };
+        vat_ids(options)(&mut seeded_rng(seed))
+    }
+
+    #[napi]
+    pub fn generate_many(count: u32, options: Option<VatOptions>) -> Result<Vec<VatResult>> {
+        let options = options.unwrap_or_default();
+        generate_many(count, options.seed, vat_ids(options))
+    }
+
+    #[napi(ts_return_type = "Promise<Array<VatResult>>")]
+    pub fn generate_many_async(
+        count: u32,
+        options: Option<VatOptions>,
+        signal: Option<AbortSignal>,
+    ) -> AsyncTask<Many> {
+        let options = options.unwrap_or_default();
+        let seed = options.seed;
+        Many::task(count, seed, vat_ids(options), Row::Vat, signal)
     }
 
     #[napi]
@@ -682,25 +1197,123 @@ impl VatId {
     }
 
     #[napi]
-    pub fn list_countries() -> Vec<CountryInfo> {
-        idsmith::vat_ids()
-            .list_countries()
+    pub fn list_countries(locale: Option<String>) -> Result<Vec<CountryInfo>> {
+        let registry = idsmith::vat_ids();
+        let countries = match parse_locale(locale.as_deref())? {
+            Some(locale) => registry.list_countries_in(locale),
+            None => registry.list_countries().to_vec(),
+        };
+        Ok(countries
             .iter()
             .map(|(code, country_name)| CountryInfo {
                 code: code.to_string(),
                 country_name: country_name.to_string(),
                 id_name: "VAT Number".to_string(),
             })
-            .collect()
+            .collect())
+    }
+}
+
+// ── Iban ──
+
+#[napi(object)]
+pub struct IbanResult {
+    pub country: String,
+    pub iban: String,
+    pub formatted: String,
+    pub valid: bool,
+}
+
+impl From<idsmith::Iban> for IbanResult {
+    fn from(iban: idsmith::Iban) -> Self {
+        Self {
+            country: iban.country_code().to_string(),
+            formatted: format!("{:#}", iban),
+            iban: iban.into(),
+            valid: true,
+        }
+    }
+}
+
+#[napi(object)]
+#[derive(Default)]
+pub struct IbanOptions {
+    /// Random if unset.
+    pub country: Option<String>,
+    pub seed: Option<i64>,
+}
+
+fn ibans(options: IbanOptions) -> impl FnMut(&mut StdRng) -> Result<IbanResult> + Send + 'static {
+    move |rng| {
+        let code = idsmith::iban::generate_iban(options.country.as_deref(), rng)
+            .map_err(|e| Error::new(Status::InvalidArg, e))?;
+        code.parse::<idsmith::Iban>()
+            .map(IbanResult::from)
+            .map_err(|e| Error::new(Status::GenericFailure, e))
+    }
+}
+
+#[napi]
+pub struct Iban;
+
+#[napi]
+impl Iban {
+    #[napi(factory)]
+    pub fn create() -> Self {
+        Self
+    }
+
+    #[napi]
+    pub fn generate(country: Option<String>, seed: Option<i64>) -> Result<IbanResult> {
+        ibans(IbanOptions { country, seed })(&mut seeded_rng(seed))
+    }
+
+    #[napi]
+    pub fn generate_many(count: u32, options: Option<IbanOptions>) -> Result<Vec<IbanResult>> {
+        let options = options.unwrap_or_default();
+        generate_many(count, options.seed, ibans(options))
+    }
+
+    #[napi(ts_return_type = "Promise<Array<IbanResult>>")]
+    pub fn generate_many_async(
+        count: u32,
+        options: Option<IbanOptions>,
+        signal: Option<AbortSignal>,
+    ) -> AsyncTask<Many> {
+        let options = options.unwrap_or_default();
+        let seed = options.seed;
+        Many::task(count, seed, ibans(options), Row::Iban, signal)
+    }
+
+    #[napi]
+    pub fn validate(iban: String) -> bool {
+        idsmith::iban::validate_iban(&iban)
+    }
+
+    /// Throws for invalid IBANs.
+    #[napi]
+    pub fn parse(iban: String) -> Result<IbanResult> {
+        iban.parse::<idsmith::Iban>()
+            .map(IbanResult::from)
+            .map_err(|e| Error::new(Status::InvalidArg, e))
+    }
+
+    #[napi]
+    pub fn format(iban: String) -> String {
+        idsmith::iban::format_iban(&iban)
+    }
+
+    #[napi]
+    pub fn list_countries() -> Vec<String> {
+        iban_countries()
     }
 }
 
 // ── IBAN functions ──
 
 #[napi]
-pub fn generate_iban(country: Option<String>) -> Result<String> {
-    let mut rng = thread_rng();
-    idsmith::iban::generate_iban(country.as_deref(), &mut rng)
+pub fn generate_iban(country: Option<String>, seed: Option<i64>) -> Result<String> {
+    idsmith::iban::generate_iban(country.as_deref(), &mut seeded_rng(seed))
         .map_err(|e| Error::new(Status::InvalidArg, e))
 }
 
@@ -721,3 +1334,303 @@ pub fn iban_countries() -> Vec<String> {
         .map(|s| s.to_string())
         .collect()
 }
+
+// ── Bulk generation ──
+
+/// A generated result of any kind.
+pub enum Row {
+    Iban(IbanResult),
+    Account(AccountResult),
+    Id(IdResult),
+    Card(CardResult),
+    Company(CompanyResult),
+    Swift(SwiftResult),
+    DriverLicense(DriverLicenseResult),
+    TaxId(TaxIdResult),
+    Passport(PassportResult),
+    Lei(LeiResult),
+    Vat(VatResult),
+}
+
+impl TypeName for Row {
+    fn type_name() -> &'static str {
+        "Row"
+    }
+
+    fn value_type() -> ValueType {
+        ValueType::Object
+    }
+}
+
+impl ToNapiValue for Row {
+    unsafe fn to_napi_value(env: sys::napi_env, val: Self) -> Result<sys::napi_value> {
+        match val {
+            Row::Iban(r) => ToNapiValue::to_napi_value(env, r),
+            Row::Account(r) => ToNapiValue::to_napi_value(env, r),
+            Row::Id(r) => ToNapiValue::to_napi_value(env, r),
+            Row::Card(r) => ToNapiValue::to_napi_value(env, r),
+            Row::Company(r) => ToNapiValue::to_napi_value(env, r),
+            Row::Swift(r) => ToNapiValue::to_napi_value(env, r),
+            Row::DriverLicense(r) => ToNapiValue::to_napi_value(env, r),
+            Row::TaxId(r) => ToNapiValue::to_napi_value(env, r),
+            Row::Passport(r) => ToNapiValue::to_napi_value(env, r),
+            Row::Lei(r) => ToNapiValue::to_napi_value(env, r),
+            Row::Vat(r) => ToNapiValue::to_napi_value(env, r),
+        }
+    }
+}
+
+type GenerateRow = Box<dyn FnMut(&mut StdRng) -> Result<Row> + Send>;
+
+fn boxed<R: 'static>(
+    mut generate: impl FnMut(&mut StdRng) -> Result<R> + Send + 'static,
+    row: fn(R) -> Row,
+) -> GenerateRow {
+    Box::new(move |rng| generate(rng).map(row))
+}
+
+/// `generateManyAsync`, run on the libuv thread pool.
+pub struct Many {
+    count: u32,
+    seed: Option<i64>,
+    generate: GenerateRow,
+}
+
+impl Many {
+    fn task<R: 'static>(
+        count: u32,
+        seed: Option<i64>,
+        generate: impl FnMut(&mut StdRng) -> Result<R> + Send + 'static,
+        row: fn(R) -> Row,
+        signal: Option<AbortSignal>,
+    ) -> AsyncTask<Many> {
+        let task = Many {
+            count,
+            seed,
+            generate: boxed(generate, row),
+        };
+        AsyncTask::with_optional_signal(task, signal)
+    }
+}
+
+impl Task for Many {
+    type Output = Vec<Row>;
+    type JsValue = Vec<Row>;
+
+    fn compute(&mut self) -> Result<Self::Output> {
+        generate_many(self.count, self.seed, &mut self.generate)
+    }
+
+    fn resolve(&mut self, _env: Env, output: Self::Output) -> Result<Self::JsValue> {
+        Ok(output)
+    }
+}
+
+/// Options for [`rows`]; each kind reads the ones it supports.
+#[napi(object)]
+#[derive(Default)]
+pub struct GenerateOptions {
+    pub country: Option<String>,
+    pub gender: Option<String>,
+    pub year: Option<u32>,
+    pub region: Option<String>,
+    pub bank_code: Option<String>,
+    pub brand: Option<String>,
+    pub state: Option<String>,
+    pub holder_type: Option<String>,
+    pub strict: Option<bool>,
+    pub seed: Option<i64>,
+}
+
+/// Results generated in batches, for streaming large datasets. With the
+/// same seed, the rows match `generateMany`.
+#[napi]
+pub struct Rows {
+    remaining: u32,
+    rng: StdRng,
+    generate: GenerateRow,
+}
+
+#[napi]
+impl Rows {
+    /// Up to `size` more rows; empty once all have been generated.
+    #[napi(
+        ts_return_type = "Array<IbanResult | AccountResult | IdResult | CardResult | CompanyResult | SwiftResult | DriverLicenseResult | TaxIdResult | PassportResult | LeiResult | VatResult>"
+    )]
+    pub fn next_batch(&mut self, size: u32) -> Result<Vec<Row>> {
+        let size = size.min(self.remaining);
+        self.remaining -= size;
+        (0..size).map(|_| (self.generate)(&mut self.rng)).collect()
+    }
+}
+
+/// `count` results of `kind` (`iban`, `account`, `id`, `card`, `company`,
+/// `swift`, `license`, `tax`, `passport`, `lei` or `vat`), generated in
+/// batches with `Rows.nextBatch`.
+#[napi]
+pub fn rows(kind: String, count: u32, options: Option<GenerateOptions>) -> Result<Rows> {
+    let kind: IdKind = kind
+        .parse()
+        .map_err(|e: String| Error::new(Status::InvalidArg, e))?;
+    let o = options.unwrap_or_default();
+    let country = || {
+        o.country.clone().ok_or_else(|| {
+            Error::new(
+                Status::InvalidArg,
+                format!("A country is required for {}", kind),
+            )
+        })
+    };
+    let generate = match kind {
+        IdKind::Iban => boxed(
+            ibans(IbanOptions {
+                country: o.country.clone(),
+                seed: None,
+            }),
+            Row::Iban,
+        ),
+        IdKind::BankAccount => boxed(
+            bank_accounts(BankAccountOptions {
+                country: country()?,
+                bank_code: o.bank_code.clone(),
+                strict: o.strict,
+                seed: None,
+            }),
+            Row::Account,
+        ),
+        IdKind::PersonalId => boxed(
+            personal_ids(PersonalIdOptions {
+                country: country()?,
+                gender: o.gender.clone(),
+                year: o.year,
+                region: o.region.clone(),
+                strict: o.strict,
+                seed: None,
+            }),
+            Row::Id,
+        ),
+        IdKind::CreditCard => boxed(
+            credit_cards(CreditCardOptions {
+                brand: o.brand.clone(),
+                seed: None,
+            }),
+            Row::Card,
+        ),
+        IdKind::CompanyId => boxed(
+            company_ids(CompanyIdOptions {
+                country: o.country.clone(),
+                strict: o.strict,
+                seed: None,
+            }),
+            Row::Company,
+        ),
+        IdKind::Swift => boxed(
+            swift_codes(SwiftOptions {
+                country: o.country.clone(),
+                seed: None,
+            }),
+            Row::Swift,
+        ),
+        IdKind::DriverLicense => boxed(
+            driver_licenses(DriverLicenseOptions {
+                country: o.country.clone(),
+                state: o.state.clone(),
+                strict: o.strict,
+                seed: None,
+            }),
+            Row::DriverLicense,
+        ),
+        IdKind::TaxId => boxed(
+            tax_ids(TaxIdOptions {
+                country: o.country.clone(),
+                holder_type: o.holder_type.clone(),
+                strict: o.strict,
+                seed: None,
+            }),
+            Row::TaxId,
+        ),
+        IdKind::Passport => boxed(
+            passports(PassportOptions {
+                country: o.country.clone(),
+                strict: o.strict,
+                seed: None,
+            }),
+            Row::Passport,
+        ),
+        IdKind::Lei => boxed(
+            lei_codes(LeiOptions {
+                country: o.country.clone(),
+                seed: None,
+            }),
+            Row::Lei,
+        ),
+        IdKind::Vat => boxed(
+            vat_ids(VatOptions {
+                country: o.country.clone(),
+                strict: o.strict,
+                seed: None,
+            }),
+            Row::Vat,
+        ),
+    };
+    Ok(Rows {
+        remaining: count,
+        rng: seeded_rng(o.seed),
+        generate,
+    })
+}
+
+// ── Scheme description ──
+
+#[napi(object)]
+pub struct SchemeInfo {
+    pub kind: String,
+    pub country: Option<String>,
+    pub name: String,
+    pub min_length: u32,
+    pub max_length: u32,
+    pub charset: String,
+    pub separators: Vec<String>,
+    pub has_checksum: bool,
+    pub checksum: Option<String>,
+    pub encodes_dob: bool,
+    pub encodes_gender: bool,
+    pub example: String,
+    pub regex: String,
+}
+
+impl From<idsmith::describe::SchemeInfo> for SchemeInfo {
+    fn from(r: idsmith::describe::SchemeInfo) -> Self {
+        Self {
+            kind: r.kind.as_str().to_string(),
+            country: r.country,
+            name: r.name,
+            min_length: r.min_length as u32,
+            max_length: r.max_length as u32,
+            charset: r.charset.to_string(),
+            separators: r.separators.iter().map(|c| c.to_string()).collect(),
+            has_checksum: r.has_checksum,
+            checksum: r.checksum,
+            encodes_dob: r.encodes_dob,
+            encodes_gender: r.encodes_gender,
+            example: r.example,
+            regex: r.regex,
+        }
+    }
+}
+
+#[napi]
+pub fn describe(kind: String, country: Option<String>) -> Result<SchemeInfo> {
+    let kind: idsmith::kind::IdKind = kind
+        .parse()
+        .map_err(|e: String| Error::new(Status::InvalidArg, e))?;
+    idsmith::describe(kind, country.as_deref())
+        .map(SchemeInfo::from)
+        .ok_or_else(|| {
+            let msg = match country {
+                Some(c) => format!("Unsupported country for {}: {}", kind, c),
+                None => format!("A country is required for {}", kind),
+            };
+            Error::new(Status::InvalidArg, msg)
+        })
+}
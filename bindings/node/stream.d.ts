@@ -0,0 +1,46 @@
+import type { Readable } from 'node:stream'
+import type {
+  AccountResult,
+  CardResult,
+  CompanyResult,
+  DriverLicenseResult,
+  GenerateOptions,
+  IbanResult,
+  IdResult,
+  LeiResult,
+  PassportResult,
+  SwiftResult,
+  TaxIdResult,
+  VatResult,
+} from './index'
+
+export interface StreamOptions {
+  /** Results generated per native call. Defaults to 1000. */
+  batchSize?: number
+}
+
+/** Maps each kind accepted by `stream` to the results it yields. */
+export interface StreamRows {
+  iban: IbanResult
+  account: AccountResult
+  id: IdResult
+  card: CardResult
+  company: CompanyResult
+  swift: SwiftResult
+  license: DriverLicenseResult
+  tax: TaxIdResult
+  passport: PassportResult
+  lei: LeiResult
+  vat: VatResult
+}
+
+/**
+ * An object-mode `Readable` of `count` generated results of `kind`, each a
+ * `StreamRows[kind]`.
+ */
+export declare function stream<K extends keyof StreamRows>(
+  kind: K,
+  count: number,
+  options?: GenerateOptions,
+  streamOptions?: StreamOptions,
+): Readable
@@ -0,0 +1,26 @@
+'use strict'
+
+const { Readable } = require('node:stream')
+const { rows } = require('./index.js')
+
+/**
+ * An object-mode `Readable` of `count` generated results of `kind`.
+ *
+ * Results are generated natively in batches of `batchSize`, so memory use
+ * stays flat however large `count` is. With a `seed`, the rows match
+ * `generateMany` for the same kind and options.
+ */
+function stream(kind, count, options, { batchSize = 1000 } = {}) {
+  const batches = rows(kind, count, options)
+  return Readable.from(
+    (function* () {
+      for (;;) {
+        const batch = batches.nextBatch(batchSize)
+        if (batch.length === 0) return
+        yield* batch
+      }
+    })(),
+  )
+}
+
+module.exports = { stream }
@@ -11,8 +11,11 @@ from ._idsmith import (
     VatId,
     generate_iban,
     validate_iban,
+    parse_iban,
     format_iban,
     iban_countries,
+    describe,
+    _generate_arrow,
 )
 
 __version__ = "0.4.0"
@@ -29,6 +32,22 @@ __all__ = [
     "VatId",
     "generate_iban",
     "validate_iban",
+    "parse_iban",
     "format_iban",
     "iban_countries",
+    "describe",
+    "generate_table",
 ]
+
+
+def generate_table(kind, count, country=None, seed=None):
+    """Generate ``count`` results of ``kind`` as a ``pyarrow.Table``.
+
+    Rows are built into Arrow arrays in Rust and handed over as an Arrow
+    IPC file, so no per-row dicts are created. The same ``seed`` gives the
+    same table. Requires ``pyarrow``.
+    """
+    import pyarrow as pa
+
+    data = _generate_arrow(kind, count, country, seed)
+    return pa.ipc.open_file(pa.BufferReader(data)).read_all()
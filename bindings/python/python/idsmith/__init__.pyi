@@ -1,96 +1,403 @@
-from typing import Optional
+from typing import Optional, TypedDict, overload
+
+import pyarrow
+
+class AccountResult(TypedDict):
+    country_code: str
+    effective_country_code: str
+    country_name: str
+    format_name: str
+    bank_code: Optional[str]
+    branch_code: Optional[str]
+    account_number: str
+    check_digits: Optional[str]
+    formatted: str
+    raw: str
+    iban: Optional[str]
+    valid: bool
+
+class IdResult(TypedDict):
+    country_code: str
+    effective_country_code: str
+    code: str
+    gender: Optional[str]
+    dob: Optional[str]
+    region: Optional[str]
+    valid: bool
+
+class CardResult(TypedDict):
+    brand: str
+    number: str
+    formatted: str
+    cvv: str
+    expiry: str
+    valid: bool
+
+class ParsedCard(TypedDict):
+    brand: Optional[str]
+    number: str
+    formatted: str
+    valid: bool
+
+class CompanyResult(TypedDict):
+    country_code: str
+    effective_country_code: str
+    country_name: str
+    name: str
+    code: str
+    valid: bool
+
+class PassportResult(CompanyResult): ...
+
+class DriverLicenseResult(CompanyResult):
+    state: Optional[str]
+
+class TaxIdResult(CompanyResult):
+    holder_type: Optional[str]
+
+class SwiftResult(TypedDict):
+    code: str
+    bank: str
+    country: str
+    location: str
+    branch: Optional[str]
+    valid: bool
+
+class LeiResult(TypedDict):
+    code: str
+    lou: str
+    country_code: str
+    valid: bool
+
+class VatResult(TypedDict):
+    code: str
+    country_code: str
+    effective_country_code: str
+    country_name: str
+    valid: bool
+
+class IbanResult(TypedDict):
+    country: str
+    iban: str
+    formatted: str
+    valid: bool
+
+class SchemeInfo(TypedDict):
+    kind: str
+    country: Optional[str]
+    name: str
+    min_length: int
+    max_length: int
+    charset: str
+    separators: list[str]
+    has_checksum: bool
+    checksum: Optional[str]
+    encodes_dob: bool
+    encodes_gender: bool
+    example: str
+    regex: str
 
 class BankAccount:
+    @overload
+    @staticmethod
+    def generate(
+        country: str,
+        bank_code: Optional[str] = None,
+        strict: bool = False,
+        count: None = None,
+        seed: Optional[int] = None,
+    ) -> AccountResult: ...
+    @overload
     @staticmethod
-    def generate(country: str, bank_code: Optional[str] = None) -> dict: ...
+    def generate(
+        country: str,
+        bank_code: Optional[str] = None,
+        strict: bool = False,
+        *,
+        count: int,
+        seed: Optional[int] = None,
+    ) -> list[AccountResult]: ...
     @staticmethod
     def validate(country: str, raw: str) -> bool: ...
     @staticmethod
+    def parse(country: str, raw: str) -> AccountResult: ...
+    @staticmethod
+    def describe(country: str) -> SchemeInfo: ...
+    @staticmethod
     def format(country: str, raw: str) -> str: ...
     @staticmethod
-    def list_countries() -> list[dict]: ...
+    def list_countries(locale: str | None = None) -> list[dict]: ...
     @staticmethod
     def is_supported(country: str) -> bool: ...
 
 class PersonalId:
+    @overload
+    @staticmethod
+    def generate(
+        country: str,
+        gender: Optional[str] = None,
+        year: Optional[int] = None,
+        region: Optional[str] = None,
+        strict: bool = False,
+        count: None = None,
+        seed: Optional[int] = None,
+    ) -> IdResult: ...
+    @overload
     @staticmethod
     def generate(
         country: str,
         gender: Optional[str] = None,
         year: Optional[int] = None,
-    ) -> str: ...
+        region: Optional[str] = None,
+        strict: bool = False,
+        *,
+        count: int,
+        seed: Optional[int] = None,
+    ) -> list[IdResult]: ...
     @staticmethod
     def validate(country: str, code: str) -> bool: ...
     @staticmethod
-    def parse(country: str, code: str) -> dict: ...
+    def parse(country: str, code: str) -> IdResult: ...
     @staticmethod
-    def list_countries() -> list[dict]: ...
+    def describe(country: str) -> SchemeInfo: ...
+    @staticmethod
+    def list_countries(locale: str | None = None) -> list[dict]: ...
     @staticmethod
     def is_supported(country: str) -> bool: ...
 
 class CreditCard:
+    @overload
+    @staticmethod
+    def generate(
+        brand: Optional[str] = None,
+        count: None = None,
+        seed: Optional[int] = None,
+    ) -> CardResult: ...
+    @overload
     @staticmethod
-    def generate(brand: Optional[str] = None) -> dict: ...
+    def generate(
+        brand: Optional[str] = None,
+        *,
+        count: int,
+        seed: Optional[int] = None,
+    ) -> list[CardResult]: ...
     @staticmethod
     def validate(number: str) -> bool: ...
     @staticmethod
+    def parse(number: str) -> ParsedCard: ...
+    @staticmethod
+    def describe() -> SchemeInfo: ...
+    @staticmethod
     def format(brand: str, number: str) -> str: ...
     @staticmethod
     def list_brands() -> list[str]: ...
 
 class CompanyId:
+    @overload
     @staticmethod
-    def generate(country: Optional[str] = None) -> dict: ...
+    def generate(
+        country: Optional[str] = None,
+        strict: bool = False,
+        count: None = None,
+        seed: Optional[int] = None,
+    ) -> CompanyResult: ...
+    @overload
+    @staticmethod
+    def generate(
+        country: Optional[str] = None,
+        strict: bool = False,
+        *,
+        count: int,
+        seed: Optional[int] = None,
+    ) -> list[CompanyResult]: ...
     @staticmethod
     def validate(country: str, code: str) -> bool: ...
     @staticmethod
-    def list_countries() -> list[dict]: ...
+    def parse(country: str, code: str) -> CompanyResult: ...
+    @staticmethod
+    def describe(country: str) -> SchemeInfo: ...
+    @staticmethod
+    def list_countries(locale: str | None = None) -> list[dict]: ...
 
 class Swift:
+    @overload
     @staticmethod
-    def generate(country: Optional[str] = None) -> dict: ...
+    def generate(
+        country: Optional[str] = None,
+        count: None = None,
+        seed: Optional[int] = None,
+    ) -> SwiftResult: ...
+    @overload
+    @staticmethod
+    def generate(
+        country: Optional[str] = None,
+        *,
+        count: int,
+        seed: Optional[int] = None,
+    ) -> list[SwiftResult]: ...
     @staticmethod
     def validate(code: str) -> bool: ...
+    @staticmethod
+    def parse(code: str) -> SwiftResult: ...
+    @staticmethod
+    def describe(country: Optional[str] = None) -> SchemeInfo: ...
 
 class DriverLicense:
+    @overload
     @staticmethod
-    def generate(country: Optional[str] = None, state: Optional[str] = None) -> dict: ...
+    def generate(
+        country: Optional[str] = None,
+        state: Optional[str] = None,
+        strict: bool = False,
+        count: None = None,
+        seed: Optional[int] = None,
+    ) -> DriverLicenseResult: ...
+    @overload
+    @staticmethod
+    def generate(
+        country: Optional[str] = None,
+        state: Optional[str] = None,
+        strict: bool = False,
+        *,
+        count: int,
+        seed: Optional[int] = None,
+    ) -> list[DriverLicenseResult]: ...
     @staticmethod
     def validate(country: str, code: str) -> bool: ...
     @staticmethod
-    def list_countries() -> list[dict]: ...
+    def parse(country: str, code: str) -> DriverLicenseResult: ...
+    @staticmethod
+    def describe(country: str) -> SchemeInfo: ...
+    @staticmethod
+    def list_countries(locale: str | None = None) -> list[dict]: ...
 
 class TaxId:
+    @overload
     @staticmethod
-    def generate(country: Optional[str] = None, holder_type: Optional[str] = None) -> dict: ...
+    def generate(
+        country: Optional[str] = None,
+        holder_type: Optional[str] = None,
+        strict: bool = False,
+        count: None = None,
+        seed: Optional[int] = None,
+    ) -> TaxIdResult: ...
+    @overload
+    @staticmethod
+    def generate(
+        country: Optional[str] = None,
+        holder_type: Optional[str] = None,
+        strict: bool = False,
+        *,
+        count: int,
+        seed: Optional[int] = None,
+    ) -> list[TaxIdResult]: ...
     @staticmethod
     def validate(country: str, code: str) -> bool: ...
     @staticmethod
-    def list_countries() -> list[dict]: ...
+    def parse(country: str, code: str) -> TaxIdResult: ...
+    @staticmethod
+    def describe(country: str) -> SchemeInfo: ...
+    @staticmethod
+    def list_countries(locale: str | None = None) -> list[dict]: ...
 
 class Passport:
+    @overload
+    @staticmethod
+    def generate(
+        country: Optional[str] = None,
+        strict: bool = False,
+        count: None = None,
+        seed: Optional[int] = None,
+    ) -> PassportResult: ...
+    @overload
     @staticmethod
-    def generate(country: Optional[str] = None) -> dict: ...
+    def generate(
+        country: Optional[str] = None,
+        strict: bool = False,
+        *,
+        count: int,
+        seed: Optional[int] = None,
+    ) -> list[PassportResult]: ...
     @staticmethod
     def validate(country: str, code: str) -> bool: ...
     @staticmethod
-    def list_countries() -> list[dict]: ...
+    def parse(country: str, code: str) -> PassportResult: ...
+    @staticmethod
+    def describe(country: str) -> SchemeInfo: ...
+    @staticmethod
+    def list_countries(locale: str | None = None) -> list[dict]: ...
 
 class LegalEntityId:
+    @overload
+    @staticmethod
+    def generate(
+        country: Optional[str] = None,
+        count: None = None,
+        seed: Optional[int] = None,
+    ) -> LeiResult: ...
+    @overload
     @staticmethod
-    def generate(country: Optional[str] = None) -> dict: ...
+    def generate(
+        country: Optional[str] = None,
+        *,
+        count: int,
+        seed: Optional[int] = None,
+    ) -> list[LeiResult]: ...
     @staticmethod
     def validate(code: str) -> bool: ...
+    @staticmethod
+    def parse(code: str) -> LeiResult: ...
+    @staticmethod
+    def describe(country: Optional[str] = None) -> SchemeInfo: ...
 
 class VatId:
+    @overload
+    @staticmethod
+    def generate(
+        country: Optional[str] = None,
+        strict: bool = False,
+        count: None = None,
+        seed: Optional[int] = None,
+    ) -> VatResult: ...
+    @overload
     @staticmethod
-    def generate(country: Optional[str] = None) -> dict: ...
+    def generate(
+        country: Optional[str] = None,
+        strict: bool = False,
+        *,
+        count: int,
+        seed: Optional[int] = None,
+    ) -> list[VatResult]: ...
     @staticmethod
     def validate(code: str) -> bool: ...
     @staticmethod
-    def list_countries() -> list[dict]: ...
+    def parse(code: str) -> VatResult: ...
+    @staticmethod
+    def describe(country: Optional[str] = None) -> SchemeInfo: ...
+    @staticmethod
+    def list_countries(locale: str | None = None) -> list[dict]: ...
 
-def generate_iban(country: Optional[str] = None) -> str: ...
+@overload
+def generate_iban(
+    country: Optional[str] = None,
+    count: None = None,
+    seed: Optional[int] = None,
+) -> str: ...
+@overload
+def generate_iban(
+    country: Optional[str] = None,
+    *,
+    count: int,
+    seed: Optional[int] = None,
+) -> list[str]: ...
 def validate_iban(iban: str) -> bool: ...
+def parse_iban(iban: str) -> IbanResult: ...
 def format_iban(iban: str) -> str: ...
 def iban_countries() -> list[str]: ...
+def describe(kind: str, country: Optional[str] = None) -> SchemeInfo: ...
+def generate_table(
+    kind: str,
+    count: int,
+    country: Optional[str] = None,
+    seed: Optional[int] = None,
+) -> pyarrow.Table: ...
@@ -1,12 +1,16 @@
 #![allow(clippy::useless_conversion)]
 
+use idsmith::kind::IdKind;
 use pyo3::prelude::*;
 use pyo3::types::PyDict;
-use rand::thread_rng;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
 
 fn account_result_to_dict(py: Python<'_>, r: &idsmith::bank_account::AccountResult) -> PyObject {
     let dict = PyDict::new(py);
     dict.set_item("country_code", &r.country_code).unwrap();
+    dict.set_item("effective_country_code", &r.effective_country_code)
+        .unwrap();
     dict.set_item("country_name", &r.country_name).unwrap();
     dict.set_item("format_name", &r.format_name).unwrap();
     dict.set_item("bank_code", &r.bank_code).unwrap();
@@ -15,7 +19,7 @@ fn account_result_to_dict(py: Python<'_>, r: &idsmith::bank_account::AccountResu
     dict.set_item("check_digits", &r.check_digits).unwrap();
     dict.set_item("formatted", &r.formatted).unwrap();
     dict.set_item("raw", &r.raw).unwrap();
-    dict.set_item("iban", &r.iban).unwrap();
+    dict.set_item("iban", r.iban.as_deref()).unwrap();
     dict.set_item("valid", r.valid).unwrap();
     dict.into()
 }
@@ -23,9 +27,12 @@ fn account_result_to_dict(py: Python<'_>, r: &idsmith::bank_account::AccountResu
 fn id_result_to_dict(py: Python<'_>, r: &idsmith::personal_id::IdResult) -> PyObject {
     let dict = PyDict::new(py);
     dict.set_item("country_code", &r.country_code).unwrap();
+    dict.set_item("effective_country_code", &r.effective_country_code)
+        .unwrap();
     dict.set_item("code", &r.code).unwrap();
     dict.set_item("gender", &r.gender).unwrap();
     dict.set_item("dob", &r.dob).unwrap();
+    dict.set_item("region", &r.region).unwrap();
     dict.set_item("valid", r.valid).unwrap();
     dict.into()
 }
@@ -33,7 +40,7 @@ fn id_result_to_dict(py: Python<'_>, r: &idsmith::personal_id::IdResult) -> PyOb
 fn card_result_to_dict(py: Python<'_>, r: &idsmith::credit_card::CardResult) -> PyObject {
     let dict = PyDict::new(py);
     dict.set_item("brand", &r.brand).unwrap();
-    dict.set_item("number", &r.number).unwrap();
+    dict.set_item("number", r.number.as_str()).unwrap();
     dict.set_item("formatted", &r.formatted).unwrap();
     dict.set_item("cvv", &r.cvv).unwrap();
     dict.set_item("expiry", &r.expiry).unwrap();
@@ -44,6 +51,8 @@ fn card_result_to_dict(py: Python<'_>, r: &idsmith::credit_card::CardResult) ->
 fn company_result_to_dict(py: Python<'_>, r: &idsmith::company_id::CompanyResult) -> PyObject {
     let dict = PyDict::new(py);
     dict.set_item("country_code", &r.country_code).unwrap();
+    dict.set_item("effective_country_code", &r.effective_country_code)
+        .unwrap();
     dict.set_item("country_name", &r.country_name).unwrap();
     dict.set_item("name", &r.name).unwrap();
     dict.set_item("code", &r.code).unwrap();
@@ -57,6 +66,8 @@ fn license_result_to_dict(
 ) -> PyObject {
     let dict = PyDict::new(py);
     dict.set_item("country_code", &r.country_code).unwrap();
+    dict.set_item("effective_country_code", &r.effective_country_code)
+        .unwrap();
     dict.set_item("country_name", &r.country_name).unwrap();
     dict.set_item("name", &r.name).unwrap();
     dict.set_item("code", &r.code).unwrap();
@@ -68,6 +79,8 @@ fn license_result_to_dict(
 fn tax_result_to_dict(py: Python<'_>, r: &idsmith::tax_id::TaxIdResult) -> PyObject {
     let dict = PyDict::new(py);
     dict.set_item("country_code", &r.country_code).unwrap();
+    dict.set_item("effective_country_code", &r.effective_country_code)
+        .unwrap();
     dict.set_item("country_name", &r.country_name).unwrap();
     dict.set_item("name", &r.name).unwrap();
     dict.set_item("code", &r.code).unwrap();
@@ -79,6 +92,8 @@ fn tax_result_to_dict(py: Python<'_>, r: &idsmith::tax_id::TaxIdResult) -> PyObj
 fn passport_result_to_dict(py: Python<'_>, r: &idsmith::passport::PassportResult) -> PyObject {
     let dict = PyDict::new(py);
     dict.set_item("country_code", &r.country_code).unwrap();
+    dict.set_item("effective_country_code", &r.effective_country_code)
+        .unwrap();
     dict.set_item("country_name", &r.country_name).unwrap();
     dict.set_item("name", &r.name).unwrap();
     dict.set_item("code", &r.code).unwrap();
@@ -88,7 +103,7 @@ fn passport_result_to_dict(py: Python<'_>, r: &idsmith::passport::PassportResult
 
 fn lei_result_to_dict(py: Python<'_>, r: &idsmith::lei::LeiResult) -> PyObject {
     let dict = PyDict::new(py);
-    dict.set_item("code", &r.code).unwrap();
+    dict.set_item("code", r.code.as_str()).unwrap();
     dict.set_item("lou", &r.lou).unwrap();
     dict.set_item("country_code", &r.country_code).unwrap();
     dict.set_item("valid", r.valid).unwrap();
@@ -97,7 +112,7 @@ fn lei_result_to_dict(py: Python<'_>, r: &idsmith::lei::LeiResult) -> PyObject {
 
 fn swift_result_to_dict(py: Python<'_>, r: &idsmith::swift::SwiftResult) -> PyObject {
     let dict = PyDict::new(py);
-    dict.set_item("code", &r.code).unwrap();
+    dict.set_item("code", r.code.as_str()).unwrap();
     dict.set_item("bank", &r.bank).unwrap();
     dict.set_item("country", &r.country).unwrap();
     dict.set_item("location", &r.location).unwrap();
@@ -108,13 +123,79 @@ fn swift_result_to_dict(py: Python<'_>, r: &idsmith::swift::SwiftResult) -> PyOb
 
 fn vat_result_to_dict(py: Python<'_>, r: &idsmith::vat::VatResult) -> PyObject {
     let dict = PyDict::new(py);
-    dict.set_item("code", &r.code).unwrap();
+    dict.set_item("code", r.code.as_str()).unwrap();
     dict.set_item("country_code", &r.country_code).unwrap();
+    dict.set_item("effective_country_code", &r.effective_country_code)
+        .unwrap();
     dict.set_item("country_name", &r.country_name).unwrap();
     dict.set_item("valid", r.valid).unwrap();
     dict.into()
 }
 
+fn iban_result_to_dict(py: Python<'_>, r: &idsmith::iban::IbanResult) -> PyObject {
+    let dict = PyDict::new(py);
+    dict.set_item("country", &r.country).unwrap();
+    dict.set_item("iban", r.iban.as_str()).unwrap();
+    dict.set_item("formatted", &r.formatted).unwrap();
+    dict.set_item("valid", r.valid).unwrap();
+    dict.into()
+}
+
+/// A generator seeded with `seed` for reproducible output, otherwise from
+/// the OS.
+fn seeded_rng(seed: Option<u64>) -> StdRng {
+    match seed {
+        Some(seed) => StdRng::seed_from_u64(seed),
+        None => StdRng::from_entropy(),
+    }
+}
+
+/// Call `generate` once, or `count` times into a list, with one generator.
+fn generate_many(
+    py: Python<'_>,
+    count: Option<usize>,
+    seed: Option<u64>,
+    mut generate: impl FnMut(&mut StdRng) -> PyResult<PyObject>,
+) -> PyResult<PyObject> {
+    let mut rng = seeded_rng(seed);
+    match count {
+        None => generate(&mut rng),
+        Some(count) => {
+            let items = (0..count)
+                .map(|_| generate(&mut rng))
+                .collect::<PyResult<Vec<_>>>()?;
+            Ok(items.into_pyobject(py)?.into())
+        }
+    }
+}
+
+/// The country as given, the country whose rules apply and the given
+/// country's name, as in generated results.
+fn resolve_country(country: &str, effective: Option<String>) -> PyResult<(String, String, String)> {
+    let effective = effective.ok_or_else(|| {
+        pyo3::exceptions::PyValueError::new_err(format!("Unsupported country: {}", country))
+    })?;
+    let name = idsmith::countries::get_country_name(country)
+        .unwrap_or("Unknown")
+        .to_string();
+    Ok((country.to_uppercase(), effective, name))
+}
+
+/// Document name of `country` in a registry's `list_countries`.
+fn scheme_name<S: AsRef<str>>(countries: &[(S, S, S)], country: &str) -> String {
+    countries
+        .iter()
+        .find(|(code, _, _)| code.as_ref() == country)
+        .map(|(_, _, name)| name.as_ref().to_string())
+        .unwrap_or_default()
+}
+
+fn parse_locale(locale: Option<&str>) -> PyResult<Option<idsmith::locale::Locale>> {
+    locale
+        .map(|l| l.parse().map_err(pyo3::exceptions::PyValueError::new_err))
+        .transpose()
+}
+
 // ── BankAccount ──
 
 #[pyclass]
@@ -123,16 +204,27 @@ struct BankAccount;
 #[pymethods]
 impl BankAccount {
     #[staticmethod]
-    #[pyo3(signature = (country, bank_code=None))]
-    fn generate(py: Python<'_>, country: &str, bank_code: Option<String>) -> PyResult<PyObject> {
-        let mut rng = thread_rng();
-        let opts = idsmith::bank_account::GenOptions { bank_code };
-        idsmith::bank_accounts()
-            .generate(country, &opts, &mut rng)
-            .map(|r| account_result_to_dict(py, &r))
-            .ok_or_else(|| {
-                pyo3::exceptions::PyValueError::new_err(format!("Unsupported country: {}", country))
-            })
+    #[pyo3(signature = (country, bank_code=None, strict=false, count=None, seed=None))]
+    fn generate(
+        py: Python<'_>,
+        country: &str,
+        bank_code: Option<String>,
+        strict: bool,
+        count: Option<usize>,
+        seed: Option<u64>,
+    ) -> PyResult<PyObject> {
+        let opts = idsmith::bank_account::GenOptions { bank_code, strict };
+        generate_many(py, count, seed, |rng| {
+            idsmith::bank_accounts()
+                .generate(country, &opts, rng)
+                .map(|r| account_result_to_dict(py, &r))
+                .ok_or_else(|| {
+                    pyo3::exceptions::PyValueError::new_err(format!(
+                        "Unsupported country: {}",
+                        country
+                    ))
+                })
+        })
     }
 
     #[staticmethod]
@@ -144,6 +236,41 @@ impl BankAccount {
             })
     }
 
+    /// Account numbers are not split into bank and branch codes, so those
+    /// fields are `None`.
+    #[staticmethod]
+    fn parse(py: Python<'_>, country: &str, raw: &str) -> PyResult<PyObject> {
+        let registry = idsmith::bank_accounts();
+        let (country_code, effective_country_code, country_name) =
+            resolve_country(country, registry.effective_country(country))?;
+        let format_name = registry
+            .list_countries()
+            .iter()
+            .find(|(code, ..)| *code == effective_country_code)
+            .map(|(_, _, format_name, _)| format_name.to_string())
+            .unwrap_or_default();
+        let result = idsmith::bank_account::AccountResult {
+            formatted: registry.format(country, raw).unwrap_or_default(),
+            valid: registry.validate(country, raw).unwrap_or(false),
+            country_code,
+            effective_country_code,
+            country_name,
+            format_name,
+            bank_code: None,
+            branch_code: None,
+            account_number: raw.to_string(),
+            check_digits: None,
+            raw: raw.to_string(),
+            iban: None,
+        };
+        Ok(account_result_to_dict(py, &result))
+    }
+
+    #[staticmethod]
+    fn describe(py: Python<'_>, country: &str) -> PyResult<PyObject> {
+        describe_dict(py, IdKind::BankAccount, Some(country))
+    }
+
     #[staticmethod]
     fn format(country: &str, raw: &str) -> PyResult<String> {
         idsmith::bank_accounts()
@@ -154,9 +281,14 @@ impl BankAccount {
     }
 
     #[staticmethod]
-    fn list_countries(py: Python<'_>) -> PyResult<PyObject> {
-        let countries: Vec<PyObject> = idsmith::bank_accounts()
-            .list_countries()
+    #[pyo3(signature = (locale=None))]
+    fn list_countries(py: Python<'_>, locale: Option<&str>) -> PyResult<PyObject> {
+        let registry = idsmith::bank_accounts();
+        let countries = match parse_locale(locale)? {
+            Some(locale) => registry.list_countries_in(locale),
+            None => registry.list_countries(),
+        };
+        let countries: Vec<PyObject> = countries
             .iter()
             .map(|(code, name, format_name, has_iban)| {
                 let dict = PyDict::new(py);
@@ -184,16 +316,46 @@ struct PersonalId;
 #[pymethods]
 impl PersonalId {
     #[staticmethod]
-    #[pyo3(signature = (country, gender=None, year=None))]
-    fn generate(country: &str, gender: Option<&str>, year: Option<u16>) -> PyResult<String> {
-        let mut rng = thread_rng();
+    #[pyo3(signature = (
+        country,
+        gender=None,
+        year=None,
+        region=None,
+        strict=false,
+        count=None,
+        seed=None,
+    ))]
+    #[allow(clippy::too_many_arguments)]
+    fn generate(
+        py: Python<'_>,
+        country: &str,
+        gender: Option<&str>,
+        year: Option<u16>,
+        region: Option<String>,
+        strict: bool,
+        count: Option<usize>,
+        seed: Option<u64>,
+    ) -> PyResult<PyObject> {
         let g = idsmith::personal_id::date::Gender::from_str_opt(gender);
-        let opts = idsmith::personal_id::GenOptions { gender: g, year };
-        idsmith::personal_ids()
-            .generate(country, &opts, &mut rng)
-            .ok_or_else(|| {
-                pyo3::exceptions::PyValueError::new_err(format!("Unsupported country: {}", country))
-            })
+        let opts = idsmith::personal_id::GenOptions {
+            gender: g,
+            year,
+            region,
+            strict,
+        };
+        let registry = idsmith::personal_ids();
+        generate_many(py, count, seed, |rng| {
+            registry
+                .generate(country, &opts, rng)
+                .and_then(|code| registry.parse(country, &code))
+                .map(|r| id_result_to_dict(py, &r))
+                .ok_or_else(|| {
+                    pyo3::exceptions::PyValueError::new_err(format!(
+                        "Unsupported country or region: {}",
+                        country
+                    ))
+                })
+        })
     }
 
     #[staticmethod]
@@ -216,9 +378,19 @@ impl PersonalId {
     }
 
     #[staticmethod]
-    fn list_countries(py: Python<'_>) -> PyResult<PyObject> {
-        let countries: Vec<PyObject> = idsmith::personal_ids()
-            .list_countries()
+    fn describe(py: Python<'_>, country: &str) -> PyResult<PyObject> {
+        describe_dict(py, IdKind::PersonalId, Some(country))
+    }
+
+    #[staticmethod]
+    #[pyo3(signature = (locale=None))]
+    fn list_countries(py: Python<'_>, locale: Option<&str>) -> PyResult<PyObject> {
+        let registry = idsmith::personal_ids();
+        let countries = match parse_locale(locale)? {
+            Some(locale) => registry.list_countries_in(locale),
+            None => registry.list_countries(),
+        };
+        let countries: Vec<PyObject> = countries
             .iter()
             .map(|(code, country_name, id_name)| {
                 let dict = PyDict::new(py);
@@ -245,16 +417,25 @@ struct CreditCard;
 #[pymethods]
 impl CreditCard {
     #[staticmethod]
-    #[pyo3(signature = (brand=None))]
-    fn generate(py: Python<'_>, brand: Option<String>) -> PyResult<PyObject> {
-        let mut rng = thread_rng();
-        let opts = idsmith::credit_card::GenOptions { brand, current_year: None };
-        idsmith::credit_cards()
-            .generate(&opts, &mut rng)
-            .map(|r| card_result_to_dict(py, &r))
-            .ok_or_else(|| {
-                pyo3::exceptions::PyValueError::new_err("Failed to generate credit card")
-            })
+    #[pyo3(signature = (brand=None, count=None, seed=None))]
+    fn generate(
+        py: Python<'_>,
+        brand: Option<String>,
+        count: Option<usize>,
+        seed: Option<u64>,
+    ) -> PyResult<PyObject> {
+        let opts = idsmith::credit_card::GenOptions {
+            brand,
+            current_year: None,
+        };
+        generate_many(py, count, seed, |rng| {
+            idsmith::credit_cards()
+                .generate(&opts, rng)
+                .map(|r| card_result_to_dict(py, &r))
+                .ok_or_else(|| {
+                    pyo3::exceptions::PyValueError::new_err("Failed to generate credit card")
+                })
+        })
     }
 
     #[staticmethod]
@@ -262,6 +443,25 @@ impl CreditCard {
         idsmith::credit_cards().validate(number)
     }
 
+    /// The number carries no CVV or expiry, so the result has neither.
+    #[staticmethod]
+    fn parse(py: Python<'_>, number: &str) -> PyResult<PyObject> {
+        let number: idsmith::CardNumber = number
+            .parse()
+            .map_err(pyo3::exceptions::PyValueError::new_err)?;
+        let dict = PyDict::new(py);
+        dict.set_item("brand", idsmith::credit_cards().brand(number.as_str()))?;
+        dict.set_item("number", number.as_str())?;
+        dict.set_item("formatted", format!("{:#}", number))?;
+        dict.set_item("valid", true)?;
+        Ok(dict.into())
+    }
+
+    #[staticmethod]
+    fn describe(py: Python<'_>) -> PyResult<PyObject> {
+        describe_dict(py, IdKind::CreditCard, None)
+    }
+
     #[staticmethod]
     fn format(brand: &str, number: &str) -> String {
         idsmith::credit_cards().format(brand, number)
@@ -281,14 +481,23 @@ struct CompanyId;
 #[pymethods]
 impl CompanyId {
     #[staticmethod]
-    #[pyo3(signature = (country=None))]
-    fn generate(py: Python<'_>, country: Option<String>) -> PyResult<PyObject> {
-        let mut rng = thread_rng();
-        let opts = idsmith::company_id::GenOptions { country };
-        idsmith::company_ids()
-            .generate(&opts, &mut rng)
-            .map(|r| company_result_to_dict(py, &r))
-            .ok_or_else(|| pyo3::exceptions::PyValueError::new_err("Failed to generate company ID"))
+    #[pyo3(signature = (country=None, strict=false, count=None, seed=None))]
+    fn generate(
+        py: Python<'_>,
+        country: Option<String>,
+        strict: bool,
+        count: Option<usize>,
+        seed: Option<u64>,
+    ) -> PyResult<PyObject> {
+        let opts = idsmith::company_id::GenOptions { country, strict };
+        generate_many(py, count, seed, |rng| {
+            idsmith::company_ids()
+                .generate(&opts, rng)
+                .map(|r| company_result_to_dict(py, &r))
+                .ok_or_else(|| {
+                    pyo3::exceptions::PyValueError::new_err("Failed to generate company ID")
+                })
+        })
     }
 
     #[staticmethod]
@@ -297,9 +506,35 @@ impl CompanyId {
     }
 
     #[staticmethod]
-    fn list_countries(py: Python<'_>) -> PyResult<PyObject> {
-        let countries: Vec<PyObject> = idsmith::company_ids()
-            .list_countries()
+    fn parse(py: Python<'_>, country: &str, code: &str) -> PyResult<PyObject> {
+        let registry = idsmith::company_ids();
+        let (country_code, effective_country_code, country_name) =
+            resolve_country(country, registry.effective_country(country))?;
+        let result = idsmith::company_id::CompanyResult {
+            name: scheme_name(&registry.list_countries(), &effective_country_code),
+            code: code.to_string(),
+            valid: registry.validate(country, code),
+            country_code,
+            effective_country_code,
+            country_name,
+        };
+        Ok(company_result_to_dict(py, &result))
+    }
+
+    #[staticmethod]
+    fn describe(py: Python<'_>, country: &str) -> PyResult<PyObject> {
+        describe_dict(py, IdKind::CompanyId, Some(country))
+    }
+
+    #[staticmethod]
+    #[pyo3(signature = (locale=None))]
+    fn list_countries(py: Python<'_>, locale: Option<&str>) -> PyResult<PyObject> {
+        let registry = idsmith::company_ids();
+        let countries = match parse_locale(locale)? {
+            Some(locale) => registry.list_countries_in(locale),
+            None => registry.list_countries(),
+        };
+        let countries: Vec<PyObject> = countries
             .iter()
             .map(|(code, country_name, id_name)| {
                 let dict = PyDict::new(py);
@@ -321,18 +556,46 @@ struct Swift;
 #[pymethods]
 impl Swift {
     #[staticmethod]
-    #[pyo3(signature = (country=None))]
-    fn generate(py: Python<'_>, country: Option<String>) -> PyObject {
-        let mut rng = thread_rng();
+    #[pyo3(signature = (country=None, count=None, seed=None))]
+    fn generate(
+        py: Python<'_>,
+        country: Option<String>,
+        count: Option<usize>,
+        seed: Option<u64>,
+    ) -> PyResult<PyObject> {
         let opts = idsmith::swift::GenOptions { country };
-        let r = idsmith::swift_codes().generate(&opts, &mut rng);
-        swift_result_to_dict(py, &r)
+        generate_many(py, count, seed, |rng| {
+            let r = idsmith::swift_codes().generate(&opts, rng);
+            Ok(swift_result_to_dict(py, &r))
+        })
     }
 
     #[staticmethod]
     fn validate(code: &str) -> bool {
         idsmith::swift_codes().validate(code)
     }
+
+    #[staticmethod]
+    fn parse(py: Python<'_>, code: &str) -> PyResult<PyObject> {
+        let bic: idsmith::Bic = code
+            .parse()
+            .map_err(pyo3::exceptions::PyValueError::new_err)?;
+        let result = idsmith::swift::SwiftResult {
+            bank: bic.bank_code().to_string(),
+            country: bic.country_code().to_string(),
+            location: bic.location_code().to_string(),
+            branch: bic.branch_code().map(str::to_string),
+            code: bic,
+            valid: true,
+        };
+        Ok(swift_result_to_dict(py, &result))
+    }
+
+    #[staticmethod]
+    #[pyo3(signature = (country=None))]
+    fn describe(py: Python<'_>, country: Option<&str>) -> PyResult<PyObject> {
+        describe_dict(py, IdKind::Swift, country)
+    }
 }
 
 // ── DriverLicense ──
@@ -343,20 +606,28 @@ struct DriverLicense;
 #[pymethods]
 impl DriverLicense {
     #[staticmethod]
-    #[pyo3(signature = (country=None, state=None))]
+    #[pyo3(signature = (country=None, state=None, strict=false, count=None, seed=None))]
     fn generate(
         py: Python<'_>,
         country: Option<String>,
         state: Option<String>,
+        strict: bool,
+        count: Option<usize>,
+        seed: Option<u64>,
     ) -> PyResult<PyObject> {
-        let mut rng = thread_rng();
-        let opts = idsmith::driver_license::GenOptions { country, state };
-        idsmith::driver_licenses()
-            .generate(&opts, &mut rng)
-            .map(|r| license_result_to_dict(py, &r))
-            .ok_or_else(|| {
-                pyo3::exceptions::PyValueError::new_err("Failed to generate driver's license")
-            })
+        let opts = idsmith::driver_license::GenOptions {
+            country,
+            state,
+            strict,
+        };
+        generate_many(py, count, seed, |rng| {
+            idsmith::driver_licenses()
+                .generate(&opts, rng)
+                .map(|r| license_result_to_dict(py, &r))
+                .ok_or_else(|| {
+                    pyo3::exceptions::PyValueError::new_err("Failed to generate driver's license")
+                })
+        })
     }
 
     #[staticmethod]
@@ -364,10 +635,38 @@ impl DriverLicense {
         idsmith::driver_licenses().validate(country, code)
     }
 
+    /// The issuing state is not read from the code, so `state` is `None`.
     #[staticmethod]
-    fn list_countries(py: Python<'_>) -> PyResult<PyObject> {
-        let countries: Vec<PyObject> = idsmith::driver_licenses()
-            .list_countries()
+    fn parse(py: Python<'_>, country: &str, code: &str) -> PyResult<PyObject> {
+        let registry = idsmith::driver_licenses();
+        let (country_code, effective_country_code, country_name) =
+            resolve_country(country, registry.effective_country(country))?;
+        let result = idsmith::driver_license::DriverLicenseResult {
+            name: scheme_name(&registry.list_countries(), &effective_country_code),
+            code: code.to_string(),
+            state: None,
+            valid: registry.validate(country, code),
+            country_code,
+            effective_country_code,
+            country_name,
+        };
+        Ok(license_result_to_dict(py, &result))
+    }
+
+    #[staticmethod]
+    fn describe(py: Python<'_>, country: &str) -> PyResult<PyObject> {
+        describe_dict(py, IdKind::DriverLicense, Some(country))
+    }
+
+    #[staticmethod]
+    #[pyo3(signature = (locale=None))]
+    fn list_countries(py: Python<'_>, locale: Option<&str>) -> PyResult<PyObject> {
+        let registry = idsmith::driver_licenses();
+        let countries = match parse_locale(locale)? {
+            Some(locale) => registry.list_countries_in(locale),
+            None => registry.list_countries(),
+        };
+        let countries: Vec<PyObject> = countries
             .iter()
             .map(|(code, country_name, id_name)| {
                 let dict = PyDict::new(py);
@@ -389,21 +688,26 @@ struct TaxId;
 #[pymethods]
 impl TaxId {
     #[staticmethod]
-    #[pyo3(signature = (country=None, holder_type=None))]
+    #[pyo3(signature = (country=None, holder_type=None, strict=false, count=None, seed=None))]
     fn generate(
         py: Python<'_>,
         country: Option<String>,
         holder_type: Option<String>,
+        strict: bool,
+        count: Option<usize>,
+        seed: Option<u64>,
     ) -> PyResult<PyObject> {
-        let mut rng = thread_rng();
         let opts = idsmith::tax_id::GenOptions {
             country,
             holder_type,
+            strict,
         };
-        idsmith::tax_ids()
-            .generate(&opts, &mut rng)
-            .map(|r| tax_result_to_dict(py, &r))
-            .ok_or_else(|| pyo3::exceptions::PyValueError::new_err("Failed to generate tax ID"))
+        generate_many(py, count, seed, |rng| {
+            idsmith::tax_ids()
+                .generate(&opts, rng)
+                .map(|r| tax_result_to_dict(py, &r))
+                .ok_or_else(|| pyo3::exceptions::PyValueError::new_err("Failed to generate tax ID"))
+        })
     }
 
     #[staticmethod]
@@ -411,10 +715,39 @@ impl TaxId {
         idsmith::tax_ids().validate(country, code)
     }
 
+    /// The holder type is not read from the code, so `holder_type` is
+    /// `None`.
     #[staticmethod]
-    fn list_countries(py: Python<'_>) -> PyResult<PyObject> {
-        let countries: Vec<PyObject> = idsmith::tax_ids()
-            .list_countries()
+    fn parse(py: Python<'_>, country: &str, code: &str) -> PyResult<PyObject> {
+        let registry = idsmith::tax_ids();
+        let (country_code, effective_country_code, country_name) =
+            resolve_country(country, registry.effective_country(country))?;
+        let result = idsmith::tax_id::TaxIdResult {
+            name: scheme_name(&registry.list_countries(), &effective_country_code),
+            code: code.to_string(),
+            holder_type: None,
+            valid: registry.validate(country, code),
+            country_code,
+            effective_country_code,
+            country_name,
+        };
+        Ok(tax_result_to_dict(py, &result))
+    }
+
+    #[staticmethod]
+    fn describe(py: Python<'_>, country: &str) -> PyResult<PyObject> {
+        describe_dict(py, IdKind::TaxId, Some(country))
+    }
+
+    #[staticmethod]
+    #[pyo3(signature = (locale=None))]
+    fn list_countries(py: Python<'_>, locale: Option<&str>) -> PyResult<PyObject> {
+        let registry = idsmith::tax_ids();
+        let countries = match parse_locale(locale)? {
+            Some(locale) => registry.list_countries_in(locale),
+            None => registry.list_countries(),
+        };
+        let countries: Vec<PyObject> = countries
             .iter()
             .map(|(code, country_name, id_name)| {
                 let dict = PyDict::new(py);
@@ -436,14 +769,23 @@ struct Passport;
 #[pymethods]
 impl Passport {
     #[staticmethod]
-    #[pyo3(signature = (country=None))]
-    fn generate(py: Python<'_>, country: Option<String>) -> PyResult<PyObject> {
-        let mut rng = thread_rng();
-        let opts = idsmith::passport::GenOptions { country };
-        idsmith::passports()
-            .generate(&opts, &mut rng)
-            .map(|r| passport_result_to_dict(py, &r))
-            .ok_or_else(|| pyo3::exceptions::PyValueError::new_err("Failed to generate passport"))
+    #[pyo3(signature = (country=None, strict=false, count=None, seed=None))]
+    fn generate(
+        py: Python<'_>,
+        country: Option<String>,
+        strict: bool,
+        count: Option<usize>,
+        seed: Option<u64>,
+    ) -> PyResult<PyObject> {
+        let opts = idsmith::passport::GenOptions { country, strict };
+        generate_many(py, count, seed, |rng| {
+            idsmith::passports()
+                .generate(&opts, rng)
+                .map(|r| passport_result_to_dict(py, &r))
+                .ok_or_else(|| {
+                    pyo3::exceptions::PyValueError::new_err("Failed to generate passport")
+                })
+        })
     }
 
     #[staticmethod]
@@ -452,9 +794,35 @@ impl Passport {
     }
 
     #[staticmethod]
-    fn list_countries(py: Python<'_>) -> PyResult<PyObject> {
-        let countries: Vec<PyObject> = idsmith::passports()
-            .list_countries()
+    fn parse(py: Python<'_>, country: &str, code: &str) -> PyResult<PyObject> {
+        let registry = idsmith::passports();
+        let (country_code, effective_country_code, country_name) =
+            resolve_country(country, registry.effective_country(country))?;
+        let result = idsmith::passport::PassportResult {
+            name: scheme_name(&registry.list_countries(), &effective_country_code),
+            code: code.to_string(),
+            valid: registry.validate(country, code),
+            country_code,
+            effective_country_code,
+            country_name,
+        };
+        Ok(passport_result_to_dict(py, &result))
+    }
+
+    #[staticmethod]
+    fn describe(py: Python<'_>, country: &str) -> PyResult<PyObject> {
+        describe_dict(py, IdKind::Passport, Some(country))
+    }
+
+    #[staticmethod]
+    #[pyo3(signature = (locale=None))]
+    fn list_countries(py: Python<'_>, locale: Option<&str>) -> PyResult<PyObject> {
+        let registry = idsmith::passports();
+        let countries = match parse_locale(locale)? {
+            Some(locale) => registry.list_countries_in(locale),
+            None => registry.list_countries(),
+        };
+        let countries: Vec<PyObject> = countries
             .iter()
             .map(|(code, country_name, id_name)| {
                 let dict = PyDict::new(py);
@@ -476,18 +844,44 @@ struct LegalEntityId;
 #[pymethods]
 impl LegalEntityId {
     #[staticmethod]
-    #[pyo3(signature = (country=None))]
-    fn generate(py: Python<'_>, country: Option<String>) -> PyObject {
-        let mut rng = thread_rng();
+    #[pyo3(signature = (country=None, count=None, seed=None))]
+    fn generate(
+        py: Python<'_>,
+        country: Option<String>,
+        count: Option<usize>,
+        seed: Option<u64>,
+    ) -> PyResult<PyObject> {
         let opts = idsmith::lei::GenOptions { country };
-        let r = idsmith::lei_codes().generate(&opts, &mut rng);
-        lei_result_to_dict(py, &r)
+        generate_many(py, count, seed, |rng| {
+            let r = idsmith::lei_codes().generate(&opts, rng);
+            Ok(lei_result_to_dict(py, &r))
+        })
     }
 
     #[staticmethod]
     fn validate(code: &str) -> bool {
         idsmith::lei_codes().validate(code)
     }
+
+    #[staticmethod]
+    fn parse(py: Python<'_>, code: &str) -> PyResult<PyObject> {
+        let lei: idsmith::Lei = code
+            .parse()
+            .map_err(pyo3::exceptions::PyValueError::new_err)?;
+        let result = idsmith::lei::LeiResult {
+            lou: lei.lou().to_string(),
+            country_code: lei.as_str()[4..6].to_string(),
+            code: lei,
+            valid: true,
+        };
+        Ok(lei_result_to_dict(py, &result))
+    }
+
+    #[staticmethod]
+    #[pyo3(signature = (country=None))]
+    fn describe(py: Python<'_>, country: Option<&str>) -> PyResult<PyObject> {
+        describe_dict(py, IdKind::Lei, country)
+    }
 }
 
 // ── VatId ──
@@ -498,14 +892,23 @@ struct VatId;
 #[pymethods]
 impl VatId {
     #[staticmethod]
-    #[pyo3(signature = (country=None))]
-    fn generate(py: Python<'_>, country: Option<String>) -> PyResult<PyObject> {
-        let mut rng = thread_rng();
-        let opts = idsmith::vat::GenOptions { country };
-        idsmith::vat_ids()
-            .generate(&opts, &mut rng)
-            .map(|r| vat_result_to_dict(py, &r))
-            .ok_or_else(|| pyo3::exceptions::PyValueError::new_err("Failed to generate VAT number"))
+    #[pyo3(signature = (country=None, strict=false, count=None, seed=None))]
+    fn generate(
+        py: Python<'_>,
+        country: Option<String>,
+        strict: bool,
+        count: Option<usize>,
+        seed: Option<u64>,
+    ) -> PyResult<PyObject> {
+        let opts = idsmith::vat::GenOptions { country, strict };
+        generate_many(py, count, seed, |rng| {
+            idsmith::vat_ids()
+                .generate(&opts, rng)
+                .map(|r| vat_result_to_dict(py, &r))
+                .ok_or_else(|| {
+                    pyo3::exceptions::PyValueError::new_err("Failed to generate VAT number")
+                })
+        })
     }
 
     #[staticmethod]
@@ -514,9 +917,47 @@ impl VatId {
     }
 
     #[staticmethod]
-    fn list_countries(py: Python<'_>) -> PyResult<PyObject> {
-        let countries: Vec<PyObject> = idsmith::vat_ids()
+    fn parse(py: Python<'_>, code: &str) -> PyResult<PyObject> {
+        let registry = idsmith::vat_ids();
+        let vat: idsmith::VatNumber = code
+            .parse()
+            .map_err(pyo3::exceptions::PyValueError::new_err)?;
+        let prefix = vat.country_prefix().to_string();
+        let country_name = registry
             .list_countries()
+            .iter()
+            .find(|(c, _)| *c == prefix)
+            .map(|(_, name)| *name)
+            .or_else(|| idsmith::countries::get_country_name(&prefix))
+            .unwrap_or("Unknown")
+            .to_string();
+        let result = idsmith::vat::VatResult {
+            effective_country_code: registry
+                .effective_country(&prefix)
+                .unwrap_or_else(|| prefix.clone()),
+            country_code: prefix,
+            country_name,
+            code: vat,
+            valid: true,
+        };
+        Ok(vat_result_to_dict(py, &result))
+    }
+
+    #[staticmethod]
+    #[pyo3(signature = (country=None))]
+    fn describe(py: Python<'_>, country: Option<&str>) -> PyResult<PyObject> {
+        describe_dict(py, IdKind::Vat, country)
+    }
+
+    #[staticmethod]
+    #[pyo3(signature = (locale=None))]
+    fn list_countries(py: Python<'_>, locale: Option<&str>) -> PyResult<PyObject> {
+        let registry = idsmith::vat_ids();
+        let countries = match parse_locale(locale)? {
+            Some(locale) => registry.list_countries_in(locale),
+            None => registry.list_countries().to_vec(),
+        };
+        let countries: Vec<PyObject> = countries
             .iter()
             .map(|(code, country_name)| {
                 let dict = PyDict::new(py);
@@ -532,10 +973,18 @@ impl VatId {
 // ── IBAN functions ──
 
 #[pyfunction]
-#[pyo3(signature = (country=None))]
-fn generate_iban(country: Option<&str>) -> PyResult<String> {
-    let mut rng = thread_rng();
-    idsmith::iban::generate_iban(country, &mut rng).map_err(pyo3::exceptions::PyValueError::new_err)
+#[pyo3(signature = (country=None, count=None, seed=None))]
+fn generate_iban(
+    py: Python<'_>,
+    country: Option<&str>,
+    count: Option<usize>,
+    seed: Option<u64>,
+) -> PyResult<PyObject> {
+    generate_many(py, count, seed, |rng| {
+        let iban = idsmith::iban::generate_iban(country, rng)
+            .map_err(pyo3::exceptions::PyValueError::new_err)?;
+        Ok(iban.into_pyobject(py)?.into_any().unbind())
+    })
 }
 
 #[pyfunction]
@@ -543,6 +992,20 @@ fn validate_iban(iban: &str) -> bool {
     idsmith::iban::validate_iban(iban)
 }
 
+#[pyfunction]
+fn parse_iban(py: Python<'_>, iban: &str) -> PyResult<PyObject> {
+    let iban: idsmith::Iban = iban
+        .parse()
+        .map_err(pyo3::exceptions::PyValueError::new_err)?;
+    let result = idsmith::iban::IbanResult {
+        country: iban.country_code().to_string(),
+        formatted: format!("{:#}", iban),
+        iban,
+        valid: true,
+    };
+    Ok(iban_result_to_dict(py, &result))
+}
+
 #[pyfunction]
 fn format_iban(iban: &str) -> String {
     idsmith::iban::format_iban(iban)
@@ -553,6 +1016,162 @@ fn iban_countries() -> Vec<&'static str> {
     idsmith::iban::supported_countries()
 }
 
+// ── Scheme description ──
+
+fn describe_dict(py: Python<'_>, kind: IdKind, country: Option<&str>) -> PyResult<PyObject> {
+    let info = idsmith::describe(kind, country).ok_or_else(|| {
+        pyo3::exceptions::PyValueError::new_err(match country {
+            Some(c) => format!("Unsupported country for {}: {}", kind, c),
+            None => format!("A country is required for {}", kind),
+        })
+    })?;
+    let separators: Vec<String> = info.separators.iter().map(|c| c.to_string()).collect();
+    let dict = PyDict::new(py);
+    dict.set_item("kind", info.kind.as_str())?;
+    dict.set_item("country", &info.country)?;
+    dict.set_item("name", &info.name)?;
+    dict.set_item("min_length", info.min_length)?;
+    dict.set_item("max_length", info.max_length)?;
+    dict.set_item("charset", info.charset.to_string())?;
+    dict.set_item("separators", separators)?;
+    dict.set_item("has_checksum", info.has_checksum)?;
+    dict.set_item("checksum", &info.checksum)?;
+    dict.set_item("encodes_dob", info.encodes_dob)?;
+    dict.set_item("encodes_gender", info.encodes_gender)?;
+    dict.set_item("example", &info.example)?;
+    dict.set_item("regex", &info.regex)?;
+    Ok(dict.into())
+}
+
+#[pyfunction]
+#[pyo3(signature = (kind, country=None))]
+fn describe(py: Python<'_>, kind: &str, country: Option<&str>) -> PyResult<PyObject> {
+    let kind: IdKind = kind
+        .parse()
+        .map_err(pyo3::exceptions::PyValueError::new_err)?;
+    describe_dict(py, kind, country)
+}
+
+// ── Columnar output ──
+
+/// Generate `count` rows into an Arrow IPC file.
+fn arrow_ipc<T: idsmith::output::Record>(
+    count: usize,
+    seed: Option<u64>,
+    mut generate: impl FnMut(&mut StdRng) -> Option<T>,
+) -> Result<Vec<u8>, String> {
+    use idsmith::arrow::{FileFormat, Writer};
+    let mut rng = seeded_rng(seed);
+    let mut writer = Writer::for_rows::<T>(Vec::new(), FileFormat::Ipc)?;
+    for _ in 0..count {
+        let row = generate(&mut rng).ok_or("Failed to generate row")?;
+        writer.write(&row)?;
+    }
+    writer.finish()
+}
+
+#[pyfunction]
+#[pyo3(signature = (kind, count, country=None, seed=None))]
+fn _generate_arrow(
+    py: Python<'_>,
+    kind: &str,
+    count: usize,
+    country: Option<String>,
+    seed: Option<u64>,
+) -> PyResult<PyObject> {
+    let kind: IdKind = kind
+        .parse()
+        .map_err(pyo3::exceptions::PyValueError::new_err)?;
+    let country = country.map(|c| c.to_uppercase());
+    if kind.requires_country() && country.is_none() {
+        return Err(pyo3::exceptions::PyValueError::new_err(format!(
+            "A country is required for {}",
+            kind
+        )));
+    }
+    let bytes = py.allow_threads(|| match kind {
+        IdKind::Iban => arrow_ipc(count, seed, |rng| {
+            let code = idsmith::iban::generate_iban(country.as_deref(), rng).ok()?;
+            Some(idsmith::iban::IbanResult {
+                country: code[..2].to_string(),
+                formatted: idsmith::iban::format_iban(&code),
+                valid: true,
+                iban: code.parse().ok()?,
+            })
+        }),
+        IdKind::BankAccount => {
+            let cc = country.unwrap_or_default();
+            arrow_ipc(count, seed, |rng| {
+                idsmith::bank_accounts().generate(&cc, &Default::default(), rng)
+            })
+        }
+        IdKind::PersonalId => {
+            let cc = country.unwrap_or_default();
+            arrow_ipc(count, seed, |rng| {
+                let registry = idsmith::personal_ids();
+                let code = registry.generate(&cc, &Default::default(), rng)?;
+                registry.parse(&cc, &code)
+            })
+        }
+        IdKind::CreditCard => arrow_ipc(count, seed, |rng| {
+            idsmith::credit_cards().generate(&Default::default(), rng)
+        }),
+        IdKind::Swift => {
+            let opts = idsmith::swift::GenOptions { country };
+            arrow_ipc(count, seed, |rng| {
+                Some(idsmith::swift_codes().generate(&opts, rng))
+            })
+        }
+        IdKind::CompanyId => {
+            let opts = idsmith::company_id::GenOptions {
+                country,
+                ..Default::default()
+            };
+            arrow_ipc(count, seed, |rng| {
+                idsmith::company_ids().generate(&opts, rng)
+            })
+        }
+        IdKind::DriverLicense => {
+            let opts = idsmith::driver_license::GenOptions {
+                country,
+                ..Default::default()
+            };
+            arrow_ipc(count, seed, |rng| {
+                idsmith::driver_licenses().generate(&opts, rng)
+            })
+        }
+        IdKind::TaxId => {
+            let opts = idsmith::tax_id::GenOptions {
+                country,
+                ..Default::default()
+            };
+            arrow_ipc(count, seed, |rng| idsmith::tax_ids().generate(&opts, rng))
+        }
+        IdKind::Passport => {
+            let opts = idsmith::passport::GenOptions {
+                country,
+                ..Default::default()
+            };
+            arrow_ipc(count, seed, |rng| idsmith::passports().generate(&opts, rng))
+        }
+        IdKind::Lei => {
+            let opts = idsmith::lei::GenOptions { country };
+            arrow_ipc(count, seed, |rng| {
+                Some(idsmith::lei_codes().generate(&opts, rng))
+            })
+        }
+        IdKind::Vat => {
+            let opts = idsmith::vat::GenOptions {
+                country,
+                ..Default::default()
+            };
+            arrow_ipc(count, seed, |rng| idsmith::vat_ids().generate(&opts, rng))
+        }
+    });
+    let bytes = bytes.map_err(pyo3::exceptions::PyValueError::new_err)?;
+    Ok(pyo3::types::PyBytes::new(py, &bytes).into())
+}
+
 // ── Module ──
 
 #[pymodule]
@@ -569,7 +1188,10 @@ fn _idsmith(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<VatId>()?;
     m.add_function(wrap_pyfunction!(generate_iban, m)?)?;
     m.add_function(wrap_pyfunction!(validate_iban, m)?)?;
+    m.add_function(wrap_pyfunction!(parse_iban, m)?)?;
     m.add_function(wrap_pyfunction!(format_iban, m)?)?;
     m.add_function(wrap_pyfunction!(iban_countries, m)?)?;
+    m.add_function(wrap_pyfunction!(describe, m)?)?;
+    m.add_function(wrap_pyfunction!(_generate_arrow, m)?)?;
     Ok(())
 }
@@ -1,3 +1,7 @@
+import re
+
+import pytest
+
 import idsmith
 
 
@@ -32,6 +36,12 @@ def test_bank_account_generate():
     assert result["formatted"]
 
 
+def test_bank_account_territory():
+    result = idsmith.BankAccount.generate("PR")
+    assert result["country_code"] == "PR"
+    assert result["effective_country_code"] == "US"
+
+
 def test_bank_account_validate():
     result = idsmith.BankAccount.generate("US")
     assert idsmith.BankAccount.validate("US", result["raw"])
@@ -50,18 +60,21 @@ def test_bank_account_is_supported():
 
 
 def test_personal_id_generate():
-    code = idsmith.PersonalId.generate("EE")
-    assert len(code) == 11
-    assert idsmith.PersonalId.validate("EE", code)
+    result = idsmith.PersonalId.generate("EE")
+    assert len(result["code"]) == 11
+    assert result["valid"] is True
+    assert result["dob"] is not None
+    assert idsmith.PersonalId.validate("EE", result["code"])
 
 
 def test_personal_id_generate_with_gender():
-    code = idsmith.PersonalId.generate("EE", gender="male")
-    assert idsmith.PersonalId.validate("EE", code)
+    result = idsmith.PersonalId.generate("EE", gender="male")
+    assert result["gender"] == "male"
+    assert idsmith.PersonalId.validate("EE", result["code"])
 
 
 def test_personal_id_parse():
-    code = idsmith.PersonalId.generate("EE")
+    code = idsmith.PersonalId.generate("EE")["code"]
     result = idsmith.PersonalId.parse("EE", code)
     assert result["country_code"] == "EE"
     assert result["valid"] is True
@@ -76,6 +89,15 @@ def test_personal_id_list_countries():
     assert "EE" in codes
 
 
+def test_personal_id_list_countries_locale():
+    countries = idsmith.PersonalId.list_countries("local")
+    fi = next(c for c in countries if c["code"] == "FI")
+    assert fi["country_name"] == "Suomi"
+    assert fi["id_name"] == "Henkilötunnus"
+    with pytest.raises(ValueError):
+        idsmith.PersonalId.list_countries("klingon")
+
+
 def test_credit_card_generate():
     result = idsmith.CreditCard.generate()
     assert result["valid"] is True
@@ -141,3 +163,108 @@ def test_swift_generate_country():
 def test_swift_validate():
     result = idsmith.Swift.generate()
     assert idsmith.Swift.validate(result["code"])
+
+
+def test_describe():
+    info = idsmith.describe("id", "EE")
+    assert info["min_length"] == 11
+    assert info["charset"] == "digits"
+    assert info["has_checksum"] is True
+    assert info["encodes_dob"] is True
+    assert re.fullmatch(info["regex"], info["example"])
+
+
+def test_describe_requires_country():
+    with pytest.raises(ValueError):
+        idsmith.describe("id")
+
+
+def test_personal_id_region():
+    result = idsmith.PersonalId.generate("MX", region="MX-JAL")
+    assert result["region"] == "JAL"
+
+
+def test_seed_repeats():
+    assert idsmith.generate_iban("DE", seed=7) == idsmith.generate_iban("DE", seed=7)
+    assert idsmith.PersonalId.generate("SE", seed=7) == idsmith.PersonalId.generate(
+        "SE", seed=7
+    )
+    assert idsmith.CreditCard.generate(count=5, seed=7) == idsmith.CreditCard.generate(
+        count=5, seed=7
+    )
+    assert idsmith.Swift.generate(seed=1) != idsmith.Swift.generate(seed=2)
+
+
+def test_count_returns_list():
+    results = idsmith.VatId.generate("DE", count=20, seed=3)
+    assert len(results) == 20
+    assert all(idsmith.VatId.validate(r["code"]) for r in results)
+    assert len(set(r["code"] for r in results)) > 1
+    assert idsmith.generate_iban("NL", count=0) == []
+    ibans = idsmith.generate_iban("NL", count=3)
+    assert all(iban.startswith("NL") for iban in ibans)
+
+
+def test_strict_rejects_territory():
+    assert idsmith.Passport.generate("PR")["effective_country_code"] == "US"
+    with pytest.raises(ValueError):
+        idsmith.Passport.generate("PR", strict=True)
+
+
+def test_parse_round_trips():
+    for cls in (idsmith.Swift, idsmith.LegalEntityId, idsmith.VatId):
+        result = cls.generate(seed=5)
+        assert cls.parse(result["code"]) == result
+    for cls in (idsmith.CompanyId, idsmith.Passport):
+        result = cls.generate("DE", seed=5)
+        assert cls.parse("DE", result["code"]) == result
+    iban = idsmith.generate_iban("DE", seed=5)
+    assert idsmith.parse_iban(iban.lower())["iban"] == iban
+
+
+def test_parse_invalid():
+    result = idsmith.TaxId.parse("IN", "ABCDE1234")
+    assert result["valid"] is False
+    assert result["name"] == "PAN"
+    with pytest.raises(ValueError):
+        idsmith.Swift.parse("XX")
+    with pytest.raises(ValueError):
+        idsmith.parse_iban("DE00000000000000000000")
+    with pytest.raises(ValueError):
+        idsmith.CompanyId.parse("ZZ", "123")
+
+
+def test_credit_card_parse():
+    card = idsmith.CreditCard.generate(brand="amex", seed=9)
+    result = idsmith.CreditCard.parse(card["number"])
+    assert result["brand"] == "AMEX"
+    assert result["formatted"] == card["formatted"]
+
+
+def test_class_describe():
+    assert idsmith.PersonalId.describe("EE") == idsmith.describe("id", "EE")
+    assert idsmith.LegalEntityId.describe()["max_length"] == 20
+    with pytest.raises(ValueError):
+        idsmith.BankAccount.describe("ZZ")
+
+
+def test_generate_table():
+    pa = pytest.importorskip("pyarrow")
+    table = idsmith.generate_table("id", 50, "SE")
+    assert table.num_rows == 50
+    assert table.schema.field("valid").type == pa.bool_()
+    assert all(table.column("valid").to_pylist())
+    assert idsmith.PersonalId.validate("SE", table.column("code")[0].as_py())
+
+
+def test_generate_table_seed():
+    pytest.importorskip("pyarrow")
+    first = idsmith.generate_table("vat", 10, "FR", seed=1)
+    second = idsmith.generate_table("vat", 10, "FR", seed=1)
+    assert first.equals(second)
+
+
+def test_generate_table_requires_country():
+    pytest.importorskip("pyarrow")
+    with pytest.raises(ValueError):
+        idsmith.generate_table("id", 10)
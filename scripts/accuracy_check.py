@@ -84,7 +84,7 @@ def test_accuracy():
             p_total += 1
             success = True
             for _ in range(10):
-                code = idsmith.PersonalId.generate(cc)
+                code = idsmith.PersonalId.generate(cc)["code"]
                 if not mod.is_valid(code):
                     print(f"  FAILED: {cc} {mod_path}: {code}")
                     success = False
@@ -25,7 +25,7 @@ def test_accuracy():
     print("\n[US SSN]")
     valid_count = 0
     for _ in range(50):
-        code = idsmith.PersonalId.generate("US")
+        code = idsmith.PersonalId.generate("US")["code"]
         if us_ssn.is_valid(code):
             valid_count += 1
     print(f"  US: {valid_count}/50 passed")
@@ -34,7 +34,7 @@ def test_accuracy():
     print("\n[EE Personal ID]")
     valid_count = 0
     for _ in range(50):
-        code = idsmith.PersonalId.generate("EE")
+        code = idsmith.PersonalId.generate("EE")["code"]
         if ee_personalid.is_valid(code):
             valid_count += 1
     print(f"  EE: {valid_count}/50 passed")
@@ -43,7 +43,7 @@ def test_accuracy():
     print("\n[BR CPF]")
     valid_count = 0
     for _ in range(50):
-        code = idsmith.PersonalId.generate("BR")
+        code = idsmith.PersonalId.generate("BR")["code"]
         if br_cpf.is_valid(code):
             valid_count += 1
     print(f"  BR: {valid_count}/50 passed")
@@ -62,7 +62,7 @@ def test_accuracy():
     print("\n[IT Codice Fiscale]")
     valid_count = 0
     for _ in range(50):
-        code = idsmith.PersonalId.generate("IT")
+        code = idsmith.PersonalId.generate("IT")["code"]
         if it_cf.is_valid(code):
             valid_count += 1
     print(f"  IT: {valid_count}/50 passed")
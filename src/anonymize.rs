@@ -0,0 +1,283 @@
+//! Consistent pseudonymization of identifiers.
+//!
+//! An [`Anonymizer`] replaces a real identifier with a checksum-valid fake of
+//! the same kind. The replacement is derived from a secret key and the
+//! normalized input, so the same input always maps to the same output and
+//! joins across files keep working.
+//!
+//! ```rust
+//! use idsmith::anonymize::Anonymizer;
+//! use idsmith::kind::Target;
+//!
+//! let anon = Anonymizer::new(b"my secret key");
+//! let target: Target = "iban".parse().unwrap();
+//! let a = anon.pseudonymize(&target, "DE89 3704 0044 0532 0130 00").unwrap();
+//! let b = anon.pseudonymize(&target, "DE89370400440532013000").unwrap();
+//! assert_eq!(a, b);
+//! assert!(a.starts_with("DE"));
+//! assert!(idsmith::iban::validate_iban(&a));
+//! ```
+//!
+//! The mapping is keyed with SipHash-2-4. It is meant for producing realistic
+//! test data from production extracts, not as a cryptographic tokenization
+//! scheme: keep the key secret and treat the output accordingly.
+//!
+//! Mappings are only stable for a given idsmith and `rand` version. The
+//! fakes are drawn from `rand`'s `StdRng` and its sampling methods, whose
+//! output `rand` may change between releases, so re-run the anonymization
+//! after upgrading rather than joining its output with older files.
+
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+use crate::detect::normalize;
+use crate::kind::{IdKind, Target};
+
+/// Maps real identifiers to consistent, valid fakes.
+#[derive(Clone)]
+pub struct Anonymizer {
+    k0: u64,
+    k1: u64,
+}
+
+impl Anonymizer {
+    /// Create an anonymizer from arbitrary key material (e.g. a key file).
+    pub fn new(key: &[u8]) -> Self {
+        Self {
+            k0: siphash24(0x736f_6d65_7073_6575, 0x646f_7261_6e64_6f6d, key),
+            k1: siphash24(0x6c79_6765_6e65_7261, 0x7465_6462_7974_6573, key),
+        }
+    }
+
+    /// Replace `value` with a fake identifier for `target`.
+    ///
+    /// The input is normalized (separators removed, uppercased) before
+    /// hashing. For IBANs and VAT numbers without an explicit country the
+    /// country prefix of the input is kept, so the country mix of the data
+    /// survives. Returns `None` for blank input or when the target country
+    /// is not supported.
+    pub fn pseudonymize(&self, target: &Target, value: &str) -> Option<String> {
+        let normalized = normalize(value);
+        if normalized.is_empty() {
+            return None;
+        }
+
+        let country = target
+            .country
+            .clone()
+            .or_else(|| inferred_country(target.kind, &normalized));
+
+        let mut material = Vec::with_capacity(normalized.len() + 16);
+        material.extend_from_slice(target.kind.as_str().as_bytes());
+        material.push(0x1f);
+        material.extend_from_slice(country.as_deref().unwrap_or("").as_bytes());
+        material.push(0x1f);
+        material.extend_from_slice(normalized.as_bytes());
+
+        let seed = siphash24(self.k0, self.k1, &material);
+        let mut rng = StdRng::seed_from_u64(seed);
+        target.kind.generate(country.as_deref(), &mut rng)
+    }
+}
+
+/// Countries that can be read off a self-describing code.
+fn inferred_country(kind: IdKind, normalized: &str) -> Option<String> {
+    let prefix = normalized.get(..2)?;
+    match kind {
+        IdKind::Iban if crate::iban::supported_countries().contains(&prefix) => {
+            Some(prefix.to_string())
+        }
+        IdKind::Vat => {
+            let prefix = if prefix == "GR" { "EL" } else { prefix };
+            crate::vat_ids()
+                .list_countries()
+                .iter()
+                .any(|(c, _)| *c == prefix)
+                .then(|| prefix.to_string())
+        }
+        _ => None,
+    }
+}
+
+/// Check that values can be generated for `target`, so no column is
+/// configured with a kind or country that would leave its values in place.
+pub fn check_target(target: &Target) -> Result<(), String> {
+    if target.kind.requires_country() && target.country.is_none() {
+        return Err(format!(
+            "{} needs a country, e.g. {}:US",
+            target.kind, target.kind
+        ));
+    }
+    match target.generate(&mut StdRng::seed_from_u64(0)) {
+        Some(_) => Ok(()),
+        None => Err(format!(
+            "Unsupported country for {}: {}",
+            target.kind,
+            target.country.as_deref().unwrap_or("")
+        )),
+    }
+}
+
+/// What [`anonymize_csv`] did.
+#[cfg(feature = "csv")]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CsvSummary {
+    /// Replaced columns and their targets, configured ones first.
+    pub columns: Vec<(String, Target)>,
+    /// Data rows written.
+    pub rows: usize,
+    /// Values that could not be replaced and were written as empty cells.
+    pub blanked: usize,
+}
+
+/// Stream a CSV file, replacing the configured columns.
+///
+/// `columns` maps header names to targets; each target is checked with
+/// [`check_target`] before anything is written. With `detect_sample > 0`,
+/// the first `detect_sample` rows are buffered and every column not listed
+/// in `columns` is checked with [`crate::detect::detect_column`]; columns
+/// where at least 80% of the values validate as one kind are anonymized as
+/// that kind. Other columns are passed through untouched.
+///
+/// A value in a replaced column is never written out as is: blank values
+/// stay blank, and values that cannot be replaced are emptied and counted
+/// in [`CsvSummary::blanked`].
+#[cfg(feature = "csv")]
+pub fn anonymize_csv<R, W>(
+    reader: R,
+    writer: &mut W,
+    anonymizer: &Anonymizer,
+    columns: &[(String, Target)],
+    detect_sample: usize,
+) -> std::io::Result<CsvSummary>
+where
+    R: std::io::BufRead,
+    W: std::io::Write,
+{
+    use std::io::{Error, ErrorKind};
+
+    let mut reader = crate::csv::Reader::new(reader);
+    let header = match reader.read_record()? {
+        Some(h) => h,
+        None => return Ok(CsvSummary::default()),
+    };
+
+    let mut plan: Vec<Option<Target>> = vec![None; header.len()];
+    let mut resolved = Vec::new();
+    for (name, target) in columns {
+        let idx = header.iter().position(|h| h == name).ok_or_else(|| {
+            Error::new(ErrorKind::InvalidInput, format!("No such column: {}", name))
+        })?;
+        check_target(target)
+            .map_err(|e| Error::new(ErrorKind::InvalidInput, format!("Column {}: {}", name, e)))?;
+        plan[idx] = Some(target.clone());
+        resolved.push((name.clone(), target.clone()));
+    }
+
+    let mut buffered = Vec::new();
+    if detect_sample > 0 {
+        while buffered.len() < detect_sample {
+            match reader.read_record()? {
+                Some(row) => buffered.push(row),
+                None => break,
+            }
+        }
+        for (idx, name) in header.iter().enumerate() {
+            if plan[idx].is_some() {
+                continue;
+            }
+            let samples = buffered
+                .iter()
+                .filter_map(|row| row.get(idx).map(String::as_str));
+            if let Some(target) = crate::detect::detect_column(samples, 0.8) {
+                plan[idx] = Some(target.clone());
+                resolved.push((name.clone(), target));
+            }
+        }
+    }
+
+    writeln!(writer, "{}", crate::csv::record_row(&header))?;
+    let mut summary = CsvSummary {
+        columns: resolved,
+        ..Default::default()
+    };
+    let mut write_row = |mut row: Vec<String>, writer: &mut W| -> std::io::Result<()> {
+        for (field, target) in row.iter_mut().zip(plan.iter()) {
+            let Some(target) = target else {
+                continue;
+            };
+            if normalize(field).is_empty() {
+                continue;
+            }
+            match anonymizer.pseudonymize(target, field) {
+                Some(fake) => *field = fake,
+                None => {
+                    field.clear();
+                    summary.blanked += 1;
+                }
+            }
+        }
+        summary.rows += 1;
+        writeln!(writer, "{}", crate::csv::record_row(&row))
+    };
+    for row in buffered {
+        write_row(row, writer)?;
+    }
+    for row in reader {
+        write_row(row?, writer)?;
+    }
+
+    Ok(summary)
+}
+
+/// SipHash-2-4 (Aumasson & Bernstein), used as a keyed PRF for seeding.
+pub(crate) fn siphash24(k0: u64, k1: u64, data: &[u8]) -> u64 {
+    let mut v0 = k0 ^ 0x736f_6d65_7073_6575;
+    let mut v1 = k1 ^ 0x646f_7261_6e64_6f6d;
+    let mut v2 = k0 ^ 0x6c79_6765_6e65_7261;
+    let mut v3 = k1 ^ 0x7465_6462_7974_6573;
+
+    macro_rules! round {
+        () => {
+            v0 = v0.wrapping_add(v1);
+            v1 = v1.rotate_left(13);
+            v1 ^= v0;
+            v0 = v0.rotate_left(32);
+            v2 = v2.wrapping_add(v3);
+            v3 = v3.rotate_left(16);
+            v3 ^= v2;
+            v0 = v0.wrapping_add(v3);
+            v3 = v3.rotate_left(21);
+            v3 ^= v0;
+            v2 = v2.wrapping_add(v1);
+            v1 = v1.rotate_left(17);
+            v1 ^= v2;
+            v2 = v2.rotate_left(32);
+        };
+    }
+
+    let mut chunks = data.chunks_exact(8);
+    for chunk in &mut chunks {
+        let m = u64::from_le_bytes(chunk.try_into().unwrap());
+        v3 ^= m;
+        round!();
+        round!();
+        v0 ^= m;
+    }
+    let rest = chunks.remainder();
+    let mut last = [0u8; 8];
+    last[..rest.len()].copy_from_slice(rest);
+    last[7] = data.len() as u8;
+    let m = u64::from_le_bytes(last);
+    v3 ^= m;
+    round!();
+    round!();
+    v0 ^= m;
+
+    v2 ^= 0xff;
+    round!();
+    round!();
+    round!();
+    round!();
+    v0 ^ v1 ^ v2 ^ v3
+}
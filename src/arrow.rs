@@ -0,0 +1,254 @@
+//! Columnar output: Arrow [`RecordBatch`]es and Arrow IPC or Parquet files.
+//!
+//! Enable with the `arrow` feature flag; Parquet files also need the
+//! `parquet` feature. The schema follows the result's [`Record`] fields:
+//! text fields become `Utf8` and `valid` becomes `Boolean`, nullable where
+//! the field is optional.
+//!
+//! ```rust
+//! use idsmith::arrow::{record_batch, FileFormat, Writer};
+//!
+//! let mut rng = rand::thread_rng();
+//! let vats: Vec<_> = (0..3)
+//!     .map(|_| idsmith::vat_ids().generate(&Default::default(), &mut rng).unwrap())
+//!     .collect();
+//! let batch = record_batch(&vats).unwrap();
+//! assert_eq!(batch.num_rows(), 3);
+//! assert_eq!(batch.schema().field(0).name(), "code");
+//!
+//! let mut writer = Writer::for_rows::<idsmith::vat::VatResult>(Vec::new(), FileFormat::Ipc).unwrap();
+//! for vat in &vats {
+//!     writer.write(vat).unwrap();
+//! }
+//! let ipc = writer.finish().unwrap();
+//! assert!(ipc.starts_with(b"ARROW1"));
+//! ```
+
+use std::io::Write;
+use std::sync::Arc;
+
+use arrow_array::builder::{BooleanBuilder, StringBuilder};
+use arrow_array::ArrayRef;
+use arrow_ipc::writer::FileWriter;
+use arrow_schema::{DataType, Schema};
+#[cfg(feature = "parquet")]
+use parquet::arrow::ArrowWriter;
+#[cfg(feature = "parquet")]
+use parquet::basic::Compression;
+#[cfg(feature = "parquet")]
+use parquet::file::properties::WriterProperties;
+
+pub use arrow_array::RecordBatch;
+pub use arrow_schema::SchemaRef;
+
+use crate::output::{Field, FieldType, Record, Value};
+
+/// Rows per record batch written by [`Writer`].
+pub const DEFAULT_BATCH_SIZE: usize = 8192;
+
+/// Arrow schema for `fields`.
+pub fn schema(fields: &[Field]) -> SchemaRef {
+    let fields: Vec<arrow_schema::Field> = fields
+        .iter()
+        .map(|f| {
+            let ty = match f.ty {
+                FieldType::Text => DataType::Utf8,
+                FieldType::Bool => DataType::Boolean,
+            };
+            arrow_schema::Field::new(&f.name, ty, f.nullable)
+        })
+        .collect();
+    Arc::new(Schema::new(fields))
+}
+
+/// Arrow schema for the fields of `T`.
+pub fn schema_for<T: Record>() -> SchemaRef {
+    schema(&T::fields())
+}
+
+enum Column {
+    Text(StringBuilder),
+    Bool(BooleanBuilder),
+}
+
+/// Accumulates rows column by column into [`RecordBatch`]es.
+pub struct BatchBuilder {
+    schema: SchemaRef,
+    columns: Vec<Column>,
+    rows: usize,
+}
+
+impl BatchBuilder {
+    pub fn new(fields: &[Field]) -> Self {
+        let columns = fields
+            .iter()
+            .map(|f| match f.ty {
+                FieldType::Text => Column::Text(StringBuilder::new()),
+                FieldType::Bool => Column::Bool(BooleanBuilder::new()),
+            })
+            .collect();
+        Self {
+            schema: schema(fields),
+            columns,
+            rows: 0,
+        }
+    }
+
+    /// A builder with the fields of `T`.
+    pub fn for_rows<T: Record>() -> Self {
+        Self::new(&T::fields())
+    }
+
+    pub fn schema(&self) -> SchemaRef {
+        self.schema.clone()
+    }
+
+    pub fn append<T: Record>(&mut self, row: &T) {
+        self.append_values(&row.values());
+    }
+
+    /// Append one row of values in field order. Text in a boolean column
+    /// is parsed as `true`/`false`, anything else becomes null.
+    pub fn append_values(&mut self, values: &[Value]) {
+        for (column, value) in self.columns.iter_mut().zip(values) {
+            match (column, value) {
+                (Column::Text(b), Value::Null) => b.append_null(),
+                (Column::Text(b), value) => b.append_value(value.as_text()),
+                (Column::Bool(b), Value::Bool(v)) => b.append_value(*v),
+                (Column::Bool(b), Value::Text(s)) => b.append_option(s.parse().ok()),
+                (Column::Bool(b), Value::Null) => b.append_null(),
+            }
+        }
+        self.rows += 1;
+    }
+
+    /// Rows appended since the last [`BatchBuilder::finish`].
+    pub fn len(&self) -> usize {
+        self.rows
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rows == 0
+    }
+
+    /// Take the appended rows as a batch and start a new one. Fails if a
+    /// non-nullable field got a null.
+    pub fn finish(&mut self) -> Result<RecordBatch, String> {
+        let arrays: Vec<ArrayRef> = self
+            .columns
+            .iter_mut()
+            .map(|column| match column {
+                Column::Text(b) => Arc::new(b.finish()) as ArrayRef,
+                Column::Bool(b) => Arc::new(b.finish()) as ArrayRef,
+            })
+            .collect();
+        self.rows = 0;
+        RecordBatch::try_new(self.schema.clone(), arrays).map_err(|e| e.to_string())
+    }
+}
+
+/// A single batch holding `rows`.
+pub fn record_batch<T: Record>(rows: &[T]) -> Result<RecordBatch, String> {
+    let mut builder = BatchBuilder::for_rows::<T>();
+    for row in rows {
+        builder.append(row);
+    }
+    builder.finish()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FileFormat {
+    /// Arrow IPC file (Feather v2).
+    Ipc,
+    /// Parquet with Snappy compression. Needs the `parquet` feature.
+    #[cfg(feature = "parquet")]
+    Parquet,
+}
+
+enum Sink<W: Write + Send> {
+    Ipc(FileWriter<W>),
+    #[cfg(feature = "parquet")]
+    Parquet(ArrowWriter<W>),
+}
+
+/// Streams rows into an Arrow IPC or Parquet file, one batch per
+/// [`DEFAULT_BATCH_SIZE`] rows.
+pub struct Writer<W: Write + Send> {
+    builder: BatchBuilder,
+    sink: Sink<W>,
+}
+
+impl<W: Write + Send> Writer<W> {
+    pub fn new(out: W, fields: &[Field], format: FileFormat) -> Result<Self, String> {
+        let builder = BatchBuilder::new(fields);
+        let schema = builder.schema();
+        let sink = match format {
+            FileFormat::Ipc => {
+                Sink::Ipc(FileWriter::try_new(out, &schema).map_err(|e| e.to_string())?)
+            }
+            #[cfg(feature = "parquet")]
+            FileFormat::Parquet => {
+                let props = WriterProperties::builder()
+                    .set_compression(Compression::SNAPPY)
+                    .build();
+                let writer =
+                    ArrowWriter::try_new(out, schema, Some(props)).map_err(|e| e.to_string())?;
+                Sink::Parquet(writer)
+            }
+        };
+        Ok(Self { builder, sink })
+    }
+
+    /// A writer with the fields of `T`.
+    pub fn for_rows<T: Record>(out: W, format: FileFormat) -> Result<Self, String> {
+        Self::new(out, &T::fields(), format)
+    }
+
+    pub fn write<T: Record>(&mut self, row: &T) -> Result<(), String> {
+        self.write_values(&row.values())
+    }
+
+    /// Write one row of values in field order.
+    pub fn write_values(&mut self, values: &[Value]) -> Result<(), String> {
+        self.builder.append_values(values);
+        if self.builder.len() >= DEFAULT_BATCH_SIZE {
+            self.flush_batch()?;
+        }
+        Ok(())
+    }
+
+    /// Write a whole batch with the writer's schema.
+    pub fn write_batch(&mut self, batch: &RecordBatch) -> Result<(), String> {
+        self.flush_batch()?;
+        match &mut self.sink {
+            Sink::Ipc(w) => w.write(batch).map_err(|e| e.to_string()),
+            #[cfg(feature = "parquet")]
+            Sink::Parquet(w) => w.write(batch).map_err(|e| e.to_string()),
+        }
+    }
+
+    /// Write any pending rows and the file footer, and return the output.
+    pub fn finish(mut self) -> Result<W, String> {
+        self.flush_batch()?;
+        match self.sink {
+            Sink::Ipc(mut w) => {
+                w.finish().map_err(|e| e.to_string())?;
+                w.into_inner().map_err(|e| e.to_string())
+            }
+            #[cfg(feature = "parquet")]
+            Sink::Parquet(w) => w.into_inner().map_err(|e| e.to_string()),
+        }
+    }
+
+    fn flush_batch(&mut self) -> Result<(), String> {
+        if self.builder.is_empty() {
+            return Ok(());
+        }
+        let batch = self.builder.finish()?;
+        match &mut self.sink {
+            Sink::Ipc(w) => w.write(&batch).map_err(|e| e.to_string()),
+            #[cfg(feature = "parquet")]
+            Sink::Parquet(w) => w.write(&batch).map_err(|e| e.to_string()),
+        }
+    }
+}
@@ -46,6 +46,7 @@ pub fn generate(_opts: &GenOptions, rng: &mut impl Rng) -> AccountResult {
 
     AccountResult {
         country_code: "AR".into(),
+        effective_country_code: "AR".into(),
         country_name: crate::countries::get_country_name("AR")
             .unwrap_or("Unknown")
             .to_string(),
@@ -19,6 +19,7 @@ pub fn generate(_opts: &GenOptions, rng: &mut impl Rng) -> AccountResult {
 
     AccountResult {
         country_code: "AU".into(),
+        effective_country_code: "AU".into(),
         country_name: crate::countries::get_country_name("AU")
             .unwrap_or("Unknown")
             .to_string(),
@@ -40,6 +40,7 @@ pub fn generate(_opts: &GenOptions, rng: &mut impl Rng) -> AccountResult {
 
     AccountResult {
         country_code: "BR".into(),
+        effective_country_code: "BR".into(),
         country_name: crate::countries::get_country_name("BR")
             .unwrap_or("Unknown")
             .to_string(),
@@ -22,6 +22,7 @@ pub fn generate(_opts: &GenOptions, rng: &mut impl Rng) -> AccountResult {
 
     AccountResult {
         country_code: "CA".into(),
+        effective_country_code: "CA".into(),
         country_name: crate::countries::get_country_name("CA")
             .unwrap_or("Unknown")
             .to_string(),
@@ -28,6 +28,7 @@ pub fn generate(_opts: &GenOptions, rng: &mut impl Rng) -> AccountResult {
 
     AccountResult {
         country_code: "CN".into(),
+        effective_country_code: "CN".into(),
         country_name: crate::countries::get_country_name("CN")
             .unwrap_or("Unknown")
             .to_string(),
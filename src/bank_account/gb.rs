@@ -24,6 +24,7 @@ pub fn generate(_opts: &GenOptions, rng: &mut impl Rng) -> AccountResult {
 
     AccountResult {
         country_code: "GB".into(),
+        effective_country_code: "GB".into(),
         country_name: crate::countries::get_country_name("GB")
             .unwrap_or("Unknown")
             .to_string(),
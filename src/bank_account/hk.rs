@@ -18,6 +18,7 @@ pub fn generate(_opts: &GenOptions, rng: &mut impl Rng) -> AccountResult {
 
     AccountResult {
         country_code: "HK".into(),
+        effective_country_code: "HK".into(),
         country_name: crate::countries::get_country_name("HK")
             .unwrap_or("Unknown")
             .to_string(),
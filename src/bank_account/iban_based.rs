@@ -165,6 +165,7 @@ pub fn generate(code: &str, rng: &mut impl Rng) -> Option<AccountResult> {
 
     Some(AccountResult {
         country_code: code.to_string(),
+        effective_country_code: code.to_string(),
         country_name: crate::countries::get_country_name(code)
             .unwrap_or("Unknown")
             .to_string(),
@@ -175,7 +176,7 @@ pub fn generate(code: &str, rng: &mut impl Rng) -> Option<AccountResult> {
         check_digits: Some(iban[2..4].to_string()),
         formatted,
         raw: bban.to_string(),
-        iban: Some(iban),
+        iban: Some(crate::Iban::from_generated(iban)),
         valid: true,
     })
 }
@@ -26,6 +26,7 @@ pub fn generate(_opts: &GenOptions, rng: &mut impl Rng) -> AccountResult {
 
     AccountResult {
         country_code: "IN".into(),
+        effective_country_code: "IN".into(),
         country_name: crate::countries::get_country_name("IN")
             .unwrap_or("Unknown")
             .to_string(),
@@ -26,6 +26,7 @@ pub fn generate(_opts: &GenOptions, rng: &mut impl Rng) -> AccountResult {
 
     AccountResult {
         country_code: "JP".into(),
+        effective_country_code: "JP".into(),
         country_name: crate::countries::get_country_name("JP")
             .unwrap_or("Unknown")
             .to_string(),
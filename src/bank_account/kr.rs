@@ -38,6 +38,7 @@ pub fn generate(_opts: &GenOptions, rng: &mut impl Rng) -> AccountResult {
 
     AccountResult {
         country_code: "KR".into(),
+        effective_country_code: "KR".into(),
         country_name: crate::countries::get_country_name("KR")
             .unwrap_or("Unknown")
             .to_string(),
@@ -1,9 +1,13 @@
 pub mod checksum;
 pub mod iban_based;
 
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
 #[cfg(feature = "json")]
 use serde::Serialize;
 
+use crate::locale::Locale;
+
 pub mod ar;
 pub mod au;
 pub mod br;
@@ -25,13 +29,20 @@ pub mod za;
 #[derive(Debug, Clone, Default)]
 pub struct GenOptions {
     pub bank_code: Option<String>,
+    /// Fail for territories without their own format instead of using the
+    /// parent country's.
+    pub strict: bool,
 }
 
 /// Result of generating or validating a bank account number.
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "json", derive(Serialize))]
 pub struct AccountResult {
+    /// Country as requested.
     pub country_code: String,
+    /// Country whose format was applied; differs from `country_code` for
+    /// territories using their parent's banking system.
+    pub effective_country_code: String,
     pub country_name: String,
     pub format_name: String,
     pub bank_code: Option<String>,
@@ -40,127 +51,24 @@ pub struct AccountResult {
     pub check_digits: Option<String>,
     pub formatted: String,
     pub raw: String,
-    pub iban: Option<String>,
+    pub iban: Option<crate::Iban>,
     pub valid: bool,
 }
 
-type GenerateFn = fn(&GenOptions, &mut rand::rngs::ThreadRng) -> AccountResult;
+type GenerateFn = fn(&GenOptions, &mut StdRng) -> AccountResult;
 type ValidateFn = fn(&str) -> bool;
 type FormatFn = fn(&str) -> String;
 
 struct RegistryEntry {
     code: &'static str,
     format_name: &'static str,
+    checksum: Option<&'static str>,
     has_iban: bool,
     generate: GenerateFn,
     validate: ValidateFn,
     format: FormatFn,
 }
 
-struct TerritoryAlias {
-    code: &'static str,
-    parent_code: &'static str,
-}
-
-/// Territories that use a parent country's banking system.
-static TERRITORY_ALIASES: &[TerritoryAlias] = &[
-    // US territories
-    TerritoryAlias {
-        code: "AS",
-        parent_code: "US",
-    },
-    TerritoryAlias {
-        code: "GU",
-        parent_code: "US",
-    },
-    TerritoryAlias {
-        code: "MP",
-        parent_code: "US",
-    },
-    TerritoryAlias {
-        code: "PR",
-        parent_code: "US",
-    },
-    TerritoryAlias {
-        code: "UM",
-        parent_code: "US",
-    },
-    TerritoryAlias {
-        code: "VI",
-        parent_code: "US",
-    },
-    // Australian territories
-    TerritoryAlias {
-        code: "CC",
-        parent_code: "AU",
-    },
-    TerritoryAlias {
-        code: "CX",
-        parent_code: "AU",
-    },
-    TerritoryAlias {
-        code: "HM",
-        parent_code: "AU",
-    },
-    TerritoryAlias {
-        code: "NF",
-        parent_code: "AU",
-    },
-    // NZ territories
-    TerritoryAlias {
-        code: "CK",
-        parent_code: "NZ",
-    },
-    TerritoryAlias {
-        code: "NU",
-        parent_code: "NZ",
-    },
-    TerritoryAlias {
-        code: "PN",
-        parent_code: "NZ",
-    },
-    TerritoryAlias {
-        code: "TK",
-        parent_code: "NZ",
-    },
-    // French territory
-    TerritoryAlias {
-        code: "BL",
-        parent_code: "FR",
-    },
-    // UK territories
-    TerritoryAlias {
-        code: "GS",
-        parent_code: "GB",
-    },
-    TerritoryAlias {
-        code: "IO",
-        parent_code: "GB",
-    },
-    TerritoryAlias {
-        code: "SH",
-        parent_code: "GB",
-    },
-    // Norwegian territories
-    TerritoryAlias {
-        code: "BV",
-        parent_code: "NO",
-    },
-    TerritoryAlias {
-        code: "SJ",
-        parent_code: "NO",
-    },
-    // Morocco
-    TerritoryAlias {
-        code: "EH",
-        parent_code: "MA",
-    },
-];
-
-fn resolve_alias(code: &str) -> Option<&'static TerritoryAlias> {
-    TERRITORY_ALIASES.iter().find(|a| a.code == code)
-}
-
 pub struct Registry {
     entries: Vec<RegistryEntry>,
 }
@@ -177,6 +85,7 @@ impl Registry {
             RegistryEntry {
                 code: "US",
                 format_name: "ABA Routing + Account",
+                checksum: Some("Weighted mod 10"),
                 has_iban: false,
                 generate: us::generate,
                 validate: us::validate,
@@ -185,6 +94,7 @@ impl Registry {
             RegistryEntry {
                 code: "CA",
                 format_name: "Inst + Transit + Account",
+                checksum: None,
                 has_iban: false,
                 generate: ca::generate,
                 validate: ca::validate,
@@ -193,6 +103,7 @@ impl Registry {
             RegistryEntry {
                 code: "MX",
                 format_name: "CLABE",
+                checksum: Some("Weighted mod 10"),
                 has_iban: false,
                 generate: mx::generate,
                 validate: mx::validate,
@@ -201,6 +112,7 @@ impl Registry {
             RegistryEntry {
                 code: "AU",
                 format_name: "BSB + Account",
+                checksum: None,
                 has_iban: false,
                 generate: au::generate,
                 validate: au::validate,
@@ -209,6 +121,7 @@ impl Registry {
             RegistryEntry {
                 code: "IN",
                 format_name: "IFSC + Account",
+                checksum: None,
                 has_iban: false,
                 generate: in_::generate,
                 validate: in_::validate,
@@ -217,6 +130,7 @@ impl Registry {
             RegistryEntry {
                 code: "JP",
                 format_name: "Bank + Branch + Account",
+                checksum: None,
                 has_iban: false,
                 generate: jp::generate,
                 validate: jp::validate,
@@ -225,6 +139,7 @@ impl Registry {
             RegistryEntry {
                 code: "CN",
                 format_name: "Bank Account (Luhn)",
+                checksum: Some("Luhn"),
                 has_iban: false,
                 generate: cn::generate,
                 validate: cn::validate,
@@ -233,6 +148,7 @@ impl Registry {
             RegistryEntry {
                 code: "ZA",
                 format_name: "Branch + Account",
+                checksum: None,
                 has_iban: false,
                 generate: za::generate,
                 validate: za::validate,
@@ -241,6 +157,7 @@ impl Registry {
             RegistryEntry {
                 code: "NZ",
                 format_name: "Bank + Branch + Account + Suffix",
+                checksum: Some("Weighted mod 11 or mod 10, by bank"),
                 has_iban: false,
                 generate: nz::generate,
                 validate: nz::validate,
@@ -249,6 +166,7 @@ impl Registry {
             RegistryEntry {
                 code: "SG",
                 format_name: "Bank + Branch + Account",
+                checksum: None,
                 has_iban: false,
                 generate: sg::generate,
                 validate: sg::validate,
@@ -257,6 +175,7 @@ impl Registry {
             RegistryEntry {
                 code: "HK",
                 format_name: "Bank + Account",
+                checksum: None,
                 has_iban: false,
                 generate: hk::generate,
                 validate: hk::validate,
@@ -265,6 +184,7 @@ impl Registry {
             RegistryEntry {
                 code: "KR",
                 format_name: "Bank Account",
+                checksum: None,
                 has_iban: false,
                 generate: kr::generate,
                 validate: kr::validate,
@@ -273,6 +193,7 @@ impl Registry {
             RegistryEntry {
                 code: "BR",
                 format_name: "Bank + Branch + Account",
+                checksum: Some("Weighted mod 11"),
                 has_iban: false,
                 generate: br::generate,
                 validate: br::validate,
@@ -281,6 +202,7 @@ impl Registry {
             RegistryEntry {
                 code: "GB",
                 format_name: "Sort Code + Account",
+                checksum: None,
                 has_iban: true,
                 generate: gb::generate,
                 validate: gb::validate,
@@ -289,6 +211,7 @@ impl Registry {
             RegistryEntry {
                 code: "AR",
                 format_name: "CBU",
+                checksum: Some("Weighted mod 10"),
                 has_iban: false,
                 generate: ar::generate,
                 validate: ar::validate,
@@ -297,6 +220,7 @@ impl Registry {
             RegistryEntry {
                 code: "NG",
                 format_name: "NUBAN",
+                checksum: Some("Weighted mod 10"),
                 has_iban: false,
                 generate: ng::generate,
                 validate: ng::validate,
@@ -310,23 +234,35 @@ impl Registry {
         self.entries.iter().find(|e| e.code == country)
     }
 
+    fn has_format(&self, country: &str) -> bool {
+        self.find(country).is_some() || iban_based::is_supported(country)
+    }
+
+    fn resolve(&self, country: &str, strict: bool) -> Option<String> {
+        crate::countries::effective_country_code(country, strict, |c| self.has_format(c))
+    }
+
     pub fn is_supported(&self, country: &str) -> bool {
-        self.find(country).is_some()
-            || iban_based::is_supported(country)
-            || resolve_alias(country).is_some()
+        self.resolve(country, false).is_some()
+    }
+
+    /// Country whose format applies to `country`: the country itself, or
+    /// the parent for a territory using its banking system.
+    pub fn effective_country(&self, country: &str) -> Option<String> {
+        self.resolve(country, false)
     }
 
     fn generate_for(
         &self,
         code: &str,
         opts: &GenOptions,
-        rng: &mut rand::rngs::ThreadRng,
+        rng: &mut StdRng,
     ) -> Option<AccountResult> {
         if let Some(entry) = self.find(code) {
             let mut result = (entry.generate)(opts, rng);
             if entry.has_iban {
                 if let Ok(iban) = crate::iban::generate_iban(Some(code), rng) {
-                    result.iban = Some(iban);
+                    result.iban = Some(crate::Iban::from_generated(iban));
                 }
             }
             return Some(result);
@@ -338,22 +274,20 @@ impl Registry {
         &self,
         country: &str,
         opts: &GenOptions,
-        rng: &mut rand::rngs::ThreadRng,
+        rng: &mut impl Rng,
     ) -> Option<AccountResult> {
-        // Try direct lookup first
-        if let Some(result) = self.generate_for(country, opts, rng) {
-            return Some(result);
-        }
-        // Try alias
-        if let Some(alias) = resolve_alias(country) {
-            let mut result = self.generate_for(alias.parent_code, opts, rng)?;
-            result.country_code = country.to_string();
-            result.country_name = crate::countries::get_country_name(country)
+        let effective = self.resolve(country, opts.strict)?;
+        let mut rng = StdRng::from_rng(rng).ok()?;
+        let mut result = self.generate_for(&effective, opts, &mut rng)?;
+        let requested = country.to_uppercase();
+        if requested != effective {
+            result.country_name = crate::countries::get_country_name(&requested)
                 .unwrap_or("Unknown")
                 .to_string();
-            return Some(result);
         }
-        None
+        result.country_code = requested;
+        result.effective_country_code = effective;
+        Some(result)
     }
 
     fn validate_for(&self, code: &str, raw: &str) -> Option<bool> {
@@ -364,13 +298,7 @@ impl Registry {
     }
 
     pub fn validate(&self, country: &str, raw: &str) -> Option<bool> {
-        if let Some(result) = self.validate_for(country, raw) {
-            return Some(result);
-        }
-        if let Some(alias) = resolve_alias(country) {
-            return self.validate_for(alias.parent_code, raw);
-        }
-        None
+        self.validate_for(&self.resolve(country, false)?, raw)
     }
 
     fn format_for(&self, code: &str, raw: &str) -> Option<String> {
@@ -380,14 +308,14 @@ impl Registry {
         iban_based::format(code, raw)
     }
 
+    /// Name of the check algorithm of the country's account number (e.g.
+    /// `"Luhn"`), or `None` if it has none.
+    pub fn checksum(&self, country: &str) -> Option<&'static str> {
+        self.find(&self.resolve(country, false)?)?.checksum
+    }
+
     pub fn format(&self, country: &str, raw: &str) -> Option<String> {
-        if let Some(result) = self.format_for(country, raw) {
-            return Some(result);
-        }
-        if let Some(alias) = resolve_alias(country) {
-            return self.format_for(alias.parent_code, raw);
-        }
-        None
+        self.format_for(&self.resolve(country, false)?, raw)
     }
 
     pub fn list_countries(&self) -> Vec<(&str, &str, &str, bool)> {
@@ -414,7 +342,7 @@ impl Registry {
         }
 
         // Territory aliases
-        for alias in TERRITORY_ALIASES {
+        for alias in crate::countries::TERRITORY_ALIASES {
             if seen.insert(alias.code) {
                 // Find the parent's format info
                 let (format_name, has_iban) =
@@ -423,7 +351,7 @@ impl Registry {
                     } else if iban_based::is_supported(alias.parent_code) {
                         ("IBAN Account", true)
                     } else {
-                        ("Bank + Account", false)
+                        continue;
                     };
                 result.push((
                     alias.code,
@@ -437,4 +365,20 @@ impl Registry {
         result.sort_by_key(|(code, _, _, _)| *code);
         result
     }
+
+    /// Like [`list_countries`](Self::list_countries), with country names in
+    /// `locale`.
+    pub fn list_countries_in(&self, locale: Locale) -> Vec<(&str, &str, &str, bool)> {
+        self.list_countries()
+            .into_iter()
+            .map(|(code, _, format_name, has_iban)| {
+                (
+                    code,
+                    crate::locale::country_label(code, locale),
+                    format_name,
+                    has_iban,
+                )
+            })
+            .collect()
+    }
 }
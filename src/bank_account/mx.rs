@@ -53,6 +53,7 @@ pub fn generate(_opts: &GenOptions, rng: &mut impl Rng) -> AccountResult {
 
     AccountResult {
         country_code: "MX".into(),
+        effective_country_code: "MX".into(),
         country_name: crate::countries::get_country_name("MX")
             .unwrap_or("Unknown")
             .to_string(),
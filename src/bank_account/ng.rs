@@ -42,6 +42,7 @@ pub fn generate(_opts: &GenOptions, rng: &mut impl Rng) -> AccountResult {
 
     AccountResult {
         country_code: "NG".into(),
+        effective_country_code: "NG".into(),
         country_name: crate::countries::get_country_name("NG")
             .unwrap_or("Unknown")
             .to_string(),
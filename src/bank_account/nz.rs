@@ -286,6 +286,7 @@ pub fn generate(_opts: &GenOptions, rng: &mut impl Rng) -> AccountResult {
 
     AccountResult {
         country_code: "NZ".into(),
+        effective_country_code: "NZ".into(),
         country_name: crate::countries::get_country_name("NZ")
             .unwrap_or("Unknown")
             .to_string(),
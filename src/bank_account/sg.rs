@@ -22,6 +22,7 @@ pub fn generate(_opts: &GenOptions, rng: &mut impl Rng) -> AccountResult {
 
     AccountResult {
         country_code: "SG".into(),
+        effective_country_code: "SG".into(),
         country_name: crate::countries::get_country_name("SG")
             .unwrap_or("Unknown")
             .to_string(),
@@ -29,6 +29,7 @@ pub fn generate(_opts: &GenOptions, rng: &mut impl Rng) -> AccountResult {
 
     AccountResult {
         country_code: "US".into(),
+        effective_country_code: "US".into(),
         country_name: crate::countries::get_country_name("US")
             .unwrap_or("Unknown")
             .to_string(),
@@ -19,6 +19,7 @@ pub fn generate(_opts: &GenOptions, rng: &mut impl Rng) -> AccountResult {
 
     AccountResult {
         country_code: "ZA".into(),
+        effective_country_code: "ZA".into(),
         country_name: crate::countries::get_country_name("ZA")
             .unwrap_or("Unknown")
             .to_string(),
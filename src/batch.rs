@@ -0,0 +1,283 @@
+//! Validate many codes at once, with a reason for every failure.
+//!
+//! [`validate_batch`] spreads the work over all cores and returns one
+//! [`Outcome`] per item, in input order. Failures carry a [`Failure`]
+//! saying whether the code was empty, had the wrong length or characters,
+//! or failed its check digits. A code is valid only as written; one that
+//! is valid once normalized fails with [`Failure::Formatting`].
+//!
+//! ```rust
+//! use idsmith::batch::{validate_batch, Failure, Item, Summary};
+//! use idsmith::kind::IdKind;
+//!
+//! let items = vec![
+//!     Item::new(IdKind::Iban, None, "DE89 3704 0044 0532 0130 00"),
+//!     Item::new(IdKind::Iban, None, "DE89 3704 0044 0532 0130 01"),
+//!     Item::new(IdKind::PersonalId, Some("EE"), "3900101"),
+//!     Item::new(IdKind::PersonalId, None, "39001010000"),
+//!     Item::new(IdKind::Iban, None, "de89 3704 0044 0532 0130 00"),
+//! ];
+//! let outcomes = validate_batch(&items);
+//! assert!(outcomes[0].valid);
+//! assert_eq!(outcomes[0].normalized, "DE89370400440532013000");
+//! assert_eq!(outcomes[1].reason, Some(Failure::Checksum));
+//! assert_eq!(outcomes[2].reason, Some(Failure::Length));
+//! assert_eq!(outcomes[3].reason, Some(Failure::CountryRequired));
+//! assert_eq!(outcomes[4].reason, Some(Failure::Formatting));
+//!
+//! let summary = Summary::new(&outcomes);
+//! assert_eq!((summary.valid, summary.invalid), (1, 4));
+//! ```
+
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+
+#[cfg(feature = "json")]
+use serde::Serialize;
+
+use crate::describe::{self, Charset, Rules};
+use crate::detect::normalize;
+use crate::kind::{IdKind, Target};
+
+/// Batches smaller than this are validated on the calling thread.
+const PARALLEL_THRESHOLD: usize = 256;
+
+/// Why a code is not valid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Failure {
+    /// Blank input.
+    Empty,
+    /// The kind is validated per country and none was given.
+    CountryRequired,
+    /// The country has no rules for this kind.
+    UnsupportedCountry,
+    /// Non-ASCII input, or characters other than letters, digits and the
+    /// usual separators.
+    Characters,
+    /// A length (not counting separators) the validator never accepts.
+    Length,
+    /// Letters where the scheme has only digits, or the structure does not
+    /// match.
+    Format,
+    /// Right length and characters, but the check digits (or other internal
+    /// rules, such as an embedded date) do not hold.
+    Checksum,
+    /// Not valid as written, but valid in its `normalized` form (without
+    /// separators, letters uppercased).
+    Formatting,
+}
+
+impl Failure {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Failure::Empty => "empty",
+            Failure::CountryRequired => "country_required",
+            Failure::UnsupportedCountry => "unsupported_country",
+            Failure::Characters => "characters",
+            Failure::Length => "length",
+            Failure::Format => "format",
+            Failure::Checksum => "checksum",
+            Failure::Formatting => "formatting",
+        }
+    }
+}
+
+impl fmt::Display for Failure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "json")]
+impl Serialize for Failure {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+/// One code to validate.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Item {
+    pub target: Target,
+    pub code: String,
+}
+
+impl Item {
+    pub fn new(kind: IdKind, country: Option<&str>, code: &str) -> Self {
+        Self {
+            target: Target::new(kind, country),
+            code: code.to_string(),
+        }
+    }
+}
+
+/// The result of validating one [`Item`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "json", derive(Serialize))]
+pub struct Outcome {
+    pub kind: IdKind,
+    pub country: Option<String>,
+    /// The code as given.
+    pub code: String,
+    /// The code with separators removed and letters uppercased.
+    pub normalized: String,
+    pub valid: bool,
+    /// Set when `valid` is false.
+    pub reason: Option<Failure>,
+}
+
+/// Counts over a batch.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "json", derive(Serialize))]
+pub struct Summary {
+    pub total: usize,
+    pub valid: usize,
+    pub invalid: usize,
+    /// Invalid codes per reason.
+    pub reasons: BTreeMap<Failure, usize>,
+}
+
+impl Summary {
+    pub fn new(outcomes: &[Outcome]) -> Self {
+        let mut summary = Self::default();
+        for outcome in outcomes {
+            summary.add(outcome);
+        }
+        summary
+    }
+
+    pub fn add(&mut self, outcome: &Outcome) {
+        self.total += 1;
+        match outcome.reason {
+            None => self.valid += 1,
+            Some(reason) => {
+                self.invalid += 1;
+                *self.reasons.entry(reason).or_default() += 1;
+            }
+        }
+    }
+}
+
+impl fmt::Display for Summary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} checked, {} valid, {} invalid",
+            self.total, self.valid, self.invalid
+        )?;
+        if !self.reasons.is_empty() {
+            let reasons: Vec<String> = self
+                .reasons
+                .iter()
+                .map(|(reason, n)| format!("{}: {}", reason, n))
+                .collect();
+            write!(f, " ({})", reasons.join(", "))?;
+        }
+        Ok(())
+    }
+}
+
+/// Validate one code.
+pub fn validate(target: &Target, code: &str) -> Outcome {
+    Validator::default().validate(target, code)
+}
+
+/// Validate `items` in parallel, returning outcomes in input order.
+pub fn validate_batch(items: &[Item]) -> Vec<Outcome> {
+    let threads = std::thread::available_parallelism().map_or(1, |n| n.get());
+    if items.len() < PARALLEL_THRESHOLD || threads == 1 {
+        let mut validator = Validator::default();
+        return items
+            .iter()
+            .map(|item| validator.validate(&item.target, &item.code))
+            .collect();
+    }
+
+    let chunk = items.len().div_ceil(threads);
+    std::thread::scope(|scope| {
+        let handles: Vec<_> = items
+            .chunks(chunk)
+            .map(|part| {
+                scope.spawn(move || {
+                    let mut validator = Validator::default();
+                    part.iter()
+                        .map(|item| validator.validate(&item.target, &item.code))
+                        .collect::<Vec<_>>()
+                })
+            })
+            .collect();
+        handles
+            .into_iter()
+            .flat_map(|h| h.join().expect("validation thread panicked"))
+            .collect()
+    })
+}
+
+/// Validates codes, caching each scheme's accepted format for failure
+/// reasons.
+#[derive(Default)]
+struct Validator {
+    rules: HashMap<(IdKind, Option<String>), Option<Rules>>,
+}
+
+impl Validator {
+    fn validate(&mut self, target: &Target, code: &str) -> Outcome {
+        let normalized = normalize(code);
+        let reason = self.check(target, code, &normalized);
+        Outcome {
+            kind: target.kind,
+            country: target.country.clone(),
+            code: code.to_string(),
+            normalized,
+            valid: reason.is_none(),
+            reason,
+        }
+    }
+
+    fn check(&mut self, target: &Target, code: &str, normalized: &str) -> Option<Failure> {
+        let kind = target.kind;
+        if normalized.is_empty() {
+            return Some(Failure::Empty);
+        }
+        // Only the code as written passes, as with a single `validate`
+        let country = target.country.as_deref();
+        match kind.validate(country, code) {
+            Some(true) => return None,
+            Some(false) if kind.validate(country, normalized) == Some(true) => {
+                return Some(Failure::Formatting)
+            }
+            Some(false) => {}
+            None if target.country.is_none() => return Some(Failure::CountryRequired),
+            None => return Some(Failure::UnsupportedCountry),
+        }
+        if !normalized.chars().all(|c| c.is_ascii_alphanumeric()) {
+            return Some(Failure::Characters);
+        }
+
+        // Self-describing codes carry their country in the prefix
+        let country = target.country.clone().or_else(|| match kind {
+            IdKind::Iban | IdKind::Vat => normalized.get(..2).map(str::to_string),
+            _ => None,
+        });
+        let rules = self
+            .rules
+            .entry((kind, country.clone()))
+            .or_insert_with(|| describe::rules(kind, country.as_deref()));
+        let Some(rules) = rules else {
+            return Some(Failure::Format);
+        };
+        let digits = normalized.bytes().all(|b| b.is_ascii_digit());
+        let letters = normalized.bytes().all(|b| b.is_ascii_alphabetic());
+        if !rules.lengths.contains(&normalized.len()) {
+            Some(Failure::Length)
+        } else if (rules.charset == Charset::Digits && !digits)
+            || (rules.charset == Charset::Letters && !letters)
+        {
+            Some(Failure::Format)
+        } else if rules.has_checksum {
+            Some(Failure::Checksum)
+        } else {
+            Some(Failure::Format)
+        }
+    }
+}
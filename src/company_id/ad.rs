@@ -1,6 +1,6 @@
 use rand::Rng;
 
-pub fn generate(rng: &mut rand::rngs::ThreadRng) -> String {
+pub fn generate(rng: &mut impl Rng) -> String {
     let prefix = "ACDEFGLOPU".chars().nth(rng.gen_range(0..10)).unwrap();
     let mut s = prefix.to_string();
     let num = match prefix {
@@ -1,6 +1,6 @@
 use rand::Rng;
 
-pub fn generate(rng: &mut rand::rngs::ThreadRng) -> String {
+pub fn generate(rng: &mut impl Rng) -> String {
     let mut s = String::new();
     s.push((b'A' + rng.gen_range(0..13)) as char); // A-M
     for _ in 0..8 {
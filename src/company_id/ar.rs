@@ -1,6 +1,6 @@
 use rand::Rng;
 
-pub fn generate(rng: &mut rand::rngs::ThreadRng) -> String {
+pub fn generate(rng: &mut impl Rng) -> String {
     let prefixes = ["20", "23", "24", "27", "30", "33", "34", "50", "51", "55"];
     let prefix = prefixes[rng.gen_range(0..prefixes.len())];
     let mut digits: Vec<u8> = prefix.bytes().map(|b| b - b'0').collect();
@@ -1,6 +1,6 @@
 use rand::Rng;
 
-pub fn generate(rng: &mut rand::rngs::ThreadRng) -> String {
+pub fn generate(rng: &mut impl Rng) -> String {
     let digits: Vec<u8> = (0..7).map(|_| rng.gen_range(0..=9)).collect();
     let weights = [1, 2, 1, 2, 1, 2, 1];
     let mut sum: u32 = 0;
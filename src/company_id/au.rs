@@ -3,7 +3,7 @@ use rand::Rng;
 /// ABN (Australian Business Number) - 11 digits, first two are check digits.
 /// stdnum: calc_check_digits: weights (3,5,7,9,11,13,15,17,19) on body digits,
 /// check = str(11 + (sum_of(-w*d) - 1) % 89)
-pub fn generate(rng: &mut rand::rngs::ThreadRng) -> String {
+pub fn generate(rng: &mut impl Rng) -> String {
     let body: Vec<u8> = (0..9).map(|_| rng.gen_range(0..=9)).collect();
     let weights = [3, 5, 7, 9, 11, 13, 15, 17, 19];
     let s: i32 = body
@@ -1,6 +1,6 @@
 use rand::Rng;
 
-pub fn generate(rng: &mut rand::rngs::ThreadRng) -> String {
+pub fn generate(rng: &mut impl Rng) -> String {
     let mut digits: Vec<u8> = (0..8).map(|_| rng.gen_range(0..=9)).collect();
     let weights = [4, 1, 8, 6, 2, 7, 5, 3];
     let sum: u32 = digits
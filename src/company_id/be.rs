@@ -1,6 +1,6 @@
 use rand::Rng;
 
-pub fn generate(rng: &mut rand::rngs::ThreadRng) -> String {
+pub fn generate(rng: &mut impl Rng) -> String {
     let base: u64 = rng.gen_range(2_000_000..=9_999_999);
     let first_digit = 0u64;
     let full_base = first_digit * 10_000_000 + base;
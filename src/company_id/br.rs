@@ -1,6 +1,6 @@
 use rand::Rng;
 
-pub fn generate(rng: &mut rand::rngs::ThreadRng) -> String {
+pub fn generate(rng: &mut impl Rng) -> String {
     let mut digits: Vec<u8> = (0..12).map(|_| rng.gen_range(0..=9)).collect();
     let calc_dv = |slice: &[u8], weights: &[u8]| -> u8 {
         let sum: u32 = slice
@@ -1,6 +1,6 @@
 use rand::Rng;
 
-pub fn generate(rng: &mut rand::rngs::ThreadRng) -> String {
+pub fn generate(rng: &mut impl Rng) -> String {
     loop {
         let mut digits: Vec<u8> = (0..8).map(|_| rng.gen_range(0..=9)).collect();
         // first char must be 1234567ABCEHKM
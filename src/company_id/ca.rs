@@ -1,7 +1,7 @@
 use crate::bank_account::checksum::luhn_check_digit;
 use rand::Rng;
 
-pub fn generate(rng: &mut rand::rngs::ThreadRng) -> String {
+pub fn generate(rng: &mut impl Rng) -> String {
     let mut digits: Vec<u8> = (0..8).map(|_| rng.gen_range(0..=9)).collect();
     let check = luhn_check_digit(&digits);
     digits.push(check);
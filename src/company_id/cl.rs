@@ -1,6 +1,6 @@
 use rand::Rng;
 
-pub fn generate(rng: &mut rand::rngs::ThreadRng) -> String {
+pub fn generate(rng: &mut impl Rng) -> String {
     let len = rng.gen_range(7..=8); // Body length 7 or 8
     let digits: Vec<u8> = (0..len).map(|_| rng.gen_range(0..=9)).collect();
 
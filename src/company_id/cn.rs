@@ -1,6 +1,6 @@
 use rand::Rng;
 
-pub fn generate(rng: &mut rand::rngs::ThreadRng) -> String {
+pub fn generate(rng: &mut impl Rng) -> String {
     let alphabet = "0123456789ABCDEFGHJKLMNPQRTUWXY";
     let mut code = String::new();
     code.push('9');
@@ -5,7 +5,7 @@ const CHECK_DIGITS: &[u8; 11] = b"01987654321";
 /// NIT (Colombian tax number) - 8-15 body digits + 1 check digit.
 /// stdnum: weights (3,7,13,17,19,23,29,37,41,43,47,53,59,67,71) applied to reversed body,
 /// check = '01987654321'[sum % 11]
-pub fn generate(rng: &mut rand::rngs::ThreadRng) -> String {
+pub fn generate(rng: &mut impl Rng) -> String {
     let len = rng.gen_range(8..=9);
     let body: Vec<u8> = (0..len).map(|_| rng.gen_range(0..=9)).collect();
     let weights = [3u32, 7, 13, 17, 19, 23, 29, 37, 41, 43, 47, 53, 59, 67, 71];
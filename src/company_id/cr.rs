@@ -1,6 +1,6 @@
 use rand::Rng;
 
-pub fn generate(rng: &mut rand::rngs::ThreadRng) -> String {
+pub fn generate(rng: &mut impl Rng) -> String {
     let class = ["3", "4", "5"][rng.gen_range(0..3)];
     let type_val = match class {
         "3" => ["101", "102", "103", "104"][rng.gen_range(0..4)],
@@ -1,6 +1,6 @@
 use rand::Rng;
 
-pub fn generate(rng: &mut rand::rngs::ThreadRng) -> String {
+pub fn generate(rng: &mut impl Rng) -> String {
     let year = rng.gen_range(0..=99);
     let month = rng.gen_range(1..=12);
     let day = rng.gen_range(1..=28);
@@ -1,6 +1,6 @@
 use rand::Rng;
 
-pub fn generate(rng: &mut rand::rngs::ThreadRng) -> String {
+pub fn generate(rng: &mut impl Rng) -> String {
     // Standard legal entity (8 digits)
     loop {
         let mut digits: Vec<u8> = (0..7).map(|_| rng.gen_range(0..=9)).collect();
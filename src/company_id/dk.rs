@@ -1,6 +1,6 @@
 use rand::Rng;
 
-pub fn generate(rng: &mut rand::rngs::ThreadRng) -> String {
+pub fn generate(rng: &mut impl Rng) -> String {
     loop {
         let mut digits: Vec<u8> = vec![rng.gen_range(1..=9)];
         for _ in 0..7 {
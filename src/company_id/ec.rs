@@ -30,7 +30,7 @@ fn weighted_checksum(digits: &[u8], weights: &[u32]) -> u32 {
 
 /// RUC (Ecuadorian company tax number) - 13 digits.
 /// Type determined by 3rd digit: 0-5=natural, 6=public, 9=juridical.
-pub fn generate(rng: &mut rand::rngs::ThreadRng) -> String {
+pub fn generate(rng: &mut impl Rng) -> String {
     loop {
         let province = rng.gen_range(1u8..=24);
         let kind = *[0u8, 6, 9].get(rng.gen_range(0..3)).unwrap();
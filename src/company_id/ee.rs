@@ -24,7 +24,7 @@ fn calc_check_digit(digits: &[u8]) -> u8 {
     }
 }
 
-pub fn generate(rng: &mut rand::rngs::ThreadRng) -> String {
+pub fn generate(rng: &mut impl Rng) -> String {
     let first = *[1u8, 7, 8, 9].get(rng.gen_range(0..4)).unwrap();
     let mut digits: Vec<u8> = vec![first];
     for _ in 1..7 {
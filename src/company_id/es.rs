@@ -6,7 +6,7 @@ const CHECK_LETTERS: &[u8; 10] = b"JABCDEFGHI";
 
 /// CIF (Spanish company tax number) - 9 chars: letter + 7 digits + check (digit or letter).
 /// stdnum: Luhn on digits 1-7, check = both numeric and letter forms accepted.
-pub fn generate(rng: &mut rand::rngs::ThreadRng) -> String {
+pub fn generate(rng: &mut impl Rng) -> String {
     let letter = FIRST_CHARS[rng.gen_range(0..FIRST_CHARS.len())] as char;
     let digits: Vec<u8> = (0..7).map(|_| rng.gen_range(0..=9)).collect();
     let check = luhn_check_digit(&digits);
@@ -1,6 +1,6 @@
 use rand::Rng;
 
-pub fn generate(rng: &mut rand::rngs::ThreadRng) -> String {
+pub fn generate(rng: &mut impl Rng) -> String {
     loop {
         let digits: Vec<u8> = (0..7).map(|_| rng.gen_range(0..=9)).collect();
         let weights = [7, 9, 10, 5, 8, 4, 2];
@@ -1,6 +1,6 @@
 use rand::Rng;
 
-pub fn generate(rng: &mut rand::rngs::ThreadRng) -> String {
+pub fn generate(rng: &mut impl Rng) -> String {
     (0..6)
         .map(|_| (b'0' + rng.gen_range(0..=9u8)) as char)
         .collect()
@@ -1,6 +1,6 @@
 use rand::Rng;
 
-pub fn generate(rng: &mut rand::rngs::ThreadRng) -> String {
+pub fn generate(rng: &mut impl Rng) -> String {
     let prefix = ["C", "G", "Q", "V"][rng.gen_range(0..4)];
     let digits: Vec<u8> = (0..9).map(|_| rng.gen_range(0..=9)).collect();
     let sum: u32 = digits
@@ -1,6 +1,6 @@
 use rand::Rng;
 
-pub fn generate(rng: &mut rand::rngs::ThreadRng) -> String {
+pub fn generate(rng: &mut impl Rng) -> String {
     let digits: Vec<u8> = (0..rng.gen_range(5..11))
         .map(|_| rng.gen_range(0..=9))
         .collect();
@@ -1,7 +1,7 @@
 use crate::personal_id::checksum::iso7064_mod11_10;
 use rand::Rng;
 
-pub fn generate(rng: &mut rand::rngs::ThreadRng) -> String {
+pub fn generate(rng: &mut impl Rng) -> String {
     let mut digits: Vec<u8> = (0..10).map(|_| rng.gen_range(0..=9)).collect();
     let check = iso7064_mod11_10(&digits);
     digits.push(check);
@@ -1,6 +1,6 @@
 use rand::Rng;
 
-pub fn generate(rng: &mut rand::rngs::ThreadRng) -> String {
+pub fn generate(rng: &mut impl Rng) -> String {
     let mut digits: Vec<u8> = vec![0];
     digits.push(rng.gen_range(1..=9)); // type
     digits.push(rng.gen_range(0..=9)); // type
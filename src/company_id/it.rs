@@ -1,7 +1,7 @@
 use crate::bank_account::checksum::luhn_check_digit;
 use rand::Rng;
 
-pub fn generate(rng: &mut rand::rngs::ThreadRng) -> String {
+pub fn generate(rng: &mut impl Rng) -> String {
     let mut digits: Vec<u8> = (0..7).map(|_| rng.gen_range(0..=9)).collect();
     let province = rng.gen_range(1..=100u16);
     digits.push((province / 100) as u8);
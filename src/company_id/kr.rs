@@ -1,6 +1,6 @@
 use rand::Rng;
 
-pub fn generate(rng: &mut rand::rngs::ThreadRng) -> String {
+pub fn generate(rng: &mut impl Rng) -> String {
     let head = rng.gen_range(101..=999);
     let mid = rng.gen_range(1..=99);
     let tail = rng.gen_range(1..=9999);
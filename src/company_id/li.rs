@@ -1,6 +1,6 @@
 use rand::Rng;
 
-pub fn generate(rng: &mut rand::rngs::ThreadRng) -> String {
+pub fn generate(rng: &mut impl Rng) -> String {
     let len = rng.gen_range(4..=12);
     (0..len)
         .map(|_| (b'0' + rng.gen_range(0..=9u8)) as char)
@@ -1,6 +1,6 @@
 use rand::Rng;
 
-pub fn generate(rng: &mut rand::rngs::ThreadRng) -> String {
+pub fn generate(rng: &mut impl Rng) -> String {
     let base = rng.gen_range(100_000..999_999);
     let check = base % 89;
     format!("LU{:06}{:02}", base, check)
@@ -1,6 +1,6 @@
 use rand::Rng;
 
-pub fn generate(rng: &mut rand::rngs::ThreadRng) -> String {
+pub fn generate(rng: &mut impl Rng) -> String {
     loop {
         let first = [4, 5, 9][rng.gen_range(0..3)];
         let mut digits: Vec<u8> = vec![first];
@@ -10,7 +10,7 @@ fn mod97(digits: &str) -> u32 {
     rem as u32
 }
 
-pub fn generate(rng: &mut rand::rngs::ThreadRng) -> String {
+pub fn generate(rng: &mut impl Rng) -> String {
     loop {
         let body: String = (0..13)
             .map(|_| (b'0' + rng.gen_range(0..=9u8)) as char)
@@ -1,6 +1,6 @@
 use rand::Rng;
 
-pub fn generate(rng: &mut rand::rngs::ThreadRng) -> String {
+pub fn generate(rng: &mut impl Rng) -> String {
     // Monaco is like France but with 000 in middle
     // TVA format: FR + 2-digit check + 000 + 6 digits
     let siren = format!("000{:06}", rng.gen_range(0..1000000));
@@ -1,6 +1,6 @@
 use rand::Rng;
 
-pub fn generate(rng: &mut rand::rngs::ThreadRng) -> String {
+pub fn generate(rng: &mut impl Rng) -> String {
     let mut digits: Vec<u8> = vec![rng.gen_range(1..=9)]; // registry
     let year = rng.gen_range(0..=25); // year code assigned
     digits.push((year / 10) as u8);
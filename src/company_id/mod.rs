@@ -250,14 +250,25 @@ pub mod za;
 pub mod zm;
 pub mod zw;
 
-use rand::Rng;
+use std::sync::{Arc, RwLock};
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
 #[cfg(feature = "json")]
 use serde::Serialize;
 
+use crate::kind::IdKind;
+use crate::locale::Locale;
+use crate::scheme::IdScheme;
+
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "json", derive(Serialize))]
 pub struct CompanyResult {
+    /// Country as requested.
     pub country_code: String,
+    /// Country whose format was applied; differs from `country_code` for
+    /// territories using their parent's (e.g. `PR` → `US`).
+    pub effective_country_code: String,
     pub country_name: String,
     pub name: String,
     pub code: String,
@@ -267,20 +278,34 @@ pub struct CompanyResult {
 #[derive(Debug, Clone, Default)]
 pub struct GenOptions {
     pub country: Option<String>,
+    /// Fail for territories without their own format instead of using the
+    /// parent country's.
+    pub strict: bool,
 }
 
-type GenerateFn = fn(&mut rand::rngs::ThreadRng) -> String;
+type GenerateFn = fn(&mut StdRng) -> String;
 type ValidateFn = fn(&str) -> bool;
 
 struct RegistryEntry {
     code: &'static str,
     name: &'static str,
+    checksum: Option<&'static str>,
     generate: GenerateFn,
     validate: ValidateFn,
 }
 
+/// A scheme added at runtime with [`Registry::register`] or
+/// [`Registry::override_scheme`].
+#[derive(Clone)]
+struct CustomEntry {
+    code: Arc<str>,
+    name: Arc<str>,
+    scheme: Arc<dyn IdScheme>,
+}
+
 pub struct Registry {
     entries: Vec<RegistryEntry>,
+    custom: RwLock<Vec<CustomEntry>>,
 }
 
 impl Default for Registry {
@@ -295,1569 +320,1937 @@ impl Registry {
             RegistryEntry {
                 code: "AD",
                 name: "NRT",
+                checksum: None,
                 generate: ad::generate,
                 validate: ad::validate,
             },
             RegistryEntry {
                 code: "AE",
                 name: "TRN",
+                checksum: None,
                 generate: ae::generate,
                 validate: ae::validate,
             },
             RegistryEntry {
                 code: "AF",
                 name: "Business ID",
+                checksum: None,
                 generate: af::generate,
                 validate: af::validate,
             },
             RegistryEntry {
                 code: "AG",
                 name: "Business ID",
+                checksum: None,
                 generate: ag::generate,
                 validate: ag::validate,
             },
             RegistryEntry {
                 code: "AI",
                 name: "Business ID",
+                checksum: None,
                 generate: ai::generate,
                 validate: ai::validate,
             },
             RegistryEntry {
                 code: "AL",
                 name: "NIPT",
+                checksum: None,
                 generate: al::generate,
                 validate: al::validate,
             },
             RegistryEntry {
                 code: "AM",
                 name: "Business ID",
+                checksum: None,
                 generate: am::generate,
                 validate: am::validate,
             },
             RegistryEntry {
                 code: "AO",
                 name: "Business ID",
+                checksum: None,
                 generate: ao::generate,
                 validate: ao::validate,
             },
             RegistryEntry {
                 code: "AQ",
                 name: "Business ID",
+                checksum: None,
                 generate: aq::generate,
                 validate: aq::validate,
             },
             RegistryEntry {
                 code: "AR",
                 name: "CUIT",
+                checksum: Some("Weighted mod 11"),
                 generate: ar::generate,
                 validate: ar::validate,
             },
             RegistryEntry {
                 code: "AS",
                 name: "Business ID",
+                checksum: None,
                 generate: as_::generate,
                 validate: as_::validate,
             },
             RegistryEntry {
                 code: "AT",
                 name: "UID",
+                checksum: Some("Luhn variant"),
                 generate: at::generate,
                 validate: at::validate,
             },
             RegistryEntry {
                 code: "AU",
                 name: "ABN",
+                checksum: Some("Weighted mod 89"),
                 generate: au::generate,
                 validate: au::validate,
             },
             RegistryEntry {
                 code: "AW",
                 name: "Business ID",
+                checksum: None,
                 generate: aw::generate,
                 validate: aw::validate,
             },
             RegistryEntry {
                 code: "AX",
                 name: "Business ID",
+                checksum: None,
                 generate: ax::generate,
                 validate: ax::validate,
             },
             RegistryEntry {
                 code: "AZ",
                 name: "VOEN",
+                checksum: Some("Weighted mod 11"),
                 generate: az::generate,
                 validate: az::validate,
             },
             RegistryEntry {
                 code: "BA",
                 name: "Business ID",
+                checksum: None,
                 generate: ba::generate,
                 validate: ba::validate,
             },
             RegistryEntry {
                 code: "BB",
                 name: "Business ID",
+                checksum: None,
                 generate: bb::generate,
                 validate: bb::validate,
             },
             RegistryEntry {
                 code: "BD",
                 name: "Business ID",
+                checksum: None,
                 generate: bd::generate,
                 validate: bd::validate,
             },
             RegistryEntry {
                 code: "BE",
                 name: "TVA",
+                checksum: Some("Mod 97"),
                 generate: be::generate,
                 validate: be::validate,
             },
             RegistryEntry {
                 code: "BF",
                 name: "Business ID",
+                checksum: None,
                 generate: bf::generate,
                 validate: bf::validate,
             },
             RegistryEntry {
                 code: "BG",
                 name: "VAT",
+                checksum: Some("Weighted mod 11"),
                 generate: bg::generate,
                 validate: bg::validate,
             },
             RegistryEntry {
                 code: "BH",
                 name: "Business ID",
+                checksum: None,
                 generate: bh::generate,
                 validate: bh::validate,
             },
             RegistryEntry {
                 code: "BI",
                 name: "Business ID",
+                checksum: None,
                 generate: bi::generate,
                 validate: bi::validate,
             },
             RegistryEntry {
                 code: "BJ",
                 name: "Business ID",
+                checksum: None,
                 generate: bj::generate,
                 validate: bj::validate,
             },
             RegistryEntry {
                 code: "BL",
                 name: "Business ID",
+                checksum: None,
                 generate: bl::generate,
                 validate: bl::validate,
             },
             RegistryEntry {
                 code: "BM",
                 name: "Business ID",
+                checksum: None,
                 generate: bm::generate,
                 validate: bm::validate,
             },
             RegistryEntry {
                 code: "BN",
                 name: "Business ID",
+                checksum: None,
                 generate: bn::generate,
                 validate: bn::validate,
             },
             RegistryEntry {
                 code: "BO",
                 name: "Business ID",
+                checksum: None,
                 generate: bo::generate,
                 validate: bo::validate,
             },
             RegistryEntry {
                 code: "BQ",
                 name: "Business ID",
+                checksum: None,
                 generate: bq::generate,
                 validate: bq::validate,
             },
             RegistryEntry {
                 code: "BR",
                 name: "CNPJ",
+                checksum: Some("Weighted mod 11, two check digits"),
                 generate: br::generate,
                 validate: br::validate,
             },
             RegistryEntry {
                 code: "BS",
                 name: "Business ID",
+                checksum: None,
                 generate: bs::generate,
                 validate: bs::validate,
             },
             RegistryEntry {
                 code: "BT",
                 name: "Business ID",
+                checksum: None,
                 generate: bt::generate,
                 validate: bt::validate,
             },
             RegistryEntry {
                 code: "BV",
                 name: "Business ID",
+                checksum: None,
                 generate: bv::generate,
                 validate: bv::validate,
             },
             RegistryEntry {
                 code: "BW",
                 name: "Business ID",
+                checksum: None,
                 generate: bw::generate,
                 validate: bw::validate,
             },
             RegistryEntry {
                 code: "BY",
                 name: "UNP",
+                checksum: Some("Weighted mod 11"),
                 generate: by::generate,
                 validate: by::validate,
             },
             RegistryEntry {
                 code: "BZ",
                 name: "Business ID",
+                checksum: None,
                 generate: bz::generate,
                 validate: bz::validate,
             },
             RegistryEntry {
                 code: "CA",
                 name: "BN",
+                checksum: Some("Luhn"),
                 generate: ca::generate,
                 validate: ca::validate,
             },
             RegistryEntry {
                 code: "CC",
                 name: "Business ID",
+                checksum: None,
                 generate: cc::generate,
                 validate: cc::validate,
             },
             RegistryEntry {
                 code: "CD",
                 name: "Business ID",
+                checksum: None,
                 generate: cd::generate,
                 validate: cd::validate,
             },
             RegistryEntry {
                 code: "CF",
                 name: "Business ID",
+                checksum: None,
                 generate: cf::generate,
                 validate: cf::validate,
             },
             RegistryEntry {
                 code: "CG",
                 name: "Business ID",
+                checksum: None,
                 generate: cg::generate,
                 validate: cg::validate,
             },
             RegistryEntry {
                 code: "CH",
                 name: "UID",
+                checksum: Some("Weighted mod 11"),
                 generate: ch::generate,
                 validate: ch::validate,
             },
             RegistryEntry {
                 code: "CI",
                 name: "Business ID",
+                checksum: None,
                 generate: ci::generate,
                 validate: ci::validate,
             },
             RegistryEntry {
                 code: "CK",
                 name: "Business ID",
+                checksum: None,
                 generate: ck::generate,
                 validate: ck::validate,
             },
             RegistryEntry {
                 code: "CL",
                 name: "RUT",
+                checksum: Some("Weighted mod 11"),
                 generate: cl::generate,
                 validate: cl::validate,
             },
             RegistryEntry {
                 code: "CM",
                 name: "Business ID",
+                checksum: None,
                 generate: cm::generate,
                 validate: cm::validate,
             },
             RegistryEntry {
                 code: "CN",
                 name: "USCC",
+                checksum: Some("Weighted mod 31"),
                 generate: cn::generate,
                 validate: cn::validate,
             },
             RegistryEntry {
                 code: "CO",
                 name: "NIT",
+                checksum: Some("Weighted mod 11"),
                 generate: co::generate,
                 validate: co::validate,
             },
             RegistryEntry {
                 code: "CR",
                 name: "CPJ",
+                checksum: None,
                 generate: cr::generate,
                 validate: cr::validate,
             },
             RegistryEntry {
                 code: "CU",
                 name: "NI",
+                checksum: None,
                 generate: cu::generate,
                 validate: cu::validate,
             },
             RegistryEntry {
                 code: "CV",
                 name: "Business ID",
+                checksum: None,
                 generate: cv::generate,
                 validate: cv::validate,
             },
             RegistryEntry {
                 code: "CW",
                 name: "Business ID",
+                checksum: None,
                 generate: cw::generate,
                 validate: cw::validate,
             },
             RegistryEntry {
                 code: "CX",
                 name: "Business ID",
+                checksum: None,
                 generate: cx::generate,
                 validate: cx::validate,
             },
             RegistryEntry {
                 code: "CY",
                 name: "VAT",
+                checksum: Some("Mod 26 letter"),
                 generate: cy::generate,
                 validate: cy::validate,
             },
             RegistryEntry {
                 code: "CZ",
-                name: "DIC",
+                name: "DIČ",
+                checksum: Some("Weighted mod 11"),
                 generate: cz::generate,
                 validate: cz::validate,
             },
             RegistryEntry {
                 code: "DE",
                 name: "USt-IdNr",
+                checksum: Some("ISO 7064 MOD 11-10"),
                 generate: de::generate,
                 validate: de::validate,
             },
             RegistryEntry {
                 code: "DJ",
                 name: "Business ID",
+                checksum: None,
                 generate: dj::generate,
                 validate: dj::validate,
             },
             RegistryEntry {
                 code: "DK",
                 name: "CVR",
+                checksum: Some("Weighted mod 11"),
                 generate: dk::generate,
                 validate: dk::validate,
             },
             RegistryEntry {
                 code: "DM",
                 name: "Business ID",
+                checksum: None,
                 generate: dm::generate,
                 validate: dm::validate,
             },
             RegistryEntry {
                 code: "DO",
                 name: "RNC",
+                checksum: Some("Weighted mod 11"),
                 generate: do_::generate,
                 validate: do_::validate,
             },
             RegistryEntry {
                 code: "DZ",
                 name: "NIF",
+                checksum: None,
                 generate: dz::generate,
                 validate: dz::validate,
             },
             RegistryEntry {
                 code: "EC",
                 name: "RUC",
+                checksum: Some("Weighted mod 10 or 11, by type"),
                 generate: ec::generate,
                 validate: ec::validate,
             },
             RegistryEntry {
                 code: "EE",
                 name: "KMKR",
+                checksum: Some("Weighted mod 11, two passes"),
                 generate: ee::generate,
                 validate: ee::validate,
             },
             RegistryEntry {
                 code: "EG",
                 name: "Tax Card",
+                checksum: None,
                 generate: eg::generate,
                 validate: eg::validate,
             },
             RegistryEntry {
                 code: "EH",
                 name: "Business ID",
+                checksum: None,
                 generate: eh::generate,
                 validate: eh::validate,
             },
             RegistryEntry {
                 code: "ER",
                 name: "Business ID",
+                checksum: None,
                 generate: er::generate,
                 validate: er::validate,
             },
             RegistryEntry {
                 code: "ES",
                 name: "CIF",
+                checksum: Some("Luhn (digit or letter)"),
                 generate: es::generate,
                 validate: es::validate,
             },
             RegistryEntry {
                 code: "ET",
                 name: "Business ID",
+                checksum: None,
                 generate: et::generate,
                 validate: et::validate,
             },
             RegistryEntry {
                 code: "FI",
                 name: "ALV nro",
+                checksum: Some("Weighted mod 11"),
                 generate: fi::generate,
                 validate: fi::validate,
             },
             RegistryEntry {
                 code: "FJ",
                 name: "Business ID",
+                checksum: None,
                 generate: fj::generate,
                 validate: fj::validate,
             },
             RegistryEntry {
                 code: "FK",
                 name: "Business ID",
+                checksum: None,
                 generate: fk::generate,
                 validate: fk::validate,
             },
             RegistryEntry {
                 code: "FM",
                 name: "Business ID",
+                checksum: None,
                 generate: fm::generate,
                 validate: fm::validate,
             },
             RegistryEntry {
                 code: "FO",
                 name: "V-number",
+                checksum: None,
                 generate: fo::generate,
                 validate: fo::validate,
             },
             RegistryEntry {
                 code: "FR",
                 name: "TVA Intracommunautaire",
+                checksum: Some("Luhn"),
                 generate: fr::generate,
                 validate: fr::validate,
             },
             RegistryEntry {
                 code: "GA",
                 name: "Business ID",
+                checksum: None,
                 generate: ga::generate,
                 validate: ga::validate,
             },
             RegistryEntry {
                 code: "GB",
                 name: "VAT Number",
+                checksum: Some("Weighted mod 97"),
                 generate: gb::generate,
                 validate: gb::validate,
             },
             RegistryEntry {
                 code: "GD",
                 name: "Business ID",
+                checksum: None,
                 generate: gd::generate,
                 validate: gd::validate,
             },
             RegistryEntry {
                 code: "GE",
                 name: "Business ID",
+                checksum: None,
                 generate: ge::generate,
                 validate: ge::validate,
             },
             RegistryEntry {
                 code: "GF",
                 name: "Business ID",
+                checksum: None,
                 generate: gf::generate,
                 validate: gf::validate,
             },
             RegistryEntry {
                 code: "GG",
                 name: "Business ID",
+                checksum: None,
                 generate: gg::generate,
                 validate: gg::validate,
             },
             RegistryEntry {
                 code: "GH",
                 name: "TIN",
+                checksum: Some("Weighted mod 11"),
                 generate: gh::generate,
                 validate: gh::validate,
             },
             RegistryEntry {
                 code: "GI",
                 name: "Business ID",
+                checksum: None,
                 generate: gi::generate,
                 validate: gi::validate,
             },
             RegistryEntry {
                 code: "GL",
                 name: "Business ID",
+                checksum: None,
                 generate: gl::generate,
                 validate: gl::validate,
             },
             RegistryEntry {
                 code: "GM",
                 name: "Business ID",
+                checksum: None,
                 generate: gm::generate,
                 validate: gm::validate,
             },
             RegistryEntry {
                 code: "GN",
                 name: "NIFp",
+                checksum: Some("Luhn"),
                 generate: gn::generate,
                 validate: gn::validate,
             },
             RegistryEntry {
                 code: "GP",
                 name: "Business ID",
+                checksum: None,
                 generate: gp::generate,
                 validate: gp::validate,
             },
             RegistryEntry {
                 code: "GQ",
                 name: "Business ID",
+                checksum: None,
                 generate: gq::generate,
                 validate: gq::validate,
             },
             RegistryEntry {
                 code: "GR",
                 name: "AFM",
+                checksum: Some("Weighted mod 11"),
                 generate: gr::generate,
                 validate: gr::validate,
             },
             RegistryEntry {
                 code: "GS",
                 name: "Business ID",
+                checksum: None,
                 generate: gs::generate,
                 validate: gs::validate,
             },
             RegistryEntry {
                 code: "GT",
                 name: "NIT",
+                checksum: Some("Weighted mod 11"),
                 generate: gt::generate,
                 validate: gt::validate,
             },
             RegistryEntry {
                 code: "GU",
                 name: "Business ID",
+                checksum: None,
                 generate: gu::generate,
                 validate: gu::validate,
             },
             RegistryEntry {
                 code: "GW",
                 name: "Business ID",
+                checksum: None,
                 generate: gw::generate,
                 validate: gw::validate,
             },
             RegistryEntry {
                 code: "GY",
                 name: "Business ID",
+                checksum: None,
                 generate: gy::generate,
                 validate: gy::validate,
             },
             RegistryEntry {
                 code: "HK",
                 name: "BR Number",
+                checksum: None,
                 generate: hk::generate,
                 validate: hk::validate,
             },
             RegistryEntry {
                 code: "HM",
                 name: "Business ID",
+                checksum: None,
                 generate: hm::generate,
                 validate: hm::validate,
             },
             RegistryEntry {
                 code: "HN",
                 name: "Business ID",
+                checksum: None,
                 generate: hn::generate,
                 validate: hn::validate,
             },
             RegistryEntry {
                 code: "HR",
                 name: "OIB",
+                checksum: Some("ISO 7064 MOD 11-10"),
                 generate: hr::generate,
                 validate: hr::validate,
             },
             RegistryEntry {
                 code: "HT",
                 name: "Business ID",
+                checksum: None,
                 generate: ht::generate,
                 validate: ht::validate,
             },
             RegistryEntry {
                 code: "HU",
                 name: "ANUM",
+                checksum: Some("Weighted mod 10"),
                 generate: hu::generate,
                 validate: hu::validate,
             },
             RegistryEntry {
                 code: "ID",
                 name: "NPWP",
+                checksum: Some("Luhn"),
                 generate: id::generate,
                 validate: id::validate,
             },
             RegistryEntry {
                 code: "IE",
                 name: "VAT",
+                checksum: None,
                 generate: ie::generate,
                 validate: ie::validate,
             },
             RegistryEntry {
                 code: "IL",
                 name: "Company Number",
+                checksum: Some("Luhn"),
                 generate: il::generate,
                 validate: il::validate,
             },
             RegistryEntry {
                 code: "IM",
                 name: "Business ID",
+                checksum: None,
                 generate: im::generate,
                 validate: im::validate,
             },
             RegistryEntry {
                 code: "IN",
                 name: "GSTIN",
+                checksum: Some("Luhn mod 36"),
                 generate: in_::generate,
                 validate: in_::validate,
             },
             RegistryEntry {
                 code: "IO",
                 name: "Business ID",
+                checksum: None,
                 generate: io::generate,
                 validate: io::validate,
             },
             RegistryEntry {
                 code: "IQ",
                 name: "Business ID",
+                checksum: None,
                 generate: iq::generate,
                 validate: iq::validate,
             },
             RegistryEntry {
                 code: "IR",
                 name: "Business ID",
+                checksum: None,
                 generate: ir::generate,
                 validate: ir::validate,
             },
             RegistryEntry {
                 code: "IS",
                 name: "Business ID",
+                checksum: None,
                 generate: is::generate,
                 validate: is::validate,
             },
             RegistryEntry {
                 code: "IT",
                 name: "Partita IVA",
+                checksum: Some("Luhn"),
                 generate: it::generate,
                 validate: it::validate,
             },
             RegistryEntry {
                 code: "JE",
                 name: "Business ID",
+                checksum: None,
                 generate: je::generate,
                 validate: je::validate,
             },
             RegistryEntry {
                 code: "JM",
                 name: "Business ID",
+                checksum: None,
                 generate: jm::generate,
                 validate: jm::validate,
             },
             RegistryEntry {
                 code: "JO",
                 name: "Business ID",
+                checksum: None,
                 generate: jo::generate,
                 validate: jo::validate,
             },
             RegistryEntry {
                 code: "JP",
                 name: "CN",
+                checksum: Some("Weighted mod 9"),
                 generate: jp::generate,
                 validate: jp::validate,
             },
             RegistryEntry {
                 code: "KE",
                 name: "PIN",
+                checksum: None,
                 generate: ke::generate,
                 validate: ke::validate,
             },
             RegistryEntry {
                 code: "KG",
                 name: "Business ID",
+                checksum: None,
                 generate: kg::generate,
                 validate: kg::validate,
             },
             RegistryEntry {
                 code: "KH",
                 name: "Business ID",
+                checksum: None,
                 generate: kh::generate,
                 validate: kh::validate,
             },
             RegistryEntry {
                 code: "KI",
                 name: "Business ID",
+                checksum: None,
                 generate: ki::generate,
                 validate: ki::validate,
             },
             RegistryEntry {
                 code: "KM",
                 name: "Business ID",
+                checksum: None,
                 generate: km::generate,
                 validate: km::validate,
             },
             RegistryEntry {
                 code: "KN",
                 name: "Business ID",
+                checksum: None,
                 generate: kn::generate,
                 validate: kn::validate,
             },
             RegistryEntry {
                 code: "KP",
                 name: "Business ID",
+                checksum: None,
                 generate: kp::generate,
                 validate: kp::validate,
             },
             RegistryEntry {
                 code: "KR",
                 name: "BRN",
+                checksum: None,
                 generate: kr::generate,
                 validate: kr::validate,
             },
             RegistryEntry {
                 code: "KW",
                 name: "Business ID",
+                checksum: None,
                 generate: kw::generate,
                 validate: kw::validate,
             },
             RegistryEntry {
                 code: "KY",
                 name: "Business ID",
+                checksum: None,
                 generate: ky::generate,
                 validate: ky::validate,
             },
             RegistryEntry {
                 code: "KZ",
                 name: "Business ID",
+                checksum: None,
                 generate: kz::generate,
                 validate: kz::validate,
             },
             RegistryEntry {
                 code: "LA",
                 name: "Business ID",
+                checksum: None,
                 generate: la::generate,
                 validate: la::validate,
             },
             RegistryEntry {
                 code: "LB",
                 name: "Business ID",
+                checksum: None,
                 generate: lb::generate,
                 validate: lb::validate,
             },
             RegistryEntry {
                 code: "LC",
                 name: "Business ID",
+                checksum: None,
                 generate: lc::generate,
                 validate: lc::validate,
             },
             RegistryEntry {
                 code: "LI",
                 name: "PEID",
+                checksum: None,
                 generate: li::generate,
                 validate: li::validate,
             },
             RegistryEntry {
                 code: "LK",
                 name: "Business ID",
+                checksum: None,
                 generate: lk::generate,
                 validate: lk::validate,
             },
             RegistryEntry {
                 code: "LR",
                 name: "Business ID",
+                checksum: None,
                 generate: lr::generate,
                 validate: lr::validate,
             },
             RegistryEntry {
                 code: "LS",
                 name: "Business ID",
+                checksum: None,
                 generate: ls::generate,
                 validate: ls::validate,
             },
             RegistryEntry {
                 code: "LT",
                 name: "PVM",
+                checksum: Some("Weighted mod 11, two passes"),
                 generate: lt::generate,
                 validate: lt::validate,
             },
             RegistryEntry {
                 code: "LU",
                 name: "TVA",
+                checksum: Some("Mod 89"),
                 generate: lu::generate,
                 validate: lu::validate,
             },
             RegistryEntry {
                 code: "LV",
                 name: "PVN",
+                checksum: Some("Weighted mod 11"),
                 generate: lv::generate,
                 validate: lv::validate,
             },
             RegistryEntry {
                 code: "LY",
                 name: "Business ID",
+                checksum: None,
                 generate: ly::generate,
                 validate: ly::validate,
             },
             RegistryEntry {
                 code: "MA",
                 name: "ICE",
+                checksum: Some("Mod 97"),
                 generate: ma::generate,
                 validate: ma::validate,
             },
             RegistryEntry {
                 code: "MC",
                 name: "TVA",
+                checksum: Some("Luhn"),
                 generate: mc::generate,
                 validate: mc::validate,
             },
             RegistryEntry {
                 code: "MD",
                 name: "IDNO",
+                checksum: Some("Weighted mod 10"),
                 generate: md::generate,
                 validate: md::validate,
             },
             RegistryEntry {
                 code: "ME",
                 name: "PIB",
+                checksum: Some("Weighted mod 11"),
                 generate: me::generate,
                 validate: me::validate,
             },
             RegistryEntry {
                 code: "MF",
                 name: "Business ID",
+                checksum: None,
                 generate: mf::generate,
                 validate: mf::validate,
             },
             RegistryEntry {
                 code: "MG",
                 name: "Business ID",
+                checksum: None,
                 generate: mg::generate,
                 validate: mg::validate,
             },
             RegistryEntry {
                 code: "MH",
                 name: "Business ID",
+                checksum: None,
                 generate: mh::generate,
                 validate: mh::validate,
             },
             RegistryEntry {
                 code: "MK",
                 name: "EDB",
+                checksum: Some("Weighted mod 11"),
                 generate: mk::generate,
                 validate: mk::validate,
             },
             RegistryEntry {
                 code: "ML",
                 name: "Business ID",
+                checksum: None,
                 generate: ml::generate,
                 validate: ml::validate,
             },
             RegistryEntry {
                 code: "MM",
                 name: "Business ID",
+                checksum: None,
                 generate: mm::generate,
                 validate: mm::validate,
             },
             RegistryEntry {
                 code: "MN",
                 name: "Business ID",
+                checksum: None,
                 generate: mn::generate,
                 validate: mn::validate,
             },
             RegistryEntry {
                 code: "MO",
                 name: "Business ID",
+                checksum: None,
                 generate: mo::generate,
                 validate: mo::validate,
             },
             RegistryEntry {
                 code: "MP",
                 name: "Business ID",
+                checksum: None,
                 generate: mp::generate,
                 validate: mp::validate,
             },
             RegistryEntry {
                 code: "MQ",
                 name: "Business ID",
+                checksum: None,
                 generate: mq::generate,
                 validate: mq::validate,
             },
             RegistryEntry {
                 code: "MR",
                 name: "Business ID",
+                checksum: None,
                 generate: mr::generate,
                 validate: mr::validate,
             },
             RegistryEntry {
                 code: "MS",
                 name: "Business ID",
+                checksum: None,
                 generate: ms::generate,
                 validate: ms::validate,
             },
             RegistryEntry {
                 code: "MT",
                 name: "VAT",
+                checksum: Some("Weighted mod 37"),
                 generate: mt::generate,
                 validate: mt::validate,
             },
             RegistryEntry {
                 code: "MU",
                 name: "NID",
+                checksum: Some("Weighted mod 17"),
                 generate: mu::generate,
                 validate: mu::validate,
             },
             RegistryEntry {
                 code: "MV",
                 name: "Business ID",
+                checksum: None,
                 generate: mv::generate,
                 validate: mv::validate,
             },
             RegistryEntry {
                 code: "MW",
                 name: "Business ID",
+                checksum: None,
                 generate: mw::generate,
                 validate: mw::validate,
             },
             RegistryEntry {
                 code: "MX",
                 name: "RFC",
+                checksum: None,
                 generate: mx::generate,
                 validate: mx::validate,
             },
             RegistryEntry {
                 code: "MY",
                 name: "Business Reg",
+                checksum: None,
                 generate: my::generate,
                 validate: my::validate,
             },
             RegistryEntry {
                 code: "MZ",
                 name: "NUIT",
+                checksum: Some("Weighted mod 11"),
                 generate: mz::generate,
                 validate: mz::validate,
             },
             RegistryEntry {
                 code: "NA",
                 name: "Business ID",
+                checksum: None,
                 generate: na::generate,
                 validate: na::validate,
             },
             RegistryEntry {
                 code: "NC",
                 name: "Business ID",
+                checksum: None,
                 generate: nc::generate,
                 validate: nc::validate,
             },
             RegistryEntry {
                 code: "NE",
                 name: "Business ID",
+                checksum: None,
                 generate: ne::generate,
                 validate: ne::validate,
             },
             RegistryEntry {
                 code: "NF",
                 name: "Business ID",
+                checksum: None,
                 generate: nf::generate,
                 validate: nf::validate,
             },
             RegistryEntry {
                 code: "NG",
                 name: "TIN",
+                checksum: None,
                 generate: ng::generate,
                 validate: ng::validate,
             },
             RegistryEntry {
                 code: "NI",
                 name: "Business ID",
+                checksum: None,
                 generate: ni::generate,
                 validate: ni::validate,
             },
             RegistryEntry {
                 code: "NL",
                 name: "BTW",
+                checksum: Some("Weighted mod 11"),
                 generate: nl::generate,
                 validate: nl::validate,
             },
             RegistryEntry {
                 code: "NO",
                 name: "MVA",
+                checksum: Some("Weighted mod 11"),
                 generate: no::generate,
                 validate: no::validate,
             },
             RegistryEntry {
                 code: "NP",
                 name: "Business ID",
+                checksum: None,
                 generate: np::generate,
                 validate: np::validate,
             },
             RegistryEntry {
                 code: "NR",
                 name: "Business ID",
+                checksum: None,
                 generate: nr::generate,
                 validate: nr::validate,
             },
             RegistryEntry {
                 code: "NU",
                 name: "Business ID",
+                checksum: None,
                 generate: nu::generate,
                 validate: nu::validate,
             },
             RegistryEntry {
                 code: "NZ",
                 name: "IRD",
+                checksum: None,
                 generate: nz::generate,
                 validate: nz::validate,
             },
             RegistryEntry {
                 code: "OM",
                 name: "Business ID",
+                checksum: None,
                 generate: om::generate,
                 validate: om::validate,
             },
             RegistryEntry {
                 code: "PA",
                 name: "Business ID",
+                checksum: None,
                 generate: pa::generate,
                 validate: pa::validate,
             },
             RegistryEntry {
                 code: "PE",
                 name: "RUC",
+                checksum: Some("Weighted mod 11"),
                 generate: pe::generate,
                 validate: pe::validate,
             },
             RegistryEntry {
                 code: "PF",
                 name: "Business ID",
+                checksum: None,
                 generate: pf::generate,
                 validate: pf::validate,
             },
             RegistryEntry {
                 code: "PG",
                 name: "Business ID",
+                checksum: None,
                 generate: pg::generate,
                 validate: pg::validate,
             },
             RegistryEntry {
                 code: "PH",
                 name: "TIN",
+                checksum: None,
                 generate: ph::generate,
                 validate: ph::validate,
             },
             RegistryEntry {
                 code: "PK",
                 name: "CNIC",
+                checksum: None,
                 generate: pk::generate,
                 validate: pk::validate,
             },
             RegistryEntry {
                 code: "PL",
                 name: "NIP",
+                checksum: Some("Weighted mod 11"),
                 generate: pl::generate,
                 validate: pl::validate,
             },
             RegistryEntry {
                 code: "PM",
                 name: "Business ID",
+                checksum: None,
                 generate: pm::generate,
                 validate: pm::validate,
             },
             RegistryEntry {
                 code: "PN",
                 name: "Business ID",
+                checksum: None,
                 generate: pn::generate,
                 validate: pn::validate,
             },
             RegistryEntry {
                 code: "PR",
                 name: "Business ID",
+                checksum: None,
                 generate: pr::generate,
                 validate: pr::validate,
             },
             RegistryEntry {
                 code: "PS",
                 name: "Business ID",
+                checksum: None,
                 generate: ps::generate,
                 validate: ps::validate,
             },
             RegistryEntry {
                 code: "PT",
                 name: "NIF",
+                checksum: Some("Weighted mod 11"),
                 generate: pt::generate,
                 validate: pt::validate,
             },
             RegistryEntry {
                 code: "PW",
                 name: "Business ID",
+                checksum: None,
                 generate: pw::generate,
                 validate: pw::validate,
             },
             RegistryEntry {
                 code: "PY",
                 name: "RUC",
+                checksum: Some("Weighted mod 11"),
                 generate: py::generate,
                 validate: py::validate,
             },
             RegistryEntry {
                 code: "QA",
                 name: "Business ID",
+                checksum: None,
                 generate: qa::generate,
                 validate: qa::validate,
             },
             RegistryEntry {
                 code: "RE",
                 name: "Business ID",
+                checksum: None,
                 generate: re::generate,
                 validate: re::validate,
             },
             RegistryEntry {
                 code: "RO",
                 name: "CUI",
+                checksum: Some("Weighted mod 11"),
                 generate: ro::generate,
                 validate: ro::validate,
             },
             RegistryEntry {
                 code: "RS",
                 name: "PIB",
+                checksum: Some("ISO 7064 MOD 11-10"),
                 generate: rs::generate,
                 validate: rs::validate,
             },
             RegistryEntry {
                 code: "RU",
                 name: "INN",
+                checksum: Some("Mod 11 or mod 13, by length"),
                 generate: ru::generate,
                 validate: ru::validate,
             },
             RegistryEntry {
                 code: "RW",
                 name: "Business ID",
+                checksum: None,
                 generate: rw::generate,
                 validate: rw::validate,
             },
             RegistryEntry {
                 code: "SA",
                 name: "VAT",
+                checksum: Some("Luhn"),
                 generate: sa::generate,
                 validate: sa::validate,
             },
             RegistryEntry {
                 code: "SB",
                 name: "Business ID",
+                checksum: None,
                 generate: sb::generate,
                 validate: sb::validate,
             },
             RegistryEntry {
                 code: "SC",
                 name: "Business ID",
+                checksum: None,
                 generate: sc::generate,
                 validate: sc::validate,
             },
             RegistryEntry {
                 code: "SD",
                 name: "Business ID",
+                checksum: None,
                 generate: sd::generate,
                 validate: sd::validate,
             },
             RegistryEntry {
                 code: "SE",
                 name: "VAT",
+                checksum: Some("Luhn"),
                 generate: se::generate,
                 validate: se::validate,
             },
             RegistryEntry {
                 code: "SG",
                 name: "UEN",
+                checksum: Some("Weighted mod 11 letter"),
                 generate: sg::generate,
                 validate: sg::validate,
             },
             RegistryEntry {
                 code: "SH",
                 name: "Business ID",
+                checksum: None,
                 generate: sh::generate,
                 validate: sh::validate,
             },
             RegistryEntry {
                 code: "SI",
                 name: "DDV",
+                checksum: Some("Weighted mod 11"),
                 generate: si::generate,
                 validate: si::validate,
             },
             RegistryEntry {
                 code: "SJ",
                 name: "Business ID",
+                checksum: None,
                 generate: sj::generate,
                 validate: sj::validate,
             },
             RegistryEntry {
                 code: "SK",
-                name: "IC DPH",
+                name: "IČ DPH",
+                checksum: Some("Mod 11"),
                 generate: sk::generate,
                 validate: sk::validate,
             },
             RegistryEntry {
                 code: "SL",
                 name: "Business ID",
+                checksum: None,
                 generate: sl::generate,
                 validate: sl::validate,
             },
             RegistryEntry {
                 code: "SM",
                 name: "COE",
+                checksum: None,
                 generate: sm::generate,
                 validate: sm::validate,
             },
             RegistryEntry {
                 code: "SN",
                 name: "NINEA",
+                checksum: Some("Weighted mod 10"),
                 generate: sn::generate,
                 validate: sn::validate,
             },
             RegistryEntry {
                 code: "SO",
                 name: "Business ID",
+                checksum: None,
                 generate: so::generate,
                 validate: so::validate,
             },
             RegistryEntry {
                 code: "SR",
                 name: "Business ID",
+                checksum: None,
                 generate: sr::generate,
                 validate: sr::validate,
             },
             RegistryEntry {
                 code: "SS",
                 name: "Business ID",
+                checksum: None,
                 generate: ss::generate,
                 validate: ss::validate,
             },
             RegistryEntry {
                 code: "ST",
                 name: "Business ID",
+                checksum: None,
                 generate: st::generate,
                 validate: st::validate,
             },
             RegistryEntry {
                 code: "SV",
                 name: "NIT",
+                checksum: Some("Weighted mod 11"),
                 generate: sv::generate,
                 validate: sv::validate,
             },
             RegistryEntry {
                 code: "SX",
                 name: "Business ID",
+                checksum: None,
                 generate: sx::generate,
                 validate: sx::validate,
             },
             RegistryEntry {
                 code: "SY",
                 name: "Business ID",
+                checksum: None,
                 generate: sy::generate,
                 validate: sy::validate,
             },
             RegistryEntry {
                 code: "SZ",
                 name: "Business ID",
+                checksum: None,
                 generate: sz::generate,
                 validate: sz::validate,
             },
             RegistryEntry {
                 code: "TC",
                 name: "Business ID",
+                checksum: None,
                 generate: tc::generate,
                 validate: tc::validate,
             },
             RegistryEntry {
                 code: "TD",
                 name: "Business ID",
+                checksum: None,
                 generate: td::generate,
                 validate: td::validate,
             },
             RegistryEntry {
                 code: "TF",
                 name: "Business ID",
+                checksum: None,
                 generate: tf::generate,
                 validate: tf::validate,
             },
             RegistryEntry {
                 code: "TG",
                 name: "Business ID",
+                checksum: None,
                 generate: tg::generate,
                 validate: tg::validate,
             },
             RegistryEntry {
                 code: "TH",
                 name: "Tax ID",
+                checksum: Some("Weighted mod 11"),
                 generate: th::generate,
                 validate: th::validate,
             },
             RegistryEntry {
                 code: "TJ",
                 name: "Business ID",
+                checksum: None,
                 generate: tj::generate,
                 validate: tj::validate,
             },
             RegistryEntry {
                 code: "TK",
                 name: "Business ID",
+                checksum: None,
                 generate: tk::generate,
                 validate: tk::validate,
             },
             RegistryEntry {
                 code: "TL",
                 name: "Business ID",
+                checksum: None,
                 generate: tl::generate,
                 validate: tl::validate,
             },
             RegistryEntry {
                 code: "TM",
                 name: "Business ID",
+                checksum: None,
                 generate: tm::generate,
                 validate: tm::validate,
             },
             RegistryEntry {
                 code: "TN",
                 name: "MF",
+                checksum: None,
                 generate: tn::generate,
                 validate: tn::validate,
             },
             RegistryEntry {
                 code: "TO",
                 name: "Business ID",
+                checksum: None,
                 generate: to::generate,
                 validate: to::validate,
             },
             RegistryEntry {
                 code: "TR",
                 name: "VKN",
+                checksum: Some("Weighted mod 10"),
                 generate: tr::generate,
                 validate: tr::validate,
             },
             RegistryEntry {
                 code: "TT",
                 name: "Business ID",
+                checksum: None,
                 generate: tt::generate,
                 validate: tt::validate,
             },
             RegistryEntry {
                 code: "TV",
                 name: "Business ID",
+                checksum: None,
                 generate: tv::generate,
                 validate: tv::validate,
             },
             RegistryEntry {
                 code: "TW",
                 name: "UBN",
+                checksum: Some("Weighted mod 10"),
                 generate: tw::generate,
                 validate: tw::validate,
             },
             RegistryEntry {
                 code: "TZ",
                 name: "Business ID",
+                checksum: None,
                 generate: tz::generate,
                 validate: tz::validate,
             },
             RegistryEntry {
                 code: "UA",
                 name: "EDRPOU",
+                checksum: Some("Weighted mod 11, two passes"),
                 generate: ua::generate,
                 validate: ua::validate,
             },
             RegistryEntry {
                 code: "UG",
                 name: "Business ID",
+                checksum: None,
                 generate: ug::generate,
                 validate: ug::validate,
             },
             RegistryEntry {
                 code: "UM",
                 name: "Business ID",
+                checksum: None,
                 generate: um::generate,
                 validate: um::validate,
             },
             RegistryEntry {
                 code: "US",
                 name: "EIN",
+                checksum: None,
                 generate: us::generate,
                 validate: us::validate,
             },
             RegistryEntry {
                 code: "UY",
                 name: "RUT",
+                checksum: Some("Weighted mod 11"),
                 generate: uy::generate,
                 validate: uy::validate,
             },
             RegistryEntry {
                 code: "UZ",
                 name: "Business ID",
+                checksum: None,
                 generate: uz::generate,
                 validate: uz::validate,
             },
             RegistryEntry {
                 code: "VA",
                 name: "Business ID",
+                checksum: None,
                 generate: va::generate,
                 validate: va::validate,
             },
             RegistryEntry {
                 code: "VC",
                 name: "Business ID",
+                checksum: None,
                 generate: vc::generate,
                 validate: vc::validate,
             },
             RegistryEntry {
                 code: "VE",
                 name: "RIF",
+                checksum: Some("Weighted mod 11"),
                 generate: ve::generate,
                 validate: ve::validate,
             },
             RegistryEntry {
                 code: "VG",
                 name: "Business ID",
+                checksum: None,
                 generate: vg::generate,
                 validate: vg::validate,
             },
             RegistryEntry {
                 code: "VI",
                 name: "Business ID",
+                checksum: None,
                 generate: vi::generate,
                 validate: vi::validate,
             },
             RegistryEntry {
                 code: "VN",
                 name: "MST",
+                checksum: Some("Weighted mod 11"),
                 generate: vn::generate,
                 validate: vn::validate,
             },
             RegistryEntry {
                 code: "VU",
                 name: "Business ID",
+                checksum: None,
                 generate: vu::generate,
                 validate: vu::validate,
             },
             RegistryEntry {
                 code: "WF",
                 name: "Business ID",
+                checksum: None,
                 generate: wf::generate,
                 validate: wf::validate,
             },
             RegistryEntry {
                 code: "WS",
                 name: "Business ID",
+                checksum: None,
                 generate: ws::generate,
                 validate: ws::validate,
             },
             RegistryEntry {
                 code: "XK",
                 name: "Business ID",
+                checksum: None,
                 generate: xk::generate,
                 validate: xk::validate,
             },
             RegistryEntry {
                 code: "YE",
                 name: "Business ID",
+                checksum: None,
                 generate: ye::generate,
                 validate: ye::validate,
             },
             RegistryEntry {
                 code: "YT",
                 name: "Business ID",
+                checksum: None,
                 generate: yt::generate,
                 validate: yt::validate,
             },
             RegistryEntry {
                 code: "ZA",
                 name: "VAT",
+                checksum: Some("Luhn"),
                 generate: za::generate,
                 validate: za::validate,
             },
             RegistryEntry {
                 code: "ZM",
                 name: "Business ID",
+                checksum: None,
                 generate: zm::generate,
                 validate: zm::validate,
             },
             RegistryEntry {
                 code: "ZW",
                 name: "Business ID",
+                checksum: None,
                 generate: zw::generate,
                 validate: zw::validate,
             },
         ];
-        Self { entries }
+        Self {
+            entries,
+            custom: RwLock::new(Vec::new()),
+        }
     }
 
     fn find(&self, country: &str) -> Option<&RegistryEntry> {
         self.entries.iter().find(|e| e.code == country)
     }
 
-    pub fn generate(
+    fn find_custom(&self, country: &str) -> Option<CustomEntry> {
+        let custom = self.custom.read().unwrap_or_else(|e| e.into_inner());
+        custom
+            .iter()
+            .find(|e| e.code.eq_ignore_ascii_case(country))
+            .cloned()
+    }
+
+    /// Add a scheme for a country (or any custom code such as `"ACME"`)
+    /// that has no company ID yet.
+    ///
+    /// Fails if the code is already supported; use
+    /// [`override_scheme`](Self::override_scheme) to replace a built-in rule.
+    pub fn register(&self, country: &str, scheme: impl IdScheme + 'static) -> Result<(), String> {
+        let key = crate::scheme::registration_key(country)?;
+        if self.find_custom(&key).is_some() || self.find(&key).is_some() {
+            return Err(format!(
+                "{} already has a company ID scheme; use override_scheme to replace it",
+                key
+            ));
+        }
+        self.override_scheme(&key, scheme)
+    }
+
+    /// Add a scheme for a country, replacing any built-in or previously
+    /// registered one.
+    pub fn override_scheme(
         &self,
-        opts: &GenOptions,
-        rng: &mut rand::rngs::ThreadRng,
-    ) -> Option<CompanyResult> {
+        country: &str,
+        scheme: impl IdScheme + 'static,
+    ) -> Result<(), String> {
+        let key = crate::scheme::registration_key(country)?;
+        let mut custom = self.custom.write().unwrap_or_else(|e| e.into_inner());
+        let entry = CustomEntry {
+            code: key.into(),
+            name: scheme.name().into(),
+            scheme: Arc::new(scheme),
+        };
+        custom.retain(|e| e.code != entry.code);
+        custom.push(entry);
+        Ok(())
+    }
+
+    pub fn generate(&self, opts: &GenOptions, rng: &mut impl Rng) -> Option<CompanyResult> {
         let country = opts
             .country
             .as_deref()
+            .map(str::to_uppercase)
             .unwrap_or_else(|| {
-                let countries = self.list_countries();
-                countries[rng.gen_range(0..countries.len())].0
-            })
-            .to_uppercase();
-
-        if !crate::countries::is_supported(&country) {
-            return None;
-        }
+                let mut countries = self.list_countries();
+                countries.swap_remove(rng.gen_range(0..countries.len())).0
+            });
 
-        if let Some(entry) = self.find(&country) {
+        if let Some(entry) = self.find_custom(&country) {
+            let mut rng = StdRng::from_rng(rng).ok()?;
             return Some(CompanyResult {
                 country_code: entry.code.to_string(),
+                effective_country_code: entry.code.to_string(),
                 country_name: crate::countries::get_country_name(&country)
                     .unwrap_or("Unknown")
                     .to_string(),
                 name: entry.name.to_string(),
-                code: (entry.generate)(rng),
+                code: entry.scheme.try_generate(&Default::default(), &mut rng)?,
                 valid: true,
             });
         }
 
-        // Fallback for territories mapped to parent countries but requested directly
-        // (Though resolved aliases usually handled by caller or generic fallback in past)
-        // With full 250 modules, we expect find() to succeed for all valid codes.
+        if !crate::countries::is_supported(&country) {
+            return None;
+        }
+
+        let effective = crate::countries::effective_country_code(&country, opts.strict, |c| {
+            self.find(c).is_some()
+        })?;
+        let entry = self.find(&effective)?;
+        let mut rng = StdRng::from_rng(rng).ok()?;
+        Some(CompanyResult {
+            country_code: country.clone(),
+            effective_country_code: effective,
+            country_name: crate::countries::get_country_name(&country)
+                .unwrap_or("Unknown")
+                .to_string(),
+            name: entry.name.to_string(),
+            code: (entry.generate)(&mut rng),
+            valid: true,
+        })
+    }
 
-        None
+    /// Country whose format applies to `country`: the country itself, or
+    /// the parent for a territory without its own.
+    pub fn effective_country(&self, country: &str) -> Option<String> {
+        if let Some(entry) = self.find_custom(country) {
+            return Some(entry.code.to_string());
+        }
+        if !crate::countries::is_supported(country) {
+            return None;
+        }
+        crate::countries::effective_country_code(country, false, |c| self.find(c).is_some())
     }
 
     pub fn validate(&self, country: &str, code: &str) -> bool {
         let country = country.to_uppercase();
+        if let Some(entry) = self.find_custom(&country) {
+            return entry.scheme.validate(code);
+        }
         if !crate::countries::is_supported(&country) {
             return false;
         }
-        if let Some(entry) = self.find(&country) {
-            return (entry.validate)(code);
+        crate::countries::effective_country_code(&country, false, |c| self.find(c).is_some())
+            .and_then(|effective| self.find(&effective))
+            .is_some_and(|entry| (entry.validate)(code))
+    }
+
+    /// Name of the check algorithm of the country's company ID (e.g.
+    /// `"Luhn"`), or `None` if it has none or a registered scheme does not
+    /// declare it.
+    pub fn checksum(&self, country: &str) -> Option<String> {
+        let country = country.to_uppercase();
+        if let Some(entry) = self.find_custom(&country) {
+            return entry.scheme.checksum();
+        }
+        if !crate::countries::is_supported(&country) {
+            return None;
         }
-        false
+        crate::countries::effective_country_code(&country, false, |c| self.find(c).is_some())
+            .and_then(|effective| self.find(&effective))
+            .and_then(|entry| entry.checksum)
+            .map(str::to_string)
     }
 
-    pub fn list_countries(&self) -> Vec<(&'static str, &'static str, &'static str)> {
+    /// `(code, country name, company ID name)` for every supported
+    /// country, including registered schemes.
+    pub fn list_countries(&self) -> Vec<(String, String, String)> {
+        let row = |code: &str, name: &str| {
+            (
+                code.to_string(),
+                crate::countries::get_country_name(code)
+                    .unwrap_or("Unknown")
+                    .to_string(),
+                name.to_string(),
+            )
+        };
         let mut result = Vec::new();
-        for entry in &self.entries {
-            result.push((
-                entry.code,
-                crate::countries::get_country_name(entry.code).unwrap_or("Unknown"),
-                entry.name,
-            ));
+        let custom = self.custom.read().unwrap_or_else(|e| e.into_inner());
+        for entry in custom.iter() {
+            result.push(row(&entry.code, &entry.name));
+        }
+        for entry in self
+            .entries
+            .iter()
+            .filter(|e| !custom.iter().any(|c| &*c.code == e.code))
+        {
+            result.push(row(entry.code, entry.name));
         }
-        result.sort_by_key(|(code, _, _)| *code);
+        result.sort_by(|a, b| a.0.cmp(&b.0));
         result
     }
+
+    /// Like [`list_countries`](Self::list_countries), with country and
+    /// company ID names in `locale`; registered schemes keep their own name.
+    pub fn list_countries_in(&self, locale: Locale) -> Vec<(String, String, String)> {
+        self.list_countries()
+            .into_iter()
+            .map(|(code, _, name)| {
+                let localized = self
+                    .find_custom(&code)
+                    .is_none()
+                    .then(|| crate::locale::document_name(IdKind::CompanyId, &code, locale))
+                    .flatten();
+                let country_name = crate::locale::country_label(&code, locale).to_string();
+                let name = localized.map_or(name, str::to_string);
+                (code, country_name, name)
+            })
+            .collect()
+    }
 }
@@ -1,6 +1,6 @@
 use rand::Rng;
 
-pub fn generate(rng: &mut rand::rngs::ThreadRng) -> String {
+pub fn generate(rng: &mut impl Rng) -> String {
     loop {
         let digits: Vec<u8> = (0..8).map(|_| rng.gen_range(0..=9)).collect();
         if digits[0] == 0 {
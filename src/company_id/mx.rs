@@ -1,6 +1,6 @@
 use rand::Rng;
 
-pub fn generate(rng: &mut rand::rngs::ThreadRng) -> String {
+pub fn generate(rng: &mut impl Rng) -> String {
     let mut code = String::new();
     for _ in 0..3 {
         code.push((b'A' + rng.gen_range(0..26)) as char);
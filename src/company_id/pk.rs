@@ -1,6 +1,6 @@
 use rand::Rng;
 
-pub fn generate(rng: &mut rand::rngs::ThreadRng) -> String {
+pub fn generate(rng: &mut impl Rng) -> String {
     let province = ["1", "2", "3", "4", "5", "6", "7"][rng.gen_range(0..7)];
     let mut s = province.to_string();
     for _ in 0..11 {
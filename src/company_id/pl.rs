@@ -2,7 +2,7 @@ use rand::Rng;
 
 /// REGON (Polish register of economic units) - 9 digits.
 /// stdnum: weights (8,9,2,3,4,5,6,7), check = sum % 11 % 10.
-pub fn generate(rng: &mut rand::rngs::ThreadRng) -> String {
+pub fn generate(rng: &mut impl Rng) -> String {
     let digits: Vec<u8> = (0..8).map(|_| rng.gen_range(0..=9)).collect();
     let weights = [8u32, 9, 2, 3, 4, 5, 6, 7];
     let sum: u32 = digits
@@ -3,7 +3,7 @@ use rand::Rng;
 /// CUI/CIF (Romanian company identifier) - 2 to 10 digits, first != 0.
 /// stdnum: weights (7,5,3,2,1,7,5,3,2), number zero-padded to 9 digits,
 /// check = (10 * sum) % 11 % 10.
-pub fn generate(rng: &mut rand::rngs::ThreadRng) -> String {
+pub fn generate(rng: &mut impl Rng) -> String {
     let len = rng.gen_range(2..=9);
     let mut body: Vec<u8> = (0..len).map(|_| rng.gen_range(0..=9)).collect();
     if body[0] == 0 {
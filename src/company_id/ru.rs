@@ -3,7 +3,7 @@ use rand::Rng;
 /// OGRN (Russian Primary State Registration Number).
 /// 13-digit: first digit != 0, check = int(first_12) % 11 % 10.
 /// 15-digit (OGRNIP): first digit 3 or 4, check = int(first_14) % 13 % 10.
-pub fn generate(rng: &mut rand::rngs::ThreadRng) -> String {
+pub fn generate(rng: &mut impl Rng) -> String {
     if rng.gen_bool(0.5) {
         generate_13(rng)
     } else {
@@ -11,7 +11,7 @@ pub fn generate(rng: &mut rand::rngs::ThreadRng) -> String {
     }
 }
 
-fn generate_13(rng: &mut rand::rngs::ThreadRng) -> String {
+fn generate_13(rng: &mut impl Rng) -> String {
     let mut digits: Vec<u8> = vec![rng.gen_range(1..=9)];
     for _ in 1..12 {
         digits.push(rng.gen_range(0..=9));
@@ -22,7 +22,7 @@ fn generate_13(rng: &mut rand::rngs::ThreadRng) -> String {
     digits.iter().map(|d| (b'0' + d) as char).collect()
 }
 
-fn generate_15(rng: &mut rand::rngs::ThreadRng) -> String {
+fn generate_15(rng: &mut impl Rng) -> String {
     let first = if rng.gen_bool(0.5) { 3u8 } else { 4 };
     let mut digits: Vec<u8> = vec![first];
     for _ in 1..14 {
@@ -3,7 +3,7 @@ use rand::Rng;
 
 /// Orgnr (Swedish company number) - 10-digit Luhn.
 /// Output as SE{orgnr}01 for VAT format.
-pub fn generate(rng: &mut rand::rngs::ThreadRng) -> String {
+pub fn generate(rng: &mut impl Rng) -> String {
     loop {
         let mut digits: Vec<u8> = vec![rng.gen_range(1..=9)];
         for _ in 1..9 {
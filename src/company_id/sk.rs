@@ -1,6 +1,6 @@
 use rand::Rng;
 
-pub fn generate(rng: &mut rand::rngs::ThreadRng) -> String {
+pub fn generate(rng: &mut impl Rng) -> String {
     let valid_d3 = [2, 3, 4, 7, 8, 9];
     loop {
         let mut digits: Vec<u8> = Vec::new();
@@ -1,6 +1,6 @@
 use rand::Rng;
 
-pub fn generate(rng: &mut rand::rngs::ThreadRng) -> String {
+pub fn generate(rng: &mut impl Rng) -> String {
     loop {
         let len = if rng.gen_bool(0.5) { 7 } else { 9 };
         let digits: Vec<u8> = (0..len).map(|_| rng.gen_range(0..=9)).collect();
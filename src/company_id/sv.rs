@@ -1,6 +1,6 @@
 use rand::Rng;
 
-pub fn generate(rng: &mut rand::rngs::ThreadRng) -> String {
+pub fn generate(rng: &mut impl Rng) -> String {
     let mut digits: Vec<u8> = (0..13).map(|_| rng.gen_range(0..=9)).collect();
     digits[0] = *["0", "1", "9"][rng.gen_range(0..3)]
         .as_bytes()
@@ -1,6 +1,6 @@
 use rand::Rng;
 
-pub fn generate(rng: &mut rand::rngs::ThreadRng) -> String {
+pub fn generate(rng: &mut impl Rng) -> String {
     let mut s = format!("{:07}", rng.gen_range(1..10000000));
     let alphabet = "ABCDEFGHJKLMNPQRSTUVWXYZ"; // simplified, exclusion list handled by random
     s.push(
@@ -1,6 +1,6 @@
 use rand::Rng;
 
-pub fn generate(rng: &mut rand::rngs::ThreadRng) -> String {
+pub fn generate(rng: &mut impl Rng) -> String {
     let mut digits: Vec<u8> = (0..7).map(|_| rng.gen_range(0..=9)).collect();
     let mut weights = [1, 2, 3, 4, 5, 6, 7];
     if digits[0] >= 3 && digits[0] <= 5 {
@@ -1,6 +1,6 @@
 use rand::Rng;
 
-pub fn generate(rng: &mut rand::rngs::ThreadRng) -> String {
+pub fn generate(rng: &mut impl Rng) -> String {
     let prefixes = [
         "10", "12", "20", "22", "30", "33", "35", "36", "40", "45", "55", "60", "90",
     ];
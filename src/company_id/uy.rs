@@ -1,6 +1,6 @@
 use rand::Rng;
 
-pub fn generate(rng: &mut rand::rngs::ThreadRng) -> String {
+pub fn generate(rng: &mut impl Rng) -> String {
     let mut digits: Vec<u8> = vec![rng.gen_range(1..=21)]; // simplified first two
     digits.push(rng.gen_range(0..=9)); // part of first two
                                        // wait first two is 01-22
@@ -0,0 +1,2635 @@
+//! Centralized registry of ISO 3166-1 alpha-2 country codes and names.
+//! This list covers 250+ countries and territories supported by the library,
+//! with their ISO alpha-3 and numeric codes, currency, calling code and
+//! EU/EEA/SEPA/Eurozone membership.
+//!
+//! ```rust
+//! use idsmith::countries::{self, Membership};
+//!
+//! let ee = countries::get("EE").unwrap();
+//! assert_eq!((ee.alpha3, ee.numeric, ee.currency), ("EST", Some(233), Some("EUR")));
+//! assert!(ee.is_member(Membership::Eurozone));
+//!
+//! assert_eq!(countries::by_alpha3("deu").unwrap().code, "DE");
+//! assert_eq!(countries::by_name("united kingdom").unwrap().code, "GB");
+//! assert!(countries::members(Membership::Sepa).any(|c| c.code == "CH"));
+//! ```
+
+pub mod subdivisions;
+
+use std::fmt;
+use std::str::FromStr;
+
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use Membership::{Eea, Eu, Eurozone, Sepa};
+
+/// Groups of countries that matter for payments and tax identifiers.
+///
+/// Only sovereign states are members of the EU, EEA and Eurozone here; EU
+/// outermost regions such as French Guiana resolve to their parent through
+/// [`TERRITORY_ALIASES`]. SEPA also lists the territories inside its
+/// geographical scope.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Membership {
+    /// European Union.
+    Eu,
+    /// European Economic Area: the EU plus Iceland, Liechtenstein and Norway.
+    Eea,
+    /// Single Euro Payments Area.
+    Sepa,
+    /// Countries using the euro as EU members.
+    Eurozone,
+}
+
+impl Membership {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Membership::Eu => "eu",
+            Membership::Eea => "eea",
+            Membership::Sepa => "sepa",
+            Membership::Eurozone => "eurozone",
+        }
+    }
+}
+
+impl fmt::Display for Membership {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for Membership {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "eu" => Ok(Membership::Eu),
+            "eea" => Ok(Membership::Eea),
+            "sepa" => Ok(Membership::Sepa),
+            "eurozone" | "euro" => Ok(Membership::Eurozone),
+            other => Err(format!(
+                "Unknown country group: {}. Use eu, eea, sepa, or eurozone.",
+                other
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CountryInfo {
+    /// ISO 3166-1 alpha-2 code.
+    pub code: &'static str,
+    pub name: &'static str,
+    /// ISO 3166-1 alpha-3 code (`XKX` for Kosovo, which has no ISO code).
+    pub alpha3: &'static str,
+    /// ISO 3166-1 numeric code; `None` for Kosovo.
+    pub numeric: Option<u16>,
+    /// ISO 4217 code of the currency in use; `None` for Antarctica.
+    pub currency: Option<&'static str>,
+    /// E.164 country calling code, with a leading `+`.
+    pub calling_code: &'static str,
+    pub memberships: &'static [Membership],
+}
+
+impl CountryInfo {
+    pub fn is_member(&self, group: Membership) -> bool {
+        self.memberships.contains(&group)
+    }
+}
+
+/// A comprehensive list of all supported ISO 3166-1 alpha-2 country codes and names.
+pub const ALL_COUNTRIES: &[CountryInfo] = &[
+    CountryInfo {
+        code: "AD",
+        name: "Andorra",
+        alpha3: "AND",
+        numeric: Some(20),
+        currency: Some("EUR"),
+        calling_code: "+376",
+        memberships: &[Sepa],
+    },
+    CountryInfo {
+        code: "AE",
+        name: "United Arab Emirates",
+        alpha3: "ARE",
+        numeric: Some(784),
+        currency: Some("AED"),
+        calling_code: "+971",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "AF",
+        name: "Afghanistan",
+        alpha3: "AFG",
+        numeric: Some(4),
+        currency: Some("AFN"),
+        calling_code: "+93",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "AG",
+        name: "Antigua and Barbuda",
+        alpha3: "ATG",
+        numeric: Some(28),
+        currency: Some("XCD"),
+        calling_code: "+1",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "AI",
+        name: "Anguilla",
+        alpha3: "AIA",
+        numeric: Some(660),
+        currency: Some("XCD"),
+        calling_code: "+1",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "AL",
+        name: "Albania",
+        alpha3: "ALB",
+        numeric: Some(8),
+        currency: Some("ALL"),
+        calling_code: "+355",
+        memberships: &[Sepa],
+    },
+    CountryInfo {
+        code: "AM",
+        name: "Armenia",
+        alpha3: "ARM",
+        numeric: Some(51),
+        currency: Some("AMD"),
+        calling_code: "+374",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "AO",
+        name: "Angola",
+        alpha3: "AGO",
+        numeric: Some(24),
+        currency: Some("AOA"),
+        calling_code: "+244",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "AQ",
+        name: "Antarctica",
+        alpha3: "ATA",
+        numeric: Some(10),
+        currency: None,
+        calling_code: "+672",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "AR",
+        name: "Argentina",
+        alpha3: "ARG",
+        numeric: Some(32),
+        currency: Some("ARS"),
+        calling_code: "+54",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "AS",
+        name: "American Samoa",
+        alpha3: "ASM",
+        numeric: Some(16),
+        currency: Some("USD"),
+        calling_code: "+1",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "AT",
+        name: "Austria",
+        alpha3: "AUT",
+        numeric: Some(40),
+        currency: Some("EUR"),
+        calling_code: "+43",
+        memberships: &[Eu, Eea, Sepa, Eurozone],
+    },
+    CountryInfo {
+        code: "AU",
+        name: "Australia",
+        alpha3: "AUS",
+        numeric: Some(36),
+        currency: Some("AUD"),
+        calling_code: "+61",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "AW",
+        name: "Aruba",
+        alpha3: "ABW",
+        numeric: Some(533),
+        currency: Some("AWG"),
+        calling_code: "+297",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "AX",
+        name: "Aland Islands",
+        alpha3: "ALA",
+        numeric: Some(248),
+        currency: Some("EUR"),
+        calling_code: "+358",
+        memberships: &[Sepa],
+    },
+    CountryInfo {
+        code: "AZ",
+        name: "Azerbaijan",
+        alpha3: "AZE",
+        numeric: Some(31),
+        currency: Some("AZN"),
+        calling_code: "+994",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "BA",
+        name: "Bosnia and Herzegovina",
+        alpha3: "BIH",
+        numeric: Some(70),
+        currency: Some("BAM"),
+        calling_code: "+387",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "BB",
+        name: "Barbados",
+        alpha3: "BRB",
+        numeric: Some(52),
+        currency: Some("BBD"),
+        calling_code: "+1",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "BD",
+        name: "Bangladesh",
+        alpha3: "BGD",
+        numeric: Some(50),
+        currency: Some("BDT"),
+        calling_code: "+880",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "BE",
+        name: "Belgium",
+        alpha3: "BEL",
+        numeric: Some(56),
+        currency: Some("EUR"),
+        calling_code: "+32",
+        memberships: &[Eu, Eea, Sepa, Eurozone],
+    },
+    CountryInfo {
+        code: "BF",
+        name: "Burkina Faso",
+        alpha3: "BFA",
+        numeric: Some(854),
+        currency: Some("XOF"),
+        calling_code: "+226",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "BG",
+        name: "Bulgaria",
+        alpha3: "BGR",
+        numeric: Some(100),
+        currency: Some("EUR"),
+        calling_code: "+359",
+        memberships: &[Eu, Eea, Sepa, Eurozone],
+    },
+    CountryInfo {
+        code: "BH",
+        name: "Bahrain",
+        alpha3: "BHR",
+        numeric: Some(48),
+        currency: Some("BHD"),
+        calling_code: "+973",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "BI",
+        name: "Burundi",
+        alpha3: "BDI",
+        numeric: Some(108),
+        currency: Some("BIF"),
+        calling_code: "+257",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "BJ",
+        name: "Benin",
+        alpha3: "BEN",
+        numeric: Some(204),
+        currency: Some("XOF"),
+        calling_code: "+229",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "BL",
+        name: "Saint Barthelemy",
+        alpha3: "BLM",
+        numeric: Some(652),
+        currency: Some("EUR"),
+        calling_code: "+590",
+        memberships: &[Sepa],
+    },
+    CountryInfo {
+        code: "BM",
+        name: "Bermuda",
+        alpha3: "BMU",
+        numeric: Some(60),
+        currency: Some("BMD"),
+        calling_code: "+1",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "BN",
+        name: "Brunei",
+        alpha3: "BRN",
+        numeric: Some(96),
+        currency: Some("BND"),
+        calling_code: "+673",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "BO",
+        name: "Bolivia",
+        alpha3: "BOL",
+        numeric: Some(68),
+        currency: Some("BOB"),
+        calling_code: "+591",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "BQ",
+        name: "Caribbean Netherlands",
+        alpha3: "BES",
+        numeric: Some(535),
+        currency: Some("USD"),
+        calling_code: "+599",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "BR",
+        name: "Brazil",
+        alpha3: "BRA",
+        numeric: Some(76),
+        currency: Some("BRL"),
+        calling_code: "+55",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "BS",
+        name: "Bahamas",
+        alpha3: "BHS",
+        numeric: Some(44),
+        currency: Some("BSD"),
+        calling_code: "+1",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "BT",
+        name: "Bhutan",
+        alpha3: "BTN",
+        numeric: Some(64),
+        currency: Some("BTN"),
+        calling_code: "+975",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "BV",
+        name: "Bouvet Island",
+        alpha3: "BVT",
+        numeric: Some(74),
+        currency: Some("NOK"),
+        calling_code: "+47",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "BW",
+        name: "Botswana",
+        alpha3: "BWA",
+        numeric: Some(72),
+        currency: Some("BWP"),
+        calling_code: "+267",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "BY",
+        name: "Belarus",
+        alpha3: "BLR",
+        numeric: Some(112),
+        currency: Some("BYN"),
+        calling_code: "+375",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "BZ",
+        name: "Belize",
+        alpha3: "BLZ",
+        numeric: Some(84),
+        currency: Some("BZD"),
+        calling_code: "+501",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "CA",
+        name: "Canada",
+        alpha3: "CAN",
+        numeric: Some(124),
+        currency: Some("CAD"),
+        calling_code: "+1",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "CC",
+        name: "Cocos (Keeling) Islands",
+        alpha3: "CCK",
+        numeric: Some(166),
+        currency: Some("AUD"),
+        calling_code: "+61",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "CD",
+        name: "DR Congo",
+        alpha3: "COD",
+        numeric: Some(180),
+        currency: Some("CDF"),
+        calling_code: "+243",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "CF",
+        name: "Central African Republic",
+        alpha3: "CAF",
+        numeric: Some(140),
+        currency: Some("XAF"),
+        calling_code: "+236",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "CG",
+        name: "Republic of Congo",
+        alpha3: "COG",
+        numeric: Some(178),
+        currency: Some("XAF"),
+        calling_code: "+242",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "CH",
+        name: "Switzerland",
+        alpha3: "CHE",
+        numeric: Some(756),
+        currency: Some("CHF"),
+        calling_code: "+41",
+        memberships: &[Sepa],
+    },
+    CountryInfo {
+        code: "CI",
+        name: "Cote d'Ivoire",
+        alpha3: "CIV",
+        numeric: Some(384),
+        currency: Some("XOF"),
+        calling_code: "+225",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "CK",
+        name: "Cook Islands",
+        alpha3: "COK",
+        numeric: Some(184),
+        currency: Some("NZD"),
+        calling_code: "+682",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "CL",
+        name: "Chile",
+        alpha3: "CHL",
+        numeric: Some(152),
+        currency: Some("CLP"),
+        calling_code: "+56",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "CM",
+        name: "Cameroon",
+        alpha3: "CMR",
+        numeric: Some(120),
+        currency: Some("XAF"),
+        calling_code: "+237",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "CN",
+        name: "China",
+        alpha3: "CHN",
+        numeric: Some(156),
+        currency: Some("CNY"),
+        calling_code: "+86",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "CO",
+        name: "Colombia",
+        alpha3: "COL",
+        numeric: Some(170),
+        currency: Some("COP"),
+        calling_code: "+57",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "CR",
+        name: "Costa Rica",
+        alpha3: "CRI",
+        numeric: Some(188),
+        currency: Some("CRC"),
+        calling_code: "+506",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "CU",
+        name: "Cuba",
+        alpha3: "CUB",
+        numeric: Some(192),
+        currency: Some("CUP"),
+        calling_code: "+53",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "CV",
+        name: "Cape Verde",
+        alpha3: "CPV",
+        numeric: Some(132),
+        currency: Some("CVE"),
+        calling_code: "+238",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "CW",
+        name: "Curacao",
+        alpha3: "CUW",
+        numeric: Some(531),
+        currency: Some("XCG"),
+        calling_code: "+599",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "CX",
+        name: "Christmas Island",
+        alpha3: "CXR",
+        numeric: Some(162),
+        currency: Some("AUD"),
+        calling_code: "+61",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "CY",
+        name: "Cyprus",
+        alpha3: "CYP",
+        numeric: Some(196),
+        currency: Some("EUR"),
+        calling_code: "+357",
+        memberships: &[Eu, Eea, Sepa, Eurozone],
+    },
+    CountryInfo {
+        code: "CZ",
+        name: "Czech Republic",
+        alpha3: "CZE",
+        numeric: Some(203),
+        currency: Some("CZK"),
+        calling_code: "+420",
+        memberships: &[Eu, Eea, Sepa],
+    },
+    CountryInfo {
+        code: "DE",
+        name: "Germany",
+        alpha3: "DEU",
+        numeric: Some(276),
+        currency: Some("EUR"),
+        calling_code: "+49",
+        memberships: &[Eu, Eea, Sepa, Eurozone],
+    },
+    CountryInfo {
+        code: "DJ",
+        name: "Djibouti",
+        alpha3: "DJI",
+        numeric: Some(262),
+        currency: Some("DJF"),
+        calling_code: "+253",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "DK",
+        name: "Denmark",
+        alpha3: "DNK",
+        numeric: Some(208),
+        currency: Some("DKK"),
+        calling_code: "+45",
+        memberships: &[Eu, Eea, Sepa],
+    },
+    CountryInfo {
+        code: "DM",
+        name: "Dominica",
+        alpha3: "DMA",
+        numeric: Some(212),
+        currency: Some("XCD"),
+        calling_code: "+1",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "DO",
+        name: "Dominican Republic",
+        alpha3: "DOM",
+        numeric: Some(214),
+        currency: Some("DOP"),
+        calling_code: "+1",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "DZ",
+        name: "Algeria",
+        alpha3: "DZA",
+        numeric: Some(12),
+        currency: Some("DZD"),
+        calling_code: "+213",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "EC",
+        name: "Ecuador",
+        alpha3: "ECU",
+        numeric: Some(218),
+        currency: Some("USD"),
+        calling_code: "+593",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "EE",
+        name: "Estonia",
+        alpha3: "EST",
+        numeric: Some(233),
+        currency: Some("EUR"),
+        calling_code: "+372",
+        memberships: &[Eu, Eea, Sepa, Eurozone],
+    },
+    CountryInfo {
+        code: "EG",
+        name: "Egypt",
+        alpha3: "EGY",
+        numeric: Some(818),
+        currency: Some("EGP"),
+        calling_code: "+20",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "EH",
+        name: "Western Sahara",
+        alpha3: "ESH",
+        numeric: Some(732),
+        currency: Some("MAD"),
+        calling_code: "+212",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "ER",
+        name: "Eritrea",
+        alpha3: "ERI",
+        numeric: Some(232),
+        currency: Some("ERN"),
+        calling_code: "+291",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "ES",
+        name: "Spain",
+        alpha3: "ESP",
+        numeric: Some(724),
+        currency: Some("EUR"),
+        calling_code: "+34",
+        memberships: &[Eu, Eea, Sepa, Eurozone],
+    },
+    CountryInfo {
+        code: "ET",
+        name: "Ethiopia",
+        alpha3: "ETH",
+        numeric: Some(231),
+        currency: Some("ETB"),
+        calling_code: "+251",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "FI",
+        name: "Finland",
+        alpha3: "FIN",
+        numeric: Some(246),
+        currency: Some("EUR"),
+        calling_code: "+358",
+        memberships: &[Eu, Eea, Sepa, Eurozone],
+    },
+    CountryInfo {
+        code: "FJ",
+        name: "Fiji",
+        alpha3: "FJI",
+        numeric: Some(242),
+        currency: Some("FJD"),
+        calling_code: "+679",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "FK",
+        name: "Falkland Islands",
+        alpha3: "FLK",
+        numeric: Some(238),
+        currency: Some("FKP"),
+        calling_code: "+500",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "FM",
+        name: "Micronesia",
+        alpha3: "FSM",
+        numeric: Some(583),
+        currency: Some("USD"),
+        calling_code: "+691",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "FO",
+        name: "Faroe Islands",
+        alpha3: "FRO",
+        numeric: Some(234),
+        currency: Some("DKK"),
+        calling_code: "+298",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "FR",
+        name: "France",
+        alpha3: "FRA",
+        numeric: Some(250),
+        currency: Some("EUR"),
+        calling_code: "+33",
+        memberships: &[Eu, Eea, Sepa, Eurozone],
+    },
+    CountryInfo {
+        code: "GA",
+        name: "Gabon",
+        alpha3: "GAB",
+        numeric: Some(266),
+        currency: Some("XAF"),
+        calling_code: "+241",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "GB",
+        name: "United Kingdom",
+        alpha3: "GBR",
+        numeric: Some(826),
+        currency: Some("GBP"),
+        calling_code: "+44",
+        memberships: &[Sepa],
+    },
+    CountryInfo {
+        code: "GD",
+        name: "Grenada",
+        alpha3: "GRD",
+        numeric: Some(308),
+        currency: Some("XCD"),
+        calling_code: "+1",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "GE",
+        name: "Georgia",
+        alpha3: "GEO",
+        numeric: Some(268),
+        currency: Some("GEL"),
+        calling_code: "+995",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "GF",
+        name: "French Guiana",
+        alpha3: "GUF",
+        numeric: Some(254),
+        currency: Some("EUR"),
+        calling_code: "+594",
+        memberships: &[Sepa],
+    },
+    CountryInfo {
+        code: "GG",
+        name: "Guernsey",
+        alpha3: "GGY",
+        numeric: Some(831),
+        currency: Some("GBP"),
+        calling_code: "+44",
+        memberships: &[Sepa],
+    },
+    CountryInfo {
+        code: "GH",
+        name: "Ghana",
+        alpha3: "GHA",
+        numeric: Some(288),
+        currency: Some("GHS"),
+        calling_code: "+233",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "GI",
+        name: "Gibraltar",
+        alpha3: "GIB",
+        numeric: Some(292),
+        currency: Some("GIP"),
+        calling_code: "+350",
+        memberships: &[Sepa],
+    },
+    CountryInfo {
+        code: "GL",
+        name: "Greenland",
+        alpha3: "GRL",
+        numeric: Some(304),
+        currency: Some("DKK"),
+        calling_code: "+299",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "GM",
+        name: "Gambia",
+        alpha3: "GMB",
+        numeric: Some(270),
+        currency: Some("GMD"),
+        calling_code: "+220",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "GN",
+        name: "Guinea",
+        alpha3: "GIN",
+        numeric: Some(324),
+        currency: Some("GNF"),
+        calling_code: "+224",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "GP",
+        name: "Guadeloupe",
+        alpha3: "GLP",
+        numeric: Some(312),
+        currency: Some("EUR"),
+        calling_code: "+590",
+        memberships: &[Sepa],
+    },
+    CountryInfo {
+        code: "GQ",
+        name: "Equatorial Guinea",
+        alpha3: "GNQ",
+        numeric: Some(226),
+        currency: Some("XAF"),
+        calling_code: "+240",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "GR",
+        name: "Greece",
+        alpha3: "GRC",
+        numeric: Some(300),
+        currency: Some("EUR"),
+        calling_code: "+30",
+        memberships: &[Eu, Eea, Sepa, Eurozone],
+    },
+    CountryInfo {
+        code: "GS",
+        name: "South Georgia and the South Sandwich Islands",
+        alpha3: "SGS",
+        numeric: Some(239),
+        currency: Some("GBP"),
+        calling_code: "+500",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "GT",
+        name: "Guatemala",
+        alpha3: "GTM",
+        numeric: Some(320),
+        currency: Some("GTQ"),
+        calling_code: "+502",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "GU",
+        name: "Guam",
+        alpha3: "GUM",
+        numeric: Some(316),
+        currency: Some("USD"),
+        calling_code: "+1",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "GW",
+        name: "Guinea-Bissau",
+        alpha3: "GNB",
+        numeric: Some(624),
+        currency: Some("XOF"),
+        calling_code: "+245",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "GY",
+        name: "Guyana",
+        alpha3: "GUY",
+        numeric: Some(328),
+        currency: Some("GYD"),
+        calling_code: "+592",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "HK",
+        name: "Hong Kong",
+        alpha3: "HKG",
+        numeric: Some(344),
+        currency: Some("HKD"),
+        calling_code: "+852",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "HM",
+        name: "Heard Island and McDonald Islands",
+        alpha3: "HMD",
+        numeric: Some(334),
+        currency: Some("AUD"),
+        calling_code: "+672",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "HN",
+        name: "Honduras",
+        alpha3: "HND",
+        numeric: Some(340),
+        currency: Some("HNL"),
+        calling_code: "+504",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "HR",
+        name: "Croatia",
+        alpha3: "HRV",
+        numeric: Some(191),
+        currency: Some("EUR"),
+        calling_code: "+385",
+        memberships: &[Eu, Eea, Sepa, Eurozone],
+    },
+    CountryInfo {
+        code: "HT",
+        name: "Haiti",
+        alpha3: "HTI",
+        numeric: Some(332),
+        currency: Some("HTG"),
+        calling_code: "+509",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "HU",
+        name: "Hungary",
+        alpha3: "HUN",
+        numeric: Some(348),
+        currency: Some("HUF"),
+        calling_code: "+36",
+        memberships: &[Eu, Eea, Sepa],
+    },
+    CountryInfo {
+        code: "ID",
+        name: "Indonesia",
+        alpha3: "IDN",
+        numeric: Some(360),
+        currency: Some("IDR"),
+        calling_code: "+62",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "IE",
+        name: "Ireland",
+        alpha3: "IRL",
+        numeric: Some(372),
+        currency: Some("EUR"),
+        calling_code: "+353",
+        memberships: &[Eu, Eea, Sepa, Eurozone],
+    },
+    CountryInfo {
+        code: "IL",
+        name: "Israel",
+        alpha3: "ISR",
+        numeric: Some(376),
+        currency: Some("ILS"),
+        calling_code: "+972",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "IM",
+        name: "Isle of Man",
+        alpha3: "IMN",
+        numeric: Some(833),
+        currency: Some("GBP"),
+        calling_code: "+44",
+        memberships: &[Sepa],
+    },
+    CountryInfo {
+        code: "IN",
+        name: "India",
+        alpha3: "IND",
+        numeric: Some(356),
+        currency: Some("INR"),
+        calling_code: "+91",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "IO",
+        name: "British Indian Ocean Territory",
+        alpha3: "IOT",
+        numeric: Some(86),
+        currency: Some("USD"),
+        calling_code: "+246",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "IQ",
+        name: "Iraq",
+        alpha3: "IRQ",
+        numeric: Some(368),
+        currency: Some("IQD"),
+        calling_code: "+964",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "IR",
+        name: "Iran",
+        alpha3: "IRN",
+        numeric: Some(364),
+        currency: Some("IRR"),
+        calling_code: "+98",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "IS",
+        name: "Iceland",
+        alpha3: "ISL",
+        numeric: Some(352),
+        currency: Some("ISK"),
+        calling_code: "+354",
+        memberships: &[Eea, Sepa],
+    },
+    CountryInfo {
+        code: "IT",
+        name: "Italy",
+        alpha3: "ITA",
+        numeric: Some(380),
+        currency: Some("EUR"),
+        calling_code: "+39",
+        memberships: &[Eu, Eea, Sepa, Eurozone],
+    },
+    CountryInfo {
+        code: "JE",
+        name: "Jersey",
+        alpha3: "JEY",
+        numeric: Some(832),
+        currency: Some("GBP"),
+        calling_code: "+44",
+        memberships: &[Sepa],
+    },
+    CountryInfo {
+        code: "JM",
+        name: "Jamaica",
+        alpha3: "JAM",
+        numeric: Some(388),
+        currency: Some("JMD"),
+        calling_code: "+1",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "JO",
+        name: "Jordan",
+        alpha3: "JOR",
+        numeric: Some(400),
+        currency: Some("JOD"),
+        calling_code: "+962",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "JP",
+        name: "Japan",
+        alpha3: "JPN",
+        numeric: Some(392),
+        currency: Some("JPY"),
+        calling_code: "+81",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "KE",
+        name: "Kenya",
+        alpha3: "KEN",
+        numeric: Some(404),
+        currency: Some("KES"),
+        calling_code: "+254",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "KG",
+        name: "Kyrgyzstan",
+        alpha3: "KGZ",
+        numeric: Some(417),
+        currency: Some("KGS"),
+        calling_code: "+996",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "KH",
+        name: "Cambodia",
+        alpha3: "KHM",
+        numeric: Some(116),
+        currency: Some("KHR"),
+        calling_code: "+855",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "KI",
+        name: "Kiribati",
+        alpha3: "KIR",
+        numeric: Some(296),
+        currency: Some("AUD"),
+        calling_code: "+686",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "KM",
+        name: "Comoros",
+        alpha3: "COM",
+        numeric: Some(174),
+        currency: Some("KMF"),
+        calling_code: "+269",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "KN",
+        name: "Saint Kitts and Nevis",
+        alpha3: "KNA",
+        numeric: Some(659),
+        currency: Some("XCD"),
+        calling_code: "+1",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "KP",
+        name: "North Korea",
+        alpha3: "PRK",
+        numeric: Some(408),
+        currency: Some("KPW"),
+        calling_code: "+850",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "KR",
+        name: "South Korea",
+        alpha3: "KOR",
+        numeric: Some(410),
+        currency: Some("KRW"),
+        calling_code: "+82",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "KW",
+        name: "Kuwait",
+        alpha3: "KWT",
+        numeric: Some(414),
+        currency: Some("KWD"),
+        calling_code: "+965",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "KY",
+        name: "Cayman Islands",
+        alpha3: "CYM",
+        numeric: Some(136),
+        currency: Some("KYD"),
+        calling_code: "+1",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "KZ",
+        name: "Kazakhstan",
+        alpha3: "KAZ",
+        numeric: Some(398),
+        currency: Some("KZT"),
+        calling_code: "+7",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "LA",
+        name: "Laos",
+        alpha3: "LAO",
+        numeric: Some(418),
+        currency: Some("LAK"),
+        calling_code: "+856",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "LB",
+        name: "Lebanon",
+        alpha3: "LBN",
+        numeric: Some(422),
+        currency: Some("LBP"),
+        calling_code: "+961",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "LC",
+        name: "Saint Lucia",
+        alpha3: "LCA",
+        numeric: Some(662),
+        currency: Some("XCD"),
+        calling_code: "+1",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "LI",
+        name: "Liechtenstein",
+        alpha3: "LIE",
+        numeric: Some(438),
+        currency: Some("CHF"),
+        calling_code: "+423",
+        memberships: &[Eea, Sepa],
+    },
+    CountryInfo {
+        code: "LK",
+        name: "Sri Lanka",
+        alpha3: "LKA",
+        numeric: Some(144),
+        currency: Some("LKR"),
+        calling_code: "+94",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "LR",
+        name: "Liberia",
+        alpha3: "LBR",
+        numeric: Some(430),
+        currency: Some("LRD"),
+        calling_code: "+231",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "LS",
+        name: "Lesotho",
+        alpha3: "LSO",
+        numeric: Some(426),
+        currency: Some("LSL"),
+        calling_code: "+266",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "LT",
+        name: "Lithuania",
+        alpha3: "LTU",
+        numeric: Some(440),
+        currency: Some("EUR"),
+        calling_code: "+370",
+        memberships: &[Eu, Eea, Sepa, Eurozone],
+    },
+    CountryInfo {
+        code: "LU",
+        name: "Luxembourg",
+        alpha3: "LUX",
+        numeric: Some(442),
+        currency: Some("EUR"),
+        calling_code: "+352",
+        memberships: &[Eu, Eea, Sepa, Eurozone],
+    },
+    CountryInfo {
+        code: "LV",
+        name: "Latvia",
+        alpha3: "LVA",
+        numeric: Some(428),
+        currency: Some("EUR"),
+        calling_code: "+371",
+        memberships: &[Eu, Eea, Sepa, Eurozone],
+    },
+    CountryInfo {
+        code: "LY",
+        name: "Libya",
+        alpha3: "LBY",
+        numeric: Some(434),
+        currency: Some("LYD"),
+        calling_code: "+218",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "MA",
+        name: "Morocco",
+        alpha3: "MAR",
+        numeric: Some(504),
+        currency: Some("MAD"),
+        calling_code: "+212",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "MC",
+        name: "Monaco",
+        alpha3: "MCO",
+        numeric: Some(492),
+        currency: Some("EUR"),
+        calling_code: "+377",
+        memberships: &[Sepa],
+    },
+    CountryInfo {
+        code: "MD",
+        name: "Moldova",
+        alpha3: "MDA",
+        numeric: Some(498),
+        currency: Some("MDL"),
+        calling_code: "+373",
+        memberships: &[Sepa],
+    },
+    CountryInfo {
+        code: "ME",
+        name: "Montenegro",
+        alpha3: "MNE",
+        numeric: Some(499),
+        currency: Some("EUR"),
+        calling_code: "+382",
+        memberships: &[Sepa],
+    },
+    CountryInfo {
+        code: "MF",
+        name: "Saint Martin",
+        alpha3: "MAF",
+        numeric: Some(663),
+        currency: Some("EUR"),
+        calling_code: "+590",
+        memberships: &[Sepa],
+    },
+    CountryInfo {
+        code: "MG",
+        name: "Madagascar",
+        alpha3: "MDG",
+        numeric: Some(450),
+        currency: Some("MGA"),
+        calling_code: "+261",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "MH",
+        name: "Marshall Islands",
+        alpha3: "MHL",
+        numeric: Some(584),
+        currency: Some("USD"),
+        calling_code: "+692",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "MK",
+        name: "North Macedonia",
+        alpha3: "MKD",
+        numeric: Some(807),
+        currency: Some("MKD"),
+        calling_code: "+389",
+        memberships: &[Sepa],
+    },
+    CountryInfo {
+        code: "ML",
+        name: "Mali",
+        alpha3: "MLI",
+        numeric: Some(466),
+        currency: Some("XOF"),
+        calling_code: "+223",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "MM",
+        name: "Myanmar",
+        alpha3: "MMR",
+        numeric: Some(104),
+        currency: Some("MMK"),
+        calling_code: "+95",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "MN",
+        name: "Mongolia",
+        alpha3: "MNG",
+        numeric: Some(496),
+        currency: Some("MNT"),
+        calling_code: "+976",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "MO",
+        name: "Macau",
+        alpha3: "MAC",
+        numeric: Some(446),
+        currency: Some("MOP"),
+        calling_code: "+853",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "MP",
+        name: "Northern Mariana Islands",
+        alpha3: "MNP",
+        numeric: Some(580),
+        currency: Some("USD"),
+        calling_code: "+1",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "MQ",
+        name: "Martinique",
+        alpha3: "MTQ",
+        numeric: Some(474),
+        currency: Some("EUR"),
+        calling_code: "+596",
+        memberships: &[Sepa],
+    },
+    CountryInfo {
+        code: "MR",
+        name: "Mauritania",
+        alpha3: "MRT",
+        numeric: Some(478),
+        currency: Some("MRU"),
+        calling_code: "+222",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "MS",
+        name: "Montserrat",
+        alpha3: "MSR",
+        numeric: Some(500),
+        currency: Some("XCD"),
+        calling_code: "+1",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "MT",
+        name: "Malta",
+        alpha3: "MLT",
+        numeric: Some(470),
+        currency: Some("EUR"),
+        calling_code: "+356",
+        memberships: &[Eu, Eea, Sepa, Eurozone],
+    },
+    CountryInfo {
+        code: "MU",
+        name: "Mauritius",
+        alpha3: "MUS",
+        numeric: Some(480),
+        currency: Some("MUR"),
+        calling_code: "+230",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "MV",
+        name: "Maldives",
+        alpha3: "MDV",
+        numeric: Some(462),
+        currency: Some("MVR"),
+        calling_code: "+960",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "MW",
+        name: "Malawi",
+        alpha3: "MWI",
+        numeric: Some(454),
+        currency: Some("MWK"),
+        calling_code: "+265",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "MX",
+        name: "Mexico",
+        alpha3: "MEX",
+        numeric: Some(484),
+        currency: Some("MXN"),
+        calling_code: "+52",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "MY",
+        name: "Malaysia",
+        alpha3: "MYS",
+        numeric: Some(458),
+        currency: Some("MYR"),
+        calling_code: "+60",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "MZ",
+        name: "Mozambique",
+        alpha3: "MOZ",
+        numeric: Some(508),
+        currency: Some("MZN"),
+        calling_code: "+258",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "NA",
+        name: "Namibia",
+        alpha3: "NAM",
+        numeric: Some(516),
+        currency: Some("NAD"),
+        calling_code: "+264",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "NC",
+        name: "New Caledonia",
+        alpha3: "NCL",
+        numeric: Some(540),
+        currency: Some("XPF"),
+        calling_code: "+687",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "NE",
+        name: "Niger",
+        alpha3: "NER",
+        numeric: Some(562),
+        currency: Some("XOF"),
+        calling_code: "+227",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "NF",
+        name: "Norfolk Island",
+        alpha3: "NFK",
+        numeric: Some(574),
+        currency: Some("AUD"),
+        calling_code: "+672",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "NG",
+        name: "Nigeria",
+        alpha3: "NGA",
+        numeric: Some(566),
+        currency: Some("NGN"),
+        calling_code: "+234",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "NI",
+        name: "Nicaragua",
+        alpha3: "NIC",
+        numeric: Some(558),
+        currency: Some("NIO"),
+        calling_code: "+505",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "NL",
+        name: "Netherlands",
+        alpha3: "NLD",
+        numeric: Some(528),
+        currency: Some("EUR"),
+        calling_code: "+31",
+        memberships: &[Eu, Eea, Sepa, Eurozone],
+    },
+    CountryInfo {
+        code: "NO",
+        name: "Norway",
+        alpha3: "NOR",
+        numeric: Some(578),
+        currency: Some("NOK"),
+        calling_code: "+47",
+        memberships: &[Eea, Sepa],
+    },
+    CountryInfo {
+        code: "NP",
+        name: "Nepal",
+        alpha3: "NPL",
+        numeric: Some(524),
+        currency: Some("NPR"),
+        calling_code: "+977",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "NR",
+        name: "Nauru",
+        alpha3: "NRU",
+        numeric: Some(520),
+        currency: Some("AUD"),
+        calling_code: "+674",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "NU",
+        name: "Niue",
+        alpha3: "NIU",
+        numeric: Some(570),
+        currency: Some("NZD"),
+        calling_code: "+683",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "NZ",
+        name: "New Zealand",
+        alpha3: "NZL",
+        numeric: Some(554),
+        currency: Some("NZD"),
+        calling_code: "+64",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "OM",
+        name: "Oman",
+        alpha3: "OMN",
+        numeric: Some(512),
+        currency: Some("OMR"),
+        calling_code: "+968",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "PA",
+        name: "Panama",
+        alpha3: "PAN",
+        numeric: Some(591),
+        currency: Some("PAB"),
+        calling_code: "+507",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "PE",
+        name: "Peru",
+        alpha3: "PER",
+        numeric: Some(604),
+        currency: Some("PEN"),
+        calling_code: "+51",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "PF",
+        name: "French Polynesia",
+        alpha3: "PYF",
+        numeric: Some(258),
+        currency: Some("XPF"),
+        calling_code: "+689",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "PG",
+        name: "Papua New Guinea",
+        alpha3: "PNG",
+        numeric: Some(598),
+        currency: Some("PGK"),
+        calling_code: "+675",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "PH",
+        name: "Philippines",
+        alpha3: "PHL",
+        numeric: Some(608),
+        currency: Some("PHP"),
+        calling_code: "+63",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "PK",
+        name: "Pakistan",
+        alpha3: "PAK",
+        numeric: Some(586),
+        currency: Some("PKR"),
+        calling_code: "+92",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "PL",
+        name: "Poland",
+        alpha3: "POL",
+        numeric: Some(616),
+        currency: Some("PLN"),
+        calling_code: "+48",
+        memberships: &[Eu, Eea, Sepa],
+    },
+    CountryInfo {
+        code: "PM",
+        name: "Saint Pierre and Miquelon",
+        alpha3: "SPM",
+        numeric: Some(666),
+        currency: Some("EUR"),
+        calling_code: "+508",
+        memberships: &[Sepa],
+    },
+    CountryInfo {
+        code: "PN",
+        name: "Pitcairn Islands",
+        alpha3: "PCN",
+        numeric: Some(612),
+        currency: Some("NZD"),
+        calling_code: "+64",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "PR",
+        name: "Puerto Rico",
+        alpha3: "PRI",
+        numeric: Some(630),
+        currency: Some("USD"),
+        calling_code: "+1",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "PS",
+        name: "Palestine",
+        alpha3: "PSE",
+        numeric: Some(275),
+        currency: Some("ILS"),
+        calling_code: "+970",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "PT",
+        name: "Portugal",
+        alpha3: "PRT",
+        numeric: Some(620),
+        currency: Some("EUR"),
+        calling_code: "+351",
+        memberships: &[Eu, Eea, Sepa, Eurozone],
+    },
+    CountryInfo {
+        code: "PW",
+        name: "Palau",
+        alpha3: "PLW",
+        numeric: Some(585),
+        currency: Some("USD"),
+        calling_code: "+680",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "PY",
+        name: "Paraguay",
+        alpha3: "PRY",
+        numeric: Some(600),
+        currency: Some("PYG"),
+        calling_code: "+595",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "QA",
+        name: "Qatar",
+        alpha3: "QAT",
+        numeric: Some(634),
+        currency: Some("QAR"),
+        calling_code: "+974",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "RE",
+        name: "Reunion",
+        alpha3: "REU",
+        numeric: Some(638),
+        currency: Some("EUR"),
+        calling_code: "+262",
+        memberships: &[Sepa],
+    },
+    CountryInfo {
+        code: "RO",
+        name: "Romania",
+        alpha3: "ROU",
+        numeric: Some(642),
+        currency: Some("RON"),
+        calling_code: "+40",
+        memberships: &[Eu, Eea, Sepa],
+    },
+    CountryInfo {
+        code: "RS",
+        name: "Serbia",
+        alpha3: "SRB",
+        numeric: Some(688),
+        currency: Some("RSD"),
+        calling_code: "+381",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "RU",
+        name: "Russia",
+        alpha3: "RUS",
+        numeric: Some(643),
+        currency: Some("RUB"),
+        calling_code: "+7",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "RW",
+        name: "Rwanda",
+        alpha3: "RWA",
+        numeric: Some(646),
+        currency: Some("RWF"),
+        calling_code: "+250",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "SA",
+        name: "Saudi Arabia",
+        alpha3: "SAU",
+        numeric: Some(682),
+        currency: Some("SAR"),
+        calling_code: "+966",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "SB",
+        name: "Solomon Islands",
+        alpha3: "SLB",
+        numeric: Some(90),
+        currency: Some("SBD"),
+        calling_code: "+677",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "SC",
+        name: "Seychelles",
+        alpha3: "SYC",
+        numeric: Some(690),
+        currency: Some("SCR"),
+        calling_code: "+248",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "SD",
+        name: "Sudan",
+        alpha3: "SDN",
+        numeric: Some(729),
+        currency: Some("SDG"),
+        calling_code: "+249",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "SE",
+        name: "Sweden",
+        alpha3: "SWE",
+        numeric: Some(752),
+        currency: Some("SEK"),
+        calling_code: "+46",
+        memberships: &[Eu, Eea, Sepa],
+    },
+    CountryInfo {
+        code: "SG",
+        name: "Singapore",
+        alpha3: "SGP",
+        numeric: Some(702),
+        currency: Some("SGD"),
+        calling_code: "+65",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "SH",
+        name: "Saint Helena, Ascension and Tristan da Cunha",
+        alpha3: "SHN",
+        numeric: Some(654),
+        currency: Some("SHP"),
+        calling_code: "+290",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "SI",
+        name: "Slovenia",
+        alpha3: "SVN",
+        numeric: Some(705),
+        currency: Some("EUR"),
+        calling_code: "+386",
+        memberships: &[Eu, Eea, Sepa, Eurozone],
+    },
+    CountryInfo {
+        code: "SJ",
+        name: "Svalbard and Jan Mayen",
+        alpha3: "SJM",
+        numeric: Some(744),
+        currency: Some("NOK"),
+        calling_code: "+47",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "SK",
+        name: "Slovakia",
+        alpha3: "SVK",
+        numeric: Some(703),
+        currency: Some("EUR"),
+        calling_code: "+421",
+        memberships: &[Eu, Eea, Sepa, Eurozone],
+    },
+    CountryInfo {
+        code: "SL",
+        name: "Sierra Leone",
+        alpha3: "SLE",
+        numeric: Some(694),
+        currency: Some("SLE"),
+        calling_code: "+232",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "SM",
+        name: "San Marino",
+        alpha3: "SMR",
+        numeric: Some(674),
+        currency: Some("EUR"),
+        calling_code: "+378",
+        memberships: &[Sepa],
+    },
+    CountryInfo {
+        code: "SN",
+        name: "Senegal",
+        alpha3: "SEN",
+        numeric: Some(686),
+        currency: Some("XOF"),
+        calling_code: "+221",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "SO",
+        name: "Somalia",
+        alpha3: "SOM",
+        numeric: Some(706),
+        currency: Some("SOS"),
+        calling_code: "+252",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "SR",
+        name: "Suriname",
+        alpha3: "SUR",
+        numeric: Some(740),
+        currency: Some("SRD"),
+        calling_code: "+597",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "SS",
+        name: "South Sudan",
+        alpha3: "SSD",
+        numeric: Some(728),
+        currency: Some("SSP"),
+        calling_code: "+211",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "ST",
+        name: "Sao Tome and Principe",
+        alpha3: "STP",
+        numeric: Some(678),
+        currency: Some("STN"),
+        calling_code: "+239",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "SV",
+        name: "El Salvador",
+        alpha3: "SLV",
+        numeric: Some(222),
+        currency: Some("USD"),
+        calling_code: "+503",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "SX",
+        name: "Sint Maarten",
+        alpha3: "SXM",
+        numeric: Some(534),
+        currency: Some("XCG"),
+        calling_code: "+1",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "SY",
+        name: "Syria",
+        alpha3: "SYR",
+        numeric: Some(760),
+        currency: Some("SYP"),
+        calling_code: "+963",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "SZ",
+        name: "Eswatini",
+        alpha3: "SWZ",
+        numeric: Some(748),
+        currency: Some("SZL"),
+        calling_code: "+268",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "TC",
+        name: "Turks and Caicos",
+        alpha3: "TCA",
+        numeric: Some(796),
+        currency: Some("USD"),
+        calling_code: "+1",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "TD",
+        name: "Chad",
+        alpha3: "TCD",
+        numeric: Some(148),
+        currency: Some("XAF"),
+        calling_code: "+235",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "TF",
+        name: "French Southern Territories",
+        alpha3: "ATF",
+        numeric: Some(260),
+        currency: Some("EUR"),
+        calling_code: "+262",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "TG",
+        name: "Togo",
+        alpha3: "TGO",
+        numeric: Some(768),
+        currency: Some("XOF"),
+        calling_code: "+228",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "TH",
+        name: "Thailand",
+        alpha3: "THA",
+        numeric: Some(764),
+        currency: Some("THB"),
+        calling_code: "+66",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "TJ",
+        name: "Tajikistan",
+        alpha3: "TJK",
+        numeric: Some(762),
+        currency: Some("TJS"),
+        calling_code: "+992",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "TK",
+        name: "Tokelau",
+        alpha3: "TKL",
+        numeric: Some(772),
+        currency: Some("NZD"),
+        calling_code: "+690",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "TL",
+        name: "Timor-Leste",
+        alpha3: "TLS",
+        numeric: Some(626),
+        currency: Some("USD"),
+        calling_code: "+670",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "TM",
+        name: "Turkmenistan",
+        alpha3: "TKM",
+        numeric: Some(795),
+        currency: Some("TMT"),
+        calling_code: "+993",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "TN",
+        name: "Tunisia",
+        alpha3: "TUN",
+        numeric: Some(788),
+        currency: Some("TND"),
+        calling_code: "+216",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "TO",
+        name: "Tonga",
+        alpha3: "TON",
+        numeric: Some(776),
+        currency: Some("TOP"),
+        calling_code: "+676",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "TR",
+        name: "Turkey",
+        alpha3: "TUR",
+        numeric: Some(792),
+        currency: Some("TRY"),
+        calling_code: "+90",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "TT",
+        name: "Trinidad and Tobago",
+        alpha3: "TTO",
+        numeric: Some(780),
+        currency: Some("TTD"),
+        calling_code: "+1",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "TV",
+        name: "Tuvalu",
+        alpha3: "TUV",
+        numeric: Some(798),
+        currency: Some("AUD"),
+        calling_code: "+688",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "TW",
+        name: "Taiwan",
+        alpha3: "TWN",
+        numeric: Some(158),
+        currency: Some("TWD"),
+        calling_code: "+886",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "TZ",
+        name: "Tanzania",
+        alpha3: "TZA",
+        numeric: Some(834),
+        currency: Some("TZS"),
+        calling_code: "+255",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "UA",
+        name: "Ukraine",
+        alpha3: "UKR",
+        numeric: Some(804),
+        currency: Some("UAH"),
+        calling_code: "+380",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "UG",
+        name: "Uganda",
+        alpha3: "UGA",
+        numeric: Some(800),
+        currency: Some("UGX"),
+        calling_code: "+256",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "UM",
+        name: "US Minor Outlying Islands",
+        alpha3: "UMI",
+        numeric: Some(581),
+        currency: Some("USD"),
+        calling_code: "+1",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "US",
+        name: "United States",
+        alpha3: "USA",
+        numeric: Some(840),
+        currency: Some("USD"),
+        calling_code: "+1",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "UY",
+        name: "Uruguay",
+        alpha3: "URY",
+        numeric: Some(858),
+        currency: Some("UYU"),
+        calling_code: "+598",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "UZ",
+        name: "Uzbekistan",
+        alpha3: "UZB",
+        numeric: Some(860),
+        currency: Some("UZS"),
+        calling_code: "+998",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "VA",
+        name: "Vatican City",
+        alpha3: "VAT",
+        numeric: Some(336),
+        currency: Some("EUR"),
+        calling_code: "+39",
+        memberships: &[Sepa],
+    },
+    CountryInfo {
+        code: "VC",
+        name: "Saint Vincent",
+        alpha3: "VCT",
+        numeric: Some(670),
+        currency: Some("XCD"),
+        calling_code: "+1",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "VE",
+        name: "Venezuela",
+        alpha3: "VEN",
+        numeric: Some(862),
+        currency: Some("VES"),
+        calling_code: "+58",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "VG",
+        name: "British Virgin Islands",
+        alpha3: "VGB",
+        numeric: Some(92),
+        currency: Some("USD"),
+        calling_code: "+1",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "VI",
+        name: "US Virgin Islands",
+        alpha3: "VIR",
+        numeric: Some(850),
+        currency: Some("USD"),
+        calling_code: "+1",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "VN",
+        name: "Vietnam",
+        alpha3: "VNM",
+        numeric: Some(704),
+        currency: Some("VND"),
+        calling_code: "+84",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "VU",
+        name: "Vanuatu",
+        alpha3: "VUT",
+        numeric: Some(548),
+        currency: Some("VUV"),
+        calling_code: "+678",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "WF",
+        name: "Wallis and Futuna",
+        alpha3: "WLF",
+        numeric: Some(876),
+        currency: Some("XPF"),
+        calling_code: "+681",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "WS",
+        name: "Samoa",
+        alpha3: "WSM",
+        numeric: Some(882),
+        currency: Some("WST"),
+        calling_code: "+685",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "XK",
+        name: "Kosovo",
+        alpha3: "XKX",
+        numeric: None,
+        currency: Some("EUR"),
+        calling_code: "+383",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "YE",
+        name: "Yemen",
+        alpha3: "YEM",
+        numeric: Some(887),
+        currency: Some("YER"),
+        calling_code: "+967",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "YT",
+        name: "Mayotte",
+        alpha3: "MYT",
+        numeric: Some(175),
+        currency: Some("EUR"),
+        calling_code: "+262",
+        memberships: &[Sepa],
+    },
+    CountryInfo {
+        code: "ZA",
+        name: "South Africa",
+        alpha3: "ZAF",
+        numeric: Some(710),
+        currency: Some("ZAR"),
+        calling_code: "+27",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "ZM",
+        name: "Zambia",
+        alpha3: "ZMB",
+        numeric: Some(894),
+        currency: Some("ZMW"),
+        calling_code: "+260",
+        memberships: &[],
+    },
+    CountryInfo {
+        code: "ZW",
+        name: "Zimbabwe",
+        alpha3: "ZWE",
+        numeric: Some(716),
+        currency: Some("ZWG"),
+        calling_code: "+263",
+        memberships: &[],
+    },
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TerritoryAlias {
+    pub code: &'static str,
+    pub name: &'static str,
+    pub parent_code: &'static str,
+}
+
+/// Maps territories and dependencies to their parent sovereign state for identifier resolution.
+pub const TERRITORY_ALIASES: &[TerritoryAlias] = &[
+    TerritoryAlias {
+        code: "AS",
+        name: "American Samoa",
+        parent_code: "US",
+    },
+    TerritoryAlias {
+        code: "BL",
+        name: "Saint Barthelemy",
+        parent_code: "FR",
+    },
+    TerritoryAlias {
+        code: "BV",
+        name: "Bouvet Island",
+        parent_code: "NO",
+    },
+    TerritoryAlias {
+        code: "CC",
+        name: "Cocos (Keeling) Islands",
+        parent_code: "AU",
+    },
+    TerritoryAlias {
+        code: "CK",
+        name: "Cook Islands",
+        parent_code: "NZ",
+    },
+    TerritoryAlias {
+        code: "CX",
+        name: "Christmas Island",
+        parent_code: "AU",
+    },
+    TerritoryAlias {
+        code: "EH",
+        name: "Western Sahara",
+        parent_code: "MA",
+    },
+    TerritoryAlias {
+        code: "FO",
+        name: "Faroe Islands",
+        parent_code: "DK",
+    },
+    TerritoryAlias {
+        code: "GF",
+        name: "French Guiana",
+        parent_code: "FR",
+    },
+    TerritoryAlias {
+        code: "GL",
+        name: "Greenland",
+        parent_code: "DK",
+    },
+    TerritoryAlias {
+        code: "GP",
+        name: "Guadeloupe",
+        parent_code: "FR",
+    },
+    TerritoryAlias {
+        code: "GS",
+        name: "South Georgia and the South Sandwich Islands",
+        parent_code: "GB",
+    },
+    TerritoryAlias {
+        code: "GU",
+        name: "Guam",
+        parent_code: "US",
+    },
+    TerritoryAlias {
+        code: "HM",
+        name: "Heard Island and McDonald Islands",
+        parent_code: "AU",
+    },
+    TerritoryAlias {
+        code: "IO",
+        name: "British Indian Ocean Territory",
+        parent_code: "GB",
+    },
+    TerritoryAlias {
+        code: "MF",
+        name: "Saint Martin",
+        parent_code: "FR",
+    },
+    TerritoryAlias {
+        code: "MP",
+        name: "Northern Mariana Islands",
+        parent_code: "US",
+    },
+    TerritoryAlias {
+        code: "MQ",
+        name: "Martinique",
+        parent_code: "FR",
+    },
+    TerritoryAlias {
+        code: "NC",
+        name: "New Caledonia",
+        parent_code: "FR",
+    },
+    TerritoryAlias {
+        code: "NF",
+        name: "Norfolk Island",
+        parent_code: "AU",
+    },
+    TerritoryAlias {
+        code: "NU",
+        name: "Niue",
+        parent_code: "NZ",
+    },
+    TerritoryAlias {
+        code: "PF",
+        name: "French Polynesia",
+        parent_code: "FR",
+    },
+    TerritoryAlias {
+        code: "PM",
+        name: "Saint Pierre and Miquelon",
+        parent_code: "FR",
+    },
+    TerritoryAlias {
+        code: "PN",
+        name: "Pitcairn Islands",
+        parent_code: "NZ",
+    },
+    TerritoryAlias {
+        code: "PR",
+        name: "Puerto Rico",
+        parent_code: "US",
+    },
+    TerritoryAlias {
+        code: "RE",
+        name: "Reunion",
+        parent_code: "FR",
+    },
+    TerritoryAlias {
+        code: "SH",
+        name: "Saint Helena, Ascension and Tristan da Cunha",
+        parent_code: "GB",
+    },
+    TerritoryAlias {
+        code: "SJ",
+        name: "Svalbard and Jan Mayen",
+        parent_code: "NO",
+    },
+    TerritoryAlias {
+        code: "TF",
+        name: "French Southern Territories",
+        parent_code: "FR",
+    },
+    TerritoryAlias {
+        code: "TK",
+        name: "Tokelau",
+        parent_code: "NZ",
+    },
+    TerritoryAlias {
+        code: "UM",
+        name: "US Minor Outlying Islands",
+        parent_code: "US",
+    },
+    TerritoryAlias {
+        code: "VG",
+        name: "British Virgin Islands",
+        parent_code: "GB",
+    },
+    TerritoryAlias {
+        code: "VI",
+        name: "US Virgin Islands",
+        parent_code: "US",
+    },
+    TerritoryAlias {
+        code: "WF",
+        name: "Wallis and Futuna",
+        parent_code: "FR",
+    },
+    TerritoryAlias {
+        code: "YT",
+        name: "Mayotte",
+        parent_code: "FR",
+    },
+];
+
+/// Returns the metadata of a country by its alpha-2 code.
+pub fn get(code: &str) -> Option<&'static CountryInfo> {
+    let code = code.to_uppercase();
+    ALL_COUNTRIES.iter().find(|c| c.code == code)
+}
+
+/// Returns the metadata of a country by its alpha-3 code.
+pub fn by_alpha3(code: &str) -> Option<&'static CountryInfo> {
+    let code = code.to_uppercase();
+    ALL_COUNTRIES.iter().find(|c| c.alpha3 == code)
+}
+
+/// Returns the metadata of a country by its English name, ignoring case.
+pub fn by_name(name: &str) -> Option<&'static CountryInfo> {
+    let name = name.trim();
+    ALL_COUNTRIES
+        .iter()
+        .find(|c| c.name.eq_ignore_ascii_case(name))
+}
+
+/// Countries in `group`, in alpha-2 order.
+pub fn members(group: Membership) -> impl Iterator<Item = &'static CountryInfo> {
+    ALL_COUNTRIES.iter().filter(move |c| c.is_member(group))
+}
+
+/// A random member of `group` that `has_rules` accepts, for generating "any
+/// SEPA country" and the like.
+pub fn random_member(
+    group: Membership,
+    rng: &mut impl Rng,
+    has_rules: impl Fn(&str) -> bool,
+) -> Option<&'static str> {
+    let candidates: Vec<_> = members(group)
+        .map(|c| c.code)
+        .filter(|c| has_rules(c))
+        .collect();
+    candidates.choose(rng).copied()
+}
+
+/// Returns the name of a country by its ISO code, or None if not found.
+pub fn get_country_name(code: &str) -> Option<&'static str> {
+    get(code).map(|c| c.name)
+}
+
+/// Returns the parent country code for a territory, if applicable.
+pub fn resolve_territory(code: &str) -> Option<&'static str> {
+    let code = code.to_uppercase();
+    TERRITORY_ALIASES
+        .iter()
+        .find(|a| a.code == code)
+        .map(|a| a.parent_code)
+}
+
+/// Returns the country whose identifier rules apply to `code`.
+///
+/// `has_rules` tells whether a registry has its own rules for a code. If it
+/// has rules for `code`, that is the answer; otherwise a territory falls back
+/// to its parent country (Puerto Rico to the US, French Guiana to France),
+/// unless `strict` is set. Returns `None` if neither applies.
+///
+/// ```rust
+/// use idsmith::countries::effective_country_code;
+///
+/// let has_rules = |c: &str| c == "US";
+/// assert_eq!(effective_country_code("US", false, has_rules).as_deref(), Some("US"));
+/// assert_eq!(effective_country_code("pr", false, has_rules).as_deref(), Some("US"));
+/// assert_eq!(effective_country_code("PR", true, has_rules), None);
+/// ```
+pub fn effective_country_code(
+    code: &str,
+    strict: bool,
+    has_rules: impl Fn(&str) -> bool,
+) -> Option<String> {
+    let code = code.to_uppercase();
+    if has_rules(&code) {
+        return Some(code);
+    }
+    if strict {
+        return None;
+    }
+    resolve_territory(&code)
+        .filter(|parent| has_rules(parent))
+        .map(str::to_string)
+}
+
+/// Returns true if the ISO code is supported.
+pub fn is_supported(code: &str) -> bool {
+    get(code).is_some()
+}
+
+/// Returns all supported ISO 3166-1 alpha-2 codes.
+pub fn all_codes() -> Vec<&'static str> {
+    ALL_COUNTRIES.iter().map(|c| c.code).collect()
+}
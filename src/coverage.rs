@@ -0,0 +1,132 @@
+//! Which identifier kinds are supported for each country, and how well.
+//!
+//! [`coverage`] lists every country and territory in [`crate::countries`]
+//! with the country-specific kinds idsmith can generate for it. Each entry
+//! says whether the scheme is checksum-verified, format-only (length and
+//! character rules, dates and ranges, but no check character), or borrowed
+//! from a parent country (e.g. Puerto Rico uses the US SSN).
+//!
+//! Card numbers, SWIFT codes and LEIs follow the same rules everywhere and
+//! are not listed per country.
+//!
+//! ```rust
+//! use idsmith::coverage::{support, Fidelity};
+//! use idsmith::kind::IdKind;
+//!
+//! assert_eq!(support(IdKind::PersonalId, "EE").unwrap().fidelity, Fidelity::Checksum);
+//! assert_eq!(support(IdKind::PersonalId, "US").unwrap().fidelity, Fidelity::FormatOnly);
+//!
+//! let pr = support(IdKind::PersonalId, "PR").unwrap();
+//! assert_eq!((pr.fidelity, pr.parent), (Fidelity::Alias, Some("US")));
+//! ```
+
+use std::fmt;
+
+#[cfg(feature = "json")]
+use serde::Serialize;
+
+use crate::kind::IdKind;
+
+/// Kinds whose rules differ by country, in the order they are reported.
+pub const COUNTRY_KINDS: [IdKind; 8] = [
+    IdKind::PersonalId,
+    IdKind::TaxId,
+    IdKind::CompanyId,
+    IdKind::Vat,
+    IdKind::Iban,
+    IdKind::BankAccount,
+    IdKind::Passport,
+    IdKind::DriverLicense,
+];
+
+/// How closely a country's scheme is modelled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "json", derive(Serialize))]
+#[cfg_attr(feature = "json", serde(rename_all = "snake_case"))]
+pub enum Fidelity {
+    /// Generated codes carry a check character and the validator verifies it.
+    Checksum,
+    /// Structure only: length, characters, dates or ranges.
+    FormatOnly,
+    /// The country uses its parent's scheme.
+    Alias,
+}
+
+impl fmt::Display for Fidelity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Fidelity::Checksum => "checksum",
+            Fidelity::FormatOnly => "format_only",
+            Fidelity::Alias => "alias",
+        })
+    }
+}
+
+/// Support for one kind in one country.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "json", derive(Serialize))]
+pub struct Support {
+    pub kind: IdKind,
+    pub fidelity: Fidelity,
+    /// Country whose scheme is used, for [`Fidelity::Alias`].
+    pub parent: Option<&'static str>,
+}
+
+/// Supported kinds for one country.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "json", derive(Serialize))]
+pub struct CountryCoverage {
+    pub code: &'static str,
+    pub name: &'static str,
+    /// Supported kinds, in [`COUNTRY_KINDS`] order. Unsupported kinds are
+    /// left out.
+    pub kinds: Vec<Support>,
+}
+
+impl CountryCoverage {
+    /// Support for `kind`, if any.
+    pub fn get(&self, kind: IdKind) -> Option<&Support> {
+        self.kinds.iter().find(|s| s.kind == kind)
+    }
+}
+
+/// Coverage of every country, sorted by country code.
+///
+/// Fidelity is measured the same way as in [`crate::describe`], so schemes
+/// registered at runtime are included. This generates and checks a few
+/// thousand codes, so call it once and keep the result.
+pub fn coverage() -> Vec<CountryCoverage> {
+    let mut codes = crate::countries::all_codes();
+    codes.dedup();
+    codes
+        .into_iter()
+        .map(|code| CountryCoverage {
+            code,
+            name: crate::countries::get_country_name(code).unwrap_or("Unknown"),
+            kinds: COUNTRY_KINDS
+                .iter()
+                .filter_map(|&kind| support(kind, code))
+                .collect(),
+        })
+        .collect()
+}
+
+/// Support for `kind` in `country`, or `None` if it cannot be generated.
+pub fn support(kind: IdKind, country: &str) -> Option<Support> {
+    let country = country.to_uppercase();
+    let has_checksum = crate::describe::has_checksum(kind, Some(&country))?;
+    let effective = kind.effective_country(&country);
+    // GR is EL for VAT, which is a different name rather than a parent
+    let parent = crate::countries::resolve_territory(&country)
+        .filter(|parent| effective.as_deref() == Some(*parent));
+    let fidelity = match (parent, has_checksum) {
+        (Some(_), _) => Fidelity::Alias,
+        (None, true) => Fidelity::Checksum,
+        (None, false) => Fidelity::FormatOnly,
+    };
+    Some(Support {
+        kind,
+        fidelity,
+        parent,
+    })
+}
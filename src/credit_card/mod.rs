@@ -7,7 +7,7 @@ use serde::Serialize;
 #[cfg_attr(feature = "json", derive(Serialize))]
 pub struct CardResult {
     pub brand: String,
-    pub number: String,
+    pub number: crate::CardNumber,
     pub formatted: String,
     pub cvv: String,
     pub expiry: String,
@@ -109,7 +109,7 @@ impl Registry {
 
         Some(CardResult {
             brand: brand_name.to_uppercase(),
-            number,
+            number: crate::CardNumber::from_generated(number),
             formatted,
             cvv,
             expiry,
@@ -128,6 +128,31 @@ impl Registry {
         luhn_check_digit(payload) == check
     }
 
+    /// Brand of a card number from its prefix and length, as in
+    /// [`CardResult::brand`] (e.g. `VISA`). `None` for unknown prefixes.
+    pub fn brand(&self, number: &str) -> Option<&'static str> {
+        let digits: String = number.chars().filter(|c| c.is_ascii_digit()).collect();
+        let prefix = |n: usize| digits.get(..n).and_then(|p| p.parse::<u32>().ok());
+        match digits.len() {
+            15 if matches!(prefix(2), Some(34 | 37)) => Some("AMEX"),
+            14 if matches!(prefix(3), Some(300..=305)) || matches!(prefix(2), Some(36 | 38)) => {
+                Some("DINERS")
+            }
+            _ if prefix(1) == Some(4) => Some("VISA"),
+            16 if matches!(prefix(2), Some(51..=55)) || matches!(prefix(4), Some(2221..=2720)) => {
+                Some("MASTERCARD")
+            }
+            16 if prefix(4) == Some(6011)
+                || prefix(2) == Some(65)
+                || matches!(prefix(3), Some(644..=649)) =>
+            {
+                Some("DISCOVER")
+            }
+            16 if matches!(prefix(4), Some(3528..=3589)) => Some("JCB"),
+            _ => None,
+        }
+    }
+
     pub fn format(&self, brand: &str, number: &str) -> String {
         match brand.to_lowercase().as_str() {
             "amex" if number.len() == 15 => {
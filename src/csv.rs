@@ -1,11 +1,12 @@
-//! Optional CSV formatting for IBAN and personal ID results.
+//! Optional CSV formatting for IBAN and personal ID results, plus a small
+//! streaming RFC 4180 reader.
 //!
 //! Enable with the `csv` feature flag:
 //! ```toml
 //! idsmith = { version = "...", features = ["csv"] }
 //! ```
 
-use std::io::Write;
+use std::io::{BufRead, Write};
 
 use crate::bank_account::AccountResult;
 use crate::company_id::CompanyResult;
@@ -28,6 +29,111 @@ fn csv_field(s: &str) -> String {
     }
 }
 
+/// Format a list of fields as a CSV row, quoting where required.
+pub fn record_row<S: AsRef<str>>(fields: &[S]) -> String {
+    fields
+        .iter()
+        .map(|f| csv_field(f.as_ref()))
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// Streaming RFC 4180 reader.
+///
+/// Handles quoted fields containing commas, doubled quotes and line breaks,
+/// and accepts both `\n` and `\r\n` record terminators.
+///
+/// ```rust
+/// use idsmith::csv::Reader;
+///
+/// let data = "name,note\nAda,\"likes \"\"math\"\", a lot\"\n";
+/// let mut reader = Reader::new(data.as_bytes());
+/// assert_eq!(reader.read_record().unwrap().unwrap(), ["name", "note"]);
+/// assert_eq!(
+///     reader.read_record().unwrap().unwrap(),
+///     ["Ada", "likes \"math\", a lot"]
+/// );
+/// assert!(reader.read_record().unwrap().is_none());
+/// ```
+pub struct Reader<R> {
+    inner: R,
+    line: String,
+}
+
+impl<R: BufRead> Reader<R> {
+    pub fn new(inner: R) -> Self {
+        Self {
+            inner,
+            line: String::new(),
+        }
+    }
+
+    /// Read the next record, or `None` at end of input.
+    pub fn read_record(&mut self) -> std::io::Result<Option<Vec<String>>> {
+        let mut fields = Vec::new();
+        let mut field = String::new();
+        let mut in_quotes = false;
+        let mut started = false;
+
+        loop {
+            self.line.clear();
+            if self.inner.read_line(&mut self.line)? == 0 {
+                if !started {
+                    return Ok(None);
+                }
+                if in_quotes {
+                    return Err(std::io::Error::new(
+                        std::io::ErrorKind::InvalidData,
+                        "unterminated quoted CSV field",
+                    ));
+                }
+                fields.push(field);
+                return Ok(Some(fields));
+            }
+            started = true;
+
+            let mut chars = self.line.chars().peekable();
+            while let Some(c) = chars.next() {
+                if in_quotes {
+                    if c == '"' {
+                        if chars.peek() == Some(&'"') {
+                            chars.next();
+                            field.push('"');
+                        } else {
+                            in_quotes = false;
+                        }
+                    } else {
+                        field.push(c);
+                    }
+                    continue;
+                }
+                match c {
+                    '"' if field.is_empty() => in_quotes = true,
+                    ',' => fields.push(std::mem::take(&mut field)),
+                    '\r' if chars.peek() == Some(&'\n') => {}
+                    '\n' => {
+                        fields.push(field);
+                        return Ok(Some(fields));
+                    }
+                    _ => field.push(c),
+                }
+            }
+            if !in_quotes {
+                fields.push(field);
+                return Ok(Some(fields));
+            }
+        }
+    }
+}
+
+impl<R: BufRead> Iterator for Reader<R> {
+    type Item = std::io::Result<Vec<String>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.read_record().transpose()
+    }
+}
+
 /// CSV header for IBAN rows.
 pub const IBAN_HEADER: &str = "country,iban,iban_formatted,valid";
 
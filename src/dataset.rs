@@ -0,0 +1,581 @@
+//! Multi-column test datasets described by a schema.
+//!
+//! A [`Schema`] lists the columns of a table and how many rows to produce.
+//! Each row draws one country from the schema's `country`; identifier
+//! columns without a country of their own use it, so the personal ID, IBAN
+//! and tax ID in a row belong to the same person. Derived columns copy a
+//! field from another column's result, e.g. the date of birth decoded from
+//! a personal ID.
+//!
+//! ```toml
+//! rows = 100
+//! country = { DE = 50, FR = 30, sepa = 20 }
+//!
+//! [[columns]]
+//! name = "country"
+//! field = "country"
+//!
+//! [[columns]]
+//! name = "national_id"
+//! kind = "id"
+//! gender = "f"
+//!
+//! [[columns]]
+//! name = "birth_date"
+//! from = "national_id"
+//! field = "dob"
+//!
+//! [[columns]]
+//! name = "iban"
+//! kind = "iban"
+//! formatted = true
+//! nulls = 0.1
+//! ```
+//!
+//! `country` (on the schema or a column) is a code, a group (`eu`, `eea`,
+//! `sepa`, `eurozone`), a list of those with equal weights, or a table of
+//! weights. A group's weight is shared by the members that every column
+//! using it supports.
+//!
+//! Column fields:
+//!
+//! | Field | Meaning |
+//! |-------|---------|
+//! | `name` | column header |
+//! | `kind` | identifier kind (`iban`, `account`, `id`, `card`, `swift`, `company`, `license`, `tax`, `passport`, `lei`, `vat`) |
+//! | `country` | country for this column instead of the row's |
+//! | `from`, `field` | copy `field` of the result in column `from` (`dob`, `gender`, `brand`, `bank_code`, ...) |
+//! | `field` alone | `country` or `country_name` of the row |
+//! | `nulls` | fraction of empty cells, 0 to 1 |
+//! | `formatted` | formatted IBAN, account or card number instead of the compact one |
+//! | `gender`, `year`, `region`, `brand`, `holder_type` | generation options |
+//!
+//! ```rust
+//! use idsmith::dataset::Schema;
+//!
+//! let schema = Schema::from_toml(r#"
+//!     rows = 3
+//!     country = "EE"
+//!
+//!     [[columns]]
+//!     name = "id"
+//!     kind = "id"
+//!
+//!     [[columns]]
+//!     name = "dob"
+//!     from = "id"
+//!     field = "dob"
+//! "#).unwrap();
+//!
+//! let rows = schema.generate(&mut rand::thread_rng()).unwrap();
+//! assert_eq!(rows.len(), 3);
+//! assert!(rows[0][1].is_some());
+//! ```
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::countries::Membership;
+use crate::kind::IdKind;
+use crate::personal_id::date::Gender;
+
+/// A table of identifiers to generate. See the [module docs](self).
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Schema {
+    /// Number of rows [`Schema::generate`] produces.
+    #[serde(default = "default_rows")]
+    pub rows: usize,
+    /// Country of each row.
+    #[serde(default)]
+    pub country: Option<Countries>,
+    pub columns: Vec<Column>,
+}
+
+fn default_rows() -> usize {
+    10
+}
+
+/// A country, a group, a list or a weighted table of them.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(untagged)]
+pub enum Countries {
+    One(String),
+    List(Vec<String>),
+    Weighted(BTreeMap<String, f64>),
+}
+
+/// One column of a [`Schema`].
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Column {
+    pub name: String,
+    pub kind: Option<IdKind>,
+    pub country: Option<Countries>,
+    pub from: Option<String>,
+    pub field: Option<String>,
+    /// Fraction of rows left empty.
+    #[serde(default)]
+    pub nulls: f64,
+    #[serde(default)]
+    pub formatted: bool,
+    pub gender: Option<String>,
+    pub year: Option<u16>,
+    pub region: Option<String>,
+    pub brand: Option<String>,
+    pub holder_type: Option<String>,
+}
+
+/// Countries with their weights, after expanding groups.
+type Weights = Vec<(String, f64)>;
+
+enum Plan {
+    RowCountry {
+        name: bool,
+    },
+    Identifier {
+        column: Box<Column>,
+        kind: IdKind,
+        countries: Option<Weights>,
+    },
+    Derived {
+        source: usize,
+        field: String,
+        nulls: f64,
+    },
+}
+
+/// A checked schema, ready to produce rows.
+pub struct Generator {
+    headers: Vec<String>,
+    countries: Option<Weights>,
+    plans: Vec<Plan>,
+}
+
+impl Schema {
+    pub fn from_json(s: &str) -> Result<Schema, String> {
+        serde_json::from_str(s).map_err(|e| format!("Invalid schema JSON: {}", e))
+    }
+
+    pub fn from_toml(s: &str) -> Result<Schema, String> {
+        toml::from_str(s).map_err(|e| format!("Invalid schema TOML: {}", e))
+    }
+
+    /// Read a schema from a `.toml` or `.json` file.
+    pub fn load(path: impl AsRef<Path>) -> Result<Schema, String> {
+        let path = path.as_ref();
+        let text = std::fs::read_to_string(path)
+            .map_err(|e| format!("Cannot read {}: {}", path.display(), e))?;
+        let is_toml = path
+            .extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("toml"));
+        let schema = if is_toml {
+            Schema::from_toml(&text)
+        } else {
+            Schema::from_json(&text)
+        };
+        schema.map_err(|e| format!("{}: {}", path.display(), e))
+    }
+
+    /// Check the schema and resolve its countries.
+    ///
+    /// Fails on duplicate or dangling column names, unknown derived fields,
+    /// and countries a column cannot generate.
+    pub fn compile(&self) -> Result<Generator, String> {
+        if self.columns.is_empty() {
+            return Err("Schema has no columns".into());
+        }
+        let mut headers: Vec<String> = Vec::new();
+        for column in &self.columns {
+            if column.name.trim().is_empty() {
+                return Err("Column name must not be empty".into());
+            }
+            if headers.contains(&column.name) {
+                return Err(format!("Duplicate column: {}", column.name));
+            }
+            if !(0.0..=1.0).contains(&column.nulls) {
+                return Err(format!("{}: nulls must be between 0 and 1", column.name));
+            }
+            headers.push(column.name.clone());
+        }
+
+        // Row countries must work for every identifier column that uses them
+        let row_columns: Vec<&Column> = self
+            .columns
+            .iter()
+            .filter(|c| c.kind.is_some() && c.country.is_none())
+            .collect();
+        let countries = match &self.country {
+            Some(countries) => Some(resolve(countries, |country| {
+                row_columns
+                    .iter()
+                    .try_for_each(|c| sample(c, Some(country)).map(|_| ()))
+            })?),
+            None => None,
+        };
+
+        let mut plans = Vec::new();
+        // Sample result of each identifier column, to check derived fields
+        let mut samples: Vec<Option<Value>> = Vec::new();
+        for column in &self.columns {
+            let (plan, sample_value) = match (column.kind, &column.from, &column.field) {
+                (Some(kind), None, None) => {
+                    if column.formatted
+                        && !matches!(
+                            kind,
+                            IdKind::Iban | IdKind::BankAccount | IdKind::CreditCard
+                        )
+                    {
+                        return Err(format!(
+                            "{}: formatted only applies to iban, account and card",
+                            column.name
+                        ));
+                    }
+                    let own = match &column.country {
+                        Some(c) => Some(resolve(c, |country| {
+                            sample(column, Some(country)).map(|_| ())
+                        })?),
+                        None => None,
+                    };
+                    let first = own.as_ref().or(countries.as_ref()).map(|w| w[0].0.as_str());
+                    let value = sample(column, first)?;
+                    let plan = Plan::Identifier {
+                        column: Box::new(column.clone()),
+                        kind,
+                        countries: own,
+                    };
+                    (plan, Some(value))
+                }
+                (None, Some(from), Some(field)) => {
+                    let source = self
+                        .columns
+                        .iter()
+                        .position(|c| &c.name == from)
+                        .ok_or_else(|| format!("{}: no column named {}", column.name, from))?;
+                    let value = samples.get(source).cloned().flatten().ok_or_else(|| {
+                        format!(
+                            "{}: {} must be an earlier identifier column",
+                            column.name, from
+                        )
+                    })?;
+                    if value.get(field).is_none() {
+                        let fields: Vec<&str> = value
+                            .as_object()
+                            .map(|o| o.keys().map(String::as_str).collect())
+                            .unwrap_or_default();
+                        return Err(format!(
+                            "{}: {} has no field {}. Use one of: {}",
+                            column.name,
+                            from,
+                            field,
+                            fields.join(", ")
+                        ));
+                    }
+                    let plan = Plan::Derived {
+                        source,
+                        field: field.clone(),
+                        nulls: column.nulls,
+                    };
+                    (plan, None)
+                }
+                (None, None, Some(field)) => {
+                    let name = match field.as_str() {
+                        "country" => false,
+                        "country_name" => true,
+                        other => {
+                            return Err(format!(
+                                "{}: unknown row field {}. Use country or country_name.",
+                                column.name, other
+                            ))
+                        }
+                    };
+                    if countries.is_none() {
+                        return Err(format!(
+                            "{}: the schema needs a country for row fields",
+                            column.name
+                        ));
+                    }
+                    (Plan::RowCountry { name }, None)
+                }
+                _ => {
+                    return Err(format!(
+                        "{}: set either kind, from and field, or field",
+                        column.name
+                    ))
+                }
+            };
+            plans.push(plan);
+            samples.push(sample_value);
+        }
+
+        Ok(Generator {
+            headers,
+            countries,
+            plans,
+        })
+    }
+
+    /// Generate [`Schema::rows`] rows; `None` cells are empty.
+    pub fn generate(&self, rng: &mut impl Rng) -> Result<Vec<Vec<Option<String>>>, String> {
+        let generator = self.compile()?;
+        (0..self.rows).map(|_| generator.row(rng)).collect()
+    }
+}
+
+impl Generator {
+    pub fn headers(&self) -> &[String] {
+        &self.headers
+    }
+
+    /// Generate one row; `None` cells are empty.
+    pub fn row(&self, rng: &mut impl Rng) -> Result<Vec<Option<String>>, String> {
+        let row_country = self.countries.as_ref().map(|w| pick(w, rng));
+        let mut results: Vec<Option<Value>> = Vec::with_capacity(self.plans.len());
+        let mut cells = Vec::with_capacity(self.plans.len());
+        for plan in &self.plans {
+            let (result, cell) = match plan {
+                Plan::RowCountry { name } => {
+                    let code = row_country.expect("checked in compile");
+                    let cell = if *name {
+                        crate::countries::get_country_name(code).unwrap_or(code)
+                    } else {
+                        code
+                    };
+                    (None, Some(cell.to_string()))
+                }
+                Plan::Identifier {
+                    column,
+                    kind,
+                    countries,
+                } => {
+                    if is_null(column.nulls, rng) {
+                        (None, None)
+                    } else {
+                        let country = countries.as_ref().map(|w| pick(w, rng)).or(row_country);
+                        let result = generate(*kind, country, column, rng).ok_or_else(|| {
+                            format!(
+                                "{}: cannot generate {} for {}",
+                                column.name,
+                                kind,
+                                country.unwrap_or("any country")
+                            )
+                        })?;
+                        let cell = result.get(primary(*kind, column.formatted)).and_then(text);
+                        (Some(result), cell)
+                    }
+                }
+                Plan::Derived {
+                    source,
+                    field,
+                    nulls,
+                } => {
+                    let cell = match &results[*source] {
+                        Some(result) if !is_null(*nulls, rng) => result.get(field).and_then(text),
+                        _ => None,
+                    };
+                    (None, cell)
+                }
+            };
+            results.push(result);
+            cells.push(cell);
+        }
+        Ok(cells)
+    }
+}
+
+fn is_null(nulls: f64, rng: &mut impl Rng) -> bool {
+    nulls > 0.0 && rng.gen_bool(nulls)
+}
+
+fn text(value: &Value) -> Option<String> {
+    match value {
+        Value::Null => None,
+        Value::String(s) => Some(s.clone()),
+        other => Some(other.to_string()),
+    }
+}
+
+/// Field of the result that fills an identifier column.
+fn primary(kind: IdKind, formatted: bool) -> &'static str {
+    match (kind, formatted) {
+        (IdKind::Iban | IdKind::BankAccount | IdKind::CreditCard, true) => "formatted",
+        (IdKind::Iban, false) => "iban",
+        (IdKind::BankAccount, false) => "raw",
+        (IdKind::CreditCard, false) => "number",
+        _ => "code",
+    }
+}
+
+fn pick<'a>(weights: &'a Weights, rng: &mut impl Rng) -> &'a str {
+    let total: f64 = weights.iter().map(|(_, w)| w).sum();
+    let mut target = rng.gen_range(0.0..total);
+    for (country, weight) in weights {
+        if target < *weight {
+            return country;
+        }
+        target -= weight;
+    }
+    &weights[weights.len() - 1].0
+}
+
+/// Expand groups and check each country with `supports`.
+fn resolve(
+    countries: &Countries,
+    supports: impl Fn(&str) -> Result<(), String>,
+) -> Result<Weights, String> {
+    let entries: Vec<(String, f64)> = match countries {
+        Countries::One(c) => vec![(c.clone(), 1.0)],
+        Countries::List(list) => list.iter().map(|c| (c.clone(), 1.0)).collect(),
+        Countries::Weighted(table) => table.iter().map(|(c, w)| (c.clone(), *w)).collect(),
+    };
+    let mut weights = Weights::new();
+    for (entry, weight) in entries {
+        if !weight.is_finite() || weight < 0.0 {
+            return Err(format!("Invalid weight for {}: {}", entry, weight));
+        }
+        match entry.parse::<Membership>() {
+            Ok(group) => {
+                let members: Vec<&str> = crate::countries::members(group)
+                    .map(|c| c.code)
+                    .filter(|c| supports(c).is_ok())
+                    .collect();
+                if members.is_empty() {
+                    return Err(format!("No country in {} fits every column", group));
+                }
+                let share = weight / members.len() as f64;
+                weights.extend(members.iter().map(|c| (c.to_string(), share)));
+            }
+            Err(_) => {
+                let code = entry.to_uppercase();
+                supports(&code)?;
+                weights.push((code, weight));
+            }
+        }
+    }
+    if weights.iter().map(|(_, w)| w).sum::<f64>() <= 0.0 {
+        return Err("Country weights must not all be zero".into());
+    }
+    Ok(weights)
+}
+
+/// Generate one value for `column` with a fixed seed, to check support.
+fn sample(column: &Column, country: Option<&str>) -> Result<Value, String> {
+    let kind = column.kind.expect("identifier column");
+    generate(kind, country, column, &mut StdRng::seed_from_u64(0)).ok_or_else(|| {
+        format!(
+            "{}: cannot generate {} for {}",
+            column.name,
+            kind,
+            country.unwrap_or("any country")
+        )
+    })
+}
+
+/// Generate the full result for `kind` as JSON, so derived columns can read
+/// any of its fields.
+fn generate(
+    kind: IdKind,
+    country: Option<&str>,
+    column: &Column,
+    rng: &mut impl Rng,
+) -> Option<Value> {
+    let country = country.map(str::to_uppercase);
+    let value = match kind {
+        IdKind::Iban => {
+            let code = crate::iban::generate_iban(country.as_deref(), rng).ok()?;
+            serde_json::to_value(crate::iban::IbanResult {
+                country: code[..2].to_string(),
+                formatted: crate::iban::format_iban(&code),
+                iban: crate::Iban::from_generated(code),
+                valid: true,
+            })
+        }
+        IdKind::BankAccount => {
+            let registry = crate::bank_accounts();
+            let cc = match country {
+                Some(c) => c,
+                None => {
+                    let countries = registry.list_countries();
+                    countries[rng.gen_range(0..countries.len())].0.to_string()
+                }
+            };
+            serde_json::to_value(registry.generate(&cc, &Default::default(), rng)?)
+        }
+        IdKind::PersonalId => {
+            let registry = crate::personal_ids();
+            let cc = match country {
+                Some(c) => c,
+                None => {
+                    let countries = registry.list_countries();
+                    countries[rng.gen_range(0..countries.len())].0.to_string()
+                }
+            };
+            let opts = crate::personal_id::GenOptions {
+                gender: Gender::from_str_opt(column.gender.as_deref()),
+                year: column.year,
+                region: column.region.clone(),
+                ..Default::default()
+            };
+            let code = registry.generate(&cc, &opts, rng)?;
+            serde_json::to_value(registry.parse(&cc, &code)?)
+        }
+        IdKind::CreditCard => {
+            let opts = crate::credit_card::GenOptions {
+                brand: column.brand.clone(),
+                ..Default::default()
+            };
+            serde_json::to_value(crate::credit_cards().generate(&opts, rng)?)
+        }
+        IdKind::Swift => {
+            let opts = crate::swift::GenOptions { country };
+            serde_json::to_value(crate::swift_codes().generate(&opts, rng))
+        }
+        IdKind::CompanyId => {
+            let opts = crate::company_id::GenOptions {
+                country,
+                ..Default::default()
+            };
+            serde_json::to_value(crate::company_ids().generate(&opts, rng)?)
+        }
+        IdKind::DriverLicense => {
+            let opts = crate::driver_license::GenOptions {
+                country,
+                state: column.region.clone(),
+                ..Default::default()
+            };
+            serde_json::to_value(crate::driver_licenses().generate(&opts, rng)?)
+        }
+        IdKind::TaxId => {
+            let opts = crate::tax_id::GenOptions {
+                country,
+                holder_type: column.holder_type.clone(),
+                ..Default::default()
+            };
+            serde_json::to_value(crate::tax_ids().generate(&opts, rng)?)
+        }
+        IdKind::Passport => {
+            let opts = crate::passport::GenOptions {
+                country,
+                ..Default::default()
+            };
+            serde_json::to_value(crate::passports().generate(&opts, rng)?)
+        }
+        IdKind::Lei => {
+            let opts = crate::lei::GenOptions { country };
+            serde_json::to_value(crate::lei_codes().generate(&opts, rng))
+        }
+        IdKind::Vat => {
+            let opts = crate::vat::GenOptions {
+                country,
+                ..Default::default()
+            };
+            serde_json::to_value(crate::vat_ids().generate(&opts, rng)?)
+        }
+    };
+    value.ok()
+}
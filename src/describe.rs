@@ -0,0 +1,1021 @@
+//! Describe the format of an identifier scheme.
+//!
+//! [`describe`] reports the length, character set, separators, checksum,
+//! encoded data, an example and an anchored regex for a kind and country.
+//! The description is derived from the generator and validator themselves,
+//! including schemes registered at runtime: a fixed-seed sample of generated
+//! codes is the starting point, and the validator is then probed for other
+//! lengths and characters it accepts and for the conventional separators
+//! (space, `-`, `.` and `/`), so the lengths and regex cover every code that
+//! validates when written with those, not just the generated ones.
+//! Punctuation the validator requires, such as the century sign of a Finnish
+//! henkilötunnus, is part of the code rather than a separator.
+//! Single-character mutations probe for a checksum.
+//!
+//! ```rust
+//! use idsmith::describe::{describe, Charset};
+//! use idsmith::kind::IdKind;
+//!
+//! let info = describe(IdKind::PersonalId, Some("EE")).unwrap();
+//! assert_eq!((info.min_length, info.max_length), (11, 11));
+//! assert_eq!(info.charset, Charset::Digits);
+//! assert!(info.has_checksum);
+//! assert!(info.encodes_dob && info.encodes_gender);
+//! assert_eq!(info.regex, "^[0-9]{11}$");
+//! ```
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+#[cfg(feature = "json")]
+use serde::Serialize;
+
+use crate::iban::CharType;
+use crate::kind::IdKind;
+use crate::personal_id::checksum::{
+    iso7064_hybrid, iso7064_mod97_10, iso7064_pure, luhn_check, verhoeff_check,
+};
+
+/// Number of generated codes the description is derived from.
+const SAMPLES: usize = 200;
+
+/// Codes mutated when probing for a checksum.
+const PROBES: usize = 40;
+
+/// Fixed so that descriptions are stable between runs.
+const SEED: u64 = 0x1d5_317;
+
+/// More distinct layouts than this are summarised as a length range.
+const MAX_PATTERNS: usize = 6;
+
+/// Lengths up to this far past the longest generated code are probed.
+const LENGTH_SLACK: usize = 8;
+
+/// Valid codes per length that are probed for separators.
+const REPRESENTATIVES: usize = 2;
+
+/// Separators probed between and around the characters of a code. Other
+/// punctuation a lenient validator skips over is not reported.
+const SEPARATORS: &str = " -./";
+
+/// Tried in turn when recomputing a check character.
+const CHECK_CHARS: &str = "0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// Characters used by a scheme, ignoring separators.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "json", derive(Serialize))]
+#[cfg_attr(feature = "json", serde(rename_all = "lowercase"))]
+pub enum Charset {
+    Digits,
+    Letters,
+    Alphanumeric,
+}
+
+impl fmt::Display for Charset {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Charset::Digits => "digits",
+            Charset::Letters => "letters",
+            Charset::Alphanumeric => "letters and digits",
+        })
+    }
+}
+
+/// Format description of one identifier scheme.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "json", derive(Serialize))]
+pub struct SchemeInfo {
+    pub kind: IdKind,
+    pub country: Option<String>,
+    /// Document or scheme name (e.g. "Isikukood", "IBAN").
+    pub name: String,
+    /// Accepted length, not counting separators but counting required
+    /// punctuation such as a century sign.
+    pub min_length: usize,
+    pub max_length: usize,
+    pub charset: Charset,
+    /// Characters the validator accepts between (or around) the letters
+    /// and digits: those in generated codes, and any of space, `-`, `.` and
+    /// `/`.
+    pub separators: Vec<char>,
+    /// True if single-character changes are rejected by the validator.
+    pub has_checksum: bool,
+    /// Name of the check algorithm declared by the scheme (see
+    /// [`IdKind::checksum`]). Registered schemes that declare none report
+    /// a recognised standard algorithm or `"undeclared"`; kinds described
+    /// across all countries report `"varies by country"`.
+    pub checksum: Option<String>,
+    pub encodes_dob: bool,
+    pub encodes_gender: bool,
+    pub example: String,
+    /// Anchored regular expression matching every code the validator
+    /// accepts with these separators (and possibly some it rejects on
+    /// checksum or date grounds).
+    pub regex: String,
+}
+
+/// Describe the scheme for `kind` in `country`.
+///
+/// Returns `None` when the kind needs a country and none is given, or when
+/// the country is not supported. For IBANs, VAT numbers, LEIs and SWIFT
+/// codes `country` may be `None` to describe the kind across all countries.
+pub fn describe(kind: IdKind, country: Option<&str>) -> Option<SchemeInfo> {
+    if kind.requires_country() && country.is_none() {
+        return None;
+    }
+    let country = country.map(|c| c.to_uppercase());
+    let cc = country.as_deref();
+
+    let mut rng = StdRng::seed_from_u64(SEED);
+    let samples = generate_samples(kind, cc, SAMPLES, &mut rng)?;
+
+    let compact: Vec<String> = samples.iter().map(|s| compact(s)).collect();
+    let checked = has_checksum(kind, cc).unwrap_or(false);
+    let checksum = checked.then(|| {
+        kind.checksum(cc)
+            .or_else(|| detect_algorithm(&compact).map(str::to_string))
+            .unwrap_or_else(|| {
+                if cc.is_some() {
+                    "undeclared"
+                } else {
+                    "varies by country"
+                }
+                .to_string()
+            })
+    });
+    let (encodes_dob, encodes_gender) = encoded_fields(kind, cc, &samples);
+
+    let layouts = accepted_layouts(kind, cc, &samples, checked, &mut rng);
+    let name = document_name(kind, cc, &mut rng);
+    let separators: BTreeSet<char> = layouts
+        .iter()
+        .flat_map(|l| l.gaps.iter().flatten().copied())
+        .collect();
+
+    Some(SchemeInfo {
+        kind,
+        name,
+        country,
+        min_length: layouts.first().map_or(0, |l| l.classes.len()),
+        max_length: layouts.last().map_or(0, |l| l.classes.len()),
+        charset: charset(&layouts),
+        separators: separators.into_iter().collect(),
+        has_checksum: checked,
+        checksum,
+        encodes_dob,
+        encodes_gender,
+        example: samples[0].clone(),
+        regex: build_regex(&layouts),
+    })
+}
+
+/// What the validator accepts for one scheme, as found by [`describe`].
+pub(crate) struct Rules {
+    /// Accepted lengths of [`crate::detect::normalize`]d codes, which drop
+    /// `-` even where it is required.
+    pub lengths: BTreeSet<usize>,
+    pub charset: Charset,
+    pub has_checksum: bool,
+}
+
+/// The lengths, characters and check character [`describe`] reports for
+/// `kind` in `country`, without the rest of the description. `None` if the
+/// country is not supported.
+pub(crate) fn rules(kind: IdKind, country: Option<&str>) -> Option<Rules> {
+    let country = country.map(|c| c.to_uppercase());
+    let cc = country.as_deref();
+    let mut rng = StdRng::seed_from_u64(SEED);
+    let samples = generate_samples(kind, cc, SAMPLES, &mut rng)?;
+    let checked = has_checksum(kind, cc).unwrap_or(false);
+    let layouts = accepted_layouts(kind, cc, &samples, checked, &mut rng);
+    Some(Rules {
+        lengths: layouts
+            .iter()
+            .flat_map(|l| {
+                let punctuation = l.classes.iter().filter(|c| c.has_punctuation()).count();
+                [l.classes.len(), l.classes.len() - punctuation]
+            })
+            .collect(),
+        charset: charset(&layouts),
+        has_checksum: checked,
+    })
+}
+
+/// Whether `kind` in `country` has a check character, using the same probe
+/// as [`describe`] without the rest of the description. `None` if the
+/// country is not supported.
+pub(crate) fn has_checksum(kind: IdKind, country: Option<&str>) -> Option<bool> {
+    let mut rng = StdRng::seed_from_u64(SEED);
+    let samples = generate_samples(kind, country, PROBES, &mut rng)?;
+    Some(probe_checksum(kind, country, &samples, &mut rng))
+}
+
+fn generate_samples(
+    kind: IdKind,
+    country: Option<&str>,
+    count: usize,
+    rng: &mut StdRng,
+) -> Option<Vec<String>> {
+    (0..count).map(|_| kind.generate(country, rng)).collect()
+}
+
+fn compact(code: &str) -> String {
+    code.chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .map(|c| c.to_ascii_uppercase())
+        .collect()
+}
+
+fn document_name(kind: IdKind, country: Option<&str>, rng: &mut StdRng) -> String {
+    let cc = country.unwrap_or("");
+    let name = match kind {
+        IdKind::Iban => Some("IBAN".to_string()),
+        IdKind::BankAccount => crate::bank_accounts()
+            .list_countries()
+            .into_iter()
+            .find(|(code, _, _, _)| *code == cc)
+            .map(|(_, _, name, _)| name.to_string()),
+        IdKind::PersonalId => crate::personal_ids().name(cc),
+        IdKind::CreditCard => Some("Payment card number".to_string()),
+        IdKind::Swift => Some("SWIFT/BIC".to_string()),
+        IdKind::CompanyId => crate::company_ids()
+            .generate(
+                &crate::company_id::GenOptions {
+                    country: country.map(str::to_string),
+                    ..Default::default()
+                },
+                rng,
+            )
+            .map(|r| r.name),
+        IdKind::DriverLicense => crate::driver_licenses()
+            .generate(
+                &crate::driver_license::GenOptions {
+                    country: country.map(str::to_string),
+                    state: None,
+                    ..Default::default()
+                },
+                rng,
+            )
+            .map(|r| r.name),
+        IdKind::TaxId => crate::tax_ids()
+            .generate(
+                &crate::tax_id::GenOptions {
+                    country: country.map(str::to_string),
+                    holder_type: None,
+                    ..Default::default()
+                },
+                rng,
+            )
+            .map(|r| r.name),
+        IdKind::Passport => crate::passports()
+            .generate(
+                &crate::passport::GenOptions {
+                    country: country.map(str::to_string),
+                    ..Default::default()
+                },
+                rng,
+            )
+            .map(|r| r.name),
+        IdKind::Lei => Some("LEI".to_string()),
+        IdKind::Vat => Some("VAT number".to_string()),
+    };
+    name.unwrap_or_else(|| kind.as_str().to_string())
+}
+
+/// Change one letter or digit in a sample to another of the same class and
+/// see how often the validator notices. A checksum catches (nearly) every
+/// such change wherever it happens; format-only schemes only reject changes
+/// that break a date or range.
+fn probe_checksum(
+    kind: IdKind,
+    country: Option<&str>,
+    samples: &[String],
+    rng: &mut StdRng,
+) -> bool {
+    // Per position: (rejected, tried)
+    let mut stats: Vec<(usize, usize)> = Vec::new();
+    for sample in samples.iter().take(PROBES) {
+        let chars: Vec<char> = sample.chars().collect();
+        if stats.len() < chars.len() {
+            stats.resize(chars.len(), (0, 0));
+        }
+        for (pos, &c) in chars.iter().enumerate() {
+            let Some(replacement) = mutate(c, rng) else {
+                continue;
+            };
+            let mut mutated = chars.clone();
+            mutated[pos] = replacement;
+            let mutated: String = mutated.into_iter().collect();
+            // The sample country is used for self-describing kinds so a
+            // changed country prefix does not count as a checksum failure.
+            let cc = country
+                .map(str::to_string)
+                .or_else(|| sample_country(kind, sample));
+            let valid = kind.validate(cc.as_deref(), &mutated) == Some(true);
+            stats[pos].1 += 1;
+            if !valid {
+                stats[pos].0 += 1;
+            }
+        }
+    }
+
+    let positions: Vec<f64> = stats
+        .iter()
+        .filter(|(_, tried)| *tried >= PROBES / 4)
+        .map(|(rejected, tried)| *rejected as f64 / *tried as f64)
+        .collect();
+    if positions.is_empty() {
+        return false;
+    }
+    let strict = positions.iter().filter(|&&rate| rate >= 0.8).count();
+    strict * 2 >= positions.len()
+}
+
+fn sample_country(kind: IdKind, sample: &str) -> Option<String> {
+    match kind {
+        IdKind::Iban | IdKind::Vat => sample.get(..2).map(str::to_string),
+        _ => None,
+    }
+}
+
+fn mutate(c: char, rng: &mut StdRng) -> Option<char> {
+    let (base, span) = match c {
+        '0'..='9' => (b'0', 10),
+        'A'..='Z' => (b'A', 26),
+        'a'..='z' => (b'a', 26),
+        _ => return None,
+    };
+    let offset = (c as u8 - base + rng.gen_range(1..span)) % span;
+    Some((base + offset) as char)
+}
+
+/// Recognise standard check-character algorithms by testing every sample,
+/// for registered schemes that do not declare theirs.
+fn detect_algorithm(compact: &[String]) -> Option<&'static str> {
+    type Check = fn(&[u32], &[u32]) -> bool;
+    fn digits(v: &[u32]) -> Vec<u8> {
+        v.iter().map(|&d| d as u8).collect()
+    }
+    let candidates: [(&str, usize, bool, Check); 6] = [
+        ("Luhn", 1, true, |body, check| {
+            luhn_check(&digits(body)) as u32 == check[0]
+        }),
+        ("Verhoeff", 1, true, |body, check| {
+            verhoeff_check(&digits(body)) as u32 == check[0]
+        }),
+        ("ISO 7064 MOD 11-10", 1, true, |body, check| {
+            iso7064_hybrid(body, 10) == check[0]
+        }),
+        ("ISO 7064 MOD 11-2", 1, true, |body, check| {
+            // 'X' stands for 10 and has the value 33 in base 36
+            let expected = iso7064_pure(body, 11, 2);
+            expected == check[0] || (expected == 10 && check[0] == 33)
+        }),
+        ("ISO 7064 MOD 37-36", 1, false, |body, check| {
+            iso7064_hybrid(body, 36) == check[0]
+        }),
+        ("ISO 7064 MOD 97-10", 2, true, |body, check| {
+            check[0] < 10 && check[1] < 10 && iso7064_mod97_10(body) == check[0] * 10 + check[1]
+        }),
+    ];
+
+    let values: Vec<Vec<u32>> = compact
+        .iter()
+        .map(|c| c.chars().filter_map(|ch| ch.to_digit(36)).collect())
+        .collect();
+    candidates
+        .iter()
+        .find(|(_, width, digits_only, check)| {
+            values.iter().all(|v| {
+                if v.len() <= *width {
+                    return false;
+                }
+                let (body, tail) = v.split_at(v.len() - width);
+                (!digits_only || body.iter().all(|&d| d < 10)) && check(body, tail)
+            })
+        })
+        .map(|(name, _, _, _)| *name)
+}
+
+fn encoded_fields(kind: IdKind, country: Option<&str>, samples: &[String]) -> (bool, bool) {
+    let (IdKind::PersonalId, Some(cc)) = (kind, country) else {
+        return (false, false);
+    };
+    let registry = crate::personal_ids();
+    let parsed: Vec<_> = samples
+        .iter()
+        .take(PROBES)
+        .filter_map(|s| registry.parse(cc, s))
+        .collect();
+    let all =
+        |f: fn(&crate::personal_id::IdResult) -> bool| !parsed.is_empty() && parsed.iter().all(f);
+    (all(|r| r.dob.is_some()), all(|r| r.gender.is_some()))
+}
+
+/// A code split into its letters, digits and required punctuation, and the
+/// separators between them: `gaps[i]` comes before `chars[i]`, the last gap
+/// after the end.
+#[derive(Clone)]
+struct Code {
+    chars: Vec<char>,
+    gaps: Vec<String>,
+}
+
+impl Code {
+    fn parse(s: &str, structural: &BTreeSet<char>) -> Self {
+        let mut code = Code {
+            chars: Vec::new(),
+            gaps: vec![String::new()],
+        };
+        for c in s.chars() {
+            if c.is_ascii_alphanumeric() || structural.contains(&c) {
+                code.chars.push(c);
+                code.gaps.push(String::new());
+            } else {
+                let last = code.gaps.len() - 1;
+                code.gaps[last].push(c);
+            }
+        }
+        code
+    }
+
+    fn render(&self) -> String {
+        let mut out = self.gaps[0].clone();
+        for (c, gap) in self.chars.iter().zip(&self.gaps[1..]) {
+            out.push(*c);
+            out.push_str(gap);
+        }
+        out
+    }
+
+    /// The first `len` characters, padded with the code's own characters
+    /// from the start when it is shorter.
+    fn resized(&self, len: usize) -> Self {
+        let n = self.chars.len();
+        let chars = (0..len).map(|i| self.chars[i % n]).collect();
+        let mut gaps = self.gaps[..len.min(n)].to_vec();
+        gaps.resize(len, String::new());
+        gaps.push(self.gaps[n].clone());
+        Code { chars, gaps }
+    }
+}
+
+/// The validator being described.
+struct Probe<'a> {
+    kind: IdKind,
+    country: Option<&'a str>,
+    /// Whether the scheme has a check character that [`Probe::complete`]
+    /// may recompute.
+    checked: bool,
+}
+
+impl Probe<'_> {
+    fn accepts(&self, code: &Code) -> bool {
+        self.kind.validate(self.country, &code.render()) == Some(true)
+    }
+
+    /// `code` if the validator accepts it. For checked schemes, otherwise
+    /// `code` with its last one or two characters other than `fixed`
+    /// replaced until the check passes.
+    fn complete(&self, mut code: Code, fixed: Option<usize>) -> Option<Code> {
+        if self.accepts(&code) {
+            return Some(code);
+        }
+        if !self.checked {
+            return None;
+        }
+        let free: Vec<usize> = (0..code.chars.len())
+            .rev()
+            .filter(|&i| Some(i) != fixed)
+            .take(2)
+            .collect();
+        let (&last, rest) = free.split_first()?;
+        for c in CHECK_CHARS.chars() {
+            code.chars[last] = c;
+            if self.accepts(&code) {
+                return Some(code);
+            }
+        }
+        let &prev = rest.first()?;
+        for a in '0'..='9' {
+            for b in '0'..='9' {
+                code.chars[prev] = a;
+                code.chars[last] = b;
+                if self.accepts(&code) {
+                    return Some(code);
+                }
+            }
+        }
+        None
+    }
+}
+
+/// One accepted length, not counting separators: the characters allowed at
+/// each position and the separators allowed before each one (`gaps` has one
+/// more entry, for the end).
+struct Layout {
+    classes: Vec<Class>,
+    gaps: Vec<BTreeSet<char>>,
+}
+
+/// Every layout the validator accepts. The generated samples are the
+/// starting point; the validator is then asked about other lengths, other
+/// characters at each position and extra characters at each gap, so the
+/// result also covers input idsmith accepts but never generates, such as a
+/// 13-digit card number or an SSN with dashes. IBAN lengths and classes come
+/// from the registered country formats instead, since the validator only
+/// checks mod 97, which can be met at any length.
+fn accepted_layouts(
+    kind: IdKind,
+    country: Option<&str>,
+    samples: &[String],
+    checked: bool,
+    rng: &mut StdRng,
+) -> Vec<Layout> {
+    let probe = Probe {
+        kind,
+        country,
+        checked,
+    };
+    let structural = structural_chars(&probe, samples);
+    let mut groups: BTreeMap<usize, Vec<Code>> = BTreeMap::new();
+    for code in samples.iter().map(|s| Code::parse(s, &structural)) {
+        if !code.chars.is_empty() {
+            groups.entry(code.chars.len()).or_default().push(code);
+        }
+    }
+    let registered = (kind == IdKind::Iban).then(|| iban_classes(country));
+    let generated: BTreeSet<usize> = groups.keys().copied().collect();
+    if registered.is_none() {
+        probe_lengths(&probe, &mut groups);
+    }
+    let ignored = ignored_letters(&probe, &groups);
+    // A probed length is not one if the validator skips its letters
+    groups.retain(|len, codes| {
+        generated.contains(len)
+            || codes.iter().all(|code| {
+                let mut skipped = code.clone();
+                skipped.chars.retain(|c| !ignored.contains(c));
+                skipped.gaps.truncate(skipped.chars.len() + 1);
+                skipped.chars.len() == code.chars.len() || !probe.accepts(&skipped)
+            })
+    });
+    let any_case = ignores_case(&probe, &groups);
+
+    let lengths: Vec<usize> = match &registered {
+        Some(classes) => classes.keys().copied().collect(),
+        None => groups.keys().copied().collect(),
+    };
+    let mut layouts: Vec<Layout> = Vec::new();
+    for len in lengths {
+        let codes = groups.get(&len).map(Vec::as_slice).unwrap_or_default();
+        let mut classes = match &registered {
+            Some(classes) => classes[&len].clone(),
+            None => probe_classes(&probe, codes, &ignored, rng),
+        };
+        if any_case {
+            classes.iter_mut().for_each(Class::add_lowercase);
+        }
+        let gaps = match codes {
+            [] => Vec::new(),
+            codes => probe_gaps(&probe, codes),
+        };
+        layouts.push(Layout { classes, gaps });
+    }
+
+    // Registered IBAN lengths missing from the sample take the separators
+    // found at the others
+    let any_gap: BTreeSet<char> = layouts
+        .iter()
+        .flat_map(|l| l.gaps.iter().flatten().copied())
+        .collect();
+    for layout in layouts.iter_mut().filter(|l| l.gaps.is_empty()) {
+        layout.gaps = vec![any_gap.clone(); layout.classes.len() + 1];
+    }
+    layouts
+}
+
+/// Punctuation in the samples that the validator rejects them without, such
+/// as the century sign of a Finnish henkilötunnus. It is part of the code
+/// rather than a separator.
+fn structural_chars(probe: &Probe, samples: &[String]) -> BTreeSet<char> {
+    let mut found = BTreeSet::new();
+    for sample in samples.iter().take(PROBES) {
+        for (i, c) in sample.char_indices() {
+            if c.is_ascii_alphanumeric() || found.contains(&c) {
+                continue;
+            }
+            let removed = format!("{}{}", &sample[..i], &sample[i + c.len_utf8()..]);
+            if probe.kind.validate(probe.country, &removed) != Some(true) {
+                found.insert(c);
+            }
+        }
+    }
+    found
+}
+
+/// Add the lengths near the generated ones that the validator also accepts,
+/// by cutting or padding a generated code and, for checked schemes,
+/// recomputing its check character.
+fn probe_lengths(probe: &Probe, groups: &mut BTreeMap<usize, Vec<Code>>) {
+    let generated: Vec<usize> = groups.keys().copied().collect();
+    let Some(&longest) = generated.last() else {
+        return;
+    };
+    for len in 1..=longest + LENGTH_SLACK {
+        let Some(nearest) = generated.iter().min_by_key(|l| l.abs_diff(len)) else {
+            continue;
+        };
+        if groups.contains_key(&len) {
+            continue;
+        }
+        let found = groups[nearest]
+            .iter()
+            .take(REPRESENTATIVES)
+            .find_map(|code| probe.complete(code.resized(len), None));
+        if let Some(code) = found {
+            groups.insert(len, vec![code]);
+        }
+    }
+}
+
+/// Letters the validator skips over, as card numbers do with anything that
+/// is not a digit. They are tried in the longest accepted code, where an
+/// extra letter cannot pass as part of the code, and must be accepted at
+/// most gaps so that a check that happens to still pass does not count.
+fn ignored_letters(probe: &Probe, groups: &BTreeMap<usize, Vec<Code>>) -> BTreeSet<char> {
+    let Some(code) = groups.values().next_back().and_then(|codes| codes.first()) else {
+        return BTreeSet::new();
+    };
+    ('A'..='Z')
+        .chain('a'..='z')
+        .filter(|&letter| {
+            let accepted = (0..code.gaps.len())
+                .filter(|&gap| {
+                    let mut probed = code.clone();
+                    probed.gaps[gap].push(letter);
+                    probe.accepts(&probed)
+                })
+                .count();
+            accepted * 2 > code.gaps.len()
+        })
+        .collect()
+}
+
+/// Whether the validator also accepts the codes in lowercase.
+fn ignores_case(probe: &Probe, groups: &BTreeMap<usize, Vec<Code>>) -> bool {
+    groups
+        .values()
+        .filter_map(|codes| codes.first())
+        .filter(|code| code.chars.iter().any(char::is_ascii_uppercase))
+        .any(|code| {
+            let mut lower = code.clone();
+            lower.chars.iter_mut().for_each(char::make_ascii_lowercase);
+            probe.accepts(&lower)
+        })
+}
+
+/// Characters allowed before each position of codes of one length: those
+/// in the codes themselves, plus each of [`SEPARATORS`] the validator still
+/// accepts when inserted there.
+fn probe_gaps(probe: &Probe, codes: &[Code]) -> Vec<BTreeSet<char>> {
+    let mut gaps = vec![BTreeSet::new(); codes[0].gaps.len()];
+    for code in codes {
+        for (gap, seen) in gaps.iter_mut().zip(&code.gaps) {
+            gap.extend(seen.chars());
+        }
+    }
+    for code in codes.iter().take(REPRESENTATIVES) {
+        for (pos, gap) in gaps.iter_mut().enumerate() {
+            for c in SEPARATORS.chars() {
+                if gap.contains(&c) {
+                    continue;
+                }
+                let mut probed = code.clone();
+                probed.gaps[pos].push(c);
+                if probe.accepts(&probed) {
+                    gap.insert(c);
+                }
+            }
+        }
+    }
+    gaps
+}
+
+/// Characters allowed at each position of codes of one length. Classes seen
+/// in the codes are widened to the whole class; a letter shared by every
+/// code stays literal. A digit and an uppercase letter are then tried at
+/// each position, and a lowercase one where letters are allowed, with the
+/// check character recomputed.
+/// Digits are never treated as literal since a fixed digit in a sample is
+/// usually a date or range field.
+fn probe_classes(
+    probe: &Probe,
+    codes: &[Code],
+    ignored: &BTreeSet<char>,
+    rng: &mut StdRng,
+) -> Vec<Class> {
+    let rep = &codes[0];
+    (0..rep.chars.len())
+        .map(|pos| {
+            let mut class = Class {
+                digit: codes.iter().any(|c| c.chars[pos].is_ascii_digit()),
+                ..Default::default()
+            };
+            let letters: BTreeSet<char> = codes
+                .iter()
+                .map(|c| c.chars[pos])
+                .filter(char::is_ascii_alphabetic)
+                .collect();
+            if letters.len() == 1 && codes.len() >= 10 {
+                class.other = letters;
+            } else {
+                letters.into_iter().for_each(|c| class.add(c));
+            }
+            let punctuation = codes.iter().map(|c| c.chars[pos]);
+            class
+                .other
+                .extend(punctuation.filter(|c| !c.is_ascii_alphanumeric()));
+
+            let accepts = |c: char| {
+                let mut code = rep.clone();
+                code.chars[pos] = c;
+                !ignored.contains(&c) && probe.complete(code, Some(pos)).is_some()
+            };
+            if !class.digit && accepts(rng.gen_range('0'..='9')) {
+                class.digit = true;
+            }
+            if !class.upper {
+                let c = rng.gen_range('A'..='Z');
+                if !class.other.contains(&c) && accepts(c) {
+                    class.upper = true;
+                    class.other.retain(|c| !c.is_ascii_alphabetic());
+                }
+            }
+            let lower = match class.other.iter().find(|c| c.is_ascii_alphabetic()) {
+                _ if class.upper => Some(rng.gen_range('a'..='z')),
+                Some(c) => Some(c.to_ascii_lowercase()),
+                None => None,
+            };
+            if let Some(c) = lower.filter(|&c| !class.lower && accepts(c)) {
+                if class.upper {
+                    class.lower = true;
+                } else {
+                    class.other.insert(c);
+                }
+            }
+            class
+        })
+        .collect()
+}
+
+/// Classes of the registered IBAN formats by length, for one country or
+/// all of them: the country code, two check digits, then the BBAN fields.
+fn iban_classes(country: Option<&str>) -> BTreeMap<usize, Vec<Class>> {
+    let countries = match country {
+        Some(c) => vec![c],
+        None => crate::iban::supported_countries(),
+    };
+    let mut by_len: BTreeMap<usize, Vec<Class>> = BTreeMap::new();
+    for cc in countries {
+        let Some(fields) = crate::iban::get_format(cc) else {
+            continue;
+        };
+        let digit = Class {
+            digit: true,
+            ..Default::default()
+        };
+        let mut classes: Vec<Class> = cc.chars().map(Class::literal).collect();
+        classes.extend([digit.clone(), digit.clone()]);
+        for field in fields {
+            let class = match field.char_type {
+                CharType::Numeric => digit.clone(),
+                CharType::Alpha => Class {
+                    upper: true,
+                    ..Default::default()
+                },
+                CharType::Alphanumeric => Class {
+                    digit: true,
+                    upper: true,
+                    ..Default::default()
+                },
+            };
+            classes.extend(std::iter::repeat_n(class, field.length as usize));
+        }
+        match by_len.get_mut(&classes.len()) {
+            Some(merged) => merged
+                .iter_mut()
+                .zip(&classes)
+                .for_each(|(m, c)| m.merge(c)),
+            None => {
+                by_len.insert(classes.len(), classes);
+            }
+        }
+    }
+    by_len
+}
+
+fn charset(layouts: &[Layout]) -> Charset {
+    let classes = || layouts.iter().flat_map(|l| &l.classes);
+    let digits = classes().any(|c| c.digit);
+    let letters = classes().any(|c| c.upper || c.lower || !c.other.is_empty());
+    match (digits, letters) {
+        (true, false) => Charset::Digits,
+        (false, true) => Charset::Letters,
+        _ => Charset::Alphanumeric,
+    }
+}
+
+/// Characters allowed at one position.
+#[derive(Default, Clone, PartialEq, Eq)]
+struct Class {
+    digit: bool,
+    upper: bool,
+    lower: bool,
+    /// Single letters allowed besides the flagged classes.
+    other: BTreeSet<char>,
+}
+
+impl Class {
+    fn literal(c: char) -> Self {
+        Class {
+            other: BTreeSet::from([c]),
+            ..Default::default()
+        }
+    }
+
+    fn add(&mut self, c: char) {
+        match c {
+            '0'..='9' => self.digit = true,
+            'A'..='Z' => self.upper = true,
+            'a'..='z' => self.lower = true,
+            c => {
+                self.other.insert(c);
+            }
+        }
+    }
+
+    fn add_lowercase(&mut self) {
+        self.lower |= self.upper;
+        let lower: Vec<char> = self.other.iter().map(char::to_ascii_lowercase).collect();
+        self.other.extend(lower);
+    }
+
+    fn merge(&mut self, other: &Class) {
+        self.digit |= other.digit;
+        self.upper |= other.upper;
+        self.lower |= other.lower;
+        self.other.extend(&other.other);
+    }
+
+    /// Whether punctuation is allowed here, as for a century sign.
+    fn has_punctuation(&self) -> bool {
+        self.other.iter().any(|c| !c.is_ascii_alphanumeric())
+    }
+
+    fn covers(&self, c: char) -> bool {
+        match c {
+            '0'..='9' => self.digit,
+            'A'..='Z' => self.upper,
+            'a'..='z' => self.lower,
+            _ => false,
+        }
+    }
+
+    fn to_regex(&self) -> String {
+        let others: Vec<char> = self
+            .other
+            .iter()
+            .copied()
+            .filter(|&c| !self.covers(c))
+            .collect();
+        if !(self.digit || self.upper || self.lower) && others.len() == 1 {
+            return escape(others[0]);
+        }
+        let mut out = String::new();
+        if self.digit {
+            out.push_str("0-9");
+        }
+        if self.upper {
+            out.push_str("A-Z");
+        }
+        if self.lower {
+            out.push_str("a-z");
+        }
+        for c in others {
+            out.push_str(&escape(c));
+        }
+        format!("[{}]", out)
+    }
+}
+
+/// Zero or more of the separators in `gap`, or nothing if there are none.
+fn gap_regex(gap: &BTreeSet<char>) -> String {
+    let chars: String = gap.iter().map(|&c| escape(c)).collect();
+    match gap.len() {
+        0 => String::new(),
+        1 => format!("{}*", chars),
+        _ => format!("[{}]*", chars),
+    }
+}
+
+fn escape(c: char) -> String {
+    match c {
+        c if r"\.+*?()|[]{}^$#&-~".contains(c) => format!("\\{}", c),
+        c => c.to_string(),
+    }
+}
+
+/// Wrap a token in a group if a repetition would otherwise only apply to
+/// its last part.
+fn group(token: &str) -> String {
+    if token.contains('*') {
+        format!("(?:{})", token)
+    } else {
+        token.to_string()
+    }
+}
+
+/// One regex per accepted length, position by position, with the allowed
+/// separators before each character and after the last.
+fn build_regex(layouts: &[Layout]) -> String {
+    let rendered: Vec<(Vec<String>, String)> = layouts
+        .iter()
+        .map(|layout| {
+            let tokens = layout
+                .classes
+                .iter()
+                .zip(&layout.gaps)
+                .map(|(class, gap)| format!("{}{}", gap_regex(gap), class.to_regex()))
+                .collect();
+            let trail = gap_regex(&layout.gaps[layout.classes.len()]);
+            (tokens, trail)
+        })
+        .collect();
+    let Some((first, first_trail)) = rendered.first() else {
+        return "^$".to_string();
+    };
+    let min = layouts[0].classes.len();
+    let max = layouts[layouts.len() - 1].classes.len();
+
+    // Lengths 14, 15 and 16 of one class read better as {14,16}
+    let contiguous = layouts
+        .windows(2)
+        .all(|w| w[1].classes.len() == w[0].classes.len() + 1);
+    let uniform = rendered
+        .iter()
+        .all(|(tokens, trail)| trail == first_trail && tokens.iter().all(|t| *t == first[0]));
+    if layouts.len() > 1 && contiguous && uniform {
+        return format!("^{}{{{},{}}}{}$", group(&first[0]), min, max, first_trail);
+    }
+
+    let mut patterns: Vec<String> = Vec::new();
+    for (tokens, trail) in &rendered {
+        let pattern = compress(tokens) + trail;
+        if !patterns.contains(&pattern) {
+            patterns.push(pattern);
+        }
+    }
+    if patterns.len() == 1 {
+        return format!("^{}$", patterns[0]);
+    }
+    if patterns.len() <= MAX_PATTERNS {
+        return format!("^(?:{})$", patterns.join("|"));
+    }
+
+    let mut class = Class::default();
+    let mut gap = BTreeSet::new();
+    for layout in layouts {
+        layout.classes.iter().for_each(|c| class.merge(c));
+        layout.gaps.iter().for_each(|g| gap.extend(g));
+    }
+    let gap = gap_regex(&gap);
+    format!(
+        "^{}{{{},{}}}{}$",
+        group(&format!("{}{}", gap, class.to_regex())),
+        min,
+        max,
+        gap
+    )
+}
+
+/// Collapse repeated tokens: `[0-9][0-9][0-9]` becomes `[0-9]{3}`.
+fn compress(tokens: &[String]) -> String {
+    let mut out = String::new();
+    let mut i = 0;
+    while i < tokens.len() {
+        let mut run = 1;
+        while i + run < tokens.len() && tokens[i + run] == tokens[i] {
+            run += 1;
+        }
+        if run > 1 {
+            out.push_str(&format!("{}{{{}}}", group(&tokens[i]), run));
+        } else {
+            out.push_str(&tokens[i]);
+        }
+        i += run;
+    }
+    out
+}
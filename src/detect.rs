@@ -0,0 +1,165 @@
+//! Identify which identifier kinds a code could be.
+//!
+//! Detection runs the real validators, so every candidate returned is a code
+//! that passes the corresponding checksum or format check.
+//!
+//! ```rust
+//! use idsmith::detect::detect;
+//! use idsmith::kind::IdKind;
+//!
+//! let found = detect("DE89 3704 0044 0532 0130 00");
+//! assert_eq!(found[0].kind, IdKind::Iban);
+//! assert_eq!(found[0].country.as_deref(), Some("DE"));
+//! ```
+
+use crate::kind::{IdKind, Target};
+
+/// Strip the separators commonly used when writing identifiers
+/// (spaces, hyphens, dots, slashes) and uppercase the rest.
+pub fn normalize(code: &str) -> String {
+    code.chars()
+        .filter(|c| !c.is_whitespace() && !matches!(c, '-' | '.' | '/'))
+        .map(|c| c.to_ascii_uppercase())
+        .collect()
+}
+
+/// Return every kind (and country, where it applies) that `code` validates as.
+///
+/// Self-describing kinds (IBAN, VAT, LEI, card, SWIFT) come first, followed
+/// by personal IDs in country order. Codes that need a country and are not
+/// checksum-protected (most company IDs, passports, licences) are not probed
+/// because almost any digit string would match one of them.
+pub fn detect(code: &str) -> Vec<Target> {
+    let trimmed = code.trim();
+    let compact = normalize(trimmed);
+    if compact.len() < 6 || !compact.chars().all(|c| c.is_ascii_alphanumeric()) {
+        return Vec::new();
+    }
+
+    let mut found = Vec::new();
+
+    let starts_alpha = compact.as_bytes()[..2]
+        .iter()
+        .all(|b| b.is_ascii_uppercase());
+
+    if starts_alpha
+        && crate::iban::has_registered_length(&compact)
+        && crate::iban::validate_iban(&compact)
+    {
+        found.push(Target::new(IdKind::Iban, Some(&compact[..2])));
+    }
+    if starts_alpha && crate::vat_ids().validate(&compact) {
+        let prefix = &compact[..2];
+        let country = if prefix == "EL" { "GR" } else { prefix };
+        found.push(Target::new(IdKind::Vat, Some(country)));
+    }
+    if compact.len() == 20 && crate::lei_codes().validate(&compact) {
+        found.push(Target::new(IdKind::Lei, Some(&compact[4..6])));
+    }
+    if compact.chars().all(|c| c.is_ascii_digit()) && crate::credit_cards().validate(&compact) {
+        found.push(Target::new(IdKind::CreditCard, None));
+    }
+    if is_swift_like(&compact) {
+        found.push(Target::new(IdKind::Swift, Some(&compact[4..6])));
+    }
+
+    let registry = crate::personal_ids();
+    for (country, _, _) in registry.list_countries() {
+        let country = country.as_str();
+        if crate::countries::resolve_territory(country).is_some() {
+            continue;
+        }
+        // Personal IDs are validated in their written form first, since some
+        // schemes (e.g. US SSN) rely on separators.
+        let ok = (trimmed.is_ascii() && registry.validate(country, trimmed) == Some(true))
+            || (compact != trimmed && registry.validate(country, &compact) == Some(true));
+        if ok {
+            found.push(Target::new(IdKind::PersonalId, Some(country)));
+        }
+    }
+
+    found
+}
+
+/// SWIFT codes are only reported when the bank part is alphabetic and the
+/// country part is a real ISO code; plain 8/11 character words would
+/// otherwise match.
+fn is_swift_like(code: &str) -> bool {
+    (code.len() == 8 || code.len() == 11)
+        && code[..6].chars().all(|c| c.is_ascii_uppercase())
+        && crate::countries::get_country_name(&code[4..6]).is_some()
+        && crate::swift_codes().validate(code)
+}
+
+/// A kind, the number of values matching it, and per-country match counts.
+type KindCount = (IdKind, usize, Vec<(Option<String>, usize)>);
+
+/// Pick the target that best describes a column of sample values.
+///
+/// The kind matched by the most non-empty samples wins, provided it covers
+/// at least `min_ratio` of them (e.g. `0.8`); ties are broken by [`detect`]
+/// order, which favours self-describing kinds. The country is kept when all
+/// matches agree. Mixed-country columns of self-describing kinds (IBAN, VAT,
+/// ...) get no country; other kinds take the most common one.
+pub fn detect_column<'a, I>(samples: I, min_ratio: f64) -> Option<Target>
+where
+    I: IntoIterator<Item = &'a str>,
+{
+    let mut counts: Vec<KindCount> = Vec::new();
+    let mut total = 0usize;
+    for value in samples {
+        if value.trim().is_empty() {
+            continue;
+        }
+        total += 1;
+        let mut seen: Vec<IdKind> = Vec::new();
+        for target in detect(value) {
+            let idx = match counts.iter().position(|(k, _, _)| *k == target.kind) {
+                Some(i) => i,
+                None => {
+                    counts.push((target.kind, 0, Vec::new()));
+                    counts.len() - 1
+                }
+            };
+            let (_, n, countries) = &mut counts[idx];
+            if !seen.contains(&target.kind) {
+                seen.push(target.kind);
+                *n += 1;
+            }
+            match countries.iter_mut().find(|(c, _)| *c == target.country) {
+                Some((_, m)) => *m += 1,
+                None => countries.push((target.country, 1)),
+            }
+        }
+    }
+    if total == 0 {
+        return None;
+    }
+
+    let mut best: Option<&KindCount> = None;
+    for entry in &counts {
+        if best.is_none_or(|b| entry.1 > b.1) {
+            best = Some(entry);
+        }
+    }
+    let (kind, n, countries) = best?;
+    if (*n as f64) < total as f64 * min_ratio {
+        return None;
+    }
+
+    let country = if countries.len() == 1 || kind.requires_country() {
+        let mut top: Option<&(Option<String>, usize)> = None;
+        for entry in countries {
+            if top.is_none_or(|t| entry.1 > t.1) {
+                top = Some(entry);
+            }
+        }
+        top.and_then(|(c, _)| c.clone())
+    } else {
+        None
+    };
+    Some(Target {
+        kind: *kind,
+        country,
+    })
+}
@@ -2,13 +2,23 @@ use rand::Rng;
 #[cfg(feature = "json")]
 use serde::Serialize;
 
+use crate::countries::subdivisions::{self, Subdivision};
+use crate::kind::IdKind;
+use crate::locale::Locale;
+
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "json", derive(Serialize))]
 pub struct DriverLicenseResult {
+    /// Country as requested.
     pub country_code: String,
+    /// Country whose format was applied; differs from `country_code` for
+    /// territories using their parent's (e.g. `PR` → `US`).
+    pub effective_country_code: String,
     pub country_name: String,
     pub name: String,
     pub code: String,
+    /// ISO 3166-2 subdivision that issued the licence, without the country
+    /// prefix (e.g. `CA` for California).
     pub state: Option<String>,
     pub valid: bool,
 }
@@ -16,39 +26,16 @@ pub struct DriverLicenseResult {
 #[derive(Debug, Clone, Default)]
 pub struct GenOptions {
     pub country: Option<String>,
+    /// ISO 3166-2 subdivision for countries that issue licences per state
+    /// (AU, CA, CN, IN, JP, MX, US), as `US-CA`, `CA` or `California`.
+    /// Random if unset; generation fails if it is not a subdivision of the
+    /// country.
     pub state: Option<String>,
+    /// Fail for territories without their own format instead of using the
+    /// parent country's.
+    pub strict: bool,
 }
 
-/// Valid Indian state/UT codes used on driving licences.
-static IN_STATES: &[&str] = &[
-    "AN", "AP", "AR", "AS", "BR", "CG", "CH", "DD", "DL", "GA", "GJ", "HP", "HR", "JH", "JK", "KA",
-    "KL", "LA", "LD", "MH", "ML", "MN", "MP", "MZ", "NL", "OD", "PB", "PY", "RJ", "SK", "TN", "TS",
-    "TR", "UK", "UP", "WB",
-];
-
-/// US state codes for driver's licenses.
-static US_STATES: &[&str] = &[
-    "AL", "AK", "AZ", "AR", "CA", "CO", "CT", "DE", "FL", "GA", "HI", "ID", "IL", "IN", "IA", "KS",
-    "KY", "LA", "ME", "MD", "MA", "MI", "MN", "MS", "MO", "MT", "NE", "NV", "NH", "NJ", "NM", "NY",
-    "NC", "ND", "OH", "OK", "OR", "PA", "RI", "SC", "SD", "TN", "TX", "UT", "VT", "VA", "WA", "WV",
-    "WI", "WY", "DC",
-];
-
-/// Japanese prefecture codes (01-47).
-static JP_PREFECTURES: &[&str] = &[
-    "01", "02", "03", "04", "05", "06", "07", "08", "09", "10", "11", "12", "13", "14", "15", "16",
-    "17", "18", "19", "20", "21", "22", "23", "24", "25", "26", "27", "28", "29", "30", "31", "32",
-    "33", "34", "35", "36", "37", "38", "39", "40", "41", "42", "43", "44", "45", "46", "47",
-];
-
-/// Australian state codes.
-static AU_STATES: &[&str] = &["NSW", "VIC", "QLD", "WA", "SA", "TAS", "ACT", "NT"];
-
-/// Canadian province codes.
-static CA_PROVINCES: &[&str] = &[
-    "AB", "BC", "MB", "NB", "NL", "NS", "NT", "NU", "ON", "PE", "QC", "SK", "YT",
-];
-
 /// German Führerschein issuing authority prefixes (simplified).
 static DE_PREFIXES: &[&str] = &[
     "B", "M", "K", "D", "F", "S", "H", "N", "HH", "HB", "DO", "E", "DD", "L", "BN",
@@ -62,6 +49,15 @@ static KR_REGIONS: &[&str] = &[
     "11", "12", "13", "14", "15", "16", "17", "18", "19", "20", "21", "22", "23", "24", "25", "26",
 ];
 
+/// RTO prefix for an Indian state: licences in Dadra and Nagar Haveli and
+/// Daman and Diu keep the `DD` prefix.
+fn in_rto_state(state: &Subdivision) -> &'static str {
+    match state.suffix() {
+        "DH" => "DD",
+        suffix => suffix,
+    }
+}
+
 /// Singapore NRIC check letter weights.
 static SG_NRIC_WEIGHTS: &[u32] = &[2, 7, 6, 5, 4, 3, 2];
 
@@ -151,6 +147,10 @@ static SPECIFIC_COUNTRIES: &[(&str, &str)] = &[
     ("UA", "Driving Licence"),
 ];
 
+fn has_specific(country: &str) -> bool {
+    SPECIFIC_COUNTRIES.iter().any(|(code, _)| *code == country)
+}
+
 pub struct Registry;
 
 impl Default for Registry {
@@ -165,7 +165,7 @@ impl Registry {
     }
 
     pub fn generate(&self, opts: &GenOptions, rng: &mut impl Rng) -> Option<DriverLicenseResult> {
-        let country = opts
+        let requested = opts
             .country
             .as_deref()
             .unwrap_or_else(|| {
@@ -173,19 +173,27 @@ impl Registry {
                 countries[rng.gen_range(0..countries.len())].0
             })
             .to_uppercase();
+        let country =
+            crate::countries::effective_country_code(&requested, opts.strict, has_specific)?;
+
+        // Licences issued per state or province carry it in the result
+        let state = match subdivisions::of(&country) {
+            [] => None,
+            _ => Some(subdivisions::resolve(&country, opts.state.as_deref(), rng)?),
+        };
 
         if let Some((name, result)) = match country.as_str() {
             // ── Existing specific implementations ──
-            "IN" => Some(("Driving Licence", self.generate_in(opts, rng))),
-            "US" => Some(("Driver's License", self.generate_us(opts, rng))),
+            "IN" => Some(("Driving Licence", self.generate_in(state?, rng))),
+            "US" => Some(("Driver's License", self.generate_us(state?, rng))),
             "GB" => Some(("Driving Licence", self.generate_gb(rng))),
             "DE" => Some(("Führerschein", self.generate_de(rng))),
             "FR" => Some(("Permis de Conduire", self.generate_fr(rng))),
             "BR" => Some(("CNH", self.generate_br(rng))),
-            "AU" => Some(("Driver Licence", self.generate_au(opts, rng))),
-            "CA" => Some(("Driver's Licence", self.generate_ca(opts, rng))),
-            "JP" => Some(("運転免許証", self.generate_jp(rng))),
-            "CN" => Some(("驾驶证", self.generate_cn(rng))),
+            "AU" => Some(("Driver Licence", self.generate_au(state?, rng))),
+            "CA" => Some(("Driver's Licence", self.generate_ca(state?, rng))),
+            "JP" => Some(("運転免許証", self.generate_jp(state?, rng))),
+            "CN" => Some(("驾驶证", self.generate_cn(state?, rng))),
             "IT" => Some(("Patente", self.generate_it(rng))),
             "ES" => Some(("Permiso de Conducir", self.generate_es(rng))),
             "NL" => Some(("Rijbewijs", self.generate_nl(rng))),
@@ -193,7 +201,7 @@ impl Registry {
             "KR" => Some(("운전면허증", self.generate_kr(rng))),
             "SG" => Some(("Driving Licence", self.generate_sg(rng))),
             "ZA" => Some(("Driver's Licence", self.generate_za(rng))),
-            "MX" => Some(("Licencia de Conducir", self.generate_mx(rng))),
+            "MX" => Some(("Licencia de Conducir", self.generate_mx(state?, rng))),
             // ── Europe (new) ──
             "AT" => Some(("Führerschein", self.generate_at(rng))),
             "BE" => Some(("Rijbewijs", self.generate_be(rng))),
@@ -261,9 +269,10 @@ impl Registry {
             "TZ" => Some(("Driving Licence", self.generate_tz(rng))),
             _ => None,
         } {
-            let country_name = crate::countries::get_country_name(&country).unwrap_or("Unknown");
+            let country_name = crate::countries::get_country_name(&requested).unwrap_or("Unknown");
             return Some(DriverLicenseResult {
-                country_code: country,
+                country_code: requested,
+                effective_country_code: country,
                 country_name: country_name.to_string(),
                 name: name.to_string(),
                 code: result.0,
@@ -275,8 +284,30 @@ impl Registry {
         None
     }
 
+    /// Country whose format applies to `country`: the country itself, or
+    /// the parent for a territory without its own.
+    pub fn effective_country(&self, country: &str) -> Option<String> {
+        crate::countries::effective_country_code(country, false, has_specific)
+    }
+
+    /// Name of the check algorithm of the country's licence number (e.g.
+    /// `"Luhn"`), or `None` if it has none.
+    pub fn checksum(&self, country: &str) -> Option<&'static str> {
+        match self.effective_country(&country.to_uppercase())?.as_str() {
+            "BR" => Some("Weighted mod 11, two check digits"),
+            "ES" => Some("Mod 23 letter"),
+            "SE" | "ZA" => Some("Luhn"),
+            "SG" => Some("Weighted mod 11 letter"),
+            _ => None,
+        }
+    }
+
     pub fn validate(&self, country: &str, code: &str) -> bool {
-        match country.to_uppercase().as_str() {
+        let Some(country) = crate::countries::effective_country_code(country, false, has_specific)
+        else {
+            return false;
+        };
+        match country.as_str() {
             // ── Existing specific implementations ──
             "IN" => self.validate_in(code),
             "US" => self.validate_us(code),
@@ -380,23 +411,38 @@ impl Registry {
         result
     }
 
+    /// Like [`list_countries`](Self::list_countries), with country and
+    /// license names in `locale`.
+    pub fn list_countries_in(
+        &self,
+        locale: Locale,
+    ) -> Vec<(&'static str, &'static str, &'static str)> {
+        self.list_countries()
+            .into_iter()
+            .map(|(code, _, name)| {
+                (
+                    code,
+                    crate::locale::country_label(code, locale),
+                    crate::locale::document_name(IdKind::DriverLicense, code, locale)
+                        .unwrap_or(name),
+                )
+            })
+            .collect()
+    }
+
     // ══════════════════════════════════════════════════════════════════════
     // Existing specific implementations (18 countries)
     // ══════════════════════════════════════════════════════════════════════
 
     // ── India ──
     // Format: {STATE}{RTO}{YEAR}{SERIAL} = 15 chars, e.g. MH0220190000001
-    fn generate_in(&self, opts: &GenOptions, rng: &mut impl Rng) -> (String, Option<String>) {
-        let state = opts
-            .state
-            .as_deref()
-            .unwrap_or_else(|| IN_STATES[rng.gen_range(0..IN_STATES.len())]);
+    fn generate_in(&self, state: &Subdivision, rng: &mut impl Rng) -> (String, Option<String>) {
         let rto: u8 = rng.gen_range(1..=99);
         let year: u16 = rng.gen_range(1990..=2025);
         let serial: u32 = rng.gen_range(1..=9999999);
         (
-            format!("{}{:02}{}{:07}", state, rto, year, serial),
-            Some(state.to_string()),
+            format!("{}{:02}{}{:07}", in_rto_state(state), rto, year, serial),
+            Some(state.suffix().to_string()),
         )
     }
 
@@ -409,7 +455,10 @@ impl Registry {
             return false;
         }
         let state = &clean[0..2];
-        if !IN_STATES.contains(&state) {
+        if !subdivisions::of("IN")
+            .iter()
+            .any(|s| in_rto_state(s) == state)
+        {
             return false;
         }
         let rto = &clean[2..4];
@@ -426,16 +475,15 @@ impl Registry {
 
     // ── United States ──
     // Format: 1 alpha + 12 digits = 13 chars (simplified; real formats vary by state)
-    fn generate_us(&self, opts: &GenOptions, rng: &mut impl Rng) -> (String, Option<String>) {
-        let state = opts
-            .state
-            .as_deref()
-            .unwrap_or_else(|| US_STATES[rng.gen_range(0..US_STATES.len())]);
+    fn generate_us(&self, state: &Subdivision, rng: &mut impl Rng) -> (String, Option<String>) {
         let letter = (b'A' + rng.gen_range(0..26u8)) as char;
         let digits: String = (0..12)
             .map(|_| (b'0' + rng.gen_range(0..=9u8)) as char)
             .collect();
-        (format!("{}{}", letter, digits), Some(state.to_string()))
+        (
+            format!("{}{}", letter, digits),
+            Some(state.suffix().to_string()),
+        )
     }
 
     fn validate_us(&self, code: &str) -> bool {
@@ -578,17 +626,13 @@ impl Registry {
 
     // ── Australia ──
     // Format: state-based, typically 8-10 alphanumeric chars
-    fn generate_au(&self, opts: &GenOptions, rng: &mut impl Rng) -> (String, Option<String>) {
-        let state = opts
-            .state
-            .as_deref()
-            .unwrap_or_else(|| AU_STATES[rng.gen_range(0..AU_STATES.len())]);
+    fn generate_au(&self, state: &Subdivision, rng: &mut impl Rng) -> (String, Option<String>) {
         let digits: String = (0..7)
             .map(|_| (b'0' + rng.gen_range(0..=9u8)) as char)
             .collect();
         (
             format!("{}{}", digits, rng.gen_range(100..=999)),
-            Some(state.to_string()),
+            Some(state.suffix().to_string()),
         )
     }
 
@@ -599,16 +643,15 @@ impl Registry {
 
     // ── Canada ──
     // Format: province-based, letter + digits, typically 13 chars
-    fn generate_ca(&self, opts: &GenOptions, rng: &mut impl Rng) -> (String, Option<String>) {
-        let province = opts
-            .state
-            .as_deref()
-            .unwrap_or_else(|| CA_PROVINCES[rng.gen_range(0..CA_PROVINCES.len())]);
+    fn generate_ca(&self, province: &Subdivision, rng: &mut impl Rng) -> (String, Option<String>) {
         let letter = (b'A' + rng.gen_range(0..26u8)) as char;
         let digits: String = (0..12)
             .map(|_| (b'0' + rng.gen_range(0..=9u8)) as char)
             .collect();
-        (format!("{}{}", letter, digits), Some(province.to_string()))
+        (
+            format!("{}{}", letter, digits),
+            Some(province.suffix().to_string()),
+        )
     }
 
     fn validate_ca(&self, code: &str) -> bool {
@@ -622,14 +665,23 @@ impl Registry {
 
     // ── Japan ──
     // Format: 12 digits (prefecture 2 + year 2 + serial 6 + check 2)
-    fn generate_jp(&self, rng: &mut impl Rng) -> (String, Option<String>) {
-        let pref = JP_PREFECTURES[rng.gen_range(0..JP_PREFECTURES.len())];
+    fn generate_jp(
+        &self,
+        prefecture: &Subdivision,
+        rng: &mut impl Rng,
+    ) -> (String, Option<String>) {
         let year: u8 = rng.gen_range(1..=35);
         let serial: u32 = rng.gen_range(0..=999999);
         let check: u8 = rng.gen_range(0..=99);
         (
-            format!("{}{:02}{:06}{:02}", pref, year, serial, check),
-            None,
+            format!(
+                "{}{:02}{:06}{:02}",
+                prefecture.suffix(),
+                year,
+                serial,
+                check
+            ),
+            Some(prefecture.suffix().to_string()),
         )
     }
 
@@ -640,10 +692,13 @@ impl Registry {
 
     // ── China ──
     // Format: 12 digits (region 6 + serial 6)
-    fn generate_cn(&self, rng: &mut impl Rng) -> (String, Option<String>) {
-        let region: u32 = rng.gen_range(110000..=820000);
+    fn generate_cn(&self, province: &Subdivision, rng: &mut impl Rng) -> (String, Option<String>) {
+        let region = crate::personal_id::cn::region_code(Some(province), rng);
         let serial: u32 = rng.gen_range(0..=999999);
-        (format!("{:06}{:06}", region, serial), None)
+        (
+            format!("{:06}{:06}", region, serial),
+            Some(province.suffix().to_string()),
+        )
     }
 
     fn validate_cn(&self, code: &str) -> bool {
@@ -852,7 +907,7 @@ impl Registry {
 
     // ── Mexico ──
     // Format: 12 chars alphanumeric (CURP-derived)
-    fn generate_mx(&self, rng: &mut impl Rng) -> (String, Option<String>) {
+    fn generate_mx(&self, state: &Subdivision, rng: &mut impl Rng) -> (String, Option<String>) {
         let prefix: String = (0..4)
             .map(|_| (b'A' + rng.gen_range(0..26u8)) as char)
             .collect();
@@ -871,7 +926,7 @@ impl Registry {
             .collect();
         (
             format!("{}{:02}{:02}{:02}{}", prefix, year, month, day, suffix),
-            None,
+            Some(state.suffix().to_string()),
         )
     }
 
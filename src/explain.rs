@@ -0,0 +1,708 @@
+//! Step-by-step traces of checksum calculations.
+//!
+//! [`explain`] shows how a code's check digits are computed: the
+//! character-to-digit mapping, weights, products, sum and modulus, and the
+//! expected check digits next to the ones found in the code.
+//!
+//! ```rust
+//! use idsmith::explain::explain;
+//! use idsmith::kind::IdKind;
+//!
+//! let explanation = explain(IdKind::Iban, None, "GB82 WEST 1234 5698 7654 32").unwrap();
+//! assert_eq!(explanation.algorithm, "ISO 7064 MOD 97-10");
+//! assert_eq!(explanation.expected, "82");
+//! assert!(explanation.valid);
+//!
+//! let explanation = explain(IdKind::PersonalId, Some("EE"), "39001010001").unwrap();
+//! assert_eq!((explanation.expected.as_str(), explanation.actual.as_str()), ("0", "1"));
+//! assert!(!explanation.valid);
+//! ```
+
+use std::fmt;
+
+#[cfg(feature = "json")]
+use serde::Serialize;
+
+use crate::detect::normalize;
+use crate::kind::IdKind;
+use crate::personal_id::checksum::{VERHOEFF_D, VERHOEFF_INV, VERHOEFF_P};
+
+/// Kinds (and countries) with a calculation of their own.
+pub const SUPPORTED: &[&str] = &[
+    "iban",
+    "card",
+    "lei",
+    "vat:AT",
+    "vat:BE",
+    "id:BE",
+    "id:EE",
+    "id:FI",
+    "id:FR",
+    "id:LT",
+    "id:NO",
+    "id:PL",
+    "company:BE",
+    "tax:BE",
+];
+
+/// Algorithms explained for every scheme that declares them (see
+/// [`IdKind::checksum`]), with the check digit last.
+pub const ALGORITHMS: &[&str] = &[
+    "Luhn",
+    "Verhoeff",
+    "ISO 7064 MOD 11-10",
+    "ISO 7064 MOD 11-2",
+];
+
+/// One step of a calculation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "json", derive(Serialize))]
+pub struct Step {
+    /// What the step does.
+    pub label: String,
+    /// Its result.
+    pub value: String,
+}
+
+/// The checksum calculation for one code.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "json", derive(Serialize))]
+pub struct Explanation {
+    pub kind: IdKind,
+    pub country: Option<String>,
+    /// The code after normalization.
+    pub code: String,
+    pub algorithm: String,
+    pub steps: Vec<Step>,
+    /// Check digits calculated from the rest of the code.
+    pub expected: String,
+    /// Check digits found in the code.
+    pub actual: String,
+    pub valid: bool,
+}
+
+impl fmt::Display for Explanation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.country {
+            Some(c) => writeln!(f, "{} ({}) {}", self.kind, c, self.code)?,
+            None => writeln!(f, "{} {}", self.kind, self.code)?,
+        }
+        writeln!(f, "Algorithm: {}", self.algorithm)?;
+        writeln!(f)?;
+        for (i, step) in self.steps.iter().enumerate() {
+            writeln!(f, "{:>3}. {}: {}", i + 1, step.label, step.value)?;
+        }
+        writeln!(f)?;
+        writeln!(f, "Expected: {}", self.expected)?;
+        writeln!(f, "Actual:   {}", self.actual)?;
+        write!(
+            f,
+            "Result:   {}",
+            if self.valid { "valid" } else { "invalid" }
+        )
+    }
+}
+
+/// Trace the checksum of `code`. Fails for kinds and countries not in
+/// [`SUPPORTED`] whose scheme uses none of the [`ALGORITHMS`], and for codes
+/// too malformed to calculate anything.
+pub fn explain(kind: IdKind, country: Option<&str>, code: &str) -> Result<Explanation, String> {
+    // Kept for the Finnish century sign, which may be a '-'
+    let written = code.trim().to_uppercase();
+    let code = normalize(code);
+    let country = country.map(str::to_uppercase);
+    match (kind, country.as_deref()) {
+        (IdKind::Iban, _) => iban(&code),
+        (IdKind::CreditCard, _) => card(&code),
+        (IdKind::Lei, _) => lei(&code),
+        (IdKind::Vat, _) => vat(country.as_deref(), &code),
+        (IdKind::PersonalId, Some(c @ ("EE" | "LT"))) => personal_id_ee(c, &code),
+        (IdKind::PersonalId, Some("FI")) => personal_id_fi(&written),
+        (IdKind::PersonalId, Some("NO")) => personal_id_no(&code),
+        (IdKind::PersonalId, Some("PL")) => personal_id_pl(&code),
+        (IdKind::PersonalId, Some(c @ ("BE" | "FR"))) | (IdKind::TaxId, Some(c @ "BE")) => {
+            mod97_complement(kind, c, &code, &code)
+        }
+        (IdKind::CompanyId, Some("BE")) => {
+            mod97_complement(kind, "BE", &code, code.strip_prefix("BE").unwrap_or(&code))
+        }
+        (_, Some(c)) => by_algorithm(kind, c, &code, checked_part(kind, c, &code)?),
+        (_, None) => Err(format!("{} requires a country", kind)),
+    }
+}
+
+fn unsupported(kind: IdKind, country: &str) -> String {
+    format!(
+        "No explanation for {}:{}. Supported: {}, and schemes using {}",
+        kind,
+        country,
+        SUPPORTED.join(", "),
+        ALGORITHMS.join(", ")
+    )
+}
+
+struct Trace {
+    steps: Vec<Step>,
+}
+
+impl Trace {
+    fn new() -> Self {
+        Self { steps: Vec::new() }
+    }
+
+    fn step(&mut self, label: &str, value: impl fmt::Display) {
+        self.steps.push(Step {
+            label: label.to_string(),
+            value: value.to_string(),
+        });
+    }
+
+    /// Record the weights, products and sum of `digits`, returning the sum.
+    fn weighted_sum(
+        &mut self,
+        digits: &[u32],
+        label: &str,
+        weights: &[u32],
+        cross_sum: bool,
+    ) -> u32 {
+        let mut products: Vec<u32> = digits.iter().zip(weights).map(|(d, w)| d * w).collect();
+        self.step(label, spaced(weights));
+        self.step("Products", spaced(&products));
+        if cross_sum {
+            for p in &mut products {
+                if *p > 9 {
+                    *p -= 9;
+                }
+            }
+            self.step("Products above 9 minus 9", spaced(&products));
+        }
+        let sum = products.iter().sum();
+        self.step(
+            "Sum",
+            format!(
+                "{} = {}",
+                products
+                    .iter()
+                    .map(u32::to_string)
+                    .collect::<Vec<_>>()
+                    .join(" + "),
+                sum
+            ),
+        );
+        sum
+    }
+
+    /// Record letters mapped to numbers (A=10 … Z=35) and the resulting
+    /// digit string.
+    fn letters_to_digits(&mut self, text: &str) -> String {
+        let letters: Vec<String> = text
+            .chars()
+            .filter(char::is_ascii_uppercase)
+            .map(|c| format!("{}={}", c, letter_value(c)))
+            .collect();
+        if !letters.is_empty() {
+            self.step("Letters as numbers (A=10 … Z=35)", letters.join(" "));
+        }
+        let digits: String = text
+            .chars()
+            .map(|c| match c {
+                'A'..='Z' => letter_value(c).to_string(),
+                _ => c.to_string(),
+            })
+            .collect();
+        self.step("Digit string", &digits);
+        digits
+    }
+
+    fn finish(
+        self,
+        kind: IdKind,
+        country: Option<&str>,
+        code: &str,
+        algorithm: &str,
+        expected: String,
+        actual: &str,
+    ) -> Explanation {
+        Explanation {
+            kind,
+            country: country.map(str::to_string),
+            code: code.to_string(),
+            algorithm: algorithm.to_string(),
+            steps: self.steps,
+            valid: expected == actual,
+            expected,
+            actual: actual.to_string(),
+        }
+    }
+}
+
+fn letter_value(c: char) -> u32 {
+    c as u32 - 'A' as u32 + 10
+}
+
+fn spaced(values: &[u32]) -> String {
+    values
+        .iter()
+        .map(u32::to_string)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn digits(code: &str, len: usize, what: &str) -> Result<Vec<u32>, String> {
+    if code.len() != len || !code.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(format!("{} must be {} digits, got {}", what, len, code));
+    }
+    Ok(code.bytes().map(|b| (b - b'0') as u32).collect())
+}
+
+fn mod97(digits: &str) -> u32 {
+    digits
+        .bytes()
+        .fold(0, |r, b| (r * 10 + (b - b'0') as u32) % 97)
+}
+
+fn iban(code: &str) -> Result<Explanation, String> {
+    if code.len() < 5 || !code.chars().all(|c| c.is_ascii_alphanumeric()) {
+        return Err(format!("Not an IBAN: {}", code));
+    }
+    let mut trace = Trace::new();
+    let rearranged = format!("{}{}00", &code[4..], &code[..2]);
+    trace.step(
+        "Move the country code to the end, with check digits 00",
+        &rearranged,
+    );
+    let numeric = trace.letters_to_digits(&rearranged);
+    let remainder = mod97(&numeric);
+    trace.step("Remainder mod 97", remainder);
+    let expected = format!("{:02}", 98 - remainder);
+    trace.step(
+        "Check digits = 98 - remainder",
+        format!("98 - {} = {}", remainder, expected),
+    );
+    Ok(trace.finish(
+        IdKind::Iban,
+        Some(&code[..2]),
+        code,
+        "ISO 7064 MOD 97-10",
+        expected,
+        &code[2..4],
+    ))
+}
+
+fn lei(code: &str) -> Result<Explanation, String> {
+    if code.len() != 20 || !code.chars().all(|c| c.is_ascii_alphanumeric()) {
+        return Err(format!("LEI must be 20 letters and digits, got {}", code));
+    }
+    let mut trace = Trace::new();
+    let base = format!("{}00", &code[..18]);
+    trace.step("Replace the check digits with 00", &base);
+    let numeric = trace.letters_to_digits(&base);
+    let remainder = mod97(&numeric);
+    trace.step("Remainder mod 97", remainder);
+    let expected = format!("{:02}", 98 - remainder);
+    trace.step(
+        "Check digits = 98 - remainder",
+        format!("98 - {} = {}", remainder, expected),
+    );
+    Ok(trace.finish(
+        IdKind::Lei,
+        Some(&code[4..6]),
+        code,
+        "ISO 7064 MOD 97-10",
+        expected,
+        &code[18..],
+    ))
+}
+
+fn card(code: &str) -> Result<Explanation, String> {
+    if !(13..=19).contains(&code.len()) || !code.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(format!("Card number must be 13-19 digits, got {}", code));
+    }
+    let digits: Vec<u32> = code.bytes().map(|b| (b - b'0') as u32).collect();
+    let (payload, check) = digits.split_at(digits.len() - 1);
+    let mut trace = Trace::new();
+    trace.step("Digits without the check digit", spaced(payload));
+    let expected = luhn(&mut trace, payload);
+    Ok(trace.finish(
+        IdKind::CreditCard,
+        None,
+        code,
+        "Luhn",
+        expected,
+        &check[0].to_string(),
+    ))
+}
+
+/// VAT numbers carry their country prefix; `country` adds it when missing.
+fn vat(country: Option<&str>, code: &str) -> Result<Explanation, String> {
+    let prefix = match country {
+        Some("GR") => "EL",
+        Some(c) => c,
+        None => code
+            .get(..2)
+            .ok_or_else(|| format!("Not a VAT number: {}", code))?,
+    };
+    let code = if code.starts_with(prefix) {
+        code.to_string()
+    } else {
+        format!("{}{}", prefix, code)
+    };
+    let body = &code[prefix.len()..];
+    match prefix {
+        "AT" => vat_at(&code),
+        "BE" => mod97_complement(IdKind::Vat, prefix, &code, body),
+        _ => by_algorithm(
+            IdKind::Vat,
+            prefix,
+            &code,
+            checked_part(IdKind::Vat, prefix, &code)?,
+        ),
+    }
+}
+
+/// The part of `code` ending in the check digit.
+fn checked_part<'a>(kind: IdKind, country: &str, code: &'a str) -> Result<&'a str, String> {
+    // Some company numbers are written with their VAT prefix
+    let body = code.strip_prefix(country).unwrap_or(code);
+    match (kind, country) {
+        // Swedish VAT numbers end in 01 after the check digit
+        (IdKind::CompanyId | IdKind::Vat, "SE") => body
+            .strip_suffix("01")
+            .ok_or_else(|| format!("Swedish VAT numbers end in 01, got {}", code)),
+        // The NPWP check digit follows the first 8 digits (15-digit form) or
+        // the first 9 (16-digit form starting with 0)
+        (IdKind::CompanyId, "ID") => match body.len() {
+            15 => Ok(&body[..9]),
+            16 if body.starts_with('0') => Ok(&body[..10]),
+            _ => Err(format!(
+                "NPWP must be 15 digits, or 16 starting with 0, got {}",
+                code
+            )),
+        },
+        _ => Ok(body),
+    }
+}
+
+/// Explain `body`, the part of `code` ending in the check digit, with the
+/// algorithm the scheme declares.
+fn by_algorithm(
+    kind: IdKind,
+    country: &str,
+    code: &str,
+    body: &str,
+) -> Result<Explanation, String> {
+    type Steps = fn(&mut Trace, &[u32]) -> String;
+    let algorithm = kind
+        .checksum(Some(country))
+        .ok_or_else(|| unsupported(kind, country))?;
+    let steps: Steps = match algorithm.as_str() {
+        "Luhn" => luhn,
+        "Verhoeff" => verhoeff,
+        "ISO 7064 MOD 11-10" => iso7064_mod11_10,
+        "ISO 7064 MOD 11-2" => iso7064_mod11_2,
+        _ => return Err(unsupported(kind, country)),
+    };
+    if body.len() < 2 {
+        return Err(format!("Too short to have a check digit: {}", code));
+    }
+    let (payload, check) = body.split_at(body.len() - 1);
+    let payload = digits(payload, payload.len(), "The part before the check digit")?;
+    let mut trace = Trace::new();
+    trace.step("Digits without the check digit", spaced(&payload));
+    let expected = steps(&mut trace, &payload);
+    Ok(trace.finish(kind, Some(country), code, &algorithm, expected, check))
+}
+
+fn luhn(trace: &mut Trace, payload: &[u32]) -> String {
+    // Doubling starts at the digit next to the check digit
+    let weights: Vec<u32> = (0..payload.len())
+        .map(|i| if (payload.len() - i) % 2 == 1 { 2 } else { 1 })
+        .collect();
+    let sum = trace.weighted_sum(payload, "Weights, doubling from the right", &weights, true);
+    let expected = (10 - sum % 10) % 10;
+    trace.step(
+        "Check digit = (10 - sum mod 10) mod 10",
+        format!("(10 - {}) mod 10 = {}", sum % 10, expected),
+    );
+    expected.to_string()
+}
+
+fn verhoeff(trace: &mut Trace, payload: &[u32]) -> String {
+    let reversed: Vec<u32> = payload.iter().rev().copied().collect();
+    trace.step("Digits from the right", spaced(&reversed));
+    // The check digit takes position 0, so the payload starts at 1
+    let permuted: Vec<u32> = reversed
+        .iter()
+        .enumerate()
+        .map(|(i, &d)| VERHOEFF_P[(i + 1) % 8][d as usize] as u32)
+        .collect();
+    trace.step("Permuted by position (p table)", spaced(&permuted));
+    let mut product = 0;
+    let running: Vec<u32> = permuted
+        .iter()
+        .map(|&p| {
+            product = VERHOEFF_D[product as usize][p as usize] as u32;
+            product
+        })
+        .collect();
+    trace.step("Running product (d table)", spaced(&running));
+    let expected = VERHOEFF_INV[product as usize];
+    trace.step(
+        "Check digit = inverse of the product",
+        format!("inv({}) = {}", product, expected),
+    );
+    expected.to_string()
+}
+
+fn iso7064_mod11_10(trace: &mut Trace, payload: &[u32]) -> String {
+    let mut p = 10;
+    let running: Vec<u32> = payload
+        .iter()
+        .map(|&d| {
+            let s = match (p + d) % 10 {
+                0 => 10,
+                s => s,
+            };
+            p = s * 2 % 11;
+            p
+        })
+        .collect();
+    trace.step(
+        "Running value from 10: ((value + digit) mod 10, 0 as 10) × 2 mod 11",
+        spaced(&running),
+    );
+    let expected = (11 - p) % 10;
+    trace.step(
+        "Check digit = (11 - value) mod 10",
+        format!("(11 - {}) mod 10 = {}", p, expected),
+    );
+    expected.to_string()
+}
+
+fn iso7064_mod11_2(trace: &mut Trace, payload: &[u32]) -> String {
+    let mut weight = 1;
+    let mut weights: Vec<u32> = payload
+        .iter()
+        .map(|_| {
+            weight = weight * 2 % 11;
+            weight
+        })
+        .collect();
+    weights.reverse();
+    let sum = trace.weighted_sum(payload, "Weights, 2^position mod 11", &weights, false);
+    let remainder = sum % 11;
+    trace.step(
+        "Remainder mod 11",
+        format!("{} mod 11 = {}", sum, remainder),
+    );
+    let expected = match (12 - remainder) % 11 {
+        10 => "X".to_string(),
+        v => v.to_string(),
+    };
+    trace.step(
+        "Check = (12 - remainder) mod 11, 10 as X",
+        format!("(12 - {}) mod 11 = {}", remainder, expected),
+    );
+    expected
+}
+
+/// Two check digits equal to 97 minus the rest of the number mod 97.
+fn mod97_complement(
+    kind: IdKind,
+    country: &str,
+    code: &str,
+    body: &str,
+) -> Result<Explanation, String> {
+    if body.len() < 3 || !body.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(format!(
+            "Must be digits ending in two check digits, got {}",
+            code
+        ));
+    }
+    let (base, actual) = body.split_at(body.len() - 2);
+    let mut trace = Trace::new();
+    trace.step("Number without the check digits", base);
+    let remainder = mod97(base);
+    trace.step("Remainder mod 97", remainder);
+    let mut expected = format!("{:02}", 97 - remainder);
+    trace.step(
+        "Check digits = 97 - remainder",
+        format!("97 - {} = {}", remainder, expected),
+    );
+    // Belgian numbers of people born from 2000 on are checked with a 2 in
+    // front of the number
+    if country == "BE" && matches!(kind, IdKind::PersonalId | IdKind::TaxId) && expected != actual {
+        let base = format!("2{}", base);
+        trace.step("Born from 2000: with 2 in front", &base);
+        let remainder = mod97(&base);
+        let later = format!("{:02}", 97 - remainder);
+        trace.step(
+            "Check digits = 97 - remainder",
+            format!("97 - {} = {}", remainder, later),
+        );
+        if later == actual {
+            expected = later;
+        }
+    }
+    Ok(trace.finish(kind, Some(country), code, "Mod 97", expected, actual))
+}
+
+fn vat_at(code: &str) -> Result<Explanation, String> {
+    let body = code
+        .strip_prefix("ATU")
+        .ok_or_else(|| format!("AT VAT numbers start with ATU, got {}", code))?;
+    let digits = digits(body, 8, "The part after ATU")?;
+    let mut trace = Trace::new();
+    trace.step("Digits without the check digit", spaced(&digits[..7]));
+    let sum = trace.weighted_sum(&digits[..7], "Weights", &[1, 2, 1, 2, 1, 2, 1], true);
+    let expected = (96 - sum) % 10;
+    trace.step(
+        "Check digit = (96 - sum) mod 10",
+        format!("(96 - {}) mod 10 = {}", sum, expected),
+    );
+    Ok(trace.finish(
+        IdKind::Vat,
+        Some("AT"),
+        code,
+        "Luhn variant (weights 1,2; check = (96 - sum) mod 10)",
+        expected.to_string(),
+        &digits[7].to_string(),
+    ))
+}
+
+fn personal_id_ee(country: &str, code: &str) -> Result<Explanation, String> {
+    let digits = digits(code, 11, "Personal code")?;
+    let mut trace = Trace::new();
+    trace.step("Digits without the check digit", spaced(&digits[..10]));
+    let passes: [(&str, [u32; 10]); 2] = [
+        ("Weights, first pass", [1, 2, 3, 4, 5, 6, 7, 8, 9, 1]),
+        ("Weights, second pass", [3, 4, 5, 6, 7, 8, 9, 1, 2, 3]),
+    ];
+    // The second pass only runs when the first leaves 10, and 10 again
+    // gives check digit 0
+    let mut expected = 0;
+    for (label, weights) in passes {
+        let sum = trace.weighted_sum(&digits[..10], label, &weights, false);
+        let remainder = sum % 11;
+        trace.step(
+            "Remainder mod 11",
+            format!("{} mod 11 = {}", sum, remainder),
+        );
+        if remainder < 10 {
+            expected = remainder;
+            break;
+        }
+    }
+    trace.step(
+        "Check digit = first remainder below 10, otherwise 0",
+        expected,
+    );
+    Ok(trace.finish(
+        IdKind::PersonalId,
+        Some(country),
+        code,
+        "Weighted mod 11, two passes",
+        expected.to_string(),
+        &digits[10].to_string(),
+    ))
+}
+
+fn personal_id_fi(code: &str) -> Result<Explanation, String> {
+    const CHECK_CHARS: &[u8] = b"0123456789ABCDEFHJKLMNPRSTUVWXY";
+    if code.len() != 11 || !code.is_ascii() {
+        return Err(format!("Henkilötunnus must be 11 characters, got {}", code));
+    }
+    let number = format!("{}{}", &code[..6], &code[7..10]);
+    if !number.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(format!(
+            "Birth date and serial must be digits, got {}",
+            code
+        ));
+    }
+    let mut trace = Trace::new();
+    trace.step("Birth date and serial, without the century sign", &number);
+    let value: u32 = number
+        .parse()
+        .map_err(|_| format!("Not a number: {}", number))?;
+    let remainder = value % 31;
+    trace.step(
+        "Remainder mod 31",
+        format!("{} mod 31 = {}", value, remainder),
+    );
+    let expected = CHECK_CHARS[remainder as usize] as char;
+    trace.step(
+        "Check character (0-9, then ABCDEFHJKLMNPRSTUVWXY)",
+        format!("{} = {}", remainder, expected),
+    );
+    Ok(trace.finish(
+        IdKind::PersonalId,
+        Some("FI"),
+        code,
+        "Mod 31 character",
+        expected.to_string(),
+        &code[10..],
+    ))
+}
+
+fn personal_id_no(code: &str) -> Result<Explanation, String> {
+    let digits = digits(code, 11, "Fødselsnummer")?;
+    let mut trace = Trace::new();
+    let passes: [(&str, &str, &[u32]); 2] = [
+        (
+            "Digits without the check digits",
+            "Weights, first check digit",
+            &[3, 7, 6, 1, 8, 9, 4, 5, 2],
+        ),
+        (
+            "Digits with the first check digit",
+            "Weights, second check digit",
+            &[5, 4, 3, 2, 7, 6, 5, 4, 3, 2],
+        ),
+    ];
+    let mut expected = String::new();
+    for (digits_label, weights_label, weights) in passes {
+        let payload = &digits[..weights.len()];
+        trace.step(digits_label, spaced(payload));
+        let sum = trace.weighted_sum(payload, weights_label, weights, false);
+        let check = (11 - sum % 11) % 11;
+        trace.step(
+            "Check digit = (11 - sum mod 11) mod 11",
+            format!("(11 - {}) mod 11 = {}", sum % 11, check),
+        );
+        // 10 cannot be written, so no code with this prefix is valid
+        if check == 10 {
+            expected.push('-');
+            break;
+        }
+        expected.push_str(&check.to_string());
+    }
+    Ok(trace.finish(
+        IdKind::PersonalId,
+        Some("NO"),
+        code,
+        "Weighted mod 11, two check digits",
+        expected,
+        &code[9..],
+    ))
+}
+
+fn personal_id_pl(code: &str) -> Result<Explanation, String> {
+    let digits = digits(code, 11, "PESEL")?;
+    let mut trace = Trace::new();
+    trace.step("Digits without the check digit", spaced(&digits[..10]));
+    let weights = [1, 3, 7, 9, 1, 3, 7, 9, 1, 3];
+    let sum = trace.weighted_sum(&digits[..10], "Weights", &weights, false);
+    let expected = (10 - sum % 10) % 10;
+    trace.step(
+        "Check digit = (10 - sum mod 10) mod 10",
+        format!("(10 - {}) mod 10 = {}", sum % 10, expected),
+    );
+    Ok(trace.finish(
+        IdKind::PersonalId,
+        Some("PL"),
+        code,
+        "Weighted mod 10",
+        expected.to_string(),
+        &digits[10].to_string(),
+    ))
+}
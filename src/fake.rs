@@ -0,0 +1,304 @@
+//! [fake](https://docs.rs/fake) integration (`fake` feature).
+//!
+//! The structs here are fakers for `#[dummy(faker = "...")]` attributes and
+//! `Fake::fake`. Each produces the typed identifier, its plain `String` code,
+//! or the full result type. With [`fake::Faker`](::fake::Faker), the typed
+//! identifiers and result types use any supported country. Fakers for an
+//! unsupported country or brand panic.
+//!
+//! ```rust
+//! use fake::{Dummy, Fake, Faker};
+//! use idsmith::personal_id::date::Gender;
+//!
+//! #[derive(Debug, Dummy)]
+//! struct Customer {
+//!     #[dummy(faker = "idsmith::fake::PersonalId(\"SE\", Some(Gender::Female))")]
+//!     national_id: idsmith::PersonalId,
+//!     #[dummy(faker = "idsmith::fake::Iban(Some(\"DE\"))")]
+//!     iban: String,
+//!     #[dummy(faker = "idsmith::fake::VatNumber(None)")]
+//!     vat: idsmith::VatNumber,
+//!     card: idsmith::CardNumber,
+//! }
+//!
+//! let customer: Customer = Faker.fake();
+//! assert!(customer.iban.starts_with("DE"));
+//! let brand: idsmith::credit_card::CardResult = idsmith::fake::CardNumber(Some("visa")).fake();
+//! assert_eq!(brand.brand, "VISA");
+//! ```
+
+use ::fake::{Dummy, Faker};
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use crate::bank_account::AccountResult;
+use crate::company_id::CompanyResult;
+use crate::credit_card::CardResult;
+use crate::driver_license::DriverLicenseResult;
+use crate::iban::IbanResult;
+use crate::lei::LeiResult;
+use crate::passport::PassportResult;
+use crate::personal_id::date::Gender;
+use crate::personal_id::IdResult;
+use crate::swift::SwiftResult;
+use crate::tax_id::TaxIdResult;
+use crate::types;
+use crate::vat::VatResult;
+
+/// IBANs for a country or group such as `"sepa"`; any country if `None`.
+pub struct Iban<'a>(pub Option<&'a str>);
+
+/// Bank accounts for a country.
+pub struct BankAccount<'a>(pub &'a str);
+
+/// Personal IDs for a country, optionally of one gender.
+pub struct PersonalId<'a>(pub &'a str, pub Option<Gender>);
+
+/// Card numbers of a brand such as `"visa"`; any brand if `None`.
+pub struct CardNumber<'a>(pub Option<&'a str>);
+
+/// SWIFT/BIC codes for a country.
+pub struct Bic<'a>(pub Option<&'a str>);
+
+/// LEI codes registered in a country.
+pub struct Lei<'a>(pub Option<&'a str>);
+
+/// VAT numbers for a country or group such as `"eurozone"`.
+pub struct VatNumber<'a>(pub Option<&'a str>);
+
+pub struct CompanyId<'a>(pub Option<&'a str>);
+
+pub struct DriverLicense<'a>(pub Option<&'a str>);
+
+pub struct TaxId<'a>(pub Option<&'a str>);
+
+pub struct Passport<'a>(pub Option<&'a str>);
+
+/// Implements `Dummy<$faker>` for further types by converting the value
+/// generated for `$from`.
+macro_rules! convert_dummy {
+    ($faker:ident => $from:ty: $($to:ty = |$v:ident| $conv:expr),+ $(,)?) => {$(
+        impl<'a> Dummy<$faker<'a>> for $to {
+            fn dummy_with_rng<R: Rng + ?Sized>(config: &$faker<'a>, rng: &mut R) -> Self {
+                let $v = <$from as Dummy<$faker<'a>>>::dummy_with_rng(config, rng);
+                $conv
+            }
+        }
+    )+};
+}
+
+/// Implements `Dummy<Faker>` through a country-less faker.
+macro_rules! any_country {
+    ($($to:ty = $faker:expr),+ $(,)?) => {$(
+        impl Dummy<Faker> for $to {
+            fn dummy_with_rng<R: Rng + ?Sized>(_: &Faker, rng: &mut R) -> Self {
+                Dummy::dummy_with_rng(&$faker, rng)
+            }
+        }
+    )+};
+}
+
+impl Dummy<Iban<'_>> for IbanResult {
+    fn dummy_with_rng<R: Rng + ?Sized>(config: &Iban<'_>, mut rng: &mut R) -> Self {
+        let code = crate::iban::generate_iban(config.0, &mut rng)
+            .unwrap_or_else(|e| panic!("Cannot generate IBAN: {}", e));
+        IbanResult {
+            country: code[..2].to_string(),
+            formatted: crate::iban::format_iban(&code),
+            iban: types::Iban::from_generated(code),
+            valid: true,
+        }
+    }
+}
+
+convert_dummy!(Iban => IbanResult:
+    types::Iban = |r| r.iban,
+    String = |r| r.iban.into(),
+);
+
+impl Dummy<BankAccount<'_>> for AccountResult {
+    fn dummy_with_rng<R: Rng + ?Sized>(config: &BankAccount<'_>, mut rng: &mut R) -> Self {
+        crate::bank_accounts()
+            .generate(config.0, &Default::default(), &mut rng)
+            .unwrap_or_else(|| panic!("Cannot generate bank account for {}", config.0))
+    }
+}
+
+impl Dummy<Faker> for AccountResult {
+    fn dummy_with_rng<R: Rng + ?Sized>(_: &Faker, mut rng: &mut R) -> Self {
+        let countries = crate::bank_accounts().list_countries();
+        let (country, _, _, _) = countries.choose(&mut rng).expect("bank account countries");
+        Dummy::dummy_with_rng(&BankAccount(country), rng)
+    }
+}
+
+impl Dummy<PersonalId<'_>> for IdResult {
+    fn dummy_with_rng<R: Rng + ?Sized>(config: &PersonalId<'_>, mut rng: &mut R) -> Self {
+        let PersonalId(country, gender) = *config;
+        let registry = crate::personal_ids();
+        let opts = crate::personal_id::GenOptions {
+            gender,
+            ..Default::default()
+        };
+        registry
+            .generate(country, &opts, &mut rng)
+            .and_then(|code| registry.parse(country, &code))
+            .unwrap_or_else(|| panic!("Cannot generate personal ID for {}", country))
+    }
+}
+
+convert_dummy!(PersonalId => IdResult:
+    types::PersonalId = |r| types::PersonalId::new(&r.country_code, &r.code)
+        .expect("generated personal IDs are valid"),
+    String = |r| r.code,
+);
+
+impl Dummy<Faker> for IdResult {
+    fn dummy_with_rng<R: Rng + ?Sized>(_: &Faker, mut rng: &mut R) -> Self {
+        let countries = crate::personal_ids().list_countries();
+        let (country, _, _) = countries.choose(&mut rng).expect("personal ID countries");
+        Dummy::dummy_with_rng(&PersonalId(country, None), rng)
+    }
+}
+
+impl Dummy<Faker> for types::PersonalId {
+    fn dummy_with_rng<R: Rng + ?Sized>(config: &Faker, rng: &mut R) -> Self {
+        let r = IdResult::dummy_with_rng(config, rng);
+        types::PersonalId::new(&r.country_code, &r.code).expect("generated personal IDs are valid")
+    }
+}
+
+impl Dummy<CardNumber<'_>> for CardResult {
+    fn dummy_with_rng<R: Rng + ?Sized>(config: &CardNumber<'_>, mut rng: &mut R) -> Self {
+        let opts = crate::credit_card::GenOptions {
+            brand: config.0.map(str::to_string),
+            ..Default::default()
+        };
+        crate::credit_cards()
+            .generate(&opts, &mut rng)
+            .unwrap_or_else(|| panic!("Cannot generate card for {:?}", config.0))
+    }
+}
+
+convert_dummy!(CardNumber => CardResult:
+    types::CardNumber = |r| r.number,
+    String = |r| r.number.into(),
+);
+
+impl Dummy<Bic<'_>> for SwiftResult {
+    fn dummy_with_rng<R: Rng + ?Sized>(config: &Bic<'_>, mut rng: &mut R) -> Self {
+        let opts = crate::swift::GenOptions {
+            country: config.0.map(str::to_uppercase),
+        };
+        crate::swift_codes().generate(&opts, &mut rng)
+    }
+}
+
+convert_dummy!(Bic => SwiftResult:
+    types::Bic = |r| r.code,
+    String = |r| r.code.into(),
+);
+
+impl Dummy<Lei<'_>> for LeiResult {
+    fn dummy_with_rng<R: Rng + ?Sized>(config: &Lei<'_>, mut rng: &mut R) -> Self {
+        let opts = crate::lei::GenOptions {
+            country: config.0.map(str::to_uppercase),
+        };
+        crate::lei_codes().generate(&opts, &mut rng)
+    }
+}
+
+convert_dummy!(Lei => LeiResult:
+    types::Lei = |r| r.code,
+    String = |r| r.code.into(),
+);
+
+impl Dummy<VatNumber<'_>> for VatResult {
+    fn dummy_with_rng<R: Rng + ?Sized>(config: &VatNumber<'_>, mut rng: &mut R) -> Self {
+        let opts = crate::vat::GenOptions {
+            country: config.0.map(str::to_string),
+            ..Default::default()
+        };
+        crate::vat_ids()
+            .generate(&opts, &mut rng)
+            .unwrap_or_else(|| panic!("Cannot generate VAT number for {:?}", config.0))
+    }
+}
+
+convert_dummy!(VatNumber => VatResult:
+    types::VatNumber = |r| r.code,
+    String = |r| r.code.into(),
+);
+
+impl Dummy<CompanyId<'_>> for CompanyResult {
+    fn dummy_with_rng<R: Rng + ?Sized>(config: &CompanyId<'_>, mut rng: &mut R) -> Self {
+        let opts = crate::company_id::GenOptions {
+            country: config.0.map(str::to_string),
+            ..Default::default()
+        };
+        crate::company_ids()
+            .generate(&opts, &mut rng)
+            .unwrap_or_else(|| panic!("Cannot generate company ID for {:?}", config.0))
+    }
+}
+
+convert_dummy!(CompanyId => CompanyResult: String = |r| r.code);
+
+impl Dummy<DriverLicense<'_>> for DriverLicenseResult {
+    fn dummy_with_rng<R: Rng + ?Sized>(config: &DriverLicense<'_>, mut rng: &mut R) -> Self {
+        let opts = crate::driver_license::GenOptions {
+            country: config.0.map(str::to_string),
+            ..Default::default()
+        };
+        crate::driver_licenses()
+            .generate(&opts, &mut rng)
+            .unwrap_or_else(|| panic!("Cannot generate driver's license for {:?}", config.0))
+    }
+}
+
+convert_dummy!(DriverLicense => DriverLicenseResult: String = |r| r.code);
+
+impl Dummy<TaxId<'_>> for TaxIdResult {
+    fn dummy_with_rng<R: Rng + ?Sized>(config: &TaxId<'_>, mut rng: &mut R) -> Self {
+        let opts = crate::tax_id::GenOptions {
+            country: config.0.map(str::to_string),
+            ..Default::default()
+        };
+        crate::tax_ids()
+            .generate(&opts, &mut rng)
+            .unwrap_or_else(|| panic!("Cannot generate tax ID for {:?}", config.0))
+    }
+}
+
+convert_dummy!(TaxId => TaxIdResult: String = |r| r.code);
+
+impl Dummy<Passport<'_>> for PassportResult {
+    fn dummy_with_rng<R: Rng + ?Sized>(config: &Passport<'_>, mut rng: &mut R) -> Self {
+        let opts = crate::passport::GenOptions {
+            country: config.0.map(str::to_string),
+            ..Default::default()
+        };
+        crate::passports()
+            .generate(&opts, &mut rng)
+            .unwrap_or_else(|| panic!("Cannot generate passport for {:?}", config.0))
+    }
+}
+
+convert_dummy!(Passport => PassportResult: String = |r| r.code);
+
+any_country!(
+    IbanResult = Iban(None),
+    types::Iban = Iban(None),
+    CardResult = CardNumber(None),
+    types::CardNumber = CardNumber(None),
+    SwiftResult = Bic(None),
+    types::Bic = Bic(None),
+    LeiResult = Lei(None),
+    types::Lei = Lei(None),
+    VatResult = VatNumber(None),
+    types::VatNumber = VatNumber(None),
+    CompanyResult = CompanyId(None),
+    DriverLicenseResult = DriverLicense(None),
+    TaxIdResult = TaxId(None),
+    PassportResult = Passport(None),
+);
@@ -627,6 +627,15 @@ pub(crate) fn get_format(country: &str) -> Option<&'static [BbanField]> {
         .map(|f| f.fields)
 }
 
+/// Returns true if `iban` (compact, uppercase) has a known country prefix
+/// and the length registered for that country. Used to tell real IBANs
+/// apart from text that happens to pass mod-97.
+pub(crate) fn has_registered_length(iban: &str) -> bool {
+    iban.get(..2).and_then(get_format).is_some_and(|fields| {
+        4 + fields.iter().map(|f| f.length as usize).sum::<usize>() == iban.len()
+    })
+}
+
 /// Returns all supported IBAN country codes.
 pub fn supported_countries() -> Vec<&'static str> {
     ALL_FORMATS.iter().map(|f| f.code).collect()
@@ -3,6 +3,7 @@ use rand::Rng;
 use super::checksum::fix_bban_checksums;
 use super::countries::{get_format, supported_countries};
 use super::util::random_chars;
+use crate::countries::{random_member, Membership};
 
 fn generate_bban(country: &str, rng: &mut impl Rng) -> Option<String> {
     let fields = get_format(country)?;
@@ -46,7 +47,8 @@ fn calculate_check_digits(country_code: &str, bban: &str) -> String {
 
 /// Generate a random valid IBAN for the given country code.
 ///
-/// If `country` is `None`, a random supported country is chosen.
+/// If `country` is `None`, a random supported country is chosen. A country
+/// group (`"sepa"`, `"eu"`, `"eea"` or `"eurozone"`) picks a random member.
 /// Returns an error if the country code is not supported.
 ///
 /// # Examples
@@ -59,16 +61,24 @@ fn calculate_check_digits(country_code: &str, bban: &str) -> String {
 /// let code = iban::generate_iban(Some("DE"), &mut rng).unwrap();
 /// assert!(code.starts_with("DE"));
 /// assert!(iban::validate_iban(&code));
+///
+/// let code = iban::generate_iban(Some("sepa"), &mut rng).unwrap();
+/// assert!(iban::validate_iban(&code));
 /// ```
 pub fn generate_iban(country: Option<&str>, rng: &mut impl Rng) -> Result<String, String> {
     let cc = match country {
-        Some(c) => {
-            let c = c.to_uppercase();
-            if get_format(&c).is_none() {
-                return Err(format!("Unsupported country: {}", c));
+        Some(c) => match c.parse::<Membership>() {
+            Ok(group) => random_member(group, rng, |c| get_format(c).is_some())
+                .ok_or_else(|| format!("No IBAN countries in {}", group))?
+                .to_string(),
+            Err(_) => {
+                let c = c.to_uppercase();
+                if get_format(&c).is_none() {
+                    return Err(format!("Unsupported country: {}", c));
+                }
+                c
             }
-            c
-        }
+        },
         None => {
             let countries = supported_countries();
             countries[rng.gen_range(0..countries.len())].to_string()
@@ -109,7 +119,11 @@ pub fn format_iban(iban: &str) -> String {
 /// ```
 pub fn validate_iban(iban: &str) -> bool {
     let clean: String = iban.chars().filter(|c| !c.is_whitespace()).collect();
-    if clean.len() < 4 {
+    if clean.len() < 4
+        || !clean
+            .chars()
+            .all(|c| c.is_ascii_digit() || c.is_ascii_uppercase())
+    {
         return false;
     }
     let rearranged: String = clean[4..].to_string() + &clean[..4];
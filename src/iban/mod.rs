@@ -7,8 +7,8 @@ mod util;
 #[cfg(feature = "json")]
 use serde::Serialize;
 
-pub(crate) use countries::get_format;
 pub use countries::supported_countries;
+pub(crate) use countries::{get_format, has_registered_length};
 pub use generate::{format_iban, generate_iban, validate_iban};
 pub use types::{BbanField, CharType};
 
@@ -16,7 +16,7 @@ pub use types::{BbanField, CharType};
 #[cfg_attr(feature = "json", derive(Serialize))]
 pub struct IbanResult {
     pub country: String,
-    pub iban: String,
+    pub iban: crate::Iban,
     pub formatted: String,
     pub valid: bool,
 }
@@ -0,0 +1,324 @@
+//! Identifier kinds and country-qualified targets shared by the CLI and the
+//! higher-level tools (detection, anonymization).
+
+use std::fmt;
+use std::str::FromStr;
+
+use rand::Rng;
+
+/// The identifier families supported by idsmith.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IdKind {
+    Iban,
+    BankAccount,
+    PersonalId,
+    CreditCard,
+    Swift,
+    CompanyId,
+    DriverLicense,
+    TaxId,
+    Passport,
+    Lei,
+    Vat,
+}
+
+impl IdKind {
+    /// All kinds, in CLI listing order.
+    pub const ALL: &'static [IdKind] = &[
+        IdKind::Iban,
+        IdKind::BankAccount,
+        IdKind::PersonalId,
+        IdKind::CreditCard,
+        IdKind::Swift,
+        IdKind::CompanyId,
+        IdKind::DriverLicense,
+        IdKind::TaxId,
+        IdKind::Passport,
+        IdKind::Lei,
+        IdKind::Vat,
+    ];
+
+    /// Short name used by the CLI (`iban`, `account`, `id`, `card`, ...).
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            IdKind::Iban => "iban",
+            IdKind::BankAccount => "account",
+            IdKind::PersonalId => "id",
+            IdKind::CreditCard => "card",
+            IdKind::Swift => "swift",
+            IdKind::CompanyId => "company",
+            IdKind::DriverLicense => "license",
+            IdKind::TaxId => "tax",
+            IdKind::Passport => "passport",
+            IdKind::Lei => "lei",
+            IdKind::Vat => "vat",
+        }
+    }
+
+    /// Returns true if validation needs a country code.
+    ///
+    /// IBANs, VAT numbers, LEIs, SWIFT codes and card numbers are
+    /// self-describing; every other kind is validated per country.
+    pub fn requires_country(&self) -> bool {
+        !matches!(
+            self,
+            IdKind::Iban | IdKind::CreditCard | IdKind::Swift | IdKind::Lei | IdKind::Vat
+        )
+    }
+
+    /// Validate `code` as this kind.
+    ///
+    /// Returns `None` when a country is required but missing or unsupported.
+    /// Non-ASCII input is always invalid.
+    pub fn validate(&self, country: Option<&str>, code: &str) -> Option<bool> {
+        let country = country.map(|c| c.to_uppercase());
+        if self.requires_country() && country.is_none() {
+            return None;
+        }
+        if !code.is_ascii() {
+            return Some(false);
+        }
+        let cc = country.as_deref().unwrap_or("");
+        match self {
+            IdKind::Iban => Some(crate::iban::validate_iban(code)),
+            IdKind::BankAccount => crate::bank_accounts().validate(cc, code),
+            IdKind::PersonalId => crate::personal_ids().validate(cc, code),
+            IdKind::CreditCard => Some(crate::credit_cards().validate(code)),
+            IdKind::Swift => Some(crate::swift_codes().validate(code)),
+            IdKind::CompanyId => Some(crate::company_ids().validate(cc, code)),
+            IdKind::DriverLicense => Some(crate::driver_licenses().validate(cc, code)),
+            IdKind::TaxId => Some(crate::tax_ids().validate(cc, code)),
+            IdKind::Passport => Some(crate::passports().validate(cc, code)),
+            IdKind::Lei => Some(crate::lei_codes().validate(code)),
+            IdKind::Vat => Some(crate::vat_ids().validate(code)),
+        }
+    }
+
+    /// Generate a single code of this kind.
+    ///
+    /// `country` is optional for every kind except personal IDs and bank
+    /// accounts; for credit cards it is ignored. Returns `None` if the
+    /// country is not supported.
+    pub fn generate(&self, country: Option<&str>, rng: &mut impl Rng) -> Option<String> {
+        let country = country.map(|c| c.to_uppercase());
+        match self {
+            IdKind::Iban => crate::iban::generate_iban(country.as_deref(), rng).ok(),
+            IdKind::BankAccount => {
+                let opts = crate::bank_account::GenOptions::default();
+                let registry = crate::bank_accounts();
+                let cc = match country {
+                    Some(c) => c,
+                    None => {
+                        let countries = registry.list_countries();
+                        countries[rng.gen_range(0..countries.len())].0.to_string()
+                    }
+                };
+                registry.generate(&cc, &opts, rng).map(|r| r.raw)
+            }
+            IdKind::PersonalId => {
+                let registry = crate::personal_ids();
+                let cc = match country {
+                    Some(c) => c,
+                    None => {
+                        let countries = registry.list_countries();
+                        countries[rng.gen_range(0..countries.len())].0.to_string()
+                    }
+                };
+                registry.generate(&cc, &Default::default(), rng)
+            }
+            IdKind::CreditCard => crate::credit_cards()
+                .generate(&Default::default(), rng)
+                .map(|r| r.number.into()),
+            IdKind::Swift => {
+                let opts = crate::swift::GenOptions { country };
+                Some(crate::swift_codes().generate(&opts, rng).code.into())
+            }
+            IdKind::CompanyId => {
+                let opts = crate::company_id::GenOptions {
+                    country,
+                    ..Default::default()
+                };
+                crate::company_ids().generate(&opts, rng).map(|r| r.code)
+            }
+            IdKind::DriverLicense => {
+                let opts = crate::driver_license::GenOptions {
+                    country,
+                    state: None,
+                    ..Default::default()
+                };
+                crate::driver_licenses()
+                    .generate(&opts, rng)
+                    .map(|r| r.code)
+            }
+            IdKind::TaxId => {
+                let opts = crate::tax_id::GenOptions {
+                    country,
+                    holder_type: None,
+                    ..Default::default()
+                };
+                crate::tax_ids().generate(&opts, rng).map(|r| r.code)
+            }
+            IdKind::Passport => {
+                let opts = crate::passport::GenOptions {
+                    country,
+                    ..Default::default()
+                };
+                crate::passports().generate(&opts, rng).map(|r| r.code)
+            }
+            IdKind::Lei => {
+                let opts = crate::lei::GenOptions { country };
+                Some(crate::lei_codes().generate(&opts, rng).code.into())
+            }
+            IdKind::Vat => {
+                let opts = crate::vat::GenOptions {
+                    country,
+                    ..Default::default()
+                };
+                crate::vat_ids().generate(&opts, rng).map(|r| r.code.into())
+            }
+        }
+    }
+
+    /// Name of the check algorithm for `country` (e.g. `"Luhn"`,
+    /// `"Weighted mod 11"`), as declared by the scheme. Returns `None` for
+    /// schemes without one, registered schemes that do not declare it, and
+    /// kinds whose algorithm differs by country when none is given.
+    pub fn checksum(&self, country: Option<&str>) -> Option<String> {
+        let country = country.map(|c| c.to_uppercase());
+        let country = country.as_deref();
+        match self {
+            IdKind::Iban | IdKind::Lei => Some("ISO 7064 MOD 97-10".to_string()),
+            IdKind::CreditCard => Some("Luhn".to_string()),
+            IdKind::Swift | IdKind::Passport => None,
+            IdKind::BankAccount => crate::bank_accounts()
+                .checksum(country?)
+                .map(str::to_string),
+            IdKind::PersonalId => crate::personal_ids().checksum(country?),
+            IdKind::CompanyId => crate::company_ids().checksum(country?),
+            IdKind::DriverLicense => crate::driver_licenses()
+                .checksum(country?)
+                .map(str::to_string),
+            IdKind::TaxId => crate::tax_ids().checksum(country?).map(str::to_string),
+            IdKind::Vat => crate::vat_ids().checksum(country?).map(str::to_string),
+        }
+    }
+
+    /// Country whose rules are used for `country`: the country itself, or
+    /// its parent for territories without their own scheme (e.g. US for
+    /// PR). Returns `None` for unsupported countries and for kinds whose
+    /// rules do not differ by country.
+    pub fn effective_country(&self, country: &str) -> Option<String> {
+        match self {
+            IdKind::PersonalId => crate::personal_ids().effective_country(country),
+            IdKind::BankAccount => crate::bank_accounts().effective_country(country),
+            IdKind::CompanyId => crate::company_ids().effective_country(country),
+            IdKind::TaxId => crate::tax_ids().effective_country(country),
+            IdKind::Passport => crate::passports().effective_country(country),
+            IdKind::DriverLicense => crate::driver_licenses().effective_country(country),
+            IdKind::Vat => crate::vat_ids().effective_country(country),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for IdKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "json")]
+impl serde::Serialize for IdKind {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "json")]
+impl<'de> serde::Deserialize<'de> for IdKind {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        value.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+impl FromStr for IdKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "iban" => Ok(IdKind::Iban),
+            "account" | "bank_account" => Ok(IdKind::BankAccount),
+            "id" | "personal_id" => Ok(IdKind::PersonalId),
+            "card" | "credit_card" => Ok(IdKind::CreditCard),
+            "swift" | "bic" => Ok(IdKind::Swift),
+            "company" | "company_id" => Ok(IdKind::CompanyId),
+            "license" | "driver_license" => Ok(IdKind::DriverLicense),
+            "tax" | "tax_id" => Ok(IdKind::TaxId),
+            "passport" => Ok(IdKind::Passport),
+            "lei" => Ok(IdKind::Lei),
+            "vat" => Ok(IdKind::Vat),
+            other => Err(format!(
+                "Unknown category: {}. Use iban, account, id, card, swift, company, license, tax, passport, lei, or vat.",
+                other
+            )),
+        }
+    }
+}
+
+/// An identifier kind, optionally pinned to a country (`id:US`, `iban:DE`, `vat`).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Target {
+    pub kind: IdKind,
+    pub country: Option<String>,
+}
+
+impl Target {
+    pub fn new(kind: IdKind, country: Option<&str>) -> Self {
+        Self {
+            kind,
+            country: country.map(|c| c.to_uppercase()),
+        }
+    }
+
+    /// Validate `code` against this target. See [`IdKind::validate`].
+    pub fn validate(&self, code: &str) -> Option<bool> {
+        self.kind.validate(self.country.as_deref(), code)
+    }
+
+    /// Generate a code for this target. See [`IdKind::generate`].
+    pub fn generate(&self, rng: &mut impl Rng) -> Option<String> {
+        self.kind.generate(self.country.as_deref(), rng)
+    }
+}
+
+impl fmt::Display for Target {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.country {
+            Some(c) => write!(f, "{}:{}", self.kind, c),
+            None => write!(f, "{}", self.kind),
+        }
+    }
+}
+
+impl FromStr for Target {
+    type Err = String;
+
+    /// Parses `kind` or `kind:CC`, e.g. `iban`, `id:US`, `tax:IN`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (kind, country) = match s.split_once(':') {
+            Some((k, c)) if !c.is_empty() => (k, Some(c)),
+            Some((k, _)) => (k, None),
+            None => (s, None),
+        };
+        let kind: IdKind = kind.parse()?;
+        if country.is_none() && kind.requires_country() {
+            return Err(format!(
+                "{} requires a country, e.g. {}:US",
+                kind.as_str(),
+                kind.as_str()
+            ));
+        }
+        Ok(Target::new(kind, country))
+    }
+}
@@ -5,7 +5,7 @@ use serde::Serialize;
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "json", derive(Serialize))]
 pub struct LeiResult {
-    pub code: String,
+    pub code: crate::Lei,
     pub lou: String,
     pub country_code: String,
     pub valid: bool,
@@ -74,7 +74,7 @@ impl Registry {
         LeiResult {
             lou: code[..4].to_string(),
             country_code: code[4..6].to_string(),
-            code,
+            code: crate::Lei::from_generated(code),
             valid: true,
         }
     }
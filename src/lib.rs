@@ -58,22 +58,61 @@
 //! let is_ssn_valid = personal_ids().validate("US", "446-72-2445").unwrap_or(false);
 //! ```
 
+pub mod anonymize;
+#[cfg(feature = "arrow")]
+pub mod arrow;
 pub mod bank_account;
+pub mod batch;
 pub mod company_id;
 pub mod countries;
+pub mod coverage;
 pub mod credit_card;
+pub mod describe;
+pub mod detect;
 pub mod driver_license;
+pub mod explain;
+#[cfg(feature = "fake")]
+pub mod fake;
 pub mod iban;
+pub mod kind;
 pub mod lei;
+pub mod locale;
 pub mod passport;
 pub mod personal_id;
+pub mod profile;
+#[cfg(feature = "proptest")]
+pub mod proptest;
+pub mod scan;
+pub mod scheme;
 pub mod swift;
 pub mod tax_id;
+pub mod types;
 pub mod vat;
+pub mod weights;
 
 #[cfg(feature = "csv")]
 pub mod csv;
 
+#[cfg(feature = "spec")]
+pub mod dataset;
+
+#[cfg(feature = "spec")]
+pub mod spec;
+
+#[cfg(feature = "output")]
+pub mod output;
+
+#[cfg(feature = "sql")]
+pub mod sql;
+
+#[cfg(feature = "serve")]
+pub mod serve;
+
+pub use describe::describe;
+pub use scan::{redact, scan};
+pub use scheme::IdScheme;
+pub use types::{Bic, CardNumber, Iban, Lei, PersonalId, VatNumber};
+
 use std::sync::OnceLock;
 
 /// Global registry for bank accounts.
@@ -0,0 +1,145 @@
+//! Country and document names in the local language, in English, or as an
+//! ASCII transliteration, for display in multilingual interfaces.
+//!
+//! Local names keep their script and diacritics ("Henkilötunnus",
+//! "여권"); transliterations strip them ("Henkilotunnus", "Yeogwon").
+//! Countries without a local name here use their English name, and
+//! documents without one use the registry's label in every locale.
+//!
+//! ```rust
+//! use idsmith::kind::IdKind;
+//! use idsmith::locale::{self, Locale};
+//!
+//! let kr = locale::document(IdKind::Passport, "KR").unwrap();
+//! assert_eq!((kr.local, kr.english, kr.latin), ("여권", "Passport", "Yeogwon"));
+//!
+//! assert_eq!(locale::country_name("DE", Locale::Local), Some("Deutschland"));
+//! assert_eq!(locale::country_name("DE", Locale::English), Some("Germany"));
+//!
+//! let ids = idsmith::personal_ids();
+//! assert_eq!(ids.name_in("FI", Locale::Latin).as_deref(), Some("Henkilotunnus"));
+//! ```
+
+mod names;
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::kind::IdKind;
+
+/// Which form of a name to return.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Locale {
+    /// Official name in the country's own language and script.
+    Local,
+    /// English name.
+    #[default]
+    English,
+    /// The local name transliterated to ASCII.
+    Latin,
+}
+
+impl Locale {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Locale::Local => "local",
+            Locale::English => "english",
+            Locale::Latin => "latin",
+        }
+    }
+}
+
+impl fmt::Display for Locale {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for Locale {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "local" | "native" => Ok(Locale::Local),
+            "english" | "en" => Ok(Locale::English),
+            "latin" | "translit" | "ascii" => Ok(Locale::Latin),
+            other => Err(format!(
+                "Unknown locale: {}. Use local, english, or latin.",
+                other
+            )),
+        }
+    }
+}
+
+/// A name in each [`Locale`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LocalizedName {
+    pub local: &'static str,
+    pub english: &'static str,
+    /// ASCII transliteration of `local`.
+    pub latin: &'static str,
+}
+
+impl LocalizedName {
+    pub fn get(&self, locale: Locale) -> &'static str {
+        match locale {
+            Locale::Local => self.local,
+            Locale::English => self.english,
+            Locale::Latin => self.latin,
+        }
+    }
+}
+
+/// Returns the names of a country, or `None` for an unknown code.
+pub fn country(code: &str) -> Option<LocalizedName> {
+    let info = crate::countries::get(code)?;
+    let localized = names::COUNTRIES
+        .iter()
+        .find(|(c, _, _)| *c == info.code)
+        .map(|(_, local, latin)| (*local, *latin));
+    let (local, latin) = localized.unwrap_or((info.name, info.name));
+    Some(LocalizedName {
+        local,
+        english: info.name,
+        latin,
+    })
+}
+
+/// Returns the name of a country in `locale`.
+pub fn country_name(code: &str, locale: Locale) -> Option<&'static str> {
+    country(code).map(|n| n.get(locale))
+}
+
+/// Returns the names of the document `kind` issued by `country`.
+///
+/// Only personal IDs, company IDs, driver's licenses, tax IDs and passports
+/// carry localized names; territories are not resolved to their parent.
+pub fn document(kind: IdKind, country: &str) -> Option<LocalizedName> {
+    let table = match kind {
+        IdKind::PersonalId => names::PERSONAL_IDS,
+        IdKind::CompanyId => names::COMPANY_IDS,
+        IdKind::DriverLicense => names::DRIVER_LICENSES,
+        IdKind::TaxId => names::TAX_IDS,
+        IdKind::Passport => names::PASSPORTS,
+        _ => return None,
+    };
+    let country = country.to_uppercase();
+    table
+        .iter()
+        .find(|(c, _, _, _)| *c == country)
+        .map(|(_, local, english, latin)| LocalizedName {
+            local,
+            english,
+            latin,
+        })
+}
+
+/// Returns the name of the document `kind` issued by `country` in `locale`.
+pub fn document_name(kind: IdKind, country: &str, locale: Locale) -> Option<&'static str> {
+    document(kind, country).map(|n| n.get(locale))
+}
+
+/// Country name for registry listings, `"Unknown"` for custom codes.
+pub(crate) fn country_label(code: &str, locale: Locale) -> &'static str {
+    country_name(code, locale).unwrap_or("Unknown")
+}
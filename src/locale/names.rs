@@ -0,0 +1,1745 @@
+//! Localized country and document names.
+
+/// Local-language country names with their ASCII transliteration; the
+/// English name comes from [`crate::countries`].
+pub(super) const COUNTRIES: &[(&str, &str, &str)] = &[
+    ("AD", "Andorra", "Andorra"),
+    (
+        "AE",
+        "الإمارات العربية المتحدة",
+        "Al-Imarat al-Arabiyya al-Muttahida",
+    ),
+    ("AL", "Shqipëria", "Shqiperia"),
+    ("AM", "Հայաստան", "Hayastan"),
+    ("AR", "Argentina", "Argentina"),
+    ("AT", "Österreich", "Osterreich"),
+    ("AX", "Åland", "Aland"),
+    ("AZ", "Azərbaycan", "Azerbaycan"),
+    ("BA", "Bosna i Hercegovina", "Bosna i Hercegovina"),
+    ("BD", "বাংলাদেশ", "Bangladesh"),
+    ("BE", "België", "Belgie"),
+    ("BG", "България", "Balgariya"),
+    ("BH", "البحرين", "Al-Bahrayn"),
+    ("BL", "Saint-Barthélemy", "Saint-Barthelemy"),
+    ("BO", "Bolivia", "Bolivia"),
+    ("BR", "Brasil", "Brasil"),
+    ("BV", "Bouvetøya", "Bouvetoya"),
+    ("BY", "Беларусь", "Belarus"),
+    ("CH", "Schweiz", "Schweiz"),
+    ("CI", "Côte d'Ivoire", "Cote d'Ivoire"),
+    ("CL", "Chile", "Chile"),
+    ("CN", "中国", "Zhongguo"),
+    ("CO", "Colombia", "Colombia"),
+    ("CR", "Costa Rica", "Costa Rica"),
+    ("CU", "Cuba", "Cuba"),
+    ("CW", "Curaçao", "Curacao"),
+    ("CY", "Κύπρος", "Kypros"),
+    ("CZ", "Česko", "Cesko"),
+    ("DE", "Deutschland", "Deutschland"),
+    ("DK", "Danmark", "Danmark"),
+    ("DO", "República Dominicana", "Republica Dominicana"),
+    ("DZ", "الجزائر", "Al-Jaza'ir"),
+    ("EC", "Ecuador", "Ecuador"),
+    ("EE", "Eesti", "Eesti"),
+    ("EG", "مصر", "Misr"),
+    ("ES", "España", "Espana"),
+    ("ET", "ኢትዮጵያ", "Ityop'iya"),
+    ("FI", "Suomi", "Suomi"),
+    ("FO", "Føroyar", "Foroyar"),
+    ("FR", "France", "France"),
+    ("GE", "საქართველო", "Sakartvelo"),
+    ("GF", "Guyane", "Guyane"),
+    ("GL", "Kalaallit Nunaat", "Kalaallit Nunaat"),
+    ("GN", "Guinée", "Guinee"),
+    ("GP", "Guadeloupe", "Guadeloupe"),
+    ("GR", "Ελλάδα", "Ellada"),
+    ("GT", "Guatemala", "Guatemala"),
+    ("HK", "香港", "Xianggang"),
+    ("HN", "Honduras", "Honduras"),
+    ("HR", "Hrvatska", "Hrvatska"),
+    ("HU", "Magyarország", "Magyarorszag"),
+    ("ID", "Indonesia", "Indonesia"),
+    ("IE", "Éire", "Eire"),
+    ("IL", "ישראל", "Yisra'el"),
+    ("IN", "भारत", "Bharat"),
+    ("IQ", "العراق", "Al-Iraq"),
+    ("IR", "ایران", "Iran"),
+    ("IS", "Ísland", "Island"),
+    ("IT", "Italia", "Italia"),
+    ("JO", "الأردن", "Al-Urdun"),
+    ("JP", "日本", "Nihon"),
+    ("KG", "Кыргызстан", "Kyrgyzstan"),
+    ("KR", "대한민국", "Daehan Minguk"),
+    ("KW", "الكويت", "Al-Kuwayt"),
+    ("KZ", "Қазақстан", "Qazaqstan"),
+    ("LB", "لبنان", "Lubnan"),
+    ("LI", "Liechtenstein", "Liechtenstein"),
+    ("LK", "ශ්‍රී ලංකාව", "Shri Lankava"),
+    ("LT", "Lietuva", "Lietuva"),
+    ("LU", "Lëtzebuerg", "Letzebuerg"),
+    ("LV", "Latvija", "Latvija"),
+    ("MA", "المغرب", "Al-Maghrib"),
+    ("MC", "Monaco", "Monaco"),
+    ("MD", "Moldova", "Moldova"),
+    ("ME", "Crna Gora", "Crna Gora"),
+    ("MF", "Saint-Martin", "Saint-Martin"),
+    ("MK", "Северна Македонија", "Severna Makedonija"),
+    ("MN", "Монгол Улс", "Mongol Uls"),
+    ("MQ", "Martinique", "Martinique"),
+    ("MX", "México", "Mexico"),
+    ("MY", "Malaysia", "Malaysia"),
+    ("MZ", "Moçambique", "Mocambique"),
+    ("NC", "Nouvelle-Calédonie", "Nouvelle-Caledonie"),
+    ("NI", "Nicaragua", "Nicaragua"),
+    ("NL", "Nederland", "Nederland"),
+    ("NO", "Norge", "Norge"),
+    ("NP", "नेपाल", "Nepal"),
+    ("OM", "عُمان", "Uman"),
+    ("PA", "Panamá", "Panama"),
+    ("PE", "Perú", "Peru"),
+    ("PF", "Polynésie française", "Polynesie francaise"),
+    ("PH", "Pilipinas", "Pilipinas"),
+    ("PK", "پاکستان", "Pakistan"),
+    ("PL", "Polska", "Polska"),
+    ("PM", "Saint-Pierre-et-Miquelon", "Saint-Pierre-et-Miquelon"),
+    ("PR", "Puerto Rico", "Puerto Rico"),
+    ("PS", "فلسطين", "Filastin"),
+    ("PT", "Portugal", "Portugal"),
+    ("PY", "Paraguay", "Paraguay"),
+    ("QA", "قطر", "Qatar"),
+    ("RE", "La Réunion", "La Reunion"),
+    ("RO", "România", "Romania"),
+    ("RS", "Србија", "Srbija"),
+    ("RU", "Россия", "Rossiya"),
+    ("SA", "السعودية", "As-Sa'udiyya"),
+    ("SE", "Sverige", "Sverige"),
+    ("SI", "Slovenija", "Slovenija"),
+    ("SJ", "Svalbard og Jan Mayen", "Svalbard og Jan Mayen"),
+    ("SK", "Slovensko", "Slovensko"),
+    ("SM", "San Marino", "San Marino"),
+    ("SN", "Sénégal", "Senegal"),
+    ("SV", "El Salvador", "El Salvador"),
+    ("SY", "سوريا", "Suriya"),
+    ("TH", "ประเทศไทย", "Prathet Thai"),
+    ("TJ", "Тоҷикистон", "Tojikiston"),
+    ("TN", "تونس", "Tunis"),
+    ("TR", "Türkiye", "Turkiye"),
+    ("TW", "臺灣", "Taiwan"),
+    ("TZ", "Tanzania", "Tanzania"),
+    ("UA", "Україна", "Ukraina"),
+    ("UY", "Uruguay", "Uruguay"),
+    ("UZ", "Oʻzbekiston", "O'zbekiston"),
+    ("VA", "Città del Vaticano", "Citta del Vaticano"),
+    ("VE", "Venezuela", "Venezuela"),
+    ("VN", "Việt Nam", "Viet Nam"),
+    ("WF", "Wallis-et-Futuna", "Wallis-et-Futuna"),
+    ("YE", "اليمن", "Al-Yaman"),
+    ("YT", "Mayotte", "Mayotte"),
+];
+
+/// Personal ID names: local name, English name and ASCII transliteration.
+pub(super) const PERSONAL_IDS: &[(&str, &str, &str, &str)] = &[
+    (
+        "AR",
+        "Código Único de Identificación Laboral",
+        "Unique labour identification code",
+        "Codigo Unico de Identificacion Laboral",
+    ),
+    (
+        "AT",
+        "Sozialversicherungsnummer",
+        "Social security number",
+        "Sozialversicherungsnummer",
+    ),
+    (
+        "AU",
+        "Tax File Number",
+        "Tax File Number",
+        "Tax File Number",
+    ),
+    (
+        "BA",
+        "Jedinstveni matični broj građana",
+        "Unique master citizen number",
+        "Jedinstveni maticni broj gradjana",
+    ),
+    (
+        "BE",
+        "Rijksregisternummer",
+        "National register number",
+        "Rijksregisternummer",
+    ),
+    (
+        "BG",
+        "Единен граждански номер",
+        "Unified civil number",
+        "Edinen grazhdanski nomer",
+    ),
+    (
+        "BR",
+        "Cadastro de Pessoas Físicas",
+        "Natural persons register",
+        "Cadastro de Pessoas Fisicas",
+    ),
+    (
+        "CA",
+        "Social Insurance Number",
+        "Social Insurance Number",
+        "Social Insurance Number",
+    ),
+    ("CH", "AHV-Nummer", "OASI number", "AHV-Nummer"),
+    (
+        "CL",
+        "Rol Único Tributario",
+        "Unique tax roll",
+        "Rol Unico Tributario",
+    ),
+    (
+        "CN",
+        "居民身份证号码",
+        "Resident identity card number",
+        "Jumin shenfenzheng haoma",
+    ),
+    (
+        "CO",
+        "Número de Identificación Tributaria",
+        "Tax identification number",
+        "Numero de Identificacion Tributaria",
+    ),
+    (
+        "CU",
+        "Número de identidad",
+        "Identity number",
+        "Numero de identidad",
+    ),
+    ("CZ", "Rodné číslo", "Birth number", "Rodne cislo"),
+    (
+        "DE",
+        "Steuerliche Identifikationsnummer",
+        "Tax identification number",
+        "Steuerliche Identifikationsnummer",
+    ),
+    ("DK", "CPR-nummer", "CPR number", "CPR-nummer"),
+    (
+        "DO",
+        "Cédula de identidad y electoral",
+        "Identity and electoral card number",
+        "Cedula de identidad y electoral",
+    ),
+    (
+        "DZ",
+        "رقم التعريف الجبائي",
+        "Tax identification number",
+        "Raqm al-ta'rif al-jiba'i",
+    ),
+    (
+        "EC",
+        "Cédula de identidad",
+        "Identity card number",
+        "Cedula de identidad",
+    ),
+    (
+        "EE",
+        "Isikukood",
+        "Personal identification code",
+        "Isikukood",
+    ),
+    (
+        "EG",
+        "الرقم القومي",
+        "National ID number",
+        "Al-raqm al-qawmi",
+    ),
+    (
+        "ES",
+        "Documento Nacional de Identidad",
+        "National identity document",
+        "Documento Nacional de Identidad",
+    ),
+    (
+        "FI",
+        "Henkilötunnus",
+        "Personal identity code",
+        "Henkilotunnus",
+    ),
+    (
+        "FR",
+        "Numéro d'inscription au répertoire",
+        "Social security number",
+        "Numero d'inscription au repertoire",
+    ),
+    (
+        "GB",
+        "National Insurance number",
+        "National Insurance number",
+        "National Insurance number",
+    ),
+    (
+        "GR",
+        "Αριθμός Μητρώου Κοινωνικής Ασφάλισης",
+        "Social security registration number",
+        "Arithmos Mitroou Koinonikis Asfalisis",
+    ),
+    (
+        "HK",
+        "香港身份證號碼",
+        "Hong Kong identity card number",
+        "Xianggang shenfenzheng haoma",
+    ),
+    (
+        "HR",
+        "Osobni identifikacijski broj",
+        "Personal identification number",
+        "Osobni identifikacijski broj",
+    ),
+    (
+        "ID",
+        "Nomor Induk Kependudukan",
+        "Population identification number",
+        "Nomor Induk Kependudukan",
+    ),
+    (
+        "IE",
+        "Personal Public Service Number",
+        "Personal Public Service Number",
+        "Personal Public Service Number",
+    ),
+    ("IL", "מספר זהות", "Identity number", "Mispar zehut"),
+    ("IN", "आधार", "Aadhaar number", "Aadhaar"),
+    ("IS", "Kennitala", "Identification number", "Kennitala"),
+    ("IT", "Codice fiscale", "Fiscal code", "Codice fiscale"),
+    ("JP", "個人番号", "Individual number", "Kojin bango"),
+    (
+        "KR",
+        "주민등록번호",
+        "Resident registration number",
+        "Jumin deungnok beonho",
+    ),
+    (
+        "KZ",
+        "Жеке сәйкестендіру нөмірі",
+        "Individual identification number",
+        "Zheke saikestendiru nomiri",
+    ),
+    ("LT", "Asmens kodas", "Personal code", "Asmens kodas"),
+    (
+        "LU",
+        "Matricule national",
+        "National identification number",
+        "Matricule national",
+    ),
+    ("LV", "Personas kods", "Personal code", "Personas kods"),
+    (
+        "ME",
+        "Jedinstveni matični broj građana",
+        "Unique master citizen number",
+        "Jedinstveni maticni broj gradjana",
+    ),
+    (
+        "MU",
+        "National Identity Card number",
+        "National Identity Card number",
+        "National Identity Card number",
+    ),
+    (
+        "MX",
+        "Clave Única de Registro de Población",
+        "Unique population registry code",
+        "Clave Unica de Registro de Poblacion",
+    ),
+    (
+        "MY",
+        "Nombor Kad Pengenalan",
+        "Identity card number",
+        "Nombor Kad Pengenalan",
+    ),
+    (
+        "NL",
+        "Burgerservicenummer",
+        "Citizen service number",
+        "Burgerservicenummer",
+    ),
+    (
+        "NO",
+        "Fødselsnummer",
+        "National identity number",
+        "Fodselsnummer",
+    ),
+    ("NZ", "IRD number", "IRD number", "IRD number"),
+    (
+        "PE",
+        "Documento Nacional de Identidad",
+        "National identity document",
+        "Documento Nacional de Identidad",
+    ),
+    (
+        "PK",
+        "قومی شناختی کارڈ",
+        "Computerised National Identity Card",
+        "Qaumi shanakhti card",
+    ),
+    ("PL", "Numer PESEL", "PESEL number", "Numer PESEL"),
+    (
+        "PT",
+        "Número de Identificação Fiscal",
+        "Tax identification number",
+        "Numero de Identificacao Fiscal",
+    ),
+    (
+        "RO",
+        "Cod numeric personal",
+        "Personal numeric code",
+        "Cod numeric personal",
+    ),
+    (
+        "RS",
+        "Јединствени матични број грађана",
+        "Unique master citizen number",
+        "Jedinstveni maticni broj gradjana",
+    ),
+    ("SA", "رقم الإقامة", "Iqama number", "Raqm al-iqama"),
+    (
+        "SE",
+        "Personnummer",
+        "Personal identity number",
+        "Personnummer",
+    ),
+    (
+        "SG",
+        "National Registration Identity Card number",
+        "National Registration Identity Card number",
+        "National Registration Identity Card number",
+    ),
+    (
+        "SI",
+        "Enotna matična številka občana",
+        "Unique master citizen number",
+        "Enotna maticna stevilka obcana",
+    ),
+    ("SK", "Rodné číslo", "Birth number", "Rodne cislo"),
+    (
+        "TH",
+        "เลขประจำตัวประชาชน",
+        "Citizen identification number",
+        "Lek pracham tua prachachon",
+    ),
+    (
+        "TR",
+        "T.C. Kimlik Numarası",
+        "Turkish identity number",
+        "T.C. Kimlik Numarasi",
+    ),
+    (
+        "TW",
+        "國民身分證統一編號",
+        "National identification number",
+        "Guomin shenfenzheng tongyi bianhao",
+    ),
+    (
+        "UA",
+        "Реєстраційний номер облікової картки платника податків",
+        "Taxpayer registration number",
+        "Reiestratsiinyi nomer oblikovoi kartky platnyka podatkiv",
+    ),
+    (
+        "US",
+        "Social Security number",
+        "Social Security number",
+        "Social Security number",
+    ),
+    (
+        "UY",
+        "Cédula de identidad",
+        "Identity card number",
+        "Cedula de identidad",
+    ),
+    (
+        "ZA",
+        "South African identity number",
+        "South African identity number",
+        "South African identity number",
+    ),
+];
+
+/// Company ID names: local name, English name and ASCII transliteration.
+pub(super) const COMPANY_IDS: &[(&str, &str, &str, &str)] = &[
+    (
+        "AD",
+        "Número de Registre Tributari",
+        "Tax register number",
+        "Numero de Registre Tributari",
+    ),
+    (
+        "AE",
+        "رقم التسجيل الضريبي",
+        "Tax registration number",
+        "Raqm al-tasjil al-daribi",
+    ),
+    (
+        "AL",
+        "Numri i Identifikimit për Personin e Tatueshëm",
+        "Taxable person identification number",
+        "Numri i Identifikimit per Personin e Tatueshem",
+    ),
+    (
+        "AR",
+        "Clave Única de Identificación Tributaria",
+        "Unique tax identification code",
+        "Clave Unica de Identificacion Tributaria",
+    ),
+    (
+        "AT",
+        "Umsatzsteuer-Identifikationsnummer",
+        "VAT identification number",
+        "Umsatzsteuer-Identifikationsnummer",
+    ),
+    (
+        "AU",
+        "Australian Business Number",
+        "Australian Business Number",
+        "Australian Business Number",
+    ),
+    (
+        "AZ",
+        "Vergi ödəyicisinin eyniləşdirmə nömrəsi",
+        "Taxpayer identification number",
+        "Vergi odeyicisinin eynilesdirme nomresi",
+    ),
+    (
+        "BE",
+        "Btw-identificatienummer",
+        "VAT identification number",
+        "Btw-identificatienummer",
+    ),
+    (
+        "BG",
+        "Идентификационен номер по ДДС",
+        "VAT identification number",
+        "Identifikatsionen nomer po DDS",
+    ),
+    (
+        "BR",
+        "Cadastro Nacional da Pessoa Jurídica",
+        "National register of legal entities",
+        "Cadastro Nacional da Pessoa Juridica",
+    ),
+    (
+        "BY",
+        "Учётный номер плательщика",
+        "Payer account number",
+        "Uchetny nomer platelshchika",
+    ),
+    (
+        "CA",
+        "Business Number",
+        "Business Number",
+        "Business Number",
+    ),
+    (
+        "CH",
+        "Unternehmens-Identifikationsnummer",
+        "Business identification number",
+        "Unternehmens-Identifikationsnummer",
+    ),
+    (
+        "CL",
+        "Rol Único Tributario",
+        "Unique tax roll",
+        "Rol Unico Tributario",
+    ),
+    (
+        "CN",
+        "统一社会信用代码",
+        "Unified social credit code",
+        "Tongyi shehui xinyong daima",
+    ),
+    (
+        "CO",
+        "Número de Identificación Tributaria",
+        "Tax identification number",
+        "Numero de Identificacion Tributaria",
+    ),
+    (
+        "CR",
+        "Cédula jurídica",
+        "Legal entity identification number",
+        "Cedula juridica",
+    ),
+    (
+        "CU",
+        "Número de identificación",
+        "Identification number",
+        "Numero de identificacion",
+    ),
+    (
+        "CY",
+        "Αριθμός Εγγραφής ΦΠΑ",
+        "VAT registration number",
+        "Arithmos Engrafis FPA",
+    ),
+    (
+        "CZ",
+        "Daňové identifikační číslo",
+        "Tax identification number",
+        "Danove identifikacni cislo",
+    ),
+    (
+        "DE",
+        "Umsatzsteuer-Identifikationsnummer",
+        "VAT identification number",
+        "Umsatzsteuer-Identifikationsnummer",
+    ),
+    (
+        "DK",
+        "CVR-nummer",
+        "Central business register number",
+        "CVR-nummer",
+    ),
+    (
+        "DO",
+        "Registro Nacional de Contribuyentes",
+        "National taxpayer register",
+        "Registro Nacional de Contribuyentes",
+    ),
+    (
+        "DZ",
+        "رقم التعريف الجبائي",
+        "Tax identification number",
+        "Raqm al-ta'rif al-jiba'i",
+    ),
+    (
+        "EC",
+        "Registro Único de Contribuyentes",
+        "Unique taxpayer register",
+        "Registro Unico de Contribuyentes",
+    ),
+    (
+        "EE",
+        "Käibemaksukohustuslase number",
+        "VAT number",
+        "Kaibemaksukohustuslase number",
+    ),
+    (
+        "EG",
+        "البطاقة الضريبية",
+        "Tax card number",
+        "Al-bitaqa al-daribiya",
+    ),
+    (
+        "ES",
+        "Código de Identificación Fiscal",
+        "Tax identification code",
+        "Codigo de Identificacion Fiscal",
+    ),
+    (
+        "FI",
+        "Arvonlisäverotunniste",
+        "VAT identification number",
+        "Arvonlisaverotunniste",
+    ),
+    ("FO", "V-tal", "VAT number", "V-tal"),
+    (
+        "FR",
+        "Numéro de TVA intracommunautaire",
+        "Intra-community VAT number",
+        "Numero de TVA intracommunautaire",
+    ),
+    (
+        "GB",
+        "VAT registration number",
+        "VAT registration number",
+        "VAT registration number",
+    ),
+    (
+        "GH",
+        "Taxpayer Identification Number",
+        "Taxpayer Identification Number",
+        "Taxpayer Identification Number",
+    ),
+    (
+        "GN",
+        "Numéro d'identification fiscale permanent",
+        "Permanent tax identification number",
+        "Numero d'identification fiscale permanent",
+    ),
+    (
+        "GR",
+        "Αριθμός Φορολογικού Μητρώου",
+        "Tax registration number",
+        "Arithmos Forologikou Mitroou",
+    ),
+    (
+        "GT",
+        "Número de Identificación Tributaria",
+        "Tax identification number",
+        "Numero de Identificacion Tributaria",
+    ),
+    (
+        "HK",
+        "商業登記號碼",
+        "Business registration number",
+        "Shangye dengji haoma",
+    ),
+    (
+        "HR",
+        "Osobni identifikacijski broj",
+        "Personal identification number",
+        "Osobni identifikacijski broj",
+    ),
+    (
+        "HU",
+        "Közösségi adószám",
+        "Community VAT number",
+        "Kozossegi adoszam",
+    ),
+    (
+        "ID",
+        "Nomor Pokok Wajib Pajak",
+        "Taxpayer identification number",
+        "Nomor Pokok Wajib Pajak",
+    ),
+    (
+        "IE",
+        "VAT registration number",
+        "VAT registration number",
+        "VAT registration number",
+    ),
+    ("IL", "מספר חברה", "Company number", "Mispar hevra"),
+    (
+        "IN",
+        "वस्तु एवं सेवा कर पहचान संख्या",
+        "GST identification number",
+        "Vastu evam seva kar pahchan sankhya",
+    ),
+    ("IT", "Partita IVA", "VAT number", "Partita IVA"),
+    ("JP", "法人番号", "Corporate number", "Hojin bango"),
+    (
+        "KE",
+        "KRA PIN",
+        "KRA personal identification number",
+        "KRA PIN",
+    ),
+    (
+        "KR",
+        "사업자등록번호",
+        "Business registration number",
+        "Saeopja deungnok beonho",
+    ),
+    (
+        "LT",
+        "PVM mokėtojo kodas",
+        "VAT payer code",
+        "PVM moketojo kodas",
+    ),
+    (
+        "LU",
+        "Numéro d'identification à la TVA",
+        "VAT identification number",
+        "Numero d'identification a la TVA",
+    ),
+    (
+        "LV",
+        "PVN reģistrācijas numurs",
+        "VAT registration number",
+        "PVN registracijas numurs",
+    ),
+    (
+        "MA",
+        "المعرف الموحد للمقاولة",
+        "Common company identifier",
+        "Al-mu'arrif al-muwahhad lil-muqawala",
+    ),
+    ("MC", "Numéro de TVA", "VAT number", "Numero de TVA"),
+    (
+        "MD",
+        "Numărul de identificare de stat",
+        "State identification number",
+        "Numarul de identificare de stat",
+    ),
+    (
+        "ME",
+        "Poreski identifikacioni broj",
+        "Tax identification number",
+        "Poreski identifikacioni broj",
+    ),
+    (
+        "MK",
+        "Единствен даночен број",
+        "Unique tax number",
+        "Edinstven danochen broj",
+    ),
+    ("MT", "Numru tal-VAT", "VAT number", "Numru tal-VAT"),
+    (
+        "MX",
+        "Registro Federal de Contribuyentes",
+        "Federal taxpayer registry",
+        "Registro Federal de Contribuyentes",
+    ),
+    (
+        "MY",
+        "Nombor Pendaftaran Perniagaan",
+        "Business registration number",
+        "Nombor Pendaftaran Perniagaan",
+    ),
+    (
+        "MZ",
+        "Número Único de Identificação Tributária",
+        "Unique tax identification number",
+        "Numero Unico de Identificacao Tributaria",
+    ),
+    (
+        "NG",
+        "Taxpayer Identification Number",
+        "Taxpayer Identification Number",
+        "Taxpayer Identification Number",
+    ),
+    (
+        "NL",
+        "Btw-identificatienummer",
+        "VAT identification number",
+        "Btw-identificatienummer",
+    ),
+    (
+        "NO",
+        "Merverdiavgiftsnummer",
+        "VAT number",
+        "Merverdiavgiftsnummer",
+    ),
+    ("NZ", "IRD number", "IRD number", "IRD number"),
+    (
+        "PE",
+        "Registro Único de Contribuyentes",
+        "Unique taxpayer register",
+        "Registro Unico de Contribuyentes",
+    ),
+    (
+        "PH",
+        "Taxpayer Identification Number",
+        "Taxpayer Identification Number",
+        "Taxpayer Identification Number",
+    ),
+    (
+        "PL",
+        "Numer identyfikacji podatkowej",
+        "Tax identification number",
+        "Numer identyfikacji podatkowej",
+    ),
+    (
+        "PT",
+        "Número de Identificação Fiscal",
+        "Tax identification number",
+        "Numero de Identificacao Fiscal",
+    ),
+    (
+        "PY",
+        "Registro Único de Contribuyentes",
+        "Unique taxpayer register",
+        "Registro Unico de Contribuyentes",
+    ),
+    (
+        "RO",
+        "Codul unic de înregistrare",
+        "Unique registration code",
+        "Codul unic de inregistrare",
+    ),
+    (
+        "RS",
+        "Порески идентификациони број",
+        "Tax identification number",
+        "Poreski identifikacioni broj",
+    ),
+    (
+        "RU",
+        "Идентификационный номер налогоплательщика",
+        "Taxpayer identification number",
+        "Identifikatsionny nomer nalogoplatelshchika",
+    ),
+    (
+        "SA",
+        "رقم تسجيل ضريبة القيمة المضافة",
+        "VAT registration number",
+        "Raqm tasjil daribat al-qima al-mudafa",
+    ),
+    (
+        "SE",
+        "Momsregistreringsnummer",
+        "VAT registration number",
+        "Momsregistreringsnummer",
+    ),
+    (
+        "SG",
+        "Unique Entity Number",
+        "Unique Entity Number",
+        "Unique Entity Number",
+    ),
+    (
+        "SI",
+        "Identifikacijska številka za DDV",
+        "VAT identification number",
+        "Identifikacijska stevilka za DDV",
+    ),
+    (
+        "SK",
+        "Identifikačné číslo pre daň z pridanej hodnoty",
+        "VAT identification number",
+        "Identifikacne cislo pre dan z pridanej hodnoty",
+    ),
+    (
+        "SM",
+        "Codice operatore economico",
+        "Economic operator code",
+        "Codice operatore economico",
+    ),
+    (
+        "SN",
+        "Numéro d'identification national des entreprises et associations",
+        "National business identification number",
+        "Numero d'identification national des entreprises et associations",
+    ),
+    (
+        "SV",
+        "Número de Identificación Tributaria",
+        "Tax identification number",
+        "Numero de Identificacion Tributaria",
+    ),
+    (
+        "TH",
+        "เลขประจำตัวผู้เสียภาษีอากร",
+        "Taxpayer identification number",
+        "Lek pracham tua phu sia phasi akon",
+    ),
+    (
+        "TN",
+        "المعرف الجبائي",
+        "Tax identification number",
+        "Al-mu'arrif al-jiba'i",
+    ),
+    (
+        "TR",
+        "Vergi Kimlik Numarası",
+        "Tax identification number",
+        "Vergi Kimlik Numarasi",
+    ),
+    (
+        "TW",
+        "統一編號",
+        "Unified business number",
+        "Tongyi bianhao",
+    ),
+    ("UA", "Код ЄДРПОУ", "EDRPOU code", "Kod YeDRPOU"),
+    (
+        "US",
+        "Employer Identification Number",
+        "Employer Identification Number",
+        "Employer Identification Number",
+    ),
+    (
+        "UY",
+        "Registro Único Tributario",
+        "Unique tax register",
+        "Registro Unico Tributario",
+    ),
+    (
+        "VE",
+        "Registro de Información Fiscal",
+        "Tax information register",
+        "Registro de Informacion Fiscal",
+    ),
+    ("VN", "Mã số thuế", "Tax code", "Ma so thue"),
+    (
+        "ZA",
+        "VAT registration number",
+        "VAT registration number",
+        "VAT registration number",
+    ),
+];
+
+/// Driver's license names: local name, English name and ASCII transliteration.
+pub(super) const DRIVER_LICENSES: &[(&str, &str, &str, &str)] = &[
+    ("AE", "رخصة قيادة", "Driving Licence", "Rukhsat qiyada"),
+    (
+        "AR",
+        "Licencia de conducir",
+        "Driving Licence",
+        "Licencia de conducir",
+    ),
+    ("AT", "Führerschein", "Driving Licence", "Fuhrerschein"),
+    ("AU", "Driver Licence", "Driver Licence", "Driver Licence"),
+    (
+        "BD",
+        "ড্রাইভিং লাইসেন্স",
+        "Driving Licence",
+        "Draibhing laisens",
+    ),
+    ("BE", "Rijbewijs", "Driving Licence", "Rijbewijs"),
+    (
+        "BG",
+        "Свидетелство за управление на МПС",
+        "Driving Licence",
+        "Svidetelstvo za upravlenie na MPS",
+    ),
+    ("BH", "رخصة قيادة", "Driving Licence", "Rukhsat qiyada"),
+    (
+        "BR",
+        "Carteira Nacional de Habilitação",
+        "National Driver's Licence",
+        "Carteira Nacional de Habilitacao",
+    ),
+    (
+        "CA",
+        "Driver's Licence",
+        "Driver's Licence",
+        "Driver's Licence",
+    ),
+    ("CH", "Führerausweis", "Driving Licence", "Fuhrerausweis"),
+    (
+        "CL",
+        "Licencia de conductor",
+        "Driving Licence",
+        "Licencia de conductor",
+    ),
+    ("CN", "驾驶证", "Driving Licence", "Jiashizheng"),
+    (
+        "CO",
+        "Licencia de conducción",
+        "Driving Licence",
+        "Licencia de conduccion",
+    ),
+    (
+        "CZ",
+        "Řidičský průkaz",
+        "Driving Licence",
+        "Ridicsky prukaz",
+    ),
+    ("DE", "Führerschein", "Driving Licence", "Fuhrerschein"),
+    ("DK", "Kørekort", "Driving Licence", "Korekort"),
+    ("DZ", "رخصة السياقة", "Driving Licence", "Rukhsat as-siyaqa"),
+    (
+        "EC",
+        "Licencia de conducir",
+        "Driving Licence",
+        "Licencia de conducir",
+    ),
+    ("EE", "Juhiluba", "Driving Licence", "Juhiluba"),
+    ("EG", "رخصة قيادة", "Driving Licence", "Rukhsat qiyada"),
+    (
+        "ES",
+        "Permiso de conducción",
+        "Driving Licence",
+        "Permiso de conduccion",
+    ),
+    ("ET", "መንጃ ፈቃድ", "Driving Licence", "Menja fekad"),
+    ("FI", "Ajokortti", "Driving Licence", "Ajokortti"),
+    (
+        "FR",
+        "Permis de conduire",
+        "Driving Licence",
+        "Permis de conduire",
+    ),
+    (
+        "GB",
+        "Driving Licence",
+        "Driving Licence",
+        "Driving Licence",
+    ),
+    (
+        "GH",
+        "Driver's Licence",
+        "Driver's Licence",
+        "Driver's Licence",
+    ),
+    ("GR", "Άδεια οδήγησης", "Driving Licence", "Adeia odigisis"),
+    ("HK", "駕駛執照", "Driving Licence", "Jiashi zhizhao"),
+    (
+        "HR",
+        "Vozačka dozvola",
+        "Driving Licence",
+        "Vozacka dozvola",
+    ),
+    (
+        "HU",
+        "Vezetői engedély",
+        "Driving Licence",
+        "Vezetoi engedely",
+    ),
+    (
+        "ID",
+        "Surat Izin Mengemudi",
+        "Driving Licence",
+        "Surat Izin Mengemudi",
+    ),
+    (
+        "IE",
+        "Ceadúnas tiomána",
+        "Driving Licence",
+        "Ceadunas tiomana",
+    ),
+    ("IL", "רישיון נהיגה", "Driving Licence", "Rishyon nehiga"),
+    ("IN", "ड्राइविंग लाइसेंस", "Driving Licence", "Draiving laisens"),
+    ("IS", "Ökuskírteini", "Driving Licence", "Okuskirteini"),
+    (
+        "IT",
+        "Patente di guida",
+        "Driving Licence",
+        "Patente di guida",
+    ),
+    ("JP", "運転免許証", "Driver's License", "Unten menkyosho"),
+    (
+        "KE",
+        "Driving Licence",
+        "Driving Licence",
+        "Driving Licence",
+    ),
+    (
+        "KR",
+        "운전면허증",
+        "Driving Licence",
+        "Unjeon myeonheojeung",
+    ),
+    ("KW", "رخصة قيادة", "Driving Licence", "Rukhsat qiyada"),
+    (
+        "LK",
+        "රියදුරු බලපත්‍රය",
+        "Driving Licence",
+        "Riyaduru balapatraya",
+    ),
+    (
+        "LT",
+        "Vairuotojo pažymėjimas",
+        "Driving Licence",
+        "Vairuotojo pazymejimas",
+    ),
+    (
+        "LU",
+        "Permis de conduire",
+        "Driving Licence",
+        "Permis de conduire",
+    ),
+    (
+        "LV",
+        "Vadītāja apliecība",
+        "Driving Licence",
+        "Vaditaja aplieciba",
+    ),
+    ("MA", "رخصة السياقة", "Driving Licence", "Rukhsat as-siyaqa"),
+    (
+        "MT",
+        "Liċenzja tas-sewqan",
+        "Driving Licence",
+        "Licenzja tas-sewqan",
+    ),
+    (
+        "MX",
+        "Licencia de conducir",
+        "Driving Licence",
+        "Licencia de conducir",
+    ),
+    ("MY", "Lesen Memandu", "Driving Licence", "Lesen Memandu"),
+    (
+        "NG",
+        "Driver's Licence",
+        "Driver's Licence",
+        "Driver's Licence",
+    ),
+    ("NL", "Rijbewijs", "Driving Licence", "Rijbewijs"),
+    ("NO", "Førerkort", "Driving Licence", "Forerkort"),
+    (
+        "NP",
+        "सवारी चालक अनुमतिपत्र",
+        "Driving Licence",
+        "Sawari chalak anumatipatra",
+    ),
+    ("NZ", "Driver Licence", "Driver Licence", "Driver Licence"),
+    ("OM", "رخصة قيادة", "Driving Licence", "Rukhsat qiyada"),
+    (
+        "PE",
+        "Licencia de conducir",
+        "Driving Licence",
+        "Licencia de conducir",
+    ),
+    (
+        "PH",
+        "Lisensya sa pagmamaneho",
+        "Driver's License",
+        "Lisensya sa pagmamaneho",
+    ),
+    (
+        "PK",
+        "ڈرائیونگ لائسنس",
+        "Driving Licence",
+        "Draiving laisans",
+    ),
+    ("PL", "Prawo jazdy", "Driving Licence", "Prawo jazdy"),
+    (
+        "PT",
+        "Carta de condução",
+        "Driving Licence",
+        "Carta de conducao",
+    ),
+    ("QA", "رخصة قيادة", "Driving Licence", "Rukhsat qiyada"),
+    (
+        "RO",
+        "Permis de conducere",
+        "Driving Licence",
+        "Permis de conducere",
+    ),
+    (
+        "RS",
+        "Возачка дозвола",
+        "Driving Licence",
+        "Vozacka dozvola",
+    ),
+    ("SA", "رخصة قيادة", "Driving Licence", "Rukhsat qiyada"),
+    ("SE", "Körkort", "Driving Licence", "Korkort"),
+    (
+        "SG",
+        "Driving Licence",
+        "Driving Licence",
+        "Driving Licence",
+    ),
+    (
+        "SI",
+        "Vozniško dovoljenje",
+        "Driving Licence",
+        "Voznisko dovoljenje",
+    ),
+    (
+        "SK",
+        "Vodičský preukaz",
+        "Driving Licence",
+        "Vodicsky preukaz",
+    ),
+    ("TH", "ใบขับขี่", "Driving Licence", "Bai khap khi"),
+    ("TN", "رخصة السياقة", "Driving Licence", "Rukhsat as-siyaqa"),
+    ("TR", "Sürücü belgesi", "Driving Licence", "Surucu belgesi"),
+    ("TW", "駕駛執照", "Driving Licence", "Jiashi zhizhao"),
+    (
+        "TZ",
+        "Leseni ya udereva",
+        "Driving Licence",
+        "Leseni ya udereva",
+    ),
+    (
+        "UA",
+        "Посвідчення водія",
+        "Driving Licence",
+        "Posvidchennia vodiia",
+    ),
+    (
+        "US",
+        "Driver's License",
+        "Driver's License",
+        "Driver's License",
+    ),
+    (
+        "UY",
+        "Libreta de conducir",
+        "Driving Licence",
+        "Libreta de conducir",
+    ),
+    (
+        "VE",
+        "Licencia de conducir",
+        "Driving Licence",
+        "Licencia de conducir",
+    ),
+    (
+        "VN",
+        "Giấy phép lái xe",
+        "Driving Licence",
+        "Giay phep lai xe",
+    ),
+    (
+        "ZA",
+        "Driver's Licence",
+        "Driver's Licence",
+        "Driver's Licence",
+    ),
+];
+
+/// Tax ID names: local name, English name and ASCII transliteration.
+pub(super) const TAX_IDS: &[(&str, &str, &str, &str)] = &[
+    (
+        "AE",
+        "رقم التسجيل الضريبي",
+        "Tax registration number",
+        "Raqm al-tasjil al-daribi",
+    ),
+    (
+        "AR",
+        "Código Único de Identificación Laboral",
+        "Unique labour identification code",
+        "Codigo Unico de Identificacion Laboral",
+    ),
+    ("AT", "Steuernummer", "Tax number", "Steuernummer"),
+    (
+        "AU",
+        "Tax File Number",
+        "Tax File Number",
+        "Tax File Number",
+    ),
+    (
+        "BD",
+        "করদাতা শনাক্তকরণ নম্বর",
+        "Taxpayer identification number",
+        "Kordata shonaktokoron nombor",
+    ),
+    (
+        "BE",
+        "Rijksregisternummer",
+        "National register number",
+        "Rijksregisternummer",
+    ),
+    (
+        "BG",
+        "Единен граждански номер",
+        "Unified civil number",
+        "Edinen grazhdanski nomer",
+    ),
+    (
+        "BH",
+        "الرقم الشخصي",
+        "Personal number",
+        "Al-raqm al-shakhsi",
+    ),
+    (
+        "BR",
+        "Cadastro de Pessoas Físicas",
+        "Natural persons register",
+        "Cadastro de Pessoas Fisicas",
+    ),
+    (
+        "CA",
+        "Social Insurance Number",
+        "Social Insurance Number",
+        "Social Insurance Number",
+    ),
+    ("CH", "AHV-Nummer", "OASI number", "AHV-Nummer"),
+    (
+        "CL",
+        "Rol Único Tributario",
+        "Unique tax roll",
+        "Rol Unico Tributario",
+    ),
+    (
+        "CN",
+        "统一社会信用代码",
+        "Unified social credit code",
+        "Tongyi shehui xinyong daima",
+    ),
+    (
+        "CO",
+        "Número de Identificación Tributaria",
+        "Tax identification number",
+        "Numero de Identificacion Tributaria",
+    ),
+    ("CZ", "Rodné číslo", "Birth number", "Rodne cislo"),
+    (
+        "DE",
+        "Steuerliche Identifikationsnummer",
+        "Tax identification number",
+        "Steuerliche Identifikationsnummer",
+    ),
+    ("DK", "CPR-nummer", "CPR number", "CPR-nummer"),
+    (
+        "DZ",
+        "رقم التعريف الجبائي",
+        "Tax identification number",
+        "Raqm al-ta'rif al-jiba'i",
+    ),
+    (
+        "EC",
+        "Registro Único de Contribuyentes",
+        "Unique taxpayer register",
+        "Registro Unico de Contribuyentes",
+    ),
+    (
+        "EE",
+        "Isikukood",
+        "Personal identification code",
+        "Isikukood",
+    ),
+    (
+        "EG",
+        "الرقم القومي",
+        "National ID number",
+        "Al-raqm al-qawmi",
+    ),
+    (
+        "ES",
+        "Número de Identificación Fiscal",
+        "Tax identification number",
+        "Numero de Identificacion Fiscal",
+    ),
+    (
+        "ET",
+        "የግብር ከፋይ መለያ ቁጥር",
+        "Taxpayer identification number",
+        "Yegibir kefay meleya kutir",
+    ),
+    (
+        "FI",
+        "Henkilötunnus",
+        "Personal identity code",
+        "Henkilotunnus",
+    ),
+    (
+        "FR",
+        "Numéro d'identification fiscale",
+        "Tax identification number",
+        "Numero d'identification fiscale",
+    ),
+    (
+        "GB",
+        "Unique Taxpayer Reference",
+        "Unique Taxpayer Reference",
+        "Unique Taxpayer Reference",
+    ),
+    (
+        "GH",
+        "Taxpayer Identification Number",
+        "Taxpayer Identification Number",
+        "Taxpayer Identification Number",
+    ),
+    (
+        "GR",
+        "Αριθμός Φορολογικού Μητρώου",
+        "Tax registration number",
+        "Arithmos Forologikou Mitroou",
+    ),
+    (
+        "HK",
+        "香港身份證號碼",
+        "Hong Kong identity card number",
+        "Xianggang shenfenzheng haoma",
+    ),
+    (
+        "HR",
+        "Osobni identifikacijski broj",
+        "Personal identification number",
+        "Osobni identifikacijski broj",
+    ),
+    (
+        "HU",
+        "Adóazonosító jel",
+        "Tax identification number",
+        "Adoazonosito jel",
+    ),
+    (
+        "ID",
+        "Nomor Pokok Wajib Pajak",
+        "Taxpayer identification number",
+        "Nomor Pokok Wajib Pajak",
+    ),
+    (
+        "IE",
+        "Personal Public Service Number",
+        "Personal Public Service Number",
+        "Personal Public Service Number",
+    ),
+    ("IL", "מספר זהות", "Identity number", "Mispar zehut"),
+    (
+        "IN",
+        "स्थायी खाता संख्या",
+        "Permanent account number",
+        "Sthayi khata sankhya",
+    ),
+    ("IS", "Kennitala", "Identification number", "Kennitala"),
+    ("IT", "Partita IVA", "VAT number", "Partita IVA"),
+    ("JP", "個人番号", "Individual number", "Kojin bango"),
+    (
+        "KE",
+        "KRA PIN",
+        "KRA personal identification number",
+        "KRA PIN",
+    ),
+    (
+        "KR",
+        "사업자등록번호",
+        "Business registration number",
+        "Saeopja deungnok beonho",
+    ),
+    ("KW", "الرقم المدني", "Civil ID number", "Al-raqm al-madani"),
+    (
+        "LK",
+        "ජාතික හැඳුනුම්පත් අංකය",
+        "National identity card number",
+        "Jathika handunumpath ankaya",
+    ),
+    ("LT", "Asmens kodas", "Personal code", "Asmens kodas"),
+    (
+        "LU",
+        "Matricule national",
+        "National identification number",
+        "Matricule national",
+    ),
+    ("LV", "Personas kods", "Personal code", "Personas kods"),
+    (
+        "MA",
+        "البطاقة الوطنية للتعريف",
+        "National identity card number",
+        "Al-bitaqa al-wataniya lil-ta'rif",
+    ),
+    (
+        "MT",
+        "Numru tal-Identifikazzjoni tat-Taxxa",
+        "Tax identification number",
+        "Numru tal-Identifikazzjoni tat-Taxxa",
+    ),
+    (
+        "MX",
+        "Registro Federal de Contribuyentes",
+        "Federal taxpayer registry",
+        "Registro Federal de Contribuyentes",
+    ),
+    (
+        "MY",
+        "Nombor Kad Pengenalan",
+        "Identity card number",
+        "Nombor Kad Pengenalan",
+    ),
+    (
+        "NG",
+        "Taxpayer Identification Number",
+        "Taxpayer Identification Number",
+        "Taxpayer Identification Number",
+    ),
+    (
+        "NL",
+        "Burgerservicenummer",
+        "Citizen service number",
+        "Burgerservicenummer",
+    ),
+    (
+        "NO",
+        "Fødselsnummer",
+        "National identity number",
+        "Fodselsnummer",
+    ),
+    (
+        "NP",
+        "स्थायी लेखा नम्बर",
+        "Permanent account number",
+        "Sthayi lekha nambar",
+    ),
+    ("NZ", "IRD number", "IRD number", "IRD number"),
+    (
+        "OM",
+        "الرقم الضريبي",
+        "Tax identification number",
+        "Al-raqm al-daribi",
+    ),
+    (
+        "PE",
+        "Registro Único de Contribuyentes",
+        "Unique taxpayer register",
+        "Registro Unico de Contribuyentes",
+    ),
+    (
+        "PH",
+        "Taxpayer Identification Number",
+        "Taxpayer Identification Number",
+        "Taxpayer Identification Number",
+    ),
+    (
+        "PK",
+        "قومی ٹیکس نمبر",
+        "National tax number",
+        "Qaumi tax nambar",
+    ),
+    ("PL", "Numer PESEL", "PESEL number", "Numer PESEL"),
+    (
+        "PT",
+        "Número de Identificação Fiscal",
+        "Tax identification number",
+        "Numero de Identificacao Fiscal",
+    ),
+    (
+        "QA",
+        "رقم البطاقة الشخصية",
+        "Qatar ID number",
+        "Raqm al-bitaqa al-shakhsiya",
+    ),
+    (
+        "RO",
+        "Cod numeric personal",
+        "Personal numeric code",
+        "Cod numeric personal",
+    ),
+    (
+        "RS",
+        "Јединствени матични број грађана",
+        "Unique master citizen number",
+        "Jedinstveni maticni broj gradjana",
+    ),
+    (
+        "RU",
+        "Идентификационный номер налогоплательщика",
+        "Taxpayer identification number",
+        "Identifikatsionny nomer nalogoplatelshchika",
+    ),
+    (
+        "SA",
+        "رقم الهوية الوطنية",
+        "National ID number",
+        "Raqm al-hawiya al-wataniya",
+    ),
+    (
+        "SE",
+        "Personnummer",
+        "Personal identity number",
+        "Personnummer",
+    ),
+    (
+        "SG",
+        "Tax reference number",
+        "Tax reference number",
+        "Tax reference number",
+    ),
+    ("SI", "Davčna številka", "Tax number", "Davcna stevilka"),
+    ("SK", "Rodné číslo", "Birth number", "Rodne cislo"),
+    (
+        "TH",
+        "เลขประจำตัวผู้เสียภาษีอากร",
+        "Taxpayer identification number",
+        "Lek pracham tua phu sia phasi akon",
+    ),
+    (
+        "TN",
+        "بطاقة التعريف الوطنية",
+        "National identity card number",
+        "Bitaqat al-ta'rif al-wataniya",
+    ),
+    (
+        "TR",
+        "T.C. Kimlik Numarası",
+        "Turkish identity number",
+        "T.C. Kimlik Numarasi",
+    ),
+    (
+        "TW",
+        "國民身分證統一編號",
+        "National identification number",
+        "Guomin shenfenzheng tongyi bianhao",
+    ),
+    (
+        "TZ",
+        "Namba ya Utambulisho wa Mlipakodi",
+        "Taxpayer identification number",
+        "Namba ya Utambulisho wa Mlipakodi",
+    ),
+    (
+        "UA",
+        "Реєстраційний номер облікової картки платника податків",
+        "Taxpayer registration number",
+        "Reiestratsiinyi nomer oblikovoi kartky platnyka podatkiv",
+    ),
+    (
+        "US",
+        "Taxpayer Identification Number",
+        "Taxpayer Identification Number",
+        "Taxpayer Identification Number",
+    ),
+    (
+        "UY",
+        "Registro Único Tributario",
+        "Unique tax register",
+        "Registro Unico Tributario",
+    ),
+    (
+        "VE",
+        "Registro de Información Fiscal",
+        "Tax information register",
+        "Registro de Informacion Fiscal",
+    ),
+    ("VN", "Mã số thuế", "Tax code", "Ma so thue"),
+    (
+        "ZA",
+        "Income tax reference number",
+        "Income tax reference number",
+        "Income tax reference number",
+    ),
+];
+
+/// Passport names: local name, English name and ASCII transliteration.
+pub(super) const PASSPORTS: &[(&str, &str, &str, &str)] = &[
+    ("AE", "جواز سفر", "Passport", "Jawaz safar"),
+    ("AR", "Pasaporte", "Passport", "Pasaporte"),
+    ("AT", "Reisepass", "Passport", "Reisepass"),
+    ("AU", "Passport", "Passport", "Passport"),
+    ("BD", "পাসপোর্ট", "Passport", "Pasport"),
+    ("BE", "Paspoort", "Passport", "Paspoort"),
+    ("BG", "Паспорт", "Passport", "Pasport"),
+    ("BH", "جواز سفر", "Passport", "Jawaz safar"),
+    ("BR", "Passaporte", "Passport", "Passaporte"),
+    ("CA", "Passport", "Passport", "Passport"),
+    ("CH", "Reisepass", "Passport", "Reisepass"),
+    ("CL", "Pasaporte", "Passport", "Pasaporte"),
+    ("CN", "护照", "Passport", "Huzhao"),
+    ("CO", "Pasaporte", "Passport", "Pasaporte"),
+    ("CZ", "Cestovní pas", "Passport", "Cestovni pas"),
+    ("DE", "Reisepass", "Passport", "Reisepass"),
+    ("DK", "Pas", "Passport", "Pas"),
+    ("DZ", "جواز سفر", "Passport", "Jawaz safar"),
+    ("EC", "Pasaporte", "Passport", "Pasaporte"),
+    ("EE", "Pass", "Passport", "Pass"),
+    ("EG", "جواز سفر", "Passport", "Jawaz safar"),
+    ("ES", "Pasaporte", "Passport", "Pasaporte"),
+    ("ET", "ፓስፖርት", "Passport", "Pasport"),
+    ("FI", "Passi", "Passport", "Passi"),
+    ("FR", "Passeport", "Passport", "Passeport"),
+    ("GB", "Passport", "Passport", "Passport"),
+    ("GH", "Passport", "Passport", "Passport"),
+    ("GR", "Διαβατήριο", "Passport", "Diavatirio"),
+    ("HK", "護照", "Passport", "Huzhao"),
+    ("HR", "Putovnica", "Passport", "Putovnica"),
+    ("HU", "Útlevél", "Passport", "Utlevel"),
+    ("ID", "Paspor", "Passport", "Paspor"),
+    ("IE", "Pas", "Passport", "Pas"),
+    ("IL", "דרכון", "Passport", "Darkon"),
+    ("IN", "पासपोर्ट", "Passport", "Pasport"),
+    ("IS", "Vegabréf", "Passport", "Vegabref"),
+    ("IT", "Passaporto", "Passport", "Passaporto"),
+    ("JP", "旅券", "Passport", "Ryoken"),
+    ("KE", "Passport", "Passport", "Passport"),
+    ("KR", "여권", "Passport", "Yeogwon"),
+    ("KW", "جواز سفر", "Passport", "Jawaz safar"),
+    ("LK", "ගමන් බලපත්‍රය", "Passport", "Gaman balapatraya"),
+    ("LT", "Pasas", "Passport", "Pasas"),
+    ("LU", "Passeport", "Passport", "Passeport"),
+    ("LV", "Pase", "Passport", "Pase"),
+    ("MA", "جواز سفر", "Passport", "Jawaz safar"),
+    ("MT", "Passaport", "Passport", "Passaport"),
+    ("MX", "Pasaporte", "Passport", "Pasaporte"),
+    ("MY", "Pasport", "Passport", "Pasport"),
+    ("NG", "Passport", "Passport", "Passport"),
+    ("NL", "Paspoort", "Passport", "Paspoort"),
+    ("NO", "Pass", "Passport", "Pass"),
+    ("NP", "राहदानी", "Passport", "Rahadani"),
+    ("NZ", "Passport", "Passport", "Passport"),
+    ("OM", "جواز سفر", "Passport", "Jawaz safar"),
+    ("PE", "Pasaporte", "Passport", "Pasaporte"),
+    ("PH", "Pasaporte", "Passport", "Pasaporte"),
+    ("PK", "پاسپورٹ", "Passport", "Pasport"),
+    ("PL", "Paszport", "Passport", "Paszport"),
+    ("PT", "Passaporte", "Passport", "Passaporte"),
+    ("QA", "جواز سفر", "Passport", "Jawaz safar"),
+    ("RO", "Pașaport", "Passport", "Pasaport"),
+    ("RS", "Пасош", "Passport", "Pasos"),
+    ("SA", "جواز سفر", "Passport", "Jawaz safar"),
+    ("SE", "Pass", "Passport", "Pass"),
+    ("SG", "Passport", "Passport", "Passport"),
+    ("SI", "Potni list", "Passport", "Potni list"),
+    ("SK", "Cestovný pas", "Passport", "Cestovny pas"),
+    ("TH", "หนังสือเดินทาง", "Passport", "Nangsue doen thang"),
+    ("TN", "جواز سفر", "Passport", "Jawaz safar"),
+    ("TR", "Pasaport", "Passport", "Pasaport"),
+    ("TW", "護照", "Passport", "Huzhao"),
+    ("TZ", "Pasipoti", "Passport", "Pasipoti"),
+    ("UA", "Паспорт", "Passport", "Pasport"),
+    ("US", "Passport", "Passport", "Passport"),
+    ("UY", "Pasaporte", "Passport", "Pasaporte"),
+    ("VE", "Pasaporte", "Passport", "Pasaporte"),
+    ("VN", "Hộ chiếu", "Passport", "Ho chieu"),
+    ("ZA", "Passport", "Passport", "Passport"),
+];
@@ -1,11 +1,12 @@
 use std::io::Write;
 
-use clap::{Parser, Subcommand};
+use clap::{Args, Parser, Subcommand};
 use rand::{thread_rng, Rng};
 
 use idsmith::{
-    bank_account, company_id, credit_card, csv as csv_fmt, driver_license, iban, lei, passport,
-    personal_id, swift, tax_id, vat,
+    anonymize, bank_account, company_id, countries::subdivisions, coverage, credit_card,
+    csv as csv_fmt, dataset, describe, driver_license, explain, iban, kind, lei, locale, output,
+    passport, personal_id, profile, scan, serve, spec, sql, swift, tax_id, vat, weights,
 };
 
 #[derive(Parser)]
@@ -14,6 +15,29 @@ use idsmith::{
     about = "Validate and generate checksum-correct IBANs, personal IDs, bank accounts, and more"
 )]
 struct Cli {
+    /// Load identifier definitions from a TOML or JSON spec file (repeatable)
+    #[arg(long = "spec", global = true, value_name = "FILE")]
+    specs: Vec<String>,
+    /// Do not fall back to a parent country's rules for territories (e.g. US for PR)
+    #[arg(long, global = true)]
+    strict: bool,
+    /// Language of country and document names in --list output (local, english, latin)
+    #[arg(long, global = true, value_name = "LOCALE")]
+    locale: Option<locale::Locale>,
+    /// SQL dialect for --sql output (postgres, mysql, sqlite)
+    #[arg(
+        long,
+        global = true,
+        value_name = "DIALECT",
+        default_value = "postgres"
+    )]
+    dialect: sql::Dialect,
+    /// Write --sql output as a COPY ... FROM STDIN block (PostgreSQL)
+    #[arg(long, global = true)]
+    copy: bool,
+    /// Start --sql output with CREATE TABLE IF NOT EXISTS
+    #[arg(long, global = true)]
+    create_table: bool,
     #[command(subcommand)]
     command: Commands,
 }
@@ -22,18 +46,16 @@ struct Cli {
 enum Commands {
     /// Generate random IBANs
     Iban {
-        /// Country code (e.g., EE, DE). Random if omitted.
+        /// Country code (e.g., EE, DE) or group (sepa, eu, eea, eurozone). Random if omitted.
         country: Option<String>,
         /// Number of IBANs to generate
         #[arg(default_value = "1")]
         count: u32,
-        /// Export as CSV (optionally to a file path)
-        #[arg(long, num_args = 0..=1, default_missing_value = "-")]
-        csv: Option<String>,
-        /// Export as JSON (optionally to a file path)
-        #[cfg(feature = "json")]
-        #[arg(long, num_args = 0..=1, default_missing_value = "-")]
-        json: Option<String>,
+        /// Weighted country mix, e.g. DE:50,FR:30,@sepa:20
+        #[arg(long, value_name = "SPEC")]
+        countries: Option<String>,
+        #[command(flatten)]
+        output: OutputArgs,
     },
     /// Generate random bank account numbers
     Account {
@@ -43,16 +65,14 @@ enum Commands {
         /// Country code (e.g., US, AU, GB). Random if omitted.
         #[arg(long)]
         country: Option<String>,
+        /// Weighted country mix, e.g. DE:50,FR:30,@sepa:20
+        #[arg(long, value_name = "SPEC", conflicts_with = "country")]
+        countries: Option<String>,
         /// List all supported countries
         #[arg(long)]
         list: bool,
-        /// Export as CSV (optionally to a file path)
-        #[arg(long, num_args = 0..=1, default_missing_value = "-")]
-        csv: Option<String>,
-        /// Export as JSON (optionally to a file path)
-        #[cfg(feature = "json")]
-        #[arg(long, num_args = 0..=1, default_missing_value = "-")]
-        json: Option<String>,
+        #[command(flatten)]
+        output: OutputArgs,
     },
     /// Generate random personal ID codes
     Id {
@@ -62,22 +82,26 @@ enum Commands {
         /// Country code
         #[arg(long, default_value = "EE")]
         country: String,
+        /// Weighted country mix, e.g. DE:50,FR:30,@sepa:20
+        #[arg(long, value_name = "SPEC", conflicts_with_all = ["country", "region"])]
+        countries: Option<String>,
         /// Gender (m or f)
         #[arg(long)]
         gender: Option<String>,
+        /// Weighted gender mix, e.g. m:48,f:52
+        #[arg(long, value_name = "SPEC", conflicts_with = "gender")]
+        genders: Option<String>,
         /// Year of birth
         #[arg(long)]
         year: Option<u16>,
+        /// ISO 3166-2 region for IDs that encode one (e.g. MX-JAL, CN-BJ)
+        #[arg(long)]
+        region: Option<String>,
         /// List all supported countries
         #[arg(long)]
         list: bool,
-        /// Export as CSV (optionally to a file path)
-        #[arg(long, num_args = 0..=1, default_missing_value = "-")]
-        csv: Option<String>,
-        /// Export as JSON (optionally to a file path)
-        #[cfg(feature = "json")]
-        #[arg(long, num_args = 0..=1, default_missing_value = "-")]
-        json: Option<String>,
+        #[command(flatten)]
+        output: OutputArgs,
     },
     /// Generate random credit card numbers
     Card {
@@ -87,16 +111,14 @@ enum Commands {
         /// Brand (visa, mastercard, amex, discover, jcb, diners)
         #[arg(long)]
         brand: Option<String>,
+        /// Weighted brand mix, e.g. visa:60,mastercard:35,amex:5
+        #[arg(long, value_name = "SPEC", conflicts_with = "brand")]
+        brands: Option<String>,
         /// List all supported brands
         #[arg(long)]
         list: bool,
-        /// Export as CSV (optionally to a file path)
-        #[arg(long, num_args = 0..=1, default_missing_value = "-")]
-        csv: Option<String>,
-        /// Export as JSON (optionally to a file path)
-        #[cfg(feature = "json")]
-        #[arg(long, num_args = 0..=1, default_missing_value = "-")]
-        json: Option<String>,
+        #[command(flatten)]
+        output: OutputArgs,
     },
     /// Generate random SWIFT/BIC codes
     Swift {
@@ -106,13 +128,11 @@ enum Commands {
         /// Country code (e.g., US, GB)
         #[arg(long)]
         country: Option<String>,
-        /// Export as CSV (optionally to a file path)
-        #[arg(long, num_args = 0..=1, default_missing_value = "-")]
-        csv: Option<String>,
-        /// Export as JSON (optionally to a file path)
-        #[cfg(feature = "json")]
-        #[arg(long, num_args = 0..=1, default_missing_value = "-")]
-        json: Option<String>,
+        /// Weighted country mix, e.g. DE:50,FR:30,@sepa:20
+        #[arg(long, value_name = "SPEC", conflicts_with = "country")]
+        countries: Option<String>,
+        #[command(flatten)]
+        output: OutputArgs,
     },
     /// Generate random Company/Business IDs (VAT, CIF, etc.)
     Company {
@@ -122,16 +142,14 @@ enum Commands {
         /// Country code (e.g., DE, GB, FR, IT, ES)
         #[arg(long)]
         country: Option<String>,
+        /// Weighted country mix, e.g. DE:50,FR:30,@sepa:20
+        #[arg(long, value_name = "SPEC", conflicts_with = "country")]
+        countries: Option<String>,
         /// List all supported countries
         #[arg(long)]
         list: bool,
-        /// Export as CSV (optionally to a file path)
-        #[arg(long, num_args = 0..=1, default_missing_value = "-")]
-        csv: Option<String>,
-        /// Export as JSON (optionally to a file path)
-        #[cfg(feature = "json")]
-        #[arg(long, num_args = 0..=1, default_missing_value = "-")]
-        json: Option<String>,
+        #[command(flatten)]
+        output: OutputArgs,
     },
     /// Generate random driver's license numbers
     License {
@@ -141,19 +159,17 @@ enum Commands {
         /// Country code (e.g., IN, US, GB)
         #[arg(long)]
         country: Option<String>,
+        /// Weighted country mix, e.g. DE:50,FR:30,@sepa:20
+        #[arg(long, value_name = "SPEC", conflicts_with = "country")]
+        countries: Option<String>,
         /// State code (for India: MH, DL, KA, etc.)
         #[arg(long)]
         state: Option<String>,
         /// List all supported countries
         #[arg(long)]
         list: bool,
-        /// Export as CSV (optionally to a file path)
-        #[arg(long, num_args = 0..=1, default_missing_value = "-")]
-        csv: Option<String>,
-        /// Export as JSON (optionally to a file path)
-        #[cfg(feature = "json")]
-        #[arg(long, num_args = 0..=1, default_missing_value = "-")]
-        json: Option<String>,
+        #[command(flatten)]
+        output: OutputArgs,
     },
     /// Generate random tax IDs (PAN, TIN, etc.)
     Tax {
@@ -163,19 +179,20 @@ enum Commands {
         /// Country code (e.g., IN, US, GB)
         #[arg(long)]
         country: Option<String>,
-        /// Holder type (for India PAN: P, C, H, F, A, T, B, L, J, G)
+        /// Weighted country mix, e.g. DE:50,FR:30,@sepa:20
+        #[arg(long, value_name = "SPEC", conflicts_with = "country")]
+        countries: Option<String>,
+        /// Holder type (India PAN: P, C, H, F, A, T, B, L, J, G; Russia INN: entity, individual)
         #[arg(long)]
         holder_type: Option<String>,
+        /// Weighted holder type mix, e.g. P:90,C:10
+        #[arg(long, value_name = "SPEC", conflicts_with = "holder_type")]
+        holder_types: Option<String>,
         /// List all supported countries
         #[arg(long)]
         list: bool,
-        /// Export as CSV (optionally to a file path)
-        #[arg(long, num_args = 0..=1, default_missing_value = "-")]
-        csv: Option<String>,
-        /// Export as JSON (optionally to a file path)
-        #[cfg(feature = "json")]
-        #[arg(long, num_args = 0..=1, default_missing_value = "-")]
-        json: Option<String>,
+        #[command(flatten)]
+        output: OutputArgs,
     },
     /// Generate random passport numbers
     Passport {
@@ -185,35 +202,31 @@ enum Commands {
         /// Country code (e.g., IN, US, GB)
         #[arg(long)]
         country: Option<String>,
+        /// Weighted country mix, e.g. DE:50,FR:30,@sepa:20
+        #[arg(long, value_name = "SPEC", conflicts_with = "country")]
+        countries: Option<String>,
         /// List all supported countries
         #[arg(long)]
         list: bool,
-        /// Export as CSV (optionally to a file path)
-        #[arg(long, num_args = 0..=1, default_missing_value = "-")]
-        csv: Option<String>,
-        /// Export as JSON (optionally to a file path)
-        #[cfg(feature = "json")]
-        #[arg(long, num_args = 0..=1, default_missing_value = "-")]
-        json: Option<String>,
+        #[command(flatten)]
+        output: OutputArgs,
     },
     /// Generate random EU VAT numbers
     Vat {
         /// Number of VAT numbers to generate
         #[arg(default_value = "1")]
         count: u32,
-        /// Country code (e.g., DE, FR, EL for Greece)
+        /// Country code (e.g., DE, FR, EL for Greece) or group (eu, eurozone)
         #[arg(long)]
         country: Option<String>,
+        /// Weighted country mix, e.g. DE:50,FR:30,@sepa:20
+        #[arg(long, value_name = "SPEC", conflicts_with = "country")]
+        countries: Option<String>,
         /// List all supported countries
         #[arg(long)]
         list: bool,
-        /// Export as CSV (optionally to a file path)
-        #[arg(long, num_args = 0..=1, default_missing_value = "-")]
-        csv: Option<String>,
-        /// Export as JSON (optionally to a file path)
-        #[cfg(feature = "json")]
-        #[arg(long, num_args = 0..=1, default_missing_value = "-")]
-        json: Option<String>,
+        #[command(flatten)]
+        output: OutputArgs,
     },
     /// Generate random LEI (Legal Entity Identifier) codes
     Lei {
@@ -223,6 +236,84 @@ enum Commands {
         /// Country code (e.g., US, GB, DE)
         #[arg(long)]
         country: Option<String>,
+        /// Weighted country mix, e.g. DE:50,FR:30,@sepa:20
+        #[arg(long, value_name = "SPEC", conflicts_with = "country")]
+        countries: Option<String>,
+        #[command(flatten)]
+        output: OutputArgs,
+    },
+    /// Validate an existing code
+    Validate {
+        /// Category (iban, account, id, card, swift, company)
+        #[arg(index = 1)]
+        category: String,
+        /// Code to validate. Without it, codes are read from --file or stdin.
+        #[arg(index = 2, conflicts_with = "file")]
+        code: Option<String>,
+        /// Country code (required for most categories)
+        #[arg(long)]
+        country: Option<String>,
+        /// Read codes from a file, one per line (use - for stdin)
+        #[arg(long)]
+        file: Option<String>,
+        /// Read the input as CSV and take codes from this column
+        #[arg(long, value_name = "NAME")]
+        column: Option<String>,
+        /// CSV column with each row's country (overrides --country)
+        #[arg(long, value_name = "NAME", requires = "column")]
+        country_column: Option<String>,
+        #[command(flatten)]
+        output: OutputArgs,
+    },
+    /// Replace identifier columns in a CSV file with consistent fake values
+    Anonymize {
+        /// Input CSV file (use - for stdin)
+        input: String,
+        /// Column to replace, as NAME=KIND[:COUNTRY] (e.g. iban=iban, ssn=id:US)
+        #[arg(long = "column", value_name = "NAME=KIND")]
+        columns: Vec<String>,
+        /// File whose contents are used as the secret mapping key.
+        /// Without it a random key is used and mappings only hold for this run;
+        /// with it they hold for the same idsmith version.
+        #[arg(long)]
+        key_file: Option<String>,
+        /// Detect identifier columns automatically using the validators
+        #[arg(long)]
+        detect: bool,
+        /// Output CSV file (defaults to stdout)
+        #[arg(long, short)]
+        output: Option<String>,
+    },
+    /// Report identifier kinds, validity, formats and duplicates per CSV column
+    ProfileFile {
+        /// Input CSV file (use - for stdin)
+        input: String,
+        /// Only profile these columns
+        #[arg(long = "column", value_name = "NAME")]
+        columns: Vec<String>,
+        /// Export as JSON (optionally to a file path)
+        #[cfg(feature = "json")]
+        #[arg(long, num_args = 0..=1, default_missing_value = "-")]
+        json: Option<String>,
+    },
+    /// Find IBANs, cards, SSNs, national IDs, VAT and passport numbers in text
+    Scan {
+        /// Files to scan (defaults to stdin)
+        files: Vec<String>,
+        /// Print the text with findings masked instead of listing them
+        /// (full, last4, country)
+        #[arg(long, value_name = "POLICY")]
+        redact: Option<String>,
+        /// Only check personal IDs and passports for these countries
+        /// (their personal IDs then need no keyword)
+        #[arg(long = "country")]
+        countries: Vec<String>,
+    },
+    /// Show which identifier kinds are supported for each country
+    Coverage {
+        /// Only show these countries
+        #[arg(long = "country")]
+        countries: Vec<String>,
         /// Export as CSV (optionally to a file path)
         #[arg(long, num_args = 0..=1, default_missing_value = "-")]
         csv: Option<String>,
@@ -231,17 +322,72 @@ enum Commands {
         #[arg(long, num_args = 0..=1, default_missing_value = "-")]
         json: Option<String>,
     },
-    /// Validate an existing code
-    Validate {
-        /// Category (iban, account, id, card, swift, company)
-        #[arg(index = 1)]
-        category: String,
-        /// Code to validate
-        #[arg(index = 2)]
+    /// Show the format of an identifier: length, charset, checksum, regex
+    Describe {
+        /// Kind (iban, account, id, card, swift, company, license, tax,
+        /// passport, lei, vat)
+        kind: String,
+        /// Country code (required for most kinds)
+        #[arg(long)]
+        country: Option<String>,
+        /// Export as JSON (optionally to a file path)
+        #[cfg(feature = "json")]
+        #[arg(long, num_args = 0..=1, default_missing_value = "-")]
+        json: Option<String>,
+    },
+    /// Show the checksum calculation for a code step by step
+    Explain {
+        /// Kind (iban, card, lei, vat, id, company, tax, ...)
+        kind: String,
+        /// Code to explain
         code: String,
-        /// Country code (required for most categories)
+        /// Country code (required for all but iban, card, lei and vat)
         #[arg(long)]
         country: Option<String>,
+        /// Export as JSON (optionally to a file path)
+        #[cfg(feature = "json")]
+        #[arg(long, num_args = 0..=1, default_missing_value = "-")]
+        json: Option<String>,
+    },
+    /// Generate a table of related identifiers from a TOML or JSON schema
+    Dataset {
+        /// Schema file (.toml or .json)
+        schema: String,
+        /// Number of rows (overrides the schema's `rows`)
+        #[arg(long)]
+        rows: Option<usize>,
+        #[command(flatten)]
+        output: OutputArgs,
+    },
+    /// Serve generate, validate, parse, detect and describe as a REST/JSON API
+    Serve {
+        /// Address to listen on
+        #[arg(long, default_value = "127.0.0.1")]
+        host: String,
+        /// Port to listen on
+        #[arg(long, short, default_value = "8080")]
+        port: u16,
+    },
+    /// Run a local mock of the EU VIES VAT check service (SOAP and REST)
+    MockVies {
+        /// Address to listen on
+        #[arg(long, default_value = "127.0.0.1")]
+        host: String,
+        /// Port to listen on
+        #[arg(long, short, default_value = "8081")]
+        port: u16,
+        /// Member states that answer MS_UNAVAILABLE (comma-separated, or all)
+        #[arg(long, value_delimiter = ',', value_name = "CC")]
+        unavailable: Vec<String>,
+        /// Member states that answer TIMEOUT (comma-separated, or all)
+        #[arg(long, value_delimiter = ',', value_name = "CC")]
+        timeout: Vec<String>,
+        /// Member states whose numbers are all reported invalid
+        #[arg(long, value_delimiter = ',', value_name = "CC")]
+        invalid: Vec<String>,
+        /// Milliseconds to wait before every answer
+        #[arg(long, default_value = "0", value_name = "MS")]
+        delay: u64,
     },
 }
 
@@ -256,16 +402,437 @@ fn csv_writer(path: &str) -> Box<dyn Write> {
     }
 }
 
+/// Output options shared by the generator commands.
+#[derive(Args)]
+struct OutputArgs {
+    /// Export as CSV (optionally to a file path)
+    #[arg(long, num_args = 0..=1, default_missing_value = "-")]
+    csv: Option<String>,
+    /// Export as JSON (optionally to a file path)
+    #[arg(long, num_args = 0..=1, default_missing_value = "-")]
+    json: Option<String>,
+    /// Export as newline-delimited JSON (optionally to a file path)
+    #[arg(long, num_args = 0..=1, default_missing_value = "-")]
+    ndjson: Option<String>,
+    /// Export as YAML (optionally to a file path)
+    #[arg(long, num_args = 0..=1, default_missing_value = "-")]
+    yaml: Option<String>,
+    /// Export as XML (optionally to a file path)
+    #[arg(long, num_args = 0..=1, default_missing_value = "-")]
+    xml: Option<String>,
+    /// Print one line per row from a template, e.g. "{code};{country_code}"
+    #[arg(long, conflicts_with = "sql")]
+    template: Option<String>,
+    /// Export as SQL statements into TABLE (see --dialect, --copy, --create-table)
+    #[arg(long, value_name = "TABLE")]
+    sql: Option<String>,
+    /// Fields to output, in order (e.g. code,country_code,dob)
+    #[arg(long, value_delimiter = ',', value_name = "FIELDS")]
+    fields: Vec<String>,
+    /// Field separator for --csv
+    #[arg(long, default_value = ",", value_name = "CHAR")]
+    delimiter: char,
+    /// Leave out the --csv header row
+    #[arg(long)]
+    no_header: bool,
+    /// Write an Arrow IPC file
+    #[cfg(feature = "arrow")]
+    #[arg(long, value_name = "PATH")]
+    arrow: Option<String>,
+    /// Write a Parquet file
+    #[cfg(feature = "parquet")]
+    #[arg(long, value_name = "PATH")]
+    parquet: Option<String>,
+    /// Output file for --template or --sql (defaults to stdout)
+    #[arg(long, short)]
+    output: Option<String>,
+}
+
+type FormatWriter = output::Writer<Box<dyn Write>>;
+
+/// The writers selected by [`OutputArgs`]; rows are printed as plain text
+/// when there are none.
+struct Output {
+    /// Path, writer and whether it writes the CSV layout of the rows.
+    writers: Vec<(String, FormatWriter, bool)>,
+    /// Indices of the `--fields` for the writers that do not select fields
+    /// themselves.
+    selected: Vec<usize>,
+    sql: Option<sql::Writer<Box<dyn Write>>>,
+    #[cfg(feature = "arrow")]
+    columnar: Vec<(String, idsmith::arrow::Writer<std::fs::File>)>,
+}
+
+impl Output {
+    /// `csv_fields` are the columns of `--csv` without `--fields`.
+    fn new(
+        args: &OutputArgs,
+        fields: Vec<output::Field>,
+        csv_fields: Vec<output::Field>,
+        sql_opts: &sql::Options,
+    ) -> Self {
+        let fail = |e: String| -> ! {
+            eprintln!("Error: {}", e);
+            std::process::exit(1);
+        };
+        let open =
+            |path: &str| -> Box<dyn Write> { Box::new(std::io::BufWriter::new(csv_writer(path))) };
+        let base = output::Options {
+            fields: args.fields.clone(),
+            delimiter: args.delimiter,
+            header: !args.no_header,
+            ..Default::default()
+        };
+        let template = args.template.as_ref().map(|t| {
+            let path = args.output.as_deref().unwrap_or("-");
+            (path, output::Format::Template(t.clone()))
+        });
+        let formats = [
+            (args.csv.as_deref(), output::Format::Csv),
+            (args.json.as_deref(), output::Format::Json),
+            (args.ndjson.as_deref(), output::Format::Ndjson),
+            (args.yaml.as_deref(), output::Format::Yaml),
+            (args.xml.as_deref(), output::Format::Xml),
+        ];
+        let writers = formats
+            .into_iter()
+            .filter_map(|(path, format)| Some((path?, format)))
+            .chain(template)
+            .map(|(path, format)| {
+                let csv_layout = format == output::Format::Csv && args.fields.is_empty();
+                let columns = if csv_layout { &csv_fields } else { &fields };
+                let opts = output::Options {
+                    format,
+                    ..base.clone()
+                };
+                let writer = output::Writer::new(open(path), columns.clone(), opts)
+                    .unwrap_or_else(|e| fail(e));
+                (path.to_string(), writer, csv_layout)
+            })
+            .collect();
+
+        let selected: Vec<usize> = if args.fields.is_empty() {
+            (0..fields.len()).collect()
+        } else {
+            args.fields
+                .iter()
+                .map(|name| {
+                    fields
+                        .iter()
+                        .position(|f| &f.name == name)
+                        .unwrap_or_else(|| fail(format!("Unknown field: {}", name)))
+                })
+                .collect()
+        };
+        let selected_fields: Vec<output::Field> =
+            selected.iter().map(|&i| fields[i].clone()).collect();
+
+        let sql = args.sql.as_deref().map(|table| {
+            let columns = selected_fields
+                .iter()
+                .cloned()
+                .map(sql::Column::from)
+                .collect();
+            let out = open(args.output.as_deref().unwrap_or("-"));
+            sql::Writer::new(out, table, columns, sql_opts.clone()).unwrap_or_else(|e| fail(e))
+        });
+
+        #[cfg(feature = "arrow")]
+        let columnar = [
+            (args.arrow.as_deref(), idsmith::arrow::FileFormat::Ipc),
+            #[cfg(feature = "parquet")]
+            (args.parquet.as_deref(), idsmith::arrow::FileFormat::Parquet),
+        ]
+        .into_iter()
+        .filter_map(|(path, format)| Some((path?, format)))
+        .map(|(path, format)| {
+            let file = std::fs::File::create(path).unwrap_or_else(|e| {
+                fail(format!("Cannot create {}: {}", path, e));
+            });
+            let writer = idsmith::arrow::Writer::new(file, &selected_fields, format)
+                .unwrap_or_else(|e| fail(e));
+            (path.to_string(), writer)
+        })
+        .collect();
+
+        Self {
+            writers,
+            selected,
+            sql,
+            #[cfg(feature = "arrow")]
+            columnar,
+        }
+    }
+
+    fn for_rows<T: output::Record>(args: &OutputArgs, sql_opts: &sql::Options) -> Self {
+        Self::new(args, T::fields(), T::csv_fields(), sql_opts)
+    }
+
+    fn is_plain(&self) -> bool {
+        #[cfg(feature = "arrow")]
+        if !self.columnar.is_empty() {
+            return false;
+        }
+        self.writers.is_empty() && self.sql.is_none()
+    }
+
+    fn write<T: output::Record>(&mut self, row: &T) {
+        let csv_values = self
+            .writers
+            .iter()
+            .any(|(_, _, csv_layout)| *csv_layout)
+            .then(|| row.csv_values());
+        self.write_values(&row.values(), csv_values.as_deref());
+    }
+
+    /// `csv_values` are for the writers of the CSV layout, and default to
+    /// `values`.
+    fn write_values(&mut self, values: &[output::Value], csv_values: Option<&[output::Value]>) {
+        for (_, w, csv_layout) in &mut self.writers {
+            let values = match csv_values {
+                Some(csv_values) if *csv_layout => csv_values,
+                _ => values,
+            };
+            w.write_values(values).unwrap();
+        }
+        let selected: Vec<_> = self.selected.iter().map(|&i| values[i].clone()).collect();
+        if let Some(w) = &mut self.sql {
+            w.write_values(&selected).unwrap();
+        }
+        #[cfg(feature = "arrow")]
+        for (path, w) in &mut self.columnar {
+            if let Err(e) = w.write_values(&selected) {
+                eprintln!("Cannot write {}: {}", path, e);
+                std::process::exit(1);
+            }
+        }
+    }
+
+    fn finish(self) {
+        for (path, w, _) in self.writers {
+            let rows = w.rows();
+            w.finish().unwrap();
+            if path != "-" {
+                eprintln!("Wrote {} rows to {}", rows, path);
+            }
+        }
+        if let Some(w) = self.sql {
+            w.finish().unwrap();
+        }
+        #[cfg(feature = "arrow")]
+        for (path, w) in self.columnar {
+            if let Err(e) = w.finish() {
+                eprintln!("Cannot write {}: {}", path, e);
+                std::process::exit(1);
+            }
+            eprintln!("Wrote {}", path);
+        }
+    }
+}
+
+/// Whether `country` can be used for `kind` under `--strict`, i.e. it does
+/// not borrow a parent country's rules.
+fn own_rules(kind: kind::IdKind, country: &str, strict: bool) -> bool {
+    !strict
+        || kind
+            .effective_country(country)
+            .is_none_or(|effective| effective == country)
+}
+
+/// The `--countries` mix for `kind`, exiting on an invalid spec.
+fn country_mix(
+    spec: Option<&str>,
+    kind: kind::IdKind,
+    strict: bool,
+) -> Option<weights::Weighted<String>> {
+    let mix = weights::countries(spec?, kind).and_then(|mix| {
+        match mix
+            .entries()
+            .iter()
+            .find(|(c, _)| !own_rules(kind, c, strict))
+        {
+            Some((c, _)) => Err(format!("Unsupported country for {}: {}", kind, c)),
+            None => Ok(mix),
+        }
+    });
+    Some(mix.unwrap_or_else(|e| {
+        eprintln!("Error: {}", e);
+        std::process::exit(1);
+    }))
+}
+
+/// A weighted mix from a `VALUE:WEIGHT,...` option, exiting on an invalid
+/// spec.
+fn weighted_mix<T>(
+    spec: Option<&str>,
+    parse: impl FnMut(&str) -> Result<T, String>,
+) -> Option<weights::Weighted<T>> {
+    let mix = weights::Weighted::parse_with(spec?, parse);
+    Some(mix.unwrap_or_else(|e| {
+        eprintln!("Error: {}", e);
+        std::process::exit(1);
+    }))
+}
+
+/// Where `validate` reads codes from when none is given.
+struct BatchInput {
+    path: String,
+    /// CSV column with the codes; plain lines without it.
+    column: Option<String>,
+    country_column: Option<String>,
+}
+
+/// Validate every code in `input` and report the outcomes. Returns the exit
+/// status: 0 if all codes are valid, 1 if any is not, 2 if the input could
+/// not be read.
+fn validate_file(
+    category: &str,
+    country: Option<String>,
+    input: &BatchInput,
+    args: &OutputArgs,
+    sql_opts: &sql::Options,
+    strict: bool,
+) -> i32 {
+    let fail = |e: String| -> i32 {
+        eprintln!("Error: {}", e);
+        2
+    };
+    let kind: kind::IdKind = match category.parse() {
+        Ok(kind) => kind,
+        Err(e) => return fail(e),
+    };
+    if kind.requires_country() && country.is_none() && input.country_column.is_none() {
+        return fail(format!(
+            "--country or --country-column is required for {}",
+            kind
+        ));
+    }
+    let reader: Box<dyn std::io::BufRead> = if input.path == "-" {
+        Box::new(std::io::stdin().lock())
+    } else {
+        match std::fs::File::open(&input.path) {
+            Ok(f) => Box::new(std::io::BufReader::new(f)),
+            Err(e) => return fail(format!("Cannot read {}: {}", input.path, e)),
+        }
+    };
+
+    let mut items = Vec::new();
+    // Input line or CSV row of each item, for the plain report
+    let mut numbers = Vec::new();
+    match &input.column {
+        Some(name) => {
+            let mut reader = csv_fmt::Reader::new(reader);
+            let header = match reader.read_record() {
+                Ok(header) => header.unwrap_or_default(),
+                Err(e) => return fail(e.to_string()),
+            };
+            let position = |name: &str| header.iter().position(|h| h == name);
+            let Some(code_idx) = position(name) else {
+                return fail(format!("No such column: {}", name));
+            };
+            let country_idx = match input.country_column.as_deref() {
+                Some(name) => match position(name) {
+                    Some(idx) => Some(idx),
+                    None => return fail(format!("No such column: {}", name)),
+                },
+                None => None,
+            };
+            for row in reader {
+                let row = match row {
+                    Ok(row) => row,
+                    Err(e) => return fail(e.to_string()),
+                };
+                let row_country = country_idx
+                    .and_then(|i| row.get(i))
+                    .filter(|c| !c.trim().is_empty())
+                    .map(|c| c.trim())
+                    .or(country.as_deref());
+                let code = row.get(code_idx).map_or("", String::as_str);
+                items.push(idsmith::batch::Item::new(kind, row_country, code));
+                numbers.push(items.len());
+            }
+        }
+        None => {
+            for (n, line) in std::io::BufRead::lines(reader).enumerate() {
+                let line = match line {
+                    Ok(line) => line,
+                    Err(e) => return fail(e.to_string()),
+                };
+                if !line.trim().is_empty() {
+                    items.push(idsmith::batch::Item::new(
+                        kind,
+                        country.as_deref(),
+                        line.trim(),
+                    ));
+                    numbers.push(n + 1);
+                }
+            }
+        }
+    }
+
+    let mut outcomes = idsmith::batch::validate_batch(&items);
+    if strict {
+        for outcome in &mut outcomes {
+            if let Some(cc) = outcome.country.as_deref() {
+                if !own_rules(kind, cc, true) {
+                    outcome.valid = false;
+                    outcome.reason = Some(idsmith::batch::Failure::UnsupportedCountry);
+                }
+            }
+        }
+    }
+
+    let mut out = Output::for_rows::<idsmith::batch::Outcome>(args, sql_opts);
+    let unit = if input.column.is_some() {
+        "row"
+    } else {
+        "line"
+    };
+    for (outcome, n) in outcomes.iter().zip(&numbers) {
+        if !out.is_plain() {
+            out.write(outcome);
+        } else if let Some(reason) = outcome.reason {
+            println!("{} {}: INVALID {} ({})", unit, n, outcome.code, reason);
+        }
+    }
+    out.finish();
+
+    let summary = idsmith::batch::Summary::new(&outcomes);
+    eprintln!("{}", summary);
+    if summary.invalid > 0 {
+        1
+    } else {
+        0
+    }
+}
+
 fn main() {
     let cli = Cli::parse();
     let mut rng = thread_rng();
 
+    for path in &cli.specs {
+        let registered = spec::Spec::load(path)
+            .and_then(|specs| specs.into_iter().try_for_each(spec::Spec::register));
+        if let Err(e) = registered {
+            eprintln!("Error: {}", e);
+            std::process::exit(1);
+        }
+    }
+
+    let strict = cli.strict;
+    let locale = cli.locale;
+    let sql_opts = sql::Options {
+        dialect: cli.dialect,
+        copy: cli.copy,
+        create_table: cli.create_table,
+        ..Default::default()
+    };
     match cli.command {
         Commands::Iban {
             country,
             count,
-            csv,
-            json,
+            countries,
+            output,
         } => {
             // Handle case where user passes just a number (e.g., `iban 3`)
             // clap parses it as country="3", count=1
@@ -276,84 +843,58 @@ fn main() {
                 _ => (country.as_deref(), count),
             };
 
-            let mut out_csv: Option<Box<dyn Write>> = csv.as_deref().map(csv_writer);
-            if let Some(ref mut w) = out_csv {
-                writeln!(w, "{}", csv_fmt::IBAN_HEADER).unwrap();
+            if let (Some(c), Some(_)) = (actual_country, &countries) {
+                eprintln!("Error: pass either a country ({}) or --countries", c);
+                std::process::exit(1);
             }
+            let mix = country_mix(countries.as_deref(), kind::IdKind::Iban, strict);
 
-            #[cfg(feature = "json")]
-            let mut json_results = Vec::new();
-
+            let mut out = Output::for_rows::<iban::IbanResult>(&output, &sql_opts);
             for _ in 0..actual_count {
-                match iban::generate_iban(actual_country, &mut rng) {
-                    Ok(iban_code) => {
-                        let valid = iban::validate_iban(&iban_code);
-                        let formatted = iban::format_iban(&iban_code);
-
-                        #[cfg(feature = "json")]
-                        if json.is_some() {
-                            json_results.push(iban::IbanResult {
-                                country: iban_code[..2].to_string(),
-                                iban: iban_code.clone(),
-                                formatted: formatted.clone(),
-                                valid,
-                            });
-                        }
-
-                        if let Some(ref mut w) = out_csv {
-                            writeln!(w, "{}", csv_fmt::iban_row(&iban_code, &formatted, valid))
-                                .unwrap();
-                        } else {
-                            let mut print_it = true;
-                            #[cfg(feature = "json")]
-                            if json.is_some() {
-                                print_it = false;
-                            }
-
-                            if print_it {
-                                println!(
-                                    "{}  (valid: {})",
-                                    formatted,
-                                    if valid { "True" } else { "False" }
-                                );
-                            }
-                        }
-                    }
-                    Err(e) => {
-                        eprintln!("{}", e);
-                        std::process::exit(1);
-                    }
-                }
-            }
-
-            #[cfg(feature = "json")]
-            if let Some(path) = json.as_deref() {
-                let mut w = csv_writer(path);
-                serde_json::to_writer_pretty(&mut w, &json_results).unwrap();
-                if path != "-" {
-                    eprintln!("Wrote {} rows to {}", actual_count, path);
-                }
-            }
+                let country = match &mix {
+                    Some(mix) => Some(mix.pick(&mut rng).as_str()),
+                    None => actual_country,
+                };
+                let iban_code = iban::generate_iban(country, &mut rng).unwrap_or_else(|e| {
+                    eprintln!("{}", e);
+                    std::process::exit(1);
+                });
+                let result = iban::IbanResult {
+                    country: iban_code[..2].to_string(),
+                    formatted: iban::format_iban(&iban_code),
+                    valid: iban::validate_iban(&iban_code),
+                    iban: iban_code.parse().expect("generated IBANs are valid"),
+                };
 
-            if let Some(path) = csv.as_deref() {
-                if path != "-" {
-                    eprintln!("Wrote {} rows to {}", actual_count, path);
+                if out.is_plain() {
+                    println!(
+                        "{}  (valid: {})",
+                        result.formatted,
+                        if result.valid { "True" } else { "False" }
+                    );
+                } else {
+                    out.write(&result);
                 }
             }
+            out.finish();
         }
         Commands::Account {
             count,
             country,
+            countries,
             list,
-            csv,
-            json,
+            output,
         } => {
             let registry = bank_account::Registry::new();
 
             if list {
                 println!("{:<6} {:<25} {:<30} IBAN", "Code", "Country", "Format");
                 println!("{}", "-".repeat(70));
-                for (code, country_name, format_name, has_iban) in registry.list_countries() {
+                let countries = match locale {
+                    Some(locale) => registry.list_countries_in(locale),
+                    None => registry.list_countries(),
+                };
+                for (code, country_name, format_name, has_iban) in countries {
                     println!(
                         "{:<6} {:<25} {:<30} {}",
                         code,
@@ -365,206 +906,188 @@ fn main() {
                 return;
             }
 
-            let opts = bank_account::GenOptions::default();
+            let opts = bank_account::GenOptions {
+                strict,
+                ..Default::default()
+            };
 
             let country = country.map(|c| c.to_uppercase());
             if let Some(ref c) = country {
-                if !registry.is_supported(c) {
+                if !registry.is_supported(c) || !own_rules(kind::IdKind::BankAccount, c, strict) {
                     eprintln!("Unsupported country: {}", c);
                     std::process::exit(1);
                 }
             }
 
-            let mut out_csv: Option<Box<dyn Write>> = csv.as_deref().map(csv_writer);
-            if let Some(ref mut w) = out_csv {
-                writeln!(w, "{}", csv_fmt::ACCOUNT_HEADER).unwrap();
-            }
-
-            #[cfg(feature = "json")]
-            let mut json_results = Vec::new();
-
+            let mix = country_mix(countries.as_deref(), kind::IdKind::BankAccount, strict);
+            // Countries picked from when none is given; --strict leaves out
+            // territories that borrow another country's format
+            let pool: Vec<&str> = registry
+                .list_countries()
+                .into_iter()
+                .map(|(code, _, _, _)| code)
+                .filter(|code| own_rules(kind::IdKind::BankAccount, code, strict))
+                .collect();
+
+            let mut out = Output::for_rows::<bank_account::AccountResult>(&output, &sql_opts);
             for _ in 0..count {
-                let result = match &country {
-                    Some(c) => registry.generate(c, &opts, &mut rng).unwrap(),
-                    None => {
-                        let countries = registry.list_countries();
-                        let pick = countries[rng.gen_range(0..countries.len())].0;
-                        registry.generate(pick, &opts, &mut rng).unwrap()
-                    }
+                let pick = match (&mix, &country) {
+                    (Some(mix), _) => mix.pick(&mut rng).as_str(),
+                    (None, Some(c)) => c.as_str(),
+                    (None, None) => pool[rng.gen_range(0..pool.len())],
+                };
+                let Some(result) = registry.generate(pick, &opts, &mut rng) else {
+                    eprintln!("Error: cannot generate a bank account for '{}'", pick);
+                    std::process::exit(1);
                 };
 
-                #[cfg(feature = "json")]
-                if json.is_some() {
-                    json_results.push(result.clone());
+                if !out.is_plain() {
+                    out.write(&result);
+                    continue;
                 }
-
-                if let Some(ref mut w) = out_csv {
-                    writeln!(w, "{}", csv_fmt::account_row(&result)).unwrap();
-                } else {
-                    let mut print_it = true;
-                    #[cfg(feature = "json")]
-                    if json.is_some() {
-                        print_it = false;
-                    }
-
-                    if print_it {
-                        println!(
-                            "{} - {} - {}:",
-                            result.country_code, result.country_name, result.format_name
-                        );
-                        let mut parts: Vec<String> = Vec::new();
-                        if let Some(ref bank) = result.bank_code {
-                            parts.push(format!("Bank: {}", bank));
-                        }
-                        if let Some(ref branch) = result.branch_code {
-                            parts.push(format!("Branch: {}", branch));
-                        }
-                        parts.push(format!("Account: {}", result.account_number));
-                        if let Some(ref check) = result.check_digits {
-                            parts.push(format!("Check: {}", check));
-                        }
-                        if let Some(ref iban_code) = result.iban {
-                            parts.push(format!("IBAN: {}", iban::format_iban(iban_code)));
-                        }
-                        parts.push(format!("Formatted: {}", result.formatted));
-                        parts.push(format!("Raw: {}", result.raw));
-                        parts.push(format!(
-                            "valid: {}",
-                            if result.valid { "True" } else { "False" }
-                        ));
-                        println!("  {}", parts.join(" | "));
-                    }
+                println!(
+                    "{} - {} - {}:",
+                    result.country_code, result.country_name, result.format_name
+                );
+                let mut parts: Vec<String> = Vec::new();
+                if let Some(ref bank) = result.bank_code {
+                    parts.push(format!("Bank: {}", bank));
                 }
-            }
-
-            #[cfg(feature = "json")]
-            if let Some(path) = json.as_deref() {
-                let mut w = csv_writer(path);
-                serde_json::to_writer_pretty(&mut w, &json_results).unwrap();
-                if path != "-" {
-                    eprintln!("Wrote {} rows to {}", count, path);
+                if let Some(ref branch) = result.branch_code {
+                    parts.push(format!("Branch: {}", branch));
                 }
-            }
-
-            if let Some(path) = csv.as_deref() {
-                if path != "-" {
-                    eprintln!("Wrote {} rows to {}", count, path);
+                parts.push(format!("Account: {}", result.account_number));
+                if let Some(ref check) = result.check_digits {
+                    parts.push(format!("Check: {}", check));
+                }
+                if let Some(ref iban_code) = result.iban {
+                    parts.push(format!("IBAN: {}", iban::format_iban(iban_code)));
                 }
+                parts.push(format!("Formatted: {}", result.formatted));
+                parts.push(format!("Raw: {}", result.raw));
+                parts.push(format!(
+                    "valid: {}",
+                    if result.valid { "True" } else { "False" }
+                ));
+                println!("  {}", parts.join(" | "));
             }
+            out.finish();
         }
         Commands::Id {
             count,
             country,
+            countries,
             gender,
+            genders,
             year,
+            region,
             list,
-            csv,
-            json,
+            output,
         } => {
-            let registry = personal_id::Registry::new();
+            let registry = idsmith::personal_ids();
 
             if list {
                 println!("{:<6} {:<25} ID Name", "Code", "Country");
                 println!("{}", "-".repeat(55));
-                for (code, country_name, name) in registry.list_countries() {
+                let countries = match locale {
+                    Some(locale) => registry.list_countries_in(locale),
+                    None => registry.list_countries(),
+                };
+                for (code, country_name, name) in countries {
                     println!("{:<6} {:<25} {}", code, country_name, name);
                 }
                 return;
             }
 
+            let mix = country_mix(countries.as_deref(), kind::IdKind::PersonalId, strict);
+            let genders = weighted_mix(genders.as_deref(), |g| {
+                personal_id::date::Gender::from_str_opt(Some(g))
+                    .ok_or_else(|| format!("Unknown gender: {}. Use m or f.", g))
+            });
+
             let country = country.to_uppercase();
-            let name = match registry.name(&country) {
-                Some(n) => n.to_string(),
+            let name = match registry
+                .name(&country)
+                .filter(|_| own_rules(kind::IdKind::PersonalId, &country, strict))
+            {
+                _ if mix.is_some() => String::new(),
+                Some(n) => n,
                 None => {
                     eprintln!("Unsupported country: {}", country);
                     let countries: Vec<_> = registry
                         .list_countries()
-                        .iter()
-                        .map(|(c, _, _)| *c)
+                        .into_iter()
+                        .map(|(c, _, _)| c)
                         .collect();
                     eprintln!("Supported: {}", countries.join(", "));
                     std::process::exit(1);
                 }
             };
 
-            let opts = personal_id::GenOptions {
+            let mut opts = personal_id::GenOptions {
                 gender: personal_id::date::Gender::from_str_opt(gender.as_deref()),
                 year,
+                region,
+                strict,
             };
-
-            let mut out_csv: Option<Box<dyn Write>> = csv.as_deref().map(csv_writer);
-            if let Some(ref mut w) = out_csv {
-                writeln!(w, "{}", csv_fmt::ID_HEADER).unwrap();
-            } else {
-                let mut print_it = true;
-                #[cfg(feature = "json")]
-                if json.is_some() {
-                    print_it = false;
-                }
-                if print_it {
-                    println!("{} - {}:", country, name);
+            if let (Some(region), Some(effective)) =
+                (opts.region.as_deref(), registry.effective_country(&country))
+            {
+                let known = subdivisions::of(&effective);
+                if !known.is_empty() && subdivisions::lookup(&effective, region).is_none() {
+                    eprintln!("Unknown region for {}: {}", country, region);
+                    let codes: Vec<_> = known.iter().map(|s| s.code).collect();
+                    eprintln!("Supported: {}", codes.join(", "));
+                    std::process::exit(1);
                 }
             }
 
-            #[cfg(feature = "json")]
-            let mut json_results = Vec::new();
-
+            let mut out = Output::for_rows::<personal_id::IdResult>(&output, &sql_opts);
+            if out.is_plain() && mix.is_none() {
+                println!("{} - {}:", country, name);
+            }
             for _ in 0..count {
-                let code = registry.generate(&country, &opts, &mut rng).unwrap();
-                let parsed = registry.parse(&country, &code).unwrap();
-
-                #[cfg(feature = "json")]
-                if json.is_some() {
-                    json_results.push(parsed.clone());
+                let country = match &mix {
+                    Some(mix) => mix.pick(&mut rng),
+                    None => &country,
+                };
+                if let Some(genders) = &genders {
+                    opts.gender = Some(*genders.pick(&mut rng));
                 }
+                let code = registry.generate(country, &opts, &mut rng).unwrap();
+                let parsed = registry.parse(country, &code).unwrap();
 
-                if let Some(ref mut w) = out_csv {
-                    writeln!(w, "{}", csv_fmt::id_row(&country, &name, &parsed)).unwrap();
-                } else {
-                    let mut print_it = true;
-                    #[cfg(feature = "json")]
-                    if json.is_some() {
-                        print_it = false;
-                    }
-
-                    if print_it {
-                        let mut parts = Vec::new();
-                        if let Some(ref g) = parsed.gender {
-                            parts.push(g.clone());
-                        }
-                        if let Some(ref dob) = parsed.dob {
-                            parts.push(dob.clone());
-                        }
-                        parts.push(format!(
-                            "valid: {}",
-                            if parsed.valid { "True" } else { "False" }
-                        ));
-                        println!("  {}  ({})", parsed.code, parts.join(", "));
-                    }
+                if !out.is_plain() {
+                    out.write(&parsed);
+                    continue;
                 }
-            }
-
-            #[cfg(feature = "json")]
-            if let Some(path) = json.as_deref() {
-                let mut w = csv_writer(path);
-                serde_json::to_writer_pretty(&mut w, &json_results).unwrap();
-                if path != "-" {
-                    eprintln!("Wrote {} rows to {}", count, path);
+                let mut parts = Vec::new();
+                if mix.is_some() {
+                    parts.push(parsed.country_code.clone());
                 }
-            }
-
-            if let Some(path) = csv.as_deref() {
-                if path != "-" {
-                    eprintln!("Wrote {} rows to {}", count, path);
+                if let Some(ref g) = parsed.gender {
+                    parts.push(g.clone());
                 }
+                if let Some(ref dob) = parsed.dob {
+                    parts.push(dob.clone());
+                }
+                if let Some(ref region) = parsed.region {
+                    parts.push(region.clone());
+                }
+                parts.push(format!(
+                    "valid: {}",
+                    if parsed.valid { "True" } else { "False" }
+                ));
+                println!("  {}  ({})", parsed.code, parts.join(", "));
             }
+            out.finish();
         }
         Commands::Card {
             count,
             brand,
+            brands,
             list,
-            csv,
-            json,
+            output,
         } => {
             let registry = credit_card::Registry::new();
 
@@ -576,604 +1099,482 @@ fn main() {
                 return;
             }
 
-            let opts = credit_card::GenOptions {
+            let supported = registry.list_brands();
+            let brands = weighted_mix(brands.as_deref(), |b| {
+                match supported.iter().find(|s| s.eq_ignore_ascii_case(b)) {
+                    Some(_) => Ok(b.to_lowercase()),
+                    None => Err(format!("Unsupported brand: {}", b)),
+                }
+            });
+            let mut opts = credit_card::GenOptions {
                 brand: brand.clone(),
                 current_year: None,
             };
 
-            let mut out_csv: Option<Box<dyn Write>> = csv.as_deref().map(csv_writer);
-            if let Some(ref mut w) = out_csv {
-                writeln!(w, "{}", csv_fmt::CARD_HEADER).unwrap();
-            }
-
-            #[cfg(feature = "json")]
-            let mut json_results = Vec::new();
-
+            let mut out = Output::for_rows::<credit_card::CardResult>(&output, &sql_opts);
             for _ in 0..count {
+                if let Some(brands) = &brands {
+                    opts.brand = Some(brands.pick(&mut rng).clone());
+                }
                 let result = match registry.generate(&opts, &mut rng) {
                     Some(r) => r,
                     None => {
-                        eprintln!("Unsupported brand: {}", brand.as_deref().unwrap_or(""));
+                        eprintln!("Unsupported brand: {}", opts.brand.as_deref().unwrap_or(""));
                         std::process::exit(1);
                     }
                 };
 
-                #[cfg(feature = "json")]
-                if json.is_some() {
-                    json_results.push(result.clone());
-                }
-
-                if let Some(ref mut w) = out_csv {
-                    writeln!(w, "{}", csv_fmt::card_row(&result)).unwrap();
+                if out.is_plain() {
+                    println!(
+                        "{} ({}): {}  CVV: {}  Exp: {}  (valid: {})",
+                        result.brand,
+                        result.formatted,
+                        result.number,
+                        result.cvv,
+                        result.expiry,
+                        result.valid
+                    );
                 } else {
-                    let mut print_it = true;
-                    #[cfg(feature = "json")]
-                    if json.is_some() {
-                        print_it = false;
-                    }
-
-                    if print_it {
-                        println!(
-                            "{} ({}): {}  CVV: {}  Exp: {}  (valid: {})",
-                            result.brand,
-                            result.formatted,
-                            result.number,
-                            result.cvv,
-                            result.expiry,
-                            result.valid
-                        );
-                    }
-                }
-            }
-
-            #[cfg(feature = "json")]
-            if let Some(path) = json.as_deref() {
-                let mut w = csv_writer(path);
-                serde_json::to_writer_pretty(&mut w, &json_results).unwrap();
-                if path != "-" {
-                    eprintln!("Wrote {} rows to {}", count, path);
-                }
-            }
-
-            if let Some(path) = csv.as_deref() {
-                if path != "-" {
-                    eprintln!("Wrote {} rows to {}", count, path);
+                    out.write(&result);
                 }
             }
+            out.finish();
         }
         Commands::Swift {
             count,
             country,
-            csv,
-            json,
+            countries,
+            output,
         } => {
             let registry = swift::Registry::new();
-            let opts = swift::GenOptions {
+            let mix = country_mix(countries.as_deref(), kind::IdKind::Swift, strict);
+            let mut opts = swift::GenOptions {
                 country: country.clone(),
             };
 
-            let mut out_csv: Option<Box<dyn Write>> = csv.as_deref().map(csv_writer);
-            if let Some(ref mut w) = out_csv {
-                writeln!(w, "{}", csv_fmt::SWIFT_HEADER).unwrap();
-            }
-
-            #[cfg(feature = "json")]
-            let mut json_results = Vec::new();
-
+            let mut out = Output::for_rows::<swift::SwiftResult>(&output, &sql_opts);
             for _ in 0..count {
-                let result = registry.generate(&opts, &mut rng);
-
-                #[cfg(feature = "json")]
-                if json.is_some() {
-                    json_results.push(result.clone());
+                if let Some(mix) = &mix {
+                    opts.country = Some(mix.pick(&mut rng).clone());
                 }
+                let result = registry.generate(&opts, &mut rng);
 
-                if let Some(ref mut w) = out_csv {
-                    writeln!(w, "{}", csv_fmt::swift_row(&result)).unwrap();
+                if out.is_plain() {
+                    println!(
+                        "{} ({}): {}  (valid: {})",
+                        result.bank, result.country, result.code, result.valid
+                    );
                 } else {
-                    let mut print_it = true;
-                    #[cfg(feature = "json")]
-                    if json.is_some() {
-                        print_it = false;
-                    }
-
-                    if print_it {
-                        println!(
-                            "{} ({}): {}  (valid: {})",
-                            result.bank, result.country, result.code, result.valid
-                        );
-                    }
-                }
-            }
-
-            #[cfg(feature = "json")]
-            if let Some(path) = json.as_deref() {
-                let mut w = csv_writer(path);
-                serde_json::to_writer_pretty(&mut w, &json_results).unwrap();
-                if path != "-" {
-                    eprintln!("Wrote {} rows to {}", count, path);
-                }
-            }
-
-            if let Some(path) = csv.as_deref() {
-                if path != "-" {
-                    eprintln!("Wrote {} rows to {}", count, path);
+                    out.write(&result);
                 }
             }
+            out.finish();
         }
         Commands::Company {
             count,
             country,
+            countries,
             list,
-            csv,
-            json,
+            output,
         } => {
-            let registry = company_id::Registry::new();
+            let registry = idsmith::company_ids();
 
             if list {
                 println!("{:<6} {:<25} ID Name", "Code", "Country");
                 println!("{}", "-".repeat(50));
-                for (code, country_name, name) in registry.list_countries() {
+                let countries = match locale {
+                    Some(locale) => registry.list_countries_in(locale),
+                    None => registry.list_countries(),
+                };
+                for (code, country_name, name) in countries {
                     println!("{:<6} {:<25} {}", code, country_name, name);
                 }
                 return;
             }
 
-            let opts = company_id::GenOptions {
+            let mix = country_mix(countries.as_deref(), kind::IdKind::CompanyId, strict);
+            let mut opts = company_id::GenOptions {
                 country: country.clone(),
+                strict,
             };
 
-            let mut out_csv: Option<Box<dyn Write>> = csv.as_deref().map(csv_writer);
-            if let Some(ref mut w) = out_csv {
-                writeln!(w, "{}", csv_fmt::COMPANY_HEADER).unwrap();
-            }
-
-            #[cfg(feature = "json")]
-            let mut json_results = Vec::new();
-
+            let mut out = Output::for_rows::<company_id::CompanyResult>(&output, &sql_opts);
             for _ in 0..count {
+                if let Some(mix) = &mix {
+                    opts.country = Some(mix.pick(&mut rng).clone());
+                }
                 let result = match registry.generate(&opts, &mut rng) {
                     Some(r) => r,
                     None => {
-                        eprintln!("Unsupported country: {}", country.as_deref().unwrap_or(""));
+                        eprintln!(
+                            "Unsupported country: {}",
+                            opts.country.as_deref().unwrap_or("")
+                        );
                         std::process::exit(1);
                     }
                 };
 
-                #[cfg(feature = "json")]
-                if json.is_some() {
-                    json_results.push(result.clone());
-                }
-
-                if let Some(ref mut w) = out_csv {
-                    writeln!(w, "{}", csv_fmt::company_row(&result)).unwrap();
+                if out.is_plain() {
+                    println!(
+                        "{} - {} - {}: {}  (valid: {})",
+                        result.country_code,
+                        result.country_name,
+                        result.name,
+                        result.code,
+                        result.valid
+                    );
                 } else {
-                    let mut print_it = true;
-                    #[cfg(feature = "json")]
-                    if json.is_some() {
-                        print_it = false;
-                    }
-
-                    if print_it {
-                        println!(
-                            "{} - {} - {}: {}  (valid: {})",
-                            result.country_code,
-                            result.country_name,
-                            result.name,
-                            result.code,
-                            result.valid
-                        );
-                    }
-                }
-            }
-
-            #[cfg(feature = "json")]
-            if let Some(path) = json.as_deref() {
-                let mut w = csv_writer(path);
-                serde_json::to_writer_pretty(&mut w, &json_results).unwrap();
-                if path != "-" {
-                    eprintln!("Wrote {} rows to {}", count, path);
-                }
-            }
-
-            if let Some(path) = csv.as_deref() {
-                if path != "-" {
-                    eprintln!("Wrote {} rows to {}", count, path);
+                    out.write(&result);
                 }
             }
+            out.finish();
         }
         Commands::License {
             count,
             country,
+            countries,
             state,
             list,
-            csv,
-            json,
+            output,
         } => {
             let registry = driver_license::Registry::new();
 
             if list {
                 println!("{:<6} {:<25} ID Name", "Code", "Country");
                 println!("{}", "-".repeat(50));
-                for (code, country_name, name) in registry.list_countries() {
+                let countries = match locale {
+                    Some(locale) => registry.list_countries_in(locale),
+                    None => registry.list_countries(),
+                };
+                for (code, country_name, name) in countries {
                     println!("{:<6} {:<25} {}", code, country_name, name);
                 }
                 return;
             }
 
-            let opts = driver_license::GenOptions {
+            let mix = country_mix(countries.as_deref(), kind::IdKind::DriverLicense, strict);
+            let mut opts = driver_license::GenOptions {
                 country: country.clone(),
                 state,
+                strict,
             };
 
-            let mut out_csv: Option<Box<dyn Write>> = csv.as_deref().map(csv_writer);
-            if let Some(ref mut w) = out_csv {
-                writeln!(w, "{}", csv_fmt::LICENSE_HEADER).unwrap();
-            }
-
-            #[cfg(feature = "json")]
-            let mut json_results = Vec::new();
-
+            let mut out =
+                Output::for_rows::<driver_license::DriverLicenseResult>(&output, &sql_opts);
             for _ in 0..count {
+                if let Some(mix) = &mix {
+                    opts.country = Some(mix.pick(&mut rng).clone());
+                }
                 let result = match registry.generate(&opts, &mut rng) {
                     Some(r) => r,
                     None => {
-                        eprintln!("Unsupported country: {}", country.as_deref().unwrap_or(""));
+                        let country = opts.country.as_deref().unwrap_or("");
+                        match opts.state.as_deref() {
+                            Some(state) => {
+                                eprintln!("Unsupported country or state: {} {}", country, state)
+                            }
+                            None => eprintln!("Unsupported country: {}", country),
+                        }
                         std::process::exit(1);
                     }
                 };
 
-                #[cfg(feature = "json")]
-                if json.is_some() {
-                    json_results.push(result.clone());
-                }
-
-                if let Some(ref mut w) = out_csv {
-                    writeln!(w, "{}", csv_fmt::license_row(&result)).unwrap();
+                if out.is_plain() {
+                    println!(
+                        "{} - {} - {}: {}  (valid: {})",
+                        result.country_code,
+                        result.country_name,
+                        result.name,
+                        result.code,
+                        result.valid
+                    );
                 } else {
-                    let mut print_it = true;
-                    #[cfg(feature = "json")]
-                    if json.is_some() {
-                        print_it = false;
-                    }
-
-                    if print_it {
-                        println!(
-                            "{} - {} - {}: {}  (valid: {})",
-                            result.country_code,
-                            result.country_name,
-                            result.name,
-                            result.code,
-                            result.valid
-                        );
-                    }
-                }
-            }
-
-            #[cfg(feature = "json")]
-            if let Some(path) = json.as_deref() {
-                let mut w = csv_writer(path);
-                serde_json::to_writer_pretty(&mut w, &json_results).unwrap();
-                if path != "-" {
-                    eprintln!("Wrote {} rows to {}", count, path);
-                }
-            }
-
-            if let Some(path) = csv.as_deref() {
-                if path != "-" {
-                    eprintln!("Wrote {} rows to {}", count, path);
+                    out.write(&result);
                 }
             }
+            out.finish();
         }
         Commands::Tax {
             count,
             country,
+            countries,
             holder_type,
+            holder_types,
             list,
-            csv,
-            json,
+            output,
         } => {
             let registry = tax_id::Registry::new();
 
             if list {
                 println!("{:<6} {:<25} ID Name", "Code", "Country");
                 println!("{}", "-".repeat(50));
-                for (code, country_name, name) in registry.list_countries() {
+                let countries = match locale {
+                    Some(locale) => registry.list_countries_in(locale),
+                    None => registry.list_countries(),
+                };
+                for (code, country_name, name) in countries {
                     println!("{:<6} {:<25} {}", code, country_name, name);
                 }
                 return;
             }
 
-            let opts = tax_id::GenOptions {
+            let mix = country_mix(countries.as_deref(), kind::IdKind::TaxId, strict);
+            let targets: Vec<String> = match (&mix, &country) {
+                (Some(mix), _) => mix.entries().iter().map(|(c, _)| c.clone()).collect(),
+                (None, Some(c)) => vec![c.clone()],
+                (None, None) => registry
+                    .list_countries()
+                    .into_iter()
+                    .map(|(c, _, _)| c.to_string())
+                    .collect(),
+            };
+            let mut allowed: Vec<String> = Vec::new();
+            for h in targets
+                .iter()
+                .filter_map(|c| registry.holder_types(c))
+                .flatten()
+            {
+                if !allowed.contains(&h) {
+                    allowed.push(h);
+                }
+            }
+            let holder = |h: &str| match allowed.iter().find(|a| a.eq_ignore_ascii_case(h)) {
+                Some(a) => Ok(a.clone()),
+                None if allowed.is_empty() => Err(format!(
+                    "Holder types are not supported for {}",
+                    targets.join(", ")
+                )),
+                None => Err(format!(
+                    "Unsupported holder type: {}. Use {}.",
+                    h,
+                    allowed.join(", ")
+                )),
+            };
+            let holder_type = holder_type.map(|h| {
+                holder(&h).unwrap_or_else(|e| {
+                    eprintln!("Error: {}", e);
+                    std::process::exit(1);
+                })
+            });
+            let holder_types = weighted_mix(holder_types.as_deref(), holder);
+            let mut opts = tax_id::GenOptions {
                 country: country.clone(),
                 holder_type,
+                strict,
             };
 
-            let mut out_csv: Option<Box<dyn Write>> = csv.as_deref().map(csv_writer);
-            if let Some(ref mut w) = out_csv {
-                writeln!(w, "{}", csv_fmt::TAX_HEADER).unwrap();
-            }
-
-            #[cfg(feature = "json")]
-            let mut json_results = Vec::new();
-
+            let mut out = Output::for_rows::<tax_id::TaxIdResult>(&output, &sql_opts);
             for _ in 0..count {
+                if let Some(mix) = &mix {
+                    opts.country = Some(mix.pick(&mut rng).clone());
+                }
+                if let Some(holder_types) = &holder_types {
+                    opts.holder_type = Some(holder_types.pick(&mut rng).clone());
+                }
                 let result = match registry.generate(&opts, &mut rng) {
                     Some(r) => r,
                     None => {
-                        eprintln!("Unsupported country: {}", country.as_deref().unwrap_or(""));
+                        eprintln!(
+                            "Unsupported country: {}",
+                            opts.country.as_deref().unwrap_or("")
+                        );
                         std::process::exit(1);
                     }
                 };
 
-                #[cfg(feature = "json")]
-                if json.is_some() {
-                    json_results.push(result.clone());
-                }
-
-                if let Some(ref mut w) = out_csv {
-                    writeln!(w, "{}", csv_fmt::tax_row(&result)).unwrap();
+                if out.is_plain() {
+                    println!(
+                        "{} - {} - {}: {}  (valid: {})",
+                        result.country_code,
+                        result.country_name,
+                        result.name,
+                        result.code,
+                        result.valid
+                    );
                 } else {
-                    let mut print_it = true;
-                    #[cfg(feature = "json")]
-                    if json.is_some() {
-                        print_it = false;
-                    }
-
-                    if print_it {
-                        println!(
-                            "{} - {} - {}: {}  (valid: {})",
-                            result.country_code,
-                            result.country_name,
-                            result.name,
-                            result.code,
-                            result.valid
-                        );
-                    }
-                }
-            }
-
-            #[cfg(feature = "json")]
-            if let Some(path) = json.as_deref() {
-                let mut w = csv_writer(path);
-                serde_json::to_writer_pretty(&mut w, &json_results).unwrap();
-                if path != "-" {
-                    eprintln!("Wrote {} rows to {}", count, path);
-                }
-            }
-
-            if let Some(path) = csv.as_deref() {
-                if path != "-" {
-                    eprintln!("Wrote {} rows to {}", count, path);
+                    out.write(&result);
                 }
             }
+            out.finish();
         }
         Commands::Passport {
             count,
             country,
+            countries,
             list,
-            csv,
-            json,
+            output,
         } => {
             let registry = passport::Registry::new();
 
             if list {
                 println!("{:<6} {:<25} ID Name", "Code", "Country");
                 println!("{}", "-".repeat(50));
-                for (code, country_name, name) in registry.list_countries() {
+                let countries = match locale {
+                    Some(locale) => registry.list_countries_in(locale),
+                    None => registry.list_countries(),
+                };
+                for (code, country_name, name) in countries {
                     println!("{:<6} {:<25} {}", code, country_name, name);
                 }
                 return;
             }
 
-            let opts = passport::GenOptions {
+            let mix = country_mix(countries.as_deref(), kind::IdKind::Passport, strict);
+            let mut opts = passport::GenOptions {
                 country: country.clone(),
+                strict,
             };
 
-            let mut out_csv: Option<Box<dyn Write>> = csv.as_deref().map(csv_writer);
-            if let Some(ref mut w) = out_csv {
-                writeln!(w, "{}", csv_fmt::PASSPORT_HEADER).unwrap();
-            }
-
-            #[cfg(feature = "json")]
-            let mut json_results = Vec::new();
-
+            let mut out = Output::for_rows::<passport::PassportResult>(&output, &sql_opts);
             for _ in 0..count {
+                if let Some(mix) = &mix {
+                    opts.country = Some(mix.pick(&mut rng).clone());
+                }
                 let result = match registry.generate(&opts, &mut rng) {
                     Some(r) => r,
                     None => {
-                        eprintln!("Unsupported country: {}", country.as_deref().unwrap_or(""));
+                        eprintln!(
+                            "Unsupported country: {}",
+                            opts.country.as_deref().unwrap_or("")
+                        );
                         std::process::exit(1);
                     }
                 };
 
-                #[cfg(feature = "json")]
-                if json.is_some() {
-                    json_results.push(result.clone());
-                }
-
-                if let Some(ref mut w) = out_csv {
-                    writeln!(w, "{}", csv_fmt::passport_row(&result)).unwrap();
+                if out.is_plain() {
+                    println!(
+                        "{} - {} - {}: {}  (valid: {})",
+                        result.country_code,
+                        result.country_name,
+                        result.name,
+                        result.code,
+                        result.valid
+                    );
                 } else {
-                    let mut print_it = true;
-                    #[cfg(feature = "json")]
-                    if json.is_some() {
-                        print_it = false;
-                    }
-
-                    if print_it {
-                        println!(
-                            "{} - {} - {}: {}  (valid: {})",
-                            result.country_code,
-                            result.country_name,
-                            result.name,
-                            result.code,
-                            result.valid
-                        );
-                    }
-                }
-            }
-
-            #[cfg(feature = "json")]
-            if let Some(path) = json.as_deref() {
-                let mut w = csv_writer(path);
-                serde_json::to_writer_pretty(&mut w, &json_results).unwrap();
-                if path != "-" {
-                    eprintln!("Wrote {} rows to {}", count, path);
-                }
-            }
-
-            if let Some(path) = csv.as_deref() {
-                if path != "-" {
-                    eprintln!("Wrote {} rows to {}", count, path);
+                    out.write(&result);
                 }
             }
+            out.finish();
         }
         Commands::Vat {
             count,
             country,
+            countries,
             list,
-            csv,
-            json,
+            output,
         } => {
             let registry = vat::Registry::new();
 
             if list {
                 println!("{:<6} Country", "Code");
                 println!("{}", "-".repeat(40));
-                for (code, country_name) in registry.list_countries() {
+                let countries = match locale {
+                    Some(locale) => registry.list_countries_in(locale),
+                    None => registry.list_countries().to_vec(),
+                };
+                for (code, country_name) in countries {
                     println!("{:<6} {}", code, country_name);
                 }
                 return;
             }
 
-            let opts = vat::GenOptions {
+            let mix = country_mix(countries.as_deref(), kind::IdKind::Vat, strict);
+            let mut opts = vat::GenOptions {
                 country: country.clone(),
+                strict,
             };
 
-            let mut out_csv: Option<Box<dyn Write>> = csv.as_deref().map(csv_writer);
-            if let Some(ref mut w) = out_csv {
-                writeln!(w, "{}", csv_fmt::VAT_HEADER).unwrap();
-            }
-
-            #[cfg(feature = "json")]
-            let mut json_results = Vec::new();
-
+            let mut out = Output::for_rows::<vat::VatResult>(&output, &sql_opts);
             for _ in 0..count {
+                if let Some(mix) = &mix {
+                    opts.country = Some(mix.pick(&mut rng).clone());
+                }
                 let result = match registry.generate(&opts, &mut rng) {
                     Some(r) => r,
                     None => {
-                        eprintln!("Unsupported country: {}", country.as_deref().unwrap_or(""));
+                        eprintln!(
+                            "Unsupported country: {}",
+                            opts.country.as_deref().unwrap_or("")
+                        );
                         std::process::exit(1);
                     }
                 };
 
-                #[cfg(feature = "json")]
-                if json.is_some() {
-                    json_results.push(result.clone());
-                }
-
-                if let Some(ref mut w) = out_csv {
-                    writeln!(w, "{}", csv_fmt::vat_row(&result)).unwrap();
+                if out.is_plain() {
+                    println!(
+                        "{} - {}: {}  (valid: {})",
+                        result.country_code, result.country_name, result.code, result.valid
+                    );
                 } else {
-                    let mut print_it = true;
-                    #[cfg(feature = "json")]
-                    if json.is_some() {
-                        print_it = false;
-                    }
-
-                    if print_it {
-                        println!(
-                            "{} - {}: {}  (valid: {})",
-                            result.country_code, result.country_name, result.code, result.valid
-                        );
-                    }
-                }
-            }
-
-            #[cfg(feature = "json")]
-            if let Some(path) = json.as_deref() {
-                let mut w = csv_writer(path);
-                serde_json::to_writer_pretty(&mut w, &json_results).unwrap();
-                if path != "-" {
-                    eprintln!("Wrote {} rows to {}", count, path);
-                }
-            }
-
-            if let Some(path) = csv.as_deref() {
-                if path != "-" {
-                    eprintln!("Wrote {} rows to {}", count, path);
+                    out.write(&result);
                 }
             }
+            out.finish();
         }
         Commands::Lei {
             count,
             country,
-            csv,
-            json,
+            countries,
+            output,
         } => {
             let registry = lei::Registry::new();
-            let opts = lei::GenOptions {
+            let mix = country_mix(countries.as_deref(), kind::IdKind::Lei, strict);
+            let mut opts = lei::GenOptions {
                 country: country.clone(),
             };
 
-            let mut out_csv: Option<Box<dyn Write>> = csv.as_deref().map(csv_writer);
-            if let Some(ref mut w) = out_csv {
-                writeln!(w, "{}", csv_fmt::LEI_HEADER).unwrap();
-            }
-
-            #[cfg(feature = "json")]
-            let mut json_results = Vec::new();
-
+            let mut out = Output::for_rows::<lei::LeiResult>(&output, &sql_opts);
             for _ in 0..count {
-                let result = registry.generate(&opts, &mut rng);
-
-                #[cfg(feature = "json")]
-                if json.is_some() {
-                    json_results.push(result.clone());
+                if let Some(mix) = &mix {
+                    opts.country = Some(mix.pick(&mut rng).clone());
                 }
+                let result = registry.generate(&opts, &mut rng);
 
-                if let Some(ref mut w) = out_csv {
-                    writeln!(w, "{}", csv_fmt::lei_row(&result)).unwrap();
+                if out.is_plain() {
+                    println!(
+                        "{} (LOU: {}, Country: {})  (valid: {})",
+                        result.code, result.lou, result.country_code, result.valid
+                    );
                 } else {
-                    let mut print_it = true;
-                    #[cfg(feature = "json")]
-                    if json.is_some() {
-                        print_it = false;
-                    }
-
-                    if print_it {
-                        println!(
-                            "{} (LOU: {}, Country: {})  (valid: {})",
-                            result.code, result.lou, result.country_code, result.valid
-                        );
-                    }
-                }
-            }
-
-            #[cfg(feature = "json")]
-            if let Some(path) = json.as_deref() {
-                let mut w = csv_writer(path);
-                serde_json::to_writer_pretty(&mut w, &json_results).unwrap();
-                if path != "-" {
-                    eprintln!("Wrote {} rows to {}", count, path);
-                }
-            }
-
-            if let Some(path) = csv.as_deref() {
-                if path != "-" {
-                    eprintln!("Wrote {} rows to {}", count, path);
+                    out.write(&result);
                 }
             }
+            out.finish();
         }
         Commands::Validate {
             category,
             code,
             country,
+            file,
+            column,
+            country_column,
+            output,
         } => {
+            let Some(code) = code else {
+                let input = BatchInput {
+                    path: file.unwrap_or_else(|| "-".into()),
+                    column,
+                    country_column,
+                };
+                let status = validate_file(&category, country, &input, &output, &sql_opts, strict);
+                std::process::exit(status);
+            };
             let cat = category.to_lowercase();
             let country = country.map(|c| c.to_uppercase());
 
+            if strict {
+                if let (Ok(kind), Some(cc)) = (cat.parse::<kind::IdKind>(), country.as_deref()) {
+                    if !own_rules(kind, cc, true) {
+                        eprintln!("Error: {} has no {} rules of its own (--strict)", cc, cat);
+                        std::process::exit(1);
+                    }
+                }
+            }
+
+            let target = cat
+                .parse::<kind::IdKind>()
+                .ok()
+                .map(|kind| kind::Target::new(kind, country.as_deref()));
             let valid = match cat.as_str() {
                 "iban" => iban::validate_iban(&code),
                 "account" => {
@@ -1190,7 +1591,7 @@ fn main() {
                         eprintln!("Error: --country is required for personal ID validation");
                         std::process::exit(1);
                     });
-                    personal_id::Registry::new()
+                    idsmith::personal_ids()
                         .validate(&cc, &code)
                         .unwrap_or(false)
                 }
@@ -1201,7 +1602,7 @@ fn main() {
                         eprintln!("Error: --country is required for company ID validation");
                         std::process::exit(1);
                     });
-                    company_id::Registry::new().validate(&cc, &code)
+                    idsmith::company_ids().validate(&cc, &code)
                 }
                 "license" => {
                     let cc = country.unwrap_or_else(|| {
@@ -1239,6 +1640,486 @@ fn main() {
                 println!("TRUE: {} is a valid {} code", code, cat);
             } else {
                 println!("FALSE: {} is NOT a valid {} code", code, cat);
+                if let Some(target) = target {
+                    let outcome = idsmith::batch::validate(&target, &code);
+                    if outcome.reason == Some(idsmith::batch::Failure::Formatting) {
+                        println!("Note: valid when written as {}", outcome.normalized);
+                    }
+                }
+                std::process::exit(1);
+            }
+        }
+        Commands::Anonymize {
+            input,
+            columns,
+            key_file,
+            detect,
+            output,
+        } => {
+            let columns: Vec<(String, kind::Target)> = columns
+                .iter()
+                .map(|spec| {
+                    let parsed = spec
+                        .split_once('=')
+                        .ok_or_else(|| format!("expected NAME=KIND, got {}", spec))
+                        .and_then(|(name, target)| Ok((name.to_string(), target.parse()?)));
+                    parsed.unwrap_or_else(|e| {
+                        eprintln!("Invalid --column {}: {}", spec, e);
+                        std::process::exit(1);
+                    })
+                })
+                .collect();
+            if columns.is_empty() && !detect {
+                eprintln!("Error: pass at least one --column or use --detect");
+                std::process::exit(1);
+            }
+
+            let key = match key_file {
+                Some(path) => std::fs::read(&path).unwrap_or_else(|e| {
+                    eprintln!("Cannot read {}: {}", path, e);
+                    std::process::exit(1);
+                }),
+                None => rng.gen::<[u8; 32]>().to_vec(),
+            };
+            let anonymizer = anonymize::Anonymizer::new(&key);
+
+            let reader: Box<dyn std::io::BufRead> = if input == "-" {
+                Box::new(std::io::stdin().lock())
+            } else {
+                let file = std::fs::File::open(&input).unwrap_or_else(|e| {
+                    eprintln!("Cannot open {}: {}", input, e);
+                    std::process::exit(1);
+                });
+                Box::new(std::io::BufReader::new(file))
+            };
+            let mut writer = std::io::BufWriter::new(csv_writer(output.as_deref().unwrap_or("-")));
+            let sample = if detect { 200 } else { 0 };
+
+            match anonymize::anonymize_csv(reader, &mut writer, &anonymizer, &columns, sample) {
+                Ok(summary) => {
+                    writer.flush().unwrap();
+                    for (name, target) in &summary.columns {
+                        eprintln!("{} -> {}", name, target);
+                    }
+                    if summary.blanked > 0 {
+                        eprintln!(
+                            "{} values could not be replaced and were left empty",
+                            summary.blanked
+                        );
+                    }
+                    if let Some(path) = output.as_deref() {
+                        eprintln!("Wrote {} rows to {}", summary.rows, path);
+                    }
+                }
+                Err(e) => {
+                    eprintln!("Error: {}", e);
+                    std::process::exit(1);
+                }
+            }
+        }
+        Commands::ProfileFile {
+            input,
+            columns,
+            #[cfg(feature = "json")]
+            json,
+        } => {
+            let reader: Box<dyn std::io::BufRead> = if input == "-" {
+                Box::new(std::io::stdin().lock())
+            } else {
+                let file = std::fs::File::open(&input).unwrap_or_else(|e| {
+                    eprintln!("Cannot open {}: {}", input, e);
+                    std::process::exit(1);
+                });
+                Box::new(std::io::BufReader::new(file))
+            };
+            let mut profiles = profile::profile_csv(reader).unwrap_or_else(|e| {
+                eprintln!("Error: {}", e);
+                std::process::exit(1);
+            });
+            if !columns.is_empty() {
+                if let Some(name) = columns
+                    .iter()
+                    .find(|c| !profiles.iter().any(|p| &p.name == *c))
+                {
+                    eprintln!("Error: No such column: {}", name);
+                    std::process::exit(1);
+                }
+                profiles.retain(|p| columns.contains(&p.name));
+            }
+
+            #[cfg(feature = "json")]
+            if let Some(path) = json.as_deref() {
+                let mut w = csv_writer(path);
+                serde_json::to_writer_pretty(&mut w, &profiles).unwrap();
+                writeln!(w).unwrap();
+                return;
+            }
+
+            let list = |counts: Vec<(String, usize)>| -> String {
+                counts
+                    .iter()
+                    .map(|(name, n)| format!("{} {}", name, n))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            };
+            for (i, p) in profiles.iter().enumerate() {
+                if i > 0 {
+                    println!();
+                }
+                println!("{} ({} rows, {} empty)", p.name, p.rows, p.empty);
+                let (Some(kind), Some(validity)) = (p.kind, p.validity.as_ref()) else {
+                    println!("  Kind:        not detected");
+                    continue;
+                };
+                match &p.country {
+                    Some(c) if p.candidates.len() > 1 => println!(
+                        "  Kind:        {} ({}, ambiguous with {})",
+                        kind,
+                        c,
+                        p.candidates[1..].join(", ")
+                    ),
+                    Some(c) if p.countries.len() <= 1 => {
+                        println!("  Kind:        {} ({})", kind, c)
+                    }
+                    _ => println!("  Kind:        {}", kind),
+                }
+                if !p.countries.is_empty() {
+                    let mut countries: Vec<_> =
+                        p.countries.iter().map(|(c, n)| (c.clone(), *n)).collect();
+                    countries.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+                    println!("  Countries:   {}", list(countries));
+                }
+                let rate = if validity.total == 0 {
+                    0.0
+                } else {
+                    validity.valid as f64 * 100.0 / validity.total as f64
+                };
+                println!(
+                    "  Valid:       {} of {} ({:.1}%)",
+                    validity.valid, validity.total, rate
+                );
+                if !validity.reasons.is_empty() {
+                    let reasons = validity
+                        .reasons
+                        .iter()
+                        .map(|(r, n)| (r.to_string(), *n))
+                        .collect();
+                    println!("  Failures:    {}", list(reasons));
+                }
+                let variants = p
+                    .variants
+                    .counts()
+                    .into_iter()
+                    .map(|(v, n)| (v.to_string(), n))
+                    .collect();
+                println!("  Formats:     {}", list(variants));
+                println!("  Duplicates:  {} ({} distinct)", p.duplicates, p.distinct);
+                if !p.birth_decades.is_empty() {
+                    let decades = p
+                        .birth_decades
+                        .iter()
+                        .map(|(d, n)| (d.clone(), *n))
+                        .collect();
+                    println!("  Born:        {}", list(decades));
+                }
+                if !p.genders.is_empty() {
+                    let genders = p.genders.iter().map(|(g, n)| (g.clone(), *n)).collect();
+                    println!("  Genders:     {}", list(genders));
+                }
+            }
+        }
+        Commands::Scan {
+            files,
+            redact,
+            countries,
+        } => {
+            let policy: Option<scan::RedactPolicy> = redact.map(|p| {
+                p.parse().unwrap_or_else(|e| {
+                    eprintln!("{}", e);
+                    std::process::exit(1);
+                })
+            });
+            let opts = scan::ScanOptions {
+                countries,
+                ..Default::default()
+            };
+            let files = if files.is_empty() {
+                vec!["-".to_string()]
+            } else {
+                files
+            };
+
+            let stdout = std::io::stdout();
+            let mut out = std::io::BufWriter::new(stdout.lock());
+            let mut total = 0usize;
+            for path in &files {
+                let mut reader: Box<dyn std::io::BufRead> = if path == "-" {
+                    Box::new(std::io::stdin().lock())
+                } else {
+                    let file = std::fs::File::open(path).unwrap_or_else(|e| {
+                        eprintln!("Cannot open {}: {}", path, e);
+                        std::process::exit(1);
+                    });
+                    Box::new(std::io::BufReader::new(file))
+                };
+
+                let mut buf = Vec::new();
+                let mut line_no = 0usize;
+                loop {
+                    buf.clear();
+                    match reader.read_until(b'\n', &mut buf) {
+                        Ok(0) => break,
+                        Ok(_) => {}
+                        Err(e) => {
+                            eprintln!("Cannot read {}: {}", path, e);
+                            std::process::exit(1);
+                        }
+                    }
+                    line_no += 1;
+                    let line = String::from_utf8_lossy(&buf);
+                    let findings = scan::scan_with(&line, &opts);
+                    total += findings.len();
+                    match policy {
+                        Some(policy) => {
+                            let redacted = scan::redact_findings(&line, &findings, policy);
+                            out.write_all(redacted.as_bytes()).unwrap();
+                        }
+                        None => {
+                            for f in &findings {
+                                let target = kind::Target::new(f.kind, f.country.as_deref());
+                                let masked =
+                                    scan::mask(&f.text, f.kind, scan::RedactPolicy::KeepLast4);
+                                writeln!(
+                                    out,
+                                    "{}:{}:{}: {} {}",
+                                    path,
+                                    line_no,
+                                    f.start + 1,
+                                    target,
+                                    masked
+                                )
+                                .unwrap();
+                            }
+                        }
+                    }
+                }
+            }
+            out.flush().unwrap();
+
+            if policy.is_none() && total > 0 {
+                eprintln!("{} identifiers found", total);
+                std::process::exit(1);
+            }
+        }
+        Commands::Coverage {
+            countries,
+            csv,
+            #[cfg(feature = "json")]
+            json,
+        } => {
+            let countries: Vec<String> = countries.iter().map(|c| c.to_uppercase()).collect();
+            let rows: Vec<coverage::CountryCoverage> = if countries.is_empty() {
+                coverage::coverage()
+            } else {
+                coverage::coverage()
+                    .into_iter()
+                    .filter(|c| countries.iter().any(|code| code == c.code))
+                    .collect()
+            };
+            let cell = |row: &coverage::CountryCoverage, kind: kind::IdKind| match row.get(kind) {
+                Some(s) => match (s.fidelity, s.parent) {
+                    (coverage::Fidelity::Alias, Some(parent)) => format!("alias:{}", parent),
+                    (fidelity, _) => fidelity.to_string(),
+                },
+                None => String::new(),
+            };
+
+            #[cfg(feature = "json")]
+            if let Some(path) = json.as_deref() {
+                let mut w = csv_writer(path);
+                serde_json::to_writer_pretty(&mut w, &rows).unwrap();
+                writeln!(w).unwrap();
+                return;
+            }
+
+            if let Some(path) = csv.as_deref() {
+                let mut w = csv_writer(path);
+                let mut header = vec!["country_code", "country_name"];
+                header.extend(coverage::COUNTRY_KINDS.iter().map(|k| k.as_str()));
+                writeln!(w, "{}", csv_fmt::record_row(&header)).unwrap();
+                for row in &rows {
+                    let mut fields = vec![row.code.to_string(), row.name.to_string()];
+                    fields.extend(coverage::COUNTRY_KINDS.iter().map(|&k| cell(row, k)));
+                    writeln!(w, "{}", csv_fmt::record_row(&fields)).unwrap();
+                }
+                return;
+            }
+
+            let mut header = format!("{:<6} {:<25}", "Code", "Country");
+            for kind in coverage::COUNTRY_KINDS {
+                header.push_str(&format!(" {:<12}", kind.as_str()));
+            }
+            println!("{}", header.trim_end());
+            println!("{}", "-".repeat(header.trim_end().len()));
+            for row in &rows {
+                let name: String = row.name.chars().take(25).collect();
+                let mut line = format!("{:<6} {:<25}", row.code, name);
+                for kind in coverage::COUNTRY_KINDS {
+                    let cell = cell(row, kind);
+                    line.push_str(&format!(
+                        " {:<12}",
+                        if cell.is_empty() { "-" } else { &cell }
+                    ));
+                }
+                println!("{}", line.trim_end());
+            }
+        }
+        Commands::Describe {
+            kind,
+            country,
+            #[cfg(feature = "json")]
+            json,
+        } => {
+            let kind: kind::IdKind = kind.parse().unwrap_or_else(|e| {
+                eprintln!("{}", e);
+                std::process::exit(1);
+            });
+            let Some(info) = describe::describe(kind, country.as_deref()) else {
+                match country {
+                    Some(c) => eprintln!("Unsupported country for {}: {}", kind, c),
+                    None => eprintln!("--country is required for {}", kind),
+                }
+                std::process::exit(1);
+            };
+
+            #[cfg(feature = "json")]
+            if let Some(path) = json.as_deref() {
+                let mut w = csv_writer(path);
+                serde_json::to_writer_pretty(&mut w, &info).unwrap();
+                writeln!(w).unwrap();
+                return;
+            }
+
+            let length = if info.min_length == info.max_length {
+                info.min_length.to_string()
+            } else {
+                format!("{}-{}", info.min_length, info.max_length)
+            };
+            let separators = if info.separators.is_empty() {
+                "none".to_string()
+            } else if info.separators.len() > 12 {
+                format!("{} characters (see --json)", info.separators.len())
+            } else {
+                info.separators
+                    .iter()
+                    .map(|c| format!("{:?}", c))
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            };
+            let yes_no = |b: bool| if b { "yes" } else { "no" };
+            println!("Name:       {}", info.name);
+            println!("Length:     {}", length);
+            println!("Charset:    {}", info.charset);
+            println!("Separators: {}", separators);
+            println!("Checksum:   {}", info.checksum.as_deref().unwrap_or("none"));
+            println!("Birth date: {}", yes_no(info.encodes_dob));
+            println!("Gender:     {}", yes_no(info.encodes_gender));
+            println!("Example:    {}", info.example);
+            println!("Regex:      {}", info.regex);
+        }
+        Commands::Explain {
+            kind,
+            code,
+            country,
+            #[cfg(feature = "json")]
+            json,
+        } => {
+            let kind: kind::IdKind = kind.parse().unwrap_or_else(|e| {
+                eprintln!("{}", e);
+                std::process::exit(1);
+            });
+            let explanation =
+                explain::explain(kind, country.as_deref(), &code).unwrap_or_else(|e| {
+                    eprintln!("Error: {}", e);
+                    std::process::exit(1);
+                });
+
+            #[cfg(feature = "json")]
+            if let Some(path) = json.as_deref() {
+                let mut w = csv_writer(path);
+                serde_json::to_writer_pretty(&mut w, &explanation).unwrap();
+                writeln!(w).unwrap();
+                return;
+            }
+
+            println!("{}", explanation);
+            if !explanation.valid {
+                std::process::exit(1);
+            }
+        }
+        Commands::Dataset {
+            schema,
+            rows,
+            mut output,
+        } => {
+            let schema = dataset::Schema::load(&schema).unwrap_or_else(|e| {
+                eprintln!("Error: {}", e);
+                std::process::exit(1);
+            });
+            let generator = schema.compile().unwrap_or_else(|e| {
+                eprintln!("Error: {}", e);
+                std::process::exit(1);
+            });
+            let fields: Vec<_> = generator
+                .headers()
+                .iter()
+                .map(|h| output::Field::new(h, output::FieldType::Text, true))
+                .collect();
+
+            // CSV on stdout unless another format was chosen
+            let mut out = Output::new(&output, fields.clone(), fields.clone(), &sql_opts);
+            if out.is_plain() {
+                output.csv = Some("-".into());
+                out = Output::new(&output, fields.clone(), fields, &sql_opts);
+            }
+            for _ in 0..rows.unwrap_or(schema.rows) {
+                let row = generator.row(&mut rng).unwrap_or_else(|e| {
+                    eprintln!("Error: {}", e);
+                    std::process::exit(1);
+                });
+                let values: Vec<_> = row.into_iter().map(output::Value::from).collect();
+                out.write_values(&values, None);
+            }
+            out.finish();
+        }
+        Commands::Serve { host, port } => {
+            let addr = format!("{}:{}", host, port);
+            eprintln!("Listening on http://{} (OpenAPI at /v1/openapi.json)", addr);
+            if let Err(e) = serve::run(&addr) {
+                eprintln!("Error: {}", e);
+                std::process::exit(1);
+            }
+        }
+        Commands::MockVies {
+            host,
+            port,
+            unavailable,
+            timeout,
+            invalid,
+            delay,
+        } => {
+            let addr = format!("{}:{}", host, port);
+            let location = format!("http://{}{}", addr, serve::vies::SOAP_PATH);
+            eprintln!("VIES mock listening on {} (WSDL at ?wsdl)", location);
+            let mock = serve::vies::Mock::new(serve::vies::Options {
+                unavailable,
+                timeout,
+                invalid,
+                delay: std::time::Duration::from_millis(delay),
+                location: Some(location),
+            });
+            if let Err(e) = serve::vies::run(&addr, mock) {
+                eprintln!("Error: {}", e);
                 std::process::exit(1);
             }
         }
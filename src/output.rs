@@ -0,0 +1,738 @@
+//! Tabular output of result types: CSV, pretty JSON, NDJSON, YAML, XML or a
+//! per-row template, with field selection.
+//!
+//! Enable with the `output` feature flag. Every result type implements
+//! [`Record`], whose fields have the same names and order as its JSON form.
+//! CSV without selected fields keeps the columns of the `csv` module, so
+//! existing CSV consumers see the same header; select fields to get the
+//! others.
+//!
+//! ```rust
+//! use idsmith::output::{Format, Options, Writer};
+//! use idsmith::personal_id::IdResult;
+//!
+//! let opts = Options {
+//!     format: Format::Template("{code};{country_code};{dob}".into()),
+//!     ..Default::default()
+//! };
+//! let mut writer = Writer::for_rows::<IdResult>(Vec::new(), opts).unwrap();
+//! let registry = idsmith::personal_ids();
+//! let code = registry
+//!     .generate("SE", &Default::default(), &mut rand::thread_rng())
+//!     .unwrap();
+//! writer.write(&registry.parse("SE", &code).unwrap()).unwrap();
+//! let text = String::from_utf8(writer.finish().unwrap()).unwrap();
+//! assert!(text.starts_with(&format!("{};SE;", code)));
+//! ```
+
+use std::borrow::Cow;
+use std::io::{self, Write};
+
+use crate::bank_account::AccountResult;
+use crate::batch::Outcome;
+use crate::company_id::CompanyResult;
+use crate::credit_card::CardResult;
+use crate::driver_license::DriverLicenseResult;
+use crate::iban::IbanResult;
+use crate::lei::LeiResult;
+use crate::passport::PassportResult;
+use crate::personal_id::IdResult;
+use crate::swift::SwiftResult;
+use crate::tax_id::TaxIdResult;
+use crate::vat::VatResult;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FieldType {
+    Text,
+    Bool,
+}
+
+/// A named field of a [`Record`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Field {
+    pub name: String,
+    pub ty: FieldType,
+    pub nullable: bool,
+}
+
+impl Field {
+    pub fn new(name: &str, ty: FieldType, nullable: bool) -> Self {
+        Self {
+            name: name.to_string(),
+            ty,
+            nullable,
+        }
+    }
+}
+
+/// A cell value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Value {
+    Null,
+    Text(String),
+    Bool(bool),
+}
+
+impl Value {
+    /// The value as plain text; empty for `Null`.
+    pub fn as_text(&self) -> Cow<'_, str> {
+        match self {
+            Value::Null => Cow::Borrowed(""),
+            Value::Text(s) => Cow::Borrowed(s),
+            Value::Bool(b) => Cow::Borrowed(if *b { "true" } else { "false" }),
+        }
+    }
+}
+
+impl From<Option<String>> for Value {
+    fn from(value: Option<String>) -> Self {
+        value.map_or(Value::Null, Value::Text)
+    }
+}
+
+/// A type that can be written as a row of named fields.
+pub trait Record {
+    fn fields() -> Vec<Field>;
+    /// Values in the order of [`Record::fields`].
+    fn values(&self) -> Vec<Value>;
+
+    /// Columns of CSV output without selected fields. Types with a layout in
+    /// the `csv` module keep its headers and order here; others use
+    /// [`Record::fields`].
+    fn csv_fields() -> Vec<Field> {
+        Self::fields()
+    }
+
+    /// Values in the order of [`Record::csv_fields`].
+    fn csv_values(&self) -> Vec<Value> {
+        self.values()
+    }
+}
+
+/// Struct field types that map to a [`Field`].
+trait RecordField {
+    const TYPE: FieldType;
+    const NULLABLE: bool = false;
+    fn value(&self) -> Value;
+}
+
+macro_rules! text_field {
+    ($($ty:ty),+) => {$(
+        impl RecordField for $ty {
+            const TYPE: FieldType = FieldType::Text;
+            fn value(&self) -> Value {
+                Value::Text(self.to_string())
+            }
+        }
+
+        impl RecordField for Option<$ty> {
+            const TYPE: FieldType = FieldType::Text;
+            const NULLABLE: bool = true;
+            fn value(&self) -> Value {
+                self.as_ref().map_or(Value::Null, |v| Value::Text(v.to_string()))
+            }
+        }
+    )+};
+}
+
+text_field!(
+    String,
+    crate::Iban,
+    crate::Bic,
+    crate::Lei,
+    crate::VatNumber,
+    crate::CardNumber,
+    crate::kind::IdKind,
+    crate::batch::Failure
+);
+
+impl RecordField for bool {
+    const TYPE: FieldType = FieldType::Bool;
+    fn value(&self) -> Value {
+        Value::Bool(*self)
+    }
+}
+
+fn field<S, T: RecordField>(name: &str, _field: fn(&S) -> &T) -> Field {
+    Field::new(name, T::TYPE, T::NULLABLE)
+}
+
+/// Implements [`Record`] with one field per listed struct field, and
+/// optionally CSV columns as `header = struct_field`.
+macro_rules! record {
+    ($ty:ty: $($field:ident),+ $(,)?) => {
+        impl Record for $ty {
+            fn fields() -> Vec<Field> {
+                vec![$(field(stringify!($field), |r: &$ty| &r.$field)),+]
+            }
+
+            fn values(&self) -> Vec<Value> {
+                vec![$(self.$field.value()),+]
+            }
+        }
+    };
+    ($ty:ty: $($field:ident),+; csv: $($header:ident = $source:ident),+ $(,)?) => {
+        impl Record for $ty {
+            fn fields() -> Vec<Field> {
+                vec![$(field(stringify!($field), |r: &$ty| &r.$field)),+]
+            }
+
+            fn values(&self) -> Vec<Value> {
+                vec![$(self.$field.value()),+]
+            }
+
+            fn csv_fields() -> Vec<Field> {
+                vec![$(field(stringify!($header), |r: &$ty| &r.$source)),+]
+            }
+
+            fn csv_values(&self) -> Vec<Value> {
+                vec![$(self.$source.value()),+]
+            }
+        }
+    };
+}
+
+record!(IbanResult: country, iban, formatted, valid;
+    csv: country = country, iban = iban, iban_formatted = formatted, valid = valid,
+);
+record!(Outcome: kind, country, code, normalized, valid, reason);
+record!(AccountResult:
+    country_code,
+    effective_country_code,
+    country_name,
+    format_name,
+    bank_code,
+    branch_code,
+    account_number,
+    check_digits,
+    formatted,
+    raw,
+    iban,
+    valid;
+    csv: country = country_code,
+    country_name = country_name,
+    format = format_name,
+    bank_code = bank_code,
+    branch_code = branch_code,
+    account_number = account_number,
+    check_digits = check_digits,
+    formatted = formatted,
+    raw = raw,
+    iban = iban,
+    valid = valid,
+);
+record!(CardResult: brand, number, formatted, cvv, expiry, valid);
+record!(SwiftResult: code, bank, country, location, branch, valid;
+    csv: country = country,
+    bank = bank,
+    location = location,
+    branch = branch,
+    code = code,
+    valid = valid,
+);
+record!(CompanyResult:
+    country_code,
+    effective_country_code,
+    country_name,
+    name,
+    code,
+    valid;
+    csv: country = country_code, id_name = name, code = code, valid = valid,
+);
+record!(DriverLicenseResult:
+    country_code,
+    effective_country_code,
+    country_name,
+    name,
+    code,
+    state,
+    valid;
+    csv: country = country_code,
+    country_name = country_name,
+    name = name,
+    code = code,
+    state = state,
+    valid = valid,
+);
+record!(TaxIdResult:
+    country_code,
+    effective_country_code,
+    country_name,
+    name,
+    code,
+    holder_type,
+    valid;
+    csv: country = country_code,
+    country_name = country_name,
+    name = name,
+    code = code,
+    holder_type = holder_type,
+    valid = valid,
+);
+record!(PassportResult:
+    country_code,
+    effective_country_code,
+    country_name,
+    name,
+    code,
+    valid;
+    csv: country = country_code,
+    country_name = country_name,
+    name = name,
+    code = code,
+    valid = valid,
+);
+record!(LeiResult: code, lou, country_code, valid;
+    csv: lei = code, lou = lou, country = country_code, valid = valid,
+);
+record!(VatResult:
+    code,
+    country_code,
+    effective_country_code,
+    country_name,
+    valid;
+    csv: vat = code,
+    country_code = country_code,
+    country_name = country_name,
+    valid = valid,
+);
+
+/// Written by hand for the CSV `id_name` column, which is the scheme's name
+/// rather than a field.
+impl Record for IdResult {
+    fn fields() -> Vec<Field> {
+        vec![
+            field("country_code", |r: &IdResult| &r.country_code),
+            field("effective_country_code", |r: &IdResult| {
+                &r.effective_country_code
+            }),
+            field("code", |r: &IdResult| &r.code),
+            field("gender", |r: &IdResult| &r.gender),
+            field("dob", |r: &IdResult| &r.dob),
+            field("region", |r: &IdResult| &r.region),
+            field("valid", |r: &IdResult| &r.valid),
+        ]
+    }
+
+    fn values(&self) -> Vec<Value> {
+        vec![
+            self.country_code.value(),
+            self.effective_country_code.value(),
+            self.code.value(),
+            self.gender.value(),
+            self.dob.value(),
+            self.region.value(),
+            self.valid.value(),
+        ]
+    }
+
+    fn csv_fields() -> Vec<Field> {
+        vec![
+            field("country", |r: &IdResult| &r.country_code),
+            Field::new("id_name", FieldType::Text, false),
+            field("code", |r: &IdResult| &r.code),
+            field("gender", |r: &IdResult| &r.gender),
+            field("dob", |r: &IdResult| &r.dob),
+            field("valid", |r: &IdResult| &r.valid),
+        ]
+    }
+
+    fn csv_values(&self) -> Vec<Value> {
+        let name = crate::personal_ids()
+            .name(&self.country_code)
+            .unwrap_or_default();
+        vec![
+            self.country_code.value(),
+            Value::Text(name),
+            self.code.value(),
+            self.gender.value(),
+            self.dob.value(),
+            self.valid.value(),
+        ]
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum Format {
+    #[default]
+    Csv,
+    /// A pretty-printed JSON array of objects.
+    Json,
+    /// One JSON object per line.
+    Ndjson,
+    /// A YAML sequence of mappings.
+    Yaml,
+    /// `<records>` with one `<record>` element per row; null fields are
+    /// omitted.
+    Xml,
+    /// One line per row with `{field}` placeholders replaced; `{{` and `}}`
+    /// are literal braces.
+    Template(String),
+}
+
+/// How [`Writer`] renders rows.
+#[derive(Debug, Clone)]
+pub struct Options {
+    pub format: Format,
+    /// Fields to output, in order. Empty means all fields.
+    pub fields: Vec<String>,
+    /// CSV field separator.
+    pub delimiter: char,
+    /// Start CSV output with a header row.
+    pub header: bool,
+    /// Prefix CSV cells starting with `=`, `+`, `-`, `@`, tab or carriage
+    /// return with `'` so spreadsheets do not evaluate them as formulas.
+    pub formula_guard: bool,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            format: Format::default(),
+            fields: Vec::new(),
+            delimiter: ',',
+            header: true,
+            formula_guard: true,
+        }
+    }
+}
+
+impl From<Format> for Options {
+    fn from(format: Format) -> Self {
+        Self {
+            format,
+            ..Default::default()
+        }
+    }
+}
+
+enum Piece {
+    Literal(String),
+    Field(usize),
+}
+
+/// Streams rows in one of the [`Format`]s.
+pub struct Writer<W: Write> {
+    out: W,
+    fields: Vec<Field>,
+    selected: Vec<usize>,
+    template: Vec<Piece>,
+    opts: Options,
+    /// Rows are written with [`Record::csv_values`].
+    csv_layout: bool,
+    rows: usize,
+    started: bool,
+}
+
+impl<W: Write> Writer<W> {
+    /// Fails on unknown fields in [`Options::fields`] or the template, on an
+    /// unbalanced template brace, or on a delimiter CSV cannot use.
+    pub fn new(out: W, fields: Vec<Field>, opts: Options) -> Result<Self, String> {
+        let position = |name: &str| {
+            fields.iter().position(|f| f.name == name).ok_or_else(|| {
+                let names: Vec<&str> = fields.iter().map(|f| f.name.as_str()).collect();
+                format!("Unknown field: {}. Use {}.", name, names.join(", "))
+            })
+        };
+        let selected = if opts.fields.is_empty() {
+            (0..fields.len()).collect()
+        } else {
+            opts.fields
+                .iter()
+                .map(|name| position(name))
+                .collect::<Result<Vec<_>, _>>()?
+        };
+        let template = match &opts.format {
+            Format::Template(template) => parse_template(template, |name| {
+                let index = position(name)?;
+                selected
+                    .iter()
+                    .position(|&i| i == index)
+                    .ok_or_else(|| format!("Field {} is not in the selected fields", name))
+            })?,
+            _ => Vec::new(),
+        };
+        if opts.format == Format::Csv && matches!(opts.delimiter, '"' | '\n' | '\r') {
+            return Err(format!("Invalid CSV delimiter: {:?}", opts.delimiter));
+        }
+        Ok(Self {
+            out,
+            fields,
+            selected,
+            template,
+            opts,
+            csv_layout: false,
+            rows: 0,
+            started: false,
+        })
+    }
+
+    /// A writer with the fields of `T`, or its [`Record::csv_fields`] for CSV
+    /// without selected fields.
+    pub fn for_rows<T: Record>(out: W, opts: Options) -> Result<Self, String> {
+        if opts.format == Format::Csv && opts.fields.is_empty() {
+            let mut writer = Self::new(out, T::csv_fields(), opts)?;
+            writer.csv_layout = true;
+            return Ok(writer);
+        }
+        Self::new(out, T::fields(), opts)
+    }
+
+    pub fn write<T: Record>(&mut self, row: &T) -> io::Result<()> {
+        if self.csv_layout {
+            return self.write_values(&row.csv_values());
+        }
+        self.write_values(&row.values())
+    }
+
+    /// Write one row of values in field order.
+    pub fn write_values(&mut self, values: &[Value]) -> io::Result<()> {
+        self.start()?;
+        let row: Vec<(&str, &Value)> = self
+            .selected
+            .iter()
+            .map(|&i| (self.fields[i].name.as_str(), &values[i]))
+            .collect();
+        match &self.opts.format {
+            Format::Csv => {
+                let cells: Vec<String> = row.iter().map(|(_, v)| self.csv_cell(v)).collect();
+                let separator = self.opts.delimiter.to_string();
+                writeln!(self.out, "{}", cells.join(&separator))?;
+            }
+            Format::Json => {
+                let entries: Vec<String> = row
+                    .iter()
+                    .map(|(name, v)| format!("    {}: {}", json_string(name), json_value(v)))
+                    .collect();
+                let separator = if self.rows == 0 { "" } else { "," };
+                write!(
+                    self.out,
+                    "{}\n  {{\n{}\n  }}",
+                    separator,
+                    entries.join(",\n")
+                )?;
+            }
+            Format::Ndjson => {
+                let entries: Vec<String> = row
+                    .iter()
+                    .map(|(name, v)| format!("{}:{}", json_string(name), json_value(v)))
+                    .collect();
+                writeln!(self.out, "{{{}}}", entries.join(","))?;
+            }
+            Format::Yaml => {
+                for (i, (name, v)) in row.iter().enumerate() {
+                    let value = match v {
+                        Value::Null => "null".into(),
+                        Value::Bool(b) => b.to_string(),
+                        Value::Text(s) => yaml_scalar(s),
+                    };
+                    let indent = if i == 0 { "- " } else { "  " };
+                    writeln!(self.out, "{}{}: {}", indent, yaml_scalar(name), value)?;
+                }
+            }
+            Format::Xml => {
+                writeln!(self.out, "  <record>")?;
+                for (name, v) in &row {
+                    if *v != &Value::Null {
+                        let tag = xml_name(name);
+                        let text = xml_escape(&v.as_text());
+                        writeln!(self.out, "    <{}>{}</{}>", tag, text, tag)?;
+                    }
+                }
+                writeln!(self.out, "  </record>")?;
+            }
+            Format::Template(_) => {
+                let mut line = String::new();
+                for piece in &self.template {
+                    match piece {
+                        Piece::Literal(s) => line.push_str(s),
+                        Piece::Field(i) => line.push_str(&row[*i].1.as_text()),
+                    }
+                }
+                writeln!(self.out, "{}", line)?;
+            }
+        }
+        self.rows += 1;
+        Ok(())
+    }
+
+    /// Number of rows written so far.
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Close any open document and return the output.
+    pub fn finish(mut self) -> io::Result<W> {
+        self.start()?;
+        match self.opts.format {
+            Format::Json if self.rows == 0 => writeln!(self.out, "]")?,
+            Format::Json => writeln!(self.out, "\n]")?,
+            Format::Yaml if self.rows == 0 => writeln!(self.out, "[]")?,
+            Format::Xml => writeln!(self.out, "</records>")?,
+            _ => {}
+        }
+        self.out.flush()?;
+        Ok(self.out)
+    }
+
+    fn start(&mut self) -> io::Result<()> {
+        if self.started {
+            return Ok(());
+        }
+        self.started = true;
+        match self.opts.format {
+            Format::Csv if self.opts.header => {
+                let names: Vec<String> = self
+                    .selected
+                    .iter()
+                    .map(|&i| self.csv_cell(&Value::Text(self.fields[i].name.clone())))
+                    .collect();
+                writeln!(self.out, "{}", names.join(&self.opts.delimiter.to_string()))
+            }
+            Format::Json => write!(self.out, "["),
+            Format::Xml => writeln!(
+                self.out,
+                "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<records>"
+            ),
+            _ => Ok(()),
+        }
+    }
+
+    fn csv_cell(&self, value: &Value) -> String {
+        let mut text = value.as_text().into_owned();
+        if self.opts.formula_guard && text.starts_with(['=', '+', '-', '@', '\t', '\r']) {
+            text.insert(0, '\'');
+        }
+        if text.contains(self.opts.delimiter) || text.contains(['"', '\n', '\r']) {
+            format!("\"{}\"", text.replace('"', "\"\""))
+        } else {
+            text
+        }
+    }
+}
+
+/// Split a template into literals and field placeholders, resolving each
+/// field name with `lookup`.
+fn parse_template(
+    template: &str,
+    mut lookup: impl FnMut(&str) -> Result<usize, String>,
+) -> Result<Vec<Piece>, String> {
+    let mut pieces = Vec::new();
+    let mut literal = String::new();
+    let mut chars = template.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '{' if chars.peek() == Some(&'{') => {
+                chars.next();
+                literal.push('{');
+            }
+            '}' if chars.peek() == Some(&'}') => {
+                chars.next();
+                literal.push('}');
+            }
+            '{' => {
+                let mut name = String::new();
+                loop {
+                    match chars.next() {
+                        Some('}') => break,
+                        Some(c) => name.push(c),
+                        None => return Err(format!("Unclosed {{ in template: {}", template)),
+                    }
+                }
+                if !literal.is_empty() {
+                    pieces.push(Piece::Literal(std::mem::take(&mut literal)));
+                }
+                pieces.push(Piece::Field(lookup(name.trim())?));
+            }
+            '}' => return Err(format!("Unmatched }} in template: {}", template)),
+            c => literal.push(c),
+        }
+    }
+    if !literal.is_empty() {
+        pieces.push(Piece::Literal(literal));
+    }
+    Ok(pieces)
+}
+
+fn json_string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            '\u{8}' => out.push_str("\\b"),
+            '\u{c}' => out.push_str("\\f"),
+            c if c < ' ' => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+fn json_value(value: &Value) -> String {
+    match value {
+        Value::Null => "null".into(),
+        Value::Bool(b) => b.to_string(),
+        Value::Text(s) => json_string(s),
+    }
+}
+
+/// A YAML scalar, double-quoted unless it reads back as the same string.
+fn yaml_scalar(s: &str) -> String {
+    let plain = s.starts_with(|c: char| c.is_ascii_alphabetic())
+        && s.chars()
+            .all(|c| c.is_alphanumeric() || matches!(c, ' ' | '_' | '-' | '.' | '/' | '(' | ')'))
+        && !s.ends_with(' ')
+        && !matches!(
+            s.to_lowercase().as_str(),
+            "true" | "false" | "yes" | "no" | "on" | "off" | "y" | "n" | "null"
+        );
+    if plain {
+        s.to_string()
+    } else {
+        json_string(s)
+    }
+}
+
+/// A valid XML element name for `name`.
+fn xml_name(name: &str) -> String {
+    let mut tag: String = name
+        .chars()
+        .map(|c| {
+            if c.is_alphanumeric() || matches!(c, '_' | '-' | '.') {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    if !tag.starts_with(|c: char| c.is_alphabetic() || c == '_') {
+        tag.insert(0, '_');
+    }
+    tag
+}
+
+fn xml_escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+/// Write `rows` to any [`Write`] destination.
+pub fn write_records<W: Write, T: Record>(
+    writer: W,
+    rows: &[T],
+    opts: Options,
+) -> Result<(), String> {
+    let mut out = Writer::for_rows::<T>(writer, opts)?;
+    for row in rows {
+        out.write(row).map_err(|e| e.to_string())?;
+    }
+    out.finish().map_err(|e| e.to_string())?;
+    Ok(())
+}
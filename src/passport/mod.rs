@@ -2,10 +2,17 @@ use rand::Rng;
 #[cfg(feature = "json")]
 use serde::Serialize;
 
+use crate::kind::IdKind;
+use crate::locale::Locale;
+
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "json", derive(Serialize))]
 pub struct PassportResult {
+    /// Country as requested.
     pub country_code: String,
+    /// Country whose format was applied; differs from `country_code` for
+    /// territories using their parent's (e.g. `PR` → `US`).
+    pub effective_country_code: String,
     pub country_name: String,
     pub name: String,
     pub code: String,
@@ -15,6 +22,9 @@ pub struct PassportResult {
 #[derive(Debug, Clone, Default)]
 pub struct GenOptions {
     pub country: Option<String>,
+    /// Fail for territories without their own format instead of using the
+    /// parent country's.
+    pub strict: bool,
 }
 
 static SPECIFIC_COUNTRIES: &[(&str, &str)] = &[
@@ -99,6 +109,10 @@ static SPECIFIC_COUNTRIES: &[(&str, &str)] = &[
     ("ZA", "Passport"),
 ];
 
+fn has_specific(country: &str) -> bool {
+    SPECIFIC_COUNTRIES.iter().any(|(code, _)| *code == country)
+}
+
 pub struct Registry;
 
 impl Default for Registry {
@@ -113,7 +127,7 @@ impl Registry {
     }
 
     pub fn generate(&self, opts: &GenOptions, rng: &mut impl Rng) -> Option<PassportResult> {
-        let country = opts
+        let requested = opts
             .country
             .as_deref()
             .unwrap_or_else(|| {
@@ -121,6 +135,8 @@ impl Registry {
                 countries[rng.gen_range(0..countries.len())].0
             })
             .to_uppercase();
+        let country =
+            crate::countries::effective_country_code(&requested, opts.strict, has_specific)?;
 
         if let Some((name, code)) = match country.as_str() {
             // ── Existing 18 countries ──
@@ -209,9 +225,10 @@ impl Registry {
             "DZ" => Some(("Passeport", self.generate_dz(rng))),
             _ => None,
         } {
-            let country_name = crate::countries::get_country_name(&country).unwrap_or("Unknown");
+            let country_name = crate::countries::get_country_name(&requested).unwrap_or("Unknown");
             return Some(PassportResult {
-                country_code: country,
+                country_code: requested,
+                effective_country_code: country,
                 country_name: country_name.to_string(),
                 name: name.to_string(),
                 code,
@@ -222,8 +239,18 @@ impl Registry {
         None
     }
 
+    /// Country whose format applies to `country`: the country itself, or
+    /// the parent for a territory without its own.
+    pub fn effective_country(&self, country: &str) -> Option<String> {
+        crate::countries::effective_country_code(country, false, has_specific)
+    }
+
     pub fn validate(&self, country: &str, code: &str) -> bool {
-        match country.to_uppercase().as_str() {
+        let Some(country) = crate::countries::effective_country_code(country, false, has_specific)
+        else {
+            return false;
+        };
+        match country.as_str() {
             // ── Existing 18 countries ──
             "IN" => self.validate_in(code),
             "US" => self.validate_us(code),
@@ -327,6 +354,24 @@ impl Registry {
         result
     }
 
+    /// Like [`list_countries`](Self::list_countries), with country and
+    /// passport names in `locale`.
+    pub fn list_countries_in(
+        &self,
+        locale: Locale,
+    ) -> Vec<(&'static str, &'static str, &'static str)> {
+        self.list_countries()
+            .into_iter()
+            .map(|(code, _, name)| {
+                (
+                    code,
+                    crate::locale::country_label(code, locale),
+                    crate::locale::document_name(IdKind::Passport, code, locale).unwrap_or(name),
+                )
+            })
+            .collect()
+    }
+
     // ════════════════════════════════════════════════════════════════════
     // Existing 18 country implementations (unchanged)
     // ════════════════════════════════════════════════════════════════════
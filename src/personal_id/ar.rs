@@ -58,6 +58,7 @@ pub fn parse(code: &str) -> IdResult {
     };
     IdResult {
         country_code: "".to_string(),
+        effective_country_code: "".to_string(),
         code: if clean.len() == 11 {
             format!("{}-{}-{}", &clean[0..2], &clean[2..10], &clean[10..11])
         } else {
@@ -65,6 +66,7 @@ pub fn parse(code: &str) -> IdResult {
         },
         gender,
         dob: None,
+        region: None,
         valid: validate(code),
     }
 }
@@ -54,9 +54,11 @@ pub fn parse(code: &str) -> IdResult {
     let century: u16 = if yy <= 25 { 2000 } else { 1900 };
     IdResult {
         country_code: "".to_string(),
+        effective_country_code: "".to_string(),
         code: code.to_string(),
         gender: None,
         dob: Some(format!("{}-{:02}-{:02}", century + yy, mm, dd)),
+        region: None,
         valid: validate(code),
     }
 }
@@ -41,6 +41,7 @@ pub fn parse(code: &str) -> IdResult {
     let clean: String = code.chars().filter(|c| c.is_ascii_digit()).collect();
     IdResult {
         country_code: "".to_string(),
+        effective_country_code: "".to_string(),
         code: if clean.len() == 9 {
             format!("{} {} {}", &clean[0..3], &clean[3..6], &clean[6..9])
         } else {
@@ -48,6 +49,7 @@ pub fn parse(code: &str) -> IdResult {
         },
         gender: None,
         dob: None,
+        region: None,
         valid: validate(code),
     }
 }
@@ -52,6 +52,7 @@ pub fn parse(code: &str) -> IdResult {
     };
     IdResult {
         country_code: "".to_string(),
+        effective_country_code: "".to_string(),
         code: format!(
             "{}.{}.{}-{}.{}",
             &clean[0..2],
@@ -62,6 +63,7 @@ pub fn parse(code: &str) -> IdResult {
         ),
         gender: Some(if seq % 2 == 1 { "male" } else { "female" }.to_string()),
         dob: Some(format!("{}-{:02}-{:02}", year, mm, dd)),
+        region: None,
         valid: validate(code),
     }
 }
@@ -57,6 +57,7 @@ pub fn parse(code: &str) -> IdResult {
     };
     IdResult {
         country_code: "".to_string(),
+        effective_country_code: "".to_string(),
         code: code.to_string(),
         gender: Some(
             if seq.is_multiple_of(2) {
@@ -67,6 +68,7 @@ pub fn parse(code: &str) -> IdResult {
             .to_string(),
         ),
         dob: Some(format!("{}-{:02}-{:02}", year, month, dd)),
+        region: None,
         valid: validate(code),
     }
 }
@@ -59,6 +59,7 @@ pub fn parse(code: &str) -> IdResult {
     let clean: String = code.chars().filter(|c| c.is_ascii_digit()).collect();
     IdResult {
         country_code: "".to_string(),
+        effective_country_code: "".to_string(),
         code: if clean.len() == 11 {
             format!(
                 "{}.{}.{}-{}",
@@ -72,6 +73,7 @@ pub fn parse(code: &str) -> IdResult {
         },
         gender: None,
         dob: None,
+        region: None,
         valid: validate(code),
     }
 }
@@ -37,6 +37,7 @@ pub fn parse(code: &str) -> IdResult {
     let clean: String = code.chars().filter(|c| *c != ' ').collect();
     IdResult {
         country_code: "".to_string(),
+        effective_country_code: "".to_string(),
         code: if clean.len() == 9 {
             format!("{} {} {}", &clean[0..3], &clean[3..6], &clean[6..9])
         } else {
@@ -44,6 +45,7 @@ pub fn parse(code: &str) -> IdResult {
         },
         gender: None,
         dob: None,
+        region: None,
         valid: validate(code),
     }
 }
@@ -36,6 +36,7 @@ pub fn parse(code: &str) -> IdResult {
     let clean: String = code.chars().filter(|c| *c != '.').collect();
     IdResult {
         country_code: "".to_string(),
+        effective_country_code: "".to_string(),
         code: format!(
             "{}.{}.{}.{}",
             &clean[..3],
@@ -45,6 +46,7 @@ pub fn parse(code: &str) -> IdResult {
         ),
         gender: None,
         dob: None,
+        region: None,
         valid: validate(code),
     }
 }
@@ -34,68 +34,50 @@ pub fn weighted_check(digits: &[u8], weights: &[u8], modulus: u32) -> u32 {
         % modulus
 }
 
+/// Verhoeff multiplication table (dihedral group D5).
+pub(crate) static VERHOEFF_D: [[u8; 10]; 10] = [
+    [0, 1, 2, 3, 4, 5, 6, 7, 8, 9],
+    [1, 2, 3, 4, 0, 6, 7, 8, 9, 5],
+    [2, 3, 4, 0, 1, 7, 8, 9, 5, 6],
+    [3, 4, 0, 1, 2, 8, 9, 5, 6, 7],
+    [4, 0, 1, 2, 3, 9, 5, 6, 7, 8],
+    [5, 9, 8, 7, 6, 0, 4, 3, 2, 1],
+    [6, 5, 9, 8, 7, 1, 0, 4, 3, 2],
+    [7, 6, 5, 9, 8, 2, 1, 0, 4, 3],
+    [8, 7, 6, 5, 9, 3, 2, 1, 0, 4],
+    [9, 8, 7, 6, 5, 4, 3, 2, 1, 0],
+];
+
+/// Verhoeff permutation table, by position mod 8.
+pub(crate) static VERHOEFF_P: [[u8; 10]; 8] = [
+    [0, 1, 2, 3, 4, 5, 6, 7, 8, 9],
+    [1, 5, 7, 6, 2, 8, 3, 0, 9, 4],
+    [5, 8, 0, 3, 7, 9, 6, 1, 4, 2],
+    [8, 9, 1, 6, 0, 4, 3, 5, 2, 7],
+    [9, 4, 5, 3, 1, 2, 6, 8, 7, 0],
+    [4, 2, 8, 6, 5, 7, 3, 9, 0, 1],
+    [2, 7, 9, 3, 8, 0, 6, 4, 1, 5],
+    [7, 0, 4, 6, 9, 1, 3, 2, 5, 8],
+];
+
+/// Verhoeff inverses.
+pub(crate) static VERHOEFF_INV: [u8; 10] = [0, 4, 3, 2, 1, 5, 6, 7, 8, 9];
+
 /// Verhoeff check digit algorithm using dihedral group D5.
 /// Returns the check digit (0-9) that makes the full number valid.
 pub fn verhoeff_check(digits: &[u8]) -> u8 {
-    static D: [[u8; 10]; 10] = [
-        [0, 1, 2, 3, 4, 5, 6, 7, 8, 9],
-        [1, 2, 3, 4, 0, 6, 7, 8, 9, 5],
-        [2, 3, 4, 0, 1, 7, 8, 9, 5, 6],
-        [3, 4, 0, 1, 2, 8, 9, 5, 6, 7],
-        [4, 0, 1, 2, 3, 9, 5, 6, 7, 8],
-        [5, 9, 8, 7, 6, 0, 4, 3, 2, 1],
-        [6, 5, 9, 8, 7, 1, 0, 4, 3, 2],
-        [7, 6, 5, 9, 8, 2, 1, 0, 4, 3],
-        [8, 7, 6, 5, 9, 3, 2, 1, 0, 4],
-        [9, 8, 7, 6, 5, 4, 3, 2, 1, 0],
-    ];
-    static P: [[u8; 10]; 8] = [
-        [0, 1, 2, 3, 4, 5, 6, 7, 8, 9],
-        [1, 5, 7, 6, 2, 8, 3, 0, 9, 4],
-        [5, 8, 0, 3, 7, 9, 6, 1, 4, 2],
-        [8, 9, 1, 6, 0, 4, 3, 5, 2, 7],
-        [9, 4, 5, 3, 1, 2, 6, 8, 7, 0],
-        [4, 2, 8, 6, 5, 7, 3, 9, 0, 1],
-        [2, 7, 9, 3, 8, 0, 6, 4, 1, 5],
-        [7, 0, 4, 6, 9, 1, 3, 2, 5, 8],
-    ];
-    static INV: [u8; 10] = [0, 4, 3, 2, 1, 5, 6, 7, 8, 9];
-
     let mut c: u8 = 0;
     for (i, &d) in digits.iter().rev().enumerate() {
-        c = D[c as usize][P[(i + 1) % 8][d as usize] as usize];
+        c = VERHOEFF_D[c as usize][VERHOEFF_P[(i + 1) % 8][d as usize] as usize];
     }
-    INV[c as usize]
+    VERHOEFF_INV[c as usize]
 }
 
 /// Verhoeff validation: returns true if the full number (including check digit) is valid.
 pub fn verhoeff_validate(digits: &[u8]) -> bool {
-    static D: [[u8; 10]; 10] = [
-        [0, 1, 2, 3, 4, 5, 6, 7, 8, 9],
-        [1, 2, 3, 4, 0, 6, 7, 8, 9, 5],
-        [2, 3, 4, 0, 1, 7, 8, 9, 5, 6],
-        [3, 4, 0, 1, 2, 8, 9, 5, 6, 7],
-        [4, 0, 1, 2, 3, 9, 5, 6, 7, 8],
-        [5, 9, 8, 7, 6, 0, 4, 3, 2, 1],
-        [6, 5, 9, 8, 7, 1, 0, 4, 3, 2],
-        [7, 6, 5, 9, 8, 2, 1, 0, 4, 3],
-        [8, 7, 6, 5, 9, 3, 2, 1, 0, 4],
-        [9, 8, 7, 6, 5, 4, 3, 2, 1, 0],
-    ];
-    static P: [[u8; 10]; 8] = [
-        [0, 1, 2, 3, 4, 5, 6, 7, 8, 9],
-        [1, 5, 7, 6, 2, 8, 3, 0, 9, 4],
-        [5, 8, 0, 3, 7, 9, 6, 1, 4, 2],
-        [8, 9, 1, 6, 0, 4, 3, 5, 2, 7],
-        [9, 4, 5, 3, 1, 2, 6, 8, 7, 0],
-        [4, 2, 8, 6, 5, 7, 3, 9, 0, 1],
-        [2, 7, 9, 3, 8, 0, 6, 4, 1, 5],
-        [7, 0, 4, 6, 9, 1, 3, 2, 5, 8],
-    ];
-
     let mut c: u8 = 0;
     for (i, &d) in digits.iter().rev().enumerate() {
-        c = D[c as usize][P[i % 8][d as usize] as usize];
+        c = VERHOEFF_D[c as usize][VERHOEFF_P[i % 8][d as usize] as usize];
     }
     c == 0
 }
@@ -112,3 +94,40 @@ pub fn iso7064_mod11_2(digits: &[u8]) -> char {
         .sum();
     CHECK_CHARS[(sum % 11) as usize] as char
 }
+
+/// ISO 7064 pure system check value (MOD 11-2, MOD 37-2, ...).
+/// `values` are character values (digits 0-9, letters 10-35); returns the
+/// check value in `0..modulus`.
+pub fn iso7064_pure(values: &[u32], modulus: u32, radix: u32) -> u32 {
+    let p = values
+        .iter()
+        .fold(0u32, |p, &v| ((p + v) * radix) % modulus);
+    (modulus + 1 - p) % modulus
+}
+
+/// ISO 7064 hybrid system check value (MOD 11,10 and MOD 37,36).
+/// `modulus` is the smaller of the two (10 or 36).
+pub fn iso7064_hybrid(values: &[u32], modulus: u32) -> u32 {
+    let mut p = modulus;
+    for &v in values {
+        let mut s = (p + v) % modulus;
+        if s == 0 {
+            s = modulus;
+        }
+        p = (s * 2) % (modulus + 1);
+    }
+    (modulus + 1 - p) % modulus
+}
+
+/// ISO 7064 MOD 97-10 check value (two digits), with letters expanded to
+/// two-digit numbers as in IBANs.
+pub fn iso7064_mod97_10(values: &[u32]) -> u32 {
+    let rem = values.iter().fold(0u64, |rem, &v| {
+        if v < 10 {
+            (rem * 10 + v as u64) % 97
+        } else {
+            (rem * 100 + v as u64) % 97
+        }
+    });
+    (98 - (rem * 100) % 97) as u32
+}
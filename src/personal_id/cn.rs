@@ -3,6 +3,7 @@ use rand::Rng;
 use super::checksum;
 use super::date::Gender;
 use super::IdResult;
+use crate::countries::subdivisions::{self, Subdivision};
 
 // Real 6-digit administrative division codes (valid from 1985 or earlier)
 static REGIONS: &[u32] = &[
@@ -55,8 +56,81 @@ static REGIONS: &[u32] = &[
     630102, 630103, 630104, 632221, 632222, 632223, 632224, // Ningxia (64)
     640121, 640122, 640202, // Xinjiang (65)
     650102, 650103, 650104, 650105, 650106, 650107, 650121, 650202, 650203,
+    // Hainan (46)
+    460105, 460106, 460107, 460108, // Chongqing (50)
+    500101, 500102, 500103, 500104, 500105, 500106,
 ];
 
+// Residence permits for Hong Kong (81), Macao (82) and Taiwan (83) residents,
+// only issued when one of those regions is asked for
+static PERMITS: &[u32] = &[810000, 820000, 830000];
+
+/// Leading two digits of the division codes in each ISO 3166-2 province.
+static PROVINCES: &[(&str, u32)] = &[
+    ("BJ", 11),
+    ("TJ", 12),
+    ("HE", 13),
+    ("SX", 14),
+    ("NM", 15),
+    ("LN", 21),
+    ("JL", 22),
+    ("HL", 23),
+    ("SH", 31),
+    ("JS", 32),
+    ("ZJ", 33),
+    ("AH", 34),
+    ("FJ", 35),
+    ("JX", 36),
+    ("SD", 37),
+    ("HA", 41),
+    ("HB", 42),
+    ("HN", 43),
+    ("GD", 44),
+    ("GX", 45),
+    ("HI", 46),
+    ("CQ", 50),
+    ("SC", 51),
+    ("GZ", 52),
+    ("YN", 53),
+    ("XZ", 54),
+    ("SN", 61),
+    ("GS", 62),
+    ("QH", 63),
+    ("NX", 64),
+    ("XJ", 65),
+    ("HK", 81),
+    ("MO", 82),
+    ("TW", 83),
+];
+
+/// A random six-digit division code, inside `province` if given. Residence
+/// permit prefixes are only used when their region is given.
+pub(crate) fn region_code(province: Option<&Subdivision>, rng: &mut impl Rng) -> u32 {
+    let prefix = province.and_then(|p| {
+        PROVINCES
+            .iter()
+            .find(|(suffix, _)| *suffix == p.suffix())
+            .map(|(_, prefix)| *prefix)
+    });
+    let candidates: Vec<u32> = match prefix {
+        Some(p) => REGIONS
+            .iter()
+            .chain(PERMITS)
+            .copied()
+            .filter(|r| r / 10000 == p)
+            .collect(),
+        None => REGIONS.to_vec(),
+    };
+    candidates[rng.gen_range(0..candidates.len())]
+}
+
+/// Province of a code starting with a division code.
+pub(crate) fn province(code: &str) -> Option<&'static Subdivision> {
+    let prefix: u32 = code.get(..2)?.parse().ok()?;
+    let (suffix, _) = PROVINCES.iter().find(|(_, p)| *p == prefix)?;
+    subdivisions::lookup("CN", suffix)
+}
+
 pub fn generate(opts: &super::GenOptions, rng: &mut impl Rng) -> String {
     let gender = Gender::resolve_or_random(opts.gender, rng);
     // Use 1985+ to match region code validity dates
@@ -65,7 +139,11 @@ pub fn generate(opts: &super::GenOptions, rng: &mut impl Rng) -> String {
         None => super::date::rand_date(rng, 1985, 2005),
     };
 
-    let full_region = REGIONS[rng.gen_range(0..REGIONS.len())];
+    let province = opts
+        .region
+        .as_deref()
+        .and_then(|r| subdivisions::lookup("CN", r));
+    let full_region = region_code(province, rng);
 
     // Sequence: odd = male, even = female
     let seq = match gender {
@@ -113,11 +191,15 @@ pub fn parse(code: &str) -> IdResult {
         (None, None)
     };
 
+    let region = province(&upper).map(|p| p.suffix().to_string());
+
     IdResult {
         country_code: "".to_string(),
+        effective_country_code: "".to_string(),
         code: upper,
         gender,
         dob,
+        region,
         valid: validate(code),
     }
 }
@@ -23,7 +23,7 @@ fn year_from_century_digit(yy: u8, century: u8) -> u16 {
     base + yy as u16
 }
 
-pub fn generate(opts: &super::GenOptions, rng: &mut rand::rngs::ThreadRng) -> String {
+pub fn generate(opts: &super::GenOptions, rng: &mut impl Rng) -> String {
     let year = opts.year.unwrap_or_else(|| rng.gen_range(1950..=2005));
     let month: u8 = rng.gen_range(1..=12);
     let day: u8 = rng.gen_range(1..=28);
@@ -119,9 +119,11 @@ pub fn parse(code: &str) -> IdResult {
 
     IdResult {
         country_code: "".to_string(),
+        effective_country_code: "".to_string(),
         code: clean,
         gender,
         dob,
+        region: None,
         valid,
     }
 }
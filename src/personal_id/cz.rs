@@ -41,9 +41,11 @@ pub fn parse(code: &str) -> IdResult {
     let century: u16 = if yy <= 25 { 2000 } else { 1900 };
     IdResult {
         country_code: "".to_string(),
+        effective_country_code: "".to_string(),
         code: format!("{}/{}", &clean[..6], &clean[6..]),
         gender: Some(gender_str.to_string()),
         dob: Some(format!("{}-{:02}-{:02}", century + yy, actual_mm, dd)),
+        region: None,
         valid: validate(code),
     }
 }
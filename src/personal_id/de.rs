@@ -57,9 +57,11 @@ pub fn validate(code: &str) -> bool {
 pub fn parse(code: &str) -> IdResult {
     IdResult {
         country_code: "".to_string(),
+        effective_country_code: "".to_string(),
         code: code.to_string(),
         gender: None,
         dob: None,
+        region: None,
         valid: validate(code),
     }
 }
@@ -3,7 +3,7 @@ use rand::Rng;
 use super::checksum;
 use super::IdResult;
 
-pub fn generate(_opts: &super::GenOptions, rng: &mut rand::rngs::ThreadRng) -> String {
+pub fn generate(_opts: &super::GenOptions, rng: &mut impl Rng) -> String {
     let mut digits: Vec<u8> = (0..10).map(|_| rng.gen_range(0..=9u8)).collect();
     let check = checksum::luhn_check(&digits);
     digits.push(check);
@@ -23,6 +23,7 @@ pub fn parse(code: &str) -> IdResult {
     let clean: String = code.chars().filter(|c| c.is_ascii_digit()).collect();
     IdResult {
         country_code: "".to_string(),
+        effective_country_code: "".to_string(),
         code: if clean.len() == 11 {
             format!("{}-{}-{}", &clean[..3], &clean[3..10], &clean[10..])
         } else {
@@ -30,6 +31,7 @@ pub fn parse(code: &str) -> IdResult {
         },
         gender: None,
         dob: None,
+        region: None,
         valid: validate(code),
     }
 }
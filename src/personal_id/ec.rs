@@ -57,9 +57,11 @@ pub fn parse(code: &str) -> IdResult {
     let clean: String = code.chars().filter(|c| c.is_ascii_digit()).collect();
     IdResult {
         country_code: "".to_string(),
+        effective_country_code: "".to_string(),
         code: clean,
         gender: None,
         dob: None,
+        region: None,
         valid: validate(code),
     }
 }
@@ -61,9 +61,11 @@ pub fn parse(code: &str) -> IdResult {
     };
     IdResult {
         country_code: "".to_string(),
+        effective_country_code: "".to_string(),
         code: code.to_string(),
         gender: Some(if g % 2 == 1 { "male" } else { "female" }.to_string()),
         dob: Some(format!("{}-{:02}-{:02}", century_base + yy, mm, dd)),
+        region: None,
         valid: validate(code),
     }
 }
@@ -69,9 +69,11 @@ pub fn parse(code: &str) -> IdResult {
 
     IdResult {
         country_code: "".to_string(),
+        effective_country_code: "".to_string(),
         code: code.to_string(),
         gender,
         dob,
+        region: None,
         valid: validate(code),
     }
 }
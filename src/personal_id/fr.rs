@@ -56,9 +56,11 @@ pub fn parse(code: &str) -> IdResult {
     let century: u16 = if yy > 25 { 1900 } else { 2000 };
     IdResult {
         country_code: "".to_string(),
+        effective_country_code: "".to_string(),
         code: code.to_string(),
         gender: Some(if s == 1 { "male" } else { "female" }.to_string()),
         dob: Some(format!("{}-{:02}", century + yy, mm)),
+        region: None,
         valid: validate(code),
     }
 }
@@ -35,9 +35,11 @@ pub fn parse(code: &str) -> IdResult {
     let century: u16 = if yy <= 25 { 2000 } else { 1900 };
     IdResult {
         country_code: "".to_string(),
+        effective_country_code: "".to_string(),
         code: code.to_string(),
         gender: Some(if seq % 2 == 1 { "male" } else { "female" }.to_string()),
         dob: Some(format!("{}-{:02}-{:02}", century + yy, mm, dd)),
+        region: None,
         valid: validate(code),
     }
 }
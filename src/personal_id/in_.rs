@@ -30,6 +30,7 @@ pub fn parse(code: &str) -> IdResult {
     let clean: String = code.chars().filter(|c| c.is_ascii_digit()).collect();
     IdResult {
         country_code: "".to_string(),
+        effective_country_code: "".to_string(),
         code: if clean.len() == 12 {
             format!("{} {} {}", &clean[0..4], &clean[4..8], &clean[8..12])
         } else {
@@ -37,6 +38,7 @@ pub fn parse(code: &str) -> IdResult {
         },
         gender: None,
         dob: None,
+        region: None,
         valid: validate(code),
     }
 }
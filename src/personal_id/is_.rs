@@ -57,9 +57,11 @@ pub fn parse(code: &str) -> IdResult {
     };
     IdResult {
         country_code: "".to_string(),
+        effective_country_code: "".to_string(),
         code: clean.to_string(),
         gender: None,
         dob: Some(format!("{}-{:02}-{:02}", century + yy, mm, dd)),
+        region: None,
         valid: validate(code),
     }
 }
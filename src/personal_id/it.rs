@@ -130,9 +130,11 @@ pub fn parse(code: &str) -> IdResult {
     let dob = month.map(|mm| format!("{}-{:02}-{:02}", century + yy, mm, actual_dd));
     IdResult {
         country_code: "".to_string(),
+        effective_country_code: "".to_string(),
         code: upper,
         gender: Some(gender_str.to_string()),
         dob,
+        region: None,
         valid: validate(code),
     }
 }
@@ -76,9 +76,11 @@ pub fn parse(code: &str) -> IdResult {
     let seq: u16 = clean[9..12].parse().unwrap_or(0);
     IdResult {
         country_code: "".to_string(),
+        effective_country_code: "".to_string(),
         code: clean,
         gender: Some(if seq < 500 { "male" } else { "female" }.to_string()),
         dob: Some(format!("{}-{:02}-{:02}", year, mm, dd)),
+        region: None,
         valid: validate(code),
     }
 }
@@ -75,6 +75,7 @@ pub fn parse(code: &str) -> IdResult {
 
     IdResult {
         country_code: "".to_string(),
+        effective_country_code: "".to_string(),
         code: if clean.len() == 13 {
             format!("{}-{}", &clean[0..6], &clean[6..13])
         } else {
@@ -82,6 +83,7 @@ pub fn parse(code: &str) -> IdResult {
         },
         gender,
         dob,
+        region: None,
         valid: validate(code),
     }
 }
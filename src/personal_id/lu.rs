@@ -33,9 +33,11 @@ pub fn parse(code: &str) -> IdResult {
 
     IdResult {
         country_code: "".to_string(),
+        effective_country_code: "".to_string(),
         code: code.to_string(),
         gender: None,
         dob,
+        region: None,
         valid: validate(code),
     }
 }
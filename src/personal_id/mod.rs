@@ -73,7 +73,15 @@ pub mod pk;
 pub mod sa;
 pub mod za;
 
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, RwLock};
+
+use crate::kind::IdKind;
+use crate::locale::Locale;
+use crate::scheme::IdScheme;
 use date::Gender;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
 #[cfg(feature = "json")]
 use serde::Serialize;
 
@@ -81,203 +89,115 @@ use serde::Serialize;
 pub struct GenOptions {
     pub gender: Option<Gender>,
     pub year: Option<u16>,
+    /// ISO 3166-2 subdivision for schemes that encode one (CN, MX), as
+    /// `MX-JAL`, `JAL` or `Jalisco`. Other schemes ignore it, but generation
+    /// fails if the country has known subdivisions and this is not one.
+    pub region: Option<String>,
+    /// Fail for territories without their own scheme instead of using the
+    /// parent country's.
+    pub strict: bool,
 }
 
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "json", derive(Serialize))]
 pub struct IdResult {
+    /// Country as requested.
     pub country_code: String,
+    /// Country whose scheme was applied; differs from `country_code` for
+    /// territories using their parent's (e.g. `PR` → `US`).
+    pub effective_country_code: String,
     pub code: String,
     pub gender: Option<String>,
     pub dob: Option<String>,
+    /// ISO 3166-2 subdivision encoded in the code, without the country
+    /// prefix (e.g. `JAL` for a CURP registered in Jalisco).
+    pub region: Option<String>,
     pub valid: bool,
 }
 
-type GenerateFn = fn(&GenOptions, &mut rand::rngs::ThreadRng) -> String;
+type GenerateFn = fn(&GenOptions, &mut StdRng) -> String;
 type ValidateFn = fn(&str) -> bool;
 type ParseFn = fn(&str) -> IdResult;
 
 struct RegistryEntry {
     code: &'static str,
     name: &'static str,
+    checksum: Option<&'static str>,
     generate: GenerateFn,
     validate: ValidateFn,
     parse: ParseFn,
 }
 
-struct TerritoryAlias {
-    code: &'static str,
-    parent_code: &'static str,
+/// A scheme added at runtime with [`Registry::register`] or
+/// [`Registry::override_scheme`].
+#[derive(Clone)]
+struct CustomEntry {
+    code: Arc<str>,
+    name: Arc<str>,
+    scheme: Arc<dyn IdScheme>,
 }
 
-static TERRITORY_ALIASES: &[TerritoryAlias] = &[
-    // US territories
-    TerritoryAlias {
-        code: "AS",
-        parent_code: "US",
-    },
-    TerritoryAlias {
-        code: "GU",
-        parent_code: "US",
-    },
-    TerritoryAlias {
-        code: "MP",
-        parent_code: "US",
-    },
-    TerritoryAlias {
-        code: "PR",
-        parent_code: "US",
-    },
-    TerritoryAlias {
-        code: "UM",
-        parent_code: "US",
-    },
-    TerritoryAlias {
-        code: "VI",
-        parent_code: "US",
-    },
-    // Australian territories
-    TerritoryAlias {
-        code: "CC",
-        parent_code: "AU",
-    },
-    TerritoryAlias {
-        code: "CX",
-        parent_code: "AU",
-    },
-    TerritoryAlias {
-        code: "HM",
-        parent_code: "AU",
-    },
-    TerritoryAlias {
-        code: "NF",
-        parent_code: "AU",
-    },
-    // NZ territories
-    TerritoryAlias {
-        code: "CK",
-        parent_code: "NZ",
-    },
-    TerritoryAlias {
-        code: "NU",
-        parent_code: "NZ",
-    },
-    TerritoryAlias {
-        code: "PN",
-        parent_code: "NZ",
-    },
-    TerritoryAlias {
-        code: "TK",
-        parent_code: "NZ",
-    },
-    // French territory
-    TerritoryAlias {
-        code: "BL",
-        parent_code: "FR",
-    },
-    // UK territories
-    TerritoryAlias {
-        code: "GS",
-        parent_code: "GB",
-    },
-    TerritoryAlias {
-        code: "IO",
-        parent_code: "GB",
-    },
-    TerritoryAlias {
-        code: "SH",
-        parent_code: "GB",
-    },
-    // Norwegian territories
-    TerritoryAlias {
-        code: "BV",
-        parent_code: "NO",
-    },
-    TerritoryAlias {
-        code: "SJ",
-        parent_code: "NO",
-    },
-    // Danish territories
-    TerritoryAlias {
-        code: "FO",
-        parent_code: "DK",
-    },
-    TerritoryAlias {
-        code: "GL",
-        parent_code: "DK",
-    },
-    // French territories
-    TerritoryAlias {
-        code: "GF",
-        parent_code: "FR",
-    },
-    TerritoryAlias {
-        code: "GP",
-        parent_code: "FR",
-    },
-    TerritoryAlias {
-        code: "MF",
-        parent_code: "FR",
-    },
-    TerritoryAlias {
-        code: "MQ",
-        parent_code: "FR",
-    },
-    TerritoryAlias {
-        code: "NC",
-        parent_code: "FR",
-    },
-    TerritoryAlias {
-        code: "PF",
-        parent_code: "FR",
-    },
-    TerritoryAlias {
-        code: "PM",
-        parent_code: "FR",
-    },
-    TerritoryAlias {
-        code: "RE",
-        parent_code: "FR",
-    },
-    TerritoryAlias {
-        code: "TF",
-        parent_code: "FR",
-    },
-    TerritoryAlias {
-        code: "WF",
-        parent_code: "FR",
-    },
-    TerritoryAlias {
-        code: "YT",
-        parent_code: "FR",
-    },
-    // UK territory
-    TerritoryAlias {
-        code: "VG",
-        parent_code: "GB",
-    },
-];
-
-fn resolve_alias(code: &str) -> Option<&'static TerritoryAlias> {
-    TERRITORY_ALIASES.iter().find(|a| a.code == code)
+/// A resolved scheme, built-in or registered.
+enum Scheme<'a> {
+    Builtin(&'a RegistryEntry),
+    Custom(CustomEntry),
+}
+
+impl Scheme<'_> {
+    fn name(&self) -> &str {
+        match self {
+            Scheme::Builtin(e) => e.name,
+            Scheme::Custom(e) => &e.name,
+        }
+    }
+
+    fn checksum(&self) -> Option<String> {
+        match self {
+            Scheme::Builtin(e) => e.checksum.map(str::to_string),
+            Scheme::Custom(e) => e.scheme.checksum(),
+        }
+    }
+
+    fn generate(&self, opts: &GenOptions, rng: &mut StdRng) -> Option<String> {
+        match self {
+            Scheme::Builtin(e) => Some((e.generate)(opts, rng)),
+            Scheme::Custom(e) => e.scheme.try_generate(opts, rng),
+        }
+    }
+
+    fn validate(&self, code: &str) -> bool {
+        match self {
+            Scheme::Builtin(e) => (e.validate)(code),
+            Scheme::Custom(e) => e.scheme.validate(code),
+        }
+    }
+
+    fn parse(&self, code: &str) -> IdResult {
+        match self {
+            Scheme::Builtin(e) => (e.parse)(code),
+            Scheme::Custom(e) => e.scheme.parse(code),
+        }
+    }
 }
 
 pub struct Registry {
     entries: Vec<RegistryEntry>,
+    custom: RwLock<Vec<CustomEntry>>,
+    revision: AtomicUsize,
 }
 
 // Wrapper functions for countries that share implementations but need
 // concrete fn pointers (can't use closures as fn pointers with captures)
-fn gen_si(opts: &GenOptions, rng: &mut rand::rngs::ThreadRng) -> String {
+fn gen_si(opts: &GenOptions, rng: &mut impl Rng) -> String {
     jmbg::generate_si(opts, rng)
 }
-fn gen_rs(opts: &GenOptions, rng: &mut rand::rngs::ThreadRng) -> String {
+fn gen_rs(opts: &GenOptions, rng: &mut impl Rng) -> String {
     jmbg::generate_rs(opts, rng)
 }
-fn gen_ba(opts: &GenOptions, rng: &mut rand::rngs::ThreadRng) -> String {
+fn gen_ba(opts: &GenOptions, rng: &mut impl Rng) -> String {
     jmbg::generate_ba(opts, rng)
 }
-fn gen_me(opts: &GenOptions, rng: &mut rand::rngs::ThreadRng) -> String {
+fn gen_me(opts: &GenOptions, rng: &mut impl Rng) -> String {
     jmbg::generate_me(opts, rng)
 }
 
@@ -294,6 +214,7 @@ impl Registry {
             RegistryEntry {
                 code: "EE",
                 name: "Isikukood",
+                checksum: Some("Weighted mod 11, two passes"),
                 generate: ee::generate,
                 validate: ee::validate,
                 parse: ee::parse,
@@ -301,6 +222,7 @@ impl Registry {
             RegistryEntry {
                 code: "LT",
                 name: "Asmens kodas",
+                checksum: Some("Weighted mod 11, two passes"),
                 generate: ee::generate,
                 validate: ee::validate,
                 parse: ee::parse,
@@ -308,13 +230,15 @@ impl Registry {
             RegistryEntry {
                 code: "LV",
                 name: "Personas kods",
+                checksum: Some("Weighted mod 11"),
                 generate: lv::generate,
                 validate: lv::validate,
                 parse: lv::parse,
             },
             RegistryEntry {
                 code: "FI",
-                name: "Henkilotunnus",
+                name: "Henkilötunnus",
+                checksum: Some("Mod 31 character"),
                 generate: fi::generate,
                 validate: fi::validate,
                 parse: fi::parse,
@@ -322,13 +246,15 @@ impl Registry {
             RegistryEntry {
                 code: "SE",
                 name: "Personnummer",
+                checksum: Some("Luhn"),
                 generate: se::generate,
                 validate: se::validate,
                 parse: se::parse,
             },
             RegistryEntry {
                 code: "NO",
-                name: "Fodselsnummer",
+                name: "Fødselsnummer",
+                checksum: Some("Weighted mod 11, two check digits"),
                 generate: no::generate,
                 validate: no::validate,
                 parse: no::parse,
@@ -336,6 +262,7 @@ impl Registry {
             RegistryEntry {
                 code: "DK",
                 name: "CPR-nummer",
+                checksum: None,
                 generate: dk::generate,
                 validate: dk::validate,
                 parse: dk::parse,
@@ -343,6 +270,7 @@ impl Registry {
             RegistryEntry {
                 code: "IS",
                 name: "Kennitala",
+                checksum: Some("Weighted mod 11"),
                 generate: is_::generate,
                 validate: is_::validate,
                 parse: is_::parse,
@@ -350,6 +278,7 @@ impl Registry {
             RegistryEntry {
                 code: "DE",
                 name: "Steuerliche IdNr",
+                checksum: Some("ISO 7064 MOD 11-10"),
                 generate: de::generate,
                 validate: de::validate,
                 parse: de::parse,
@@ -357,6 +286,7 @@ impl Registry {
             RegistryEntry {
                 code: "AT",
                 name: "Sozialversicherungsnr",
+                checksum: Some("Weighted mod 11"),
                 generate: at::generate,
                 validate: at::validate,
                 parse: at::parse,
@@ -364,6 +294,7 @@ impl Registry {
             RegistryEntry {
                 code: "CH",
                 name: "AHV-Nummer",
+                checksum: Some("EAN-13"),
                 generate: ch::generate,
                 validate: ch::validate,
                 parse: ch::parse,
@@ -371,6 +302,7 @@ impl Registry {
             RegistryEntry {
                 code: "NL",
                 name: "BSN",
+                checksum: Some("Weighted mod 11"),
                 generate: nl::generate,
                 validate: nl::validate,
                 parse: nl::parse,
@@ -378,6 +310,7 @@ impl Registry {
             RegistryEntry {
                 code: "BE",
                 name: "Rijksregisternr",
+                checksum: Some("Mod 97"),
                 generate: be::generate,
                 validate: be::validate,
                 parse: be::parse,
@@ -385,6 +318,7 @@ impl Registry {
             RegistryEntry {
                 code: "FR",
                 name: "NIR",
+                checksum: Some("Mod 97"),
                 generate: fr::generate,
                 validate: fr::validate,
                 parse: fr::parse,
@@ -392,6 +326,7 @@ impl Registry {
             RegistryEntry {
                 code: "ES",
                 name: "DNI",
+                checksum: Some("Mod 23 letter"),
                 generate: es::generate,
                 validate: es::validate,
                 parse: es::parse,
@@ -399,6 +334,7 @@ impl Registry {
             RegistryEntry {
                 code: "PT",
                 name: "NIF",
+                checksum: Some("Weighted mod 11"),
                 generate: pt::generate,
                 validate: pt::validate,
                 parse: pt::parse,
@@ -406,6 +342,7 @@ impl Registry {
             RegistryEntry {
                 code: "IT",
                 name: "Codice Fiscale",
+                checksum: Some("Odd/even character table mod 26"),
                 generate: it::generate,
                 validate: it::validate,
                 parse: it::parse,
@@ -413,6 +350,7 @@ impl Registry {
             RegistryEntry {
                 code: "GB",
                 name: "NINO",
+                checksum: None,
                 generate: gb::generate,
                 validate: gb::validate,
                 parse: gb::parse,
@@ -420,6 +358,7 @@ impl Registry {
             RegistryEntry {
                 code: "IE",
                 name: "PPS Number",
+                checksum: Some("Weighted mod 23 letter"),
                 generate: ie::generate,
                 validate: ie::validate,
                 parse: ie::parse,
@@ -427,20 +366,23 @@ impl Registry {
             RegistryEntry {
                 code: "PL",
                 name: "PESEL",
+                checksum: Some("Weighted mod 10"),
                 generate: pl::generate,
                 validate: pl::validate,
                 parse: pl::parse,
             },
             RegistryEntry {
                 code: "CZ",
-                name: "Rodne cislo",
+                name: "Rodné číslo",
+                checksum: Some("Mod 11"),
                 generate: cz::generate,
                 validate: cz::validate,
                 parse: cz::parse,
             },
             RegistryEntry {
                 code: "SK",
-                name: "Rodne cislo",
+                name: "Rodné číslo",
+                checksum: Some("Mod 11"),
                 generate: cz::generate,
                 validate: cz::validate,
                 parse: cz::parse,
@@ -448,6 +390,7 @@ impl Registry {
             RegistryEntry {
                 code: "RO",
                 name: "CNP",
+                checksum: Some("Weighted mod 11"),
                 generate: ro::generate,
                 validate: ro::validate,
                 parse: ro::parse,
@@ -455,6 +398,7 @@ impl Registry {
             RegistryEntry {
                 code: "BG",
                 name: "EGN",
+                checksum: Some("Weighted mod 11"),
                 generate: bg::generate,
                 validate: bg::validate,
                 parse: bg::parse,
@@ -462,6 +406,7 @@ impl Registry {
             RegistryEntry {
                 code: "HR",
                 name: "OIB",
+                checksum: Some("ISO 7064 MOD 11-10"),
                 generate: hr::generate,
                 validate: hr::validate,
                 parse: hr::parse,
@@ -469,6 +414,7 @@ impl Registry {
             RegistryEntry {
                 code: "SI",
                 name: "EMSO",
+                checksum: Some("Weighted mod 11"),
                 generate: gen_si,
                 validate: jmbg::validate,
                 parse: jmbg::parse,
@@ -476,6 +422,7 @@ impl Registry {
             RegistryEntry {
                 code: "RS",
                 name: "JMBG",
+                checksum: Some("Weighted mod 11"),
                 generate: gen_rs,
                 validate: jmbg::validate,
                 parse: jmbg::parse,
@@ -483,6 +430,7 @@ impl Registry {
             RegistryEntry {
                 code: "BA",
                 name: "JMBG",
+                checksum: Some("Weighted mod 11"),
                 generate: gen_ba,
                 validate: jmbg::validate,
                 parse: jmbg::parse,
@@ -490,6 +438,7 @@ impl Registry {
             RegistryEntry {
                 code: "ME",
                 name: "JMBG",
+                checksum: Some("Weighted mod 11"),
                 generate: gen_me,
                 validate: jmbg::validate,
                 parse: jmbg::parse,
@@ -497,6 +446,7 @@ impl Registry {
             RegistryEntry {
                 code: "TR",
                 name: "TC Kimlik No",
+                checksum: Some("Mod 10, two check digits"),
                 generate: tr::generate,
                 validate: tr::validate,
                 parse: tr::parse,
@@ -504,6 +454,7 @@ impl Registry {
             RegistryEntry {
                 code: "GR",
                 name: "AMKA",
+                checksum: Some("Luhn"),
                 generate: gr::generate,
                 validate: gr::validate,
                 parse: gr::parse,
@@ -512,6 +463,7 @@ impl Registry {
             RegistryEntry {
                 code: "US",
                 name: "SSN",
+                checksum: None,
                 generate: us::generate,
                 validate: us::validate,
                 parse: us::parse,
@@ -519,6 +471,7 @@ impl Registry {
             RegistryEntry {
                 code: "CA",
                 name: "SIN",
+                checksum: Some("Luhn"),
                 generate: ca::generate,
                 validate: ca::validate,
                 parse: ca::parse,
@@ -526,6 +479,7 @@ impl Registry {
             RegistryEntry {
                 code: "BR",
                 name: "CPF",
+                checksum: Some("Weighted mod 11, two check digits"),
                 generate: br::generate,
                 validate: br::validate,
                 parse: br::parse,
@@ -533,6 +487,7 @@ impl Registry {
             RegistryEntry {
                 code: "AR",
                 name: "CUIL",
+                checksum: Some("Weighted mod 11"),
                 generate: ar::generate,
                 validate: ar::validate,
                 parse: ar::parse,
@@ -540,6 +495,7 @@ impl Registry {
             RegistryEntry {
                 code: "CL",
                 name: "RUT",
+                checksum: Some("Weighted mod 11"),
                 generate: cl::generate,
                 validate: cl::validate,
                 parse: cl::parse,
@@ -547,6 +503,7 @@ impl Registry {
             RegistryEntry {
                 code: "CO",
                 name: "NIT",
+                checksum: Some("Weighted mod 11"),
                 generate: co::generate,
                 validate: co::validate,
                 parse: co::parse,
@@ -554,6 +511,7 @@ impl Registry {
             RegistryEntry {
                 code: "UY",
                 name: "CI",
+                checksum: Some("Weighted mod 10"),
                 generate: uy::generate,
                 validate: uy::validate,
                 parse: uy::parse,
@@ -561,20 +519,23 @@ impl Registry {
             RegistryEntry {
                 code: "CU",
                 name: "NI",
+                checksum: None,
                 generate: cu::generate,
                 validate: cu::validate,
                 parse: cu::parse,
             },
             RegistryEntry {
                 code: "DO",
-                name: "Cedula",
+                name: "Cédula",
+                checksum: Some("Luhn"),
                 generate: do_::generate,
                 validate: do_::validate,
                 parse: do_::parse,
             },
             RegistryEntry {
                 code: "EC",
-                name: "Cedula",
+                name: "Cédula",
+                checksum: Some("Luhn"),
                 generate: ec::generate,
                 validate: ec::validate,
                 parse: ec::parse,
@@ -582,6 +543,7 @@ impl Registry {
             RegistryEntry {
                 code: "PE",
                 name: "DNI",
+                checksum: None,
                 generate: pe::generate,
                 validate: pe::validate,
                 parse: pe::parse,
@@ -589,6 +551,7 @@ impl Registry {
             RegistryEntry {
                 code: "MX",
                 name: "CURP",
+                checksum: Some("Weighted mod 10"),
                 generate: mx::generate,
                 validate: mx::validate,
                 parse: mx::parse,
@@ -597,6 +560,7 @@ impl Registry {
             RegistryEntry {
                 code: "CN",
                 name: "Resident ID",
+                checksum: Some("ISO 7064 MOD 11-2"),
                 generate: cn::generate,
                 validate: cn::validate,
                 parse: cn::parse,
@@ -604,6 +568,7 @@ impl Registry {
             RegistryEntry {
                 code: "IN",
                 name: "Aadhaar",
+                checksum: Some("Verhoeff"),
                 generate: in_::generate,
                 validate: in_::validate,
                 parse: in_::parse,
@@ -611,6 +576,7 @@ impl Registry {
             RegistryEntry {
                 code: "JP",
                 name: "My Number",
+                checksum: Some("Weighted mod 11"),
                 generate: jp::generate,
                 validate: jp::validate,
                 parse: jp::parse,
@@ -618,6 +584,7 @@ impl Registry {
             RegistryEntry {
                 code: "KR",
                 name: "RRN",
+                checksum: Some("Weighted mod 11"),
                 generate: kr::generate,
                 validate: kr::validate,
                 parse: kr::parse,
@@ -625,6 +592,7 @@ impl Registry {
             RegistryEntry {
                 code: "TW",
                 name: "National ID",
+                checksum: Some("Weighted mod 10"),
                 generate: tw::generate,
                 validate: tw::validate,
                 parse: tw::parse,
@@ -632,6 +600,7 @@ impl Registry {
             RegistryEntry {
                 code: "TH",
                 name: "Citizen ID",
+                checksum: Some("Weighted mod 11"),
                 generate: th::generate,
                 validate: th::validate,
                 parse: th::parse,
@@ -639,6 +608,7 @@ impl Registry {
             RegistryEntry {
                 code: "SG",
                 name: "NRIC",
+                checksum: Some("Weighted mod 11 letter"),
                 generate: sg::generate,
                 validate: sg::validate,
                 parse: sg::parse,
@@ -646,6 +616,7 @@ impl Registry {
             RegistryEntry {
                 code: "MY",
                 name: "MyKad",
+                checksum: None,
                 generate: my::generate,
                 validate: my::validate,
                 parse: my::parse,
@@ -653,6 +624,7 @@ impl Registry {
             RegistryEntry {
                 code: "ID",
                 name: "NIK",
+                checksum: None,
                 generate: id_::generate,
                 validate: id_::validate,
                 parse: id_::parse,
@@ -660,6 +632,7 @@ impl Registry {
             RegistryEntry {
                 code: "HK",
                 name: "HKID",
+                checksum: Some("Weighted mod 11"),
                 generate: hk::generate,
                 validate: hk::validate,
                 parse: hk::parse,
@@ -667,6 +640,7 @@ impl Registry {
             RegistryEntry {
                 code: "AU",
                 name: "TFN",
+                checksum: Some("Weighted mod 11"),
                 generate: au::generate,
                 validate: au::validate,
                 parse: au::parse,
@@ -674,6 +648,7 @@ impl Registry {
             RegistryEntry {
                 code: "NZ",
                 name: "IRD",
+                checksum: Some("Weighted mod 11"),
                 generate: nz::generate,
                 validate: nz::validate,
                 parse: nz::parse,
@@ -682,6 +657,7 @@ impl Registry {
             RegistryEntry {
                 code: "ZA",
                 name: "SA ID",
+                checksum: Some("Luhn"),
                 generate: za::generate,
                 validate: za::validate,
                 parse: za::parse,
@@ -689,6 +665,7 @@ impl Registry {
             RegistryEntry {
                 code: "IL",
                 name: "Teudat Zehut",
+                checksum: Some("Luhn"),
                 generate: il::generate,
                 validate: il::validate,
                 parse: il::parse,
@@ -696,6 +673,7 @@ impl Registry {
             RegistryEntry {
                 code: "EG",
                 name: "National ID",
+                checksum: Some("Luhn"),
                 generate: eg::generate,
                 validate: eg::validate,
                 parse: eg::parse,
@@ -703,6 +681,7 @@ impl Registry {
             RegistryEntry {
                 code: "DZ",
                 name: "NIF",
+                checksum: Some("Luhn"),
                 generate: dz::generate,
                 validate: dz::validate,
                 parse: dz::parse,
@@ -710,6 +689,7 @@ impl Registry {
             RegistryEntry {
                 code: "MU",
                 name: "NID",
+                checksum: Some("Weighted mod 17"),
                 generate: mu::generate,
                 validate: mu::validate,
                 parse: mu::parse,
@@ -717,6 +697,7 @@ impl Registry {
             RegistryEntry {
                 code: "PK",
                 name: "CNIC",
+                checksum: None,
                 generate: pk::generate,
                 validate: pk::validate,
                 parse: pk::parse,
@@ -724,6 +705,7 @@ impl Registry {
             RegistryEntry {
                 code: "SA",
                 name: "Iqama",
+                checksum: Some("Luhn"),
                 generate: sa::generate,
                 validate: sa::validate,
                 parse: sa::parse,
@@ -732,6 +714,7 @@ impl Registry {
             RegistryEntry {
                 code: "KZ",
                 name: "IIN",
+                checksum: Some("Weighted mod 11, two passes"),
                 generate: kz::generate,
                 validate: kz::validate,
                 parse: kz::parse,
@@ -740,6 +723,7 @@ impl Registry {
             RegistryEntry {
                 code: "UA",
                 name: "RNTRKP",
+                checksum: Some("Weighted mod 11"),
                 generate: ua::generate,
                 validate: ua::validate,
                 parse: ua::parse,
@@ -748,73 +732,148 @@ impl Registry {
             RegistryEntry {
                 code: "LU",
                 name: "Matricule",
+                checksum: Some("Luhn and Verhoeff"),
                 generate: lu::generate,
                 validate: lu::validate,
                 parse: lu::parse,
             },
         ];
-        Registry { entries }
+        Registry {
+            entries,
+            custom: RwLock::new(Vec::new()),
+            revision: AtomicUsize::new(0),
+        }
     }
 
     fn find(&self, country: &str) -> Option<&RegistryEntry> {
         self.entries.iter().find(|e| e.code == country)
     }
 
-    pub fn generate(
+    fn find_custom(&self, country: &str) -> Option<CustomEntry> {
+        let custom = self.custom.read().unwrap_or_else(|e| e.into_inner());
+        custom
+            .iter()
+            .find(|e| e.code.eq_ignore_ascii_case(country))
+            .cloned()
+    }
+
+    /// Registered schemes take precedence over built-in ones; territories
+    /// fall back to their parent country unless `strict` is set. Returns the
+    /// effective country code along with the scheme.
+    fn lookup(&self, country: &str, strict: bool) -> Option<(String, Scheme<'_>)> {
+        let direct = |code: &str| {
+            self.find_custom(code)
+                .map(Scheme::Custom)
+                .or_else(|| self.find(code).map(Scheme::Builtin))
+        };
+        let effective =
+            crate::countries::effective_country_code(country, strict, |c| direct(c).is_some())?;
+        let scheme = direct(&effective)?;
+        Some((effective, scheme))
+    }
+
+    /// Country whose scheme applies to `country`: the country itself, or
+    /// the parent for a territory without its own (`"PR"` → `"US"`).
+    pub fn effective_country(&self, country: &str) -> Option<String> {
+        self.lookup(country, false).map(|(effective, _)| effective)
+    }
+
+    /// Add a scheme for a country (or any custom code such as `"ACME"`)
+    /// that has no personal ID yet.
+    ///
+    /// Fails if the code is already supported; use
+    /// [`override_scheme`](Self::override_scheme) to replace a built-in rule.
+    pub fn register(&self, country: &str, scheme: impl IdScheme + 'static) -> Result<(), String> {
+        let key = crate::scheme::registration_key(country)?;
+        if self.lookup(&key, true).is_some() {
+            return Err(format!(
+                "{} already has a personal ID scheme; use override_scheme to replace it",
+                key
+            ));
+        }
+        self.override_scheme(&key, scheme)
+    }
+
+    /// Add a scheme for a country, replacing any built-in or previously
+    /// registered one.
+    pub fn override_scheme(
         &self,
         country: &str,
-        opts: &GenOptions,
-        rng: &mut rand::rngs::ThreadRng,
-    ) -> Option<String> {
-        if let Some(entry) = self.find(country) {
-            return Some((entry.generate)(opts, rng));
-        }
-        if let Some(alias) = resolve_alias(country) {
-            if let Some(entry) = self.find(alias.parent_code) {
-                return Some((entry.generate)(opts, rng));
+        scheme: impl IdScheme + 'static,
+    ) -> Result<(), String> {
+        let key = crate::scheme::registration_key(country)?;
+        let mut custom = self.custom.write().unwrap_or_else(|e| e.into_inner());
+        let entry = CustomEntry {
+            code: key.into(),
+            name: scheme.name().into(),
+            scheme: Arc::new(scheme),
+        };
+        custom.retain(|e| e.code != entry.code);
+        custom.push(entry);
+        self.revision.fetch_add(1, Ordering::Relaxed);
+        Ok(())
+    }
+
+    /// Changes whenever a scheme is registered, so callers can cache
+    /// per-scheme data.
+    pub(crate) fn revision(&self) -> usize {
+        self.revision.load(Ordering::Relaxed)
+    }
+
+    /// Generate a personal ID for `country`.
+    ///
+    /// Any [`Rng`] can be used; pass a seeded RNG (e.g. `StdRng::seed_from_u64`)
+    /// for reproducible output.
+    pub fn generate(&self, country: &str, opts: &GenOptions, rng: &mut impl Rng) -> Option<String> {
+        let (effective, scheme) = self.lookup(country, opts.strict)?;
+        if let Some(region) = opts.region.as_deref() {
+            let known = crate::countries::subdivisions::of(&effective);
+            if !known.is_empty()
+                && crate::countries::subdivisions::lookup(&effective, region).is_none()
+            {
+                return None;
             }
         }
-        None
+        let mut rng = StdRng::from_rng(rng).ok()?;
+        scheme.generate(opts, &mut rng)
     }
 
     pub fn validate(&self, country: &str, code: &str) -> Option<bool> {
-        if let Some(entry) = self.find(country) {
-            return Some((entry.validate)(code));
-        }
-        if let Some(alias) = resolve_alias(country) {
-            if let Some(entry) = self.find(alias.parent_code) {
-                return Some((entry.validate)(code));
-            }
-        }
-        None
+        self.lookup(country, false)
+            .map(|(_, scheme)| scheme.validate(code))
     }
 
     pub fn parse(&self, country: &str, code: &str) -> Option<IdResult> {
-        let mut result = if let Some(entry) = self.find(country) {
-            (entry.parse)(code)
-        } else if let Some(alias) = resolve_alias(country) {
-            if let Some(entry) = self.find(alias.parent_code) {
-                (entry.parse)(code)
-            } else {
-                return None;
-            }
-        } else {
-            return None;
-        };
-        result.country_code = country.to_string();
+        let (effective, scheme) = self.lookup(country, false)?;
+        let mut result = scheme.parse(code);
+        result.country_code = country.to_uppercase();
+        result.effective_country_code = effective;
         Some(result)
     }
 
-    pub fn name(&self, country: &str) -> Option<&str> {
-        if let Some(e) = self.find(country) {
-            return Some(e.name);
-        }
-        if let Some(alias) = resolve_alias(country) {
-            if let Some(e) = self.find(alias.parent_code) {
-                return Some(e.name);
+    pub fn name(&self, country: &str) -> Option<String> {
+        self.lookup(country, false)
+            .map(|(_, scheme)| scheme.name().to_string())
+    }
+
+    /// Name of the check algorithm of the country's ID (e.g. `"Luhn"`), or
+    /// `None` if it has none or a registered scheme does not declare it.
+    pub fn checksum(&self, country: &str) -> Option<String> {
+        self.lookup(country, false)
+            .and_then(|(_, scheme)| scheme.checksum())
+    }
+
+    /// Name of the country's ID in `locale`; registered schemes keep their
+    /// own name.
+    pub fn name_in(&self, country: &str, locale: Locale) -> Option<String> {
+        let (effective, scheme) = self.lookup(country, false)?;
+        let localized = match scheme {
+            Scheme::Builtin(_) => {
+                crate::locale::document_name(IdKind::PersonalId, &effective, locale)
             }
-        }
-        None
+            Scheme::Custom(_) => None,
+        };
+        Some(localized.unwrap_or(scheme.name()).to_string())
     }
 
     pub fn country_name(&self, country: &str) -> Option<&str> {
@@ -822,35 +881,52 @@ impl Registry {
     }
 
     pub fn is_supported(&self, country: &str) -> bool {
-        self.find(country).is_some() || resolve_alias(country).is_some()
+        self.lookup(country, false).is_some()
     }
 
-    pub fn list_countries(&self) -> Vec<(&str, &str, &str)> {
+    /// `(code, country name, ID name)` for every supported country,
+    /// including registered schemes and territories using their parent's.
+    pub fn list_countries(&self) -> Vec<(String, String, String)> {
         let mut seen = std::collections::HashSet::new();
         let mut result = Vec::new();
-        for e in &self.entries {
-            if seen.insert(e.code) {
+        let mut push = |code: &str, name: &str| {
+            if seen.insert(code.to_string()) {
                 result.push((
-                    e.code,
-                    crate::countries::get_country_name(e.code).unwrap_or("Unknown"),
-                    e.name,
+                    code.to_string(),
+                    crate::countries::get_country_name(code)
+                        .unwrap_or("Unknown")
+                        .to_string(),
+                    name.to_string(),
                 ));
             }
+        };
+        let custom = self.custom.read().unwrap_or_else(|e| e.into_inner());
+        for e in custom.iter() {
+            push(&e.code, &e.name);
         }
-        for alias in TERRITORY_ALIASES {
-            if seen.insert(alias.code) {
-                let name = self
-                    .find(alias.parent_code)
-                    .map(|e| e.name)
-                    .unwrap_or("National ID");
-                result.push((
-                    alias.code,
-                    crate::countries::get_country_name(alias.code).unwrap_or("Unknown"),
-                    name,
-                ));
+        drop(custom);
+        for e in &self.entries {
+            push(e.code, e.name);
+        }
+        for alias in crate::countries::TERRITORY_ALIASES {
+            if let Some((_, scheme)) = self.lookup(alias.parent_code, true) {
+                push(alias.code, scheme.name());
             }
         }
-        result.sort_by_key(|(code, _, _)| *code);
+        result.sort_by(|a, b| a.0.cmp(&b.0));
         result
     }
+
+    /// Like [`list_countries`](Self::list_countries), with country and ID
+    /// names in `locale`.
+    pub fn list_countries_in(&self, locale: Locale) -> Vec<(String, String, String)> {
+        self.list_countries()
+            .into_iter()
+            .map(|(code, _, name)| {
+                let country_name = crate::locale::country_label(&code, locale).to_string();
+                let name = self.name_in(&code, locale).unwrap_or(name);
+                (code, country_name, name)
+            })
+            .collect()
+    }
 }
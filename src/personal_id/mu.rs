@@ -21,7 +21,7 @@ fn calc_check_digit(number: &[u8]) -> u8 {
     ALPHABET[((17u32.wrapping_sub(sum % 17)) % 17) as usize]
 }
 
-pub fn generate(_opts: &super::GenOptions, rng: &mut rand::rngs::ThreadRng) -> String {
+pub fn generate(_opts: &super::GenOptions, rng: &mut impl Rng) -> String {
     // First char: A-Z (first letter of surname)
     let first = (b'A' + rng.gen_range(0..26u8)) as char;
 
@@ -101,9 +101,11 @@ pub fn parse(code: &str) -> IdResult {
 
     IdResult {
         country_code: "".to_string(),
+        effective_country_code: "".to_string(),
         code: clean,
         gender: None,
         dob,
+        region: None,
         valid,
     }
 }
@@ -2,13 +2,61 @@ use rand::Rng;
 
 use super::date::Gender;
 use super::IdResult;
-
-static STATES: &[&str] = &[
-    "AS", "BC", "BS", "CC", "CL", "CM", "CS", "CH", "DF", "DG", "GT", "GR", "HG", "JC", "MC", "MN",
-    "MS", "NT", "NL", "OC", "PL", "QT", "QR", "SP", "SL", "SR", "TC", "TS", "TL", "VZ", "YN", "ZS",
-    "NE",
+use crate::countries::subdivisions::{self, Subdivision};
+
+/// CURP state codes for each ISO 3166-2 state.
+static STATES: &[(&str, &str)] = &[
+    ("AGU", "AS"),
+    ("BCN", "BC"),
+    ("BCS", "BS"),
+    ("CAM", "CC"),
+    ("CHH", "CH"),
+    ("CHP", "CS"),
+    ("CMX", "DF"),
+    ("COA", "CL"),
+    ("COL", "CM"),
+    ("DUR", "DG"),
+    ("GRO", "GR"),
+    ("GUA", "GT"),
+    ("HID", "HG"),
+    ("JAL", "JC"),
+    ("MEX", "MC"),
+    ("MIC", "MN"),
+    ("MOR", "MS"),
+    ("NAY", "NT"),
+    ("NLE", "NL"),
+    ("OAX", "OC"),
+    ("PUE", "PL"),
+    ("QUE", "QT"),
+    ("ROO", "QR"),
+    ("SIN", "SL"),
+    ("SLP", "SP"),
+    ("SON", "SR"),
+    ("TAB", "TC"),
+    ("TAM", "TS"),
+    ("TLA", "TL"),
+    ("VER", "VZ"),
+    ("YUC", "YN"),
+    ("ZAC", "ZS"),
 ];
 
+/// CURP state code for people born abroad, which has no ISO 3166-2 state.
+const BORN_ABROAD: &str = "NE";
+
+/// CURP state code for an ISO 3166-2 state.
+fn curp_state(state: &Subdivision) -> Option<&'static str> {
+    STATES
+        .iter()
+        .find(|(iso, _)| *iso == state.suffix())
+        .map(|(_, curp)| *curp)
+}
+
+/// ISO 3166-2 state for a CURP state code.
+fn iso_state(curp: &str) -> Option<&'static Subdivision> {
+    let (iso, _) = STATES.iter().find(|(_, c)| *c == curp)?;
+    subdivisions::lookup("MX", iso)
+}
+
 static BAD_WORDS: &[&str] = &[
     "BACA", "BAKA", "BUEI", "BUEY", "CACA", "CACO", "CAGA", "CAGO", "CAKA", "CAKO", "COGE", "COGI",
     "COJA", "COJE", "COJI", "COJO", "COLA", "CULO", "FALO", "FETO", "GETA", "GUEI", "GUEY", "JETA",
@@ -57,7 +105,16 @@ pub fn generate(opts: &super::GenOptions, rng: &mut impl Rng) -> String {
         Gender::Male => 'H',
         Gender::Female => 'M',
     };
-    let state = STATES[rng.gen_range(0..STATES.len())];
+    let state = opts
+        .region
+        .as_deref()
+        .and_then(|r| subdivisions::lookup("MX", r))
+        .and_then(curp_state)
+        .unwrap_or_else(|| {
+            STATES
+                .get(rng.gen_range(0..=STATES.len()))
+                .map_or(BORN_ABROAD, |(_, curp)| curp)
+        });
 
     loop {
         // Positions 0-3: surname1 initial, surname1 first vowel, surname2 initial, name initial
@@ -123,7 +180,7 @@ pub fn validate(code: &str) -> bool {
 }
 
 pub fn parse(code: &str) -> IdResult {
-    let (gender, dob) = if code.len() == 18 {
+    let (gender, dob, region) = if code.len() == 18 {
         let g = match code.as_bytes()[10] {
             b'H' => Some("male".to_string()),
             b'M' => Some("female".to_string()),
@@ -140,16 +197,19 @@ pub fn parse(code: &str) -> IdResult {
             1900 + yy
         };
         let d = format!("{:04}-{:02}-{:02}", year, mm, dd);
-        (g, Some(d))
+        let region = code.get(11..13).and_then(iso_state);
+        (g, Some(d), region.map(|s| s.suffix().to_string()))
     } else {
-        (None, None)
+        (None, None, None)
     };
 
     IdResult {
         country_code: "".to_string(),
+        effective_country_code: "".to_string(),
         code: code.to_string(),
         gender,
         dob,
+        region,
         valid: validate(code),
     }
 }
@@ -62,6 +62,7 @@ pub fn parse(code: &str) -> IdResult {
 
     IdResult {
         country_code: "".to_string(),
+        effective_country_code: "".to_string(),
         code: if clean.len() == 12 {
             format!("{}-{}-{}", &clean[0..6], &clean[6..8], &clean[8..12])
         } else {
@@ -69,6 +70,7 @@ pub fn parse(code: &str) -> IdResult {
         },
         gender,
         dob,
+        region: None,
         valid: validate(code),
     }
 }
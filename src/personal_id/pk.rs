@@ -3,7 +3,7 @@ use rand::Rng;
 use super::date::Gender;
 use super::IdResult;
 
-pub fn generate(opts: &super::GenOptions, rng: &mut rand::rngs::ThreadRng) -> String {
+pub fn generate(opts: &super::GenOptions, rng: &mut impl Rng) -> String {
     // Province digit (1-7)
     let province: u8 = rng.gen_range(1..=7);
 
@@ -62,6 +62,7 @@ pub fn parse(code: &str) -> IdResult {
 
     IdResult {
         country_code: "".to_string(),
+        effective_country_code: "".to_string(),
         code: if clean.len() == 13 {
             format!("{}-{}-{}", &clean[..5], &clean[5..12], &clean[12..])
         } else {
@@ -69,6 +70,7 @@ pub fn parse(code: &str) -> IdResult {
         },
         gender,
         dob: None,
+        region: None,
         valid,
     }
 }
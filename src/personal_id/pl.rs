@@ -71,6 +71,7 @@ pub fn parse(code: &str) -> IdResult {
     };
     IdResult {
         country_code: "".to_string(),
+        effective_country_code: "".to_string(),
         code: code.to_string(),
         gender: Some(
             if (code.as_bytes()[9] - b'0') % 2 == 1 {
@@ -81,6 +82,7 @@ pub fn parse(code: &str) -> IdResult {
             .to_string(),
         ),
         dob: Some(format!("{}-{:02}-{:02}", year, month, dd)),
+        region: None,
         valid: validate(code),
     }
 }
@@ -48,6 +48,7 @@ pub fn parse(code: &str) -> IdResult {
     };
     IdResult {
         country_code: "".to_string(),
+        effective_country_code: "".to_string(),
         code: code.to_string(),
         gender: Some(
             if clean.as_bytes()[8] % 2 == 1 {
@@ -58,6 +59,7 @@ pub fn parse(code: &str) -> IdResult {
             .to_string(),
         ),
         dob: Some(format!("{}-{:02}-{:02}", century + yy, mm, dd)),
+        region: None,
         valid: validate(code),
     }
 }
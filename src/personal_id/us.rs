@@ -29,6 +29,7 @@ pub fn parse(code: &str) -> IdResult {
     let clean: String = code.chars().filter(|c| *c != '-').collect();
     IdResult {
         country_code: "".to_string(),
+        effective_country_code: "".to_string(),
         code: if clean.len() == 9 {
             format!("{}-{}-{}", &clean[0..3], &clean[3..5], &clean[5..9])
         } else {
@@ -36,6 +37,7 @@ pub fn parse(code: &str) -> IdResult {
         },
         gender: None,
         dob: None,
+        region: None,
         valid: validate(code),
     }
 }
@@ -0,0 +1,274 @@
+//! Data-quality profiles of identifier columns.
+//!
+//! [`profile_column`] detects which identifier kind a column holds, then
+//! reports its validity rate and failure reasons, the countries seen, how
+//! the values are written, duplicates, and the birth dates and genders
+//! encoded in personal IDs.
+//!
+//! ```rust
+//! use idsmith::batch::Failure;
+//! use idsmith::kind::IdKind;
+//! use idsmith::profile::profile_column;
+//!
+//! let values = [
+//!     "DE89370400440532013000",
+//!     "de89 3704 0044 0532 0130 00",
+//!     "GB82WEST12345698765432",
+//!     "GB82WEST12345698765431",
+//!     "",
+//! ];
+//! let profile = profile_column("iban", &values);
+//! assert_eq!(profile.kind, Some(IdKind::Iban));
+//! assert_eq!(profile.empty, 1);
+//! assert_eq!(profile.countries["DE"], 2);
+//!
+//! let validity = profile.validity.unwrap();
+//! assert_eq!((validity.valid, validity.invalid), (2, 2));
+//! assert_eq!(validity.reasons[&Failure::Checksum], 1);
+//! assert_eq!(validity.reasons[&Failure::Formatting], 1);
+//! assert_eq!((profile.variants.spaces, profile.variants.lowercase), (1, 1));
+//! assert_eq!(profile.duplicates, 1);
+//! ```
+
+use std::collections::{BTreeMap, HashSet};
+
+#[cfg(feature = "json")]
+use serde::Serialize;
+
+use crate::batch::{validate_batch, Failure, Item, Outcome, Summary};
+use crate::detect::{detect, detect_column, normalize};
+use crate::kind::IdKind;
+
+/// Values looked at to detect a column's kind.
+pub const DETECT_SAMPLE: usize = 1000;
+
+/// Share of the sampled values that must match a kind for the column to be
+/// profiled as that kind. Lower than for anonymization, since extracts with
+/// many broken codes are what a profile is for.
+pub const DETECT_RATIO: f64 = 0.5;
+
+/// How the non-empty values of a column are written. A value can count
+/// towards several variants.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "json", derive(Serialize))]
+pub struct Variants {
+    /// No separators, lowercase letters or surrounding whitespace.
+    pub compact: usize,
+    pub spaces: usize,
+    pub hyphens: usize,
+    pub dots: usize,
+    pub slashes: usize,
+    pub lowercase: usize,
+    /// Leading or trailing whitespace.
+    pub padded: usize,
+}
+
+impl Variants {
+    fn add(&mut self, value: &str) {
+        let trimmed = value.trim();
+        let spaces = trimmed.contains(char::is_whitespace);
+        let hyphens = trimmed.contains('-');
+        let dots = trimmed.contains('.');
+        let slashes = trimmed.contains('/');
+        let lowercase = trimmed.chars().any(|c| c.is_lowercase());
+        let padded = trimmed.len() != value.len();
+        self.spaces += spaces as usize;
+        self.hyphens += hyphens as usize;
+        self.dots += dots as usize;
+        self.slashes += slashes as usize;
+        self.lowercase += lowercase as usize;
+        self.padded += padded as usize;
+        if !(spaces || hyphens || dots || slashes || lowercase || padded) {
+            self.compact += 1;
+        }
+    }
+
+    /// Non-zero variants by name, in field order.
+    pub fn counts(&self) -> Vec<(&'static str, usize)> {
+        [
+            ("compact", self.compact),
+            ("spaces", self.spaces),
+            ("hyphens", self.hyphens),
+            ("dots", self.dots),
+            ("slashes", self.slashes),
+            ("lowercase", self.lowercase),
+            ("padded", self.padded),
+        ]
+        .into_iter()
+        .filter(|(_, n)| *n > 0)
+        .collect()
+    }
+}
+
+/// The profile of one column.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "json", derive(Serialize))]
+pub struct ColumnProfile {
+    pub name: String,
+    /// All values, including empty ones.
+    pub rows: usize,
+    pub empty: usize,
+    /// The detected kind; `None` when no kind matched enough values.
+    pub kind: Option<IdKind>,
+    /// The column's country, when all matches agree. For kinds that need a
+    /// country this is the one every value is validated against.
+    pub country: Option<String>,
+    /// Countries whose format fits the column equally well when detection
+    /// cannot tell them apart (e.g. EE and LT), `country` first. Empty when
+    /// the country is unambiguous.
+    pub candidates: Vec<String>,
+    /// Values per country that are valid as written or once normalized.
+    pub countries: BTreeMap<String, usize>,
+    /// Validity of the non-empty values against the detected kind.
+    pub validity: Option<Summary>,
+    pub variants: Variants,
+    /// Distinct non-empty values after normalization.
+    pub distinct: usize,
+    /// Non-empty values repeating an earlier one after normalization.
+    pub duplicates: usize,
+    /// Valid personal IDs per birth decade, e.g. `1980s`.
+    pub birth_decades: BTreeMap<String, usize>,
+    /// Valid personal IDs per encoded gender.
+    pub genders: BTreeMap<String, usize>,
+}
+
+/// Profile a column of values.
+pub fn profile_column<S: AsRef<str>>(name: &str, values: &[S]) -> ColumnProfile {
+    let mut profile = ColumnProfile {
+        name: name.to_string(),
+        rows: values.len(),
+        ..Default::default()
+    };
+    let values: Vec<&str> = values
+        .iter()
+        .map(AsRef::as_ref)
+        .filter(|v| !v.trim().is_empty())
+        .collect();
+    profile.empty = profile.rows - values.len();
+
+    let mut seen = HashSet::new();
+    for value in &values {
+        profile.variants.add(value);
+        if !seen.insert(normalize(value)) {
+            profile.duplicates += 1;
+        }
+    }
+    profile.distinct = seen.len();
+
+    let sample: Vec<&str> = values.iter().copied().take(DETECT_SAMPLE).collect();
+    let Some(target) = detect_column(sample.iter().copied(), DETECT_RATIO) else {
+        return profile;
+    };
+    profile.kind = Some(target.kind);
+    profile.country = target.country.clone();
+    if target.kind.requires_country() {
+        let candidates = candidate_countries(target.kind, &sample);
+        if let Some(first) = candidates.first() {
+            profile.country = Some(first.clone());
+        }
+        if candidates.len() > 1 {
+            profile.candidates = candidates;
+        }
+    }
+
+    let items: Vec<Item> = values
+        .iter()
+        .map(|value| Item::new(target.kind, profile.country.as_deref(), value))
+        .collect();
+    let outcomes = validate_batch(&items);
+
+    let usable = |o: &&Outcome| o.valid || o.reason == Some(Failure::Formatting);
+    for outcome in outcomes.iter().filter(usable) {
+        if let Some(country) = country_of(outcome) {
+            *profile.countries.entry(country).or_default() += 1;
+        }
+        if target.kind == IdKind::PersonalId {
+            let Some(parsed) = parse(outcome) else {
+                continue;
+            };
+            if let Some(decade) = parsed.dob.as_deref().and_then(decade) {
+                *profile.birth_decades.entry(decade).or_default() += 1;
+            }
+            if let Some(gender) = parsed.gender {
+                *profile.genders.entry(gender).or_default() += 1;
+            }
+        }
+    }
+    profile.validity = Some(Summary::new(&outcomes));
+    profile
+}
+
+/// Profile every column of a CSV file with a header row.
+#[cfg(feature = "csv")]
+pub fn profile_csv<R: std::io::BufRead>(reader: R) -> std::io::Result<Vec<ColumnProfile>> {
+    let mut reader = crate::csv::Reader::new(reader);
+    let Some(header) = reader.read_record()? else {
+        return Ok(Vec::new());
+    };
+    let mut columns: Vec<Vec<String>> = vec![Vec::new(); header.len()];
+    while let Some(row) = reader.read_record()? {
+        for (i, column) in columns.iter_mut().enumerate() {
+            column.push(row.get(i).cloned().unwrap_or_default());
+        }
+    }
+    Ok(header
+        .iter()
+        .zip(&columns)
+        .map(|(name, values)| profile_column(name, values))
+        .collect())
+}
+
+/// Countries that validate at least [`DETECT_RATIO`] of the sampled values,
+/// keeping only those with a check character when any has one, since a
+/// looser format matching as often says less. Most matches first.
+fn candidate_countries(kind: IdKind, sample: &[&str]) -> Vec<String> {
+    let mut counts: BTreeMap<String, usize> = BTreeMap::new();
+    for value in sample {
+        for target in detect(value) {
+            if let (true, Some(country)) = (target.kind == kind, target.country) {
+                *counts.entry(country).or_default() += 1;
+            }
+        }
+    }
+    let mut fits: Vec<(String, usize)> = counts
+        .into_iter()
+        .filter(|(_, n)| *n as f64 >= sample.len() as f64 * DETECT_RATIO)
+        .collect();
+    fits.sort_by_key(|(_, n)| std::cmp::Reverse(*n));
+    let checked: Vec<(String, usize)> = fits
+        .iter()
+        .filter(|(c, _)| crate::describe::has_checksum(kind, Some(c)) == Some(true))
+        .cloned()
+        .collect();
+    let fits = if checked.is_empty() { fits } else { checked };
+    fits.into_iter().map(|(c, _)| c).collect()
+}
+
+/// The country of a valid code: its prefix for self-describing kinds,
+/// otherwise the country it was validated for.
+fn country_of(outcome: &Outcome) -> Option<String> {
+    let code = &outcome.normalized;
+    let country = match outcome.kind {
+        IdKind::Iban => code.get(..2),
+        IdKind::Vat => code.get(..2).map(|c| if c == "EL" { "GR" } else { c }),
+        IdKind::Lei | IdKind::Swift => code.get(4..6),
+        IdKind::CreditCard => None,
+        _ => outcome.country.as_deref(),
+    };
+    country.map(str::to_string)
+}
+
+fn parse(outcome: &Outcome) -> Option<crate::personal_id::IdResult> {
+    let registry = crate::personal_ids();
+    let country = outcome.country.as_deref()?;
+    [outcome.code.trim(), outcome.normalized.as_str()]
+        .into_iter()
+        .filter_map(|code| registry.parse(country, code))
+        .find(|parsed| parsed.valid)
+}
+
+/// `1987-03-14` → `1980s`.
+fn decade(dob: &str) -> Option<String> {
+    let year: u32 = dob.get(..4)?.parse().ok()?;
+    Some(format!("{}s", year / 10 * 10))
+}
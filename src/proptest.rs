@@ -0,0 +1,197 @@
+//! [proptest](https://docs.rs/proptest) strategies for every identifier kind
+//! (`proptest` feature).
+//!
+//! Values are drawn from the regular generators using a seed picked by
+//! proptest, so shrinking works on the seed and every shrunk value is still
+//! checksum-correct. [`invalid`] produces near-misses of valid codes for
+//! negative tests. Strategies for an unsupported country panic when they
+//! are built.
+//!
+//! ```rust
+//! use proptest::prelude::*;
+//! use idsmith::proptest as ids;
+//!
+//! proptest! {
+//!     fn accepts_german_ibans(iban in ids::iban(Some("DE"))) {
+//!         prop_assert!(iban.starts_with("DE"));
+//!     }
+//!
+//!     fn accepts_swedish_ids(id in ids::personal_id("SE", Default::default())) {
+//!         prop_assert_eq!(id.country(), "SE");
+//!     }
+//! }
+//! # accepts_german_ibans();
+//! # accepts_swedish_ids();
+//! ```
+
+use std::fmt::Debug;
+
+use ::proptest::prelude::*;
+use ::proptest::sample::Index;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+use crate::kind::{IdKind, Target};
+use crate::{
+    bank_account, company_id, credit_card, driver_license, passport, personal_id, tax_id, Bic,
+    CardNumber, Iban, Lei, PersonalId, VatNumber,
+};
+
+/// Strategy over the values `generate` produces for proptest-chosen seeds.
+fn seeded<T: Debug + 'static>(
+    what: String,
+    generate: impl Fn(&mut StdRng) -> Option<T> + 'static,
+) -> BoxedStrategy<T> {
+    if generate(&mut StdRng::seed_from_u64(0)).is_none() {
+        panic!("Cannot generate {}", what);
+    }
+    any::<u64>()
+        .prop_map(move |seed| {
+            generate(&mut StdRng::seed_from_u64(seed))
+                .unwrap_or_else(|| panic!("Cannot generate {}", what))
+        })
+        .boxed()
+}
+
+/// Codes of any kind; `country` as for [`IdKind::generate`].
+pub fn identifier(kind: IdKind, country: Option<&str>) -> BoxedStrategy<String> {
+    let target = Target::new(kind, country);
+    seeded(target.to_string(), move |rng| target.generate(rng))
+}
+
+/// Codes of `kind` that fail validation: a valid code with one character
+/// changed, one appended or the last one dropped.
+///
+/// Panics if `kind` needs a country and none is given.
+pub fn invalid(kind: IdKind, country: Option<&str>) -> BoxedStrategy<String> {
+    let target = Target::new(kind, country);
+    if kind.requires_country() && country.is_none() {
+        panic!("{} requires a country", kind);
+    }
+    if target.generate(&mut StdRng::seed_from_u64(0)).is_none() {
+        panic!("Cannot generate {}", target);
+    }
+    (any::<u64>(), any::<Index>(), 1..10u8)
+        .prop_filter_map("mutation is still valid", move |(seed, index, delta)| {
+            let code = target.generate(&mut StdRng::seed_from_u64(seed))?;
+            mutations(&code, index, delta)
+                .into_iter()
+                .find(|m| target.validate(m) == Some(false))
+        })
+        .boxed()
+}
+
+/// Candidate near-misses of `code`, most subtle first.
+fn mutations(code: &str, index: Index, delta: u8) -> Vec<String> {
+    let mut chars: Vec<char> = code.chars().collect();
+    let mut candidates = Vec::new();
+    let positions: Vec<usize> = (0..chars.len())
+        .filter(|&i| chars[i].is_ascii_alphanumeric())
+        .collect();
+    if !positions.is_empty() {
+        let i = positions[index.index(positions.len())];
+        chars[i] = match chars[i] {
+            c @ '0'..='9' => (b'0' + (c as u8 - b'0' + delta) % 10) as char,
+            c @ 'A'..='Z' => (b'A' + (c as u8 - b'A' + delta) % 26) as char,
+            c => (b'a' + (c as u8 - b'a' + delta) % 26) as char,
+        };
+        candidates.push(chars.iter().collect());
+    }
+    candidates.push(format!("{}{}", code, delta % 10));
+    if code.len() > 1 {
+        candidates.push(code[..code.len() - 1].to_string());
+    }
+    candidates
+}
+
+/// IBANs for `country` (or a group such as `"sepa"`), any country if `None`.
+pub fn iban(country: Option<&str>) -> BoxedStrategy<Iban> {
+    let country = country.map(str::to_string);
+    seeded(format!("IBAN for {:?}", country), move |rng| {
+        crate::iban::generate_iban(country.as_deref(), rng)
+            .ok()
+            .map(Iban::from_generated)
+    })
+}
+
+pub fn bank_account(
+    country: &str,
+    opts: bank_account::GenOptions,
+) -> BoxedStrategy<bank_account::AccountResult> {
+    let country = country.to_string();
+    seeded(format!("bank account for {}", country), move |rng| {
+        crate::bank_accounts().generate(&country, &opts, rng)
+    })
+}
+
+pub fn personal_id(country: &str, opts: personal_id::GenOptions) -> BoxedStrategy<PersonalId> {
+    let country = country.to_uppercase();
+    seeded(format!("personal ID for {}", country), move |rng| {
+        let code = crate::personal_ids().generate(&country, &opts, rng)?;
+        PersonalId::new(&country, &code).ok()
+    })
+}
+
+pub fn card(opts: credit_card::GenOptions) -> BoxedStrategy<CardNumber> {
+    seeded(format!("card for {:?}", opts.brand), move |rng| {
+        crate::credit_cards().generate(&opts, rng).map(|r| r.number)
+    })
+}
+
+pub fn bic(country: Option<&str>) -> BoxedStrategy<Bic> {
+    let opts = crate::swift::GenOptions {
+        country: country.map(str::to_uppercase),
+    };
+    seeded(format!("BIC for {:?}", opts.country), move |rng| {
+        Some(crate::swift_codes().generate(&opts, rng).code)
+    })
+}
+
+pub fn lei(country: Option<&str>) -> BoxedStrategy<Lei> {
+    let opts = crate::lei::GenOptions {
+        country: country.map(str::to_uppercase),
+    };
+    seeded(format!("LEI for {:?}", opts.country), move |rng| {
+        Some(crate::lei_codes().generate(&opts, rng).code)
+    })
+}
+
+/// VAT numbers for `country` (or a group such as `"eurozone"`).
+pub fn vat(country: Option<&str>) -> BoxedStrategy<VatNumber> {
+    let opts = crate::vat::GenOptions {
+        country: country.map(str::to_string),
+        ..Default::default()
+    };
+    seeded(format!("VAT number for {:?}", opts.country), move |rng| {
+        crate::vat_ids().generate(&opts, rng).map(|r| r.code)
+    })
+}
+
+pub fn company_id(opts: company_id::GenOptions) -> BoxedStrategy<String> {
+    seeded(format!("company ID for {:?}", opts.country), move |rng| {
+        crate::company_ids().generate(&opts, rng).map(|r| r.code)
+    })
+}
+
+pub fn driver_license(opts: driver_license::GenOptions) -> BoxedStrategy<String> {
+    seeded(
+        format!("driver's license for {:?}", opts.country),
+        move |rng| {
+            crate::driver_licenses()
+                .generate(&opts, rng)
+                .map(|r| r.code)
+        },
+    )
+}
+
+pub fn tax_id(opts: tax_id::GenOptions) -> BoxedStrategy<String> {
+    seeded(format!("tax ID for {:?}", opts.country), move |rng| {
+        crate::tax_ids().generate(&opts, rng).map(|r| r.code)
+    })
+}
+
+pub fn passport(opts: passport::GenOptions) -> BoxedStrategy<String> {
+    seeded(format!("passport for {:?}", opts.country), move |rng| {
+        crate::passports().generate(&opts, rng).map(|r| r.code)
+    })
+}
@@ -0,0 +1,563 @@
+//! Find and redact identifiers in free text.
+//!
+//! [`scan`] looks for IBANs, card numbers, VAT numbers, personal IDs (SSNs,
+//! national IDs) and passport numbers in arbitrary text such as log lines.
+//! Every candidate is confirmed with the real validator. Checks that random
+//! numbers pass too often are not enough on their own: cards need a known
+//! brand prefix, and a personal ID is only reported without a nearby keyword
+//! (such as "SSN" or the document's name) when its scheme has a check digit
+//! and the number is written in its national format or is too unlikely to
+//! validate by chance. Passport numbers always need a keyword.
+//!
+//! ```rust
+//! use idsmith::kind::IdKind;
+//! use idsmith::scan::{redact, scan, RedactPolicy};
+//!
+//! let line = "refund to DE89 3704 0044 0532 0130 00 for card 4111-1111-1111-1111";
+//! let found = scan(line);
+//! assert_eq!(found[0].kind, IdKind::Iban);
+//! assert_eq!(found[0].country.as_deref(), Some("DE"));
+//! assert_eq!(&line[found[1].start..found[1].end], "4111-1111-1111-1111");
+//!
+//! assert_eq!(
+//!     redact(line, RedactPolicy::KeepLast4),
+//!     "refund to **** **** **** **** **30 00 for card ****-****-****-1111"
+//! );
+//! ```
+
+use std::collections::HashSet;
+use std::fmt;
+use std::str::FromStr;
+use std::sync::{Arc, Mutex};
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+#[cfg(feature = "json")]
+use serde::Serialize;
+
+use crate::kind::IdKind;
+
+/// Longest candidate, in tokens. An IBAN written in groups of four has at
+/// most nine groups.
+const MAX_TOKENS: usize = 9;
+
+/// Personal IDs are written in at most a few groups (`123-45-6789`,
+/// `850101-1234`); longer windows are not tried.
+const MAX_ID_TOKENS: usize = 4;
+
+/// Passport numbers carry no checksum in most countries, so they are only
+/// reported when one of these words appears shortly before the number.
+const PASSPORT_KEYWORDS: &[&str] = &[
+    "passport",
+    "reisepass",
+    "passeport",
+    "pasaporte",
+    "passaporto",
+    "paspoort",
+    "pass no",
+    "pass nr",
+];
+
+/// Words that mark the next number as a personal ID. The document name of
+/// each country's scheme (e.g. "personnummer", "PESEL") counts too.
+const ID_KEYWORDS: &[&str] = &[
+    "ssn",
+    "social security",
+    "national id",
+    "personal id",
+    "personal number",
+    "id number",
+    "id no",
+    "identity",
+];
+
+/// Common written forms of personal IDs that the generators emit without
+/// separators. Shapes use `9` for a digit and `A` for a letter.
+const WRITTEN_FORMATS: &[(&str, &[&str])] = &[
+    ("AR", &["99-99999999-9"]),
+    ("AU", &["999 999 999"]),
+    ("BE", &["99.99.99-999.99"]),
+    ("BR", &["999.999.999-99"]),
+    ("CA", &["999-999-999", "999 999 999"]),
+    ("CH", &["999.9999.9999.99"]),
+    (
+        "CL",
+        &["99.999.999-9", "99.999.999-A", "99999999-9", "99999999-A"],
+    ),
+    ("CZ", &["999999/9999", "999999/999"]),
+    ("DK", &["999999-9999"]),
+    ("FR", &["9 99 99 99 999 999 99"]),
+    ("GB", &["AA 99 99 99 A"]),
+    ("IN", &["9999 9999 9999"]),
+    ("JP", &["9999 9999 9999"]),
+    ("KR", &["999999-9999999"]),
+    ("MY", &["999999-99-9999"]),
+    ("NZ", &["999-999-999"]),
+    ("SK", &["999999/9999", "999999/999"]),
+    ("US", &["999-99-9999"]),
+];
+
+/// Samples generated per country to learn its written ID formats.
+const SHAPE_SAMPLES: usize = 32;
+
+/// Random codes tried per country to see how often chance alone validates.
+const CHANCE_SAMPLES: usize = 2000;
+
+/// Random codes of a country's shape may validate at most this many times
+/// out of [`CHANCE_SAMPLES`] for unseparated numbers to be reported without
+/// a keyword (1 in 1000).
+const MAX_CHANCE_HITS: usize = 2;
+
+/// How far back (in characters) to look for a keyword.
+const KEYWORD_WINDOW: usize = 40;
+
+/// The kinds [`scan`] looks for.
+pub const SCAN_KINDS: &[IdKind] = &[
+    IdKind::Iban,
+    IdKind::Vat,
+    IdKind::CreditCard,
+    IdKind::PersonalId,
+    IdKind::Passport,
+];
+
+/// An identifier found in text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "json", derive(Serialize))]
+pub struct Finding {
+    /// Byte offset of the first character.
+    pub start: usize,
+    /// Byte offset one past the last character.
+    pub end: usize,
+    pub kind: IdKind,
+    /// Country, when the code carries one or only one country matched.
+    pub country: Option<String>,
+    /// The identifier as written in the text.
+    pub text: String,
+}
+
+/// Options for [`scan_with`].
+#[derive(Debug, Clone, Default)]
+pub struct ScanOptions {
+    /// Restrict personal ID and passport checks to these countries.
+    /// Empty means every supported country. Personal IDs of listed
+    /// countries are reported without a keyword, even if their scheme has
+    /// no check digit.
+    pub countries: Vec<String>,
+    /// Kinds to look for. Empty means all of [`SCAN_KINDS`].
+    pub kinds: Vec<IdKind>,
+}
+
+/// How [`redact`] masks a finding.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RedactPolicy {
+    /// Mask every letter and digit.
+    #[default]
+    FullMask,
+    /// Keep the last four letters or digits.
+    KeepLast4,
+    /// Keep the country prefix of IBANs and VAT numbers; mask everything else.
+    KeepCountry,
+}
+
+impl fmt::Display for RedactPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            RedactPolicy::FullMask => "full",
+            RedactPolicy::KeepLast4 => "last4",
+            RedactPolicy::KeepCountry => "country",
+        })
+    }
+}
+
+impl FromStr for RedactPolicy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "full" => Ok(RedactPolicy::FullMask),
+            "last4" => Ok(RedactPolicy::KeepLast4),
+            "country" => Ok(RedactPolicy::KeepCountry),
+            other => Err(format!(
+                "Unknown redaction policy: {}. Use full, last4, or country.",
+                other
+            )),
+        }
+    }
+}
+
+/// Find identifiers in `text` using the default [`ScanOptions`].
+pub fn scan(text: &str) -> Vec<Finding> {
+    scan_with(text, &ScanOptions::default())
+}
+
+/// Find identifiers in `text`.
+///
+/// Candidates are runs of ASCII letters and digits, optionally joined by a
+/// single space, hyphen, dot or slash. The longest run that validates wins
+/// and findings never overlap. Findings are returned in text order.
+pub fn scan_with(text: &str, opts: &ScanOptions) -> Vec<Finding> {
+    let tokens = tokenize(text);
+    let mut findings = Vec::new();
+    let shapes = if wants(opts, IdKind::PersonalId) && !tokens.is_empty() {
+        id_shapes()
+    } else {
+        Arc::default()
+    };
+
+    let mut i = 0;
+    while i < tokens.len() {
+        // The candidate may extend over tokens joined by a single separator.
+        let mut last = i;
+        while last + 1 < tokens.len()
+            && last + 1 - i < MAX_TOKENS
+            && is_separator(&text[tokens[last].1..tokens[last + 1].0])
+        {
+            last += 1;
+        }
+
+        let mut matched = None;
+        for j in (i..=last).rev() {
+            let start = tokens[i].0;
+            let end = tokens[j].1;
+            if let Some((kind, country)) = classify(text, start, end, j - i + 1, opts, &shapes) {
+                matched = Some((j, kind, country, start, end));
+                break;
+            }
+        }
+
+        match matched {
+            Some((j, kind, country, start, end)) => {
+                findings.push(Finding {
+                    start,
+                    end,
+                    kind,
+                    country,
+                    text: text[start..end].to_string(),
+                });
+                i = j + 1;
+            }
+            None => i += 1,
+        }
+    }
+
+    findings
+}
+
+/// Mask every identifier [`scan`] finds in `text`.
+pub fn redact(text: &str, policy: RedactPolicy) -> String {
+    redact_findings(text, &scan(text), policy)
+}
+
+/// Mask the given findings in `text`. `findings` must come from scanning
+/// the same text.
+pub fn redact_findings(text: &str, findings: &[Finding], policy: RedactPolicy) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut pos = 0;
+    for finding in findings {
+        out.push_str(&text[pos..finding.start]);
+        out.push_str(&mask(&finding.text, finding.kind, policy));
+        pos = finding.end;
+    }
+    out.push_str(&text[pos..]);
+    out
+}
+
+/// Mask a single identifier, keeping separators so the layout survives.
+pub fn mask(code: &str, kind: IdKind, policy: RedactPolicy) -> String {
+    let alnum = code.chars().filter(|c| c.is_ascii_alphanumeric()).count();
+    let (keep_head, keep_tail) = match policy {
+        RedactPolicy::FullMask => (0, 0),
+        RedactPolicy::KeepLast4 => (0, 4.min(alnum)),
+        RedactPolicy::KeepCountry if matches!(kind, IdKind::Iban | IdKind::Vat) => (2, 0),
+        RedactPolicy::KeepCountry => (0, 0),
+    };
+
+    let mut seen = 0;
+    code.chars()
+        .map(|c| {
+            if !c.is_ascii_alphanumeric() {
+                return c;
+            }
+            seen += 1;
+            if seen <= keep_head || seen > alnum - keep_tail {
+                c
+            } else {
+                '*'
+            }
+        })
+        .collect()
+}
+
+/// Byte ranges of the ASCII alphanumeric runs in `text`.
+fn tokenize(text: &str) -> Vec<(usize, usize)> {
+    let mut tokens = Vec::new();
+    let mut start = None;
+    for (idx, c) in text.char_indices() {
+        match (c.is_ascii_alphanumeric(), start) {
+            (true, None) => start = Some(idx),
+            (false, Some(s)) => {
+                tokens.push((s, idx));
+                start = None;
+            }
+            _ => {}
+        }
+    }
+    if let Some(s) = start {
+        tokens.push((s, text.len()));
+    }
+    tokens
+}
+
+fn is_separator(gap: &str) -> bool {
+    matches!(gap, " " | "-" | "." | "/")
+}
+
+fn wants(opts: &ScanOptions, kind: IdKind) -> bool {
+    if opts.kinds.is_empty() {
+        SCAN_KINDS.contains(&kind)
+    } else {
+        opts.kinds.contains(&kind)
+    }
+}
+
+/// Keep a country only when exactly one matched.
+fn single(mut countries: Vec<&str>) -> Option<String> {
+    if countries.len() == 1 {
+        countries.pop().map(str::to_string)
+    } else {
+        None
+    }
+}
+
+fn classify(
+    text: &str,
+    start: usize,
+    end: usize,
+    tokens: usize,
+    opts: &ScanOptions,
+    id_shapes: &[(String, IdShapes)],
+) -> Option<(IdKind, Option<String>)> {
+    let written = &text[start..end];
+    let compact: String = written
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .collect();
+    if compact.len() < 6 || compact.len() > 34 || !compact.chars().any(|c| c.is_ascii_digit()) {
+        return None;
+    }
+
+    let prefix_alpha = compact.as_bytes()[..2]
+        .iter()
+        .all(|b| b.is_ascii_uppercase());
+    let all_digits = compact.chars().all(|c| c.is_ascii_digit());
+
+    if wants(opts, IdKind::Iban)
+        && prefix_alpha
+        && crate::iban::has_registered_length(&compact)
+        && crate::iban::validate_iban(&compact)
+    {
+        return Some((IdKind::Iban, Some(compact[..2].to_string())));
+    }
+    if wants(opts, IdKind::Vat) && prefix_alpha && crate::vat_ids().validate(&compact) {
+        let country = match &compact[..2] {
+            "EL" => "GR",
+            other => other,
+        };
+        return Some((IdKind::Vat, Some(country.to_string())));
+    }
+    if wants(opts, IdKind::CreditCard)
+        && all_digits
+        && (13..=19).contains(&compact.len())
+        && crate::credit_cards().brand(&compact).is_some()
+        && crate::credit_cards().validate(&compact)
+    {
+        return Some((IdKind::CreditCard, None));
+    }
+
+    if wants(opts, IdKind::PersonalId) && tokens <= MAX_ID_TOKENS {
+        let registry = crate::personal_ids();
+        let written_shape = shape(written);
+        let compact_shape = shape(&compact);
+        let before = &text[..start];
+        let keyword = has_keyword(before, ID_KEYWORDS);
+        let matches: Vec<&str> = id_shapes
+            .iter()
+            .filter(|(code, _)| in_scope(opts, code))
+            .filter(|(_, shapes)| {
+                shapes.written.contains(&written_shape)
+                    || (tokens == 1 && shapes.compact.contains(&compact_shape))
+            })
+            .filter(|(_, shapes)| {
+                keyword
+                    || !opts.countries.is_empty()
+                    || (shapes.checked && (tokens > 1 || shapes.rare))
+                    || shapes
+                        .name
+                        .as_deref()
+                        .is_some_and(|n| has_keyword(before, &[n]))
+            })
+            .map(|(code, _)| code.as_str())
+            .filter(|code| {
+                registry.validate(code, written) == Some(true)
+                    || (written != compact && registry.validate(code, &compact) == Some(true))
+            })
+            .collect();
+        if !matches.is_empty() {
+            return Some((IdKind::PersonalId, single(matches)));
+        }
+    }
+
+    if wants(opts, IdKind::Passport)
+        && tokens <= 2
+        && has_keyword(&text[..start], PASSPORT_KEYWORDS)
+    {
+        let registry = crate::passports();
+        let matches: Vec<&str> = registry
+            .list_countries()
+            .into_iter()
+            .map(|(code, _, _)| code)
+            .filter(|code| in_scope(opts, code) && registry.validate(code, &compact))
+            .collect();
+        if !matches.is_empty() {
+            return Some((IdKind::Passport, single(matches)));
+        }
+    }
+
+    None
+}
+
+fn in_scope(opts: &ScanOptions, country: &str) -> bool {
+    opts.countries.is_empty()
+        || opts
+            .countries
+            .iter()
+            .any(|c| c.eq_ignore_ascii_case(country))
+}
+
+/// Digits become `9`, letters `A`; separators are kept.
+fn shape(code: &str) -> String {
+    code.chars()
+        .map(|c| match c {
+            c if c.is_ascii_digit() => '9',
+            c if c.is_ascii_alphabetic() => 'A',
+            c => c,
+        })
+        .collect()
+}
+
+/// Shapes a country's personal IDs are written in, with and without
+/// separators. Many validators ignore separators or pad short input, so a
+/// candidate must look like the national format before it is validated;
+/// this keeps timestamps, IP addresses and version numbers out.
+struct IdShapes {
+    written: HashSet<String>,
+    compact: HashSet<String>,
+    /// The scheme has a check digit.
+    checked: bool,
+    /// Random codes of the compact shapes almost never validate.
+    rare: bool,
+    /// Lowercased document name, when long enough to be a keyword.
+    name: Option<String>,
+}
+
+/// Shapes for every personal ID country in the global registry.
+///
+/// Shapes are learned from generated samples and rebuilt whenever a scheme
+/// is registered, so runtime schemes are picked up by the next scan.
+fn id_shapes() -> Arc<Vec<(String, IdShapes)>> {
+    type Cache = Option<(usize, Arc<Vec<(String, IdShapes)>>)>;
+    static CACHE: Mutex<Cache> = Mutex::new(None);
+
+    let registry = crate::personal_ids();
+    let revision = registry.revision();
+    let mut cache = CACHE.lock().unwrap_or_else(|e| e.into_inner());
+    if let Some((cached, shapes)) = cache.as_ref() {
+        if *cached == revision {
+            return Arc::clone(shapes);
+        }
+    }
+
+    let opts = crate::personal_id::GenOptions::default();
+    let mut rng = StdRng::seed_from_u64(0x5ca7);
+    let mut result = Vec::new();
+    for (code, _, _) in registry.list_countries() {
+        if crate::countries::resolve_territory(&code).is_some() {
+            continue;
+        }
+        let mut written = HashSet::new();
+        let mut compact = HashSet::new();
+        for _ in 0..SHAPE_SAMPLES {
+            if let Some(id) = registry.generate(&code, &opts, &mut rng) {
+                written.insert(shape(&id));
+                compact.insert(shape(
+                    &id.chars()
+                        .filter(|c| c.is_ascii_alphanumeric())
+                        .collect::<String>(),
+                ));
+            }
+        }
+        if let Some((_, formats)) = WRITTEN_FORMATS.iter().find(|(c, _)| *c == code) {
+            written.extend(formats.iter().map(|f| f.to_string()));
+        }
+        let checked =
+            crate::describe::has_checksum(IdKind::PersonalId, Some(&code)).unwrap_or(false);
+        let rare = checked && chance_hits(&code, &compact, &mut rng) <= MAX_CHANCE_HITS;
+        let name = registry
+            .name(&code)
+            .map(|n| n.to_lowercase())
+            .filter(|n| n.len() > 2);
+        result.push((
+            code,
+            IdShapes {
+                written,
+                compact,
+                checked,
+                rare,
+                name,
+            },
+        ));
+    }
+
+    let shapes = Arc::new(result);
+    *cache = Some((revision, Arc::clone(&shapes)));
+    shapes
+}
+
+/// How many of [`CHANCE_SAMPLES`] random codes in `shapes` validate for
+/// `country`.
+fn chance_hits(country: &str, shapes: &HashSet<String>, rng: &mut StdRng) -> usize {
+    let registry = crate::personal_ids();
+    let mut shapes: Vec<&String> = shapes.iter().collect();
+    shapes.sort();
+    if shapes.is_empty() {
+        return 0;
+    }
+    (0..CHANCE_SAMPLES)
+        .filter(|i| {
+            let code: String = shapes[i % shapes.len()]
+                .chars()
+                .map(|c| match c {
+                    '9' => char::from(b'0' + rng.gen_range(0..10)),
+                    'A' => char::from(b'A' + rng.gen_range(0..26)),
+                    c => c,
+                })
+                .collect();
+            registry.validate(country, &code) == Some(true)
+        })
+        .count()
+}
+
+/// Whether one of `keywords` appears as a whole word shortly before a
+/// candidate.
+fn has_keyword<K: AsRef<str>>(before: &str, keywords: &[K]) -> bool {
+    let tail: Vec<char> = before.chars().rev().take(KEYWORD_WINDOW).collect();
+    let tail: String = tail.into_iter().rev().collect::<String>().to_lowercase();
+    let boundary = |c: Option<char>| !c.is_some_and(|c| c.is_alphanumeric());
+    keywords.iter().any(|k| {
+        let k = k.as_ref();
+        tail.match_indices(k).any(|(i, _)| {
+            boundary(tail[..i].chars().next_back()) && boundary(tail[i + k.len()..].chars().next())
+        })
+    })
+}
@@ -0,0 +1,108 @@
+//! User-defined identifier schemes.
+//!
+//! Implement [`IdScheme`] to teach the personal ID and company ID registries
+//! a new identifier, or to replace a built-in country rule. Schemes added to
+//! the global registries are picked up everywhere those registries are used.
+//!
+//! ```rust
+//! use idsmith::IdScheme;
+//! use rand::{Rng, RngCore};
+//!
+//! /// Internal customer number: "CU" + 6 digits + mod-10 check digit.
+//! struct CustomerNumber;
+//!
+//! impl IdScheme for CustomerNumber {
+//!     fn name(&self) -> &str {
+//!         "Customer number"
+//!     }
+//!
+//!     fn generate(&self, rng: &mut dyn RngCore) -> String {
+//!         let body: u32 = rng.gen_range(0..1_000_000);
+//!         let check = (0..6).map(|i| body / 10u32.pow(i) % 10).sum::<u32>() % 10;
+//!         format!("CU{:06}{}", body, check)
+//!     }
+//!
+//!     fn validate(&self, code: &str) -> bool {
+//!         let Some(digits) = code.strip_prefix("CU") else {
+//!             return false;
+//!         };
+//!         digits.len() == 7
+//!             && digits.chars().all(|c| c.is_ascii_digit())
+//!             && digits[..6].bytes().map(|b| (b - b'0') as u32).sum::<u32>() % 10
+//!                 == (digits.as_bytes()[6] - b'0') as u32
+//!     }
+//! }
+//!
+//! idsmith::company_ids().register("ACME", CustomerNumber).unwrap();
+//!
+//! let opts = idsmith::company_id::GenOptions {
+//!     country: Some("ACME".to_string()),
+//!     ..Default::default()
+//! };
+//! let result = idsmith::company_ids().generate(&opts, &mut rand::thread_rng()).unwrap();
+//! assert!(idsmith::company_ids().validate("ACME", &result.code));
+//! ```
+
+use rand::RngCore;
+
+use crate::personal_id::{GenOptions, IdResult};
+
+/// An identifier format that can be registered with a registry.
+///
+/// Schemes are shared between threads through the global registries, so
+/// they must be `Send + Sync`. Do not call back into the registry a scheme
+/// is registered with from inside its methods.
+pub trait IdScheme: Send + Sync {
+    /// Human-readable name of the identifier (e.g. "Isikukood").
+    fn name(&self) -> &str;
+
+    /// Generate a valid code.
+    fn generate(&self, rng: &mut dyn RngCore) -> String;
+
+    /// Check a code.
+    fn validate(&self, code: &str) -> bool;
+
+    /// Name of the check algorithm (e.g. `"Luhn"`, `"Weighted mod 11"`),
+    /// reported by [`crate::describe`]. The default declares none.
+    fn checksum(&self) -> Option<String> {
+        None
+    }
+
+    /// Generate a personal ID honouring [`GenOptions`] (gender, birth year).
+    /// Schemes that encode neither can keep the default, which ignores the
+    /// options.
+    fn generate_with(&self, opts: &GenOptions, rng: &mut dyn RngCore) -> String {
+        let _ = opts;
+        self.generate(rng)
+    }
+
+    /// Generate a code, or `None` if the scheme could not produce one.
+    /// Registries call this; schemes that cannot fail keep the default,
+    /// which wraps [`generate_with`](Self::generate_with).
+    fn try_generate(&self, opts: &GenOptions, rng: &mut dyn RngCore) -> Option<String> {
+        Some(self.generate_with(opts, rng))
+    }
+
+    /// Extract details for [`crate::personal_id::Registry::parse`]. The
+    /// default reports validity only.
+    fn parse(&self, code: &str) -> IdResult {
+        IdResult {
+            country_code: String::new(),
+            effective_country_code: String::new(),
+            code: code.to_string(),
+            gender: None,
+            dob: None,
+            region: None,
+            valid: self.validate(code),
+        }
+    }
+}
+
+/// Normalize a registration key: uppercase, ASCII letters and digits only.
+pub(crate) fn registration_key(country: &str) -> Result<String, String> {
+    let key = country.trim().to_uppercase();
+    if key.is_empty() || !key.chars().all(|c| c.is_ascii_alphanumeric()) {
+        return Err(format!("Invalid country code: {}", country));
+    }
+    Ok(key)
+}
@@ -0,0 +1,463 @@
+//! A small REST/JSON API over the generators and validators, so services
+//! written in other languages can run idsmith as a local sidecar.
+//!
+//! Enable with the `serve` feature flag. [`handle`] does the routing and is
+//! independent of the transport; [`run`] serves it over HTTP.
+//!
+//! | Route | Description |
+//! |-------|-------------|
+//! | `GET /v1/{kind}?country=DE&count=10` | Generate results |
+//! | `POST /v1/validate` | Validate one `{kind, code, country}` object or an array of them |
+//! | `GET /v1/parse?kind=id&country=SE&code=...` | Parse a personal ID or IBAN |
+//! | `GET /v1/detect?code=...` | Kinds and countries a code validates as |
+//! | `GET /v1/describe?kind=id&country=SE` | Format of a scheme |
+//! | `GET /v1/openapi.json` | OpenAPI 3 document |
+//! | `GET /health` | Liveness check |
+//!
+//! [`vies`] is a separate mock of the EU VIES VAT check service.
+//!
+//! ```rust
+//! use idsmith::serve::handle;
+//!
+//! let response = handle("GET", "/v1/iban?country=DE&count=2", "");
+//! assert_eq!(response.status, 200);
+//! let ibans: serde_json::Value = serde_json::from_str(&response.body).unwrap();
+//! assert_eq!(ibans.as_array().unwrap().len(), 2);
+//!
+//! let response = handle("POST", "/v1/validate", r#"{"kind": "iban", "code": "DE89370400440532013000"}"#);
+//! assert!(response.body.contains("\"valid\":true"));
+//! ```
+
+use std::sync::Arc;
+
+use rand::Rng;
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+use crate::kind::IdKind;
+use crate::personal_id::date::Gender;
+
+pub mod vies;
+
+/// Most results a single generate request may ask for.
+pub const MAX_COUNT: usize = 10_000;
+
+const OPENAPI: &str = include_str!("openapi.json");
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Response {
+    pub status: u16,
+    pub content_type: &'static str,
+    pub body: String,
+}
+
+impl Response {
+    fn json(status: u16, value: &Value) -> Self {
+        Self {
+            status,
+            content_type: "application/json",
+            body: value.to_string(),
+        }
+    }
+
+    fn error(status: u16, message: impl Into<String>) -> Self {
+        Self::json(status, &json!({ "error": message.into() }))
+    }
+}
+
+/// The OpenAPI 3 document describing the API.
+pub fn openapi() -> Value {
+    let mut doc: Value = serde_json::from_str(OPENAPI).expect("bundled OpenAPI document is valid");
+    doc["info"]["version"] = json!(env!("CARGO_PKG_VERSION"));
+    doc
+}
+
+/// Answer one request. `url` is the path with its query string.
+pub fn handle(method: &str, url: &str, body: &str) -> Response {
+    let (path, query) = url.split_once('?').unwrap_or((url, ""));
+    match route(
+        method,
+        path.trim_end_matches('/'),
+        &Params::parse(query),
+        body,
+    ) {
+        Ok(value) => Response::json(200, &value),
+        Err(response) => response,
+    }
+}
+
+fn route(method: &str, path: &str, params: &Params, body: &str) -> Result<Value, Response> {
+    match (method, path) {
+        ("GET", "/health") => Ok(json!({ "status": "ok" })),
+        ("GET", "/v1/openapi.json") => Ok(openapi()),
+        ("POST", "/v1/validate") => validate_body(body),
+        ("GET", "/v1/validate") => validate(
+            params.kind()?,
+            params.require("code")?,
+            params.get("country"),
+        ),
+        ("GET", "/v1/parse") => parse(
+            params.kind()?,
+            params.require("code")?,
+            params.get("country"),
+        ),
+        ("GET", "/v1/detect") => detect(params.require("code")?),
+        ("GET", "/v1/describe") => describe(params.kind()?, params.get("country")),
+        (
+            _,
+            "/health" | "/v1/openapi.json" | "/v1/validate" | "/v1/parse" | "/v1/detect"
+            | "/v1/describe",
+        ) => Err(Response::error(
+            405,
+            format!("Method not allowed: {}", method),
+        )),
+        _ => match path.strip_prefix("/v1/").map(str::parse::<IdKind>) {
+            Some(Ok(kind)) if method == "GET" => generate(kind, params, &mut rand::thread_rng()),
+            Some(Ok(_)) => Err(Response::error(
+                405,
+                format!("Method not allowed: {}", method),
+            )),
+            _ => Err(Response::error(404, format!("Not found: {}", path))),
+        },
+    }
+}
+
+/// Serve the API on `addr` (e.g. `127.0.0.1:8080`) until the process exits.
+pub fn run(addr: &str) -> Result<(), String> {
+    listen(addr, handle)
+}
+
+/// Answer requests on `addr` with `handler` from a pool of worker threads.
+fn listen<F>(addr: &str, handler: F) -> Result<(), String>
+where
+    F: Fn(&str, &str, &str) -> Response + Send + Sync + 'static,
+{
+    let server = tiny_http::Server::http(addr).map_err(|e| format!("{}: {}", addr, e))?;
+    let server = Arc::new(server);
+    let handler = Arc::new(handler);
+    let workers = std::thread::available_parallelism().map_or(4, |n| n.get());
+    let handles: Vec<_> = (0..workers)
+        .map(|_| {
+            let server = Arc::clone(&server);
+            let handler = Arc::clone(&handler);
+            std::thread::spawn(move || {
+                for request in server.incoming_requests() {
+                    respond(request, &*handler);
+                }
+            })
+        })
+        .collect();
+    for handle in handles {
+        let _ = handle.join();
+    }
+    Ok(())
+}
+
+fn respond(mut request: tiny_http::Request, handler: &dyn Fn(&str, &str, &str) -> Response) {
+    let mut body = String::new();
+    let response = match request.as_reader().read_to_string(&mut body) {
+        Ok(_) => handler(request.method().as_str(), request.url(), &body),
+        Err(e) => Response::error(400, format!("Could not read request body: {}", e)),
+    };
+    let content_type = tiny_http::Header::from_bytes("Content-Type", response.content_type)
+        .expect("content type is a valid header");
+    let reply = tiny_http::Response::from_string(response.body)
+        .with_status_code(response.status)
+        .with_header(content_type);
+    let _ = request.respond(reply);
+}
+
+/// Decoded query parameters.
+struct Params(Vec<(String, String)>);
+
+impl Params {
+    fn parse(query: &str) -> Self {
+        Self(
+            query
+                .split('&')
+                .filter(|pair| !pair.is_empty())
+                .map(|pair| {
+                    let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+                    (percent_decode(key), percent_decode(value))
+                })
+                .collect(),
+        )
+    }
+
+    fn get(&self, key: &str) -> Option<&str> {
+        self.0
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v.as_str())
+            .filter(|v| !v.is_empty())
+    }
+
+    fn require(&self, key: &str) -> Result<&str, Response> {
+        self.get(key)
+            .ok_or_else(|| Response::error(400, format!("Missing parameter: {}", key)))
+    }
+
+    fn kind(&self) -> Result<IdKind, Response> {
+        self.require("kind")?
+            .parse()
+            .map_err(|e: String| Response::error(400, e))
+    }
+}
+
+fn percent_decode(s: &str) -> String {
+    let bytes = s.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let hex = bytes
+            .get(i + 1..i + 3)
+            .and_then(|h| std::str::from_utf8(h).ok())
+            .and_then(|h| u8::from_str_radix(h, 16).ok());
+        match (bytes[i], hex) {
+            (b'%', Some(b)) => {
+                out.push(b);
+                i += 3;
+                continue;
+            }
+            (b'+', _) => out.push(b' '),
+            (b, _) => out.push(b),
+        }
+        i += 1;
+    }
+    String::from_utf8_lossy(&out).into_owned()
+}
+
+fn unsupported(kind: IdKind, country: Option<&str>) -> Response {
+    Response::error(
+        400,
+        match country {
+            Some(c) => format!("Unsupported country for {}: {}", kind, c),
+            None => format!("A country is required for {}", kind),
+        },
+    )
+}
+
+fn generate(kind: IdKind, params: &Params, rng: &mut impl Rng) -> Result<Value, Response> {
+    let count = match params.get("count") {
+        Some(n) => n
+            .parse::<usize>()
+            .map_err(|_| Response::error(400, format!("Invalid count: {}", n)))?,
+        None => 1,
+    };
+    if count > MAX_COUNT {
+        return Err(Response::error(
+            400,
+            format!("count must be at most {}", MAX_COUNT),
+        ));
+    }
+    let country = params.get("country").map(str::to_uppercase);
+    let results = (0..count)
+        .map(|_| generate_one(kind, country.as_deref(), params, rng))
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(Value::Array(results))
+}
+
+fn generate_one(
+    kind: IdKind,
+    country: Option<&str>,
+    params: &Params,
+    rng: &mut impl Rng,
+) -> Result<Value, Response> {
+    let country = country.map(str::to_string);
+    let value = match kind {
+        IdKind::Iban => {
+            let code = crate::iban::generate_iban(country.as_deref(), rng)
+                .map_err(|e| Response::error(400, e))?;
+            serde_json::to_value(crate::iban::IbanResult {
+                country: code[..2].to_string(),
+                formatted: crate::iban::format_iban(&code),
+                iban: crate::Iban::from_generated(code),
+                valid: true,
+            })
+        }
+        IdKind::BankAccount => {
+            let registry = crate::bank_accounts();
+            let cc = country.clone().unwrap_or_else(|| {
+                let countries = registry.list_countries();
+                countries[rng.gen_range(0..countries.len())].0.to_string()
+            });
+            let opts = crate::bank_account::GenOptions {
+                bank_code: params.get("bank_code").map(str::to_string),
+                ..Default::default()
+            };
+            let result = registry.generate(&cc, &opts, rng);
+            serde_json::to_value(result.ok_or_else(|| unsupported(kind, Some(&cc)))?)
+        }
+        IdKind::PersonalId => {
+            let registry = crate::personal_ids();
+            let cc = country.clone().unwrap_or_else(|| {
+                let countries = registry.list_countries();
+                countries[rng.gen_range(0..countries.len())].0.to_string()
+            });
+            let year = match params.get("year") {
+                Some(y) => Some(
+                    y.parse()
+                        .map_err(|_| Response::error(400, format!("Invalid year: {}", y)))?,
+                ),
+                None => None,
+            };
+            let opts = crate::personal_id::GenOptions {
+                gender: Gender::from_str_opt(params.get("gender")),
+                year,
+                region: params.get("region").map(str::to_string),
+                ..Default::default()
+            };
+            let code = registry
+                .generate(&cc, &opts, rng)
+                .ok_or_else(|| unsupported(kind, Some(&cc)))?;
+            serde_json::to_value(registry.parse(&cc, &code))
+        }
+        IdKind::CreditCard => {
+            let opts = crate::credit_card::GenOptions {
+                brand: params.get("brand").map(str::to_string),
+                ..Default::default()
+            };
+            let result = crate::credit_cards().generate(&opts, rng).ok_or_else(|| {
+                Response::error(
+                    400,
+                    format!("Unknown brand: {}", params.get("brand").unwrap_or("")),
+                )
+            })?;
+            serde_json::to_value(result)
+        }
+        IdKind::Swift => {
+            let opts = crate::swift::GenOptions { country };
+            serde_json::to_value(crate::swift_codes().generate(&opts, rng))
+        }
+        IdKind::CompanyId => {
+            let opts = crate::company_id::GenOptions {
+                country: country.clone(),
+                ..Default::default()
+            };
+            let result = crate::company_ids().generate(&opts, rng);
+            serde_json::to_value(result.ok_or_else(|| unsupported(kind, country.as_deref()))?)
+        }
+        IdKind::DriverLicense => {
+            let opts = crate::driver_license::GenOptions {
+                country: country.clone(),
+                state: params.get("state").map(str::to_string),
+                ..Default::default()
+            };
+            let result = crate::driver_licenses().generate(&opts, rng);
+            serde_json::to_value(result.ok_or_else(|| unsupported(kind, country.as_deref()))?)
+        }
+        IdKind::TaxId => {
+            let opts = crate::tax_id::GenOptions {
+                country: country.clone(),
+                holder_type: params.get("holder_type").map(str::to_string),
+                ..Default::default()
+            };
+            let result = crate::tax_ids().generate(&opts, rng);
+            serde_json::to_value(result.ok_or_else(|| unsupported(kind, country.as_deref()))?)
+        }
+        IdKind::Passport => {
+            let opts = crate::passport::GenOptions {
+                country: country.clone(),
+                ..Default::default()
+            };
+            let result = crate::passports().generate(&opts, rng);
+            serde_json::to_value(result.ok_or_else(|| unsupported(kind, country.as_deref()))?)
+        }
+        IdKind::Lei => {
+            let opts = crate::lei::GenOptions { country };
+            serde_json::to_value(crate::lei_codes().generate(&opts, rng))
+        }
+        IdKind::Vat => {
+            let opts = crate::vat::GenOptions {
+                country: country.clone(),
+                ..Default::default()
+            };
+            let result = crate::vat_ids().generate(&opts, rng);
+            serde_json::to_value(result.ok_or_else(|| unsupported(kind, country.as_deref()))?)
+        }
+    };
+    value.map_err(|e| Response::error(500, e.to_string()))
+}
+
+#[derive(Deserialize)]
+struct ValidateRequest {
+    kind: String,
+    code: String,
+    country: Option<String>,
+}
+
+fn validate_body(body: &str) -> Result<Value, Response> {
+    let value: Value = serde_json::from_str(body)
+        .map_err(|e| Response::error(400, format!("Invalid JSON: {}", e)))?;
+    let one = |value: Value| -> Result<Value, Response> {
+        let request: ValidateRequest = serde_json::from_value(value)
+            .map_err(|e| Response::error(400, format!("Invalid request: {}", e)))?;
+        let kind = request
+            .kind
+            .parse()
+            .map_err(|e: String| Response::error(400, e))?;
+        validate(kind, &request.code, request.country.as_deref())
+    };
+    match value {
+        Value::Array(items) => items
+            .into_iter()
+            .map(one)
+            .collect::<Result<Vec<_>, _>>()
+            .map(Value::Array),
+        value => one(value),
+    }
+}
+
+fn validate(kind: IdKind, code: &str, country: Option<&str>) -> Result<Value, Response> {
+    let valid = kind
+        .validate(country, code)
+        .ok_or_else(|| unsupported(kind, country))?;
+    Ok(json!({
+        "kind": kind,
+        "country": country.map(str::to_uppercase),
+        "code": code,
+        "valid": valid,
+    }))
+}
+
+fn parse(kind: IdKind, code: &str, country: Option<&str>) -> Result<Value, Response> {
+    let value = match kind {
+        IdKind::PersonalId => {
+            let cc = country.ok_or_else(|| unsupported(kind, None))?;
+            let result = crate::personal_ids()
+                .parse(cc, code)
+                .ok_or_else(|| unsupported(kind, Some(cc)))?;
+            serde_json::to_value(result)
+        }
+        IdKind::Iban => {
+            let iban: crate::Iban = code.parse().map_err(|e: String| Response::error(422, e))?;
+            let compact = iban.to_string();
+            serde_json::to_value(crate::iban::IbanResult {
+                country: compact[..2].to_string(),
+                formatted: crate::iban::format_iban(&compact),
+                iban,
+                valid: true,
+            })
+        }
+        _ => {
+            return Err(Response::error(
+                400,
+                format!("parse supports id and iban, not {}", kind),
+            ))
+        }
+    };
+    value.map_err(|e| Response::error(500, e.to_string()))
+}
+
+fn detect(code: &str) -> Result<Value, Response> {
+    let found: Vec<Value> = crate::detect::detect(code)
+        .into_iter()
+        .map(|t| json!({ "kind": t.kind, "country": t.country }))
+        .collect();
+    Ok(json!({ "code": code, "matches": found }))
+}
+
+fn describe(kind: IdKind, country: Option<&str>) -> Result<Value, Response> {
+    let info = crate::describe(kind, country).ok_or_else(|| unsupported(kind, country))?;
+    serde_json::to_value(info).map_err(|e| Response::error(500, e.to_string()))
+}
@@ -0,0 +1,702 @@
+//! A local stand-in for the EU VIES VAT number check service.
+//!
+//! Speaks the SOAP `checkVat` contract at the real service path and the
+//! VIES REST API, so clients only need their base URL changed. Numbers are
+//! checked with [`vat::Registry::validate`](crate::vat::Registry::validate);
+//! valid ones get a company name and address derived from the number, so
+//! repeated checks return the same trader.
+//!
+//! Failures can be simulated per member state with [`Options`], and the
+//! numbers of the official VIES test service are honoured for every country
+//! (`100` valid, `200` invalid, `201` `INVALID_INPUT`, `301`
+//! `MS_UNAVAILABLE`, `302` `TIMEOUT`, ...).
+//!
+//! ```rust
+//! use idsmith::serve::vies::{Fault, Mock, Options};
+//!
+//! let mock = Mock::new(Options {
+//!     unavailable: vec!["FR".into()],
+//!     ..Default::default()
+//! });
+//! let check = mock.check("DE", "129273398").unwrap();
+//! assert!(check.valid);
+//! assert_eq!(check.name, mock.check("DE", "129273398").unwrap().name);
+//!
+//! assert_eq!(mock.check("FR", "40303265045").unwrap_err(), Fault::MsUnavailable);
+//! assert_eq!(mock.check("DE", "302").unwrap_err(), Fault::Timeout);
+//! ```
+
+use std::fmt;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+use super::Response;
+
+/// Path of the SOAP endpoint on the real service.
+pub const SOAP_PATH: &str = "/taxation_customs/vies/services/checkVatService";
+
+const WSDL: &str = include_str!("checkVatService.wsdl");
+
+/// Member states in VIES: the EU by VAT prefix, plus `XI` for Northern
+/// Ireland.
+pub const MEMBER_STATES: &[&str] = &[
+    "AT", "BE", "BG", "CY", "CZ", "DE", "DK", "EE", "EL", "ES", "FI", "FR", "HR", "HU", "IE", "IT",
+    "LT", "LU", "LV", "MT", "NL", "PL", "PT", "RO", "SE", "SI", "SK", "XI",
+];
+
+/// Error codes VIES returns instead of an answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Fault {
+    InvalidInput,
+    InvalidRequesterInfo,
+    ServiceUnavailable,
+    MsUnavailable,
+    Timeout,
+    VatBlocked,
+    IpBlocked,
+    GlobalMaxConcurrentReq,
+    GlobalMaxConcurrentReqTime,
+    MsMaxConcurrentReq,
+    MsMaxConcurrentReqTime,
+}
+
+impl Fault {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Fault::InvalidInput => "INVALID_INPUT",
+            Fault::InvalidRequesterInfo => "INVALID_REQUESTER_INFO",
+            Fault::ServiceUnavailable => "SERVICE_UNAVAILABLE",
+            Fault::MsUnavailable => "MS_UNAVAILABLE",
+            Fault::Timeout => "TIMEOUT",
+            Fault::VatBlocked => "VAT_BLOCKED",
+            Fault::IpBlocked => "IP_BLOCKED",
+            Fault::GlobalMaxConcurrentReq => "GLOBAL_MAX_CONCURRENT_REQ",
+            Fault::GlobalMaxConcurrentReqTime => "GLOBAL_MAX_CONCURRENT_REQ_TIME",
+            Fault::MsMaxConcurrentReq => "MS_MAX_CONCURRENT_REQ",
+            Fault::MsMaxConcurrentReqTime => "MS_MAX_CONCURRENT_REQ_TIME",
+        }
+    }
+
+    /// HTTP status for REST answers: 400 for bad input, 500 otherwise.
+    fn status(&self) -> u16 {
+        match self {
+            Fault::InvalidInput | Fault::InvalidRequesterInfo => 400,
+            _ => 500,
+        }
+    }
+}
+
+impl fmt::Display for Fault {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Simulated failures. Country lists take VIES prefixes (`EL` for Greece);
+/// `all` matches every member state.
+#[derive(Debug, Clone, Default)]
+pub struct Options {
+    /// Member states that answer `MS_UNAVAILABLE`.
+    pub unavailable: Vec<String>,
+    /// Member states that answer `TIMEOUT`.
+    pub timeout: Vec<String>,
+    /// Member states whose numbers are all reported invalid.
+    pub invalid: Vec<String>,
+    /// Wait this long before every answer.
+    pub delay: Duration,
+    /// Address the WSDL advertises for the SOAP endpoint. Defaults to
+    /// `http://127.0.0.1:8081` plus [`SOAP_PATH`].
+    pub location: Option<String>,
+}
+
+/// The answer to one check.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Check {
+    pub country_code: String,
+    pub vat_number: String,
+    /// `YYYY-MM-DD`, in UTC.
+    pub request_date: String,
+    pub valid: bool,
+    /// `---` for invalid numbers, as VIES returns.
+    pub name: String,
+    pub address: String,
+}
+
+/// The mock service.
+#[derive(Debug, Clone, Default)]
+pub struct Mock {
+    opts: Options,
+}
+
+impl Mock {
+    pub fn new(opts: Options) -> Self {
+        Self { opts }
+    }
+
+    /// Check `vat_number` (without its prefix) for member state
+    /// `country_code`.
+    pub fn check(&self, country_code: &str, vat_number: &str) -> Result<Check, Fault> {
+        let cc = country_code.trim().to_uppercase();
+        let number: String = vat_number
+            .chars()
+            .filter(|c| c.is_ascii_alphanumeric())
+            .map(|c| c.to_ascii_uppercase())
+            .collect();
+        if !MEMBER_STATES.contains(&cc.as_str()) || number.is_empty() {
+            return Err(Fault::InvalidInput);
+        }
+
+        let valid = match test_number(&number) {
+            Some(answer) => answer?,
+            None => {
+                if listed(&self.opts.timeout, &cc) {
+                    return Err(Fault::Timeout);
+                }
+                if listed(&self.opts.unavailable, &cc) {
+                    return Err(Fault::MsUnavailable);
+                }
+                // Northern Ireland numbers use the GB format
+                let prefix = if cc == "XI" { "GB" } else { &cc };
+                !listed(&self.opts.invalid, &cc)
+                    && crate::vat_ids().validate(&format!("{}{}", prefix, number))
+            }
+        };
+
+        let (name, address) = if valid {
+            trader(&cc, &number)
+        } else {
+            ("---".to_string(), "---".to_string())
+        };
+        Ok(Check {
+            country_code: cc,
+            vat_number: number,
+            request_date: today(),
+            valid,
+            name,
+            address,
+        })
+    }
+
+    /// Answer one request. `url` is the path with its query string.
+    pub fn handle(&self, method: &str, url: &str, body: &str) -> Response {
+        if !self.opts.delay.is_zero() {
+            std::thread::sleep(self.opts.delay);
+        }
+        let (path, query) = url.split_once('?').unwrap_or((url, ""));
+        let path = path.trim_end_matches('/');
+        let soap =
+            path == SOAP_PATH || path == "/taxation_customs/vies/services/checkVatTestService";
+
+        match (method, path) {
+            ("GET", _) if soap && query.eq_ignore_ascii_case("wsdl") => {
+                let default = format!("http://127.0.0.1:8081{}", SOAP_PATH);
+                let location = self.opts.location.as_deref().unwrap_or(&default);
+                xml(200, WSDL.replace("{location}", &xml_escape(location)))
+            }
+            ("POST", _) if soap => self.soap(body),
+            ("POST", "/rest-api/check-vat-number" | "/rest-api/check-vat-test-service") => {
+                self.rest(body)
+            }
+            ("GET", "/rest-api/check-status") => self.status(),
+            ("GET", _) => match path
+                .strip_prefix("/rest-api/ms/")
+                .and_then(|rest| rest.split_once("/vat/"))
+            {
+                Some((cc, number)) => self.rest_ms(cc, number),
+                None => Response::error(404, format!("Not found: {}", path)),
+            },
+            _ => Response::error(404, format!("Not found: {}", path)),
+        }
+    }
+
+    fn soap(&self, body: &str) -> Response {
+        let (Some(cc), Some(number)) = (element(body, "countryCode"), element(body, "vatNumber"))
+        else {
+            return soap_fault(Fault::InvalidInput);
+        };
+        match self.check(cc, number) {
+            Ok(check) => xml(
+                200,
+                format!(
+                    concat!(
+                        "<env:Envelope xmlns:env=\"http://schemas.xmlsoap.org/soap/envelope/\">",
+                        "<env:Header/><env:Body>",
+                        "<ns2:checkVatResponse xmlns:ns2=\"urn:ec.europa.eu:taxud:vies:services:checkVat:types\">",
+                        "<ns2:countryCode>{}</ns2:countryCode>",
+                        "<ns2:vatNumber>{}</ns2:vatNumber>",
+                        "<ns2:requestDate>{}+00:00</ns2:requestDate>",
+                        "<ns2:valid>{}</ns2:valid>",
+                        "<ns2:name>{}</ns2:name>",
+                        "<ns2:address>{}</ns2:address>",
+                        "</ns2:checkVatResponse></env:Body></env:Envelope>"
+                    ),
+                    check.country_code,
+                    xml_escape(&check.vat_number),
+                    check.request_date,
+                    check.valid,
+                    xml_escape(&check.name),
+                    xml_escape(&check.address),
+                ),
+            ),
+            Err(fault) => soap_fault(fault),
+        }
+    }
+
+    fn rest(&self, body: &str) -> Response {
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct Request {
+            country_code: String,
+            vat_number: String,
+        }
+
+        let check = serde_json::from_str::<Request>(body)
+            .map_err(|_| Fault::InvalidInput)
+            .and_then(|r| self.check(&r.country_code, &r.vat_number));
+        match check {
+            Ok(check) => Response::json(
+                200,
+                &json!({
+                    "countryCode": check.country_code,
+                    "vatNumber": check.vat_number,
+                    "requestDate": timestamp(&check.request_date),
+                    "valid": check.valid,
+                    "name": check.name,
+                    "address": check.address,
+                }),
+            ),
+            Err(fault) => Response::json(
+                fault.status(),
+                &json!({
+                    "actionSucceed": false,
+                    "errorWrappers": [{ "error": fault.as_str() }],
+                }),
+            ),
+        }
+    }
+
+    fn rest_ms(&self, cc: &str, number: &str) -> Response {
+        let number = super::percent_decode(number);
+        let mut value = match self.check(cc, &number) {
+            Ok(check) => json!({
+                "isValid": check.valid,
+                "requestDate": timestamp(&check.request_date),
+                "userError": if check.valid { "VALID" } else { "INVALID" },
+                "name": check.name,
+                "address": check.address,
+            }),
+            Err(fault) => json!({
+                "isValid": false,
+                "requestDate": timestamp(&today()),
+                "userError": fault.as_str(),
+                "name": "---",
+                "address": "---",
+            }),
+        };
+        value["requestIdentifier"] = json!("");
+        value["originalVatNumber"] = json!(number);
+        value["vatNumber"] = json!(number);
+        Response::json(200, &value)
+    }
+
+    fn status(&self) -> Response {
+        let countries: Vec<Value> = MEMBER_STATES
+            .iter()
+            .map(|cc| {
+                let down = listed(&self.opts.unavailable, cc) || listed(&self.opts.timeout, cc);
+                json!({
+                    "countryCode": cc,
+                    "availability": if down { "Unavailable" } else { "Available" },
+                })
+            })
+            .collect();
+        Response::json(
+            200,
+            &json!({ "vow": { "available": true }, "countries": countries }),
+        )
+    }
+}
+
+/// Serve the mock on `addr` (e.g. `127.0.0.1:8081`) until the process exits.
+pub fn run(addr: &str, mock: Mock) -> Result<(), String> {
+    super::listen(addr, move |method, url, body| {
+        mock.handle(method, url, body)
+    })
+}
+
+fn listed(list: &[String], cc: &str) -> bool {
+    list.iter()
+        .any(|c| c.eq_ignore_ascii_case(cc) || c.eq_ignore_ascii_case("all"))
+}
+
+/// Answers of the official VIES test service, keyed by VAT number.
+fn test_number(number: &str) -> Option<Result<bool, Fault>> {
+    Some(match number {
+        "100" => Ok(true),
+        "200" => Ok(false),
+        "201" => Err(Fault::InvalidInput),
+        "202" => Err(Fault::InvalidRequesterInfo),
+        "300" => Err(Fault::ServiceUnavailable),
+        "301" => Err(Fault::MsUnavailable),
+        "302" => Err(Fault::Timeout),
+        "400" => Err(Fault::VatBlocked),
+        "401" => Err(Fault::IpBlocked),
+        "500" => Err(Fault::GlobalMaxConcurrentReq),
+        "501" => Err(Fault::GlobalMaxConcurrentReqTime),
+        "600" => Err(Fault::MsMaxConcurrentReq),
+        "601" => Err(Fault::MsMaxConcurrentReqTime),
+        _ => return None,
+    })
+}
+
+/// Per member state: legal form, street pattern (`{s}` name, `{n}` house
+/// number), postcode pattern (`#` digit, `@` letter) and cities.
+const LOCALES: &[(&str, &str, &str, &str, &[&str])] = &[
+    (
+        "AT",
+        "GMBH",
+        "{s}GASSE {n}",
+        "####",
+        &["WIEN", "GRAZ", "LINZ"],
+    ),
+    (
+        "BE",
+        "BV",
+        "RUE {s} {n}",
+        "####",
+        &["BRUXELLES", "ANTWERPEN", "GENT"],
+    ),
+    (
+        "BG",
+        "EOOD",
+        "UL. {s} {n}",
+        "####",
+        &["SOFIA", "PLOVDIV", "VARNA"],
+    ),
+    (
+        "CY",
+        "LTD",
+        "{s} STREET {n}",
+        "####",
+        &["NICOSIA", "LIMASSOL", "LARNACA"],
+    ),
+    (
+        "CZ",
+        "S.R.O.",
+        "{s}OVA {n}",
+        "### ##",
+        &["PRAHA", "BRNO", "OSTRAVA"],
+    ),
+    (
+        "DE",
+        "GMBH",
+        "{s}STRASSE {n}",
+        "#####",
+        &["BERLIN", "HAMBURG", "MUENCHEN"],
+    ),
+    (
+        "DK",
+        "APS",
+        "{s}VEJ {n}",
+        "####",
+        &["KOEBENHAVN", "AARHUS", "ODENSE"],
+    ),
+    (
+        "EE",
+        "OU",
+        "{s} TN {n}",
+        "#####",
+        &["TALLINN", "TARTU", "PARNU"],
+    ),
+    (
+        "EL",
+        "I.K.E.",
+        "ODOS {s} {n}",
+        "### ##",
+        &["ATHINA", "THESSALONIKI", "PATRA"],
+    ),
+    (
+        "ES",
+        "S.L.",
+        "CALLE {s} {n}",
+        "#####",
+        &["MADRID", "BARCELONA", "VALENCIA"],
+    ),
+    (
+        "FI",
+        "OY",
+        "{s}KATU {n}",
+        "#####",
+        &["HELSINKI", "ESPOO", "TAMPERE"],
+    ),
+    (
+        "FR",
+        "SAS",
+        "{n} RUE {s}",
+        "#####",
+        &["PARIS", "LYON", "MARSEILLE"],
+    ),
+    (
+        "HR",
+        "D.O.O.",
+        "ULICA {s} {n}",
+        "#####",
+        &["ZAGREB", "SPLIT", "RIJEKA"],
+    ),
+    (
+        "HU",
+        "KFT.",
+        "{s} UTCA {n}",
+        "####",
+        &["BUDAPEST", "DEBRECEN", "SZEGED"],
+    ),
+    (
+        "IE",
+        "LIMITED",
+        "{n} {s} STREET",
+        "D## @###",
+        &["DUBLIN", "CORK", "GALWAY"],
+    ),
+    (
+        "IT",
+        "S.R.L.",
+        "VIA {s} {n}",
+        "#####",
+        &["ROMA", "MILANO", "TORINO"],
+    ),
+    (
+        "LT",
+        "UAB",
+        "{s} G. {n}",
+        "LT-#####",
+        &["VILNIUS", "KAUNAS", "KLAIPEDA"],
+    ),
+    (
+        "LU",
+        "S.A R.L.",
+        "{n}, RUE {s}",
+        "L-####",
+        &["LUXEMBOURG", "ESCH-SUR-ALZETTE"],
+    ),
+    (
+        "LV",
+        "SIA",
+        "{s} IELA {n}",
+        "LV-####",
+        &["RIGA", "DAUGAVPILS", "LIEPAJA"],
+    ),
+    (
+        "MT",
+        "LTD",
+        "{n}, TRIQ {s}",
+        "@@@ ####",
+        &["VALLETTA", "BIRKIRKARA", "SLIEMA"],
+    ),
+    (
+        "NL",
+        "B.V.",
+        "{s}STRAAT {n}",
+        "#### @@",
+        &["AMSTERDAM", "ROTTERDAM", "UTRECHT"],
+    ),
+    (
+        "PL",
+        "SP. Z O.O.",
+        "UL. {s} {n}",
+        "##-###",
+        &["WARSZAWA", "KRAKOW", "GDANSK"],
+    ),
+    (
+        "PT",
+        "LDA",
+        "RUA {s} {n}",
+        "####-###",
+        &["LISBOA", "PORTO", "BRAGA"],
+    ),
+    (
+        "RO",
+        "S.R.L.",
+        "STRADA {s} {n}",
+        "######",
+        &["BUCURESTI", "CLUJ-NAPOCA", "IASI"],
+    ),
+    (
+        "SE",
+        "AB",
+        "{s}GATAN {n}",
+        "### ##",
+        &["STOCKHOLM", "GOETEBORG", "MALMOE"],
+    ),
+    (
+        "SI",
+        "D.O.O.",
+        "{s}OVA ULICA {n}",
+        "####",
+        &["LJUBLJANA", "MARIBOR", "CELJE"],
+    ),
+    (
+        "SK",
+        "S.R.O.",
+        "{s}OVA {n}",
+        "### ##",
+        &["BRATISLAVA", "KOSICE", "ZILINA"],
+    ),
+    (
+        "XI",
+        "LTD",
+        "{n} {s} ROAD",
+        "BT# #@@",
+        &["BELFAST", "DERRY", "LISBURN"],
+    ),
+];
+
+const NAME_WORDS: &[&str] = &[
+    "ALPINE",
+    "ATLAS",
+    "BALTIC",
+    "BOREAL",
+    "CEDAR",
+    "DELTA",
+    "EMERALD",
+    "FALCON",
+    "GRANITE",
+    "HARBOUR",
+    "JUNIPER",
+    "KESTREL",
+    "LUMEN",
+    "MERIDIAN",
+    "NOVA",
+    "ORION",
+    "POLARIS",
+    "QUARTZ",
+    "RIVERSIDE",
+    "SILVA",
+    "TERRA",
+    "VEGA",
+    "WESTWIND",
+    "ZENITH",
+];
+
+const SECTORS: &[&str] = &[
+    "TRADING",
+    "LOGISTICS",
+    "SYSTEMS",
+    "FOODS",
+    "CONSULTING",
+    "ENGINEERING",
+    "TEXTILES",
+    "MEDIA",
+    "ENERGY",
+    "HOLDING",
+    "TECHNOLOGIES",
+];
+
+const STREETS: &[&str] = &[
+    "MOZART", "EUROPA", "KEPLER", "CURIE", "GALILEI", "NOBEL", "DANTE", "CHOPIN", "VIVALDI",
+    "GOETHE", "ERASMUS", "LINNE",
+];
+
+/// Company name and address for a valid number, the same on every call.
+fn trader(cc: &str, number: &str) -> (String, String) {
+    let seed = crate::anonymize::siphash24(0, 0, format!("{}{}", cc, number).as_bytes());
+    let mut rng = StdRng::seed_from_u64(seed);
+    let (_, form, street, postcode, cities) = LOCALES
+        .iter()
+        .find(|(c, ..)| *c == cc)
+        .expect("every member state has a locale");
+
+    let name = format!(
+        "{} {} {}",
+        NAME_WORDS.choose(&mut rng).unwrap(),
+        SECTORS.choose(&mut rng).unwrap(),
+        form
+    );
+    let street = street
+        .replace("{s}", STREETS.choose(&mut rng).unwrap())
+        .replace("{n}", &rng.gen_range(1..200).to_string());
+    let postcode: String = postcode
+        .chars()
+        .map(|c| match c {
+            '#' => char::from(b'0' + rng.gen_range(0..10)),
+            '@' => char::from(b'A' + rng.gen_range(0..26)),
+            c => c,
+        })
+        .collect();
+    let city = cities.choose(&mut rng).unwrap();
+    (name, format!("{}\n{} {}", street, postcode, city))
+}
+
+/// Text of the first element named `name`, with or without a namespace
+/// prefix.
+fn element<'a>(xml: &'a str, name: &str) -> Option<&'a str> {
+    let mut rest = xml;
+    while let Some(i) = rest.find('<') {
+        rest = &rest[i + 1..];
+        let end = rest.find(['>', ' ', '/'])?;
+        let tag = &rest[..end];
+        let local = tag.rsplit(':').next().unwrap_or(tag);
+        if local == name {
+            let body = &rest[rest.find('>')? + 1..];
+            return Some(body[..body.find('<')?].trim());
+        }
+    }
+    None
+}
+
+fn xml(status: u16, body: String) -> Response {
+    Response {
+        status,
+        content_type: "text/xml; charset=utf-8",
+        body,
+    }
+}
+
+fn soap_fault(fault: Fault) -> Response {
+    xml(
+        500,
+        format!(
+            concat!(
+                "<env:Envelope xmlns:env=\"http://schemas.xmlsoap.org/soap/envelope/\">",
+                "<env:Header/><env:Body><env:Fault>",
+                "<faultcode>env:Server</faultcode><faultstring>{}</faultstring>",
+                "</env:Fault></env:Body></env:Envelope>"
+            ),
+            fault
+        ),
+    )
+}
+
+fn xml_escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Today's date in UTC as `YYYY-MM-DD`.
+fn today() -> String {
+    let secs = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs());
+    // Days since 1970-01-01 to a civil date (Hinnant's algorithm)
+    let z = (secs / 86_400) as i64 + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
+
+/// The REST API's request timestamp for `date`.
+fn timestamp(date: &str) -> String {
+    format!("{}T00:00:00.000Z", date)
+}
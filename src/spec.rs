@@ -0,0 +1,917 @@
+//! Declarative identifier definitions.
+//!
+//! Many identifiers are a fixed layout of segments (a prefix, N digits, a
+//! date, ...) closed by a checksum. A [`Spec`] describes such a layout in
+//! TOML or JSON so idsmith can generate and validate it without Rust code.
+//!
+//! ```toml
+//! name = "ACME customer number"
+//! country = "ACME"
+//! registry = "company"        # or "personal"
+//!
+//! [[segments]]
+//! type = "literal"
+//! value = "CU-"
+//!
+//! [[segments]]
+//! type = "digits"
+//! length = 8
+//!
+//! [[segments]]
+//! type = "checksum"
+//! algorithm = "weighted"
+//! weights = [3, 7, 1]
+//! modulus = 10
+//! ```
+//!
+//! Segment types:
+//!
+//! | `type` | Fields | Produces |
+//! |--------|--------|----------|
+//! | `literal` | `value` | the value as-is |
+//! | `digits` | `length` | random digits |
+//! | `letters` | `length` | random uppercase letters |
+//! | `alphanumeric` | `length` | random digits and uppercase letters |
+//! | `charset` | `chars`, `length` | random characters from `chars` |
+//! | `range` | `min`, `max`, `width` | a zero-padded number in `min..=max` |
+//! | `choice` | `values` | one of `values` |
+//! | `date` | `format`, `min_year`, `max_year` | a date, e.g. `YYMMDD` |
+//! | `checksum` | `algorithm`, ... | check character(s) over the preceding segments |
+//!
+//! Checksum algorithms: `luhn`, `verhoeff`, `mod97` (ISO 7064 MOD 97-10),
+//! `iso7064_mod11_2`, `iso7064_mod11_10`, `iso7064_mod37_2`,
+//! `iso7064_mod37_36` and `weighted` (`weights`, `modulus` = 11,
+//! `complement` = true, `ten` = replacement for a check value of 10).
+//! A checksum covers every preceding non-literal character; set
+//! `include_literals = true` to cover literals too. Letters count as 10-35.
+//!
+//! ```rust
+//! use idsmith::spec::Spec;
+//!
+//! let specs = Spec::from_json(r#"{
+//!     "name": "Badge",
+//!     "country": "BADGE",
+//!     "segments": [
+//!         { "type": "letters", "length": 2 },
+//!         { "type": "digits", "length": 6 },
+//!         { "type": "checksum", "algorithm": "iso7064_mod37_36" }
+//!     ]
+//! }"#).unwrap();
+//!
+//! let code = specs[0].generate(&mut rand::thread_rng()).unwrap();
+//! assert_eq!(code.len(), 9);
+//! assert!(specs[0].validate(&code));
+//! ```
+
+use std::path::Path;
+
+use rand::{Rng, RngCore};
+use serde::Deserialize;
+
+use crate::personal_id::checksum::{
+    iso7064_hybrid, iso7064_mod11_10, iso7064_mod97_10, iso7064_pure, luhn_check, verhoeff_check,
+};
+use crate::personal_id::GenOptions;
+use crate::scheme::IdScheme;
+
+/// Generation gives up after this many attempts to hit a representable
+/// checksum (e.g. a weighted mod-11 value of 10 without a `ten` mapping).
+const MAX_ATTEMPTS: usize = 1000;
+
+/// `range` segments with at most this many values are checked value by
+/// value when deciding whether a checksum can be written; wider ranges are
+/// treated as any digits.
+const RANGE_VALUES_CHECKED: u64 = 100;
+
+/// Weighted checksums with more (weight position, residue) states than this
+/// are not checked for representability up front.
+const MAX_CHECKSUM_STATES: usize = 1 << 16;
+
+const ALPHANUMERIC: &[u8] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// A declarative identifier definition.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Spec {
+    /// Name of the identifier, shown in country listings.
+    pub name: String,
+    /// Country code (or any custom code) the scheme is registered under.
+    pub country: String,
+    /// Registry the scheme belongs to.
+    #[serde(default)]
+    pub registry: SpecRegistry,
+    /// Replace an existing scheme for `country` instead of failing.
+    #[serde(default, rename = "override")]
+    pub replace: bool,
+    pub segments: Vec<Segment>,
+}
+
+/// Which global registry a [`Spec`] is registered with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SpecRegistry {
+    #[default]
+    Company,
+    Personal,
+}
+
+/// One part of an identifier layout. See the [module docs](self).
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case", deny_unknown_fields)]
+pub enum Segment {
+    Literal {
+        value: String,
+    },
+    Digits {
+        length: usize,
+    },
+    Letters {
+        length: usize,
+    },
+    Alphanumeric {
+        length: usize,
+    },
+    Charset {
+        chars: String,
+        length: usize,
+    },
+    Range {
+        min: u64,
+        max: u64,
+        /// Zero-padded width; defaults to the number of digits in `max`.
+        #[serde(default)]
+        width: Option<usize>,
+    },
+    Choice {
+        values: Vec<String>,
+    },
+    Date {
+        /// `YYYY`, `YY`, `MM` and `DD` tokens; other characters are literal.
+        #[serde(default = "default_date_format")]
+        format: String,
+        #[serde(default = "default_min_year")]
+        min_year: u16,
+        #[serde(default = "default_max_year")]
+        max_year: u16,
+    },
+    Checksum {
+        algorithm: Algorithm,
+        #[serde(default)]
+        weights: Vec<u32>,
+        #[serde(default)]
+        modulus: Option<u32>,
+        #[serde(default = "default_true")]
+        complement: bool,
+        #[serde(default)]
+        ten: Option<String>,
+        #[serde(default)]
+        include_literals: bool,
+    },
+}
+
+/// Checksum algorithms available to [`Segment::Checksum`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Algorithm {
+    Luhn,
+    Verhoeff,
+    #[serde(alias = "iso7064_mod97_10")]
+    Mod97,
+    Iso7064Mod11_2,
+    Iso7064Mod11_10,
+    Iso7064Mod37_2,
+    Iso7064Mod37_36,
+    Weighted,
+}
+
+impl Algorithm {
+    /// Algorithms defined over digits only.
+    fn digits_only(&self) -> bool {
+        matches!(
+            self,
+            Algorithm::Luhn
+                | Algorithm::Verhoeff
+                | Algorithm::Iso7064Mod11_2
+                | Algorithm::Iso7064Mod11_10
+        )
+    }
+
+    /// Name reported by [`IdScheme::checksum`].
+    fn label(&self, modulus: Option<u32>) -> String {
+        match self {
+            Algorithm::Luhn => "Luhn".to_string(),
+            Algorithm::Verhoeff => "Verhoeff".to_string(),
+            Algorithm::Mod97 => "ISO 7064 MOD 97-10".to_string(),
+            Algorithm::Iso7064Mod11_2 => "ISO 7064 MOD 11-2".to_string(),
+            Algorithm::Iso7064Mod11_10 => "ISO 7064 MOD 11-10".to_string(),
+            Algorithm::Iso7064Mod37_2 => "ISO 7064 MOD 37-2".to_string(),
+            Algorithm::Iso7064Mod37_36 => "ISO 7064 MOD 37-36".to_string(),
+            Algorithm::Weighted => format!("Weighted mod {}", modulus.unwrap_or(11)),
+        }
+    }
+}
+
+fn default_date_format() -> String {
+    "YYYYMMDD".to_string()
+}
+
+fn default_min_year() -> u16 {
+    1950
+}
+
+fn default_max_year() -> u16 {
+    2020
+}
+
+fn default_true() -> bool {
+    true
+}
+
+impl Spec {
+    /// Parse one spec (an object) or several (an array) from JSON.
+    pub fn from_json(s: &str) -> Result<Vec<Spec>, String> {
+        let value: serde_json::Value =
+            serde_json::from_str(s).map_err(|e| format!("Invalid spec JSON: {}", e))?;
+        let values = match value {
+            serde_json::Value::Array(items) => items,
+            other => vec![other],
+        };
+        values
+            .into_iter()
+            .map(|v| {
+                let spec: Spec =
+                    Spec::deserialize(v).map_err(|e| format!("Invalid spec: {}", e))?;
+                spec.check()?;
+                Ok(spec)
+            })
+            .collect()
+    }
+
+    /// Parse one spec (a top-level table) or several (`[[spec]]` tables)
+    /// from TOML.
+    pub fn from_toml(s: &str) -> Result<Vec<Spec>, String> {
+        let mut table: toml::Table = s.parse().map_err(|e| format!("Invalid spec TOML: {}", e))?;
+        let values = match table.remove("spec") {
+            Some(toml::Value::Array(items)) if table.is_empty() => items,
+            Some(_) => return Err("Invalid spec TOML: `spec` must be an array of tables".into()),
+            None => vec![toml::Value::Table(table)],
+        };
+        values
+            .into_iter()
+            .map(|v| {
+                let spec: Spec = v.try_into().map_err(|e| format!("Invalid spec: {}", e))?;
+                spec.check()?;
+                Ok(spec)
+            })
+            .collect()
+    }
+
+    /// Read specs from a `.toml` or `.json` file.
+    pub fn load(path: impl AsRef<Path>) -> Result<Vec<Spec>, String> {
+        let path = path.as_ref();
+        let text = std::fs::read_to_string(path)
+            .map_err(|e| format!("Cannot read {}: {}", path.display(), e))?;
+        let is_toml = path
+            .extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("toml"));
+        let specs = if is_toml {
+            Spec::from_toml(&text)
+        } else {
+            Spec::from_json(&text)
+        };
+        specs.map_err(|e| format!("{}: {}", path.display(), e))
+    }
+
+    /// Register the spec with the global personal ID or company ID
+    /// registry, so `personal_ids()` / `company_ids()` and everything built
+    /// on them can use it.
+    pub fn register(self) -> Result<(), String> {
+        let country = self.country.clone();
+        match (self.registry, self.replace) {
+            (SpecRegistry::Company, false) => crate::company_ids().register(&country, self),
+            (SpecRegistry::Company, true) => crate::company_ids().override_scheme(&country, self),
+            (SpecRegistry::Personal, false) => crate::personal_ids().register(&country, self),
+            (SpecRegistry::Personal, true) => crate::personal_ids().override_scheme(&country, self),
+        }
+    }
+
+    /// Check the definition itself: lengths, ranges, date formats and
+    /// whether each checksum can be computed over what precedes it.
+    pub fn check(&self) -> Result<(), String> {
+        let fail = |msg: String| Err(format!("{}: {}", self.name, msg));
+        if self.name.trim().is_empty() {
+            return Err("Spec name must not be empty".into());
+        }
+        crate::scheme::registration_key(&self.country)?;
+        if self.segments.is_empty() {
+            return fail("no segments".into());
+        }
+
+        // Whether the characters a checksum would cover so far are all digits.
+        let mut body_digits = true;
+        let mut full_digits = true;
+        for (i, segment) in self.segments.iter().enumerate() {
+            let n = i + 1;
+            let (digits, literal) = match segment {
+                Segment::Literal { value } => {
+                    if value.is_empty() || !value.is_ascii() {
+                        return fail(format!("segment {}: literal must be non-empty ASCII", n));
+                    }
+                    (value.chars().all(|c| c.is_ascii_digit()), true)
+                }
+                Segment::Digits { length }
+                | Segment::Letters { length }
+                | Segment::Alphanumeric { length }
+                    if *length == 0 =>
+                {
+                    return fail(format!("segment {}: length must be at least 1", n));
+                }
+                Segment::Digits { .. } => (true, false),
+                Segment::Letters { .. } | Segment::Alphanumeric { .. } => (false, false),
+                Segment::Charset { chars, length } => {
+                    if *length == 0 || chars.is_empty() {
+                        return fail(format!("segment {}: empty charset or length", n));
+                    }
+                    if !chars.chars().all(|c| c.is_ascii_alphanumeric()) {
+                        return fail(format!(
+                            "segment {}: charset must be ASCII letters and digits",
+                            n
+                        ));
+                    }
+                    (chars.chars().all(|c| c.is_ascii_digit()), false)
+                }
+                Segment::Range { min, max, width } => {
+                    if min > max {
+                        return fail(format!("segment {}: min is greater than max", n));
+                    }
+                    if width.is_some_and(|w| w < max.to_string().len()) {
+                        return fail(format!("segment {}: width is too small for max", n));
+                    }
+                    (true, false)
+                }
+                Segment::Choice { values } => {
+                    if values.is_empty()
+                        || values
+                            .iter()
+                            .any(|v| v.is_empty() || !v.chars().all(|c| c.is_ascii_alphanumeric()))
+                    {
+                        return fail(format!(
+                            "segment {}: choice values must be non-empty ASCII letters and digits",
+                            n
+                        ));
+                    }
+                    (
+                        values.iter().all(|v| v.chars().all(|c| c.is_ascii_digit())),
+                        false,
+                    )
+                }
+                Segment::Date {
+                    format,
+                    min_year,
+                    max_year,
+                } => {
+                    let tokens = date_tokens(format);
+                    if !tokens.iter().any(|t| matches!(t, DateToken::Field(_))) {
+                        return fail(format!("segment {}: date format has no fields", n));
+                    }
+                    if min_year > max_year {
+                        return fail(format!("segment {}: min_year is after max_year", n));
+                    }
+                    let digits = tokens
+                        .iter()
+                        .all(|t| !matches!(t, DateToken::Literal(c) if !c.is_ascii_digit()));
+                    (digits, false)
+                }
+                Segment::Checksum {
+                    algorithm,
+                    weights,
+                    modulus,
+                    ten,
+                    include_literals,
+                    ..
+                } => {
+                    let covered_digits = if *include_literals {
+                        full_digits
+                    } else {
+                        body_digits
+                    };
+                    if algorithm.digits_only() && !covered_digits {
+                        return fail(format!(
+                            "segment {}: {:?} checksum needs digits-only input",
+                            n, algorithm
+                        ));
+                    }
+                    if *algorithm == Algorithm::Weighted {
+                        if weights.is_empty() {
+                            return fail(format!("segment {}: weighted checksum needs weights", n));
+                        }
+                        if modulus.is_some_and(|m| m < 2) {
+                            return fail(format!("segment {}: modulus must be at least 2", n));
+                        }
+                    }
+                    if ten.as_ref().is_some_and(|t| {
+                        t.len() != 1 || !t.chars().all(|c| c.is_ascii_alphanumeric())
+                    }) {
+                        return fail(format!("segment {}: `ten` must be one letter or digit", n));
+                    }
+                    if let Err(reason) = self.checksum_reachable(i) {
+                        return fail(format!("segment {}: {}", n, reason));
+                    }
+                    // Check characters are digits unless an algorithm can
+                    // produce letters.
+                    let digits = matches!(
+                        algorithm,
+                        Algorithm::Luhn
+                            | Algorithm::Verhoeff
+                            | Algorithm::Mod97
+                            | Algorithm::Iso7064Mod11_10
+                    ) || (*algorithm == Algorithm::Weighted
+                        && ten
+                            .as_ref()
+                            .is_none_or(|t| t.chars().all(|c| c.is_ascii_digit()))
+                        && modulus.unwrap_or(11) <= 11);
+                    (digits, false)
+                }
+            };
+            full_digits &= digits;
+            if !literal {
+                body_digits &= digits;
+            }
+        }
+        Ok(())
+    }
+
+    /// Whether checksum segment `idx` can ever be written: some filling of
+    /// the segments it covers must consist of letters and digits and, for
+    /// weighted checksums, give a value with a check character.
+    fn checksum_reachable(&self, idx: usize) -> Result<(), String> {
+        let Segment::Checksum {
+            algorithm,
+            weights,
+            modulus,
+            complement,
+            ten,
+            include_literals,
+        } = &self.segments[idx]
+        else {
+            return Ok(());
+        };
+        // Other algorithms have a check character for any alphanumeric
+        // input, so only the weighted sum needs tracking.
+        let (weights, m) = match algorithm {
+            Algorithm::Weighted => (weights.as_slice(), modulus.unwrap_or(11) as usize),
+            _ => (&[0][..], 1),
+        };
+        let size = weights.len() * m;
+        if size > MAX_CHECKSUM_STATES {
+            return Ok(());
+        }
+
+        // Reachable (weight position, weighted sum mod m), as `pos * m + sum`.
+        let mut states = vec![false; size];
+        states[0] = true;
+        for segment in &self.segments[..idx] {
+            if matches!(segment, Segment::Literal { .. }) && !include_literals {
+                continue;
+            }
+            let mut after = vec![false; size];
+            for layout in layouts(segment) {
+                let mut current = states.clone();
+                for values in &layout {
+                    let mut next = vec![false; size];
+                    for state in (0..size).filter(|&s| current[s]) {
+                        let (pos, sum) = (state / m, state % m);
+                        for &v in values {
+                            let sum = (sum + v as usize * weights[pos] as usize) % m;
+                            next[(pos + 1) % weights.len() * m + sum] = true;
+                        }
+                    }
+                    current = next;
+                }
+                for (a, c) in after.iter_mut().zip(current) {
+                    *a |= c;
+                }
+            }
+            states = after;
+        }
+
+        let sums: Vec<u32> = (0..size)
+            .filter(|&s| states[s])
+            .map(|s| (s % m) as u32)
+            .collect();
+        if sums.is_empty() {
+            return Err("checksum covers characters that are not letters or digits".into());
+        }
+        let writable = |r: u32| {
+            let m = m as u32;
+            let check = if *complement { (m - r) % m } else { r };
+            check <= 9 || (check == 10 && ten.is_some())
+        };
+        if *algorithm == Algorithm::Weighted && !sums.into_iter().any(writable) {
+            return Err(
+                "weighted checksum never has a check character; set `ten` or change the weights"
+                    .into(),
+            );
+        }
+        Ok(())
+    }
+
+    /// Generate a code matching the spec.
+    ///
+    /// [`check`](Self::check) rejects specs whose checksum can never be
+    /// written; this still fails if no attempt hits a representable one.
+    pub fn generate<R: Rng + ?Sized>(&self, rng: &mut R) -> Result<String, String> {
+        (0..MAX_ATTEMPTS)
+            .find_map(|_| self.try_generate(rng))
+            .ok_or_else(|| {
+                format!(
+                    "{}: could not generate a code with a representable checksum",
+                    self.name
+                )
+            })
+    }
+
+    fn try_generate<R: Rng + ?Sized>(&self, rng: &mut R) -> Option<String> {
+        let mut full = String::new();
+        let mut body = String::new();
+        for segment in &self.segments {
+            let part = match segment {
+                Segment::Literal { value } => {
+                    full.push_str(value);
+                    continue;
+                }
+                Segment::Digits { length } => random_from(rng, b"0123456789", *length),
+                Segment::Letters { length } => random_from(rng, &ALPHANUMERIC[10..], *length),
+                Segment::Alphanumeric { length } => random_from(rng, ALPHANUMERIC, *length),
+                Segment::Charset { chars, length } => random_from(rng, chars.as_bytes(), *length),
+                Segment::Range { min, max, width } => {
+                    let width = width.unwrap_or_else(|| max.to_string().len());
+                    format!("{:0width$}", rng.gen_range(*min..=*max), width = width)
+                }
+                Segment::Choice { values } => values[rng.gen_range(0..values.len())].clone(),
+                Segment::Date {
+                    format,
+                    min_year,
+                    max_year,
+                } => {
+                    let year = rng.gen_range(*min_year..=*max_year);
+                    let month = rng.gen_range(1..=12);
+                    let day = rng.gen_range(1..=days_in_month(year, month));
+                    format_date(format, year, month, day)
+                }
+                Segment::Checksum {
+                    include_literals, ..
+                } => {
+                    let input = if *include_literals { &full } else { &body };
+                    checksum(segment, input)?
+                }
+            };
+            full.push_str(&part);
+            body.push_str(&part);
+        }
+        Some(full)
+    }
+
+    /// Check whether `code` matches the spec, including every checksum.
+    pub fn validate(&self, code: &str) -> bool {
+        code.is_ascii() && self.matches(0, code, String::new(), String::new())
+    }
+
+    fn matches(&self, idx: usize, rest: &str, full: String, body: String) -> bool {
+        let Some(segment) = self.segments.get(idx) else {
+            return rest.is_empty();
+        };
+        let next = |len: usize, ok: &dyn Fn(&str) -> bool, literal: bool| -> bool {
+            let Some(part) = rest.get(..len) else {
+                return false;
+            };
+            if !ok(part) {
+                return false;
+            }
+            let full = full.clone() + part;
+            let body = if literal {
+                body.clone()
+            } else {
+                body.clone() + part
+            };
+            self.matches(idx + 1, &rest[len..], full, body)
+        };
+
+        match segment {
+            Segment::Literal { value } => next(value.len(), &|p| p == value, true),
+            Segment::Digits { length } => {
+                next(*length, &|p| p.bytes().all(|b| b.is_ascii_digit()), false)
+            }
+            Segment::Letters { length } => next(
+                *length,
+                &|p| p.bytes().all(|b| b.is_ascii_uppercase()),
+                false,
+            ),
+            Segment::Alphanumeric { length } => next(
+                *length,
+                &|p| {
+                    p.bytes()
+                        .all(|b| b.is_ascii_digit() || b.is_ascii_uppercase())
+                },
+                false,
+            ),
+            Segment::Charset { chars, length } => {
+                next(*length, &|p| p.chars().all(|c| chars.contains(c)), false)
+            }
+            Segment::Range { min, max, width } => {
+                let width = width.unwrap_or_else(|| max.to_string().len());
+                next(
+                    width,
+                    &|p| {
+                        p.bytes().all(|b| b.is_ascii_digit())
+                            && p.parse::<u64>().is_ok_and(|v| (*min..=*max).contains(&v))
+                    },
+                    false,
+                )
+            }
+            Segment::Choice { values } => values.iter().any(|v| next(v.len(), &|p| p == v, false)),
+            Segment::Date { format, .. } => {
+                let len = date_tokens(format).iter().map(DateToken::len).sum();
+                next(len, &|p| parse_date(format, p), false)
+            }
+            Segment::Checksum {
+                include_literals, ..
+            } => {
+                let input = if *include_literals { &full } else { &body };
+                match checksum(segment, input) {
+                    Some(expected) => next(expected.len(), &|p| p == expected, false),
+                    None => false,
+                }
+            }
+        }
+    }
+}
+
+impl IdScheme for Spec {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Empty if generation fails; registries use
+    /// [`try_generate`](IdScheme::try_generate), which reports it.
+    fn generate(&self, rng: &mut dyn RngCore) -> String {
+        Spec::generate(self, rng).unwrap_or_default()
+    }
+
+    fn try_generate(&self, _opts: &GenOptions, rng: &mut dyn RngCore) -> Option<String> {
+        Spec::generate(self, rng).ok()
+    }
+
+    fn validate(&self, code: &str) -> bool {
+        Spec::validate(self, code)
+    }
+
+    /// The algorithms of the checksum segments, joined with "and".
+    fn checksum(&self) -> Option<String> {
+        let names: Vec<String> = self
+            .segments
+            .iter()
+            .filter_map(|segment| match segment {
+                Segment::Checksum {
+                    algorithm, modulus, ..
+                } => Some(algorithm.label(*modulus)),
+                _ => None,
+            })
+            .collect();
+        (!names.is_empty()).then(|| names.join(" and "))
+    }
+}
+
+/// Values each position of a segment can take, one list of positions per
+/// way the segment can be written. Characters that are not letters or
+/// digits have no value, so a checksum can never cover them.
+fn layouts(segment: &Segment) -> Vec<Vec<Vec<u32>>> {
+    let fixed = |s: &str| -> Vec<Vec<u32>> {
+        s.chars()
+            .map(|c| char_value(c).into_iter().collect())
+            .collect()
+    };
+    let any_of = |chars: &[u8], length: usize| -> Vec<Vec<u32>> {
+        let values: Vec<u32> = chars
+            .iter()
+            .filter_map(|&b| char_value(b as char))
+            .collect();
+        vec![values; length]
+    };
+    let digits = &ALPHANUMERIC[..10];
+    match segment {
+        Segment::Literal { value } => vec![fixed(value)],
+        Segment::Digits { length } => vec![any_of(digits, *length)],
+        Segment::Letters { length } => vec![any_of(&ALPHANUMERIC[10..], *length)],
+        Segment::Alphanumeric { length } => vec![any_of(ALPHANUMERIC, *length)],
+        Segment::Charset { chars, length } => vec![any_of(chars.as_bytes(), *length)],
+        Segment::Range { min, max, width } => {
+            let width = width.unwrap_or_else(|| max.to_string().len());
+            if max - min < RANGE_VALUES_CHECKED {
+                (*min..=*max)
+                    .map(|v| fixed(&format!("{:0width$}", v, width = width)))
+                    .collect()
+            } else {
+                vec![any_of(digits, width)]
+            }
+        }
+        Segment::Choice { values } => values.iter().map(|v| fixed(v)).collect(),
+        Segment::Date { format, .. } => vec![date_tokens(format)
+            .iter()
+            .flat_map(|token| match token {
+                DateToken::Field(_) => any_of(digits, token.len()),
+                DateToken::Literal(c) => fixed(&c.to_string()),
+            })
+            .collect()],
+        Segment::Checksum { algorithm, ten, .. } => match algorithm {
+            Algorithm::Mod97 => vec![any_of(digits, 2)],
+            Algorithm::Iso7064Mod11_2 => vec![any_of(b"0123456789X", 1)],
+            Algorithm::Iso7064Mod37_2 | Algorithm::Iso7064Mod37_36 => {
+                vec![any_of(ALPHANUMERIC, 1)]
+            }
+            Algorithm::Weighted => {
+                let mut chars = digits.to_vec();
+                chars.extend(ten.iter().flat_map(|t| t.bytes()));
+                vec![any_of(&chars, 1)]
+            }
+            _ => vec![any_of(digits, 1)],
+        },
+    }
+}
+
+fn random_from<R: Rng + ?Sized>(rng: &mut R, chars: &[u8], length: usize) -> String {
+    (0..length)
+        .map(|_| chars[rng.gen_range(0..chars.len())] as char)
+        .collect()
+}
+
+/// Digits are worth 0-9, letters 10-35.
+fn char_value(c: char) -> Option<u32> {
+    c.to_digit(36)
+}
+
+fn check_char(value: u32) -> char {
+    ALPHANUMERIC[value as usize] as char
+}
+
+/// Compute the check character(s) for a checksum segment, or `None` when
+/// the value cannot be written (e.g. a weighted value of 10 without `ten`).
+fn checksum(segment: &Segment, input: &str) -> Option<String> {
+    let Segment::Checksum {
+        algorithm,
+        weights,
+        modulus,
+        complement,
+        ten,
+        ..
+    } = segment
+    else {
+        return None;
+    };
+    let values: Vec<u32> = input.chars().map(char_value).collect::<Option<_>>()?;
+    let digits = || -> Option<Vec<u8>> {
+        values
+            .iter()
+            .map(|&v| (v < 10).then_some(v as u8))
+            .collect()
+    };
+
+    match algorithm {
+        Algorithm::Luhn => Some(luhn_check(&digits()?).to_string()),
+        Algorithm::Verhoeff => Some(verhoeff_check(&digits()?).to_string()),
+        Algorithm::Iso7064Mod11_10 => Some(iso7064_mod11_10(&digits()?).to_string()),
+        Algorithm::Mod97 => Some(format!("{:02}", iso7064_mod97_10(&values))),
+        Algorithm::Iso7064Mod11_2 => {
+            digits()?;
+            match iso7064_pure(&values, 11, 2) {
+                10 => Some("X".to_string()),
+                v => Some(v.to_string()),
+            }
+        }
+        Algorithm::Iso7064Mod37_2 => match iso7064_pure(&values, 37, 2) {
+            36 => Some("*".to_string()),
+            v => Some(check_char(v).to_string()),
+        },
+        Algorithm::Iso7064Mod37_36 => Some(check_char(iso7064_hybrid(&values, 36)).to_string()),
+        Algorithm::Weighted => {
+            let m = modulus.unwrap_or(11);
+            let sum: u32 = values
+                .iter()
+                .zip(weights.iter().cycle())
+                .map(|(v, w)| v * w)
+                .sum();
+            let r = sum % m;
+            let check = if *complement { (m - r) % m } else { r };
+            match check {
+                0..=9 => Some(check.to_string()),
+                10 => ten.clone(),
+                _ => None,
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DateField {
+    Year4,
+    Year2,
+    Month,
+    Day,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum DateToken {
+    Field(DateField),
+    Literal(char),
+}
+
+impl DateToken {
+    fn len(&self) -> usize {
+        match self {
+            DateToken::Field(DateField::Year4) => 4,
+            DateToken::Field(_) => 2,
+            DateToken::Literal(c) => c.len_utf8(),
+        }
+    }
+}
+
+fn date_tokens(format: &str) -> Vec<DateToken> {
+    let mut tokens = Vec::new();
+    let mut rest = format;
+    while !rest.is_empty() {
+        let (token, len) = if rest.starts_with("YYYY") {
+            (DateToken::Field(DateField::Year4), 4)
+        } else if rest.starts_with("YY") {
+            (DateToken::Field(DateField::Year2), 2)
+        } else if rest.starts_with("MM") {
+            (DateToken::Field(DateField::Month), 2)
+        } else if rest.starts_with("DD") {
+            (DateToken::Field(DateField::Day), 2)
+        } else {
+            let c = rest.chars().next().unwrap();
+            (DateToken::Literal(c), c.len_utf8())
+        };
+        tokens.push(token);
+        rest = &rest[len..];
+    }
+    tokens
+}
+
+fn is_leap(year: u16) -> bool {
+    (year.is_multiple_of(4) && !year.is_multiple_of(100)) || year.is_multiple_of(400)
+}
+
+fn days_in_month(year: u16, month: u8) -> u8 {
+    match month {
+        2 if is_leap(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+fn format_date(format: &str, year: u16, month: u8, day: u8) -> String {
+    date_tokens(format)
+        .iter()
+        .map(|t| match t {
+            DateToken::Field(DateField::Year4) => format!("{:04}", year),
+            DateToken::Field(DateField::Year2) => format!("{:02}", year % 100),
+            DateToken::Field(DateField::Month) => format!("{:02}", month),
+            DateToken::Field(DateField::Day) => format!("{:02}", day),
+            DateToken::Literal(c) => c.to_string(),
+        })
+        .collect()
+}
+
+fn parse_date(format: &str, text: &str) -> bool {
+    // Two-digit years are checked as 20YY, which has the same leap years
+    // as 19YY for every year but 1900.
+    let (mut year, mut month, mut day) = (2000u16, 1u8, 1u8);
+    let mut pos = 0;
+    for token in date_tokens(format) {
+        let Some(part) = text.get(pos..pos + token.len()) else {
+            return false;
+        };
+        pos += token.len();
+        match token {
+            DateToken::Literal(c) => {
+                if !part.starts_with(c) {
+                    return false;
+                }
+            }
+            DateToken::Field(field) => {
+                if !part.bytes().all(|b| b.is_ascii_digit()) {
+                    return false;
+                }
+                let v: u16 = part.parse().unwrap_or(0);
+                match field {
+                    DateField::Year4 => year = v,
+                    DateField::Year2 => year = 2000 + v,
+                    DateField::Month => month = v as u8,
+                    DateField::Day => day = v as u8,
+                }
+            }
+        }
+    }
+    (1..=12).contains(&month) && day >= 1 && day <= days_in_month(year, month)
+}
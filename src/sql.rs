@@ -0,0 +1,349 @@
+//! SQL fixture output: batched `INSERT` statements or a PostgreSQL
+//! `COPY ... FROM STDIN` block, with an optional `CREATE TABLE` preamble.
+//!
+//! Enable with the `sql` feature flag. Every result type implements
+//! [`ToSql`] through [`Record`](crate::output::Record), which describes its
+//! typed columns.
+//!
+//! ```rust
+//! use idsmith::sql::{Dialect, Options, Writer};
+//! use idsmith::vat::VatResult;
+//!
+//! let opts = Options {
+//!     dialect: Dialect::Sqlite,
+//!     create_table: true,
+//!     ..Default::default()
+//! };
+//! let mut writer = Writer::for_rows::<VatResult>(Vec::new(), "vat_numbers", opts).unwrap();
+//! let vat = idsmith::vat_ids()
+//!     .generate(&Default::default(), &mut rand::thread_rng())
+//!     .unwrap();
+//! writer.write(&vat).unwrap();
+//! let sql = String::from_utf8(writer.finish().unwrap()).unwrap();
+//! assert!(sql.starts_with("CREATE TABLE IF NOT EXISTS \"vat_numbers\""));
+//! assert!(sql.contains("INSERT INTO \"vat_numbers\""));
+//! ```
+
+use std::fmt;
+use std::io::{self, Write};
+use std::str::FromStr;
+
+use crate::output::{Field, FieldType, Record};
+
+pub use crate::output::Value;
+
+/// Rows per `INSERT` statement unless [`Options::batch_size`] says otherwise.
+pub const DEFAULT_BATCH_SIZE: usize = 500;
+
+/// SQL dialect, which decides quoting, column types and boolean literals.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Dialect {
+    #[default]
+    Postgres,
+    Mysql,
+    Sqlite,
+}
+
+impl Dialect {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Dialect::Postgres => "postgres",
+            Dialect::Mysql => "mysql",
+            Dialect::Sqlite => "sqlite",
+        }
+    }
+
+    /// Quote a table or column name.
+    pub fn identifier(&self, name: &str) -> String {
+        match self {
+            Dialect::Mysql => format!("`{}`", name.replace('`', "``")),
+            _ => format!("\"{}\"", name.replace('"', "\"\"")),
+        }
+    }
+
+    /// Render a value as an SQL literal.
+    pub fn literal(&self, value: &Value) -> String {
+        match (value, self) {
+            (Value::Null, _) => "NULL".to_string(),
+            (Value::Bool(b), Dialect::Sqlite) => if *b { "1" } else { "0" }.to_string(),
+            (Value::Bool(b), _) => if *b { "TRUE" } else { "FALSE" }.to_string(),
+            // MySQL treats backslashes in string literals as escapes
+            (Value::Text(s), Dialect::Mysql) => {
+                format!("'{}'", s.replace('\\', "\\\\").replace('\'', "''"))
+            }
+            (Value::Text(s), _) => format!("'{}'", s.replace('\'', "''")),
+        }
+    }
+
+    fn type_name(&self, ty: SqlType) -> &'static str {
+        match (ty, self) {
+            (SqlType::Text, Dialect::Mysql) => "VARCHAR(255)",
+            (SqlType::Text, _) => "TEXT",
+            (SqlType::Boolean, _) => "BOOLEAN",
+        }
+    }
+}
+
+impl fmt::Display for Dialect {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for Dialect {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "postgres" | "postgresql" | "pg" => Ok(Dialect::Postgres),
+            "mysql" | "mariadb" => Ok(Dialect::Mysql),
+            "sqlite" => Ok(Dialect::Sqlite),
+            other => Err(format!(
+                "Unknown SQL dialect: {}. Use postgres, mysql, or sqlite.",
+                other
+            )),
+        }
+    }
+}
+
+/// Column type in `CREATE TABLE`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SqlType {
+    Text,
+    Boolean,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Column {
+    pub name: String,
+    pub ty: SqlType,
+    pub nullable: bool,
+}
+
+impl Column {
+    pub fn new(name: &str, ty: SqlType, nullable: bool) -> Self {
+        Self {
+            name: name.to_string(),
+            ty,
+            nullable,
+        }
+    }
+}
+
+impl From<Field> for Column {
+    fn from(field: Field) -> Self {
+        let ty = match field.ty {
+            FieldType::Text => SqlType::Text,
+            FieldType::Bool => SqlType::Boolean,
+        };
+        Self {
+            name: field.name,
+            ty,
+            nullable: field.nullable,
+        }
+    }
+}
+
+/// A type that can be written as a table row.
+///
+/// Implemented for every [`Record`], which covers all result types.
+pub trait ToSql {
+    fn sql_columns() -> Vec<Column>;
+    /// Values in the order of [`ToSql::sql_columns`].
+    fn sql_values(&self) -> Vec<Value>;
+}
+
+impl<T: Record> ToSql for T {
+    fn sql_columns() -> Vec<Column> {
+        T::fields().into_iter().map(Column::from).collect()
+    }
+
+    fn sql_values(&self) -> Vec<Value> {
+        self.values()
+    }
+}
+
+/// How [`Writer`] renders rows.
+#[derive(Debug, Clone)]
+pub struct Options {
+    pub dialect: Dialect,
+    /// Emit a `COPY ... FROM STDIN` block instead of `INSERT`s (PostgreSQL
+    /// only, for `psql`).
+    pub copy: bool,
+    /// Start with `CREATE TABLE IF NOT EXISTS`.
+    pub create_table: bool,
+    /// Rows per `INSERT` statement.
+    pub batch_size: usize,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            dialect: Dialect::default(),
+            copy: false,
+            create_table: false,
+            batch_size: DEFAULT_BATCH_SIZE,
+        }
+    }
+}
+
+/// Streams rows into SQL statements.
+pub struct Writer<W: Write> {
+    out: W,
+    table: String,
+    columns: Vec<Column>,
+    opts: Options,
+    batch: Vec<String>,
+    started: bool,
+}
+
+impl<W: Write> Writer<W> {
+    /// Fails if `COPY` is requested for a dialect other than PostgreSQL or
+    /// there are no columns.
+    pub fn new(out: W, table: &str, columns: Vec<Column>, opts: Options) -> Result<Self, String> {
+        if opts.copy && opts.dialect != Dialect::Postgres {
+            return Err(format!("COPY is not supported by {}", opts.dialect));
+        }
+        if columns.is_empty() {
+            return Err("SQL output needs at least one column".into());
+        }
+        Ok(Self {
+            out,
+            table: table.to_string(),
+            columns,
+            opts,
+            batch: Vec::new(),
+            started: false,
+        })
+    }
+
+    /// A writer with the columns of `T`.
+    pub fn for_rows<T: ToSql>(out: W, table: &str, opts: Options) -> Result<Self, String> {
+        Self::new(out, table, T::sql_columns(), opts)
+    }
+
+    pub fn write<T: ToSql>(&mut self, row: &T) -> io::Result<()> {
+        self.write_values(&row.sql_values())
+    }
+
+    /// Write one row of values in column order.
+    pub fn write_values(&mut self, values: &[Value]) -> io::Result<()> {
+        self.start()?;
+        if self.opts.copy {
+            let fields: Vec<String> = values.iter().map(copy_field).collect();
+            return writeln!(self.out, "{}", fields.join("\t"));
+        }
+        let literals: Vec<String> = values
+            .iter()
+            .map(|v| self.opts.dialect.literal(v))
+            .collect();
+        self.batch.push(format!("({})", literals.join(", ")));
+        if self.batch.len() >= self.opts.batch_size.max(1) {
+            self.flush_batch()?;
+        }
+        Ok(())
+    }
+
+    /// Write any pending statement and return the output.
+    pub fn finish(mut self) -> io::Result<W> {
+        self.start()?;
+        if self.opts.copy {
+            writeln!(self.out, "\\.")?;
+        } else {
+            self.flush_batch()?;
+        }
+        self.out.flush()?;
+        Ok(self.out)
+    }
+
+    fn column_list(&self) -> String {
+        let names: Vec<String> = self
+            .columns
+            .iter()
+            .map(|c| self.opts.dialect.identifier(&c.name))
+            .collect();
+        names.join(", ")
+    }
+
+    fn start(&mut self) -> io::Result<()> {
+        if self.started {
+            return Ok(());
+        }
+        self.started = true;
+        let dialect = self.opts.dialect;
+        let table = dialect.identifier(&self.table);
+        if self.opts.create_table {
+            let definitions: Vec<String> = self
+                .columns
+                .iter()
+                .map(|c| {
+                    let null = if c.nullable { "" } else { " NOT NULL" };
+                    format!(
+                        "  {} {}{}",
+                        dialect.identifier(&c.name),
+                        dialect.type_name(c.ty),
+                        null
+                    )
+                })
+                .collect();
+            writeln!(
+                self.out,
+                "CREATE TABLE IF NOT EXISTS {} (\n{}\n);",
+                table,
+                definitions.join(",\n")
+            )?;
+        }
+        if self.opts.copy {
+            writeln!(
+                self.out,
+                "COPY {} ({}) FROM STDIN;",
+                table,
+                self.column_list()
+            )?;
+        }
+        Ok(())
+    }
+
+    fn flush_batch(&mut self) -> io::Result<()> {
+        if self.batch.is_empty() {
+            return Ok(());
+        }
+        writeln!(
+            self.out,
+            "INSERT INTO {} ({}) VALUES\n{};",
+            self.opts.dialect.identifier(&self.table),
+            self.column_list(),
+            self.batch.join(",\n")
+        )?;
+        self.batch.clear();
+        Ok(())
+    }
+}
+
+/// A field in PostgreSQL's COPY text format.
+fn copy_field(value: &Value) -> String {
+    match value {
+        Value::Null => "\\N".to_string(),
+        Value::Bool(b) => if *b { "t" } else { "f" }.to_string(),
+        Value::Text(s) => s
+            .replace('\\', "\\\\")
+            .replace('\t', "\\t")
+            .replace('\n', "\\n")
+            .replace('\r', "\\r"),
+    }
+}
+
+/// Write `rows` as SQL for `table` to any [`Write`] destination.
+pub fn write_sql<W: Write, T: ToSql>(
+    writer: W,
+    table: &str,
+    rows: &[T],
+    opts: Options,
+) -> Result<(), String> {
+    let mut sql = Writer::for_rows::<T>(writer, table, opts)?;
+    for row in rows {
+        sql.write(row).map_err(|e| e.to_string())?;
+    }
+    sql.finish().map_err(|e| e.to_string())?;
+    Ok(())
+}
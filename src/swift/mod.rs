@@ -5,7 +5,7 @@ use serde::Serialize;
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "json", derive(Serialize))]
 pub struct SwiftResult {
-    pub code: String,
+    pub code: crate::Bic,
     pub bank: String,
     pub country: String,
     pub location: String,
@@ -77,7 +77,7 @@ impl Registry {
         );
 
         SwiftResult {
-            code,
+            code: crate::Bic::from_generated(code),
             bank,
             country,
             location,
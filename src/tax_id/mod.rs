@@ -2,10 +2,17 @@ use rand::Rng;
 #[cfg(feature = "json")]
 use serde::Serialize;
 
+use crate::kind::IdKind;
+use crate::locale::Locale;
+
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "json", derive(Serialize))]
 pub struct TaxIdResult {
+    /// Country as requested.
     pub country_code: String,
+    /// Country whose rules were applied; differs from `country_code` for
+    /// territories using their parent's (e.g. `PR` → `US`).
+    pub effective_country_code: String,
     pub country_name: String,
     pub name: String,
     pub code: String,
@@ -17,6 +24,9 @@ pub struct TaxIdResult {
 pub struct GenOptions {
     pub country: Option<String>,
     pub holder_type: Option<String>,
+    /// Fail for territories without their own rules instead of using the
+    /// parent country's.
+    pub strict: bool,
 }
 
 /// Valid PAN holder-type characters.
@@ -119,6 +129,10 @@ static SPECIFIC_COUNTRIES: &[(&str, &str)] = &[
     ("ZA", "Tax Number"),
 ];
 
+fn has_specific(country: &str) -> bool {
+    SPECIFIC_COUNTRIES.iter().any(|(code, _)| *code == country)
+}
+
 pub struct Registry;
 
 impl Default for Registry {
@@ -133,7 +147,7 @@ impl Registry {
     }
 
     pub fn generate(&self, opts: &GenOptions, rng: &mut impl Rng) -> Option<TaxIdResult> {
-        let country = opts
+        let requested = opts
             .country
             .as_deref()
             .unwrap_or_else(|| {
@@ -141,6 +155,8 @@ impl Registry {
                 countries[rng.gen_range(0..countries.len())].0
             })
             .to_uppercase();
+        let country =
+            crate::countries::effective_country_code(&requested, opts.strict, has_specific)?;
 
         if let Some((name, code, holder)) = match country.as_str() {
             "AE" => Some(("TRN", self.generate_ae(rng), None)),
@@ -228,9 +244,10 @@ impl Registry {
             "ZA" => Some(("Tax Number", self.generate_za(rng), None)),
             _ => None,
         } {
-            let country_name = crate::countries::get_country_name(&country).unwrap_or("Unknown");
+            let country_name = crate::countries::get_country_name(&requested).unwrap_or("Unknown");
             return Some(TaxIdResult {
-                country_code: country,
+                country_code: requested,
+                effective_country_code: country,
                 country_name: country_name.to_string(),
                 name: name.to_string(),
                 code,
@@ -242,8 +259,58 @@ impl Registry {
         None
     }
 
+    /// Country whose rules apply to `country`: the country itself, or
+    /// the parent for a territory without its own.
+    pub fn effective_country(&self, country: &str) -> Option<String> {
+        crate::countries::effective_country_code(country, false, has_specific)
+    }
+
+    /// Values `GenOptions::holder_type` can take for `country`, or `None`
+    /// when its codes have no holder type.
+    pub fn holder_types(&self, country: &str) -> Option<Vec<String>> {
+        match self.effective_country(&country.to_uppercase())?.as_str() {
+            "IN" => Some(PAN_HOLDER_TYPES.iter().map(char::to_string).collect()),
+            "RU" => Some(vec!["entity".to_string(), "individual".to_string()]),
+            _ => None,
+        }
+    }
+
+    /// Name of the check algorithm of the country's tax ID (e.g. `"Luhn"`),
+    /// or `None` if it has none.
+    pub fn checksum(&self, country: &str) -> Option<&'static str> {
+        match self.effective_country(&country.to_uppercase())?.as_str() {
+            "AR" | "AU" | "BG" | "CL" | "CO" | "GB" | "GR" | "HK" | "HU" | "IS" | "JP" | "MX"
+            | "NL" | "PE" | "PT" | "RO" | "RS" | "RU" | "SI" | "TH" | "UA" | "VN" => {
+                Some("Weighted mod 11")
+            }
+            "AT" => Some("Luhn variant"),
+            "BE" => Some("Mod 97"),
+            "BR" | "NO" => Some("Weighted mod 11, two check digits"),
+            "CA" | "IL" | "IT" | "SA" | "SE" | "ZA" => Some("Luhn"),
+            "CH" => Some("EAN-13"),
+            "CN" => Some("Weighted mod 31"),
+            "CZ" | "SK" => Some("Mod 11"),
+            "DE" | "HR" => Some("ISO 7064 MOD 11-10"),
+            "EC" => Some("Weighted mod 10 or 11, by type"),
+            "EE" | "LT" | "NZ" => Some("Weighted mod 11, two passes"),
+            "ES" => Some("Mod 23 letter"),
+            "FI" => Some("Mod 31 character"),
+            "FR" => Some("Mod 511"),
+            "IE" => Some("Weighted mod 23 letter"),
+            "KR" | "PL" | "TW" => Some("Weighted mod 10"),
+            "LU" => Some("Luhn and Verhoeff"),
+            "SG" => Some("Weighted mod 11 letter"),
+            "TR" => Some("Mod 10, two check digits"),
+            _ => None,
+        }
+    }
+
     pub fn validate(&self, country: &str, code: &str) -> bool {
-        match country.to_uppercase().as_str() {
+        let Some(country) = crate::countries::effective_country_code(country, false, has_specific)
+        else {
+            return false;
+        };
+        match country.as_str() {
             "AE" => self.validate_ae(code),
             "AR" => self.validate_ar(code),
             "AT" => self.validate_at(code),
@@ -343,6 +410,24 @@ impl Registry {
         result
     }
 
+    /// Like [`list_countries`](Self::list_countries), with country and
+    /// tax ID names in `locale`.
+    pub fn list_countries_in(
+        &self,
+        locale: Locale,
+    ) -> Vec<(&'static str, &'static str, &'static str)> {
+        self.list_countries()
+            .into_iter()
+            .map(|(code, _, name)| {
+                (
+                    code,
+                    crate::locale::country_label(code, locale),
+                    crate::locale::document_name(IdKind::TaxId, code, locale).unwrap_or(name),
+                )
+            })
+            .collect()
+    }
+
     // ── AE TRN (Tax Registration Number) ──
     // Format: 15 digits
     fn generate_ae(&self, rng: &mut impl Rng) -> String {
@@ -1253,6 +1338,7 @@ impl Registry {
             .as_deref()
             .and_then(|s| s.chars().next())
             .map(|c| c.to_ascii_uppercase())
+            .filter(|c| PAN_HOLDER_TYPES.contains(c))
             .unwrap_or_else(|| PAN_HOLDER_TYPES[rng.gen_range(0..PAN_HOLDER_TYPES.len())]);
         let c5 = (b'A' + rng.gen_range(0..26u8)) as char;
         let seq: u16 = rng.gen_range(1..=9999);
@@ -0,0 +1,433 @@
+//! Validated identifier types.
+//!
+//! Each type can only hold a value that passes its checksum, so models built
+//! from them reject bad data where it enters: [`FromStr`] and (with the
+//! `json` feature) deserialization fail on invalid input. Values are stored
+//! and displayed in their canonical form, without spaces and in uppercase;
+//! the alternate flag (`{:#}`) prints IBANs and card numbers grouped.
+//!
+//! ```rust
+//! use idsmith::{Iban, PersonalId};
+//!
+//! let iban: Iban = "de89 3704 0044 0532 0130 00".parse().unwrap();
+//! assert_eq!(iban.to_string(), "DE89370400440532013000");
+//! assert_eq!(format!("{:#}", iban), "DE89 3704 0044 0532 0130 00");
+//! assert_eq!(iban.country_code(), "DE");
+//! assert!("DE00370400440532013000".parse::<Iban>().is_err());
+//!
+//! let id: PersonalId = "EE:38001085718".parse().unwrap();
+//! assert_eq!((id.country(), id.code()), ("EE", "38001085718"));
+//! ```
+
+use std::fmt;
+use std::ops::Deref;
+use std::str::FromStr;
+
+/// Traits shared by the single-string identifier types.
+macro_rules! string_identifier {
+    ($name:ident, $what:literal) => {
+        impl $name {
+            /// Validates `value` and stores it in canonical form.
+            pub fn new(value: &str) -> Result<Self, String> {
+                let canonical = Self::canonicalize(value);
+                if Self::is_valid(&canonical) {
+                    Ok(Self(canonical))
+                } else {
+                    Err(format!(concat!("Invalid ", $what, ": {}"), value))
+                }
+            }
+
+            /// Wraps a value generated by this crate, which is already
+            /// canonical and valid.
+            pub(crate) fn from_generated(value: String) -> Self {
+                debug_assert!(Self::is_valid(&value), "{}", value);
+                Self(value)
+            }
+
+            pub fn as_str(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = String;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                Self::new(s)
+            }
+        }
+
+        impl TryFrom<String> for $name {
+            type Error = String;
+
+            fn try_from(value: String) -> Result<Self, Self::Error> {
+                Self::new(&value)
+            }
+        }
+
+        impl From<$name> for String {
+            fn from(value: $name) -> Self {
+                value.0
+            }
+        }
+
+        impl Deref for $name {
+            type Target = str;
+
+            fn deref(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl AsRef<str> for $name {
+            fn as_ref(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl PartialEq<str> for $name {
+            fn eq(&self, other: &str) -> bool {
+                self.0 == other
+            }
+        }
+
+        impl PartialEq<&str> for $name {
+            fn eq(&self, other: &&str) -> bool {
+                self.0 == *other
+            }
+        }
+
+        #[cfg(feature = "json")]
+        impl serde::Serialize for $name {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_str(&self.0)
+            }
+        }
+
+        #[cfg(feature = "json")]
+        impl<'de> serde::Deserialize<'de> for $name {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let value = String::deserialize(deserializer)?;
+                Self::new(&value).map_err(serde::de::Error::custom)
+            }
+        }
+    };
+}
+
+fn compact(value: &str) -> String {
+    value
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .collect::<String>()
+        .to_uppercase()
+}
+
+/// An International Bank Account Number that passes the mod-97 check.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Iban(String);
+
+string_identifier!(Iban, "IBAN");
+
+impl Iban {
+    fn canonicalize(value: &str) -> String {
+        compact(value)
+    }
+
+    fn is_valid(value: &str) -> bool {
+        crate::iban::validate_iban(value)
+    }
+
+    pub fn country_code(&self) -> &str {
+        &self.0[..2]
+    }
+
+    pub fn check_digits(&self) -> &str {
+        &self.0[2..4]
+    }
+
+    pub fn bban(&self) -> &str {
+        &self.0[4..]
+    }
+}
+
+impl fmt::Display for Iban {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            f.write_str(&crate::iban::format_iban(&self.0))
+        } else {
+            f.write_str(&self.0)
+        }
+    }
+}
+
+/// A SWIFT/BIC code of 8 or 11 characters.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Bic(String);
+
+string_identifier!(Bic, "BIC");
+
+impl Bic {
+    fn canonicalize(value: &str) -> String {
+        compact(value)
+    }
+
+    fn is_valid(value: &str) -> bool {
+        crate::swift_codes().validate(value)
+    }
+
+    pub fn bank_code(&self) -> &str {
+        &self.0[..4]
+    }
+
+    pub fn country_code(&self) -> &str {
+        &self.0[4..6]
+    }
+
+    pub fn location_code(&self) -> &str {
+        &self.0[6..8]
+    }
+
+    /// Branch code of an 11-character BIC.
+    pub fn branch_code(&self) -> Option<&str> {
+        self.0.get(8..).filter(|branch| !branch.is_empty())
+    }
+}
+
+impl fmt::Display for Bic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// An ISO 17442 Legal Entity Identifier.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Lei(String);
+
+string_identifier!(Lei, "LEI");
+
+impl Lei {
+    fn canonicalize(value: &str) -> String {
+        compact(value)
+    }
+
+    fn is_valid(value: &str) -> bool {
+        crate::lei_codes().validate(value)
+    }
+
+    /// Prefix of the Local Operating Unit that issued the code.
+    pub fn lou(&self) -> &str {
+        &self.0[..4]
+    }
+}
+
+impl fmt::Display for Lei {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// An EU (or UK) VAT identification number, including its country prefix.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct VatNumber(String);
+
+string_identifier!(VatNumber, "VAT number");
+
+impl VatNumber {
+    fn canonicalize(value: &str) -> String {
+        compact(value).replace(['-', '.'], "")
+    }
+
+    fn is_valid(value: &str) -> bool {
+        value.is_ascii() && crate::vat_ids().validate(value)
+    }
+
+    /// VAT prefix, `EL` for Greece.
+    pub fn country_prefix(&self) -> &str {
+        &self.0[..2]
+    }
+}
+
+impl fmt::Display for VatNumber {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// A payment card number that passes the Luhn check.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct CardNumber(String);
+
+string_identifier!(CardNumber, "card number");
+
+impl CardNumber {
+    fn canonicalize(value: &str) -> String {
+        value.replace([' ', '-'], "")
+    }
+
+    fn is_valid(value: &str) -> bool {
+        value.chars().all(|c| c.is_ascii_digit()) && crate::credit_cards().validate(value)
+    }
+}
+
+impl fmt::Display for CardNumber {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if !f.alternate() {
+            return f.write_str(&self.0);
+        }
+        // Amex and Diners group 4-6-5 and 4-6-4, everything else in fours
+        let layout = match self.0.len() {
+            15 => "amex",
+            14 => "diners",
+            _ => "visa",
+        };
+        f.write_str(&crate::credit_cards().format(layout, &self.0))
+    }
+}
+
+/// A national personal ID together with the country that issued it.
+///
+/// Parses from and displays as `COUNTRY:CODE`, e.g. `EE:38001085718`, and
+/// serializes as `{"country": "EE", "code": "38001085718"}`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct PersonalId {
+    country: String,
+    code: String,
+}
+
+impl PersonalId {
+    /// Validates `code` with `country`'s personal ID scheme.
+    pub fn new(country: &str, code: &str) -> Result<Self, String> {
+        let country = country.trim().to_uppercase();
+        let code = code.trim();
+        match crate::personal_ids().validate(&country, code) {
+            Some(true) => Ok(Self {
+                country,
+                code: code.to_string(),
+            }),
+            Some(false) => Err(format!("Invalid personal ID for {}: {}", country, code)),
+            None => Err(format!("Unsupported country: {}", country)),
+        }
+    }
+
+    pub fn country(&self) -> &str {
+        &self.country
+    }
+
+    pub fn code(&self) -> &str {
+        &self.code
+    }
+}
+
+impl fmt::Display for PersonalId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.country, self.code)
+    }
+}
+
+impl FromStr for PersonalId {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (country, code) = s
+            .split_once(':')
+            .ok_or_else(|| format!("Expected COUNTRY:CODE, got {}", s))?;
+        Self::new(country, code)
+    }
+}
+
+#[cfg(feature = "json")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct PersonalIdFields<'a> {
+    country: std::borrow::Cow<'a, str>,
+    code: std::borrow::Cow<'a, str>,
+}
+
+#[cfg(feature = "json")]
+impl serde::Serialize for PersonalId {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        PersonalIdFields {
+            country: self.country.as_str().into(),
+            code: self.code.as_str().into(),
+        }
+        .serialize(serializer)
+    }
+}
+
+#[cfg(feature = "json")]
+impl<'de> serde::Deserialize<'de> for PersonalId {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let fields = PersonalIdFields::deserialize(deserializer)?;
+        Self::new(&fields.country, &fields.code).map_err(serde::de::Error::custom)
+    }
+}
+
+/// `Arbitrary` impls (`quickcheck` feature). Values come from the regular
+/// generators and are not shrunk, since a shrunk code would fail its
+/// checksum.
+#[cfg(feature = "quickcheck")]
+mod arbitrary {
+    use quickcheck::{Arbitrary, Gen};
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    use super::{Bic, CardNumber, Iban, Lei, PersonalId, VatNumber};
+
+    fn rng(g: &mut Gen) -> StdRng {
+        StdRng::seed_from_u64(u64::arbitrary(g))
+    }
+
+    impl Arbitrary for Iban {
+        fn arbitrary(g: &mut Gen) -> Self {
+            let code = crate::iban::generate_iban(None, &mut rng(g)).expect("IBAN for any country");
+            Iban::from_generated(code)
+        }
+    }
+
+    impl Arbitrary for Bic {
+        fn arbitrary(g: &mut Gen) -> Self {
+            crate::swift_codes()
+                .generate(&Default::default(), &mut rng(g))
+                .code
+        }
+    }
+
+    impl Arbitrary for Lei {
+        fn arbitrary(g: &mut Gen) -> Self {
+            crate::lei_codes()
+                .generate(&Default::default(), &mut rng(g))
+                .code
+        }
+    }
+
+    impl Arbitrary for VatNumber {
+        fn arbitrary(g: &mut Gen) -> Self {
+            crate::vat_ids()
+                .generate(&Default::default(), &mut rng(g))
+                .expect("VAT number for any country")
+                .code
+        }
+    }
+
+    impl Arbitrary for CardNumber {
+        fn arbitrary(g: &mut Gen) -> Self {
+            crate::credit_cards()
+                .generate(&Default::default(), &mut rng(g))
+                .expect("card of any brand")
+                .number
+        }
+    }
+
+    impl Arbitrary for PersonalId {
+        fn arbitrary(g: &mut Gen) -> Self {
+            let registry = crate::personal_ids();
+            let countries = registry.list_countries();
+            loop {
+                let (country, _, _) = g.choose(&countries).expect("personal ID countries");
+                let code = registry.generate(country, &Default::default(), &mut rng(g));
+                if let Some(id) = code.and_then(|code| PersonalId::new(country, &code).ok()) {
+                    return id;
+                }
+            }
+        }
+    }
+}
@@ -1,14 +1,23 @@
+use std::sync::OnceLock;
+
 use rand::Rng;
 #[cfg(feature = "json")]
 use serde::Serialize;
 
+use crate::countries::Membership;
+use crate::locale::Locale;
+
 use crate::personal_id::checksum::{iso7064_mod11_10, luhn_check, weighted_check};
 
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "json", derive(Serialize))]
 pub struct VatResult {
-    pub code: String,
+    pub code: crate::VatNumber,
+    /// Country as requested (`EL` for Greece).
     pub country_code: String,
+    /// Country whose VAT format was applied; differs from `country_code`
+    /// for territories using their parent's (e.g. `GF` → `FR`).
+    pub effective_country_code: String,
     pub country_name: String,
     pub valid: bool,
 }
@@ -16,38 +25,41 @@ pub struct VatResult {
 #[derive(Debug, Clone, Default)]
 pub struct GenOptions {
     pub country: Option<String>,
+    /// Kept for symmetry with the other registries. Territories are outside
+    /// the EU VAT area and have no VAT numbers, so they never fall back to
+    /// the parent country's.
+    pub strict: bool,
+}
+
+/// Countries outside the EU that still issue VAT numbers in the same format.
+const NON_EU: &[&str] = &["GB"];
+
+/// VAT prefix for an ISO code: Greece uses `EL`.
+fn vat_prefix(code: &str) -> &str {
+    if code == "GR" {
+        "EL"
+    } else {
+        code
+    }
 }
 
-static COUNTRIES: &[(&str, &str)] = &[
-    ("AT", "Austria"),
-    ("BE", "Belgium"),
-    ("BG", "Bulgaria"),
-    ("CY", "Cyprus"),
-    ("CZ", "Czech Republic"),
-    ("DE", "Germany"),
-    ("DK", "Denmark"),
-    ("EE", "Estonia"),
-    ("EL", "Greece"),
-    ("ES", "Spain"),
-    ("FI", "Finland"),
-    ("FR", "France"),
-    ("GB", "United Kingdom"),
-    ("HR", "Croatia"),
-    ("HU", "Hungary"),
-    ("IE", "Ireland"),
-    ("IT", "Italy"),
-    ("LT", "Lithuania"),
-    ("LU", "Luxembourg"),
-    ("LV", "Latvia"),
-    ("MT", "Malta"),
-    ("NL", "Netherlands"),
-    ("PL", "Poland"),
-    ("PT", "Portugal"),
-    ("RO", "Romania"),
-    ("SE", "Sweden"),
-    ("SI", "Slovenia"),
-    ("SK", "Slovakia"),
-];
+/// EU member states plus [`NON_EU`], by VAT prefix.
+fn countries() -> &'static [(&'static str, &'static str)] {
+    static COUNTRIES: OnceLock<Vec<(&str, &str)>> = OnceLock::new();
+    COUNTRIES.get_or_init(|| {
+        let mut countries: Vec<_> = crate::countries::ALL_COUNTRIES
+            .iter()
+            .filter(|c| c.is_member(Membership::Eu) || NON_EU.contains(&c.code))
+            .map(|c| (vat_prefix(c.code), c.name))
+            .collect();
+        countries.sort();
+        countries
+    })
+}
+
+fn has_vat(country: &str) -> bool {
+    countries().iter().any(|(code, _)| *code == country)
+}
 
 pub struct Registry;
 
@@ -63,27 +75,46 @@ impl Registry {
     }
 
     pub fn list_countries(&self) -> &'static [(&'static str, &'static str)] {
-        COUNTRIES
+        countries()
+    }
+
+    /// Like [`list_countries`](Self::list_countries), with country names in
+    /// `locale`.
+    pub fn list_countries_in(&self, locale: Locale) -> Vec<(&'static str, &'static str)> {
+        countries()
+            .iter()
+            .map(|(code, _)| {
+                let iso = if *code == "EL" { "GR" } else { code };
+                (*code, crate::locale::country_label(iso, locale))
+            })
+            .collect()
     }
 
     pub fn generate(&self, opts: &GenOptions, rng: &mut impl Rng) -> Option<VatResult> {
-        let country = opts
-            .country
-            .as_deref()
-            .unwrap_or_else(|| COUNTRIES[rng.gen_range(0..COUNTRIES.len())].0)
-            .to_uppercase();
+        let country = match opts.country.as_deref() {
+            Some(c) => match c.parse::<Membership>() {
+                // A group such as "eurozone" picks one of its members
+                Ok(group) => {
+                    crate::countries::random_member(group, rng, |c| has_vat(vat_prefix(c)))?
+                        .to_string()
+                }
+                Err(_) => c.to_uppercase(),
+            },
+            None => {
+                let countries = countries();
+                countries[rng.gen_range(0..countries.len())].0.to_string()
+            }
+        };
 
         // Accept GR as alias for EL
-        let country = if country == "GR" {
-            "EL".to_string()
-        } else {
-            country
-        };
+        let requested = vat_prefix(&country).to_string();
+        let country = has_vat(&requested).then(|| requested.clone())?;
 
-        let country_name = COUNTRIES
+        let country_name = countries()
             .iter()
-            .find(|(c, _)| *c == country)
-            .map(|(_, n)| *n)?;
+            .find(|(c, _)| *c == requested)
+            .map(|(_, n)| *n)
+            .or_else(|| crate::countries::get_country_name(&requested))?;
 
         let digits = match country.as_str() {
             "AT" => self.generate_at(rng),
@@ -119,13 +150,48 @@ impl Registry {
 
         let code = format!("{}{}", country, digits);
         Some(VatResult {
-            code,
-            country_code: country,
+            code: crate::VatNumber::from_generated(code),
+            country_code: requested,
+            effective_country_code: country,
             country_name: country_name.to_string(),
             valid: true,
         })
     }
 
+    /// Country whose VAT format applies to `country`: the country itself
+    /// (`EL` for `GR`). Territories outside the EU VAT area, such as the
+    /// Faroe Islands or Greenland, get `None` rather than the parent's.
+    pub fn effective_country(&self, country: &str) -> Option<String> {
+        let country = country.to_uppercase();
+        let prefix = vat_prefix(&country);
+        has_vat(prefix).then(|| prefix.to_string())
+    }
+
+    /// Name of the check algorithm of the country's VAT number (e.g.
+    /// `"Luhn"`), or `None` if it has none.
+    pub fn checksum(&self, country: &str) -> Option<&'static str> {
+        match self.effective_country(country)?.as_str() {
+            "AT" => Some("Luhn variant"),
+            "BE" | "FR" => Some("Mod 97"),
+            "BG" | "CZ" | "DK" | "EL" | "FI" | "LV" | "PL" | "PT" | "RO" | "SI" => {
+                Some("Weighted mod 11")
+            }
+            "CY" => Some("Mod 26 letter"),
+            "DE" | "HR" => Some("ISO 7064 MOD 11-10"),
+            "EE" | "HU" => Some("Weighted mod 10"),
+            "ES" => Some("Mod 23 letter or Luhn"),
+            "GB" => Some("Weighted mod 97"),
+            "IE" => Some("Weighted mod 23 letter"),
+            "IT" | "SE" => Some("Luhn"),
+            "LT" => Some("Weighted mod 11, two passes"),
+            "LU" => Some("Mod 89"),
+            "MT" => Some("Weighted mod 37"),
+            "NL" => Some("ISO 7064 MOD 97-10"),
+            "SK" => Some("Mod 11"),
+            _ => None,
+        }
+    }
+
     pub fn validate(&self, code: &str) -> bool {
         let code = code.replace([' ', '-', '.'], "");
         if code.len() < 4 {
@@ -142,9 +208,9 @@ impl Registry {
         };
 
         // Accept GR as alias for EL
-        let prefix = if prefix == "GR" { "EL" } else { prefix };
+        let prefix = vat_prefix(prefix);
 
-        if !COUNTRIES.iter().any(|(c, _)| *c == prefix) {
+        if !has_vat(prefix) {
             return false;
         }
 
@@ -0,0 +1,156 @@
+//! Weighted choices for bulk generation, so that generated data can mirror a
+//! real mix of countries, genders, card brands or PAN holder types.
+//!
+//! Specs are comma-separated `VALUE:WEIGHT` pairs such as
+//! `DE:50,FR:30,NL:20`. Weights are relative and need not add up to 100; a
+//! value without one gets weight 1. Country specs also accept groups
+//! (`@sepa`, `@eu`, `@eea`, `@eurozone`), whose weight is split evenly
+//! among the members with rules for the kind.
+//!
+//! ```rust
+//! use idsmith::kind::IdKind;
+//! use idsmith::weights::{countries, Weighted};
+//!
+//! let mut rng = rand::thread_rng();
+//! let mix = countries("DE:50,FR:30,@eurozone:20", IdKind::Iban).unwrap();
+//! let country = mix.pick(&mut rng);
+//! let iban = idsmith::iban::generate_iban(Some(country), &mut rng).unwrap();
+//! assert!(iban.starts_with(country.as_str()));
+//!
+//! let brands: Weighted<String> = "visa:60,mastercard:40".parse().unwrap();
+//! assert_eq!(brands.entries().len(), 2);
+//! ```
+
+use std::fmt;
+use std::str::FromStr;
+
+use rand::distributions::{Distribution, WeightedIndex};
+use rand::Rng;
+
+use crate::countries::{members, Membership};
+use crate::kind::IdKind;
+
+/// Values with relative weights.
+#[derive(Debug, Clone)]
+pub struct Weighted<T> {
+    entries: Vec<(T, f64)>,
+    index: WeightedIndex<f64>,
+}
+
+impl<T> Weighted<T> {
+    /// Fails if there are no entries, a weight is negative or not finite,
+    /// or all weights are zero.
+    pub fn new(entries: Vec<(T, f64)>) -> Result<Self, String> {
+        if entries.is_empty() {
+            return Err("No values to choose from".into());
+        }
+        if let Some((_, w)) = entries.iter().find(|(_, w)| !w.is_finite() || *w < 0.0) {
+            return Err(format!("Invalid weight: {}", w));
+        }
+        let index = WeightedIndex::new(entries.iter().map(|(_, w)| *w))
+            .map_err(|_| "Weights must not all be zero".to_string())?;
+        Ok(Self { entries, index })
+    }
+
+    /// Every value with the same weight.
+    pub fn uniform(values: impl IntoIterator<Item = T>) -> Result<Self, String> {
+        Self::new(values.into_iter().map(|v| (v, 1.0)).collect())
+    }
+
+    /// Parse a `VALUE:WEIGHT,...` spec, turning each value into a `T` with
+    /// `parse`.
+    pub fn parse_with(
+        spec: &str,
+        mut parse: impl FnMut(&str) -> Result<T, String>,
+    ) -> Result<Self, String> {
+        let entries = split(spec)?
+            .into_iter()
+            .map(|(value, weight)| Ok((parse(value)?, weight)))
+            .collect::<Result<_, String>>()?;
+        Self::new(entries)
+    }
+
+    /// A value at random, in proportion to its weight.
+    pub fn pick(&self, rng: &mut impl Rng) -> &T {
+        &self.entries[self.index.sample(rng)].0
+    }
+
+    pub fn entries(&self) -> &[(T, f64)] {
+        &self.entries
+    }
+}
+
+impl<T> FromStr for Weighted<T>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_with(s, |value| value.parse().map_err(|e: T::Err| e.to_string()))
+    }
+}
+
+/// Split a spec into values and weights.
+fn split(spec: &str) -> Result<Vec<(&str, f64)>, String> {
+    spec.split(',')
+        .map(str::trim)
+        .filter(|part| !part.is_empty())
+        .map(|part| match part.rsplit_once(':') {
+            Some((value, weight)) => weight
+                .trim()
+                .parse()
+                .map(|w| (value.trim(), w))
+                .map_err(|_| format!("Invalid weight in {}", part)),
+            None => Ok((part, 1.0)),
+        })
+        .collect()
+}
+
+/// Whether `kind` can be generated for `country`.
+fn has_rules(kind: IdKind, country: &str) -> bool {
+    match kind {
+        IdKind::Iban => crate::iban::supported_countries().contains(&country),
+        IdKind::Swift | IdKind::Lei => crate::countries::get(country).is_some(),
+        IdKind::CreditCard => false,
+        _ => kind.effective_country(country).is_some(),
+    }
+}
+
+/// Parse a country spec such as `DE:50,FR:30,@sepa:20` for generating
+/// `kind`. Countries given more than once, directly or through groups, have
+/// their weights added up.
+pub fn countries(spec: &str, kind: IdKind) -> Result<Weighted<String>, String> {
+    let mut entries: Vec<(String, f64)> = Vec::new();
+    let mut add = |country: &str, weight: f64| match entries.iter_mut().find(|(c, _)| c == country)
+    {
+        Some((_, w)) => *w += weight,
+        None => entries.push((country.to_string(), weight)),
+    };
+    for (value, weight) in split(spec)? {
+        match value.strip_prefix('@') {
+            Some(group) => {
+                let group: Membership = group.parse()?;
+                let codes: Vec<&str> = members(group)
+                    .map(|c| c.code)
+                    .filter(|c| has_rules(kind, c))
+                    .collect();
+                if codes.is_empty() {
+                    return Err(format!("No {} countries in {}", kind, group));
+                }
+                for code in &codes {
+                    add(code, weight / codes.len() as f64);
+                }
+            }
+            None => {
+                let country = value.to_uppercase();
+                if !has_rules(kind, &country) {
+                    return Err(format!("Unsupported country for {}: {}", kind, country));
+                }
+                add(&country, weight);
+            }
+        }
+    }
+    Weighted::new(entries)
+}
@@ -0,0 +1,156 @@
+use idsmith::anonymize::Anonymizer;
+use idsmith::kind::Target;
+
+#[test]
+fn test_pseudonymize_is_consistent() {
+    let anon = Anonymizer::new(b"key");
+    let target: Target = "id:US".parse().unwrap();
+    let a = anon.pseudonymize(&target, "446-72-2445").unwrap();
+    let b = anon.pseudonymize(&target, "446 72 2445").unwrap();
+    assert_eq!(a, b);
+    assert_eq!(idsmith::personal_ids().validate("US", &a), Some(true));
+}
+
+#[test]
+fn test_pseudonymize_depends_on_key_and_input() {
+    let target: Target = "iban".parse().unwrap();
+    let a = Anonymizer::new(b"key one");
+    let b = Anonymizer::new(b"key two");
+    let x = a.pseudonymize(&target, "DE89370400440532013000").unwrap();
+    let y = b.pseudonymize(&target, "DE89370400440532013000").unwrap();
+    let z = a.pseudonymize(&target, "GB29NWBK60161331926819").unwrap();
+    assert_ne!(x, y);
+    assert_ne!(x, "DE89370400440532013000");
+    assert!(z.starts_with("GB"));
+}
+
+#[test]
+fn test_pseudonymize_all_kinds_valid() {
+    let anon = Anonymizer::new(b"key");
+    for spec in [
+        "iban",
+        "iban:NL",
+        "account:US",
+        "id:SE",
+        "card",
+        "swift",
+        "company:GB",
+        "license:US",
+        "tax:IN",
+        "passport:DE",
+        "lei",
+        "vat",
+        "vat:FR",
+    ] {
+        let target: Target = spec.parse().unwrap();
+        for input in ["A1", "B2", "C3"] {
+            let fake = anon.pseudonymize(&target, input).unwrap();
+            assert_eq!(target.validate(&fake), Some(true), "{}: {}", spec, fake);
+        }
+    }
+}
+
+#[test]
+fn test_pseudonymize_blank_is_none() {
+    let anon = Anonymizer::new(b"key");
+    let target: Target = "card".parse().unwrap();
+    assert!(anon.pseudonymize(&target, "").is_none());
+    assert!(anon.pseudonymize(&target, " - ").is_none());
+}
+
+#[cfg(feature = "csv")]
+#[test]
+fn test_anonymize_csv_columns_and_detect() {
+    let input = "name,iban,ssn,note\n\
+                 Ada,DE89370400440532013000,446-72-2445,\"a, \"\"b\"\"\"\n\
+                 Bob,GB29NWBK60161331926819,,x\n\
+                 Ada,DE89 3704 0044 0532 0130 00,446-72-2445,y\n";
+    let anon = Anonymizer::new(b"key");
+    let columns = vec![("ssn".to_string(), "id:US".parse().unwrap())];
+    let mut out = Vec::new();
+    let summary =
+        idsmith::anonymize::anonymize_csv(input.as_bytes(), &mut out, &anon, &columns, 100)
+            .unwrap();
+    assert_eq!(summary.rows, 3);
+    assert_eq!(summary.blanked, 0);
+    assert_eq!(summary.columns.len(), 2);
+    assert_eq!(summary.columns[1].0, "iban");
+
+    let records: Vec<Vec<String>> = idsmith::csv::Reader::new(out.as_slice())
+        .map(|r| r.unwrap())
+        .collect();
+    assert_eq!(records[0], ["name", "iban", "ssn", "note"]);
+    assert_eq!(records[1][0], "Ada");
+    assert_eq!(records[1][3], "a, \"b\"");
+    assert_eq!(records[2][2], "");
+    // Same input in different spacing maps to the same fake
+    assert_eq!(records[1][1], records[3][1]);
+    assert_eq!(records[1][2], records[3][2]);
+    assert_ne!(records[1][1], "DE89370400440532013000");
+    assert!(idsmith::iban::validate_iban(&records[2][1]));
+}
+
+#[cfg(feature = "csv")]
+#[test]
+fn test_anonymize_csv_unknown_column() {
+    let anon = Anonymizer::new(b"key");
+    let columns = vec![("missing".to_string(), "iban".parse().unwrap())];
+    let mut out = Vec::new();
+    let err =
+        idsmith::anonymize::anonymize_csv("a,b\n1,2\n".as_bytes(), &mut out, &anon, &columns, 0);
+    assert!(err.is_err());
+}
+
+#[cfg(feature = "csv")]
+#[test]
+fn test_anonymize_csv_rejects_unsupported_targets() {
+    let anon = Anonymizer::new(b"key");
+    let input = "ssn,acct\n446-72-2445,DE89370400440532013000\n";
+    for (column, target) in [
+        (
+            "ssn",
+            Target::new(idsmith::kind::IdKind::PersonalId, Some("ZZ")),
+        ),
+        (
+            "acct",
+            Target::new(idsmith::kind::IdKind::BankAccount, Some("ZZ")),
+        ),
+        ("ssn", Target::new(idsmith::kind::IdKind::PersonalId, None)),
+    ] {
+        let columns = vec![(column.to_string(), target.clone())];
+        let mut out = Vec::new();
+        let err = idsmith::anonymize::anonymize_csv(input.as_bytes(), &mut out, &anon, &columns, 0)
+            .unwrap_err();
+        assert!(err.to_string().starts_with("Column "), "{}", err);
+        assert!(out.is_empty(), "{}", target);
+    }
+}
+
+#[cfg(feature = "csv")]
+#[test]
+fn test_anonymize_csv_never_passes_values_through() {
+    let anon = Anonymizer::new(b"key");
+    // An unknown country prefix still gets a fake from a random country
+    let input = "iban\nDE89370400440532013000\nXX00SECRET\n\n";
+    let columns = vec![("iban".to_string(), "iban".parse().unwrap())];
+    let mut out = Vec::new();
+    let summary =
+        idsmith::anonymize::anonymize_csv(input.as_bytes(), &mut out, &anon, &columns, 0).unwrap();
+    assert_eq!((summary.rows, summary.blanked), (3, 0));
+    let out = String::from_utf8(out).unwrap();
+    assert!(!out.contains("SECRET"), "{}", out);
+    assert!(!out.contains("DE89370400440532013000"), "{}", out);
+}
+
+#[cfg(feature = "csv")]
+#[test]
+fn test_csv_reader_multiline_and_crlf() {
+    let data = "a,b\r\n\"line1\nline2\",2\r\nlast,\n";
+    let records: Vec<Vec<String>> = idsmith::csv::Reader::new(data.as_bytes())
+        .map(|r| r.unwrap())
+        .collect();
+    assert_eq!(records.len(), 3);
+    assert_eq!(records[0], ["a", "b"]);
+    assert_eq!(records[1], ["line1\nline2", "2"]);
+    assert_eq!(records[2], ["last", ""]);
+}
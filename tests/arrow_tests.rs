@@ -0,0 +1,126 @@
+#![cfg(feature = "arrow")]
+
+use std::io::Cursor;
+
+use arrow_array::{Array, BooleanArray, StringArray};
+use arrow_schema::DataType;
+#[cfg(feature = "parquet")]
+use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+use rand::thread_rng;
+
+use idsmith::arrow::{self, BatchBuilder, FileFormat, Writer};
+use idsmith::output::{Field, FieldType, Value};
+use idsmith::personal_id::IdResult;
+
+fn personal_ids(count: usize) -> Vec<IdResult> {
+    let mut rng = thread_rng();
+    let registry = idsmith::personal_ids();
+    (0..count)
+        .map(|_| {
+            let code = registry
+                .generate("SE", &Default::default(), &mut rng)
+                .unwrap();
+            registry.parse("SE", &code).unwrap()
+        })
+        .collect()
+}
+
+#[test]
+fn test_schema() {
+    let schema = arrow::schema_for::<IdResult>();
+    let names: Vec<&str> = schema.fields().iter().map(|f| f.name().as_str()).collect();
+    assert_eq!(
+        names,
+        [
+            "country_code",
+            "effective_country_code",
+            "code",
+            "gender",
+            "dob",
+            "region",
+            "valid"
+        ]
+    );
+    assert_eq!(schema.field(6).data_type(), &DataType::Boolean);
+    assert!(!schema.field(2).is_nullable());
+    assert!(schema.field(5).is_nullable());
+}
+
+#[test]
+fn test_record_batch() {
+    let ids = personal_ids(5);
+    let batch = arrow::record_batch(&ids).unwrap();
+    assert_eq!(batch.num_rows(), 5);
+
+    let codes = batch
+        .column(2)
+        .as_any()
+        .downcast_ref::<StringArray>()
+        .unwrap();
+    assert_eq!(codes.value(3), ids[3].code);
+    assert_eq!(batch.column(5).null_count(), 5);
+    let valid = batch
+        .column(6)
+        .as_any()
+        .downcast_ref::<BooleanArray>()
+        .unwrap();
+    assert!(valid.iter().all(|v| v == Some(true)));
+}
+
+#[test]
+fn test_batch_builder_rejects_null_in_required_field() {
+    let fields = [Field::new("code", FieldType::Text, false)];
+    let mut builder = BatchBuilder::new(&fields);
+    builder.append_values(&[Value::Null]);
+    assert!(builder.finish().is_err());
+
+    builder.append_values(&[Value::Text("x".into())]);
+    assert_eq!(builder.finish().unwrap().num_rows(), 1);
+}
+
+#[test]
+fn test_ipc_round_trip() {
+    let ids = personal_ids(3);
+    let mut writer = Writer::for_rows::<IdResult>(Vec::new(), FileFormat::Ipc).unwrap();
+    for id in &ids {
+        writer.write(id).unwrap();
+    }
+    let bytes = writer.finish().unwrap();
+
+    let reader = arrow_ipc::reader::FileReader::try_new(Cursor::new(bytes), None).unwrap();
+    assert_eq!(reader.schema(), arrow::schema_for::<IdResult>());
+    let rows: usize = reader.map(|b| b.unwrap().num_rows()).sum();
+    assert_eq!(rows, 3);
+}
+
+#[test]
+#[cfg(feature = "parquet")]
+fn test_parquet_round_trip() {
+    let ids = personal_ids(idsmith::arrow::DEFAULT_BATCH_SIZE + 10);
+    let mut writer = Writer::for_rows::<IdResult>(Vec::new(), FileFormat::Parquet).unwrap();
+    for id in &ids {
+        writer.write(id).unwrap();
+    }
+    let bytes = writer.finish().unwrap();
+
+    let path = std::env::temp_dir().join(format!("idsmith-{}.parquet", std::process::id()));
+    std::fs::write(&path, bytes).unwrap();
+    let reader = ParquetRecordBatchReaderBuilder::try_new(std::fs::File::open(&path).unwrap())
+        .unwrap()
+        .build()
+        .unwrap();
+    let batches: Vec<_> = reader.map(|b| b.unwrap()).collect();
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(
+        batches.iter().map(|b| b.num_rows()).sum::<usize>(),
+        ids.len()
+    );
+    let last = batches.last().unwrap();
+    let codes = last
+        .column(2)
+        .as_any()
+        .downcast_ref::<StringArray>()
+        .unwrap();
+    assert_eq!(codes.value(last.num_rows() - 1), ids.last().unwrap().code);
+}
@@ -194,7 +194,24 @@ fn test_territory_aliases() {
     // BL (Saint Barthélemy) uses French IBAN
     let result = registry.generate("BL", &opts, &mut rng).unwrap();
     assert_eq!(result.country_code, "BL");
+    assert_eq!(result.effective_country_code, "FR");
     assert!(result.iban.is_some(), "BL should have IBAN via FR");
+    // Western Sahara uses Moroccan accounts
+    let result = registry.generate("EH", &opts, &mut rng).unwrap();
+    assert_eq!(result.effective_country_code, "MA");
+}
+
+#[test]
+fn test_strict_disables_fallback() {
+    let registry = Registry::new();
+    let mut rng = thread_rng();
+    let opts = GenOptions {
+        strict: true,
+        ..Default::default()
+    };
+    assert!(registry.generate("PR", &opts, &mut rng).is_none());
+    assert!(registry.generate("US", &opts, &mut rng).is_some());
+    assert_eq!(registry.effective_country("PR").as_deref(), Some("US"));
 }
 
 #[test]
@@ -0,0 +1,146 @@
+use idsmith::batch::{validate, validate_batch, Failure, Item, Outcome, Summary};
+use idsmith::kind::{IdKind, Target};
+use rand::thread_rng;
+
+fn reason(kind: IdKind, country: Option<&str>, code: &str) -> Option<Failure> {
+    validate(&Target::new(kind, country), code).reason
+}
+
+#[test]
+fn test_valid_codes_are_normalized() {
+    let outcome = validate(
+        &Target::new(IdKind::Iban, None),
+        "GB82 WEST 1234 5698 7654 32",
+    );
+    assert!(outcome.valid);
+    assert_eq!(outcome.reason, None);
+    assert_eq!(outcome.normalized, "GB82WEST12345698765432");
+    assert_eq!(outcome.code, "GB82 WEST 1234 5698 7654 32");
+}
+
+#[test]
+fn test_only_codes_valid_as_written_pass() {
+    let cases = [
+        (IdKind::Iban, None, "gb82 west 1234 5698 7654 32"),
+        (IdKind::PersonalId, Some("EE"), "390 0101 0000"),
+        (IdKind::Vat, None, "atu13585627"),
+    ];
+    for (kind, country, code) in cases {
+        let target = Target::new(kind, country);
+        let outcome = validate(&target, code);
+        assert_eq!(target.validate(code), Some(false), "{code}");
+        assert!(!outcome.valid, "{code}");
+        assert_eq!(outcome.reason, Some(Failure::Formatting), "{code}");
+        assert_eq!(target.validate(&outcome.normalized), Some(true), "{code}");
+    }
+}
+
+#[test]
+fn test_failure_reasons() {
+    assert_eq!(reason(IdKind::Iban, None, "  "), Some(Failure::Empty));
+    assert_eq!(
+        reason(IdKind::PersonalId, None, "39001010000"),
+        Some(Failure::CountryRequired)
+    );
+    assert_eq!(
+        reason(IdKind::PersonalId, Some("XX"), "39001010000"),
+        Some(Failure::UnsupportedCountry)
+    );
+    assert_eq!(
+        reason(IdKind::Iban, None, "DE89370400440532013000é"),
+        Some(Failure::Characters)
+    );
+    assert_eq!(
+        reason(IdKind::Iban, None, "DE89 3704 0044 0532 0130"),
+        Some(Failure::Length)
+    );
+    assert_eq!(
+        reason(IdKind::Iban, None, "DE89370400440532013001"),
+        Some(Failure::Checksum)
+    );
+    assert_eq!(
+        reason(IdKind::PersonalId, Some("EE"), "3900101000A"),
+        Some(Failure::Format)
+    );
+    assert_eq!(
+        reason(IdKind::CreditCard, None, "4111111111111112"),
+        Some(Failure::Checksum)
+    );
+    // Any length the validator accepts is not a length failure
+    assert_eq!(
+        reason(IdKind::CreditCard, None, "4222222222223"),
+        Some(Failure::Checksum)
+    );
+    assert_eq!(
+        reason(IdKind::CreditCard, None, "4000000000000000007"),
+        Some(Failure::Checksum)
+    );
+    assert_eq!(
+        reason(IdKind::CreditCard, None, "40000000000000000000"),
+        Some(Failure::Length)
+    );
+    assert_eq!(
+        reason(IdKind::Swift, None, "DEUTDEFF5"),
+        Some(Failure::Length)
+    );
+}
+
+#[test]
+fn test_batch_keeps_order() {
+    let mut rng = thread_rng();
+    let items: Vec<Item> = (0..2000)
+        .map(|i| {
+            let code = IdKind::PersonalId.generate(Some("SE"), &mut rng).unwrap();
+            // Break every third code
+            let code = if i % 3 == 0 {
+                format!("{}9", code)
+            } else {
+                code
+            };
+            Item::new(IdKind::PersonalId, Some("se"), &code)
+        })
+        .collect();
+    let outcomes = validate_batch(&items);
+    assert_eq!(outcomes.len(), items.len());
+    for (i, (item, outcome)) in items.iter().zip(&outcomes).enumerate() {
+        assert_eq!(outcome.code, item.code);
+        assert_eq!(outcome.country.as_deref(), Some("SE"));
+        assert_eq!(outcome.valid, i % 3 != 0, "{}", item.code);
+    }
+}
+
+#[test]
+fn test_summary() {
+    let items = [
+        Item::new(IdKind::Vat, None, "DE123456789"),
+        Item::new(IdKind::Swift, None, "DEUTDEFF"),
+        Item::new(IdKind::Swift, None, ""),
+        Item::new(IdKind::Swift, None, "DEUT"),
+    ];
+    let outcomes: Vec<Outcome> = validate_batch(&items);
+    let summary = Summary::new(&outcomes);
+    assert_eq!(summary.total, 4);
+    assert_eq!(summary.valid + summary.invalid, 4);
+    assert_eq!(summary.reasons[&Failure::Empty], 1);
+    assert!(summary
+        .to_string()
+        .starts_with(&format!("4 checked, {} valid", summary.valid)));
+}
+
+#[cfg(feature = "output")]
+#[test]
+fn test_outcome_record() {
+    use idsmith::output::{Record, Value};
+
+    let names: Vec<String> = Outcome::fields().into_iter().map(|f| f.name).collect();
+    assert_eq!(
+        names,
+        ["kind", "country", "code", "normalized", "valid", "reason"]
+    );
+    let outcome = validate(&Target::new(IdKind::Iban, None), "DE00");
+    let values = outcome.values();
+    assert_eq!(values[0], Value::Text("iban".into()));
+    assert_eq!(values[1], Value::Null);
+    assert_eq!(values[4], Value::Bool(false));
+    assert_eq!(values[5], Value::Text("length".into()));
+}
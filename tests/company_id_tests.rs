@@ -24,6 +24,7 @@ fn test_specific_countries() {
     for country in countries {
         let opts = GenOptions {
             country: Some(country.to_string()),
+            ..Default::default()
         };
         let result = registry.generate(&opts, &mut rng).expect(country);
         assert_eq!(result.country_code, country);
@@ -39,6 +40,7 @@ fn test_generic_and_fallback_countries() {
     // Generic country (defined in generic.rs but no specific impl)
     let opts_us = GenOptions {
         country: Some("US".to_string()),
+        ..Default::default()
     };
     let res_us = registry.generate(&opts_us, &mut rng).unwrap();
     assert_eq!(res_us.country_code, "US");
@@ -47,6 +49,7 @@ fn test_generic_and_fallback_countries() {
     // Unknown country (not in any list) — should return None
     let opts_zz = GenOptions {
         country: Some("ZZ".to_string()),
+        ..Default::default()
     };
     assert!(registry.generate(&opts_zz, &mut rng).is_none());
 }
@@ -0,0 +1,117 @@
+use rand::thread_rng;
+
+use idsmith::countries::{self, subdivisions, Membership};
+use idsmith::iban;
+
+#[test]
+fn test_metadata() {
+    let de = countries::get("de").unwrap();
+    assert_eq!(de.alpha3, "DEU");
+    assert_eq!(de.numeric, Some(276));
+    assert_eq!(de.currency, Some("EUR"));
+    assert_eq!(de.calling_code, "+49");
+    assert!(de.is_member(Membership::Eu));
+    assert!(de.is_member(Membership::Eurozone));
+
+    let ch = countries::get("CH").unwrap();
+    assert_eq!(ch.currency, Some("CHF"));
+    assert!(ch.is_member(Membership::Sepa));
+    assert!(!ch.is_member(Membership::Eea));
+
+    // Territories that used to be aliases only have full entries too
+    assert_eq!(countries::get("PR").unwrap().alpha3, "PRI");
+}
+
+#[test]
+fn test_codes_are_unique() {
+    let mut alpha3 = std::collections::HashSet::new();
+    let mut numeric = std::collections::HashSet::new();
+    for c in countries::ALL_COUNTRIES {
+        assert_eq!(c.code.len(), 2, "{}", c.code);
+        assert_eq!(c.alpha3.len(), 3, "{}", c.code);
+        assert!(c.calling_code.starts_with('+'), "{}", c.code);
+        assert!(alpha3.insert(c.alpha3), "duplicate alpha-3 {}", c.alpha3);
+        if let Some(n) = c.numeric {
+            assert!(numeric.insert(n), "duplicate numeric {}", n);
+        }
+    }
+    for a in countries::TERRITORY_ALIASES {
+        assert!(countries::get(a.code).is_some(), "{} missing", a.code);
+    }
+}
+
+#[test]
+fn test_lookup_by_alpha3_and_name() {
+    assert_eq!(countries::by_alpha3("est").unwrap().code, "EE");
+    assert_eq!(countries::by_alpha3("XKX").unwrap().code, "XK");
+    assert!(countries::by_alpha3("ZZZ").is_none());
+    assert_eq!(countries::by_name("Estonia").unwrap().code, "EE");
+    assert_eq!(countries::by_name(" united states ").unwrap().code, "US");
+    assert!(countries::by_name("Atlantis").is_none());
+}
+
+#[test]
+fn test_memberships() {
+    let eu: Vec<_> = countries::members(Membership::Eu).collect();
+    assert_eq!(eu.len(), 27);
+    for c in &eu {
+        assert!(c.is_member(Membership::Eea), "{}", c.code);
+        assert!(c.is_member(Membership::Sepa), "{}", c.code);
+    }
+    assert_eq!(countries::members(Membership::Eea).count(), 30);
+    for c in countries::members(Membership::Eurozone) {
+        assert!(c.is_member(Membership::Eu), "{}", c.code);
+        assert_eq!(c.currency, Some("EUR"), "{}", c.code);
+    }
+    assert!(countries::members(Membership::Sepa).any(|c| c.code == "GB"));
+}
+
+#[test]
+fn test_parse_membership() {
+    assert_eq!("SEPA".parse::<Membership>(), Ok(Membership::Sepa));
+    assert_eq!("euro".parse::<Membership>(), Ok(Membership::Eurozone));
+    assert!("nato".parse::<Membership>().is_err());
+    assert_eq!(Membership::Eea.to_string(), "eea");
+}
+
+#[test]
+fn test_iban_country_group() {
+    let mut rng = thread_rng();
+    for _ in 0..50 {
+        let code = iban::generate_iban(Some("sepa"), &mut rng).unwrap();
+        assert!(iban::validate_iban(&code));
+        let country = countries::get(&code[..2]).unwrap();
+        assert!(country.is_member(Membership::Sepa), "{}", code);
+    }
+}
+
+#[test]
+fn test_subdivisions() {
+    let ca = subdivisions::lookup("US", "california").unwrap();
+    assert_eq!(ca.code, "US-CA");
+    assert_eq!(ca.country(), "US");
+    assert_eq!(ca.suffix(), "CA");
+    assert_eq!(subdivisions::lookup("us", "us-ca"), Some(ca));
+    assert_eq!(subdivisions::lookup("US", "CA"), Some(ca));
+    assert!(subdivisions::lookup("US", "XX").is_none());
+    assert!(subdivisions::lookup("GB", "ENG").is_none());
+
+    assert_eq!(subdivisions::of("US").len(), 51);
+    assert_eq!(subdivisions::of("CA").len(), 13);
+    assert_eq!(subdivisions::of("IN").len(), 36);
+    assert_eq!(subdivisions::of("MX").len(), 32);
+    assert_eq!(subdivisions::of("JP").len(), 47);
+    assert!(subdivisions::of("DE").is_empty());
+
+    for country in ["AU", "CA", "CN", "IN", "JP", "MX", "US"] {
+        let mut codes = std::collections::HashSet::new();
+        for s in subdivisions::of(country) {
+            assert_eq!(s.country(), country);
+            assert!(codes.insert(s.code), "duplicate {}", s.code);
+        }
+    }
+
+    let mut rng = thread_rng();
+    let random = subdivisions::random("AU", &mut rng).unwrap();
+    assert_eq!(random.country(), "AU");
+}
@@ -0,0 +1,91 @@
+use std::sync::OnceLock;
+
+use idsmith::coverage::{coverage, support, CountryCoverage, Fidelity, COUNTRY_KINDS};
+use idsmith::kind::IdKind;
+
+/// The full matrix takes a few seconds in debug builds; compute it once.
+fn rows() -> &'static [CountryCoverage] {
+    static ROWS: OnceLock<Vec<CountryCoverage>> = OnceLock::new();
+    ROWS.get_or_init(coverage)
+}
+
+#[test]
+fn test_coverage_lists_every_country() {
+    let rows = rows();
+    let mut codes = idsmith::countries::all_codes();
+    codes.dedup();
+    assert_eq!(rows.len(), codes.len());
+    assert!(rows.windows(2).all(|w| w[0].code < w[1].code));
+    // Company IDs are modelled for every country
+    assert!(rows.iter().all(|r| r.get(IdKind::CompanyId).is_some()));
+}
+
+#[test]
+fn test_coverage_kinds_in_order() {
+    for row in rows() {
+        let order: Vec<usize> = row
+            .kinds
+            .iter()
+            .map(|s| COUNTRY_KINDS.iter().position(|&k| k == s.kind).unwrap())
+            .collect();
+        assert!(order.windows(2).all(|w| w[0] < w[1]), "{}", row.code);
+    }
+}
+
+#[test]
+fn test_coverage_matches_registries() {
+    let rows = rows();
+    let vat = rows
+        .iter()
+        .filter(|r| {
+            r.get(IdKind::Vat)
+                .is_some_and(|s| s.fidelity != Fidelity::Alias)
+        })
+        .count();
+    assert_eq!(vat, idsmith::vat_ids().list_countries().len());
+    for row in rows {
+        assert_eq!(
+            row.get(IdKind::Iban).is_some(),
+            idsmith::iban::supported_countries().contains(&row.code),
+            "{}",
+            row.code
+        );
+    }
+}
+
+#[test]
+fn test_support_fidelity() {
+    let cases = [
+        (IdKind::PersonalId, "EE", Fidelity::Checksum),
+        (IdKind::PersonalId, "US", Fidelity::FormatOnly),
+        (IdKind::Iban, "DE", Fidelity::Checksum),
+        (IdKind::Vat, "GR", Fidelity::Checksum),
+        (IdKind::Passport, "DE", Fidelity::FormatOnly),
+    ];
+    for (kind, country, fidelity) in cases {
+        let s = support(kind, country).unwrap();
+        assert_eq!(s.fidelity, fidelity, "{kind} {country}");
+        assert_eq!(s.parent, None);
+    }
+}
+
+#[test]
+fn test_support_alias() {
+    let s = support(IdKind::PersonalId, "pr").unwrap();
+    assert_eq!(s.fidelity, Fidelity::Alias);
+    assert_eq!(s.parent, Some("US"));
+
+    let s = support(IdKind::BankAccount, "GU").unwrap();
+    assert_eq!(s.fidelity, Fidelity::Alias);
+    assert_eq!(s.parent, Some("US"));
+}
+
+#[test]
+fn test_support_unsupported() {
+    assert!(support(IdKind::PersonalId, "AQ").is_none());
+    assert!(support(IdKind::Vat, "US").is_none());
+    assert!(support(IdKind::Vat, "PR").is_none());
+    assert!(support(IdKind::Vat, "FO").is_none());
+    assert!(support(IdKind::Vat, "GF").is_none());
+    assert!(support(IdKind::PersonalId, "XX").is_none());
+}
@@ -102,3 +102,26 @@ fn test_diners_length() {
     let result = registry.generate(&opts, &mut rng).unwrap();
     assert_eq!(result.number.len(), 14);
 }
+
+#[test]
+fn test_brand_detection() {
+    let registry = Registry::new();
+    let mut rng = thread_rng();
+    for brand in ["visa", "mastercard", "amex", "discover", "jcb", "diners"] {
+        let opts = GenOptions {
+            brand: Some(brand.to_string()),
+            ..Default::default()
+        };
+        for _ in 0..100 {
+            let result = registry.generate(&opts, &mut rng).unwrap();
+            assert_eq!(
+                registry.brand(result.number.as_str()),
+                Some(result.brand.as_str()),
+                "{}",
+                result.number
+            );
+        }
+    }
+    assert_eq!(registry.brand("4152 8394 0512 6374"), Some("VISA"));
+    assert_eq!(registry.brand("1234567890123456"), None);
+}
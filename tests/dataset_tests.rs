@@ -0,0 +1,142 @@
+#![cfg(feature = "spec")]
+
+use rand::thread_rng;
+
+use idsmith::dataset::{Countries, Schema};
+
+fn schema(toml: &str) -> Schema {
+    Schema::from_toml(toml).unwrap()
+}
+
+#[test]
+fn test_rows_are_coherent() {
+    let s = schema(
+        r#"
+        rows = 200
+        country = { EE = 1, LV = 1, sepa = 1 }
+
+        [[columns]]
+        name = "country"
+        field = "country"
+
+        [[columns]]
+        name = "id"
+        kind = "id"
+        gender = "m"
+
+        [[columns]]
+        name = "gender"
+        from = "id"
+        field = "gender"
+
+        [[columns]]
+        name = "iban"
+        kind = "iban"
+        "#,
+    );
+    let rows = s.generate(&mut thread_rng()).unwrap();
+    assert_eq!(rows.len(), 200);
+    for row in &rows {
+        let country = row[0].as_deref().unwrap();
+        let id = row[1].as_deref().unwrap();
+        assert_eq!(idsmith::personal_ids().validate(country, id), Some(true));
+        if let Some(gender) = row[2].as_deref() {
+            assert_eq!(gender, "male");
+        }
+        let iban = row[3].as_deref().unwrap();
+        assert!(iban.starts_with(country), "{} {}", country, iban);
+        assert!(idsmith::iban::validate_iban(iban));
+    }
+    assert!(rows.iter().any(|r| r[0].as_deref() == Some("EE")));
+}
+
+#[test]
+fn test_column_options() {
+    let s = schema(
+        r#"
+        rows = 100
+
+        [[columns]]
+        name = "card"
+        kind = "card"
+        brand = "amex"
+        formatted = true
+
+        [[columns]]
+        name = "brand"
+        from = "card"
+        field = "brand"
+
+        [[columns]]
+        name = "vat"
+        kind = "vat"
+        country = ["DE", "FR"]
+        nulls = 0.5
+
+        [[columns]]
+        name = "pan"
+        kind = "tax"
+        country = "IN"
+        holder_type = "C"
+        "#,
+    );
+    let rows = s.generate(&mut thread_rng()).unwrap();
+    let nulls = rows.iter().filter(|r| r[2].is_none()).count();
+    assert!((10..90).contains(&nulls), "{}", nulls);
+    for row in &rows {
+        assert_eq!(row[0].as_deref().unwrap().len(), 17);
+        assert_eq!(row[1].as_deref(), Some("AMEX"));
+        if let Some(vat) = row[2].as_deref() {
+            assert!(vat.starts_with("DE") || vat.starts_with("FR"));
+        }
+        assert_eq!(row[3].as_deref().unwrap().chars().nth(3), Some('C'));
+    }
+}
+
+#[test]
+fn test_json_schema() {
+    let s = Schema::from_json(
+        r#"{"rows": 2, "country": {"NL": 3}, "columns": [
+            {"name": "company", "kind": "company"},
+            {"name": "name", "field": "country_name"}
+        ]}"#,
+    )
+    .unwrap();
+    assert_eq!(
+        s.country,
+        Some(Countries::Weighted([("NL".into(), 3.0)].into()))
+    );
+    let generator = s.compile().unwrap();
+    assert_eq!(generator.headers(), ["company", "name"]);
+    let row = generator.row(&mut thread_rng()).unwrap();
+    assert_eq!(row[1].as_deref(), Some("Netherlands"));
+}
+
+#[test]
+fn test_invalid_schemas() {
+    let err = |toml: &str| schema(toml).compile().err().unwrap();
+
+    let e = err("country = \"US\"\n[[columns]]\nname = \"iban\"\nkind = \"iban\"");
+    assert!(e.contains("cannot generate iban for US"), "{}", e);
+
+    let e = err("[[columns]]\nname = \"dob\"\nfrom = \"id\"\nfield = \"dob\"");
+    assert!(e.contains("no column named id"), "{}", e);
+
+    let e = err(
+        "[[columns]]\nname = \"id\"\nkind = \"id\"\n[[columns]]\nname = \"x\"\nfrom = \"id\"\nfield = \"nope\"",
+    );
+    assert!(e.contains("no field nope") && e.contains("dob"), "{}", e);
+
+    let e = err("[[columns]]\nname = \"c\"\nfield = \"country\"");
+    assert!(e.contains("needs a country"), "{}", e);
+
+    let e = err("[[columns]]\nname = \"a\"\nkind = \"lei\"\nnulls = 2.0");
+    assert!(e.contains("between 0 and 1"), "{}", e);
+
+    let e =
+        err("[[columns]]\nname = \"a\"\nkind = \"lei\"\n[[columns]]\nname = \"a\"\nkind = \"lei\"");
+    assert!(e.contains("Duplicate"), "{}", e);
+
+    assert!(Schema::from_toml("[[columns]]\nname = \"a\"\nkind = \"bogus\"").is_err());
+    assert!(Schema::from_toml("[[columns]]\nname = \"a\"\ncolour = \"red\"").is_err());
+}
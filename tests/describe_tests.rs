@@ -0,0 +1,216 @@
+use idsmith::describe::{describe, Charset};
+use idsmith::kind::IdKind;
+use rand::Rng;
+use regex::Regex;
+
+#[test]
+fn test_describe_personal_id() {
+    let info = describe(IdKind::PersonalId, Some("ee")).unwrap();
+    assert_eq!(info.country.as_deref(), Some("EE"));
+    assert_eq!(info.name, "Isikukood");
+    assert_eq!((info.min_length, info.max_length), (11, 11));
+    assert_eq!(info.charset, Charset::Digits);
+    assert!(info.separators.is_empty());
+    assert!(info.has_checksum);
+    assert!(info.encodes_dob);
+    assert!(info.encodes_gender);
+    assert_eq!(info.regex, "^[0-9]{11}$");
+}
+
+#[test]
+fn test_describe_separators() {
+    let info = describe(IdKind::PersonalId, Some("SE")).unwrap();
+    assert_eq!(info.separators, ['-']);
+    assert_eq!((info.min_length, info.max_length), (10, 10));
+    assert_eq!(info.regex, r"^(?:\-*[0-9]){10}\-*$");
+}
+
+#[test]
+fn test_describe_only_conventional_separators() {
+    for (kind, country) in [
+        (IdKind::CompanyId, Some("DE")),
+        (IdKind::CreditCard, None),
+        (IdKind::PersonalId, Some("IN")),
+        (IdKind::PersonalId, Some("BR")),
+        (IdKind::Iban, None),
+    ] {
+        let info = describe(kind, country).unwrap();
+        assert!(
+            info.separators.iter().all(|c| " -./".contains(*c)),
+            "{kind} {country:?}: {:?}",
+            info.separators
+        );
+    }
+    let info = describe(IdKind::PersonalId, Some("IN")).unwrap();
+    assert_eq!((info.min_length, info.max_length), (12, 12));
+}
+
+#[test]
+fn test_describe_keeps_required_punctuation() {
+    // The century sign of a henkilötunnus is part of the code
+    let info = describe(IdKind::PersonalId, Some("FI")).unwrap();
+    assert!(info.separators.is_empty());
+    assert_eq!((info.min_length, info.max_length), (11, 11));
+    let re = Regex::new(&info.regex).unwrap();
+    assert!(re.is_match(&info.example), "{}", info.regex);
+    let compact = info.example.replace('-', "");
+    if compact != info.example {
+        assert_eq!(
+            IdKind::PersonalId.validate(Some("FI"), &compact),
+            Some(false)
+        );
+        assert!(!re.is_match(&compact), "{compact} =~ {}", info.regex);
+    }
+}
+
+#[test]
+fn test_describe_covers_accepted_input() {
+    let cases = [
+        (IdKind::CreditCard, None, "4222222222222"),
+        (IdKind::CreditCard, None, "4000000000000000006"),
+        (IdKind::CreditCard, None, "4000 0000 0000 0000 006"),
+        (IdKind::PersonalId, Some("US"), "446-72-2445"),
+        (IdKind::PersonalId, Some("US"), "446722445"),
+        (IdKind::PersonalId, Some("SE"), "4310012804"),
+        (IdKind::PersonalId, Some("SE"), "431001-2804"),
+        (IdKind::Iban, Some("DE"), "DE89 3704 0044 0532 0130 00"),
+    ];
+    for (kind, country, code) in cases {
+        assert_eq!(kind.validate(country, code), Some(true), "{code}");
+        let info = describe(kind, country).unwrap();
+        let re = Regex::new(&info.regex).unwrap();
+        assert!(re.is_match(code), "{code} !~ {}", info.regex);
+    }
+}
+
+#[test]
+fn test_describe_names_declared_algorithms() {
+    let cases = [
+        (IdKind::Iban, None, "ISO 7064 MOD 97-10"),
+        (IdKind::Lei, None, "ISO 7064 MOD 97-10"),
+        (IdKind::CreditCard, None, "Luhn"),
+        (IdKind::PersonalId, Some("ZA"), "Luhn"),
+        (IdKind::PersonalId, Some("IN"), "Verhoeff"),
+        (IdKind::PersonalId, Some("CN"), "ISO 7064 MOD 11-2"),
+        (IdKind::PersonalId, Some("HR"), "ISO 7064 MOD 11-10"),
+        (IdKind::PersonalId, Some("PL"), "Weighted mod 10"),
+        (
+            IdKind::PersonalId,
+            Some("EE"),
+            "Weighted mod 11, two passes",
+        ),
+        (IdKind::PersonalId, Some("FI"), "Mod 31 character"),
+        (IdKind::CompanyId, Some("BE"), "Mod 97"),
+        (
+            IdKind::TaxId,
+            Some("BR"),
+            "Weighted mod 11, two check digits",
+        ),
+        (IdKind::Vat, Some("NL"), "ISO 7064 MOD 97-10"),
+        (IdKind::Vat, None, "varies by country"),
+    ];
+    for (kind, country, algorithm) in cases {
+        let info = describe(kind, country).unwrap();
+        assert_eq!(
+            info.checksum.as_deref(),
+            Some(algorithm),
+            "{kind} {country:?}"
+        );
+    }
+}
+
+#[test]
+fn test_describe_format_only() {
+    for (kind, country) in [
+        (IdKind::PersonalId, Some("US")),
+        (IdKind::Swift, None),
+        (IdKind::Passport, Some("DE")),
+    ] {
+        let info = describe(kind, country).unwrap();
+        assert!(!info.has_checksum, "{kind} {country:?}");
+        assert_eq!(info.checksum, None);
+        assert!(!info.encodes_dob);
+    }
+}
+
+#[test]
+fn test_describe_iban_country() {
+    let info = describe(IdKind::Iban, Some("DE")).unwrap();
+    assert_eq!(info.regex, r"^ *D *E(?: *[0-9]){20} *$");
+    assert_eq!((info.min_length, info.max_length), (22, 22));
+    assert_eq!(info.charset, Charset::Alphanumeric);
+}
+
+#[test]
+fn test_describe_length_range() {
+    let info = describe(IdKind::CreditCard, None).unwrap();
+    assert_eq!((info.min_length, info.max_length), (13, 19));
+    assert_eq!(info.regex, r"^(?:[ \-\./]*[0-9]){13,19}[ \-\./]*$");
+}
+
+#[test]
+fn test_describe_requires_country() {
+    assert!(describe(IdKind::PersonalId, None).is_none());
+    assert!(describe(IdKind::PersonalId, Some("XX")).is_none());
+}
+
+#[test]
+fn test_describe_regex_matches_generated_codes() {
+    let mut rng = rand::thread_rng();
+    for (code, _, _) in idsmith::personal_ids().list_countries() {
+        let info = describe(IdKind::PersonalId, Some(&code)).unwrap();
+        let re = Regex::new(&info.regex).unwrap();
+        assert!(re.is_match(&info.example), "{}: {}", code, info.regex);
+        for _ in 0..100 {
+            let id = IdKind::PersonalId.generate(Some(&code), &mut rng).unwrap();
+            assert!(re.is_match(&id), "{}: {} !~ {}", code, id, info.regex);
+        }
+    }
+}
+
+#[test]
+fn test_describe_regex_matches_accepted_variants() {
+    let mut rng = rand::thread_rng();
+    let mut targets: Vec<(IdKind, Option<String>)> = [
+        IdKind::Iban,
+        IdKind::CreditCard,
+        IdKind::Swift,
+        IdKind::Lei,
+        IdKind::Vat,
+    ]
+    .into_iter()
+    .map(|kind| (kind, None))
+    .collect();
+    for (code, _, _) in idsmith::personal_ids().list_countries() {
+        targets.push((IdKind::PersonalId, Some(code)));
+    }
+    for (kind, country) in targets {
+        let country = country.as_deref();
+        let info = describe(kind, country).unwrap();
+        let re = Regex::new(&info.regex).unwrap();
+        for _ in 0..50 {
+            let code = kind.generate(country, &mut rng).unwrap();
+            let at = rng.gen_range(0..=code.len());
+            let variants = [
+                code.to_lowercase(),
+                format!("{} ", code),
+                format!(
+                    "{}{}{}",
+                    &code[..at],
+                    [' ', '-', '.', '/'][at % 4],
+                    &code[at..]
+                ),
+                format!("{}{}", &code[..at], code.get(at + 1..).unwrap_or("")),
+            ];
+            for variant in variants {
+                if kind.validate(country, &variant) == Some(true) {
+                    assert!(
+                        re.is_match(&variant),
+                        "{kind} {country:?}: {variant:?} !~ {}",
+                        info.regex
+                    );
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,87 @@
+use rand::thread_rng;
+
+use idsmith::detect::{detect, detect_column};
+use idsmith::kind::{IdKind, Target};
+
+#[test]
+fn test_detect_iban() {
+    let found = detect("GB29 NWBK 6016 1331 9268 19");
+    assert_eq!(found[0], Target::new(IdKind::Iban, Some("GB")));
+}
+
+#[test]
+fn test_detect_vat_maps_el_to_gr() {
+    let mut rng = thread_rng();
+    let opts = idsmith::vat::GenOptions {
+        country: Some("EL".to_string()),
+        ..Default::default()
+    };
+    let vat = idsmith::vat_ids().generate(&opts, &mut rng).unwrap();
+    let found = detect(&vat.code);
+    assert!(found.contains(&Target::new(IdKind::Vat, Some("GR"))));
+}
+
+#[test]
+fn test_detect_card_and_personal_id() {
+    let found = detect("4111 1111 1111 1111");
+    assert!(found.contains(&Target::new(IdKind::CreditCard, None)));
+
+    let found = detect("446-72-2445");
+    assert!(found.contains(&Target::new(IdKind::PersonalId, Some("US"))));
+}
+
+#[test]
+fn test_detect_generated_codes_round_trip() {
+    let mut rng = thread_rng();
+    for kind in [IdKind::Iban, IdKind::Lei, IdKind::Vat, IdKind::CreditCard] {
+        for _ in 0..20 {
+            let code = kind.generate(None, &mut rng).unwrap();
+            assert!(
+                detect(&code).iter().any(|t| t.kind == kind),
+                "{} not detected as {}",
+                code,
+                kind
+            );
+        }
+    }
+}
+
+#[test]
+fn test_detect_rejects_noise() {
+    assert!(detect("").is_empty());
+    assert!(detect("hello").is_empty());
+    assert!(detect("ÄÖÜ-ÄÖÜ-ÄÖÜ").is_empty());
+    assert!(
+        detect("de89370400440532013000") // lowercase IBAN is normalized
+            .iter()
+            .any(|t| t.kind == IdKind::Iban)
+    );
+}
+
+#[test]
+fn test_detect_column_mixed_countries() {
+    let values = [
+        "DE89370400440532013000",
+        "GB29NWBK60161331926819",
+        "FR7630006000011234567890189",
+        "",
+    ];
+    let target = detect_column(values.iter().copied(), 0.8).unwrap();
+    assert_eq!(target, Target::new(IdKind::Iban, None));
+}
+
+#[test]
+fn test_detect_column_requires_ratio() {
+    let values = ["446-72-2445", "hello", "world", "foo"];
+    assert!(detect_column(values.iter().copied(), 0.8).is_none());
+}
+
+#[test]
+fn test_target_parse() {
+    let t: Target = "id:us".parse().unwrap();
+    assert_eq!(t, Target::new(IdKind::PersonalId, Some("US")));
+    assert_eq!(t.to_string(), "id:US");
+    assert!("id".parse::<Target>().is_err());
+    assert!("nope:US".parse::<Target>().is_err());
+    assert_eq!("iban".parse::<Target>().unwrap().country, None);
+}
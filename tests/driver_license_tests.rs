@@ -19,6 +19,7 @@ fn test_all_specific_countries_generate_valid() {
             let opts = GenOptions {
                 country: Some(cc.to_string()),
                 state: None,
+                ..Default::default()
             };
             let result = registry
                 .generate(&opts, &mut rng)
@@ -43,6 +44,7 @@ fn test_india_dl_format() {
     let opts = GenOptions {
         country: Some("IN".to_string()),
         state: None,
+        ..Default::default()
     };
     let result = registry.generate(&opts, &mut rng).unwrap();
     assert_eq!(result.code.len(), 15);
@@ -57,12 +59,86 @@ fn test_india_dl_with_state() {
     let opts = GenOptions {
         country: Some("IN".to_string()),
         state: Some("MH".to_string()),
+        ..Default::default()
     };
     let result = registry.generate(&opts, &mut rng).unwrap();
     assert!(result.code.starts_with("MH"));
     assert_eq!(result.state, Some("MH".to_string()));
 }
 
+#[test]
+fn test_state_by_iso_code_or_name() {
+    let registry = Registry::new();
+    let mut rng = thread_rng();
+    for (country, state, expected) in [
+        ("US", "US-NY", "NY"),
+        ("US", "texas", "TX"),
+        ("AU", "Victoria", "VIC"),
+        ("JP", "JP-13", "13"),
+        ("IN", "IN-DH", "DH"),
+        ("CN", "CN-GD", "GD"),
+        ("MX", "Jalisco", "JAL"),
+    ] {
+        let opts = GenOptions {
+            country: Some(country.to_string()),
+            state: Some(state.to_string()),
+            ..Default::default()
+        };
+        let result = registry.generate(&opts, &mut rng).unwrap();
+        assert_eq!(
+            result.state.as_deref(),
+            Some(expected),
+            "{} {}",
+            country,
+            state
+        );
+        assert!(registry.validate(country, &result.code), "{}", result.code);
+    }
+
+    // Codes carry the subdivision where the format has one
+    let opts = GenOptions {
+        country: Some("CN".to_string()),
+        state: Some("BJ".to_string()),
+        ..Default::default()
+    };
+    assert!(registry
+        .generate(&opts, &mut rng)
+        .unwrap()
+        .code
+        .starts_with("11"));
+    let opts = GenOptions {
+        country: Some("IN".to_string()),
+        state: Some("DH".to_string()),
+        ..Default::default()
+    };
+    assert!(registry
+        .generate(&opts, &mut rng)
+        .unwrap()
+        .code
+        .starts_with("DD"));
+}
+
+#[test]
+fn test_unknown_state() {
+    let registry = Registry::new();
+    let mut rng = thread_rng();
+    let opts = GenOptions {
+        country: Some("US".to_string()),
+        state: Some("XX".to_string()),
+        ..Default::default()
+    };
+    assert!(registry.generate(&opts, &mut rng).is_none());
+
+    // Countries without subdivision data ignore the option
+    let opts = GenOptions {
+        country: Some("GB".to_string()),
+        state: Some("XX".to_string()),
+        ..Default::default()
+    };
+    let result = registry.generate(&opts, &mut rng).unwrap();
+    assert!(result.state.is_none());
+}
+
 #[test]
 fn test_us_dl_format() {
     let registry = Registry::new();
@@ -71,6 +147,7 @@ fn test_us_dl_format() {
     let opts = GenOptions {
         country: Some("US".to_string()),
         state: Some("CA".to_string()),
+        ..Default::default()
     };
     let result = registry.generate(&opts, &mut rng).unwrap();
     assert_eq!(result.code.len(), 13);
@@ -86,6 +163,7 @@ fn test_gb_dl_format() {
     let opts = GenOptions {
         country: Some("GB".to_string()),
         state: None,
+        ..Default::default()
     };
     let result = registry.generate(&opts, &mut rng).unwrap();
     assert_eq!(result.code.len(), 16);
@@ -100,6 +178,7 @@ fn test_br_cnh_checksum() {
         let opts = GenOptions {
             country: Some("BR".to_string()),
             state: None,
+            ..Default::default()
         };
         let result = registry.generate(&opts, &mut rng).unwrap();
         assert_eq!(result.code.len(), 11);
@@ -116,6 +195,7 @@ fn test_es_permiso_checksum() {
         let opts = GenOptions {
             country: Some("ES".to_string()),
             state: None,
+            ..Default::default()
         };
         let result = registry.generate(&opts, &mut rng).unwrap();
         assert_eq!(result.code.len(), 9);
@@ -136,6 +216,7 @@ fn test_se_korkort_luhn() {
         let opts = GenOptions {
             country: Some("SE".to_string()),
             state: None,
+            ..Default::default()
         };
         let result = registry.generate(&opts, &mut rng).unwrap();
         assert_eq!(result.code.len(), 10);
@@ -156,6 +237,7 @@ fn test_sg_dl_checksum() {
         let opts = GenOptions {
             country: Some("SG".to_string()),
             state: None,
+            ..Default::default()
         };
         let result = registry.generate(&opts, &mut rng).unwrap();
         assert_eq!(result.code.len(), 9);
@@ -176,6 +258,7 @@ fn test_za_dl_luhn() {
         let opts = GenOptions {
             country: Some("ZA".to_string()),
             state: None,
+            ..Default::default()
         };
         let result = registry.generate(&opts, &mut rng).unwrap();
         assert_eq!(result.code.len(), 13);
@@ -209,6 +292,7 @@ fn test_generic_countries() {
         let opts = GenOptions {
             country: Some(country.to_string()),
             state: None,
+            ..Default::default()
         };
         let result = registry.generate(&opts, &mut rng).expect(country);
         assert_eq!(result.country_code, country);
@@ -224,6 +308,7 @@ fn test_unknown_country_returns_none() {
     let opts = GenOptions {
         country: Some("ZZ".to_string()),
         state: None,
+        ..Default::default()
     };
     assert!(registry.generate(&opts, &mut rng).is_none());
 }
@@ -251,3 +336,28 @@ fn test_random_country_generation() {
         assert!(!result.code.is_empty());
     }
 }
+
+#[test]
+fn test_territory_aliases() {
+    let registry = Registry::new();
+    let mut rng = thread_rng();
+    for (cc, parent) in [("GF", "FR"), ("FO", "DK"), ("GU", "US")] {
+        let opts = GenOptions {
+            country: Some(cc.to_string()),
+            ..Default::default()
+        };
+        let result = registry
+            .generate(&opts, &mut rng)
+            .unwrap_or_else(|| panic!("{}: generate failed", cc));
+        assert_eq!(result.country_code, cc);
+        assert_eq!(result.effective_country_code, parent);
+        assert!(registry.validate(cc, &result.code));
+
+        let strict = GenOptions {
+            country: Some(cc.to_string()),
+            strict: true,
+            ..Default::default()
+        };
+        assert!(registry.generate(&strict, &mut rng).is_none());
+    }
+}
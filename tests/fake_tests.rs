@@ -0,0 +1,72 @@
+#![cfg(feature = "fake")]
+
+use fake::{Dummy, Fake, Faker};
+use idsmith::credit_card::CardResult;
+use idsmith::personal_id::date::Gender;
+use idsmith::personal_id::IdResult;
+use idsmith::{fake as ids, CardNumber, Iban, PersonalId, VatNumber};
+
+#[derive(Debug, Dummy)]
+struct Customer {
+    #[dummy(faker = "idsmith::fake::PersonalId(\"SE\", Some(Gender::Female))")]
+    national_id: PersonalId,
+    #[dummy(faker = "idsmith::fake::Iban(Some(\"DE\"))")]
+    iban: Iban,
+    #[dummy(faker = "idsmith::fake::VatNumber(Some(\"FR\"))")]
+    vat: String,
+    #[dummy(faker = "idsmith::fake::CardNumber(Some(\"amex\"))")]
+    card: CardNumber,
+    company: idsmith::company_id::CompanyResult,
+    any_id: PersonalId,
+}
+
+#[test]
+fn test_derive() {
+    for _ in 0..20 {
+        let c: Customer = Faker.fake();
+        assert_eq!(c.national_id.country(), "SE");
+        let parsed = idsmith::personal_ids()
+            .parse("SE", c.national_id.code())
+            .unwrap();
+        assert_eq!(parsed.gender.as_deref(), Some("female"));
+        assert_eq!(c.iban.country_code(), "DE");
+        assert!(c.vat.parse::<VatNumber>().unwrap().starts_with("FR"));
+        assert_eq!(c.card.len(), 15);
+        assert!(c.company.valid);
+        assert!(PersonalId::new(c.any_id.country(), c.any_id.code()).is_ok());
+    }
+}
+
+#[test]
+fn test_result_types() {
+    let card: CardResult = ids::CardNumber(Some("visa")).fake();
+    assert_eq!(card.brand, "VISA");
+
+    let id: IdResult = ids::PersonalId("EE", Some(Gender::Male)).fake();
+    assert_eq!(id.gender.as_deref(), Some("male"));
+    assert!(id.valid);
+
+    let account: idsmith::bank_account::AccountResult = ids::BankAccount("GB").fake();
+    assert!(account.valid);
+
+    let tax: String = ids::TaxId(Some("IT")).fake();
+    assert!(idsmith::tax_ids().validate("IT", &tax));
+
+    let passport: String = ids::Passport(Some("DE")).fake();
+    assert!(idsmith::passports().validate("DE", &passport));
+
+    let licence: String = ids::DriverLicense(Some("FR")).fake();
+    assert!(idsmith::driver_licenses().validate("FR", &licence));
+
+    let bic: idsmith::Bic = ids::Bic(Some("nl")).fake();
+    assert_eq!(bic.country_code(), "NL");
+
+    let lei: String = ids::Lei(None).fake();
+    assert!(idsmith::lei_codes().validate(&lei));
+}
+
+#[test]
+#[should_panic(expected = "Cannot generate")]
+fn test_unknown_country_panics() {
+    let _: String = ids::PersonalId("XX", None).fake();
+}
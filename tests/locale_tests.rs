@@ -0,0 +1,157 @@
+use idsmith::kind::IdKind;
+use idsmith::locale::{self, Locale};
+use idsmith::{countries, driver_license, passport, personal_id, tax_id, vat};
+
+#[test]
+fn test_parse_locale() {
+    assert_eq!("local".parse::<Locale>(), Ok(Locale::Local));
+    assert_eq!("EN".parse::<Locale>(), Ok(Locale::English));
+    assert_eq!("translit".parse::<Locale>(), Ok(Locale::Latin));
+    assert!("klingon".parse::<Locale>().is_err());
+    assert_eq!(Locale::default(), Locale::English);
+}
+
+#[test]
+fn test_country_names() {
+    let de = locale::country("de").unwrap();
+    assert_eq!(
+        (de.local, de.english, de.latin),
+        ("Deutschland", "Germany", "Deutschland")
+    );
+
+    let jp = locale::country("JP").unwrap();
+    assert_eq!((jp.local, jp.latin), ("日本", "Nihon"));
+    assert_eq!(
+        locale::country_name("AT", Locale::Latin),
+        Some("Osterreich")
+    );
+
+    // No local name: English everywhere
+    assert_eq!(
+        locale::country_name("GB", Locale::Local),
+        Some("United Kingdom")
+    );
+    assert!(locale::country("XX").is_none());
+
+    for c in countries::ALL_COUNTRIES {
+        let name = locale::country(c.code).unwrap();
+        assert!(name.latin.is_ascii(), "{}: {}", c.code, name.latin);
+        assert!(!name.local.is_empty(), "{}", c.code);
+    }
+}
+
+#[test]
+fn test_document_names() {
+    let fi = locale::document(IdKind::PersonalId, "fi").unwrap();
+    assert_eq!(fi.local, "Henkilötunnus");
+    assert_eq!(fi.english, "Personal identity code");
+    assert_eq!(fi.latin, "Henkilotunnus");
+
+    let cz = locale::document(IdKind::Passport, "CZ").unwrap();
+    assert_eq!(
+        (cz.local, cz.english, cz.latin),
+        ("Cestovní pas", "Passport", "Cestovni pas")
+    );
+    assert_eq!(
+        locale::document_name(IdKind::DriverLicense, "JP", Locale::Latin),
+        Some("Unten menkyosho")
+    );
+    assert!(locale::document(IdKind::Iban, "DE").is_none());
+}
+
+#[test]
+fn test_every_document_is_localized() {
+    let lists = [
+        (IdKind::Passport, passport::Registry::new().list_countries()),
+        (
+            IdKind::DriverLicense,
+            driver_license::Registry::new().list_countries(),
+        ),
+        (IdKind::TaxId, tax_id::Registry::new().list_countries()),
+    ];
+    for (kind, countries) in lists {
+        for (code, _, _) in countries {
+            let name = locale::document(kind, code).unwrap_or_else(|| panic!("{} {}", kind, code));
+            assert!(name.latin.is_ascii(), "{} {}: {}", kind, code, name.latin);
+        }
+    }
+
+    let registry = personal_id::Registry::new();
+    for (code, _, _) in registry.list_countries() {
+        let effective = registry.effective_country(&code).unwrap();
+        let name = locale::document(IdKind::PersonalId, &effective)
+            .unwrap_or_else(|| panic!("id {}", code));
+        assert!(name.latin.is_ascii(), "id {}: {}", code, name.latin);
+    }
+}
+
+#[test]
+fn test_registry_listings() {
+    let registry = personal_id::Registry::new();
+    assert_eq!(
+        registry.name_in("NO", Locale::Local).as_deref(),
+        Some("Fødselsnummer")
+    );
+    assert_eq!(
+        registry.name_in("NO", Locale::English).as_deref(),
+        Some("National identity number")
+    );
+    // Territories use their parent's ID name
+    assert_eq!(
+        registry.name_in("PR", Locale::English).as_deref(),
+        Some("Social Security number")
+    );
+    assert!(registry.name_in("XX", Locale::English).is_none());
+
+    let listed = registry.list_countries_in(Locale::Local);
+    assert_eq!(listed.len(), registry.list_countries().len());
+    assert!(listed.contains(&(
+        "RS".into(),
+        "Србија".into(),
+        "Јединствени матични број грађана".into()
+    )));
+
+    let passports = passport::Registry::new().list_countries_in(Locale::Latin);
+    assert!(passports.contains(&("KR", "Daehan Minguk", "Yeogwon")));
+
+    let companies = idsmith::company_ids().list_countries_in(Locale::English);
+    assert!(companies.contains(&(
+        "DK".into(),
+        "Denmark".into(),
+        "Central business register number".into()
+    )));
+
+    let vat = vat::Registry::new().list_countries_in(Locale::Local);
+    assert!(vat.contains(&("EL", "Ελλάδα")));
+}
+
+#[test]
+fn test_custom_scheme_keeps_its_name() {
+    struct Anything;
+
+    impl idsmith::IdScheme for Anything {
+        fn name(&self) -> &str {
+            "Anything"
+        }
+
+        fn generate(&self, _rng: &mut dyn rand::RngCore) -> String {
+            "ANY".to_string()
+        }
+
+        fn validate(&self, _code: &str) -> bool {
+            true
+        }
+    }
+
+    let registry = personal_id::Registry::new();
+    registry.override_scheme("EE", Anything).unwrap();
+    assert_eq!(
+        registry.name_in("EE", Locale::Local).as_deref(),
+        Some("Anything")
+    );
+    assert!(registry.list_countries_in(Locale::English).contains(&(
+        "EE".into(),
+        "Estonia".into(),
+        "Anything".into()
+    )));
+}
@@ -0,0 +1,277 @@
+#![cfg(feature = "output")]
+
+use idsmith::iban::IbanResult;
+use idsmith::output::{self, Field, FieldType, Format, Options, Record, Value, Writer};
+
+fn iban() -> IbanResult {
+    IbanResult {
+        country: "DE".into(),
+        iban: "DE89370400440532013000".parse().unwrap(),
+        formatted: "DE89 3704 0044 0532 0130 00".into(),
+        valid: true,
+    }
+}
+
+fn render(format: Format, fields: &[&str]) -> String {
+    let opts = Options {
+        format,
+        fields: fields.iter().map(|f| f.to_string()).collect(),
+        ..Default::default()
+    };
+    let mut out = Vec::new();
+    output::write_records(&mut out, &[iban(), iban()], opts).unwrap();
+    String::from_utf8(out).unwrap()
+}
+
+fn text_fields(names: &[&str]) -> Vec<Field> {
+    names
+        .iter()
+        .map(|n| Field::new(n, FieldType::Text, true))
+        .collect()
+}
+
+#[test]
+fn test_record_fields() {
+    let names: Vec<String> = IbanResult::fields().into_iter().map(|f| f.name).collect();
+    assert_eq!(names, ["country", "iban", "formatted", "valid"]);
+    assert_eq!(iban().values()[3], Value::Bool(true));
+}
+
+#[test]
+fn test_csv() {
+    assert_eq!(
+        render(Format::Csv, &["iban", "valid"]),
+        "iban,valid\nDE89370400440532013000,true\nDE89370400440532013000,true\n"
+    );
+
+    let opts = Options {
+        delimiter: ';',
+        header: false,
+        ..Default::default()
+    };
+    let mut w = Writer::new(Vec::new(), text_fields(&["a", "b"]), opts).unwrap();
+    w.write_values(&[Value::Text("x;y".into()), Value::Null])
+        .unwrap();
+    assert_eq!(w.finish().unwrap(), b"\"x;y\";\n");
+}
+
+#[test]
+fn test_csv_formula_guard() {
+    let fields = text_fields(&["=cmd", "b", "c"]);
+    let values = [
+        Value::Text("+1".into()),
+        Value::Text("@SUM(A1)".into()),
+        Value::Text("-2,3".into()),
+    ];
+    let mut w = Writer::new(Vec::new(), fields.clone(), Options::default()).unwrap();
+    w.write_values(&values).unwrap();
+    assert_eq!(
+        String::from_utf8(w.finish().unwrap()).unwrap(),
+        "'=cmd,b,c\n'+1,'@SUM(A1),\"'-2,3\"\n"
+    );
+
+    let opts = Options {
+        formula_guard: false,
+        header: false,
+        ..Default::default()
+    };
+    let mut w = Writer::new(Vec::new(), fields, opts).unwrap();
+    w.write_values(&values).unwrap();
+    assert_eq!(w.finish().unwrap(), b"+1,@SUM(A1),\"-2,3\"\n");
+}
+
+#[test]
+fn test_json_and_ndjson() {
+    let json = render(Format::Json, &["country", "valid"]);
+    assert_eq!(
+        json,
+        "[\n  {\n    \"country\": \"DE\",\n    \"valid\": true\n  },\n  {\n    \"country\": \"DE\",\n    \"valid\": true\n  }\n]\n"
+    );
+    assert_eq!(
+        render(Format::Ndjson, &["country", "valid"]),
+        "{\"country\":\"DE\",\"valid\":true}\n".repeat(2)
+    );
+
+    let mut w = Writer::new(Vec::new(), text_fields(&["a"]), Format::Json.into()).unwrap();
+    w.write_values(&[Value::Text("say \"hi\"\n\u{1}".into())])
+        .unwrap();
+    let out = String::from_utf8(w.finish().unwrap()).unwrap();
+    assert!(out.contains(r#""a": "say \"hi\"\n\u0001""#));
+    #[cfg(feature = "json")]
+    {
+        let parsed: serde_json::Value = serde_json::from_str(&out).unwrap();
+        assert_eq!(parsed[0]["a"], "say \"hi\"\n\u{1}");
+    }
+
+    let empty = Writer::new(Vec::new(), text_fields(&["a"]), Format::Json.into()).unwrap();
+    assert_eq!(empty.finish().unwrap(), b"[]\n");
+}
+
+#[test]
+fn test_yaml() {
+    assert_eq!(
+        render(Format::Yaml, &["country", "formatted", "valid"]),
+        "- country: DE\n  formatted: DE89 3704 0044 0532 0130 00\n  valid: true\n".repeat(2)
+    );
+
+    let mut w = Writer::new(
+        Vec::new(),
+        text_fields(&["a", "b", "c"]),
+        Format::Yaml.into(),
+    )
+    .unwrap();
+    w.write_values(&[
+        Value::Text("123-45-6789".into()),
+        Value::Text("no".into()),
+        Value::Null,
+    ])
+    .unwrap();
+    assert_eq!(
+        w.finish().unwrap(),
+        b"- a: \"123-45-6789\"\n  b: \"no\"\n  c: null\n"
+    );
+}
+
+#[test]
+fn test_xml() {
+    let fields = text_fields(&["a b", "1st", "c"]);
+    let mut w = Writer::new(Vec::new(), fields, Format::Xml.into()).unwrap();
+    w.write_values(&[
+        Value::Text("<&>".into()),
+        Value::Text("x".into()),
+        Value::Null,
+    ])
+    .unwrap();
+    assert_eq!(
+        String::from_utf8(w.finish().unwrap()).unwrap(),
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<records>\n  <record>\n    <a_b>&lt;&amp;&gt;</a_b>\n    <_1st>x</_1st>\n  </record>\n</records>\n"
+    );
+}
+
+#[test]
+fn test_template() {
+    assert_eq!(
+        render(
+            Format::Template("{{{country}}} {iban}: {valid}".into()),
+            &[]
+        ),
+        "{DE} DE89370400440532013000: true\n".repeat(2)
+    );
+
+    let fields = IbanResult::fields();
+    for template in ["{nope}", "{iban", "iban}"] {
+        let opts = Format::Template(template.into()).into();
+        assert!(Writer::new(Vec::new(), fields.clone(), opts).is_err());
+    }
+    let opts = Options {
+        format: Format::Template("{iban}".into()),
+        fields: vec!["country".into()],
+        ..Default::default()
+    };
+    assert!(Writer::new(Vec::new(), fields, opts).is_err());
+}
+
+#[test]
+fn test_unknown_field() {
+    let opts = Options {
+        fields: vec!["bic".into()],
+        ..Default::default()
+    };
+    let err = Writer::for_rows::<IbanResult>(Vec::new(), opts)
+        .err()
+        .unwrap();
+    assert!(err.contains("Unknown field: bic"));
+}
+
+/// CSV output without selected fields keeps the columns of the `csv` module.
+#[cfg(feature = "csv")]
+#[test]
+fn test_csv_default_columns_match_csv_module() {
+    use idsmith::csv;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    fn default_csv<T: Record>(row: &T) -> String {
+        let mut out = Vec::new();
+        output::write_records(&mut out, std::slice::from_ref(row), Options::default()).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    let rng = &mut StdRng::seed_from_u64(7);
+    let row = iban();
+    assert_eq!(
+        default_csv(&row),
+        format!(
+            "{}\n{}\n",
+            csv::IBAN_HEADER,
+            csv::iban_row(&row.iban, &row.formatted, row.valid)
+        )
+    );
+
+    let registry = idsmith::personal_ids();
+    let code = registry.generate("SE", &Default::default(), rng).unwrap();
+    let row = registry.parse("SE", &code).unwrap();
+    let name = registry.name("SE").unwrap();
+    assert_eq!(
+        default_csv(&row),
+        format!("{}\n{}\n", csv::ID_HEADER, csv::id_row("SE", &name, &row))
+    );
+
+    let row = idsmith::credit_cards()
+        .generate(&Default::default(), rng)
+        .unwrap();
+    let expected = format!("{}\n{}\n", csv::CARD_HEADER, csv::card_row(&row));
+    assert_eq!(default_csv(&row), expected);
+
+    let row = idsmith::swift_codes().generate(&Default::default(), rng);
+    let expected = format!("{}\n{}\n", csv::SWIFT_HEADER, csv::swift_row(&row));
+    assert_eq!(default_csv(&row), expected);
+
+    let row = idsmith::company_ids()
+        .generate(&Default::default(), rng)
+        .unwrap();
+    let expected = format!("{}\n{}\n", csv::COMPANY_HEADER, csv::company_row(&row));
+    assert_eq!(default_csv(&row), expected);
+
+    let row = idsmith::bank_accounts()
+        .generate("DE", &Default::default(), rng)
+        .unwrap();
+    let expected = format!("{}\n{}\n", csv::ACCOUNT_HEADER, csv::account_row(&row));
+    assert_eq!(default_csv(&row), expected);
+
+    let row = idsmith::driver_licenses()
+        .generate(&Default::default(), rng)
+        .unwrap();
+    let expected = format!("{}\n{}\n", csv::LICENSE_HEADER, csv::license_row(&row));
+    assert_eq!(default_csv(&row), expected);
+
+    let row = idsmith::tax_ids()
+        .generate(&Default::default(), rng)
+        .unwrap();
+    let expected = format!("{}\n{}\n", csv::TAX_HEADER, csv::tax_row(&row));
+    assert_eq!(default_csv(&row), expected);
+
+    let row = idsmith::lei_codes().generate(&Default::default(), rng);
+    let expected = format!("{}\n{}\n", csv::LEI_HEADER, csv::lei_row(&row));
+    assert_eq!(default_csv(&row), expected);
+
+    let row = idsmith::vat_ids()
+        .generate(&Default::default(), rng)
+        .unwrap();
+    let expected = format!("{}\n{}\n", csv::VAT_HEADER, csv::vat_row(&row));
+    assert_eq!(default_csv(&row), expected);
+
+    let row = idsmith::passports()
+        .generate(&Default::default(), rng)
+        .unwrap();
+    let expected = format!("{}\n{}\n", csv::PASSPORT_HEADER, csv::passport_row(&row));
+    assert_eq!(default_csv(&row), expected);
+
+    // Selecting fields opts into the record's own names
+    assert_eq!(
+        render(Format::Csv, &["country", "formatted"])
+            .lines()
+            .next(),
+        Some("country,formatted")
+    );
+}
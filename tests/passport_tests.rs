@@ -18,6 +18,7 @@ fn test_all_specific_countries_generate_valid() {
         for _ in 0..20 {
             let opts = GenOptions {
                 country: Some(cc.to_string()),
+                ..Default::default()
             };
             let result = registry
                 .generate(&opts, &mut rng)
@@ -41,6 +42,7 @@ fn test_india_passport_format() {
 
     let opts = GenOptions {
         country: Some("IN".to_string()),
+        ..Default::default()
     };
     let result = registry.generate(&opts, &mut rng).unwrap();
     assert_eq!(result.code.len(), 8);
@@ -56,6 +58,7 @@ fn test_us_passport_format() {
 
     let opts = GenOptions {
         country: Some("US".to_string()),
+        ..Default::default()
     };
     let result = registry.generate(&opts, &mut rng).unwrap();
     assert_eq!(result.code.len(), 9);
@@ -69,6 +72,7 @@ fn test_de_passport_format() {
 
     let opts = GenOptions {
         country: Some("DE".to_string()),
+        ..Default::default()
     };
     let result = registry.generate(&opts, &mut rng).unwrap();
     assert_eq!(result.code.len(), 9);
@@ -82,6 +86,7 @@ fn test_cn_passport_format() {
 
     let opts = GenOptions {
         country: Some("CN".to_string()),
+        ..Default::default()
     };
     let result = registry.generate(&opts, &mut rng).unwrap();
     assert_eq!(result.code.len(), 9);
@@ -96,6 +101,7 @@ fn test_kr_passport_format() {
 
     let opts = GenOptions {
         country: Some("KR".to_string()),
+        ..Default::default()
     };
     let result = registry.generate(&opts, &mut rng).unwrap();
     assert_eq!(result.code.len(), 9);
@@ -110,6 +116,7 @@ fn test_sg_passport_format() {
 
     let opts = GenOptions {
         country: Some("SG".to_string()),
+        ..Default::default()
     };
     let result = registry.generate(&opts, &mut rng).unwrap();
     assert_eq!(result.code.len(), 9);
@@ -152,6 +159,7 @@ fn test_generic_countries() {
     for country in countries {
         let opts = GenOptions {
             country: Some(country.to_string()),
+            ..Default::default()
         };
         let result = registry.generate(&opts, &mut rng).expect(country);
         assert_eq!(result.country_code, country);
@@ -166,6 +174,7 @@ fn test_unknown_country_returns_none() {
 
     let opts = GenOptions {
         country: Some("ZZ".to_string()),
+        ..Default::default()
     };
     assert!(registry.generate(&opts, &mut rng).is_none());
 }
@@ -193,3 +202,27 @@ fn test_random_country_generation() {
         assert!(!result.code.is_empty());
     }
 }
+
+#[test]
+fn test_territory_aliases() {
+    let registry = Registry::new();
+    let mut rng = thread_rng();
+    for (cc, parent) in [("PR", "US"), ("GF", "FR"), ("FO", "DK")] {
+        let opts = GenOptions {
+            country: Some(cc.to_string()),
+            ..Default::default()
+        };
+        let result = registry
+            .generate(&opts, &mut rng)
+            .unwrap_or_else(|| panic!("{}: generate failed", cc));
+        assert_eq!(result.country_code, cc);
+        assert_eq!(result.effective_country_code, parent);
+        assert!(registry.validate(cc, &result.code));
+
+        let strict = GenOptions {
+            country: Some(cc.to_string()),
+            strict: true,
+        };
+        assert!(registry.generate(&strict, &mut rng).is_none());
+    }
+}
@@ -93,6 +93,17 @@ fn test_territory_aliases() {
         let valid = registry.validate(cc, &code);
         assert_eq!(valid, Some(true), "{}: validation failed for {}", cc, code);
     }
+
+    let parsed = registry.parse("pr", "123-45-6789").unwrap();
+    assert_eq!(parsed.country_code, "PR");
+    assert_eq!(parsed.effective_country_code, "US");
+
+    let strict = GenOptions {
+        strict: true,
+        ..Default::default()
+    };
+    assert!(registry.generate("PR", &strict, &mut rng).is_none());
+    assert!(registry.generate("US", &strict, &mut rng).is_some());
 }
 
 #[test]
@@ -112,10 +123,12 @@ fn test_gender_filter() {
         let opts_m = GenOptions {
             gender: Some(Gender::Male),
             year: None,
+            ..Default::default()
         };
         let opts_f = GenOptions {
             gender: Some(Gender::Female),
             year: None,
+            ..Default::default()
         };
 
         for _ in 0..5 {
@@ -154,6 +167,7 @@ fn test_year_filter() {
         let opts = GenOptions {
             gender: None,
             year: Some(1985),
+            ..Default::default()
         };
         for _ in 0..5 {
             let code = registry.generate(cc, &opts, &mut rng).unwrap();
@@ -185,22 +199,22 @@ fn test_unsupported_country() {
 #[test]
 fn test_registry_name() {
     let registry = personal_id::Registry::new();
-    assert_eq!(registry.name("EE"), Some("Isikukood"));
-    assert_eq!(registry.name("IT"), Some("Codice Fiscale"));
-    assert_eq!(registry.name("PL"), Some("PESEL"));
-    assert_eq!(registry.name("GB"), Some("NINO"));
+    assert_eq!(registry.name("EE").as_deref(), Some("Isikukood"));
+    assert_eq!(registry.name("IT").as_deref(), Some("Codice Fiscale"));
+    assert_eq!(registry.name("PL").as_deref(), Some("PESEL"));
+    assert_eq!(registry.name("GB").as_deref(), Some("NINO"));
     // New countries
-    assert_eq!(registry.name("US"), Some("SSN"));
-    assert_eq!(registry.name("CN"), Some("Resident ID"));
-    assert_eq!(registry.name("ZA"), Some("SA ID"));
-    assert_eq!(registry.name("IN"), Some("Aadhaar"));
+    assert_eq!(registry.name("US").as_deref(), Some("SSN"));
+    assert_eq!(registry.name("CN").as_deref(), Some("Resident ID"));
+    assert_eq!(registry.name("ZA").as_deref(), Some("SA ID"));
+    assert_eq!(registry.name("IN").as_deref(), Some("Aadhaar"));
     // New countries
-    assert_eq!(registry.name("CU"), Some("NI"));
-    assert_eq!(registry.name("DO"), Some("Cedula"));
-    assert_eq!(registry.name("MU"), Some("NID"));
-    assert_eq!(registry.name("PK"), Some("CNIC"));
+    assert_eq!(registry.name("CU").as_deref(), Some("NI"));
+    assert_eq!(registry.name("DO").as_deref(), Some("Cédula"));
+    assert_eq!(registry.name("MU").as_deref(), Some("NID"));
+    assert_eq!(registry.name("PK").as_deref(), Some("CNIC"));
     // Alias
-    assert_eq!(registry.name("PR"), Some("SSN"));
+    assert_eq!(registry.name("PR").as_deref(), Some("SSN"));
 }
 
 #[test]
@@ -240,3 +254,80 @@ fn test_checksum_corruption() {
         }
     }
 }
+
+#[test]
+fn test_region() {
+    let registry = personal_id::Registry::new();
+    let mut rng = thread_rng();
+
+    let opts = GenOptions {
+        region: Some("MX-JAL".to_string()),
+        ..Default::default()
+    };
+    for _ in 0..20 {
+        let code = registry.generate("MX", &opts, &mut rng).unwrap();
+        assert_eq!(&code[11..13], "JC", "{}", code);
+        let parsed = registry.parse("MX", &code).unwrap();
+        assert_eq!(parsed.region.as_deref(), Some("JAL"));
+    }
+
+    let opts = GenOptions {
+        region: Some("Guangdong".to_string()),
+        ..Default::default()
+    };
+    for _ in 0..20 {
+        let code = registry.generate("CN", &opts, &mut rng).unwrap();
+        assert!(code.starts_with("44"), "{}", code);
+        assert_eq!(registry.validate("CN", &code), Some(true));
+        let parsed = registry.parse("CN", &code).unwrap();
+        assert_eq!(parsed.region.as_deref(), Some("GD"));
+    }
+
+    // Every CN province can be generated
+    for province in idsmith::countries::subdivisions::of("CN") {
+        let opts = GenOptions {
+            region: Some(province.code.to_string()),
+            ..Default::default()
+        };
+        let code = registry.generate("CN", &opts, &mut rng).unwrap();
+        let parsed = registry.parse("CN", &code).unwrap();
+        assert_eq!(parsed.region.as_deref(), Some(province.suffix()));
+    }
+
+    // Residence permits only when asked for; NE (born abroad) among random states
+    let mut born_abroad = false;
+    for _ in 0..2000 {
+        let code = registry
+            .generate("CN", &Default::default(), &mut rng)
+            .unwrap();
+        assert!(!["81", "82", "83"].contains(&&code[..2]), "{}", code);
+        let code = registry
+            .generate("MX", &Default::default(), &mut rng)
+            .unwrap();
+        if &code[11..13] == "NE" {
+            born_abroad = true;
+            assert_eq!(registry.validate("MX", &code), Some(true));
+            assert!(registry.parse("MX", &code).unwrap().region.is_none());
+        }
+    }
+    assert!(born_abroad);
+    let opts = GenOptions {
+        region: Some("CN-HK".to_string()),
+        ..Default::default()
+    };
+    let code = registry.generate("CN", &opts, &mut rng).unwrap();
+    assert!(code.starts_with("810000"), "{}", code);
+
+    let opts = GenOptions {
+        region: Some("MX-XXX".to_string()),
+        ..Default::default()
+    };
+    assert!(registry.generate("MX", &opts, &mut rng).is_none());
+    // Schemes without regions ignore it
+    assert!(registry.generate("EE", &opts, &mut rng).is_some());
+    assert!(registry
+        .parse("EE", "39001010008")
+        .unwrap()
+        .region
+        .is_none());
+}
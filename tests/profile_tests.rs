@@ -0,0 +1,121 @@
+use idsmith::batch::Failure;
+use idsmith::kind::IdKind;
+use idsmith::personal_id::date::Gender;
+use idsmith::personal_id::GenOptions;
+use idsmith::profile::profile_column;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+#[test]
+fn test_iban_column() {
+    let values = [
+        "DE89370400440532013000",
+        "DE89 3704 0044 0532 0130 00",
+        " gb82west12345698765432",
+        "GB82-WEST-1234-5698-7654-31",
+        "",
+        "  ",
+    ];
+    let profile = profile_column("iban", &values);
+    assert_eq!(profile.rows, 6);
+    assert_eq!(profile.empty, 2);
+    assert_eq!(profile.kind, Some(IdKind::Iban));
+    assert_eq!(profile.country, None);
+    assert_eq!(profile.countries.get("DE"), Some(&2));
+    assert_eq!(profile.countries.get("GB"), Some(&1));
+
+    let validity = profile.validity.unwrap();
+    assert_eq!(
+        (validity.total, validity.valid, validity.invalid),
+        (4, 2, 2)
+    );
+    assert_eq!(validity.reasons.get(&Failure::Checksum), Some(&1));
+    assert_eq!(validity.reasons.get(&Failure::Formatting), Some(&1));
+
+    let variants = profile.variants;
+    assert_eq!(variants.compact, 1);
+    assert_eq!(variants.spaces, 1);
+    assert_eq!(variants.hyphens, 1);
+    assert_eq!(variants.lowercase, 1);
+    assert_eq!(variants.padded, 1);
+    assert_eq!((profile.distinct, profile.duplicates), (3, 1));
+}
+
+#[test]
+fn test_personal_id_column_decodes_dob_and_gender() {
+    let mut rng = StdRng::seed_from_u64(7);
+    let registry = idsmith::personal_ids();
+    let mut values = Vec::new();
+    for (gender, year) in [
+        (Gender::Male, 1985),
+        (Gender::Female, 1987),
+        (Gender::Female, 1992),
+    ] {
+        let opts = GenOptions {
+            gender: Some(gender),
+            year: Some(year),
+            ..Default::default()
+        };
+        values.push(registry.generate("EE", &opts, &mut rng).unwrap());
+    }
+    values.push("12345".to_string());
+
+    let profile = profile_column("national_id", &values);
+    assert_eq!(profile.kind, Some(IdKind::PersonalId));
+    // LT personal codes have the same format
+    assert_eq!(profile.country.as_deref(), Some("EE"));
+    assert_eq!(profile.candidates, ["EE", "LT"]);
+    assert_eq!(profile.validity.as_ref().unwrap().valid, 3);
+    assert_eq!(profile.birth_decades.get("1980s"), Some(&2));
+    assert_eq!(profile.birth_decades.get("1990s"), Some(&1));
+    assert_eq!(profile.genders.get("female"), Some(&2));
+    assert_eq!(profile.genders.get("male"), Some(&1));
+}
+
+#[test]
+fn test_personal_id_column_uses_one_country() {
+    let mut rng = StdRng::seed_from_u64(11);
+    let registry = idsmith::personal_ids();
+    let mut values: Vec<String> = (0..20)
+        .map(|_| {
+            registry
+                .generate("SE", &GenOptions::default(), &mut rng)
+                .unwrap()
+        })
+        .collect();
+    // Valid as a Malaysian MyKad, but not in a Swedish column
+    values.push("800101-01-0000".to_string());
+    values.push("19800101-0000".to_string());
+
+    let profile = profile_column("personnummer", &values);
+    assert_eq!(profile.kind, Some(IdKind::PersonalId));
+    // DK accepts the same numbers but has no check digit
+    assert_eq!(profile.country.as_deref(), Some("SE"));
+    assert!(profile.candidates.is_empty());
+    assert_eq!(profile.countries.len(), 1);
+    let validity = profile.validity.unwrap();
+    assert_eq!((validity.valid, validity.invalid), (20, 2));
+    assert!(profile.birth_decades.keys().all(|d| d.as_str() < "2030s"));
+}
+
+#[test]
+fn test_non_identifier_column() {
+    let profile = profile_column("name", &["Alice", "Bob", "Alice"]);
+    assert_eq!(profile.kind, None);
+    assert_eq!(profile.validity, None);
+    assert!(profile.countries.is_empty());
+    assert_eq!(profile.duplicates, 1);
+}
+
+#[cfg(feature = "csv")]
+#[test]
+fn test_profile_csv() {
+    let data = "id,iban\n1,DE89370400440532013000\n2,\n3,DE89370400440532013000\n";
+    let profiles = idsmith::profile::profile_csv(data.as_bytes()).unwrap();
+    assert_eq!(profiles.len(), 2);
+    assert_eq!(profiles[0].name, "id");
+    assert_eq!(profiles[0].kind, None);
+    assert_eq!(profiles[1].kind, Some(IdKind::Iban));
+    assert_eq!(profiles[1].empty, 1);
+    assert_eq!(profiles[1].duplicates, 1);
+}
@@ -0,0 +1,113 @@
+#![cfg(feature = "proptest")]
+
+use proptest::prelude::*;
+use proptest::strategy::ValueTree;
+use proptest::test_runner::TestRunner;
+
+use idsmith::kind::IdKind;
+use idsmith::proptest as ids;
+use idsmith::{credit_card, personal_id, tax_id, Iban};
+
+proptest! {
+    #[test]
+    fn test_iban_strategy(iban in ids::iban(Some("DE"))) {
+        prop_assert_eq!(iban.country_code(), "DE");
+        prop_assert!(Iban::new(&iban).is_ok());
+    }
+
+    #[test]
+    fn test_personal_id_strategy(
+        id in ids::personal_id(
+            "SE",
+            personal_id::GenOptions {
+                year: Some(1990),
+                ..Default::default()
+            },
+        )
+    ) {
+        prop_assert_eq!(id.country(), "SE");
+        prop_assert!(idsmith::personal_ids().validate("SE", id.code()).unwrap());
+    }
+
+    #[test]
+    fn test_card_strategy(
+        card in ids::card(credit_card::GenOptions {
+            brand: Some("amex".to_string()),
+            ..Default::default()
+        })
+    ) {
+        prop_assert_eq!(card.len(), 15);
+    }
+
+    #[test]
+    fn test_identifier_strategy(code in ids::identifier(IdKind::Lei, None)) {
+        prop_assert_eq!(IdKind::Lei.validate(None, &code), Some(true));
+    }
+
+    #[test]
+    fn test_tax_id_strategy(
+        code in ids::tax_id(tax_id::GenOptions {
+            country: Some("IT".to_string()),
+            ..Default::default()
+        })
+    ) {
+        prop_assert!(idsmith::tax_ids().validate("IT", &code));
+    }
+
+    #[test]
+    fn test_invalid_strategy(code in ids::invalid(IdKind::PersonalId, Some("EE"))) {
+        prop_assert_eq!(IdKind::PersonalId.validate(Some("EE"), &code), Some(false));
+    }
+
+    #[test]
+    fn test_invalid_iban_strategy(code in ids::invalid(IdKind::Iban, None)) {
+        prop_assert_eq!(IdKind::Iban.validate(None, &code), Some(false));
+    }
+}
+
+#[test]
+fn test_shrinking_stays_valid() {
+    let mut runner = TestRunner::deterministic();
+    let mut tree = ids::vat(Some("FR")).new_tree(&mut runner).unwrap();
+    for _ in 0..64 {
+        let vat = tree.current();
+        assert!(idsmith::vat_ids().validate(&vat), "{}", vat);
+        if !tree.simplify() {
+            break;
+        }
+    }
+}
+
+#[test]
+#[should_panic(expected = "Cannot generate")]
+fn test_unsupported_country_panics() {
+    let _ = ids::personal_id("XX", Default::default());
+}
+
+#[cfg(feature = "quickcheck")]
+mod quickcheck_impls {
+    use idsmith::{Bic, CardNumber, Iban, Lei, PersonalId, VatNumber};
+    use quickcheck::{Arbitrary, Gen};
+
+    #[test]
+    fn test_arbitrary_values_are_valid() {
+        let mut g = Gen::new(10);
+        for _ in 0..50 {
+            let iban = Iban::arbitrary(&mut g);
+            assert!(Iban::new(&iban).is_ok(), "{}", iban);
+            assert!(iban.shrink().next().is_none());
+            assert!(Bic::new(&Bic::arbitrary(&mut g)).is_ok());
+            assert!(Lei::new(&Lei::arbitrary(&mut g)).is_ok());
+            assert!(VatNumber::new(&VatNumber::arbitrary(&mut g)).is_ok());
+            assert!(CardNumber::new(&CardNumber::arbitrary(&mut g)).is_ok());
+            let id = PersonalId::arbitrary(&mut g);
+            assert_eq!(id.to_string().parse::<PersonalId>(), Ok(id));
+        }
+    }
+
+    quickcheck::quickcheck! {
+        fn prop_iban_roundtrip(iban: Iban) -> bool {
+            format!("{:#}", iban).parse::<Iban>() == Ok(iban)
+        }
+    }
+}
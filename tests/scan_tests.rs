@@ -0,0 +1,160 @@
+use idsmith::kind::IdKind;
+use idsmith::scan::{mask, redact, scan, scan_with, RedactPolicy, ScanOptions};
+
+#[test]
+fn test_scan_finds_each_kind() {
+    let text = "iban DE89 3704 0044 0532 0130 00, card 4111-1111-1111-1111, \
+                ssn 446-72-2445, vat DE136695976, passport no C01X00T47";
+    let found = scan(text);
+    let kinds: Vec<IdKind> = found.iter().map(|f| f.kind).collect();
+    assert_eq!(
+        kinds,
+        [
+            IdKind::Iban,
+            IdKind::CreditCard,
+            IdKind::PersonalId,
+            IdKind::Vat,
+            IdKind::Passport
+        ]
+    );
+    assert_eq!(found[0].text, "DE89 3704 0044 0532 0130 00");
+    assert_eq!(found[2].country.as_deref(), Some("US"));
+    assert_eq!(found[3].country.as_deref(), Some("DE"));
+    for f in &found {
+        assert_eq!(&text[f.start..f.end], f.text);
+    }
+}
+
+#[test]
+fn test_scan_ignores_log_noise() {
+    let text = "2026-10-19T12:00:01.123Z INFO 127.0.0.1 request_id=8f3a2c1e-77aa-4b1c \
+                took 153ms build 1.2.3 amount=123.45";
+    assert!(scan(text).is_empty());
+}
+
+#[test]
+fn test_scan_passport_needs_keyword() {
+    assert!(scan("ref C01X00T47").is_empty());
+    let found = scan("Reisepass: C01X00T47");
+    assert_eq!(found.len(), 1);
+    assert_eq!(found[0].kind, IdKind::Passport);
+}
+
+#[test]
+fn test_scan_with_options() {
+    let text = "Swedish 811228-9874 and iban GB29NWBK60161331926819";
+    let opts = ScanOptions {
+        countries: vec!["SE".to_string()],
+        ..Default::default()
+    };
+    let found = scan_with(text, &opts);
+    assert_eq!(found[0].country.as_deref(), Some("SE"));
+
+    let opts = ScanOptions {
+        kinds: vec![IdKind::Iban],
+        ..Default::default()
+    };
+    let found = scan_with(text, &opts);
+    assert_eq!(found.len(), 1);
+    assert_eq!(found[0].kind, IdKind::Iban);
+}
+
+#[test]
+fn test_scan_non_ascii_text() {
+    let text = "Überweisung an GB29 NWBK 6016 1331 9268 19 — danke ✓";
+    let found = scan(text);
+    assert_eq!(found.len(), 1);
+    assert_eq!(
+        &text[found[0].start..found[0].end],
+        "GB29 NWBK 6016 1331 9268 19"
+    );
+}
+
+#[test]
+fn test_redact_policies() {
+    let text = "pay GB29 NWBK 6016 1331 9268 19 now";
+    assert_eq!(
+        redact(text, RedactPolicy::FullMask),
+        "pay **** **** **** **** **** ** now"
+    );
+    assert_eq!(
+        redact(text, RedactPolicy::KeepLast4),
+        "pay **** **** **** **** **68 19 now"
+    );
+    assert_eq!(
+        redact(text, RedactPolicy::KeepCountry),
+        "pay GB** **** **** **** **** ** now"
+    );
+    assert_eq!(
+        redact("nothing here", RedactPolicy::FullMask),
+        "nothing here"
+    );
+}
+
+#[test]
+fn test_mask_keep_country_only_for_prefixed_kinds() {
+    assert_eq!(
+        mask("446-72-2445", IdKind::PersonalId, RedactPolicy::KeepCountry),
+        "***-**-****"
+    );
+    assert_eq!(
+        mask("123", IdKind::PersonalId, RedactPolicy::KeepLast4),
+        "123"
+    );
+}
+
+#[test]
+fn test_redact_policy_parse() {
+    assert_eq!("last4".parse(), Ok(RedactPolicy::KeepLast4));
+    assert_eq!("FULL".parse(), Ok(RedactPolicy::FullMask));
+    assert_eq!(RedactPolicy::KeepCountry.to_string(), "country");
+    assert!("partial".parse::<RedactPolicy>().is_err());
+}
+
+#[test]
+fn test_scan_ignores_random_numbers() {
+    use rand::{Rng, SeedableRng};
+    let mut rng = rand::rngs::StdRng::seed_from_u64(27);
+    let mut log = String::new();
+    for _ in 0..2000 {
+        let order: u64 = rng.gen_range(100_000..100_000_000);
+        let request: u64 = rng.gen_range(100_000_000..1_000_000_000_000);
+        let ts: u64 = rng.gen_range(1_600_000_000..1_800_000_000);
+        let ms: u64 = rng.gen_range(1_600_000_000_000..1_800_000_000_000);
+        log.push_str(&format!(
+            "order {} request {} at {} ({} ms)\n",
+            order, request, ts, ms
+        ));
+    }
+    let found = scan(&log);
+    assert!(found.is_empty(), "{:?}", &found[..found.len().min(5)]);
+}
+
+#[test]
+fn test_scan_personal_ids_without_check_digit_need_keyword() {
+    assert!(scan("order 446722445 shipped").is_empty());
+    assert!(scan("order 446-72-2445 shipped").is_empty());
+    let found = scan("SSN: 446722445");
+    assert_eq!(found.len(), 1);
+    assert_eq!(found[0].country.as_deref(), Some("US"));
+    // Schemes with a check digit, written in their national format
+    let found = scan("ref 811228-9874");
+    assert_eq!(found[0].country.as_deref(), Some("SE"));
+    // Unseparated numbers with a weak check need the document name
+    assert!(scan("ref 8112289874").is_empty());
+    assert_eq!(scan("personnummer 8112289874").len(), 1);
+    // Listing a country opts into format-only matching
+    let opts = ScanOptions {
+        countries: vec!["US".to_string()],
+        ..Default::default()
+    };
+    assert_eq!(scan_with("order 446722445", &opts).len(), 1);
+}
+
+#[test]
+fn test_scan_cards_need_brand_prefix() {
+    // Luhn-valid, but no issuer uses the 9 prefix
+    assert!(idsmith::credit_cards().validate("9000000000000001"));
+    assert!(scan("ref 9000000000000001").is_empty());
+    assert_eq!(scan("card 4000000000000002").len(), 1);
+}
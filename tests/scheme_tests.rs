@@ -0,0 +1,176 @@
+use rand::{thread_rng, Rng, RngCore};
+
+use idsmith::{company_id, personal_id, IdScheme};
+
+/// "EMP-" + 6 digits + a mod-10 check digit.
+struct EmployeeNumber;
+
+impl EmployeeNumber {
+    fn check(body: &str) -> u32 {
+        body.bytes().map(|b| (b - b'0') as u32).sum::<u32>() % 10
+    }
+}
+
+impl IdScheme for EmployeeNumber {
+    fn name(&self) -> &str {
+        "Employee number"
+    }
+
+    fn generate(&self, rng: &mut dyn RngCore) -> String {
+        let body = format!("{:06}", rng.gen_range(0..1_000_000));
+        format!("EMP-{}-{}", body, Self::check(&body))
+    }
+
+    fn validate(&self, code: &str) -> bool {
+        let parts: Vec<&str> = code.split('-').collect();
+        parts.len() == 3
+            && parts[0] == "EMP"
+            && parts[1].len() == 6
+            && parts[1].chars().all(|c| c.is_ascii_digit())
+            && parts[2] == Self::check(parts[1]).to_string()
+    }
+
+    fn checksum(&self) -> Option<String> {
+        Some("Digit sum mod 10".to_string())
+    }
+}
+
+/// Accepts everything, to check overrides take effect.
+struct AnyCode;
+
+impl IdScheme for AnyCode {
+    fn name(&self) -> &str {
+        "Anything"
+    }
+
+    fn generate(&self, _rng: &mut dyn RngCore) -> String {
+        "ANY".to_string()
+    }
+
+    fn validate(&self, _code: &str) -> bool {
+        true
+    }
+}
+
+#[test]
+fn test_personal_register_custom_code() {
+    let registry = personal_id::Registry::new();
+    let mut rng = thread_rng();
+    registry.register("acme", EmployeeNumber).unwrap();
+
+    assert!(registry.is_supported("ACME"));
+    assert_eq!(registry.name("ACME").as_deref(), Some("Employee number"));
+    for _ in 0..20 {
+        let code = registry
+            .generate("ACME", &Default::default(), &mut rng)
+            .unwrap();
+        assert_eq!(registry.validate("ACME", &code), Some(true));
+        let parsed = registry.parse("ACME", &code).unwrap();
+        assert!(parsed.valid);
+        assert_eq!(parsed.country_code, "ACME");
+        assert!(parsed.dob.is_none());
+    }
+    assert_eq!(registry.validate("ACME", "EMP-123456-0"), Some(false));
+    assert!(registry
+        .list_countries()
+        .iter()
+        .any(|(code, _, name)| *code == "ACME" && *name == "Employee number"));
+}
+
+#[test]
+fn test_personal_register_rejects_existing_and_invalid() {
+    let registry = personal_id::Registry::new();
+    assert!(registry.register("EE", EmployeeNumber).is_err());
+    // Territories only borrow their parent's scheme, so they can get their own
+    registry.register("PR", EmployeeNumber).unwrap();
+    assert_eq!(registry.effective_country("PR").as_deref(), Some("PR"));
+    assert_eq!(registry.name("PR").as_deref(), Some("Employee number"));
+    assert!(registry.register("", EmployeeNumber).is_err());
+    assert!(registry.register("A-B", EmployeeNumber).is_err());
+    registry.register("ACME", EmployeeNumber).unwrap();
+    assert!(registry.register("ACME", EmployeeNumber).is_err());
+}
+
+#[test]
+fn test_personal_override_builtin() {
+    let registry = personal_id::Registry::new();
+    assert_eq!(registry.validate("EE", "not an id"), Some(false));
+    assert_eq!(
+        registry.checksum("EE").as_deref(),
+        Some("Weighted mod 11, two passes")
+    );
+    registry.override_scheme("EE", AnyCode).unwrap();
+    assert_eq!(registry.validate("EE", "not an id"), Some(true));
+    assert_eq!(registry.name("EE").as_deref(), Some("Anything"));
+    assert_eq!(registry.checksum("EE"), None);
+    let count = registry
+        .list_countries()
+        .iter()
+        .filter(|(code, _, _)| *code == "EE")
+        .count();
+    assert_eq!(count, 1);
+
+    // Overriding again replaces the earlier override
+    registry.override_scheme("EE", EmployeeNumber).unwrap();
+    assert_eq!(registry.validate("EE", "not an id"), Some(false));
+}
+
+#[test]
+fn test_personal_override_applies_to_territories() {
+    let registry = personal_id::Registry::new();
+    registry.override_scheme("US", AnyCode).unwrap();
+    assert_eq!(registry.validate("PR", "whatever"), Some(true));
+}
+
+#[test]
+fn test_company_register_and_override() {
+    let registry = company_id::Registry::new();
+    let mut rng = thread_rng();
+    registry.register("ACME", EmployeeNumber).unwrap();
+    assert!(registry.register("GB", EmployeeNumber).is_err());
+
+    let opts = company_id::GenOptions {
+        country: Some("acme".to_string()),
+        ..Default::default()
+    };
+    let result = registry.generate(&opts, &mut rng).unwrap();
+    assert_eq!(result.country_code, "ACME");
+    assert_eq!(result.name, "Employee number");
+    assert!(registry.validate("ACME", &result.code));
+    assert_eq!(
+        registry.checksum("ACME").as_deref(),
+        Some("Digit sum mod 10")
+    );
+
+    registry.override_scheme("GB", AnyCode).unwrap();
+    assert!(registry.validate("GB", "anything"));
+    let gb: Vec<_> = registry
+        .list_countries()
+        .into_iter()
+        .filter(|(code, _, _)| *code == "GB")
+        .collect();
+    assert_eq!(gb.len(), 1);
+    assert_eq!(gb[0].2, "Anything");
+}
+
+#[test]
+fn test_global_registries_pick_up_schemes() {
+    idsmith::personal_ids()
+        .register("EMPX", EmployeeNumber)
+        .unwrap();
+    let mut rng = thread_rng();
+    let code = idsmith::personal_ids()
+        .generate("EMPX", &Default::default(), &mut rng)
+        .unwrap();
+    assert_eq!(idsmith::personal_ids().validate("EMPX", &code), Some(true));
+
+    let text = format!("employee {} left", code);
+    let opts = idsmith::scan::ScanOptions {
+        countries: vec!["EMPX".to_string()],
+        ..Default::default()
+    };
+    let found = idsmith::scan::scan_with(&text, &opts);
+    assert_eq!(found.len(), 1);
+    assert_eq!(found[0].text, code);
+    assert_eq!(found[0].country.as_deref(), Some("EMPX"));
+}
@@ -0,0 +1,122 @@
+#![cfg(feature = "serve")]
+
+use idsmith::serve::{handle, openapi, MAX_COUNT};
+use serde_json::Value;
+
+fn get(url: &str) -> (u16, Value) {
+    let response = handle("GET", url, "");
+    (
+        response.status,
+        serde_json::from_str(&response.body).unwrap(),
+    )
+}
+
+#[test]
+fn test_generate() {
+    let (status, body) = get("/v1/iban?country=DE&count=3");
+    assert_eq!(status, 200);
+    let ibans = body.as_array().unwrap();
+    assert_eq!(ibans.len(), 3);
+    for iban in ibans {
+        assert!(idsmith::iban::validate_iban(iban["iban"].as_str().unwrap()));
+        assert_eq!(iban["country"], "DE");
+    }
+
+    let (status, body) = get("/v1/id?country=SE&gender=female&year=1990");
+    assert_eq!(status, 200);
+    assert_eq!(body[0]["gender"], "female");
+    assert!(body[0]["dob"].as_str().unwrap().starts_with("1990-"));
+
+    let (status, body) = get("/v1/card?brand=visa");
+    assert_eq!(status, 200);
+    assert_eq!(body[0]["brand"], "VISA");
+}
+
+#[test]
+fn test_generate_errors() {
+    let (status, body) = get("/v1/vat?country=XX");
+    assert_eq!(status, 400);
+    assert_eq!(body["error"], "Unsupported country for vat: XX");
+
+    let (status, _) = get(&format!("/v1/iban?count={}", MAX_COUNT + 1));
+    assert_eq!(status, 400);
+    let (status, _) = get("/v1/iban?count=many");
+    assert_eq!(status, 400);
+    let (status, _) = get("/v1/unknown");
+    assert_eq!(status, 404);
+    assert_eq!(handle("DELETE", "/v1/iban", "").status, 405);
+}
+
+#[test]
+fn test_validate() {
+    let response = handle(
+        "POST",
+        "/v1/validate",
+        r#"{"kind": "iban", "code": "DE89370400440532013000"}"#,
+    );
+    assert_eq!(response.status, 200);
+    let body: Value = serde_json::from_str(&response.body).unwrap();
+    assert_eq!(body["valid"], true);
+
+    let response = handle(
+        "POST",
+        "/v1/validate",
+        r#"[{"kind": "vat", "code": "DE123456789"}, {"kind": "id", "code": "39001010000", "country": "ee"}]"#,
+    );
+    let body: Value = serde_json::from_str(&response.body).unwrap();
+    assert_eq!(body.as_array().unwrap().len(), 2);
+    assert_eq!(body[1]["country"], "EE");
+
+    let (status, body) = get("/v1/validate?kind=swift&code=DEUTDEFF");
+    assert_eq!(status, 200);
+    assert_eq!(body["valid"], true);
+
+    assert_eq!(handle("POST", "/v1/validate", "not json").status, 400);
+    let missing_country = r#"{"kind": "id", "code": "39001010000"}"#;
+    assert_eq!(handle("POST", "/v1/validate", missing_country).status, 400);
+}
+
+#[test]
+fn test_parse_detect_describe() {
+    let (status, body) = get("/v1/parse?kind=iban&code=DE89%203704%200044%200532%200130%2000");
+    assert_eq!(status, 200);
+    assert_eq!(body["iban"], "DE89370400440532013000");
+    assert_eq!(get("/v1/parse?kind=iban&code=DE00").0, 422);
+    assert_eq!(get("/v1/parse?kind=vat&code=DE123456789").0, 400);
+
+    let (_, ids) = get("/v1/id?country=EE");
+    let code = ids[0]["code"].as_str().unwrap();
+    let (status, body) = get(&format!("/v1/parse?kind=id&country=EE&code={}", code));
+    assert_eq!(status, 200);
+    assert_eq!(body["dob"], ids[0]["dob"]);
+
+    let (status, body) = get("/v1/detect?code=DE89+3704+0044+0532+0130+00");
+    assert_eq!(status, 200);
+    assert_eq!(body["matches"][0]["kind"], "iban");
+    assert_eq!(body["matches"][0]["country"], "DE");
+
+    let (status, body) = get("/v1/describe?kind=id&country=EE");
+    assert_eq!(status, 200);
+    assert_eq!(body["min_length"], 11);
+    assert_eq!(get("/v1/describe?kind=id").0, 400);
+}
+
+#[test]
+fn test_openapi() {
+    let doc = openapi();
+    assert_eq!(doc["info"]["version"], env!("CARGO_PKG_VERSION"));
+    let paths = doc["paths"].as_object().unwrap();
+    for path in [
+        "/v1/{kind}",
+        "/v1/validate",
+        "/v1/parse",
+        "/v1/detect",
+        "/v1/describe",
+        "/health",
+    ] {
+        assert!(paths.contains_key(path), "{}", path);
+    }
+    let (status, served) = get("/v1/openapi.json");
+    assert_eq!(status, 200);
+    assert_eq!(served, doc);
+}
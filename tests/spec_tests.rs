@@ -0,0 +1,254 @@
+#![cfg(feature = "spec")]
+
+use rand::thread_rng;
+
+use idsmith::spec::{Spec, SpecRegistry};
+
+fn single(json: &str) -> Spec {
+    Spec::from_json(json).unwrap().remove(0)
+}
+
+fn checksum_spec(digits: &str, algorithm: &str) -> Spec {
+    single(&format!(
+        r#"{{"name": "T", "country": "TST", "segments": [
+            {{"type": "literal", "value": "{}"}},
+            {{"type": "checksum", "algorithm": "{}", "include_literals": true}}
+        ]}}"#,
+        digits, algorithm
+    ))
+}
+
+#[test]
+fn test_checksum_catalogue_reference_values() {
+    // Reference values from the ISO 7064 and Luhn examples
+    let cases = [
+        ("7992739871", "luhn", "79927398713"),
+        ("236", "verhoeff", "2363"),
+        ("794", "mod97", "79444"),
+        ("079", "iso7064_mod11_2", "079X"),
+        ("0794", "iso7064_mod11_10", "07945"),
+        ("G123498654321", "iso7064_mod37_2", "G123498654321H"),
+        (
+            "A12425GABC1234002",
+            "iso7064_mod37_36",
+            "A12425GABC1234002M",
+        ),
+    ];
+    for (input, algorithm, expected) in cases {
+        let spec = checksum_spec(input, algorithm);
+        let mut rng = thread_rng();
+        assert_eq!(spec.generate(&mut rng).unwrap(), expected, "{}", algorithm);
+        assert!(spec.validate(expected), "{}", algorithm);
+    }
+}
+
+#[test]
+fn test_weighted_mod11() {
+    // Norwegian-style organisation number: 8 digits, weights 3 2 7 6 5 4 3 2
+    let spec = single(
+        r#"{"name": "Org", "country": "TST", "segments": [
+            {"type": "digits", "length": 8},
+            {"type": "checksum", "algorithm": "weighted", "weights": [3, 2, 7, 6, 5, 4, 3, 2]}
+        ]}"#,
+    );
+    assert!(spec.validate("923609016"));
+    assert!(!spec.validate("923609017"));
+    let mut rng = thread_rng();
+    for _ in 0..100 {
+        let code = spec.generate(&mut rng).unwrap();
+        assert_eq!(code.len(), 9);
+        assert!(spec.validate(&code), "{}", code);
+    }
+}
+
+#[test]
+fn test_declares_checksum_algorithms() {
+    use idsmith::IdScheme;
+
+    let weighted = single(
+        r#"{"name": "T", "country": "TST", "segments": [
+            {"type": "digits", "length": 8},
+            {"type": "checksum", "algorithm": "weighted", "weights": [3, 2], "modulus": 10},
+            {"type": "checksum", "algorithm": "luhn"}
+        ]}"#,
+    );
+    assert_eq!(
+        weighted.checksum().as_deref(),
+        Some("Weighted mod 10 and Luhn")
+    );
+    let plain =
+        single(r#"{"name": "T", "country": "TST", "segments": [{"type": "digits", "length": 3}]}"#);
+    assert_eq!(plain.checksum(), None);
+}
+
+#[test]
+fn test_segments_round_trip() {
+    let spec = single(
+        r#"{"name": "Mixed", "country": "MIX", "registry": "personal", "segments": [
+            {"type": "choice", "values": ["A", "BC"]},
+            {"type": "literal", "value": "-"},
+            {"type": "date", "format": "YYMMDD", "min_year": 1990, "max_year": 1999},
+            {"type": "range", "min": 1, "max": 250, "width": 3},
+            {"type": "charset", "chars": "XYZ", "length": 2},
+            {"type": "alphanumeric", "length": 3},
+            {"type": "checksum", "algorithm": "iso7064_mod37_36"}
+        ]}"#,
+    );
+    assert_eq!(spec.registry, SpecRegistry::Personal);
+    let mut rng = thread_rng();
+    for _ in 0..200 {
+        let code = spec.generate(&mut rng).unwrap();
+        assert!(spec.validate(&code), "{}", code);
+        assert!(code.starts_with("A-9") || code.starts_with("BC-9"));
+    }
+    assert!(!spec.validate("A-991301001XY1234"));
+    assert!(!spec.validate(""));
+}
+
+#[test]
+fn test_date_validation() {
+    let spec = single(
+        r#"{"name": "D", "country": "TST", "segments": [
+            {"type": "date", "format": "YYYY-MM-DD"}
+        ]}"#,
+    );
+    assert!(spec.validate("2000-02-29"));
+    assert!(!spec.validate("1900-02-29"));
+    assert!(!spec.validate("2021-13-01"));
+    assert!(!spec.validate("2021-04-31"));
+    assert!(!spec.validate("2021/04/30"));
+}
+
+#[test]
+fn test_from_toml_single_and_many() {
+    let one = Spec::from_toml(
+        r#"
+name = "ACME customer number"
+country = "ACME"
+
+[[segments]]
+type = "literal"
+value = "CU-"
+
+[[segments]]
+type = "digits"
+length = 8
+
+[[segments]]
+type = "checksum"
+algorithm = "luhn"
+"#,
+    )
+    .unwrap();
+    assert_eq!(one.len(), 1);
+    assert_eq!(one[0].registry, SpecRegistry::Company);
+
+    let many = Spec::from_toml(
+        r#"
+[[spec]]
+name = "One"
+country = "ONE"
+segments = [{ type = "digits", length = 4 }]
+
+[[spec]]
+name = "Two"
+country = "TWO"
+override = true
+segments = [{ type = "letters", length = 4 }]
+"#,
+    )
+    .unwrap();
+    assert_eq!(many.len(), 2);
+    assert!(many[1].replace);
+}
+
+#[test]
+fn test_invalid_specs_rejected() {
+    let bad = [
+        r#"{"name": "X", "country": "X", "segments": []}"#,
+        r#"{"name": "X", "country": "X-Y", "segments": [{"type": "digits", "length": 1}]}"#,
+        r#"{"name": "X", "country": "X", "segments": [{"type": "digits", "length": 0}]}"#,
+        r#"{"name": "X", "country": "X", "segments": [{"type": "range", "min": 5, "max": 1}]}"#,
+        r#"{"name": "X", "country": "X", "segments": [{"type": "bogus"}]}"#,
+        r#"{"name": "X", "country": "X", "segments": [{"type": "digits", "length": 2, "extra": 1}]}"#,
+        r#"{"name": "X", "country": "X", "segments": [
+            {"type": "letters", "length": 2}, {"type": "checksum", "algorithm": "luhn"}]}"#,
+        r#"{"name": "X", "country": "X", "segments": [
+            {"type": "digits", "length": 2}, {"type": "checksum", "algorithm": "weighted"}]}"#,
+        r#"{"name": "X", "country": "X", "segments": [
+            {"type": "digits", "length": 2}, {"type": "checksum", "algorithm": "crc32"}]}"#,
+        // The weighted value is always 10 and there is no `ten` mapping
+        r#"{"name": "X", "country": "X", "segments": [
+            {"type": "literal", "value": "1"},
+            {"type": "checksum", "algorithm": "weighted", "weights": [1], "include_literals": true}]}"#,
+        // Date separators cannot be covered by a checksum
+        r#"{"name": "X", "country": "X", "segments": [
+            {"type": "date", "format": "YY-MM"},
+            {"type": "checksum", "algorithm": "iso7064_mod37_36"}]}"#,
+    ];
+    for json in bad {
+        assert!(Spec::from_json(json).is_err(), "{}", json);
+    }
+
+    let ten = single(
+        r#"{"name": "X", "country": "X", "segments": [
+            {"type": "literal", "value": "1"},
+            {"type": "checksum", "algorithm": "weighted", "weights": [1], "include_literals": true,
+             "ten": "X"}]}"#,
+    );
+    assert_eq!(ten.generate(&mut thread_rng()).unwrap(), "1X");
+}
+
+#[test]
+fn test_register_with_global_registries() {
+    let specs = Spec::from_json(
+        r#"[
+            {"name": "Spec company", "country": "SPECCO", "segments": [
+                {"type": "literal", "value": "SC"},
+                {"type": "digits", "length": 7},
+                {"type": "checksum", "algorithm": "mod97"}
+            ]},
+            {"name": "Spec person", "country": "SPECPE", "registry": "personal", "segments": [
+                {"type": "date", "format": "YYMMDD"},
+                {"type": "digits", "length": 3},
+                {"type": "checksum", "algorithm": "verhoeff"}
+            ]}
+        ]"#,
+    )
+    .unwrap();
+    for spec in specs {
+        spec.register().unwrap();
+    }
+
+    let mut rng = thread_rng();
+    let opts = idsmith::company_id::GenOptions {
+        country: Some("SPECCO".to_string()),
+        ..Default::default()
+    };
+    let company = idsmith::company_ids().generate(&opts, &mut rng).unwrap();
+    assert_eq!(company.name, "Spec company");
+    assert!(idsmith::company_ids().validate("SPECCO", &company.code));
+
+    let id = idsmith::personal_ids()
+        .generate("SPECPE", &Default::default(), &mut rng)
+        .unwrap();
+    assert_eq!(id.len(), 10);
+    assert_eq!(idsmith::personal_ids().validate("SPECPE", &id), Some(true));
+
+    assert_eq!(
+        idsmith::company_ids().checksum("SPECCO").as_deref(),
+        Some("ISO 7064 MOD 97-10")
+    );
+    assert_eq!(
+        idsmith::personal_ids().checksum("SPECPE").as_deref(),
+        Some("Verhoeff")
+    );
+}
+
+#[test]
+fn test_register_existing_country_needs_override() {
+    let spec = single(
+        r#"{"name": "Fake GB", "country": "GB", "segments": [{"type": "digits", "length": 3}]}"#,
+    );
+    assert!(spec.clone().register().is_err());
+}
@@ -0,0 +1,129 @@
+#![cfg(feature = "sql")]
+
+use rand::thread_rng;
+
+use idsmith::sql::{self, Column, Dialect, Options, SqlType, ToSql, Value, Writer};
+use idsmith::swift::SwiftResult;
+
+fn render(columns: Vec<Column>, rows: &[Vec<Value>], opts: Options) -> String {
+    let mut writer = Writer::new(Vec::new(), "t", columns, opts).unwrap();
+    for row in rows {
+        writer.write_values(row).unwrap();
+    }
+    String::from_utf8(writer.finish().unwrap()).unwrap()
+}
+
+fn text_columns() -> Vec<Column> {
+    vec![
+        Column::new("a", SqlType::Text, false),
+        Column::new("b", SqlType::Text, true),
+    ]
+}
+
+#[test]
+fn test_parse_dialect() {
+    assert_eq!("PostgreSQL".parse::<Dialect>(), Ok(Dialect::Postgres));
+    assert_eq!("mariadb".parse::<Dialect>(), Ok(Dialect::Mysql));
+    assert_eq!("sqlite".parse::<Dialect>(), Ok(Dialect::Sqlite));
+    assert!("oracle".parse::<Dialect>().is_err());
+}
+
+#[test]
+fn test_literals() {
+    let text = Value::Text("O'Brien \\ co".into());
+    assert_eq!(Dialect::Postgres.literal(&text), "'O''Brien \\ co'");
+    assert_eq!(Dialect::Mysql.literal(&text), "'O''Brien \\\\ co'");
+    assert_eq!(Dialect::Sqlite.literal(&Value::Bool(true)), "1");
+    assert_eq!(Dialect::Postgres.literal(&Value::Bool(false)), "FALSE");
+    assert_eq!(Dialect::Mysql.literal(&Value::Null), "NULL");
+    assert_eq!(Dialect::Mysql.identifier("a`b"), "`a``b`");
+    assert_eq!(Dialect::Sqlite.identifier("a\"b"), "\"a\"\"b\"");
+}
+
+#[test]
+fn test_batched_inserts() {
+    let rows: Vec<Vec<Value>> = (0..5)
+        .map(|i| vec![Value::Text(i.to_string()), Value::Null])
+        .collect();
+    let opts = Options {
+        batch_size: 2,
+        ..Default::default()
+    };
+    let out = render(text_columns(), &rows, opts);
+    assert_eq!(
+        out.matches("INSERT INTO \"t\" (\"a\", \"b\") VALUES")
+            .count(),
+        3
+    );
+    assert!(out.contains("('0', NULL),\n('1', NULL);"));
+    assert!(out.ends_with("('4', NULL);\n"));
+}
+
+#[test]
+fn test_copy() {
+    let rows = vec![vec![Value::Text("tab\there".into()), Value::Null]];
+    let opts = Options {
+        copy: true,
+        create_table: true,
+        ..Default::default()
+    };
+    let out = render(text_columns(), &rows, opts);
+    assert_eq!(
+        out,
+        "CREATE TABLE IF NOT EXISTS \"t\" (\n  \"a\" TEXT NOT NULL,\n  \"b\" TEXT\n);\n\
+         COPY \"t\" (\"a\", \"b\") FROM STDIN;\ntab\\there\t\\N\n\\.\n"
+    );
+
+    let mysql = Options {
+        dialect: Dialect::Mysql,
+        copy: true,
+        ..Default::default()
+    };
+    assert!(Writer::new(Vec::new(), "t", text_columns(), mysql).is_err());
+}
+
+#[test]
+fn test_empty_output_keeps_preamble() {
+    let opts = Options {
+        dialect: Dialect::Mysql,
+        create_table: true,
+        ..Default::default()
+    };
+    let out = render(text_columns(), &[], opts);
+    assert!(out.starts_with("CREATE TABLE IF NOT EXISTS `t` (\n  `a` VARCHAR(255) NOT NULL,"));
+    assert!(!out.contains("INSERT"));
+}
+
+#[test]
+fn test_result_columns() {
+    let columns = SwiftResult::sql_columns();
+    let names: Vec<&str> = columns.iter().map(|c| c.name.as_str()).collect();
+    assert_eq!(
+        names,
+        ["code", "bank", "country", "location", "branch", "valid"]
+    );
+    assert!(columns[4].nullable && !columns[0].nullable);
+    assert_eq!(columns[5].ty, SqlType::Boolean);
+
+    let mut rng = thread_rng();
+    let result = idsmith::swift_codes().generate(&Default::default(), &mut rng);
+    let values = result.sql_values();
+    assert_eq!(values[0], Value::Text(result.code.to_string()));
+    assert_eq!(values[5], Value::Bool(true));
+
+    let ids: Vec<_> = (0..3)
+        .map(|_| {
+            let code = idsmith::personal_ids()
+                .generate("SE", &Default::default(), &mut rng)
+                .unwrap();
+            idsmith::personal_ids().parse("SE", &code).unwrap()
+        })
+        .collect();
+    let mut out = Vec::new();
+    sql::write_sql(&mut out, "people", &ids, Options::default()).unwrap();
+    let out = String::from_utf8(out).unwrap();
+    assert!(out.starts_with(
+        "INSERT INTO \"people\" (\"country_code\", \"effective_country_code\", \"code\", \"gender\", \"dob\", \"region\", \"valid\") VALUES"
+    ));
+    assert_eq!(out.matches(", NULL, TRUE)").count(), 3);
+}
@@ -19,6 +19,7 @@ fn test_all_specific_countries_generate_valid() {
             let opts = GenOptions {
                 country: Some(cc.to_string()),
                 holder_type: None,
+                ..Default::default()
             };
             let result = registry
                 .generate(&opts, &mut rng)
@@ -43,6 +44,7 @@ fn test_india_pan_format() {
     let opts = GenOptions {
         country: Some("IN".to_string()),
         holder_type: None,
+        ..Default::default()
     };
     let result = registry.generate(&opts, &mut rng).unwrap();
     assert_eq!(result.code.len(), 10);
@@ -57,10 +59,30 @@ fn test_india_pan_with_holder_type() {
     let opts = GenOptions {
         country: Some("IN".to_string()),
         holder_type: Some("P".to_string()),
+        ..Default::default()
     };
     let result = registry.generate(&opts, &mut rng).unwrap();
     assert_eq!(result.holder_type, Some("P".to_string()));
     assert_eq!(result.code.chars().nth(3), Some('P'));
+
+    // Holder types PAN does not have are ignored rather than encoded
+    let opts = GenOptions {
+        holder_type: Some("entity".to_string()),
+        ..opts
+    };
+    for _ in 0..20 {
+        let result = registry.generate(&opts, &mut rng).unwrap();
+        assert!(registry.validate("IN", &result.code), "{}", result.code);
+    }
+
+    let types = registry.holder_types("in").unwrap();
+    assert_eq!(types.len(), 10);
+    assert!(types.contains(&"P".to_string()));
+    assert_eq!(
+        registry.holder_types("RU"),
+        Some(vec!["entity".to_string(), "individual".to_string()])
+    );
+    assert_eq!(registry.holder_types("DE"), None);
 }
 
 #[test]
@@ -93,6 +115,7 @@ fn test_de_steuer_idnr_checksum() {
         let opts = GenOptions {
             country: Some("DE".to_string()),
             holder_type: None,
+            ..Default::default()
         };
         let result = registry.generate(&opts, &mut rng).unwrap();
         assert_eq!(result.code.len(), 11);
@@ -115,6 +138,7 @@ fn test_gb_utr_checksum() {
         let opts = GenOptions {
             country: Some("GB".to_string()),
             holder_type: None,
+            ..Default::default()
         };
         let result = registry.generate(&opts, &mut rng).unwrap();
         assert_eq!(result.code.len(), 10);
@@ -135,6 +159,7 @@ fn test_fr_nif_mod97() {
         let opts = GenOptions {
             country: Some("FR".to_string()),
             holder_type: None,
+            ..Default::default()
         };
         let result = registry.generate(&opts, &mut rng).unwrap();
         assert_eq!(result.code.len(), 13);
@@ -155,6 +180,7 @@ fn test_cn_usci_checksum() {
         let opts = GenOptions {
             country: Some("CN".to_string()),
             holder_type: None,
+            ..Default::default()
         };
         let result = registry.generate(&opts, &mut rng).unwrap();
         assert_eq!(result.code.len(), 18);
@@ -175,6 +201,7 @@ fn test_br_cpf_checksum() {
         let opts = GenOptions {
             country: Some("BR".to_string()),
             holder_type: None,
+            ..Default::default()
         };
         let result = registry.generate(&opts, &mut rng).unwrap();
         assert_eq!(result.code.len(), 11);
@@ -195,6 +222,7 @@ fn test_au_tfn_checksum() {
         let opts = GenOptions {
             country: Some("AU".to_string()),
             holder_type: None,
+            ..Default::default()
         };
         let result = registry.generate(&opts, &mut rng).unwrap();
         assert_eq!(result.code.len(), 9);
@@ -215,6 +243,7 @@ fn test_ca_sin_luhn() {
         let opts = GenOptions {
             country: Some("CA".to_string()),
             holder_type: None,
+            ..Default::default()
         };
         let result = registry.generate(&opts, &mut rng).unwrap();
         assert_eq!(result.code.len(), 9);
@@ -235,6 +264,7 @@ fn test_jp_my_number_checksum() {
         let opts = GenOptions {
             country: Some("JP".to_string()),
             holder_type: None,
+            ..Default::default()
         };
         let result = registry.generate(&opts, &mut rng).unwrap();
         assert_eq!(result.code.len(), 12);
@@ -255,6 +285,7 @@ fn test_it_partita_iva_checksum() {
         let opts = GenOptions {
             country: Some("IT".to_string()),
             holder_type: None,
+            ..Default::default()
         };
         let result = registry.generate(&opts, &mut rng).unwrap();
         assert_eq!(result.code.len(), 11);
@@ -276,6 +307,7 @@ fn test_es_nif_checksum() {
         let opts = GenOptions {
             country: Some("ES".to_string()),
             holder_type: None,
+            ..Default::default()
         };
         let result = registry.generate(&opts, &mut rng).unwrap();
         assert_eq!(result.code.len(), 9);
@@ -296,6 +328,7 @@ fn test_nl_bsn_11check() {
         let opts = GenOptions {
             country: Some("NL".to_string()),
             holder_type: None,
+            ..Default::default()
         };
         let result = registry.generate(&opts, &mut rng).unwrap();
         assert_eq!(result.code.len(), 9);
@@ -316,6 +349,7 @@ fn test_se_personnummer_luhn() {
         let opts = GenOptions {
             country: Some("SE".to_string()),
             holder_type: None,
+            ..Default::default()
         };
         let result = registry.generate(&opts, &mut rng).unwrap();
         assert_eq!(result.code.len(), 10);
@@ -336,6 +370,7 @@ fn test_kr_brn_checksum() {
         let opts = GenOptions {
             country: Some("KR".to_string()),
             holder_type: None,
+            ..Default::default()
         };
         let result = registry.generate(&opts, &mut rng).unwrap();
         assert_eq!(result.code.len(), 10);
@@ -356,6 +391,7 @@ fn test_sg_nric_checksum() {
         let opts = GenOptions {
             country: Some("SG".to_string()),
             holder_type: None,
+            ..Default::default()
         };
         let result = registry.generate(&opts, &mut rng).unwrap();
         assert_eq!(result.code.len(), 9);
@@ -376,6 +412,7 @@ fn test_za_tax_number_luhn() {
         let opts = GenOptions {
             country: Some("ZA".to_string()),
             holder_type: None,
+            ..Default::default()
         };
         let result = registry.generate(&opts, &mut rng).unwrap();
         assert_eq!(result.code.len(), 10);
@@ -396,6 +433,7 @@ fn test_mx_rfc_checksum() {
         let opts = GenOptions {
             country: Some("MX".to_string()),
             holder_type: None,
+            ..Default::default()
         };
         let result = registry.generate(&opts, &mut rng).unwrap();
         assert!(
@@ -421,6 +459,7 @@ fn test_generic_countries() {
         let opts = GenOptions {
             country: Some(country.to_string()),
             holder_type: None,
+            ..Default::default()
         };
         let result = registry.generate(&opts, &mut rng).expect(country);
         assert_eq!(result.country_code, country);
@@ -436,6 +475,7 @@ fn test_unknown_country_returns_none() {
     let opts = GenOptions {
         country: Some("ZZ".to_string()),
         holder_type: None,
+        ..Default::default()
     };
     assert!(registry.generate(&opts, &mut rng).is_none());
 }
@@ -463,3 +503,43 @@ fn test_random_country_generation() {
         assert!(!result.code.is_empty());
     }
 }
+
+#[test]
+fn test_territory_aliases() {
+    let registry = Registry::new();
+    let mut rng = thread_rng();
+    for (cc, parent) in [("PR", "US"), ("GF", "FR"), ("FO", "DK"), ("EH", "MA")] {
+        let opts = GenOptions {
+            country: Some(cc.to_lowercase()),
+            ..Default::default()
+        };
+        let result = registry
+            .generate(&opts, &mut rng)
+            .unwrap_or_else(|| panic!("{}: generate failed", cc));
+        assert_eq!(result.country_code, cc);
+        assert_eq!(result.effective_country_code, parent);
+        assert!(registry.validate(cc, &result.code));
+        assert_eq!(registry.effective_country(cc).as_deref(), Some(parent));
+    }
+    assert_eq!(registry.effective_country("US").as_deref(), Some("US"));
+    assert!(registry.effective_country("ZZ").is_none());
+}
+
+#[test]
+fn test_strict_disables_fallback() {
+    let registry = Registry::new();
+    let mut rng = thread_rng();
+    let opts = GenOptions {
+        country: Some("PR".to_string()),
+        strict: true,
+        ..Default::default()
+    };
+    assert!(registry.generate(&opts, &mut rng).is_none());
+    let opts = GenOptions {
+        country: Some("US".to_string()),
+        strict: true,
+        ..Default::default()
+    };
+    let result = registry.generate(&opts, &mut rng).unwrap();
+    assert_eq!(result.effective_country_code, "US");
+}
@@ -0,0 +1,112 @@
+use rand::thread_rng;
+
+use idsmith::{Bic, CardNumber, Iban, Lei, PersonalId, VatNumber};
+
+#[test]
+fn test_iban() {
+    let iban: Iban = " gb29 nwbk 6016 1331 9268 19 ".parse().unwrap();
+    assert_eq!(iban.as_str(), "GB29NWBK60161331926819");
+    assert_eq!(iban.to_string(), "GB29NWBK60161331926819");
+    assert_eq!(format!("{:#}", iban), "GB29 NWBK 6016 1331 9268 19");
+    assert_eq!(
+        (iban.country_code(), iban.check_digits(), iban.bban()),
+        ("GB", "29", "NWBK60161331926819")
+    );
+    assert!("GB28NWBK60161331926819".parse::<Iban>().is_err());
+    assert!("".parse::<Iban>().is_err());
+}
+
+#[test]
+fn test_bic_lei_vat() {
+    let bic: Bic = "deutdeff500".parse().unwrap();
+    assert_eq!(bic, "DEUTDEFF500");
+    assert_eq!((bic.bank_code(), bic.country_code()), ("DEUT", "DE"));
+    assert_eq!(bic.branch_code(), Some("500"));
+    assert_eq!(Bic::new("DEUTDEFF").unwrap().branch_code(), None);
+    assert!(Bic::new("DEUT").is_err());
+
+    let lei: Lei = "5493001KJTIIGC8Y1R12".parse().unwrap();
+    assert_eq!(lei.lou(), "5493");
+    assert!(Lei::new("5493001KJTIIGC8Y1R13").is_err());
+
+    let vat: VatNumber = "de 123-456-788".parse().unwrap();
+    assert_eq!(vat.to_string(), "DE123456788");
+    assert_eq!(vat.country_prefix(), "DE");
+    assert!(VatNumber::new("DE123456789").is_err());
+    assert!(VatNumber::new("ÄB123456789").is_err());
+}
+
+#[test]
+fn test_card_number() {
+    let card: CardNumber = "4111-1111-1111-1111".parse().unwrap();
+    assert_eq!(card.to_string(), "4111111111111111");
+    assert_eq!(format!("{:#}", card), "4111 1111 1111 1111");
+    let amex = CardNumber::new("378282246310005").unwrap();
+    assert_eq!(format!("{:#}", amex), "3782 822463 10005");
+    assert!(CardNumber::new("4111111111111112").is_err());
+    assert!(CardNumber::new("4111x111111111111").is_err());
+}
+
+#[test]
+fn test_personal_id() {
+    let id: PersonalId = "ee:38001085718".parse().unwrap();
+    assert_eq!((id.country(), id.code()), ("EE", "38001085718"));
+    assert_eq!(id.to_string(), "EE:38001085718");
+    assert_eq!(PersonalId::new("EE", "38001085718").unwrap(), id);
+
+    assert!("EE:38001085719".parse::<PersonalId>().is_err());
+    assert!("38001085718".parse::<PersonalId>().is_err());
+    let err = PersonalId::new("XX", "123").unwrap_err();
+    assert!(err.contains("Unsupported"), "{}", err);
+}
+
+#[test]
+fn test_generated_results_are_typed() {
+    let mut rng = thread_rng();
+    for _ in 0..50 {
+        let vat = idsmith::vat_ids()
+            .generate(&Default::default(), &mut rng)
+            .unwrap();
+        assert_eq!(vat.code.parse::<VatNumber>().unwrap(), vat.code);
+
+        let card = idsmith::credit_cards()
+            .generate(&Default::default(), &mut rng)
+            .unwrap();
+        assert_eq!(CardNumber::new(&card.formatted).unwrap(), card.number);
+
+        let bic = idsmith::swift_codes().generate(&Default::default(), &mut rng);
+        assert!(Bic::new(&bic.code).is_ok());
+
+        let lei = idsmith::lei_codes().generate(&Default::default(), &mut rng);
+        assert!(Lei::new(&lei.code).is_ok());
+
+        let account = idsmith::bank_accounts()
+            .generate("DE", &Default::default(), &mut rng)
+            .unwrap();
+        let iban = account.iban.unwrap();
+        assert_eq!(Iban::new(&format!("{:#}", iban)).unwrap(), iban);
+    }
+}
+
+#[cfg(feature = "json")]
+#[test]
+fn test_serde() {
+    let iban: Iban = serde_json::from_str("\"DE89 3704 0044 0532 0130 00\"").unwrap();
+    assert_eq!(
+        serde_json::to_string(&iban).unwrap(),
+        "\"DE89370400440532013000\""
+    );
+    assert!(serde_json::from_str::<Iban>("\"DE00370400440532013000\"").is_err());
+    assert!(serde_json::from_str::<VatNumber>("\"DE123456789\"").is_err());
+
+    let id: PersonalId =
+        serde_json::from_str(r#"{"country": "EE", "code": "38001085718"}"#).unwrap();
+    assert_eq!(
+        serde_json::to_string(&id).unwrap(),
+        r#"{"country":"EE","code":"38001085718"}"#
+    );
+    let err = serde_json::from_str::<PersonalId>(r#"{"country": "EE", "code": "1"}"#)
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("Invalid personal ID"), "{}", err);
+}
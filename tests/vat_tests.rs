@@ -9,6 +9,7 @@ fn test_generate_and_validate_all_countries() {
     for (code, _name) in registry.list_countries() {
         let opts = GenOptions {
             country: Some(code.to_string()),
+            ..Default::default()
         };
         for _ in 0..20 {
             let result = registry.generate(&opts, &mut rng).unwrap();
@@ -55,6 +56,7 @@ fn test_greece_gr_alias() {
     // Generate with GR should produce EL prefix
     let opts = GenOptions {
         country: Some("GR".to_string()),
+        ..Default::default()
     };
     let result = registry.generate(&opts, &mut rng).unwrap();
     assert_eq!(result.country_code, "EL");
@@ -74,6 +76,7 @@ fn test_checksum_corruption_detected() {
     for (code, _) in registry.list_countries() {
         let opts = GenOptions {
             country: Some(code.to_string()),
+            ..Default::default()
         };
         let result = registry.generate(&opts, &mut rng).unwrap();
 
@@ -112,6 +115,7 @@ fn test_unsupported_country_returns_none() {
     let mut rng = thread_rng();
     let opts = GenOptions {
         country: Some("XX".to_string()),
+        ..Default::default()
     };
     assert!(registry.generate(&opts, &mut rng).is_none());
 }
@@ -124,6 +128,7 @@ fn test_specific_known_formats() {
     // DE: should be DE + 9 digits
     let opts = GenOptions {
         country: Some("DE".to_string()),
+        ..Default::default()
     };
     let result = registry.generate(&opts, &mut rng).unwrap();
     assert_eq!(result.code.len(), 11); // DE + 9
@@ -132,6 +137,7 @@ fn test_specific_known_formats() {
     // AT: should be ATU + 8 digits
     let opts = GenOptions {
         country: Some("AT".to_string()),
+        ..Default::default()
     };
     let result = registry.generate(&opts, &mut rng).unwrap();
     assert!(result.code.starts_with("ATU"));
@@ -140,6 +146,7 @@ fn test_specific_known_formats() {
     // NL: should be NL + 9digits + B + 2digits
     let opts = GenOptions {
         country: Some("NL".to_string()),
+        ..Default::default()
     };
     let result = registry.generate(&opts, &mut rng).unwrap();
     assert_eq!(result.code.len(), 14); // NL + 12
@@ -148,6 +155,7 @@ fn test_specific_known_formats() {
     // SE: should end with "01"
     let opts = GenOptions {
         country: Some("SE".to_string()),
+        ..Default::default()
     };
     let result = registry.generate(&opts, &mut rng).unwrap();
     assert_eq!(result.code.len(), 14); // SE + 12
@@ -156,6 +164,7 @@ fn test_specific_known_formats() {
     // CY: should end with a letter
     let opts = GenOptions {
         country: Some("CY".to_string()),
+        ..Default::default()
     };
     let result = registry.generate(&opts, &mut rng).unwrap();
     assert_eq!(result.code.len(), 11); // CY + 9
@@ -171,3 +180,34 @@ fn test_list_countries() {
     assert!(countries.iter().any(|(c, _)| *c == "EL"));
     assert!(!countries.iter().any(|(c, _)| *c == "GR"));
 }
+
+#[test]
+fn test_no_territory_fallback() {
+    // Territories are outside the EU VAT area and issue no VAT numbers
+    let registry = Registry::new();
+    let mut rng = thread_rng();
+    for country in ["FO", "GL", "GF", "NC"] {
+        let opts = GenOptions {
+            country: Some(country.to_string()),
+            ..Default::default()
+        };
+        assert!(registry.generate(&opts, &mut rng).is_none(), "{}", country);
+        assert_eq!(registry.effective_country(country), None);
+    }
+    assert_eq!(registry.effective_country("gr").as_deref(), Some("EL"));
+}
+
+#[test]
+fn test_country_group() {
+    let registry = Registry::new();
+    let mut rng = thread_rng();
+    let opts = GenOptions {
+        country: Some("eurozone".to_string()),
+        ..Default::default()
+    };
+    for _ in 0..20 {
+        let result = registry.generate(&opts, &mut rng).unwrap();
+        assert!(registry.validate(&result.code));
+        assert!(!["DK", "SE", "PL", "GB"].contains(&result.country_code.as_str()));
+    }
+}
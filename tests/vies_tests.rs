@@ -0,0 +1,170 @@
+#![cfg(feature = "serve")]
+
+use std::time::Duration;
+
+use idsmith::serve::vies::{Fault, Mock, Options, SOAP_PATH};
+use serde_json::Value;
+
+fn generated_vat(country: &str) -> String {
+    let opts = idsmith::vat::GenOptions {
+        country: Some(country.to_string()),
+        ..Default::default()
+    };
+    let vat = idsmith::vat_ids()
+        .generate(&opts, &mut rand::thread_rng())
+        .unwrap();
+    vat.code.as_str()[2..].to_string()
+}
+
+fn soap_request(country: &str, number: &str) -> String {
+    format!(
+        r#"<soapenv:Envelope xmlns:soapenv="http://schemas.xmlsoap.org/soap/envelope/" xmlns:urn="urn:ec.europa.eu:taxud:vies:services:checkVat:types">
+  <soapenv:Header/>
+  <soapenv:Body>
+    <urn:checkVat>
+      <urn:countryCode>{}</urn:countryCode>
+      <urn:vatNumber>{}</urn:vatNumber>
+    </urn:checkVat>
+  </soapenv:Body>
+</soapenv:Envelope>"#,
+        country, number
+    )
+}
+
+#[test]
+fn test_check() {
+    let mock = Mock::default();
+    for cc in ["DE", "FR", "EL", "NL", "AT"] {
+        let number = generated_vat(cc);
+        let check = mock.check(cc, &number).unwrap();
+        assert!(check.valid, "{}{}", cc, number);
+        assert_ne!(check.name, "---");
+        assert!(check.address.contains('\n'));
+        assert_eq!(mock.check(cc, &number).unwrap(), check);
+    }
+
+    let check = mock.check("DE", "123456789").unwrap();
+    assert!(!check.valid);
+    assert_eq!(check.name, "---");
+
+    assert_eq!(mock.check("GB", "123").unwrap_err(), Fault::InvalidInput);
+    assert_eq!(mock.check("US", "123").unwrap_err(), Fault::InvalidInput);
+    assert!(mock.check("XI", &generated_vat("GB")).unwrap().valid);
+}
+
+#[test]
+fn test_test_service_numbers() {
+    let mock = Mock::default();
+    assert!(mock.check("PL", "100").unwrap().valid);
+    assert!(!mock.check("PL", "200").unwrap().valid);
+    assert_eq!(mock.check("PL", "201").unwrap_err(), Fault::InvalidInput);
+    assert_eq!(mock.check("PL", "301").unwrap_err(), Fault::MsUnavailable);
+    assert_eq!(mock.check("PL", "302").unwrap_err(), Fault::Timeout);
+}
+
+#[test]
+fn test_simulated_failures() {
+    let mock = Mock::new(Options {
+        unavailable: vec!["FR".into()],
+        timeout: vec!["it".into()],
+        invalid: vec!["DE".into()],
+        ..Default::default()
+    });
+    assert_eq!(
+        mock.check("FR", &generated_vat("FR")).unwrap_err(),
+        Fault::MsUnavailable
+    );
+    assert_eq!(
+        mock.check("IT", &generated_vat("IT")).unwrap_err(),
+        Fault::Timeout
+    );
+    assert!(!mock.check("DE", &generated_vat("DE")).unwrap().valid);
+    assert!(mock.check("NL", &generated_vat("NL")).unwrap().valid);
+
+    let everything_down = Mock::new(Options {
+        unavailable: vec!["all".into()],
+        ..Default::default()
+    });
+    assert_eq!(
+        everything_down.check("SE", "100000000001").unwrap_err(),
+        Fault::MsUnavailable
+    );
+}
+
+#[test]
+fn test_soap() {
+    let mock = Mock::default();
+    let number = generated_vat("DE");
+    let response = mock.handle("POST", SOAP_PATH, &soap_request("DE", &number));
+    assert_eq!(response.status, 200);
+    assert!(response.content_type.starts_with("text/xml"));
+    assert!(response.body.contains("<ns2:valid>true</ns2:valid>"));
+    assert!(response
+        .body
+        .contains(&format!("<ns2:vatNumber>{}</ns2:vatNumber>", number)));
+
+    let response = mock.handle("POST", SOAP_PATH, &soap_request("DE", "302"));
+    assert_eq!(response.status, 500);
+    assert!(response.body.contains("<faultstring>TIMEOUT</faultstring>"));
+
+    let response = mock.handle("POST", SOAP_PATH, "<not-soap/>");
+    assert!(response.body.contains("INVALID_INPUT"));
+
+    let wsdl = mock.handle("GET", &format!("{}?wsdl", SOAP_PATH), "");
+    assert_eq!(wsdl.status, 200);
+    assert!(wsdl.body.contains("<wsdl:operation name=\"checkVat\">"));
+    assert!(wsdl.body.contains(SOAP_PATH));
+}
+
+#[test]
+fn test_rest() {
+    let mock = Mock::new(Options {
+        unavailable: vec!["FR".into()],
+        ..Default::default()
+    });
+    let number = generated_vat("ES");
+    let body = format!(r#"{{"countryCode": "ES", "vatNumber": "{}"}}"#, number);
+    let response = mock.handle("POST", "/rest-api/check-vat-number", &body);
+    assert_eq!(response.status, 200);
+    let json: Value = serde_json::from_str(&response.body).unwrap();
+    assert_eq!(json["valid"], true);
+    assert_eq!(json["vatNumber"], number);
+
+    let body = r#"{"countryCode": "FR", "vatNumber": "40303265045"}"#;
+    let response = mock.handle("POST", "/rest-api/check-vat-number", body);
+    assert_eq!(response.status, 500);
+    let json: Value = serde_json::from_str(&response.body).unwrap();
+    assert_eq!(json["errorWrappers"][0]["error"], "MS_UNAVAILABLE");
+
+    let response = mock.handle("GET", &format!("/rest-api/ms/ES/vat/{}", number), "");
+    let json: Value = serde_json::from_str(&response.body).unwrap();
+    assert_eq!(json["isValid"], true);
+    assert_eq!(json["userError"], "VALID");
+
+    let response = mock.handle("GET", "/rest-api/ms/FR/vat/40303265045", "");
+    let json: Value = serde_json::from_str(&response.body).unwrap();
+    assert_eq!(json["userError"], "MS_UNAVAILABLE");
+
+    let response = mock.handle("GET", "/rest-api/check-status", "");
+    let json: Value = serde_json::from_str(&response.body).unwrap();
+    let france = json["countries"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|c| c["countryCode"] == "FR")
+        .unwrap();
+    assert_eq!(france["availability"], "Unavailable");
+
+    assert_eq!(mock.handle("GET", "/nowhere", "").status, 404);
+}
+
+#[test]
+fn test_delay() {
+    let mock = Mock::new(Options {
+        delay: Duration::from_millis(50),
+        ..Default::default()
+    });
+    let start = std::time::Instant::now();
+    mock.handle("GET", "/rest-api/check-status", "");
+    assert!(start.elapsed() >= Duration::from_millis(50));
+}
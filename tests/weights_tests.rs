@@ -0,0 +1,81 @@
+use idsmith::countries::{members, Membership};
+use idsmith::kind::IdKind;
+use idsmith::personal_id::date::Gender;
+use idsmith::weights::{countries, Weighted};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+#[test]
+fn test_parse_spec() {
+    let mix: Weighted<String> = "DE:50, FR:30,NL".parse().unwrap();
+    let entries: Vec<(&str, f64)> = mix
+        .entries()
+        .iter()
+        .map(|(v, w)| (v.as_str(), *w))
+        .collect();
+    assert_eq!(entries, [("DE", 50.0), ("FR", 30.0), ("NL", 1.0)]);
+
+    assert!("".parse::<Weighted<String>>().is_err());
+    assert!("DE:x".parse::<Weighted<String>>().is_err());
+    assert!("DE:-1".parse::<Weighted<String>>().is_err());
+    assert!("DE:0,FR:0".parse::<Weighted<String>>().is_err());
+}
+
+#[test]
+fn test_pick_follows_weights() {
+    let mut rng = StdRng::seed_from_u64(48);
+    let mix = countries("DE:50,FR:30,NL:20", IdKind::Iban).unwrap();
+    let mut counts = [0usize; 3];
+    for _ in 0..10_000 {
+        let picked = mix.pick(&mut rng);
+        let i = ["DE", "FR", "NL"].iter().position(|c| c == picked).unwrap();
+        counts[i] += 1;
+    }
+    assert!((4700..5300).contains(&counts[0]), "{:?}", counts);
+    assert!((2700..3300).contains(&counts[1]), "{:?}", counts);
+    assert!((1700..2300).contains(&counts[2]), "{:?}", counts);
+}
+
+#[test]
+fn test_groups_split_their_weight() {
+    let mix = countries("DE:50,@eurozone:50", IdKind::Vat).unwrap();
+    let eurozone = members(Membership::Eurozone).count();
+    assert_eq!(mix.entries().len(), eurozone);
+    let weight = |c: &str| mix.entries().iter().find(|(v, _)| v == c).unwrap().1;
+    let share = 50.0 / eurozone as f64;
+    assert!((weight("DE") - (50.0 + share)).abs() < 1e-9);
+    assert!((weight("FR") - share).abs() < 1e-9);
+
+    assert!(countries("@nowhere", IdKind::Iban).is_err());
+    assert!(countries("XX:10", IdKind::Iban).is_err());
+    assert!(countries("US", IdKind::Vat).is_err());
+}
+
+#[test]
+fn test_parse_with() {
+    let genders = Weighted::parse_with("m:0,f:1", |g| {
+        Gender::from_str_opt(Some(g)).ok_or_else(|| format!("Unknown gender: {}", g))
+    })
+    .unwrap();
+    let mut rng = StdRng::seed_from_u64(1);
+    assert!((0..100).all(|_| *genders.pick(&mut rng) == Gender::Female));
+
+    let err = Weighted::parse_with("x", |g| {
+        Gender::from_str_opt(Some(g)).ok_or_else(|| format!("Unknown gender: {}", g))
+    });
+    assert_eq!(err.unwrap_err(), "Unknown gender: x");
+}
+
+#[test]
+fn test_mixed_countries_generate() {
+    let mut rng = StdRng::seed_from_u64(2);
+    let mix = countries("EE:1,LT:1,SE:2", IdKind::PersonalId).unwrap();
+    let registry = idsmith::personal_ids();
+    for _ in 0..50 {
+        let country = mix.pick(&mut rng);
+        let code = registry
+            .generate(country, &Default::default(), &mut rng)
+            .unwrap();
+        assert_eq!(registry.validate(country, &code), Some(true));
+    }
+}